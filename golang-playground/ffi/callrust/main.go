@@ -0,0 +1,58 @@
+// Go calling Rust - rust-playground's examples/ffi/rust_exports.rs as a cdylib
+//
+//	(cd ../rust-playground && cargo build --release --example ffi_rust_exports)
+//	go run ./ffi/callrust
+//
+// The reverse of rust-playground's ffi_with_go demo. Rust allocates the
+// greeting and the counter, so Rust frees them: through rp_string_free and
+// rp_counter_free, never C.free. Go's GC doesn't see either allocation.
+package main
+
+/*
+#cgo LDFLAGS: -L${SRCDIR}/../../../rust-playground/target/release/examples -lffi_rust_exports
+#cgo LDFLAGS: -Wl,-rpath,${SRCDIR}/../../../rust-playground/target/release/examples
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct RpCounter RpCounter;
+
+char *rp_greeting(const char *name);
+void rp_string_free(char *s);
+int64_t rp_sum(const int64_t *values, size_t len);
+RpCounter *rp_counter_new(void);
+int64_t rp_counter_add(RpCounter *counter, int64_t n);
+void rp_counter_free(RpCounter *counter);
+*/
+import "C"
+
+import (
+	"fmt"
+	"runtime"
+	"unsafe"
+)
+
+func main() {
+	// Go -> Rust string: C.CString mallocs a copy the GC won't free, so we do
+	name := C.CString("gopher")
+	defer C.free(unsafe.Pointer(name))
+
+	// Rust -> Go string: Rust's allocator owns it until rp_string_free
+	greeting := C.rp_greeting(name)
+	fmt.Println(C.GoString(greeting)) // GoString copies into the Go heap
+	C.rp_string_free(greeting)
+
+	// A Go pointer may cross for one call if the memory holds no Go pointers
+	values := []int64{1, 2, 3, 4, 5}
+	sum := C.rp_sum((*C.int64_t)(unsafe.Pointer(&values[0])), C.size_t(len(values)))
+	fmt.Printf("rp_sum(%v) = %d - Rust read the Go slice in place\n", values, sum)
+
+	// A Rust object behind an opaque pointer: the GC neither scans nor frees it
+	counter := C.rp_counter_new()
+	defer C.rp_counter_free(counter)
+	var total C.int64_t
+	for i := 1; i <= 3; i++ {
+		total = C.rp_counter_add(counter, C.int64_t(i*10))
+		runtime.GC() // Rust's heap isn't the GC's business
+	}
+	fmt.Printf("RpCounter total after 3 adds and 3 GCs: %d\n", total)
+}
@@ -0,0 +1,110 @@
+// Go functions for Rust to call - the Go half of rust-playground's ffi_with_go
+//
+// Built as a C archive (the Go runtime and GC included) and linked into the
+// Rust binary by rust-playground/build.rs with --features go-ffi:
+//
+//	go build -buildmode=c-archive -o libgoexport.a ./ffi/goexport
+//
+// Every //export below is declared in an `unsafe extern "C"` block in
+// rust-playground/src/ffi_with_go.rs. cgo's pointer rules decide who keeps what:
+// memory the caller passes in is borrowed for the call only, Go never returns a
+// pointer into its own heap (C.CString mallocs a copy), and Go objects cross the
+// boundary as cgo.Handle numbers.
+package main
+
+/*
+#include <stdint.h>
+#include <stdlib.h>
+*/
+import "C"
+
+import (
+	"fmt"
+	"runtime"
+	"runtime/cgo"
+	"unsafe"
+)
+
+// GoGreeting returns C memory: the caller hands it back to GoFreeString
+//
+//export GoGreeting
+func GoGreeting(name *C.char) *C.char {
+	greeting := fmt.Sprintf("hello from Go, %s", C.GoString(name)) // GoString copies into the Go heap
+	return C.CString(greeting)                                      // malloc + copy: outside the GC's reach
+}
+
+// GoFreeString frees what GoGreeting returned - with C's free, the allocator that made it
+//
+//export GoFreeString
+func GoFreeString(s *C.char) {
+	C.free(unsafe.Pointer(s))
+}
+
+// GoSum reads the caller's array in place; the slice view must not outlive the call
+//
+//export GoSum
+func GoSum(values *C.int64_t, length C.size_t) C.int64_t {
+	var total C.int64_t
+	for _, v := range unsafe.Slice(values, int(length)) {
+		total += v
+	}
+	return total
+}
+
+// GoFill writes into a caller-owned buffer and returns how many bytes it used
+//
+//export GoFill
+func GoFill(buf *C.char, capacity C.size_t) C.size_t {
+	dst := unsafe.Slice((*byte)(unsafe.Pointer(buf)), int(capacity))
+	return C.size_t(copy(dst, "written by Go into Rust's buffer"))
+}
+
+// counter lives on the Go heap; only its handle leaves Go
+type counter struct {
+	hits []int64
+}
+
+// GoCounterNew returns a handle, not a pointer: the handle table keeps the counter reachable
+//
+//export GoCounterNew
+func GoCounterNew() C.uintptr_t {
+	return C.uintptr_t(cgo.NewHandle(&counter{}))
+}
+
+// GoCounterAdd records n and returns the running total
+//
+//export GoCounterAdd
+func GoCounterAdd(handle C.uintptr_t, n C.int64_t) C.int64_t {
+	c := cgo.Handle(handle).Value().(*counter)
+	c.hits = append(c.hits, int64(n))
+	var total int64
+	for _, hit := range c.hits {
+		total += hit
+	}
+	return C.int64_t(total)
+}
+
+// GoCounterFree drops the handle; the next GC may collect the counter
+//
+//export GoCounterFree
+func GoCounterFree(handle C.uintptr_t) {
+	cgo.Handle(handle).Delete()
+}
+
+// GoCollect forces a GC cycle and returns how many have run so far
+//
+//export GoCollect
+func GoCollect() C.int64_t {
+	runtime.GC()
+	var stats runtime.MemStats
+	runtime.ReadMemStats(&stats)
+	return C.int64_t(stats.NumGC)
+}
+
+// GoNoop does nothing: calling it measures the cost of crossing into Go
+//
+//export GoNoop
+func GoNoop() {}
+
+// Required by -buildmode=c-archive, never called
+func main() {}
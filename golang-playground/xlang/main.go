@@ -0,0 +1,156 @@
+// Cross-language workloads - the Go half of rust-playground's xlang_bench
+//
+// Each workload here has a twin in rust-playground/src/xlang_bench.rs doing
+// the same operations on the same sizes. Both print one line per workload:
+//
+//	xlang workload=vec-push ops=1000000 ns=4123456 allocs=38 bytes=41678848 gcs=2
+//
+// and the Rust runner (make xlang-bench in rust-playground) runs both and
+// prints them side by side. Run this one alone with: go run ./xlang
+package main
+
+import (
+	"fmt"
+	"runtime"
+	"strconv"
+	"strings"
+	"sync"
+	"time"
+)
+
+type Point struct {
+	X, Y, Z, W int64
+}
+
+type Node struct {
+	Left, Right *Node
+}
+
+type Workload struct {
+	Name string
+	Ops  int
+	Run  func(ops int) uint64
+}
+
+// Keep in sync with WORKLOADS in rust-playground/src/xlang_bench.rs
+var workloads = []Workload{
+	{"box-alloc", 1_000_000, boxAlloc},
+	{"vec-push", 1_000_000, vecPush},
+	{"map-insert", 200_000, mapInsert},
+	{"string-build", 200_000, stringBuild},
+	{"binary-tree", 1<<19 - 1, binaryTree},
+	{"mutex-counter", 1_000_000, mutexCounter},
+}
+
+const threads = 4
+
+// One heap object per op: a slice of pointers keeps them alive until the end
+func boxAlloc(ops int) uint64 {
+	points := make([]*Point, 0, ops)
+	for i := 0; i < ops; i++ {
+		points = append(points, &Point{X: int64(i), Y: 1, Z: 2, W: 3})
+	}
+	var sum uint64
+	for _, p := range points {
+		sum += uint64(p.X)
+	}
+	return sum
+}
+
+// append into a nil slice: the runtime grows it as needed
+func vecPush(ops int) uint64 {
+	var values []uint64
+	for i := 0; i < ops; i++ {
+		values = append(values, uint64(i))
+	}
+	return uint64(len(values))
+}
+
+// A map with no size hint
+func mapInsert(ops int) uint64 {
+	m := make(map[uint64]uint64)
+	for i := 0; i < ops; i++ {
+		m[uint64(i)] = uint64(i) * 2
+	}
+	return uint64(len(m))
+}
+
+// "item-0,item-1,..." through one growing strings.Builder
+func stringBuild(ops int) uint64 {
+	var b strings.Builder
+	var digits [20]byte
+	for i := 0; i < ops; i++ {
+		b.WriteString("item-")
+		b.Write(strconv.AppendInt(digits[:0], int64(i), 10))
+		b.WriteByte(',')
+	}
+	return uint64(b.Len())
+}
+
+func build(depth int) *Node {
+	if depth == 0 {
+		return &Node{}
+	}
+	return &Node{Left: build(depth - 1), Right: build(depth - 1)}
+}
+
+func (n *Node) count() uint64 {
+	if n == nil {
+		return 0
+	}
+	return 1 + n.Left.count() + n.Right.count()
+}
+
+// A complete binary tree with `ops` nodes (ops = 2^(depth+1) - 1)
+func binaryTree(ops int) uint64 {
+	depth := 0
+	for (1<<(depth+2))-1 <= ops {
+		depth++
+	}
+	return build(depth).count()
+}
+
+// `threads` goroutines sharing one sync.Mutex-guarded counter
+func mutexCounter(ops int) uint64 {
+	var mu sync.Mutex
+	var counter uint64
+	var wg sync.WaitGroup
+	for t := 0; t < threads; t++ {
+		wg.Add(1)
+		go func() {
+			defer wg.Done()
+			for i := 0; i < ops/threads; i++ {
+				mu.Lock()
+				counter++
+				mu.Unlock()
+			}
+		}()
+	}
+	wg.Wait()
+	return counter
+}
+
+var sink uint64
+
+func measure(w Workload) {
+	sink += w.Run(w.Ops / 10) // Warm up: page in the heap, size the GC's target
+
+	var before, after runtime.MemStats
+	runtime.GC()
+	runtime.ReadMemStats(&before)
+	start := time.Now()
+	sink += w.Run(w.Ops)
+	elapsed := time.Since(start)
+	runtime.ReadMemStats(&after)
+
+	fmt.Printf("xlang workload=%s ops=%d ns=%d allocs=%d bytes=%d gcs=%d\n",
+		w.Name, w.Ops, elapsed.Nanoseconds(),
+		after.Mallocs-before.Mallocs, after.TotalAlloc-before.TotalAlloc, after.NumGC-before.NumGC)
+}
+
+func main() {
+	fmt.Printf("xlang language=go version=%s threads=%d\n", runtime.Version(), runtime.GOMAXPROCS(0))
+	for _, w := range workloads {
+		measure(w)
+	}
+}
@@ -0,0 +1,102 @@
+// Reproducible numbers for rc_weak::cost_comparison()
+// Run: cargo bench --bench ownership_costs
+//
+// Plain borrow vs Rc clone/drop vs Arc clone/drop,
+// and RefCell::borrow vs Mutex::lock (uncontended and contended).
+
+use std::cell::RefCell;
+use std::hint::black_box;
+use std::rc::Rc;
+use std::sync::{Arc, Barrier, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+
+// Clone + drop one handle to a shared Vec: the cost of "one more owner"
+fn clone_and_drop(c: &mut Criterion) {
+    let mut group = c.benchmark_group("clone_and_drop");
+
+    let data = vec![1, 2, 3];
+    group.bench_function("borrow &T", |b| {
+        b.iter(|| {
+            let r = black_box(&data);  // Just a pointer copy
+            black_box(r.len())
+        })
+    });
+
+    let rc = Rc::new(vec![1, 2, 3]);
+    group.bench_function("Rc::clone + drop", |b| {
+        b.iter(|| {
+            let r = Rc::clone(black_box(&rc));  // Non-atomic increment
+            black_box(r.len())
+        })  // Non-atomic decrement
+    });
+
+    let arc = Arc::new(vec![1, 2, 3]);
+    group.bench_function("Arc::clone + drop", |b| {
+        b.iter(|| {
+            let r = Arc::clone(black_box(&arc));  // Atomic increment
+            black_box(r.len())
+        })  // Atomic decrement
+    });
+
+    group.finish();
+}
+
+// Read access through interior mutability, single thread
+fn uncontended_access(c: &mut Criterion) {
+    let mut group = c.benchmark_group("uncontended_access");
+
+    let cell = RefCell::new(vec![1, 2, 3]);
+    group.bench_function("RefCell::borrow", |b| {
+        b.iter(|| black_box(cell.borrow().len()))  // Check + bump borrow flag
+    });
+
+    let mutex = Mutex::new(vec![1, 2, 3]);
+    group.bench_function("Mutex::lock", |b| {
+        b.iter(|| black_box(mutex.lock().unwrap().len()))  // Atomic CAS, no waiting
+    });
+
+    group.finish();
+}
+
+// Mutex::lock while other threads hammer the same lock
+fn contended_mutex(c: &mut Criterion) {
+    let mut group = c.benchmark_group("contended_mutex_lock");
+    group.measurement_time(Duration::from_secs(3));
+
+    for threads in [1, 2, 4, 8] {
+        group.bench_with_input(BenchmarkId::from_parameter(threads), &threads, |b, &threads| {
+            b.iter_custom(|iters| {
+                let counter = Arc::new(Mutex::new(0u64));
+                let start_line = Arc::new(Barrier::new(threads));
+                let per_thread = iters / threads as u64 + 1;
+
+                let start = Instant::now();
+                let handles: Vec<_> = (0..threads)
+                    .map(|_| {
+                        let counter = Arc::clone(&counter);
+                        let start_line = Arc::clone(&start_line);
+                        thread::spawn(move || {
+                            start_line.wait();
+                            for _ in 0..per_thread {
+                                *counter.lock().unwrap() += 1;
+                            }
+                        })
+                    })
+                    .collect();
+                for handle in handles {
+                    handle.join().unwrap();
+                }
+                // Wall time for ~iters acquisitions spread across all threads
+                start.elapsed()
+            })
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, clone_and_drop, uncontended_access, contended_mutex);
+criterion_main!(benches);
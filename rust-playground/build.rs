@@ -0,0 +1,43 @@
+// Links ../golang-playground/ffi/goexport as a C archive for the ffi_with_go demo.
+// Does nothing unless --features go-ffi is on, so default builds need no Go.
+
+use std::env;
+use std::path::PathBuf;
+use std::process::Command;
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    if env::var_os("CARGO_FEATURE_GO_FFI").is_none() {
+        return;
+    }
+
+    let manifest_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    let package = manifest_dir.join("../golang-playground/ffi/goexport");
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+    println!("cargo:rerun-if-changed={}", package.join("main.go").display());
+    println!("cargo:rerun-if-env-changed=GO");
+
+    let status = Command::new(env::var("GO").unwrap_or_else(|_| "go".into()))
+        .args(["build", "-buildmode=c-archive", "-o"])
+        .arg(out_dir.join("libgoexport.a"))
+        .arg(".")
+        .current_dir(&package)
+        .status();
+    match status {
+        Ok(status) if status.success() => {}
+        Ok(status) => panic!("`go build -buildmode=c-archive` in {} failed ({})", package.display(), status),
+        Err(err) => panic!("--features go-ffi needs a Go toolchain on PATH (or GO=/path/to/go): {}", err),
+    }
+
+    println!("cargo:rustc-link-search=native={}", out_dir.display());
+    println!("cargo:rustc-link-lib=static=goexport");
+    // What the Go runtime inside the archive links against
+    match env::var("CARGO_CFG_TARGET_OS").as_deref() {
+        Ok("macos") => {
+            println!("cargo:rustc-link-lib=framework=CoreFoundation");
+            println!("cargo:rustc-link-lib=framework=Security");
+        }
+        Ok("windows") => {}
+        _ => println!("cargo:rustc-link-lib=dylib=pthread"),
+    }
+}
@@ -0,0 +1,143 @@
+// Example: a REAL data race - only possible with `unsafe`
+// Run: cargo run --example data_race_unsafe   (make data-race)
+//
+// Safe Rust rejects shared mutation across threads at compile time
+// (Rc is !Send, &mut is exclusive). Raw pointers opt out of those checks,
+// so here several threads increment one plain u64 with no synchronization.
+// This is Undefined Behavior - it's here to be watched, never copied.
+//
+// See it flagged by a tool instead of by a wrong number:
+//   Miri:  cargo +nightly miri run --example data_race_unsafe
+//          → "error: Undefined Behavior: Data race detected between ..."
+//   TSan:  RUSTFLAGS="-Zsanitizer=thread" cargo +nightly run -Zbuild-std \
+//              --target x86_64-unknown-linux-gnu --example data_race_unsafe
+//          → "WARNING: ThreadSanitizer: data race"
+//   Go:    go run -race main.go  (same ThreadSanitizer, built in)
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Barrier, Mutex};
+use std::thread;
+use std::time::Instant;
+
+const THREADS: u64 = 4;
+// Miri interprets every instruction; keep it short there
+const INCREMENTS: u64 = if cfg!(miri) { 100 } else { 1_000_000 };
+
+static mut COUNTER: u64 = 0;
+
+// A pair that should always hold two equal halves
+static mut PAIR: (u64, u64) = (0, 0);
+
+// Plain load + add + store: three steps another thread can interleave with.
+// Volatile keeps the compiler from merging the loop into one big add.
+fn racy_increment() {
+    let counter = &raw mut COUNTER;
+    // SAFETY: none - this is the data race being demonstrated
+    unsafe { counter.write_volatile(counter.read_volatile() + 1) };
+}
+
+fn lost_updates() {
+    println!("--- {} threads × {} unsynchronized increments ---", THREADS, INCREMENTS);
+    let start = Instant::now();
+    let barrier = Arc::new(Barrier::new(THREADS as usize));
+    let handles: Vec<_> = (0..THREADS)
+        .map(|_| {
+            let barrier = Arc::clone(&barrier);
+            thread::spawn(move || {
+                barrier.wait();  // Start together so the loops overlap
+                for _ in 0..INCREMENTS {
+                    racy_increment();
+                }
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    // SAFETY: all writers have been joined
+    let total = unsafe { (&raw const COUNTER).read() };
+    let expected = THREADS * INCREMENTS;
+    println!("  expected: {}", expected);
+    println!("  got:      {}  ({:?})", total, start.elapsed());
+    if total == expected {
+        println!("  (no lost updates this run - with few cores the loops may not overlap; run it again)");
+    } else {
+        println!("  ❌ {} increments LOST: two threads read the same value, both wrote value + 1", expected - total);
+    }
+    println!();
+}
+
+fn torn_reads() {
+    println!("--- Writer keeps (a, b) equal; reader checks a == b ---");
+    static WRITING: AtomicBool = AtomicBool::new(true);
+    let writer = thread::spawn(|| {
+        let pair = &raw mut PAIR;
+        for i in 1..=INCREMENTS {
+            // SAFETY: none - the reader runs concurrently
+            unsafe {
+                (&raw mut (*pair).0).write_volatile(i);
+                (&raw mut (*pair).1).write_volatile(i);
+            }
+        }
+        WRITING.store(false, Ordering::Release);
+    });
+    let reader = thread::spawn(|| {
+        let pair = &raw const PAIR;
+        let mut torn = 0;
+        let mut example = None;
+        while WRITING.load(Ordering::Acquire) {
+            // SAFETY: none - the writer runs concurrently
+            let (a, b) = unsafe { ((&raw const (*pair).0).read_volatile(), (&raw const (*pair).1).read_volatile()) };
+            if a != b {
+                torn += 1;
+                example.get_or_insert((a, b));
+            }
+        }
+        (torn, example)
+    });
+    writer.join().unwrap();
+    let (torn, example) = reader.join().unwrap();
+
+    println!("  reads that saw a half-updated pair: {}", torn);
+    if let Some((a, b)) = example {
+        println!("  ❌ e.g. a = {}, b = {}: an invariant that \"always holds\" didn't", a, b);
+    }
+    println!();
+}
+
+fn mutex_fix() {
+    println!("--- Fixed: Arc<Mutex<u64>> ---");
+    let start = Instant::now();
+    let counter = Arc::new(Mutex::new(0u64));
+    let handles: Vec<_> = (0..THREADS)
+        .map(|_| {
+            let counter = Arc::clone(&counter);
+            thread::spawn(move || {
+                for _ in 0..INCREMENTS {
+                    *counter.lock().unwrap() += 1;
+                }
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    let total = *counter.lock().unwrap();
+    println!("  expected: {}", THREADS * INCREMENTS);
+    println!("  got:      {}  ({:?})", total, start.elapsed());
+    println!("  ✅ The lock makes read-modify-write one step; no unsafe needed");
+    println!("  ✅ (AtomicU64::fetch_add does the same without a lock - see the atomics demo)\n");
+}
+
+fn main() {
+    println!("=== Data Race Example (unsafe) ===\n");
+
+    lost_updates();
+    torn_reads();
+    mutex_fix();
+
+    println!("Safe Rust: this program doesn't compile without `unsafe`.");
+    println!("Go: it compiles and runs as-is - only `go run -race` catches it.");
+}
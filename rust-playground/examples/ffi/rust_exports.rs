@@ -0,0 +1,77 @@
+// Rust functions for Go to call - a cdylib linked by golang-playground/ffi/callrust
+//
+//   cargo build --release --example ffi_rust_exports
+//   cd ../golang-playground && go run ./ffi/callrust    (or: make go-calls-rust)
+//
+// The mirror image of src/ffi_with_go.rs: Rust allocates, so Rust frees.
+// Strings leave as CString::into_raw and come back through rp_string_free;
+// objects leave as Box::into_raw and come back through rp_counter_free. Go
+// must never hand either to C.free - a different allocator owns them.
+
+use std::ffi::{c_char, CStr, CString};
+
+/// Returns an owned C string; give it back with `rp_string_free`, not `free`
+///
+/// # Safety
+/// `name` is null or a NUL-terminated string valid for the duration of the call.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn rp_greeting(name: *const c_char) -> *mut c_char {
+    let name = if name.is_null() { "nobody".into() } else { unsafe { CStr::from_ptr(name) }.to_string_lossy() };
+    CString::new(format!("hello from Rust, {}", name)).expect("no NUL in the greeting").into_raw()
+}
+
+/// Frees a string from `rp_greeting`
+///
+/// # Safety
+/// `s` is null or came from `rp_greeting` and hasn't been freed yet.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn rp_string_free(s: *mut c_char) {
+    if !s.is_null() {
+        drop(unsafe { CString::from_raw(s) });  // back into a CString, so Rust's allocator frees it
+    }
+}
+
+/// Sums a borrowed array; the pointer isn't kept after the call
+///
+/// # Safety
+/// `values` points to `len` readable i64s (or `len` is 0).
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn rp_sum(values: *const i64, len: usize) -> i64 {
+    if len == 0 {
+        return 0;  // from_raw_parts needs a non-null pointer even for an empty slice
+    }
+    unsafe { std::slice::from_raw_parts(values, len) }.iter().sum()
+}
+
+/// Opaque to Go: it only ever sees a *RpCounter
+pub struct RpCounter {
+    hits: Vec<i64>,
+}
+
+/// A heap counter owned by the caller until `rp_counter_free`
+#[unsafe(no_mangle)]
+pub extern "C" fn rp_counter_new() -> *mut RpCounter {
+    Box::into_raw(Box::new(RpCounter { hits: Vec::new() }))
+}
+
+/// Records `n` and returns the running total
+///
+/// # Safety
+/// `counter` came from `rp_counter_new` and hasn't been freed yet.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn rp_counter_add(counter: *mut RpCounter, n: i64) -> i64 {
+    let counter = unsafe { &mut *counter };
+    counter.hits.push(n);
+    counter.hits.iter().sum()
+}
+
+/// Frees a counter - Box::from_raw hands it back to Drop
+///
+/// # Safety
+/// `counter` is null or came from `rp_counter_new` and hasn't been freed yet.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn rp_counter_free(counter: *mut RpCounter) {
+    if !counter.is_null() {
+        drop(unsafe { Box::from_raw(counter) });
+    }
+}
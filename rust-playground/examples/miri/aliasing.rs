@@ -0,0 +1,27 @@
+// Miri pack: breaking &mut's exclusivity through a raw pointer
+// Run: cargo +nightly miri run --example miri_aliasing   (make miri)
+// Expected: does not exist in the borrow stack
+//
+// No race, no free, no bad value - and still UB. A &mut promises that
+// nothing else touches the value while it's live; the optimizer may keep it
+// in a register on that promise. Writing through an older raw pointer and
+// then using the &mut again breaks it. Miri's aliasing model (Stacked
+// Borrows by default, Tree Borrows with -Zmiri-tree-borrows) notices that the
+// &mut's permission was revoked by the raw write:
+//
+//   error: Undefined Behavior: attempting a read access using <tag> at
+//          alloc[0x0], but that tag does not exist in the borrow stack
+//
+// Go: no such rule exists, so there is nothing to break - and nothing for
+// the compiler to optimize with. The race detector has no equivalent check.
+
+fn main() {
+    let mut total = 10u32;
+    let raw: *mut u32 = &mut total;
+    // SAFETY: fine so far - the &mut is derived from the raw pointer
+    let exclusive: &mut u32 = unsafe { &mut *raw };
+    // SAFETY: none - writes behind the back of the live `exclusive`
+    unsafe { *raw = 20 };
+    *exclusive += 1;
+    println!("total = {}", total);
+}
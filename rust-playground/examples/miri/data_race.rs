@@ -0,0 +1,35 @@
+// Miri pack: a data race on a plain static
+// Run: cargo +nightly miri run --example miri_data_race   (make miri)
+// Expected: Data race detected
+//
+// Two threads write one `static mut` with no lock and no atomic. Safe Rust
+// won't compile this (static mut needs unsafe, &mut is exclusive). Natively
+// the program just prints a number - lost updates only show under load.
+// Miri's race detector keeps a vector clock per thread and reports any two
+// accesses, at least one a write, that aren't ordered by happens-before -
+// whether or not they actually overlapped in this run:
+//
+//   error: Undefined Behavior: Data race detected between (1) non-atomic write
+//          on thread `unnamed-1` and (2) non-atomic write on thread `unnamed-2`
+//
+// Go: `go run -race` finds the same race (ThreadSanitizer), but only on the
+// interleavings that happen while it watches.
+
+use std::thread;
+
+static mut HITS: u64 = 0;
+
+fn record_hit() {
+    let hits = &raw mut HITS;
+    // SAFETY: none - two threads call this without synchronization
+    unsafe { hits.write(hits.read() + 1) };
+}
+
+fn main() {
+    let first = thread::spawn(record_hit);
+    let second = thread::spawn(record_hit);
+    first.join().unwrap();
+    second.join().unwrap();
+    // SAFETY: both writers have been joined
+    println!("hits = {}", unsafe { (&raw const HITS).read() });
+}
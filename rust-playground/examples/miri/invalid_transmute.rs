@@ -0,0 +1,21 @@
+// Miri pack: an invalid value built with transmute
+// Run: cargo +nightly miri run --example miri_invalid_transmute   (make miri)
+// Expected: expected a boolean
+//
+// A bool must be 0 or 1 - the compiler relies on it (niches, branch
+// elimination: `if b` may test any bit). transmute skips the check, so 3u8
+// becomes a bool that is neither true nor false. Natively, what prints
+// depends on the optimizer. Miri validates every value as it's produced:
+//
+//   error: Undefined Behavior: constructing invalid value: encountered 0x03,
+//          but expected a boolean
+//
+// Go: unsafe.Pointer casts allow the same, and nothing checks them.
+
+#[allow(clippy::transmute_int_to_bool)]  // The lint is right; the point is to see Miri agree
+fn main() {
+    let byte: u8 = 3;
+    // SAFETY: none - 3 is not a valid bool
+    let flag: bool = unsafe { std::mem::transmute::<u8, bool>(byte) };
+    println!("flag = {}, !flag = {}", flag, !flag);
+}
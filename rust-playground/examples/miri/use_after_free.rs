@@ -0,0 +1,24 @@
+// Miri pack: use-after-free through a raw pointer
+// Run: cargo +nightly miri run --example miri_use_after_free   (make miri)
+// Expected: has been freed
+//
+// A reference can't outlive its Box (E0597); a raw pointer can. The read
+// after drop() is Undefined Behavior. Natively it usually prints the old
+// value, or whatever the allocator wrote there since. Miri tracks every
+// allocation's lifetime and stops at the read:
+//
+//   error: Undefined Behavior: memory access failed: alloc1234 has been freed,
+//          so this pointer is dangling
+//
+// Go: can't happen without package unsafe - the GC keeps the object alive
+// as long as any pointer to it exists.
+
+fn main() {
+    let balance = Box::new(100u64);
+    let dangling: *const u64 = &*balance;
+    drop(balance);
+
+    // SAFETY: none - the Box was freed on the line above
+    let read = unsafe { dangling.read() };
+    println!("read {} through a dangling pointer", read);
+}
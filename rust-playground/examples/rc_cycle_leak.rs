@@ -0,0 +1,113 @@
+// Example: a real Rc reference cycle leak - and the Weak fix
+// Run with heap numbers: cargo run --example rc_cycle_leak --features alloc-stats
+//
+// Go's tracing GC collects cycles. Reference counting can't:
+// a -> b -> a keeps both strong counts at >= 1 forever.
+
+use std::cell::RefCell;
+use std::rc::{Rc, Weak};
+
+use rust_playground::alloc_stats;
+
+// Strong link to the next node: a cycle of these never frees
+struct Node {
+    name: &'static str,
+    payload: Vec<u8>,
+    next: RefCell<Option<Rc<Node>>>,
+}
+
+// Same node, but the back-link is Weak
+struct WeakNode {
+    name: &'static str,
+    payload: Vec<u8>,
+    next: RefCell<Option<Rc<WeakNode>>>,
+    prev: RefCell<Weak<WeakNode>>,
+}
+
+impl Drop for Node {
+    fn drop(&mut self) {
+        println!("  drop {} ({} bytes freed)", self.name, self.payload.len());
+    }
+}
+
+impl Drop for WeakNode {
+    fn drop(&mut self) {
+        println!("  drop {} ({} bytes freed)", self.name, self.payload.len());
+    }
+}
+
+const PAYLOAD: usize = 64 * 1024;
+
+fn print_heap(label: &str, baseline: usize) {
+    if alloc_stats::enabled() {
+        let live = alloc_stats::live_bytes().saturating_sub(baseline);
+        println!("  heap still live {}: {} bytes", label, live);
+    }
+}
+
+fn leaking_cycle() {
+    println!("--- Strong cycle: a -> b -> a ---");
+    let baseline = alloc_stats::live_bytes();
+    {
+        let a = Rc::new(Node { name: "a", payload: vec![0; PAYLOAD], next: RefCell::new(None) });
+        let b = Rc::new(Node { name: "b", payload: vec![0; PAYLOAD], next: RefCell::new(None) });
+
+        *a.next.borrow_mut() = Some(Rc::clone(&b));
+        *b.next.borrow_mut() = Some(Rc::clone(&a));  // Closes the cycle
+
+        println!("  a strong_count = {}", Rc::strong_count(&a));
+        println!("  b strong_count = {}", Rc::strong_count(&b));
+        println!("  -- scope ends: dropping locals a and b --");
+    }
+    // Each local drop only took the count from 2 to 1: no "drop" lines above
+    println!("  (no drop messages - both nodes are unreachable but alive)");
+    print_heap("after scope", baseline);
+
+    println!("\n❌ Strong counts never reach zero → Drop never runs → memory leaked");
+    println!("⚠️ Go's GC would collect this: it traces reachability, not counts\n");
+}
+
+fn weak_back_link() {
+    println!("--- Fixed: a -> b strong, b -> a Weak ---");
+    let baseline = alloc_stats::live_bytes();
+    {
+        let a = Rc::new(WeakNode {
+            name: "a",
+            payload: vec![0; PAYLOAD],
+            next: RefCell::new(None),
+            prev: RefCell::new(Weak::new()),
+        });
+        let b = Rc::new(WeakNode {
+            name: "b",
+            payload: vec![0; PAYLOAD],
+            next: RefCell::new(None),
+            prev: RefCell::new(Weak::new()),
+        });
+
+        *a.next.borrow_mut() = Some(Rc::clone(&b));
+        *b.prev.borrow_mut() = Rc::downgrade(&a);  // Doesn't bump strong_count
+
+        println!("  a strong_count = {}, weak_count = {}", Rc::strong_count(&a), Rc::weak_count(&a));
+        println!("  b strong_count = {}, weak_count = {}", Rc::strong_count(&b), Rc::weak_count(&b));
+        if let Some(prev) = b.prev.borrow().upgrade() {
+            println!("  b.prev.upgrade() → Some({})", prev.name);
+        }
+        println!("  -- scope ends: dropping locals a and b --");
+    }
+    print_heap("after scope", baseline);
+
+    println!("\n✅ a hits zero → a drops → its strong link to b goes → b drops");
+    println!("✅ Weak breaks the cycle: ownership flows one way only\n");
+}
+
+fn main() {
+    println!("=== Rc Cycle Leak Example ===\n");
+    if !alloc_stats::enabled() {
+        println!("(run with --features alloc-stats to see live heap bytes)\n");
+    }
+
+    leaking_cycle();
+    weak_back_link();
+
+    println!("Rule of thumb: parent → child is Rc, child → parent is Weak");
+}
@@ -29,6 +29,7 @@ fn main() {
     // let _mut_borrow = data.borrow_mut();  // 💥 PANIC! "already borrowed: BorrowMutError"
     
     println!("(Commented out the panic line - uncomment to see it fail!)");
+    println!("(Or run it for real, caught: cargo run --example refcell_panic_caught)");
     println!("\n⚠️ RefCell checks borrowing rules at RUNTIME");
     println!("⚠️ Violating rules causes PANIC, not compile error");
 }
@@ -0,0 +1,103 @@
+// Example: a RefCell borrow violation, actually triggered and caught
+// Run: cargo run --example refcell_panic_caught   (make refcell-panic-caught)
+//
+// refcell_panic.rs stops one line short of the panic. This one takes the
+// line: borrow_mut() while a borrow() is alive panics ("RefCell already
+// borrowed"), and std::panic::catch_unwind stops the unwind so we can look
+// at it.
+//
+// Catching it is for looking, not for control flow. The panic means the
+// program broke its own aliasing rule; the data it was halfway through
+// changing may be inconsistent, and with panic = "abort" there's nothing to
+// catch at all. If a conflict is expected, ask first: try_borrow_mut().
+//
+// Go: recover() in a deferred function is the closest thing - and it's used
+// the same way in good Go code: at a boundary (one HTTP request, one job),
+// never as an exception mechanism.
+
+use std::any::Any;
+use std::cell::RefCell;
+use std::panic::{self, AssertUnwindSafe};
+
+// A panic payload is Box<dyn Any>: a &str for panic!("literal"), a String when formatted
+fn payload_text(payload: &(dyn Any + Send)) -> String {
+    if let Some(text) = payload.downcast_ref::<&str>() {
+        text.to_string()
+    } else if let Some(text) = payload.downcast_ref::<String>() {
+        text.clone()
+    } else {
+        "<non-string payload>".to_string()
+    }
+}
+
+struct Account {
+    balance: RefCell<i64>,
+    history: RefCell<Vec<String>>,
+}
+
+impl Account {
+    // The bug: the audit hook runs while `balance` is still mutably borrowed
+    fn deposit(&self, amount: i64, audit: impl Fn(&Account)) {
+        self.history.borrow_mut().push(format!("deposit {} started", amount));
+        let mut balance = self.balance.borrow_mut();
+        *balance += amount;
+        audit(self);  // 💥 the hook reads balance → already mutably borrowed
+        self.history.borrow_mut().push(format!("deposit {} finished", amount));
+    }
+}
+
+fn main() {
+    println!("--- borrow_mut() while a borrow() is alive ---");
+    let data = RefCell::new(42);
+    let reader = data.borrow();
+    println!("reader holds {} (borrow flag: 1 shared borrow)", *reader);
+
+    // The default hook still prints "thread 'main' panicked at ..." to stderr
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        let mut writer = data.borrow_mut();  // 💥 RefCell already borrowed
+        *writer = 100;
+    }));
+    match &result {
+        Ok(()) => println!("no panic?!"),
+        Err(payload) => println!("❌ caught the panic, payload: {:?}", payload_text(payload.as_ref())),
+    }
+    println!("value is still {} - the write never happened", *reader);
+    drop(reader);
+
+    println!("\n--- the non-panicking API: ask first ---");
+    let reader = data.borrow();
+    match data.try_borrow_mut() {
+        Ok(_) => println!("got the mutable borrow"),
+        Err(error) => println!("try_borrow_mut() → Err({:?}) - no panic, handle it like any Result", error),
+    }
+    drop(reader);
+    println!("after the reader drops: try_borrow_mut().is_ok() = {}", data.try_borrow_mut().is_ok());
+
+    println!("\n--- why catching is not a fix: state left halfway ---");
+    let account = Account { balance: RefCell::new(100), history: RefCell::new(Vec::new()) };
+    let previous_hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));  // Quiet this time - we print the payload ourselves
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        account.deposit(50, |account| println!("audit: balance = {}", account.balance.borrow()));
+    }));
+    panic::set_hook(previous_hook);
+    if let Err(payload) = &result {
+        println!("❌ deposit panicked: {}", payload_text(payload.as_ref()));
+    }
+    println!("balance: {}  (the += ran before the panic)", account.balance.borrow());
+    println!("history: {:?}", account.history.borrow());
+    println!("⚠️ Started but never finished: catch_unwind gave us back an Account whose invariants are broken");
+    println!("   That's why catch_unwind wants an UnwindSafe closure - AssertUnwindSafe is you promising it's fine");
+
+    println!("\n--- what to do instead ---");
+    println!("✓ Fix the borrow scope: end the borrow_mut() before calling out (`drop(balance); audit(self);`)");
+    println!("✓ Or use try_borrow()/try_borrow_mut() where a conflict is a legitimate case");
+    println!("✓ catch_unwind belongs at a boundary: a thread pool job, a plugin call, an FFI edge");
+    println!("✓ With panic = \"abort\" in Cargo.toml none of this runs - the process just stops");
+
+    println!("\n--- Go ---");
+    println!("defer func() {{ if r := recover(); r != nil {{ log.Print(r) }} }}()");
+    println!("Same rule: net/http recovers per request so one bad handler doesn't kill the server,");
+    println!("but a recovered panic is still a bug report. And some Go failures (concurrent map");
+    println!("writes, out of memory) are fatal errors recover() can't catch at all.");
+}
@@ -0,0 +1,48 @@
+// Example: a use-after-free - only possible with `unsafe`
+// Run: cargo run --example use_after_free   (make use-after-free)
+//
+// Safe Rust refuses to let a reference outlive its owner (E0597). A raw
+// pointer has no lifetime, so here one survives the Box it points into and
+// is read after the free. This is Undefined Behavior - it's here to be
+// watched, never copied. It often "works", which is the danger.
+//
+// See it flagged by a tool instead of by luck:
+//   Miri:  cargo +nightly miri run --example use_after_free
+//          → "error: Undefined Behavior: memory access failed: alloc... has been freed"
+//   ASan:  RUSTFLAGS="-Zsanitizer=address" cargo +nightly run \
+//              --target x86_64-unknown-linux-gnu --example use_after_free
+//          → "ERROR: AddressSanitizer: heap-use-after-free"
+//   Go:    no equivalent without unsafe - the GC keeps x alive while p points at it
+
+struct Account {
+    id: u64,
+    balance: u64,
+}
+
+fn main() {
+    println!("--- read through a pointer after the Box is dropped ---");
+    let account = Box::new(Account { id: 1, balance: 100 });
+    let dangling: *const Account = &*account;
+    println!("before drop: {:p} → id {}, balance {}", dangling, account.id, account.balance);
+
+    drop(account);
+
+    // The slot is free; the next allocation of the same size may take it
+    let reused = Box::new(Account { id: 2, balance: 999_999 });
+    let reused_ptr: *const Account = &*reused;
+
+    // SAFETY: none - this is the use-after-free being demonstrated
+    let (id, balance) = unsafe { ((*dangling).id, (*dangling).balance) };
+    println!("after drop:  {:p} → id {}, balance {}", dangling, id, balance);
+    println!("new Box at:  {:p}", reused_ptr);
+
+    if std::ptr::eq(dangling, reused_ptr) {
+        println!("\n❌ The allocator reused the slot: the old pointer now reads someone else's account");
+    } else {
+        println!("\n⚠️ Stale data this time - still UB; run it under Miri to see it flagged");
+    }
+
+    println!("\n--- the safe version doesn't compile ---");
+    println!("let r; {{ let b = Box::new(7); r = &*b; }} println!(\"{{}}\", r);  // ❌ E0597");
+    println!("✓ Keep the Box (or an Rc) alive as long as anything points into it");
+}
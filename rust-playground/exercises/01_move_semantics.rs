@@ -0,0 +1,18 @@
+// Exercise 1 - move semantics
+// `greet` takes ownership of its String, so `name` is gone after the first
+// call. Make both calls work without changing what gets printed.
+// (Related demo: cargo run -- run ownership)
+
+// I AM NOT DONE
+
+fn greet(name: String) -> String {
+    format!("hello, {}", name)
+}
+
+fn main() {
+    let name = String::from("gopher");
+    let first = greet(name);
+    let second = greet(name);
+    assert_eq!(first, second);
+    println!("{}", second);
+}
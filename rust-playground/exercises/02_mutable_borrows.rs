@@ -0,0 +1,15 @@
+// Exercise 2 - one &mut at a time
+// Two mutable borrows of `scores` are alive at once. Reorder the code so
+// each borrow ends before the next one starts.
+// (Related demo: cargo run -- run borrow-checker)
+
+// I AM NOT DONE
+
+fn main() {
+    let mut scores = vec![10, 20, 30];
+    let first = &mut scores[0];
+    let last = scores.last_mut().unwrap();
+    *first += 1;
+    *last += 1;
+    assert_eq!(scores, [11, 20, 31]);
+}
@@ -0,0 +1,16 @@
+// Exercise 3 - returning a reference to a local
+// `label` builds a String and tries to hand out a reference to it, but the
+// String is dropped when the function returns. In Go the escape analysis
+// would move it to the heap; in Rust, return something the caller can own.
+// (Related demo: cargo run -- run stack-vs-heap-escape)
+
+// I AM NOT DONE
+
+fn label(id: u32) -> &String {
+    let text = format!("item-{}", id);
+    &text
+}
+
+fn main() {
+    assert_eq!(label(7), "item-7");
+}
@@ -0,0 +1,16 @@
+// Exercise 4 - lifetime annotations
+// The compiler can't tell whether `longest` returns a borrow of `a` or of
+// `b`. Add a lifetime parameter that ties the result to both inputs.
+// (Related demo: cargo run -- run lifetimes)
+
+// I AM NOT DONE
+
+fn longest(a: &str, b: &str) -> &str {
+    if a.len() >= b.len() { a } else { b }
+}
+
+fn main() {
+    let go = String::from("gopher");
+    let rust = String::from("ferris the crab");
+    assert_eq!(longest(&go, &rust), "ferris the crab");
+}
@@ -0,0 +1,16 @@
+// Exercise 5 - shared ownership AND mutation
+// Two owners share one counter through Rc, but Rc only hands out shared
+// references. Wrap the counter so both owners can increment it.
+// (Related demo: cargo run -- run rc-weak)
+
+// I AM NOT DONE
+
+use std::rc::Rc;
+
+fn main() {
+    let counter = Rc::new(0);
+    let other_owner = Rc::clone(&counter);
+    *counter += 1;
+    *other_owner += 1;
+    assert_eq!(*counter, 2);
+}
@@ -0,0 +1,25 @@
+// Exercise 6 - sharing across threads
+// Rc's reference count isn't atomic, so an Rc can't be sent to another
+// thread (E0277: `Rc<...>` cannot be sent between threads safely). Switch to
+// the thread-safe equivalents so four threads can each add to the total.
+// (Related demo: cargo run -- run send-sync)
+
+// I AM NOT DONE
+
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::thread;
+
+fn main() {
+    let total = Rc::new(RefCell::new(0));
+    let handles: Vec<_> = (1..=4)
+        .map(|n| {
+            let total = Rc::clone(&total);
+            thread::spawn(move || *total.borrow_mut() += n)
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+    assert_eq!(*total.borrow(), 10);
+}
@@ -0,0 +1,14 @@
+// Exercise 7 - closures that outlive the stack frame
+// The spawned thread may run after `main`'s locals are gone, so it can't
+// borrow `words`. Give the thread its own data (or use thread::scope).
+// (Related demo: cargo run -- run closures-and-moves)
+
+// I AM NOT DONE
+
+use std::thread;
+
+fn main() {
+    let words = vec!["go", "rust", "zig"];
+    let handle = thread::spawn(|| words.iter().map(|word| word.len()).sum::<usize>());
+    assert_eq!(handle.join().unwrap(), 9);
+}
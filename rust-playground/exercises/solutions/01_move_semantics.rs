@@ -0,0 +1,13 @@
+// Solution 1 - borrow instead of taking ownership (cloning would also work)
+
+fn greet(name: &str) -> String {
+    format!("hello, {}", name)
+}
+
+fn main() {
+    let name = String::from("gopher");
+    let first = greet(&name);
+    let second = greet(&name);
+    assert_eq!(first, second);
+    println!("{}", second);
+}
@@ -0,0 +1,10 @@
+// Solution 2 - finish with one &mut before taking the next
+
+fn main() {
+    let mut scores = vec![10, 20, 30];
+    let first = &mut scores[0];
+    *first += 1;
+    let last = scores.last_mut().unwrap();
+    *last += 1;
+    assert_eq!(scores, [11, 20, 31]);
+}
@@ -0,0 +1,9 @@
+// Solution 3 - return the String itself; ownership moves to the caller
+
+fn label(id: u32) -> String {
+    format!("item-{}", id)
+}
+
+fn main() {
+    assert_eq!(label(7), "item-7");
+}
@@ -0,0 +1,11 @@
+// Solution 4 - the result lives as long as the shorter of the two inputs
+
+fn longest<'a>(a: &'a str, b: &'a str) -> &'a str {
+    if a.len() >= b.len() { a } else { b }
+}
+
+fn main() {
+    let go = String::from("gopher");
+    let rust = String::from("ferris the crab");
+    assert_eq!(longest(&go, &rust), "ferris the crab");
+}
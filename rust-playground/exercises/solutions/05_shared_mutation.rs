@@ -0,0 +1,13 @@
+// Solution 5 - Rc for the owners, RefCell for checked mutation
+// (Rc<Cell<i32>> works too for a Copy value)
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+fn main() {
+    let counter = Rc::new(RefCell::new(0));
+    let other_owner = Rc::clone(&counter);
+    *counter.borrow_mut() += 1;
+    *other_owner.borrow_mut() += 1;
+    assert_eq!(*counter.borrow(), 2);
+}
@@ -0,0 +1,18 @@
+// Solution 6 - Arc for the owners, Mutex for the mutation
+
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+fn main() {
+    let total = Arc::new(Mutex::new(0));
+    let handles: Vec<_> = (1..=4)
+        .map(|n| {
+            let total = Arc::clone(&total);
+            thread::spawn(move || *total.lock().unwrap() += n)
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+    assert_eq!(*total.lock().unwrap(), 10);
+}
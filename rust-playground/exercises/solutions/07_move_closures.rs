@@ -0,0 +1,9 @@
+// Solution 7 - `move` hands the Vec to the thread
+
+use std::thread;
+
+fn main() {
+    let words = vec!["go", "rust", "zig"];
+    let handle = thread::spawn(move || words.iter().map(|word| word.len()).sum::<usize>());
+    assert_eq!(handle.join().unwrap(), 9);
+}
@@ -0,0 +1,356 @@
+// Actors - one thread owns the state, everyone else sends it commands
+// Go:   "share memory by communicating": a goroutine owns a map and loops
+//       over a chan of commands; a request carries its own reply chan.
+//       Nothing stops other code from touching that map too - the race
+//       detector finds it at run time, if a test happens to hit it.
+// Rust: the same loop over an mpsc Receiver, but the state is MOVED into
+//       the actor's thread. No Mutex, no Arc: after the move nothing else
+//       can name it, and the compiler enforces that. Callers hold a cheap
+//       Clone handle (a Sender); a request carries a sync_channel(1) for its
+//       one reply. When the last handle drops, the loop ends and join()
+//       hands the state back.
+
+use std::collections::HashMap;
+use std::sync::mpsc::{self, Receiver, Sender, SyncSender};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+use crate::alloc_stats;
+use crate::contention_bench::timed;
+use crate::registry::{Demo, Difficulty, Example, Tag};
+use crate::report::{DemoReport, SideBySideRow};
+
+pub const THREAD_COUNTS: [usize; 4] = [1, 2, 4, 8];
+/// Adds per benchmark run, split across the threads
+pub const OPS: usize = 200_000;
+/// Round trips per request/reply run - each one waits for the actor
+pub const REQUESTS: usize = 20_000;
+
+/// What the actor owns: plain fields, no Mutex, no Arc
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Tally {
+    pub total: u64,
+    pub by_key: HashMap<&'static str, u64>,
+}
+
+impl Tally {
+    pub fn add(&mut self, key: &'static str, amount: u64) {
+        self.total += amount;
+        *self.by_key.entry(key).or_default() += amount;
+    }
+}
+
+/// What a handle can ask the actor to do
+pub enum Command {
+    Add { key: &'static str, amount: u64 },
+    /// A request: the actor sends its answer back on `reply`
+    Get { key: &'static str, reply: SyncSender<u64> },
+    Total { reply: SyncSender<u64> },
+    /// Stop now, even while handles are still alive
+    Stop,
+}
+
+/// The caller's side of the actor - Clone it for every thread that needs it
+#[derive(Clone)]
+pub struct TallyHandle {
+    commands: Sender<Command>,
+}
+
+impl TallyHandle {
+    /// Fire and forget; false when the actor has stopped
+    pub fn add(&self, key: &'static str, amount: u64) -> bool {
+        self.commands.send(Command::Add { key, amount }).is_ok()
+    }
+
+    /// Round trip; None when the actor has stopped
+    pub fn get(&self, key: &'static str) -> Option<u64> {
+        self.request(|reply| Command::Get { key, reply })
+    }
+
+    pub fn total(&self) -> Option<u64> {
+        self.request(|reply| Command::Total { reply })
+    }
+
+    pub fn stop(&self) {
+        let _ = self.commands.send(Command::Stop);
+    }
+
+    // A fresh one-slot channel per request: Go's `reply chan int`
+    fn request(&self, command: impl FnOnce(SyncSender<u64>) -> Command) -> Option<u64> {
+        let (reply, answer) = mpsc::sync_channel(1);
+        self.commands.send(command(reply)).ok()?;
+        answer.recv().ok()  // Err if the actor stopped before answering
+    }
+}
+
+// The actor: the only code that ever touches `tally`
+fn run(mut tally: Tally, commands: Receiver<Command>) -> Tally {
+    for command in commands {
+        match command {
+            Command::Add { key, amount } => tally.add(key, amount),
+            Command::Get { key, reply } => {
+                let _ = reply.send(tally.by_key.get(key).copied().unwrap_or(0));  // The asker may have given up
+            }
+            Command::Total { reply } => {
+                let _ = reply.send(tally.total);
+            }
+            Command::Stop => break,
+        }
+    }
+    tally  // Every handle dropped (or Stop): the state goes back through join()
+}
+
+/// Move `tally` into a new actor thread
+pub fn spawn(tally: Tally) -> (TallyHandle, JoinHandle<Tally>) {
+    let (commands, inbox) = mpsc::channel();
+    let actor = thread::spawn(move || run(tally, inbox));
+    (TallyHandle { commands }, actor)
+}
+
+/// How the benchmark's counter is shared
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Strategy {
+    /// add() only, then one total() to wait for the actor to catch up
+    ActorSend,
+    /// get() after every add(): a round trip per operation
+    ActorRequest,
+    /// The same Tally behind Arc<Mutex>
+    Mutex,
+}
+
+impl Strategy {
+    pub const ALL: [Strategy; 3] = [Strategy::ActorSend, Strategy::ActorRequest, Strategy::Mutex];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Strategy::ActorSend => "actor: send",
+            Strategy::ActorRequest => "actor: send + reply",
+            Strategy::Mutex => "Arc<Mutex<Tally>>",
+        }
+    }
+}
+
+/// One cell of the benchmark table
+#[derive(Debug, Clone, Copy)]
+pub struct Measurement {
+    pub strategy: Strategy,
+    pub threads: usize,
+    pub ops: usize,
+    pub elapsed: Duration,
+    /// Final total - always equals `ops`
+    pub total: u64,
+}
+
+impl Measurement {
+    pub fn ns_per_op(&self) -> f64 {
+        self.elapsed.as_nanos() as f64 / self.ops as f64
+    }
+}
+
+/// `ops` adds (a round trip each for ActorRequest) split across `threads` threads
+pub fn bench(strategy: Strategy, threads: usize, ops: usize) -> Measurement {
+    let threads = threads.max(1);
+    let per_thread = ops / threads;
+    let ops = per_thread * threads;
+
+    let start = Instant::now();
+    let total = match strategy {
+        Strategy::ActorSend | Strategy::ActorRequest => {
+            let (handle, actor) = spawn(Tally::default());
+            let shared = handle.clone();
+            timed(threads, move || {
+                for _ in 0..per_thread {
+                    shared.add("ops", 1);
+                    if strategy == Strategy::ActorRequest {
+                        std::hint::black_box(shared.get("ops"));
+                    }
+                }
+            });
+            let total = handle.total().unwrap_or_default();  // Queued after every add: answered once they're applied
+            drop(handle);
+            actor.join().unwrap();
+            total
+        }
+        Strategy::Mutex => {
+            let tally = Arc::new(Mutex::new(Tally::default()));
+            let shared = Arc::clone(&tally);
+            timed(threads, move || {
+                for _ in 0..per_thread {
+                    shared.lock().unwrap().add("ops", 1);
+                }
+            });
+            tally.lock().unwrap().total
+        }
+    };
+    Measurement { strategy, threads, ops, elapsed: start.elapsed(), total }
+}
+
+// Move the state in, send from several threads, get it back at the end
+pub fn owned_state() -> DemoReport {
+    let mut report = DemoReport::new("An Actor Owns Its State - No Mutex Anywhere");
+
+    report.step("Spawn: the Tally moves into the actor's thread");
+    report.code("let (handle, actor) = actor_model::spawn(Tally::default());");
+    report.code("// inside: for command in inbox { match command { Add { key, amount } => tally.add(key, amount), ... } }");
+    report.code("tally.add(\"x\", 1);  // ❌ error[E0382]: borrow of moved value: `tally`");
+    let (handle, actor) = spawn(Tally::default());
+
+    report.step("Send: three threads, each with its own handle clone");
+    let workers: Vec<_> = ["apples", "pears", "plums"]
+        .into_iter()
+        .enumerate()
+        .map(|(index, key)| {
+            let handle = handle.clone();
+            thread::spawn(move || {
+                for _ in 0..=index {
+                    handle.add(key, 10);
+                }
+            })
+        })
+        .collect();
+    for worker in workers {
+        worker.join().unwrap();
+    }
+    report.text("Each add() moves a Command into the channel; only the actor ever calls Tally::add");
+    report.count("pears (asked with get)", handle.get("pears").unwrap_or_default() as usize);
+
+    report.step("Stop: the last handle drops, the loop ends, join() returns the Tally");
+    drop(handle);
+    let tally = actor.join().unwrap();
+    let mut keys: Vec<_> = tally.by_key.iter().collect();
+    keys.sort();
+    report.table(&["key", "count"], keys.into_iter().map(|(key, count)| vec![key.to_string(), count.to_string()]).collect());
+    report.count("total", tally.total as usize);
+
+    report.gap();
+    report.ok("The state was owned by one thread at a time: main, then the actor, then main again");
+    report.ok("Tally has no Mutex and needs no Sync - it never crosses threads while shared");
+    report.text("Only TallyHandle (a Sender) is shared, and Sender is Send + Clone");
+    report
+}
+
+// A request carries the channel its answer comes back on
+pub fn request_reply() -> DemoReport {
+    let mut report = DemoReport::new("Request/Reply - a One-Slot Channel per Question");
+
+    report.code("let (reply, answer) = mpsc::sync_channel(1);");
+    report.code("self.commands.send(Command::Get { key, reply }).ok()?;");
+    report.code("answer.recv().ok()  // Err if the actor stopped before answering");
+
+    let (handle, actor) = spawn(Tally::default());
+    handle.add("hits", 3);
+    report.text(format!("handle.get(\"hits\") = {:?}", handle.get("hits")));
+    report.text(format!("handle.get(\"misses\") = {:?}  (the actor answers 0, not an error)", handle.get("misses")));
+
+    let (_, stats) = alloc_stats::measure(|| {
+        for _ in 0..100 {
+            std::hint::black_box(handle.get("hits"));
+        }
+    });
+    match stats {
+        Some(stats) => report.text(format!("100 round trips: {} allocations - the reply channel is allocated per request", stats.allocations)),
+        None => report.text("(allocation counts per round trip need --features alloc-stats)"),
+    };
+
+    report.step("A stopped actor");
+    handle.stop();
+    let tally = actor.join().unwrap();
+    report.text(format!("after stop(): add() = {}, get() = {:?}", handle.add("hits", 1), handle.get("hits")));
+    report.count("hits the actor handed back", tally.by_key.get("hits").copied().unwrap_or_default() as usize);
+
+    report.gap();
+    report.ok("A dead actor is a closed channel: send() and recv() return Err, nothing blocks forever");
+    report.text("Go: a send to a goroutine that stopped reading blocks forever unless every caller also selects on a done channel");
+    report
+}
+
+// The same counter behind an actor and behind Arc<Mutex>
+pub fn vs_mutex() -> DemoReport {
+    let mut report = DemoReport::new("Actor vs Arc<Mutex> - ns per Operation");
+
+    report.count("adds per run", OPS);
+    report.count("round trips per send + reply run", REQUESTS);
+    if cfg!(debug_assertions) {
+        report.warn("Debug build - run with --release for real numbers");
+    }
+
+    let results: Vec<Measurement> = Strategy::ALL
+        .iter()
+        .flat_map(|&strategy| {
+            let ops = if strategy == Strategy::ActorRequest { REQUESTS } else { OPS };
+            THREAD_COUNTS.iter().map(move |&threads| bench(strategy, threads, ops))
+        })
+        .collect();
+    let mut header = vec!["Threads"];
+    header.extend(Strategy::ALL.iter().map(|strategy| strategy.name()));
+    let rows = THREAD_COUNTS
+        .iter()
+        .map(|&threads| {
+            let mut row = vec![threads.to_string()];
+            row.extend(Strategy::ALL.iter().map(|&strategy| {
+                results
+                    .iter()
+                    .find(|m| m.strategy == strategy && m.threads == threads)
+                    .map_or_else(String::new, |m| format!("{:.1}", m.ns_per_op()))
+            }));
+            row
+        })
+        .collect();
+    report.summary_table(&header, rows);
+
+    report.gap();
+    if results.iter().all(|m| m.total == m.ops as u64) {
+        report.ok("Every run counted exactly right - the actor loses nothing without a lock");
+    } else {
+        report.fail("A run lost adds");
+    }
+    report.text("A Mutex add is a lock and an unlock; an actor add is a channel send (and one allocation per block of messages)");
+    report.text("For a counter the Mutex usually wins: both are serial, and a lock is cheaper than a send");
+    report.text("The actor pays off when the work is long (I/O, many steps) - callers don't wait, and no lock is held across it");
+    report.warn("A reply per operation is a thread wake-up each way: batch requests, or use the Mutex, for tight loops");
+    report
+}
+
+pub fn actors_vs_go() -> DemoReport {
+    let mut report = DemoReport::new("Actors: Go vs Rust");
+
+    report.side_by_side(vec![
+        SideBySideRow::differs("type cmd struct { key string; reply chan int }", "enum Command { Get { key, reply: SyncSender<u64> }, ... }"),
+        SideBySideRow::differs("go func() { for c := range cmds { ... } }()", "thread::spawn(move || run(tally, inbox))"),
+        SideBySideRow::differs("counts[\"x\"]++  // outside the goroutine: compiles, races", "tally.add(\"x\", 1)  // ❌ E0382: moved into the actor"),
+        SideBySideRow::differs("close(cmds)  // one owner must close", "drop(handle)  // the last Sender to drop closes it"),
+        SideBySideRow::differs("state := <-done  // a second channel for the result", "let tally = actor.join().unwrap();"),
+    ]);
+
+    report.table(
+        &["", "Go", "Rust"],
+        vec![
+            vec!["who can touch the state".into(), "anyone with the variable (by convention: only the goroutine)".into(), "only the actor: it was moved in".into()],
+            vec!["caught at".into(), "go test -race, if exercised".into(), "compile time".into()],
+            vec!["actor cost".into(), "a goroutine (~2 KiB stack)".into(), "an OS thread (or a tokio task: see async-await)".into()],
+            vec!["reply".into(), "make(chan int, 1)".into(), "mpsc::sync_channel(1)".into()],
+        ],
+    );
+
+    report.gap();
+    report.ok("Same pattern, same channels - Rust turns \"only the goroutine touches it\" from a convention into a type error");
+    report
+}
+
+pub const DEMO: Demo = Demo {
+    name: "actor-model",
+    about: "Actors: a thread owns its state and takes commands over mpsc, replies on one-slot channels, no Mutex needed; benchmarked against Arc<Mutex>",
+    difficulty: Difficulty::Intermediate,
+    tags: &[Tag::Concurrency, Tag::Ownership, Tag::Benchmark],
+    examples: &[
+        Example { name: "owned-state", run: owned_state },
+        Example { name: "request-reply", run: request_reply },
+        Example { name: "vs-mutex", run: vs_mutex },
+        Example { name: "vs-go", run: actors_vs_go },
+    ],
+};
+
+pub fn demonstrate_actor_model() -> Vec<DemoReport> {
+    DEMO.run()
+}
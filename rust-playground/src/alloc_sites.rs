@@ -0,0 +1,169 @@
+// Allocation sites - a tiny heap profiler (enable with `--features alloc-sites`)
+// Like Go's heap profile: roughly one allocation per `sample_rate` bytes gets
+// a stack trace, and the bytes allocated since the previous sample are
+// charged to it (runtime.MemProfileRate, 512 KiB by default in Go). Here the
+// default is 1 - every allocation - because the demos are small and exact
+// numbers teach better. `run --alloc-sites` prints the top sites per demo.
+//
+// Only instruction pointers are captured inside the allocator; symbols are
+// resolved once per distinct stack after the demo finishes.
+
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+const DEPTH: usize = 32;
+pub const DEFAULT_SAMPLE_RATE: usize = 1;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static SAMPLING: AtomicBool = AtomicBool::new(false);
+static SAMPLE_RATE: AtomicUsize = AtomicUsize::new(DEFAULT_SAMPLE_RATE);
+static SAMPLES: Mutex<Vec<Sample>> = Mutex::new(Vec::new());
+
+thread_local! {
+    // Set while this thread is capturing: allocations made by the
+    // profiler itself are counted but never sampled (no recursion)
+    static IN_HOOK: Cell<bool> = const { Cell::new(false) };
+    // (bytes, allocations) since this thread's last sample
+    static UNSAMPLED: Cell<(usize, usize)> = const { Cell::new((0, 0)) };
+}
+
+struct Sample {
+    stack: [usize; DEPTH],
+    bytes: usize,
+    allocations: usize,
+}
+
+/// One line of the profile: where the memory was requested
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AllocSite {
+    /// First playground frame, e.g. "cow::normalize_username (cow.rs:24)"
+    pub location: String,
+    /// The playground frames above it, innermost first
+    pub callers: Vec<String>,
+    pub bytes: usize,
+    pub allocations: usize,
+}
+
+/// Turn profiling on for every `DemoReport::measured` run
+pub fn enable(sample_rate: usize) {
+    SAMPLE_RATE.store(sample_rate.max(1), Ordering::Relaxed);
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+pub fn enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+pub fn sample_rate() -> usize {
+    SAMPLE_RATE.load(Ordering::Relaxed)
+}
+
+// Called by the counting allocator for every allocation
+pub(crate) fn on_alloc(size: usize) {
+    if !SAMPLING.load(Ordering::Relaxed) {
+        return;
+    }
+    // try_with: the allocator may run while thread-locals are torn down
+    let _ = IN_HOOK.try_with(|in_hook| {
+        if in_hook.get() {
+            return;
+        }
+        let (bytes, allocations) = UNSAMPLED.get();
+        let (bytes, allocations) = (bytes + size, allocations + 1);
+        if bytes < sample_rate() {
+            UNSAMPLED.set((bytes, allocations));
+            return;
+        }
+        UNSAMPLED.set((0, 0));
+
+        in_hook.set(true);
+        let mut stack = [0; DEPTH];
+        let mut depth = 0;
+        backtrace::trace(|frame| {
+            stack[depth] = frame.ip() as usize;
+            depth += 1;
+            depth < DEPTH
+        });
+        if let Ok(mut samples) = SAMPLES.lock() {
+            samples.push(Sample { stack, bytes, allocations });
+        }
+        in_hook.set(false);
+    });
+}
+
+/// Run `f` and return the allocation sites it hit, heaviest first
+/// (None unless profiling was enabled)
+pub fn profile<R>(f: impl FnOnce() -> R) -> (R, Option<Vec<AllocSite>>) {
+    if !enabled() {
+        return (f(), None);
+    }
+
+    SAMPLES.lock().unwrap().clear();
+    UNSAMPLED.set((0, 0));
+    SAMPLING.store(true, Ordering::Relaxed);
+    let result = f();
+    SAMPLING.store(false, Ordering::Relaxed);
+
+    let samples = std::mem::take(&mut *SAMPLES.lock().unwrap());
+    (result, Some(aggregate(samples)))
+}
+
+// Resolve each distinct stack once, then merge stacks that share a site
+fn aggregate(samples: Vec<Sample>) -> Vec<AllocSite> {
+    let mut by_stack: HashMap<[usize; DEPTH], (usize, usize)> = HashMap::new();
+    for sample in samples {
+        let totals = by_stack.entry(sample.stack).or_default();
+        totals.0 += sample.bytes;
+        totals.1 += sample.allocations;
+    }
+
+    let mut symbols = HashMap::new();
+    let mut by_site: HashMap<(String, Vec<String>), (usize, usize)> = HashMap::new();
+    for (stack, (bytes, allocations)) in by_stack {
+        let mut frames = stack
+            .iter()
+            .take_while(|ip| **ip != 0)
+            .filter_map(|ip| symbols.entry(*ip).or_insert_with(|| resolve(*ip)).clone());
+        let location = frames.next().unwrap_or_else(|| "<outside the playground>".to_string());
+        let callers: Vec<String> = frames.take(2).collect();
+
+        let totals = by_site.entry((location, callers)).or_default();
+        totals.0 += bytes;
+        totals.1 += allocations;
+    }
+
+    let mut sites: Vec<AllocSite> = by_site
+        .into_iter()
+        .map(|((location, callers), (bytes, allocations))| AllocSite { location, callers, bytes, allocations })
+        .collect();
+    sites.sort_by(|a, b| b.bytes.cmp(&a.bytes).then_with(|| a.location.cmp(&b.location)));
+    sites
+}
+
+// Frames that are the profiler, the allocator or report plumbing, not the demo
+const SKIPPED: [&str; 4] = ["alloc_sites::", "alloc_stats::", "report::", "render::"];
+
+// "cow::normalize_username (cow.rs:24)", or None for
+// frames outside the playground (std, alloc, third-party crates)
+fn resolve(ip: usize) -> Option<String> {
+    let mut found = None;
+    backtrace::resolve(ip as *mut std::ffi::c_void, |symbol| {
+        if found.is_some() {
+            return;
+        }
+        let Some(name) = symbol.name() else { return };
+        let name = format!("{:#}", name);  // {:#} drops the ::h0123... hash
+        let Some(path) = name.trim_start_matches('<').strip_prefix("rust_playground::") else { return };
+        if SKIPPED.iter().any(|skip| path.starts_with(skip)) {
+            return;
+        }
+        let file = symbol.filename().and_then(|f| f.file_name()).map(|f| f.to_string_lossy().into_owned());
+        found = Some(match (file, symbol.lineno()) {
+            (Some(file), Some(line)) => format!("{} ({}:{})", path, file, line),
+            _ => path.to_string(),
+        });
+    });
+    found
+}
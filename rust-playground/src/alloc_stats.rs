@@ -0,0 +1,149 @@
+// Instrumented global allocator (enable with `--features alloc-stats`)
+// Wraps the allocator underneath and counts every allocation, so the
+// "no GC, deterministic cleanup" claims come with real numbers. Underneath
+// is the system allocator, or mimalloc / jemalloc with `--features mimalloc`
+// / `--features jemalloc` - those swap the global allocator with or without
+// the counters on top.
+//
+// Counters are process-wide: allocations made by other threads while a
+// demo runs are included too (the demos that spawn threads want that).
+
+use std::alloc::{GlobalAlloc, Layout};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static DEALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static BYTES_ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+static LIVE_BYTES: AtomicUsize = AtomicUsize::new(0);
+static PEAK_BYTES: AtomicUsize = AtomicUsize::new(0);
+
+/// The allocator every Box, Vec and String ends up in (mimalloc wins when
+/// both features are on, as with --all-features)
+#[cfg(feature = "mimalloc")]
+pub type Backend = mimalloc::MiMalloc;
+#[cfg(all(feature = "jemalloc", not(feature = "mimalloc")))]
+pub type Backend = tikv_jemallocator::Jemalloc;
+#[cfg(not(any(feature = "mimalloc", feature = "jemalloc")))]
+pub type Backend = std::alloc::System;
+
+#[cfg(feature = "mimalloc")]
+const BACKEND: Backend = mimalloc::MiMalloc;
+#[cfg(all(feature = "jemalloc", not(feature = "mimalloc")))]
+const BACKEND: Backend = tikv_jemallocator::Jemalloc;
+#[cfg(not(any(feature = "mimalloc", feature = "jemalloc")))]
+const BACKEND: Backend = std::alloc::System;
+
+/// Name of the allocator underneath: "system", "mimalloc" or "jemalloc"
+pub fn backend() -> &'static str {
+    if cfg!(feature = "mimalloc") {
+        "mimalloc"
+    } else if cfg!(feature = "jemalloc") {
+        "jemalloc"
+    } else {
+        "system"
+    }
+}
+
+/// The backend + counters
+pub struct CountingAllocator;
+
+#[cfg(feature = "alloc-stats")]
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+// No counters: the backend itself is the global allocator
+#[cfg(all(not(feature = "alloc-stats"), any(feature = "mimalloc", feature = "jemalloc")))]
+#[global_allocator]
+static GLOBAL: Backend = BACKEND;
+
+fn record_alloc(size: usize) {
+    ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+    BYTES_ALLOCATED.fetch_add(size, Ordering::Relaxed);
+    let live = LIVE_BYTES.fetch_add(size, Ordering::Relaxed) + size;
+    PEAK_BYTES.fetch_max(live, Ordering::Relaxed);
+    #[cfg(feature = "alloc-sites")]
+    crate::alloc_sites::on_alloc(size);
+}
+
+fn record_dealloc(size: usize) {
+    DEALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+    LIVE_BYTES.fetch_sub(size, Ordering::Relaxed);
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { BACKEND.alloc(layout) };
+        if !ptr.is_null() {
+            record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { BACKEND.alloc_zeroed(layout) };
+        if !ptr.is_null() {
+            record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { BACKEND.dealloc(ptr, layout) };
+        record_dealloc(layout.size());
+    }
+
+    // A realloc is counted as free(old) + alloc(new), like Go's heap profile
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = unsafe { BACKEND.realloc(ptr, layout, new_size) };
+        if !new_ptr.is_null() {
+            record_dealloc(layout.size());
+            record_alloc(new_size);
+        }
+        new_ptr
+    }
+}
+
+/// Heap activity observed while running a piece of code
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct AllocStats {
+    pub allocations: usize,
+    pub deallocations: usize,
+    pub bytes_allocated: usize,
+    /// Highest live heap size reached, above the level at the start
+    pub peak_bytes: usize,
+}
+
+/// Is the counting allocator installed in this build?
+pub fn enabled() -> bool {
+    cfg!(feature = "alloc-stats")
+}
+
+/// Bytes currently live on the heap (0 when the feature is off)
+pub fn live_bytes() -> usize {
+    LIVE_BYTES.load(Ordering::Relaxed)
+}
+
+/// Run `f` and report the heap activity it caused (None without the feature)
+pub fn measure<R>(f: impl FnOnce() -> R) -> (R, Option<AllocStats>) {
+    if !enabled() {
+        return (f(), None);
+    }
+
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let deallocations = DEALLOCATIONS.load(Ordering::Relaxed);
+    let bytes = BYTES_ALLOCATED.load(Ordering::Relaxed);
+    let baseline = LIVE_BYTES.load(Ordering::Relaxed);
+    // Nested inside another measure: keep the outer peak, restored below
+    let outer_peak = PEAK_BYTES.swap(baseline, Ordering::Relaxed);
+
+    let result = f();
+
+    let peak = PEAK_BYTES.fetch_max(outer_peak, Ordering::Relaxed);
+    let stats = AllocStats {
+        allocations: ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+        deallocations: DEALLOCATIONS.load(Ordering::Relaxed) - deallocations,
+        bytes_allocated: BYTES_ALLOCATED.load(Ordering::Relaxed) - bytes,
+        peak_bytes: peak.saturating_sub(baseline),
+    };
+    (result, Some(stats))
+}
@@ -0,0 +1,246 @@
+// Allocator backends - "no GC" still leaves the allocator as a knob
+// Go:   the runtime's allocator (size classes, per-P caches, TCMalloc-style)
+//       is part of the GC and can't be swapped. The knobs are GOGC and
+//       GOMEMLIMIT: when to collect, not how memory is handed out.
+// Rust: malloc/free go through one #[global_allocator]. The system's is the
+//       default; one line swaps in mimalloc or jemalloc, and every Box, Vec
+//       and String in the program - std's included - uses it. Here that line
+//       sits behind `--features mimalloc` / `--features jemalloc` (with
+//       alloc-stats, the counting allocator wraps whichever is chosen).
+//       The same workloads, run per backend, show what the choice changes:
+//
+//   cargo run --release -- compare-run allocator-backends --candidate mimalloc --release
+
+use std::hint::black_box;
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::alloc_stats;
+use crate::process_stats;
+use crate::registry::{Demo, Difficulty, Example, Tag};
+use crate::report::{format_bytes, DemoReport, SideBySideRow};
+
+/// Operations per workload
+pub const OPS: usize = 200_000;
+/// Producer threads in the cross-thread workload
+pub const THREADS: usize = 4;
+
+/// One allocation pattern: `run(ops)` does `ops` operations, returns a checksum
+pub struct Workload {
+    pub name: &'static str,
+    pub about: &'static str,
+    pub run: fn(usize) -> u64,
+}
+
+pub const WORKLOADS: &[Workload] = &[
+    Workload { name: "churn", about: "allocate and free one 32-byte Box per op (the thread cache's fast path)", run: churn },
+    Workload { name: "mixed-sizes", about: "keep a String of 8..520 bytes per op, then free them all", run: mixed_sizes },
+    Workload { name: "vec-growth", about: "push u64s into one Vec: the realloc path", run: vec_growth },
+    Workload { name: "cross-thread", about: "4 threads allocate, the main thread frees (remote frees)", run: cross_thread },
+];
+
+fn churn(ops: usize) -> u64 {
+    let mut sum = 0;
+    for i in 0..ops {
+        let boxed = black_box(Box::new([i as u64; 4]));
+        sum += boxed[0];
+    }
+    sum
+}
+
+fn mixed_sizes(ops: usize) -> u64 {
+    let strings: Vec<String> = (0..ops).map(|i| "x".repeat(8 + (i * 37) % 512)).collect();
+    strings.iter().map(|s| s.len() as u64).sum()
+}
+
+fn vec_growth(ops: usize) -> u64 {
+    let mut values = Vec::new();
+    for i in 0..ops * 5 {
+        values.push(i as u64);
+    }
+    black_box(&values).len() as u64
+}
+
+fn cross_thread(ops: usize) -> u64 {
+    let (sender, receiver) = mpsc::channel::<Vec<Box<[u8; 64]>>>();
+    thread::scope(|scope| {
+        for _ in 0..THREADS {
+            let sender = sender.clone();
+            scope.spawn(move || {
+                for _ in 0..ops / THREADS / 100 {
+                    let batch = (0..100).map(|i| Box::new([i as u8; 64])).collect();
+                    if sender.send(batch).is_err() {
+                        return;
+                    }
+                }
+            });
+        }
+        drop(sender);
+        receiver.iter().map(|batch| batch.len() as u64).sum()  // Each batch is freed here, not where it was allocated
+    })
+}
+
+/// A workload's time and (with alloc-stats) allocation count
+pub struct Run {
+    pub elapsed: Duration,
+    pub allocations: Option<usize>,
+}
+
+/// Run `workload` once for warm-up, then measured
+pub fn measure(workload: &Workload, ops: usize) -> Run {
+    black_box((workload.run)(ops / 10));
+    let start = Instant::now();
+    let (checksum, stats) = alloc_stats::measure(|| (workload.run)(black_box(ops)));
+    let elapsed = start.elapsed();
+    black_box(checksum);
+    Run { elapsed, allocations: stats.map(|stats| stats.allocations) }
+}
+
+// Which allocator this build uses, and how it got there
+pub fn active() -> DemoReport {
+    let mut report = DemoReport::new("Which Allocator Is This Build Using?");
+
+    report.text(format!("allocator: {}", alloc_stats::backend()));
+    report.text(format!("counting allocator on top: {}", if alloc_stats::enabled() { "yes (alloc-stats)" } else { "no" }));
+    report.code("#[global_allocator]");
+    report.code(match alloc_stats::backend() {
+        "mimalloc" => "static GLOBAL: mimalloc::MiMalloc = mimalloc::MiMalloc;",
+        "jemalloc" => "static GLOBAL: tikv_jemallocator::Jemalloc = tikv_jemallocator::Jemalloc;",
+        _ => "// none: std::alloc::System - glibc malloc, macOS libmalloc, Windows HeapAlloc",
+    });
+
+    report.table(
+        &["build with", "global allocator", "counted"],
+        vec![
+            vec!["(defaults)".into(), "System".into(), "no".into()],
+            vec!["--features alloc-stats".into(), "CountingAllocator → System".into(), "yes".into()],
+            vec!["--features mimalloc".into(), "MiMalloc".into(), "no".into()],
+            vec!["--features mimalloc,alloc-stats".into(), "CountingAllocator → MiMalloc".into(), "yes".into()],
+            vec!["--features jemalloc[,alloc-stats]".into(), "Jemalloc (counted with alloc-stats)".into(), "with alloc-stats".into()],
+        ],
+    );
+    report.text("One #[global_allocator] per program - the counting allocator forwards to whichever backend was picked");
+
+    report.gap();
+    report.ok("Allocation counts don't change with the backend - the program asks for the same blocks");
+    report.ok("Time and RSS do: size classes, thread caches and when pages go back to the OS differ");
+    report
+}
+
+// The same allocation patterns, timed on this build's allocator
+pub fn workloads() -> DemoReport {
+    let mut report = DemoReport::new(format!("Allocation Workloads on {}", alloc_stats::backend()));
+
+    let rows = WORKLOADS
+        .iter()
+        .map(|workload| {
+            let run = measure(workload, OPS);
+            vec![
+                workload.name.to_string(),
+                alloc_stats::backend().to_string(),
+                format!("{:.1?}", run.elapsed),
+                format!("{:.1}", run.elapsed.as_nanos() as f64 / OPS as f64),
+                run.allocations.map_or("n/a".to_string(), |count| count.to_string()),
+            ]
+        })
+        .collect();
+    report.summary_table(&["workload", "allocator", "time", "ns/op", "allocations"], rows);
+    for workload in WORKLOADS {
+        report.text(format!("{:<13} {}", workload.name, workload.about));
+    }
+    if !alloc_stats::enabled() {
+        report.text("(allocation counts need --features alloc-stats)");
+    }
+    if cfg!(debug_assertions) {
+        report.warn("Debug build - run with --release for allocator numbers worth comparing");
+    }
+
+    report.gap();
+    report.ok("Same program, same allocations - only the allocator changed between builds");
+    report.text("Compare two builds side by side: compare-run allocator-backends --candidate mimalloc --release");
+    report
+}
+
+// Freed isn't returned: what RSS does after the blocks are gone
+pub fn give_back() -> DemoReport {
+    let mut report = DemoReport::new(format!("After free(): What {} Gives Back", alloc_stats::backend()));
+
+    const BLOCKS: usize = 50_000;
+    let start = process_stats::rss();
+    let mut blocks: Vec<Box<[u8; 1024]>> = (0..BLOCKS).map(|i| Box::new([i as u8; 1024])).collect();
+    let full = process_stats::rss();
+    let mut index = 0;
+    blocks.retain(|_| {
+        index += 1;
+        index % 2 == 0
+    });
+    let half = process_stats::rss();
+    drop(blocks);
+    let freed = process_stats::rss();
+
+    report.text(format!("{} blocks of 1 KiB ({}), then every other one freed, then all", BLOCKS, format_bytes(BLOCKS * 1024)));
+    match (start, full, half, freed) {
+        (Some(start), Some(full), Some(half), Some(freed)) => {
+            let change = |rss: usize| process_stats::format_change(rss as isize - start as isize);
+            report.table(
+                &["after", "RSS change"],
+                vec![
+                    vec!["allocating all".into(), change(full)],
+                    vec!["freeing every other (holes)".into(), change(half)],
+                    vec!["freeing the rest".into(), change(freed)],
+                ],
+            )
+        }
+        _ => report.text("(RSS isn't available on this platform)"),
+    };
+    report.text("Run it on its own (-e give-back): pages freed by earlier examples get reused and hide the growth");
+
+    report.gap();
+    report.ok("Every block was freed at the drop - that part never depends on the allocator");
+    report.text("Whether the pages go back to the OS right away, later, or never is the allocator's policy");
+    report.text("glibc trims the top of its heap; mimalloc and jemalloc purge unused pages on their own timers");
+    report
+}
+
+pub fn allocators_vs_go() -> DemoReport {
+    let mut report = DemoReport::new("Allocator Choice: Go vs Rust");
+
+    report.side_by_side(vec![
+        SideBySideRow::differs("// the runtime's allocator, always", "#[global_allocator] static A: MiMalloc = MiMalloc;"),
+        SideBySideRow::differs("GOGC=200 ./app  // collect less often", "// no collector to tune"),
+        SideBySideRow::differs("GOMEMLIMIT=512MiB ./app  // collect harder near the limit", "// memory is freed at each drop already"),
+        SideBySideRow::differs("GODEBUG=madvdontneed=1  // return pages eagerly", "MIMALLOC_PURGE_DELAY=0 / MALLOC_CONF=dirty_decay_ms:0"),
+    ]);
+
+    report.table(
+        &["", "Go", "Rust"],
+        vec![
+            vec!["allocator".into(), "built into the runtime".into(), "System, or any #[global_allocator]".into()],
+            vec!["what you tune".into(), "GC pacing (GOGC, GOMEMLIMIT)".into(), "the allocator itself".into()],
+            vec!["per-thread caches".into(), "per-P mcache".into(), "mimalloc / jemalloc thread caches".into()],
+            vec!["cost of a free".into(), "deferred to a GC cycle".into(), "paid at the drop, by the chosen allocator".into()],
+        ],
+    );
+
+    report.gap();
+    report.ok("Go tunes when garbage is found; Rust has no garbage to find, so the knob is the allocator");
+    report
+}
+
+pub const DEMO: Demo = Demo {
+    name: "allocator-backends",
+    about: "Global allocator choice: system vs mimalloc vs jemalloc (--features), the same workloads per backend, RSS after free vs Go's GOGC/GOMEMLIMIT",
+    difficulty: Difficulty::Advanced,
+    tags: &[Tag::Allocation, Tag::Benchmark],
+    examples: &[
+        Example { name: "active", run: active },
+        Example { name: "workloads", run: workloads },
+        Example { name: "give-back", run: give_back },
+        Example { name: "vs-go", run: allocators_vs_go },
+    ],
+};
+
+pub fn demonstrate_allocator_backends() -> Vec<DemoReport> {
+    DEMO.run()
+}
@@ -0,0 +1,227 @@
+// Arena (bump) allocation - allocate many, free all at once
+// Box<T> per object: one malloc + one free each, freed one by one
+// Arena:            carve objects out of big chunks, free the chunks together
+// Go: every `&T{}` is a GC allocation (fast bump into a per-P span),
+//     freed later by a concurrent sweep - no way to say "all of these, now"
+//
+// Completes the "choose your tradeoff" story: Box (precise), Rc (shared),
+// arena (bulk lifetime), Go's GC (automatic).
+
+use std::cell::RefCell;
+use std::hint::black_box;
+use std::mem::size_of;
+use std::time::{Duration, Instant};
+
+use crate::alloc_stats::{self, AllocStats};
+use crate::registry::{Demo, Difficulty, Example, Tag};
+use crate::report::{format_bytes, DemoReport};
+use crate::workload;
+
+pub const NODE_COUNT: usize = 100_000;
+const FIRST_CHUNK: usize = 1024;
+
+/// A typed bump arena: values live until the arena itself is dropped
+pub struct BumpArena<T> {
+    chunks: RefCell<Vec<Vec<T>>>,
+}
+
+impl<T> BumpArena<T> {
+    pub fn new() -> Self {
+        BumpArena { chunks: RefCell::new(vec![Vec::with_capacity(FIRST_CHUNK)]) }
+    }
+
+    /// Move `value` into the arena; the reference lives as long as the arena
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc(&self, value: T) -> &mut T {
+        let mut chunks = self.chunks.borrow_mut();
+        let full = chunks.last().is_some_and(|chunk| chunk.len() == chunk.capacity());
+        if full {
+            let next = chunks.last().map_or(FIRST_CHUNK, |chunk| chunk.capacity() * 2);
+            chunks.push(Vec::with_capacity(next));  // Old chunks stay where they are
+        }
+
+        let chunk = chunks.last_mut().expect("arena always has a chunk");
+        let len = chunk.len();
+        // SAFETY: len < capacity, so the slot is in bounds and the chunk never
+        // reallocates; chunks are only freed when the whole arena is dropped,
+        // which the returned borrow of `self` prevents.
+        unsafe {
+            let slot = chunk.as_mut_ptr().add(len);
+            slot.write(value);
+            chunk.set_len(len + 1);
+            &mut *slot
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.chunks.borrow().iter().map(Vec::len).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn chunk_count(&self) -> usize {
+        self.chunks.borrow().len()
+    }
+}
+
+impl<T> Default for BumpArena<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Small object, like a tree/graph node
+#[derive(Debug)]
+struct Node<'a> {
+    value: u64,
+    next: Option<&'a Node<'a>>,
+}
+
+#[allow(dead_code)]
+struct Point {
+    x: u64,
+    y: u64,
+}
+
+// One allocation strategy, measured
+struct Run {
+    alloc: Duration,
+    free: Duration,
+    stats: Option<AllocStats>,
+}
+
+fn boxed_run(count: usize) -> Run {
+    let ((boxes, alloc), stats) = alloc_stats::measure(|| {
+        let start = Instant::now();
+        let boxes: Vec<Box<Point>> = (0..count as u64).map(|i| Box::new(Point { x: i, y: i })).collect();
+        (boxes, start.elapsed())
+    });
+    black_box(&boxes);
+
+    let start = Instant::now();
+    drop(boxes);  // `count` individual frees
+    Run { alloc, free: start.elapsed(), stats }
+}
+
+fn arena_run(count: usize) -> Run {
+    let ((arena, alloc), stats) = alloc_stats::measure(|| {
+        let arena = BumpArena::new();
+        let start = Instant::now();
+        for i in 0..count as u64 {
+            arena.alloc(Point { x: i, y: i });
+        }
+        (arena, start.elapsed())
+    });
+    black_box(&arena);
+
+    let start = Instant::now();
+    drop(arena);  // A handful of chunk frees
+    Run { alloc, free: start.elapsed(), stats }
+}
+
+fn alloc_count(stats: Option<AllocStats>) -> String {
+    stats.map_or("n/a".to_string(), |s| s.allocations.to_string())
+}
+
+// The arena itself: chunks that double, nodes that reference each other
+pub fn bump_arena_basics() -> DemoReport {
+    let mut report = DemoReport::new("A Bump Arena - Nodes Borrowing From the Arena");
+
+    let arena = BumpArena::new();
+    let mut head: Option<&Node> = None;
+    for value in 0..NODE_COUNT as u64 {
+        head = Some(arena.alloc(Node { value, next: head }));  // Nodes point at older nodes
+    }
+
+    let first = arena.alloc(Node { value: 0, next: None });
+    let second = arena.alloc(Node { value: 1, next: None });
+    report.address("node A", &*first, format!("value {}", first.value));
+    report.address("node B", &*second, format!("{} bytes later (size_of::<Node>())", size_of::<Node>()));
+
+    let mut sum = 0;
+    let mut cursor = head;
+    while let Some(node) = cursor {
+        sum += node.value;
+        cursor = node.next;
+    }
+    report.count("Nodes allocated", arena.len());
+    report.count("Chunks (1024, 2048, 4096, ...)", arena.chunk_count());
+    report.text(format!("Sum over the linked list: {}", sum));
+
+    report.gap();
+    report.ok("alloc() = write into the next free slot: a pointer bump");
+    report.ok("&'arena Node: the borrow checker ties every node to the arena");
+    report.ok("Dropping the arena frees all chunks - no per-node free, no recursion");
+    report.warn("Nothing is freed early: memory grows until the whole arena goes");
+    report
+}
+
+// Box per object vs arena, timed
+pub fn arena_vs_box() -> DemoReport {
+    let mut report = DemoReport::new("Box per Object vs Arena - Timed");
+
+    let count = workload::object_count_or(NODE_COUNT);  // --object-count
+    let boxed = boxed_run(count);
+    let arena = arena_run(count);
+
+    report.text(format!("{} objects of {} each", count, format_bytes(size_of::<Point>())));
+    report.gap();
+    report.table(
+        &["Strategy", "allocate", "free", "allocations"],
+        vec![
+            vec!["Box<T> each".into(), format!("{:?}", boxed.alloc), format!("{:?}", boxed.free), alloc_count(boxed.stats)],
+            vec!["BumpArena<T>".into(), format!("{:?}", arena.alloc), format!("{:?}", arena.free), alloc_count(arena.stats)],
+        ],
+    );
+    if !alloc_stats::enabled() {
+        report.text("(allocation counts need --features alloc-stats)");
+    }
+
+    report.gap();
+    report.ok("Arena: ~log2(n) mallocs instead of n, one bulk free");
+    report.ok("Objects packed contiguously → better cache locality when walking them");
+    report.text("Box is still right when objects die at different times");
+    report
+}
+
+pub fn arena_vs_go_gc() -> DemoReport {
+    let mut report = DemoReport::new("Arenas vs Go's GC Model");
+
+    report.step("Go");
+    report.code("for i := 0; i < n; i++ { nodes = append(nodes, &Node{Value: i}) }");
+    report.ok("Allocation is already a cheap bump into a per-P span");
+    report.warn("Freeing is the GC's job: mark everything reachable, sweep the rest");
+    report.warn("100k live nodes = 100k pointers the GC must trace every cycle");
+    report.text("GOEXPERIMENT=arenas existed, but was put on hold - not a stable API");
+
+    report.step("Rust");
+    report.table(
+        &["Tool", "Frees", "Good for"],
+        vec![
+            vec!["Box<T>".into(), "each value, at its owner's scope end".into(), "independent lifetimes".into()],
+            vec!["Rc<T>".into(), "when the last owner drops".into(), "shared, unclear owner".into()],
+            vec!["BumpArena<T>".into(), "everything, when the arena drops".into(), "parse trees, graphs, per-request data".into()],
+        ],
+    );
+    report.ok("Pick the tradeoff per data structure - no global collector");
+    report.text("Production crates: bumpalo (any type), typed-arena, slotmap (indices)");
+    report
+}
+
+pub const DEMO: Demo = Demo {
+    name: "arena-allocation",
+    about: "Arena/bump allocation vs Box per object vs Go's GC",
+    difficulty: Difficulty::Intermediate,
+    tags: &[Tag::Allocation, Tag::Benchmark],
+    examples: &[
+        Example { name: "basics", run: bump_arena_basics },
+        Example { name: "vs-box", run: arena_vs_box },
+        Example { name: "vs-go", run: arena_vs_go_gc },
+    ],
+};
+
+pub fn demonstrate_arena_allocation() -> Vec<DemoReport> {
+    DEMO.run()
+}
@@ -0,0 +1,240 @@
+// async/await with tokio vs goroutines (enable with `--features tokio`)
+// Go:   go f()            → runtime schedules a goroutine, always
+// Rust: async fn f()      → returns a Future; NOTHING runs until polled
+//       tokio::spawn(f()) → hands the future to an executor (≈ go f())
+
+use std::time::{Duration, Instant};
+
+use tokio::runtime::Runtime;
+use tokio::sync::mpsc;
+use tokio::time::sleep;
+
+use crate::registry::{Demo, Difficulty, Example, Tag};
+use crate::report::DemoReport;
+
+fn runtime() -> Runtime {
+    tokio::runtime::Builder::new_multi_thread()
+        .worker_threads(4)
+        .enable_time()
+        .build()
+        .expect("failed to build tokio runtime")
+}
+
+async fn fetch(id: u32, delay_ms: u64) -> String {
+    sleep(Duration::from_millis(delay_ms)).await;  // Yields to the executor, thread stays free
+    format!("response {}", id)
+}
+
+// async fn is lazy - a Future is just a value until someone polls it
+pub fn async_fn_example() -> DemoReport {
+    let mut report = DemoReport::new("async fn - Futures Are Lazy");
+
+    let rt = runtime();
+
+    let future = fetch(1, 10);  // Nothing has happened yet!
+    report.bytes("Future size (state machine)", std::mem::size_of_val(&future));
+    report.text("Created the future - no work done yet");
+
+    let response = rt.block_on(future);  // Now it runs
+    report.text(format!("block_on(future) → {:?}", response));
+
+    report.gap();
+    report.ok("Calling an async fn only builds a state machine");
+    report.ok("Work happens when it's .await-ed or spawned");
+    report.warn("Go has no equivalent: `go f()` always starts running f");
+    report
+}
+
+// tokio::spawn ≈ go f(), JoinHandle ≈ a channel you read the result from
+pub fn spawn_example() -> DemoReport {
+    let mut report = DemoReport::new("tokio::spawn - Like `go f()`");
+
+    let rt = runtime();
+
+    let (results, elapsed) = rt.block_on(async {
+        let start = Instant::now();
+        let handles: Vec<_> = (0..5)
+            .map(|id| tokio::spawn(fetch(id, 50)))  // Future MOVED into the task
+            .collect();
+
+        let mut results = vec![];
+        for handle in handles {
+            results.push(handle.await.unwrap());  // Like wg.Wait() + collecting results
+        }
+        (results, start.elapsed())
+    });
+
+    for result in &results {
+        report.text(format!("Got: {}", result));
+    }
+    report.count("Tasks spawned", results.len());
+    report.duration("Total time (5 × 50ms sleeps)", elapsed);
+
+    report.gap();
+    report.ok("Tasks ran concurrently: total ≈ 50ms, not 250ms");
+    report.ok("Spawned futures must be 'static + Send - they OWN their data");
+
+    report.step("Go");
+    report.code("var wg sync.WaitGroup");
+    report.code("for id := 0; id < 5; id++ { wg.Add(1); go func() { defer wg.Done(); fetch(id) }() }");
+    report.code("wg.Wait()");
+    report
+}
+
+// join! - await several futures concurrently in the SAME task
+pub fn join_example() -> DemoReport {
+    let mut report = DemoReport::new("tokio::join! - Concurrent Awaits");
+
+    let rt = runtime();
+
+    let ((a, b, c), elapsed) = rt.block_on(async {
+        let start = Instant::now();
+        let results = tokio::join!(fetch(1, 30), fetch(2, 60), fetch(3, 90));
+        (results, start.elapsed())
+    });
+
+    report.text(format!("a = {:?}, b = {:?}, c = {:?}", a, b, c));
+    report.duration("Total time (30 + 60 + 90ms)", elapsed);
+
+    report.gap();
+    report.ok("Time ≈ slowest (90ms), not the sum (180ms)");
+    report.ok("No spawn: futures may borrow local data (no 'static needed)");
+
+    report.step("Go");
+    report.code("g, ctx := errgroup.WithContext(ctx)");
+    report.code("g.Go(func() error { a = fetch(1) }) ... g.Wait()");
+    report.text("Needs goroutines + shared variables; Rust returns a tuple");
+    report
+}
+
+// select! - first ready branch wins, like Go's select
+pub fn select_example() -> DemoReport {
+    let mut report = DemoReport::new("tokio::select! - Like Go's select");
+
+    let rt = runtime();
+
+    let outcomes = rt.block_on(async {
+        let mut outcomes = vec![];
+
+        // Race a fast request against a timeout
+        tokio::select! {
+            response = fetch(1, 10) => outcomes.push(format!("fast request won: {}", response)),
+            _ = sleep(Duration::from_millis(100)) => outcomes.push("timeout won".to_string()),
+        }
+
+        // Race a slow request against a timeout
+        tokio::select! {
+            response = fetch(2, 200) => outcomes.push(format!("slow request won: {}", response)),
+            _ = sleep(Duration::from_millis(50)) => outcomes.push("timeout won (slow request dropped)".to_string()),
+        }
+        outcomes
+    });
+
+    for outcome in outcomes {
+        report.text(outcome);
+    }
+
+    report.gap();
+    report.ok("Losing branches are DROPPED - cancellation is just drop()");
+    report.warn("Dropped futures stop at their last .await (cancel-safety matters)");
+
+    report.step("Go");
+    report.code("select {");
+    report.code("case r := <-fetch(1):");
+    report.code("case <-time.After(100 * time.Millisecond):");
+    report.code("}");
+    report.text("The losing goroutine keeps running unless you cancel a context");
+    report
+}
+
+// Fan-out to workers, fan-in results over a channel
+pub fn fan_out_fan_in() -> DemoReport {
+    let mut report = DemoReport::new("Fan-Out / Fan-In with tokio::sync::mpsc");
+
+    let rt = runtime();
+
+    let (mut squares, elapsed) = rt.block_on(async {
+        let start = Instant::now();
+        let (tx, mut rx) = mpsc::channel(8);  // Bounded, like make(chan T, 8)
+
+        for worker in 0..4u64 {
+            let tx = tx.clone();  // Each worker owns a Sender
+            tokio::spawn(async move {
+                for n in (worker * 5)..(worker * 5 + 5) {
+                    sleep(Duration::from_millis(5)).await;
+                    tx.send((worker, n * n)).await.unwrap();
+                }
+            });
+        }
+        drop(tx);  // Close our copy so rx ends when workers finish
+
+        let mut squares = vec![];
+        while let Some((_worker, square)) = rx.recv().await {
+            squares.push(square);
+        }
+        (squares, start.elapsed())
+    });
+    squares.sort();
+
+    report.text(format!("Squares (fan-in, sorted): {:?}", squares));
+    report.count("Results received", squares.len());
+    report.duration("Total time", elapsed);
+
+    report.gap();
+    report.ok("Same shape as Go's pipeline pattern");
+    report.ok("Channel closes when every Sender is dropped (no close())");
+
+    report.step("Go");
+    report.code("out := make(chan int, 8)");
+    report.code("for w := 0; w < 4; w++ { go worker(w, out) }");
+    report.code("for sq := range out { ... }  // someone must close(out)");
+    report
+}
+
+pub fn async_vs_goroutines() -> DemoReport {
+    let mut report = DemoReport::new("Async Rust vs Goroutines");
+
+    report.step("Go");
+    report.text("- Every function can block; the runtime parks the goroutine");
+    report.text("- Growable stack per goroutine (starts ~2KB)");
+    report.text("- Preemptive scheduling built into the language");
+
+    report.step("Rust async");
+    report.text("- Only async fns can .await; blocking calls stall the executor thread");
+    report.text("- No stack per task: a state machine sized at compile-time");
+    report.text("- Cooperative: tasks yield at .await points");
+    report.text("- Runtime is a library (tokio), not part of the language");
+
+    report.step("Mapping");
+    report.table(
+        &["Go", "Rust (tokio)"],
+        vec![
+            vec!["go f()".into(), "tokio::spawn(f())".into()],
+            vec!["sync.WaitGroup".into(), "JoinHandle.await / join!".into()],
+            vec!["select { ... }".into(), "tokio::select! { ... }".into()],
+            vec!["time.After(d)".into(), "tokio::time::sleep(d)".into()],
+            vec!["make(chan T, n)".into(), "tokio::sync::mpsc::channel(n)".into()],
+            vec!["ctx.Done()".into(), "drop the future / CancellationToken".into()],
+        ],
+    );
+    report
+}
+
+pub const DEMO: Demo = Demo {
+    name: "async-await",
+    about: "async/await with tokio vs goroutines (needs --features tokio)",
+    difficulty: Difficulty::Intermediate,
+    tags: &[Tag::Concurrency],
+    examples: &[
+        Example { name: "async-fn", run: async_fn_example },
+        Example { name: "spawn", run: spawn_example },
+        Example { name: "join", run: join_example },
+        Example { name: "select", run: select_example },
+        Example { name: "fan-out-fan-in", run: fan_out_fan_in },
+        Example { name: "vs-go", run: async_vs_goroutines },
+    ],
+};
+
+pub fn demonstrate_async_await() -> Vec<DemoReport> {
+    DEMO.run()
+}
@@ -0,0 +1,179 @@
+// Atomics - Go's sync/atomic vs std::sync::atomic
+// Go:   atomic.AddInt64(&n, 1), atomic.Int64 - always sequentially consistent
+// Rust: AtomicUsize::fetch_add(1, Ordering::X) - you pick the ordering
+//       every time: Relaxed, Acquire, Release, AcqRel, SeqCst
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::thread;
+use std::time::Instant;
+
+use crate::registry::{Demo, Difficulty, Example, Tag};
+use crate::report::DemoReport;
+
+const THREADS: usize = 4;
+const INCREMENTS: usize = 100_000;
+
+// Lock-free counter shared by several threads
+pub fn atomic_counter() -> DemoReport {
+    let mut report = DemoReport::new("Lock-Free Counter - AtomicUsize::fetch_add");
+
+    let counter = Arc::new(AtomicUsize::new(0));
+    let start = Instant::now();
+    let handles: Vec<_> = (0..THREADS)
+        .map(|_| {
+            let counter = Arc::clone(&counter);
+            thread::spawn(move || {
+                for _ in 0..INCREMENTS {
+                    counter.fetch_add(1, Ordering::Relaxed);  // Only the count matters
+                }
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    report.count("Expected", THREADS * INCREMENTS);
+    report.count("Counted", counter.load(Ordering::Relaxed));
+    report.duration("Time", start.elapsed());
+
+    report.gap();
+    report.ok("No lock, no lost updates: fetch_add is one indivisible read-modify-write");
+    report.ok("Relaxed is enough - nothing else is published through the counter");
+    report.text("`counter += 1` on a plain usize won't compile across threads");
+
+    report.step("Go");
+    report.code("var n atomic.Int64");
+    report.code("go func() { n.Add(1) }()");
+    report.text("Same idea; Go always uses the strongest ordering (seq-cst)");
+    report
+}
+
+// Release/Acquire: publish data through a flag
+pub fn acquire_release() -> DemoReport {
+    let mut report = DemoReport::new("AtomicBool Flag - Release/Acquire Publishing");
+
+    let data = Arc::new(AtomicUsize::new(0));
+    let ready = Arc::new(AtomicBool::new(false));
+
+    let producer = {
+        let data = Arc::clone(&data);
+        let ready = Arc::clone(&ready);
+        thread::spawn(move || {
+            data.store(42, Ordering::Relaxed);
+            ready.store(true, Ordering::Release);  // Everything before this is published
+        })
+    };
+
+    let mut spins = 0;
+    while !ready.load(Ordering::Acquire) {  // Pairs with the Release store
+        spins += 1;
+        std::hint::spin_loop();
+    }
+    producer.join().unwrap();
+
+    report.count("data seen after ready == true", data.load(Ordering::Relaxed));
+    report.count("spins while waiting", spins);
+
+    report.gap();
+    report.ok("Release store + Acquire load = happens-before edge");
+    report.ok("Once the flag reads true, data == 42 is guaranteed");
+    report.warn("With Relaxed on the flag, the consumer could see ready=true, data=0");
+
+    report.step("Go");
+    report.code("data = 42");
+    report.code("ready.Store(true)     // seq-cst: implies release");
+    report.code("for !ready.Load() {}  // seq-cst: implies acquire");
+    report.text("Go's memory model gives you this pairing without asking");
+    report
+}
+
+// compare_exchange: the building block of lock-free updates
+pub fn compare_exchange_example() -> DemoReport {
+    let mut report = DemoReport::new("compare_exchange - CAS Loops");
+
+    // Track a running maximum from several threads
+    let max = Arc::new(AtomicUsize::new(0));
+    let retries = Arc::new(AtomicUsize::new(0));
+    let handles: Vec<_> = (0..THREADS)
+        .map(|t| {
+            let max = Arc::clone(&max);
+            let retries = Arc::clone(&retries);
+            thread::spawn(move || {
+                for i in 0..1_000 {
+                    let candidate = i * THREADS + t;
+                    let mut current = max.load(Ordering::Relaxed);
+                    while candidate > current {
+                        match max.compare_exchange(current, candidate, Ordering::AcqRel, Ordering::Relaxed) {
+                            Ok(_) => break,
+                            Err(actual) => {
+                                retries.fetch_add(1, Ordering::Relaxed);
+                                current = actual;  // Someone else won - retry with their value
+                            }
+                        }
+                    }
+                }
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    report.count("Final max", max.load(Ordering::Relaxed));
+    report.count("Expected", 999 * THREADS + THREADS - 1);
+    report.count("CAS retries (lost races)", retries.load(Ordering::Relaxed));
+
+    report.gap();
+    report.ok("compare_exchange(expected, new) succeeds only if nobody changed it");
+    report.ok("Err(actual) hands back the current value for the retry");
+    report.text("fetch_max does this in one call; the loop shows the general pattern");
+
+    report.step("Go");
+    report.code("for {");
+    report.code("    cur := max.Load()");
+    report.code("    if cand <= cur || max.CompareAndSwap(cur, cand) { break }");
+    report.code("}");
+    report
+}
+
+// The orderings, and what Go gives you implicitly
+pub fn orderings() -> DemoReport {
+    let mut report = DemoReport::new("Memory Orderings - Rust vs Go");
+
+    report.table(
+        &["Ordering", "Guarantees", "Typical use"],
+        vec![
+            vec!["Relaxed".into(), "atomicity only".into(), "counters, statistics".into()],
+            vec!["Release".into(), "earlier writes visible to Acquire".into(), "publish / unlock".into()],
+            vec!["Acquire".into(), "sees writes before the Release".into(), "consume / lock".into()],
+            vec!["AcqRel".into(), "both, for read-modify-write".into(), "CAS on shared state".into()],
+            vec!["SeqCst".into(), "one global order for all SeqCst ops".into(), "when in doubt".into()],
+        ],
+    );
+
+    report.gap();
+    report.ok("Go's sync/atomic: every operation behaves like SeqCst");
+    report.ok("Rust makes the choice explicit - weaker orderings are cheaper on ARM");
+    report.warn("Too-weak orderings are a logic bug the compiler can't catch");
+    report.text("Safe default: SeqCst; weaken only with a reason written down");
+    report
+}
+
+pub const DEMO: Demo = Demo {
+    name: "atomics",
+    about: "Atomics - sync/atomic vs std::sync::atomic",
+    difficulty: Difficulty::Advanced,
+    tags: &[Tag::Concurrency],
+    examples: &[
+        Example { name: "counter", run: atomic_counter },
+        Example { name: "acquire-release", run: acquire_release },
+        Example { name: "compare-exchange", run: compare_exchange_example },
+        Example { name: "orderings", run: orderings },
+    ],
+};
+
+pub fn demonstrate_atomics() -> Vec<DemoReport> {
+    DEMO.run()
+}
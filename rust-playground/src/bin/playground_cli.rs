@@ -0,0 +1,85 @@
+// The demos for workshop machines: shell completions and a doctor.
+// Same registry as the main binary, so completions know every demo and
+// example this build has; `doctor` says which features are compiled in.
+//
+//   cargo install --path . --bin playground-cli
+//   playground-cli completions bash > ~/.local/share/bash-completion/completions/playground-cli
+//   playground-cli doctor
+//   playground-cli run rc-weak -e weak
+
+use std::io::IsTerminal;
+
+use clap::builder::PossibleValuesParser;
+use clap::{Arg, ArgAction, ArgMatches, Command};
+use clap_complete::Shell;
+use rust_playground::doctor;
+use rust_playground::registry::DemoRegistry;
+use rust_playground::render::{Colored, Plain, Renderer};
+use rust_playground::report::DemoReport;
+
+// Built with the builder API: `run` gets one subcommand per registered demo
+fn cli(registry: &DemoRegistry) -> Command {
+    let run = registry.demos().iter().fold(
+        Command::new("run").about("Run one demo (every example unless -e is given)").subcommand_required(true),
+        |run, demo| {
+            run.subcommand(
+                Command::new(demo.name).about(demo.about).arg(
+                    Arg::new("examples")
+                        .short('e')
+                        .long("example")
+                        .value_name("EXAMPLE")
+                        .help("Only run these examples (repeatable)")
+                        .action(ArgAction::Append)
+                        .value_parser(PossibleValuesParser::new(demo.examples.iter().map(|example| example.name))),
+                ),
+            )
+        },
+    );
+    Command::new("playground-cli")
+        .version(env!("CARGO_PKG_VERSION"))
+        .about("Rust vs Go memory model demos, with shell completions and a setup check")
+        .subcommand_required(true)
+        .subcommand(Command::new("list").about("List every demo and its examples"))
+        .subcommand(run)
+        .subcommand(Command::new("doctor").about("Check which optional features and tools this build has"))
+        .subcommand(
+            Command::new("completions")
+                .about("Print a shell completion script (bash, zsh, fish, ...)")
+                .arg(Arg::new("shell").required(true).value_parser(clap::value_parser!(Shell))),
+        )
+}
+
+fn renderer() -> Box<dyn Renderer> {
+    let no_color = std::env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty());
+    if std::io::stdout().is_terminal() && !no_color { Box::new(Colored) } else { Box::new(Plain) }
+}
+
+fn run(registry: &DemoRegistry, matches: &ArgMatches) -> Vec<DemoReport> {
+    let (name, args) = matches.subcommand().expect("subcommand_required");
+    let demo = registry.get(name).expect("only registered demos are subcommands");
+    let examples: Vec<&String> = args.get_many::<String>("examples").into_iter().flatten().collect();
+    if examples.is_empty() {
+        return demo.run();
+    }
+    demo.run_examples(examples)
+}
+
+fn main() {
+    let registry = DemoRegistry::builtin();
+    let matches = cli(&registry).get_matches();
+    match matches.subcommand() {
+        Some(("list", _)) => {
+            for demo in registry.demos() {
+                let examples: Vec<&str> = demo.examples.iter().map(|example| example.name).collect();
+                println!("{:<28} {}", demo.name, examples.join(", "));
+            }
+        }
+        Some(("run", args)) => print!("{}", renderer().render_all(&run(&registry, args))),
+        Some(("doctor", _)) => print!("{}", renderer().render(&doctor::diagnose())),
+        Some(("completions", args)) => {
+            let shell = *args.get_one::<Shell>("shell").expect("required");
+            clap_complete::generate(shell, &mut cli(&registry), "playground-cli", &mut std::io::stdout());
+        }
+        _ => unreachable!("subcommand_required"),
+    }
+}
@@ -0,0 +1,53 @@
+// Rust vs Go on the same workloads: runs both programs, prints one table
+// (see src/xlang_bench.rs for the workloads and the line format).
+//
+//   cargo run --release --features alloc-stats --bin xlang_bench
+//   cargo run --release --bin xlang_bench -- --rust-only
+
+use std::path::PathBuf;
+
+use clap::Parser;
+use rust_playground::render::{Plain, Renderer};
+use rust_playground::xlang_bench::{self, Sample};
+
+#[derive(Parser)]
+#[command(name = "xlang_bench", about = "Run the Rust and Go versions of the same workloads and compare them")]
+struct Cli {
+    /// Where golang-playground (with xlang/main.go) lives
+    #[arg(long, value_name = "DIR", default_value = concat!(env!("CARGO_MANIFEST_DIR"), "/../golang-playground"))]
+    go_dir: PathBuf,
+    /// Skip Go (no Go toolchain installed)
+    #[arg(long)]
+    rust_only: bool,
+    /// Run the Rust workloads in this process and print the raw sample lines
+    #[arg(long, hide = true)]
+    emit: bool,
+}
+
+fn main() {
+    let cli = Cli::parse();
+    if cli.emit {
+        print!("{}", xlang_bench::emit());
+        return;
+    }
+
+    // Each language gets a fresh process: no heap or warm caches left over from the other
+    eprintln!("==> Rust workloads...");
+    let exe = std::env::current_exe().expect("the running binary has a path");
+    let rust = collect("Rust", xlang_bench::run_rust(&exe));
+    let go = if cli.rust_only {
+        Vec::new()
+    } else {
+        eprintln!("==> Go workloads (go run ./xlang in {})...", cli.go_dir.display());
+        collect("Go", xlang_bench::run_go(&cli.go_dir))
+    };
+    print!("{}", Plain.render(&xlang_bench::comparison(&rust, &go)));
+}
+
+// A language that didn't run leaves its columns as "-"
+fn collect(language: &str, samples: Result<Vec<Sample>, xlang_bench::RunError>) -> Vec<Sample> {
+    samples.unwrap_or_else(|error| {
+        eprintln!("{}: {}", language, error);
+        Vec::new()
+    })
+}
@@ -1,6 +1,9 @@
 // This file demonstrates Rust's borrowing rules
 // These are COMPILE-TIME checks - no runtime cost!
 
+use crate::registry::{Demo, Difficulty, Example, Tag};
+use crate::report::DemoReport;
+
 #[allow(dead_code)]
 #[derive(Debug)]
 struct Data {
@@ -8,86 +11,99 @@ struct Data {
 }
 
 // Rule 1: You can have EITHER multiple immutable refs OR one mutable ref
-#[allow(dead_code)]
-fn borrowing_rules() {
+pub fn borrowing_rules() -> DemoReport {
+    let mut report = DemoReport::new("Rule 1: Multiple immutable OR one mutable");
+
     let mut data = Data { value: 42 };
-    
+
     // ✓ Multiple immutable borrows OK
     let r1 = &data;
     let r2 = &data;
-    println!("r1: {}, r2: {}", r1.value, r2.value);
-    
+    report.text(format!("r1: {}, r2: {}", r1.value, r2.value));
+
     // ✓ Mutable borrow OK (after immutable borrows done)
     let r3 = &mut data;
     r3.value = 100;
-    println!("r3: {}", r3.value);
-    
+    report.text(format!("r3: {}", r3.value));
+
     // ❌ This would fail: can't have immutable and mutable at same time
+    //    (tests/compile_fail/shared_and_mutable_borrow.rs)
     // let r4 = &data;
     // let r5 = &mut data;  // Error!
+    report
 }
 
 // Rule 2: References must not outlive the data they refer to
-#[allow(dead_code)]
-fn no_dangling_references() {
+pub fn no_dangling_references() -> DemoReport {
+    let mut report = DemoReport::new("Rule 2: No dangling references (enforced at compile-time)");
+
     let _reference: &Data;
-    
+
     {
         let _data = Data { value: 42 };
         // ❌ This would fail: reference would outlive data
+        //    (tests/compile_fail/dangling_reference.rs)
         // _reference = &_data;  // Error: borrowed value does not live long enough
     }
-    
+
     // If we could compile above, reference would be dangling here!
     // println!("{:?}", _reference);  // Would be undefined behavior in C/Go
+
+    report.ok("Compiler prevents dangling pointers");
+    report
 }
 
 // Rule 3: Moves prevent use-after-move bugs
-#[allow(dead_code)]
-fn move_semantics() {
+pub fn move_semantics() -> DemoReport {
+    let mut report = DemoReport::new("Rule 3: Move semantics prevent use-after-move");
+
     let data1 = Data { value: 42 };
     let data2 = data1;  // Ownership moves to data2
-    
+
     // ❌ This would fail: can't use data1 after move
     // println!("{}", data1.value);  // Error: value borrowed after move
-    
-    println!("{}", data2.value);  // ✓ OK
+
+    report.text(format!("{}", data2.value));  // ✓ OK
+    report
 }
 
 // Rule 4: Interior mutability with RefCell (runtime checks)
-#[allow(dead_code)]
-fn interior_mutability_example() {
+pub fn interior_mutability_example() -> DemoReport {
     use std::cell::RefCell;
-    
+
+    let mut report = DemoReport::new("Rule 4: RefCell for runtime-checked borrowing");
+
     let data = RefCell::new(Data { value: 42 });
-    
+
     // Multiple borrows through RefCell
     let borrow1 = data.borrow();
     let borrow2 = data.borrow();
-    println!("borrow1: {}, borrow2: {}", borrow1.value, borrow2.value);
+    report.text(format!("borrow1: {}, borrow2: {}", borrow1.value, borrow2.value));
     drop(borrow1);
     drop(borrow2);
-    
+
     // Mutable borrow
     let mut borrow_mut = data.borrow_mut();
     borrow_mut.value = 100;
-    println!("After mutation: {}", borrow_mut.value);
+    report.text(format!("After mutation: {}", borrow_mut.value));
+    drop(borrow_mut);
+    report
 }
 
 // Demonstrate the key rules
-pub fn demonstrate_borrow_checker() {
-    println!("\n=== Borrow Checker Rules ===\n");
-    
-    println!("Rule 1: Multiple immutable OR one mutable");
-    borrowing_rules();
-    
-    println!("\nRule 2: No dangling references (enforced at compile-time)");
-    println!("  ✓ Compiler prevents dangling pointers");
-    
-    println!("\nRule 3: Move semantics prevent use-after-move");
-    move_semantics();
-    
-    println!("\nRule 4: RefCell for runtime-checked borrowing");
-    interior_mutability_example();
-}
+pub const DEMO: Demo = Demo {
+    name: "borrow-checker",
+    about: "Borrow checker rules explained",
+    difficulty: Difficulty::Beginner,
+    tags: &[Tag::Borrowing, Tag::Ownership],
+    examples: &[
+        Example { name: "borrowing-rules", run: borrowing_rules },
+        Example { name: "no-dangling", run: no_dangling_references },
+        Example { name: "move-semantics", run: move_semantics },
+        Example { name: "interior-mutability", run: interior_mutability_example },
+    ],
+};
 
+pub fn demonstrate_borrow_checker() -> Vec<DemoReport> {
+    DEMO.run()
+}
@@ -0,0 +1,288 @@
+// Errors on the heap - Box<dyn Error> vs enums
+// Go:   `error` is an interface. Returning a *MyError or fmt.Errorf(...)
+//       puts the value behind an interface, which means a heap allocation
+//       per failure (sentinels like io.EOF are allocated once, up front).
+// Rust: a Result<T, E> carries E by value. An enum error costs nothing
+//       extra - it lives in the Result on the stack. Box<dyn Error> is the
+//       Go-style choice: any error type, one allocation each time, and the
+//       type is only recoverable by downcasting. anyhow::Error is that Box
+//       plus context, made convenient.
+
+use std::error::Error;
+use std::fmt;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use crate::alloc_stats;
+use crate::registry::{Demo, Difficulty, Example, Tag};
+use crate::report::DemoReport;
+
+const FAILURES: usize = 10_000;
+
+/// What can go wrong parsing a port number
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PortError {
+    Empty,
+    InvalidDigit { at: usize },
+    TooLarge,
+}
+
+impl fmt::Display for PortError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PortError::Empty => write!(f, "empty port"),
+            PortError::InvalidDigit { at } => write!(f, "invalid digit at position {}", at),
+            PortError::TooLarge => write!(f, "port above 65535"),
+        }
+    }
+}
+
+impl Error for PortError {}
+
+// The error by value: no allocation on either path
+fn parse_port(text: &str) -> Result<u16, PortError> {
+    if text.is_empty() {
+        return Err(PortError::Empty);
+    }
+    let mut port: u32 = 0;
+    for (at, byte) in text.bytes().enumerate() {
+        if !byte.is_ascii_digit() {
+            return Err(PortError::InvalidDigit { at });
+        }
+        port = port * 10 + (byte - b'0') as u32;
+        if port > u16::MAX as u32 {
+            return Err(PortError::TooLarge);
+        }
+    }
+    Ok(port as u16)
+}
+
+// Same error, boxed: `?` calls From<PortError> for Box<dyn Error>, which allocates
+fn parse_port_boxed(text: &str) -> Result<u16, Box<dyn Error + Send + Sync>> {
+    Ok(parse_port(text)?)
+}
+
+// A message instead of a type: a String, then a Box around it
+fn parse_port_message(text: &str) -> Result<u16, Box<dyn Error + Send + Sync>> {
+    parse_port(text).map_err(|err| format!("bad port {:?}: {}", text, err).into())
+}
+
+/// A hand-rolled anyhow::Error: any error, plus the context added on the way up
+pub struct AnyError {
+    source: Box<dyn Error + Send + Sync>,
+    context: Vec<String>,
+}
+
+// Like anyhow, AnyError is deliberately not an Error itself - that's what
+// lets this blanket From exist without overlapping From<T> for T
+impl<E: Error + Send + Sync + 'static> From<E> for AnyError {
+    fn from(err: E) -> Self {
+        AnyError { source: Box::new(err), context: Vec::new() }
+    }
+}
+
+impl AnyError {
+    /// The original error, if it is an `E`
+    pub fn downcast_ref<E: Error + 'static>(&self) -> Option<&E> {
+        self.source.downcast_ref::<E>()
+    }
+}
+
+impl fmt::Display for AnyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for context in self.context.iter().rev() {
+            write!(f, "{}: ", context)?;
+        }
+        write!(f, "{}", self.source)
+    }
+}
+
+/// `.context("...")` on any Result, like anyhow::Context
+pub trait Context<T> {
+    fn context(self, context: &str) -> Result<T, AnyError>;
+}
+
+impl<T, E: Into<AnyError>> Context<T> for Result<T, E> {
+    fn context(self, context: &str) -> Result<T, AnyError> {
+        self.map_err(|err| {
+            let mut err = err.into();
+            err.context.push(context.to_string());
+            err
+        })
+    }
+}
+
+fn load_port(text: &str) -> Result<u16, AnyError> {
+    parse_port(text).context("parsing `port`")
+}
+
+fn load_config(text: &str) -> Result<u16, AnyError> {
+    load_port(text).context("loading server.toml")
+}
+
+// Box<dyn Error>: one type for every error, at the price of a Box
+pub fn boxed_errors() -> DemoReport {
+    let mut report = DemoReport::new("Box<dyn Error> - Any Error, Behind a Pointer");
+
+    report.code("fn parse_port(s: &str) -> Result<u16, PortError>");
+    report.code("fn parse_port_boxed(s: &str) -> Result<u16, Box<dyn Error + Send + Sync>> { Ok(parse_port(s)?) }");
+    report.table(
+        &["Type", "size_of"],
+        vec![
+            vec!["PortError".into(), size_of::<PortError>().to_string()],
+            vec!["Result<u16, PortError>".into(), size_of::<Result<u16, PortError>>().to_string()],
+            vec!["Box<dyn Error + Send + Sync>".into(), size_of::<Box<dyn Error + Send + Sync>>().to_string()],
+            vec!["Result<u16, Box<dyn Error>>".into(), size_of::<Result<u16, Box<dyn Error>>>().to_string()],
+        ],
+    );
+    report.text("The boxed Result is a fat pointer (data + vtable), the enum one is the enum");
+
+    report.step("What the caller can do with each");
+    match parse_port("80x0") {
+        Err(PortError::InvalidDigit { at }) => report.text(format!("enum: match PortError::InvalidDigit {{ at: {} }} directly", at)),
+        other => report.fail(format!("unexpected {:?}", other)),
+    };
+    if let Err(err) = parse_port_boxed("80x0") {
+        report.text(format!("boxed: prints as {:?}", err.to_string()));
+        let recovered = err.downcast_ref::<PortError>();
+        report.text(format!("boxed: err.downcast_ref::<PortError>() = {:?}", recovered));
+    }
+
+    report.gap();
+    report.ok("`?` boxes any E: Error automatically - mix io, parse and your own errors freely");
+    report.warn("The signature no longer says which errors can happen; matching needs a downcast");
+    report.text("Rule of thumb: enums in libraries, Box<dyn Error> / anyhow in applications");
+
+    report.step("Go");
+    report.code("func parsePort(s string) (uint16, error)  // always the interface: Go's Box<dyn Error>");
+    report.code("var perr *PortError; errors.As(err, &perr)  // Go's downcast");
+    report
+}
+
+// anyhow-style context without the crate
+pub fn anyhow_style() -> DemoReport {
+    let mut report = DemoReport::new("anyhow-Style Errors, Hand-Rolled");
+
+    report.code("struct AnyError { source: Box<dyn Error + Send + Sync>, context: Vec<String> }");
+    report.code("impl<E: Error + Send + Sync + 'static> From<E> for AnyError { ... }");
+    report.code("fn load_config(s: &str) -> Result<u16, AnyError> {");
+    report.code("    load_port(s).context(\"loading server.toml\")  // load_port adds \"parsing `port`\"");
+    report.code("}");
+
+    match load_config("80x0") {
+        Ok(port) => report.fail(format!("parsed {}", port)),
+        Err(err) => {
+            report.text(format!("error: {}", err));
+            report.text(format!("downcast_ref::<PortError>() = {:?}", err.downcast_ref::<PortError>()));
+            report.bytes("size_of::<AnyError>()", size_of::<AnyError>())
+        }
+    };
+
+    report.gap();
+    report.ok("The message reads top-down like Go's wrapped errors, and the original is still there");
+    report.text("The real anyhow::Error is one pointer wide: the context lives in the same heap block");
+    report.warn("Every .context() on the error path is another String - still nothing on the Ok path");
+
+    report.step("Go");
+    report.code("return 0, fmt.Errorf(\"loading server.toml: %w\", err)");
+    report.text("%w wraps, errors.Unwrap / errors.Is walk the chain - the same design, built in");
+    report
+}
+
+fn cost_of(parse: impl Fn(&str) -> bool) -> (Option<usize>, Duration) {
+    let inputs = ["80x0", "", "99999", "8o"];
+    let start = Instant::now();
+    let (_, stats) = alloc_stats::measure(|| {
+        for i in 0..FAILURES {
+            black_box(parse(black_box(inputs[i % inputs.len()])));
+        }
+    });
+    (stats.map(|stats| stats.allocations), start.elapsed())
+}
+
+// Allocations per failure for each error style
+pub fn allocation_cost() -> DemoReport {
+    let mut report = DemoReport::new("The Price of a Failure - Allocations per Error");
+
+    report.count("failing parses per row", FAILURES);
+    let rows = [
+        ("Result<u16, PortError>", cost_of(|s| parse_port(s).is_err())),
+        ("Result<u16, Box<dyn Error>>", cost_of(|s| parse_port_boxed(s).is_err())),
+        ("Box<dyn Error> from format!()", cost_of(|s| parse_port_message(s).is_err())),
+        ("AnyError + 2 × .context()", cost_of(|s| load_config(s).is_err())),
+    ];
+    report.table(
+        &["Error style", "Allocations / error", "ns / error"],
+        rows.iter()
+            .map(|(style, (allocations, elapsed))| {
+                vec![
+                    style.to_string(),
+                    allocations.map_or("n/a".to_string(), |n| format!("{:.1}", n as f64 / FAILURES as f64)),
+                    format!("{:.0}", elapsed.as_nanos() as f64 / FAILURES as f64),
+                ]
+            })
+            .collect(),
+    );
+    if !alloc_stats::enabled() {
+        report.text("(allocation counts need --features alloc-stats)");
+    }
+
+    let (_, ok_allocations) = alloc_stats::measure(|| {
+        for _ in 0..FAILURES {
+            black_box(load_config(black_box("8080")).is_ok());
+        }
+    });
+    if let Some(stats) = ok_allocations {
+        report.count("allocations for 10,000 successful load_config() calls", stats.allocations);
+    }
+
+    report.gap();
+    report.ok("The enum fails for free: the error is just a different variant of the return value");
+    report.ok("Every style is free on the Ok path - boxing only happens once something went wrong");
+    report.text("Errors are usually rare; Box them for convenience unless failure is a hot path (parsers, lookups)");
+
+    report.step("Go");
+    report.code("return 0, &PortError{At: i}       // escapes to the heap: 1 alloc");
+    report.code("return 0, fmt.Errorf(\"bad port %q: %w\", s, err)  // 2+ allocs");
+    report.code("return 0, ErrEmpty                // sentinel, allocated once at init: 0");
+    report.text("go test -bench . -benchmem shows the same pattern in allocs/op");
+    report
+}
+
+pub fn errors_vs_go() -> DemoReport {
+    let mut report = DemoReport::new("Error Values: Go vs Rust");
+
+    report.table(
+        &["", "Go", "Rust"],
+        vec![
+            vec!["error type".into(), "interface error".into(), "any E in Result<T, E>".into()],
+            vec!["typed error".into(), "*MyError behind the interface".into(), "enum, by value".into()],
+            vec!["\"any error\"".into(), "error (the default)".into(), "Box<dyn Error> (opt-in)".into()],
+            vec!["cost per failure".into(), "usually 1+ allocation".into(), "0 (enum) or 1+ (Box)".into()],
+            vec!["add context".into(), "fmt.Errorf(\"...: %w\", err)".into(), ".context(\"...\") / map_err".into()],
+            vec!["inspect".into(), "errors.Is / errors.As".into(), "match, or downcast_ref".into()],
+        ],
+    );
+
+    report.gap();
+    report.ok("Rust lets you choose per API: exact and free, or flexible and boxed");
+    report.text("Go chose flexible everywhere - one reason error paths show up in Go heap profiles");
+    report
+}
+
+pub const DEMO: Demo = Demo {
+    name: "box-dyn-error",
+    about: "Box<dyn Error>, hand-rolled anyhow and allocations per error vs Go's error interface",
+    difficulty: Difficulty::Intermediate,
+    tags: &[Tag::Allocation, Tag::Layout],
+    examples: &[
+        Example { name: "boxed", run: boxed_errors },
+        Example { name: "anyhow-style", run: anyhow_style },
+        Example { name: "cost", run: allocation_cost },
+        Example { name: "vs-go", run: errors_vs_go },
+    ],
+};
+
+pub fn demonstrate_box_dyn_error() -> Vec<DemoReport> {
+    DEMO.run()
+}
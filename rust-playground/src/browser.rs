@@ -0,0 +1,192 @@
+// Browser build - the playground compiled to wasm32-unknown-unknown
+// That target has no threads (thread::spawn fails), no clock (Instant::now
+// panics), no filesystem and no unwinding (a panic aborts the whole module).
+// Most demos never touch any of these; the ones below do, so in the browser they
+// render a short "run this one natively" report instead of taking the page
+// down. Everything else runs exactly as it does under `cargo run`.
+//
+// Build (see web/index.html for the page that loads it):
+//   cargo build --lib --release --no-default-features --target wasm32-unknown-unknown
+//   wasm-bindgen --target web --out-dir web/pkg target/wasm32-unknown-unknown/release/rust_playground.wasm
+
+use std::fmt;
+
+use crate::registry::{Demo, Example};
+use crate::render::{json_string, Json, Plain, Renderer};
+use crate::report::DemoReport;
+use crate::UnknownDemo;
+
+/// What an example needs that wasm32-unknown-unknown doesn't have
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Needs {
+    Threads,
+    Clock,
+    Filesystem,
+    Unwinding,
+}
+
+impl Needs {
+    pub fn name(self) -> &'static str {
+        match self {
+            Needs::Threads => "threads",
+            Needs::Clock => "a clock",
+            Needs::Filesystem => "a filesystem",
+            Needs::Unwinding => "panic unwinding",
+        }
+    }
+}
+
+impl fmt::Display for Needs {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Native-only demos ("demo") and examples ("demo::example")
+pub const NATIVE_ONLY: &[(&str, Needs)] = &[
+    ("actor-model", Needs::Threads),
+    ("allocator-backends::workloads", Needs::Threads),
+    ("arena-allocation::vs-box", Needs::Clock),
+    ("async-await", Needs::Threads),
+    ("atomics", Needs::Threads),
+    ("box-dyn-error::cost", Needs::Clock),
+    ("cancellation", Needs::Threads),
+    ("channels", Needs::Threads),
+    ("condvar", Needs::Threads),
+    ("contention-bench", Needs::Threads),
+    ("deadlock", Needs::Threads),
+    ("defer-vs-drop::early-returns", Needs::Clock),
+    ("defer-vs-drop::defer-in-loop", Needs::Filesystem),
+    ("defer-vs-drop::panic", Needs::Unwinding),
+    ("dining-philosophers", Needs::Threads),
+    ("drop-glue-and-needs-drop::clear-cost", Needs::Clock),
+    ("enum-memory::state-machine", Needs::Clock),
+    ("error-handling::unwrap", Needs::Unwinding),
+    ("ffi-with-go", Needs::Threads),
+    ("generics-monomorphization::benchmark", Needs::Clock),
+    ("globals::once-lock", Needs::Threads),
+    ("globals::lazy-lock", Needs::Clock),
+    ("globals::thread-local", Needs::Threads),
+    ("globals::mutable-static", Needs::Threads),
+    ("crossbeam", Needs::Threads),
+    ("goroutines-vs-threads", Needs::Threads),
+    ("interior-mutability-zoo::hierarchy", Needs::Threads),
+    ("interior-mutability-zoo::cache", Needs::Threads),
+    ("interior-mutability-zoo::benchmark", Needs::Clock),
+    ("iterator-adapters::benchmark", Needs::Clock),
+    ("latency", Needs::Clock),
+    ("memory-ordering", Needs::Threads),
+    ("mpsc-pipeline", Needs::Threads),
+    ("object-pool::thread-local", Needs::Threads),
+    ("object-pool::benchmark", Needs::Clock),
+    ("parking-lot", Needs::Threads),
+    ("raii-resources::files", Needs::Filesystem),
+    ("rc-weak::arc-mutex", Needs::Threads),
+    ("rwlock", Needs::Threads),
+    ("scoped-threads", Needs::Threads),
+    ("send-sync", Needs::Threads),
+    ("smart-pointers::walkthrough", Needs::Threads),
+    ("static-lifetime-and-leak::box-leak", Needs::Threads),
+    ("static-lifetime-and-leak::when-to-leak", Needs::Clock),
+    ("string-concat-bench::strategies", Needs::Clock),
+    ("stress", Needs::Threads),
+    ("string-encoding::bytes", Needs::Unwinding),
+    ("sync-primitives", Needs::Threads),
+    ("thread-local-storage", Needs::Threads),
+    ("traits-vs-interfaces::dispatch", Needs::Clock),
+    ("two-owners-compile-fail-gallery::threads-async", Needs::Threads),
+    ("vecdeque-and-collections-memory::ring-buffer", Needs::Clock),
+    ("vecdeque-and-collections-memory::locality", Needs::Clock),
+];
+
+/// Why `demo::example` can't run in the browser, if it can't
+pub fn blocked(demo: &Demo, example: &Example) -> Option<Needs> {
+    let path = format!("{}::{}", demo.name, example.name);
+    NATIVE_ONLY.iter().find(|(blocked, _)| *blocked == demo.name || *blocked == path).map(|(_, needs)| *needs)
+}
+
+// Stands in for an example the browser can't run
+fn native_only(demo: &Demo, example: &Example, needs: Needs) -> DemoReport {
+    let mut report = DemoReport::new(format!("{}::{} - Native Only", demo.name, example.name));
+    report.warn(format!("This example needs {}, which the browser build doesn't have", needs));
+    report.text("Run it on your machine instead:");
+    report.code(format!("cargo run -- run {} -e {}", demo.name, example.name));
+    report
+}
+
+/// [`crate::run`] for the browser: blocked examples become a short report
+pub fn run(path: &str) -> Result<Vec<DemoReport>, UnknownDemo> {
+    let (demo, example) = crate::find(path)?;
+    let examples = match example {
+        Some(example) => std::slice::from_ref(example),
+        None => demo.examples,
+    };
+    Ok(examples
+        .iter()
+        .map(|example| match blocked(demo, example) {
+            Some(needs) => native_only(demo, example, needs),
+            None => DemoReport::measured(example.run),
+        })
+        .collect())
+}
+
+/// Every demo with its examples, and which of them the browser can run
+pub fn catalogue_json() -> String {
+    let demos: Vec<String> = crate::demos()
+        .iter()
+        .map(|demo| {
+            let tags: Vec<String> = demo.tags.iter().map(|tag| json_string(tag.name())).collect();
+            let examples: Vec<String> = demo
+                .examples
+                .iter()
+                .map(|example| {
+                    let needs = blocked(demo, example).map_or("null".to_string(), |needs| json_string(needs.name()));
+                    format!("{{\"name\":{},\"needs\":{}}}", json_string(example.name), needs)
+                })
+                .collect();
+            format!(
+                "{{\"name\":{},\"about\":{},\"difficulty\":{},\"tags\":[{}],\"examples\":[{}]}}",
+                json_string(demo.name),
+                json_string(demo.about),
+                json_string(demo.difficulty.name()),
+                tags.join(","),
+                examples.join(",")
+            )
+        })
+        .collect();
+    format!("[{}]", demos.join(","))
+}
+
+/// `path`'s reports as a JSON array (see [`Json`])
+pub fn run_json(path: &str) -> Result<String, UnknownDemo> {
+    run(path).map(|reports| Json.render_all(&reports))
+}
+
+/// `path`'s reports as the plain text the CLI prints
+pub fn run_text(path: &str) -> Result<String, UnknownDemo> {
+    run(path).map(|reports| Plain.render_all(&reports))
+}
+
+// What JavaScript sees: demos(), runJson(path) and runText(path)
+#[cfg(target_arch = "wasm32")]
+mod bindings {
+    use wasm_bindgen::prelude::*;
+
+    /// The catalogue as JSON: [{name, about, difficulty, tags, examples: [{name, needs}]}]
+    #[wasm_bindgen]
+    pub fn demos() -> String {
+        super::catalogue_json()
+    }
+
+    /// Run "demo" or "demo::example"; the reports as a JSON array
+    #[wasm_bindgen(js_name = runJson)]
+    pub fn run_json(path: &str) -> Result<String, JsError> {
+        super::run_json(path).map_err(|error| JsError::new(&error.to_string()))
+    }
+
+    /// Run "demo" or "demo::example"; the reports as plain text
+    #[wasm_bindgen(js_name = runText)]
+    pub fn run_text(path: &str) -> Result<String, JsError> {
+        super::run_text(path).map_err(|error| JsError::new(&error.to_string()))
+    }
+}
@@ -0,0 +1,288 @@
+// Builders - how ownership shapes an API
+// Go:   `func (b *Builder) Header(k, v string) *Builder` - every builder is a
+//       pointer receiver returning the same pointer. Chaining works, reuse
+//       works, and every variable holding the builder sees every change.
+// Rust: the signature decides. A consuming builder (`fn header(self, ..) ->
+//       Self`) moves itself through the chain: one owner at a time, build()
+//       moves the fields into the result for free, but the builder is gone
+//       afterwards. A mutating builder (`fn header(&mut self, ..) -> &mut Self`)
+//       stays put: it can be reused as a template and configured in if
+//       statements, but build() can only borrow - so it clones - and a chain
+//       can't start from a temporary you keep.
+
+use crate::alloc_stats;
+use crate::registry::{Demo, Difficulty, Example, Tag};
+use crate::report::DemoReport;
+
+/// What both builders produce
+#[derive(Debug, Clone, PartialEq)]
+pub struct Request {
+    pub method: &'static str,
+    pub url: String,
+    pub headers: Vec<(String, String)>,
+    pub body: Option<String>,
+}
+
+/// Consuming builder: every setter takes `self` and hands it back
+#[derive(Debug)]
+pub struct RequestBuilder {
+    method: &'static str,
+    url: String,
+    headers: Vec<(String, String)>,
+    body: Option<String>,
+}
+
+impl RequestBuilder {
+    pub fn new(url: impl Into<String>) -> Self {
+        RequestBuilder { method: "GET", url: url.into(), headers: Vec::new(), body: None }
+    }
+
+    pub fn method(mut self, method: &'static str) -> Self {
+        self.method = method;
+        self
+    }
+
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    pub fn body(mut self, body: impl Into<String>) -> Self {
+        self.body = Some(body.into());
+        self
+    }
+
+    /// Moves every field into the Request - no clones
+    pub fn build(self) -> Request {
+        let RequestBuilder { method, url, headers, body } = self;
+        Request { method, url, headers, body }
+    }
+}
+
+/// Mutating builder: setters take `&mut self`, build() only borrows
+#[derive(Debug, Clone)]
+pub struct RequestTemplate {
+    method: &'static str,
+    url: String,
+    headers: Vec<(String, String)>,
+    body: Option<String>,
+}
+
+impl RequestTemplate {
+    pub fn new(url: impl Into<String>) -> Self {
+        RequestTemplate { method: "GET", url: url.into(), headers: Vec::new(), body: None }
+    }
+
+    pub fn method(&mut self, method: &'static str) -> &mut Self {
+        self.method = method;
+        self
+    }
+
+    pub fn header(&mut self, name: impl Into<String>, value: impl Into<String>) -> &mut Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    pub fn body(&mut self, body: impl Into<String>) -> &mut Self {
+        self.body = Some(body.into());
+        self
+    }
+
+    /// The builder stays usable, so everything it owns is cloned
+    pub fn build(&self) -> Request {
+        Request { method: self.method, url: self.url.clone(), headers: self.headers.clone(), body: self.body.clone() }
+    }
+}
+
+fn allocations_of<R>(f: impl FnOnce() -> R) -> (R, String) {
+    let (result, stats) = alloc_stats::measure(f);
+    (result, stats.map_or("n/a (needs --features alloc-stats)".to_string(), |stats| stats.allocations.to_string()))
+}
+
+// The same request built both ways
+pub fn two_builders() -> DemoReport {
+    let mut report = DemoReport::new("Two Builders - self vs &mut self");
+
+    report.code("fn header(self, ..) -> Self          // consuming: the builder moves through the chain");
+    report.code("fn header(&mut self, ..) -> &mut Self  // mutating: the chain borrows one builder");
+
+    report.step("Consuming");
+    report.code("let request = RequestBuilder::new(url).method(\"POST\").header(\"Accept\", \"json\").body(\"{}\").build();");
+    let consumed = RequestBuilder::new("https://api.example.com/users").method("POST").header("Accept", "application/json").body("{}").build();
+    report.text(format!("{:?}", consumed));
+
+    report.step("Mutating");
+    report.code("let request = RequestTemplate::new(url).method(\"POST\").header(\"Accept\", \"json\").body(\"{}\").build();");
+    let mutated = RequestTemplate::new("https://api.example.com/users").method("POST").header("Accept", "application/json").body("{}").build();
+    report.text(format!("same request: {}", consumed == mutated));
+
+    report.gap();
+    report.ok("Both chain in one expression - the difference is what happens around the chain");
+    report.text("The mutating one works here because the temporary lives until the end of the statement");
+    report
+}
+
+// What build() costs: moving the fields out vs cloning them
+pub fn build_cost() -> DemoReport {
+    let mut report = DemoReport::new("build() - Move the Fields or Clone Them");
+
+    let headers = [("Accept", "application/json"), ("Authorization", "Bearer abc"), ("User-Agent", "playground")];
+    let mut consuming = RequestBuilder::new("https://api.example.com/users").body("{\"name\":\"gopher\"}");
+    let mut template = RequestTemplate::new("https://api.example.com/users");
+    template.body("{\"name\":\"gopher\"}");
+    for (name, value) in headers {
+        consuming = consuming.header(name, value);
+        template.header(name, value);
+    }
+
+    let (consumed, consumed_allocations) = allocations_of(|| consuming.build());
+    let (built, built_allocations) = allocations_of(|| template.build());
+    report.table(
+        &["builder", "build()", "allocations in build()"],
+        vec![
+            vec!["RequestBuilder".into(), "fn build(self): destructure, move fields".into(), consumed_allocations],
+            vec!["RequestTemplate".into(), "fn build(&self): clone url, headers, body".into(), built_allocations],
+        ],
+    );
+    report.text(format!("same request: {}", consumed == built));
+
+    report.gap();
+    report.ok("build(self) owns the builder, so its Strings and Vec move into the Request untouched");
+    report.warn("build(&self) can't move out of a borrow - url, 3 header pairs, the Vec and the body are all copied");
+    report.text("A mutating builder that's only used once can take instead: mem::take(&mut self.headers)");
+    report
+}
+
+// Conditional configuration and templates: where each one is awkward
+pub fn reuse_and_conditionals() -> DemoReport {
+    let mut report = DemoReport::new("Conditionals and Reuse");
+
+    let authenticated = true;
+    report.step("Configuring in an if - consuming");
+    report.code("let mut builder = RequestBuilder::new(url);");
+    report.code("if authenticated { builder = builder.header(\"Authorization\", token); }  // move out, move back in");
+    let mut builder = RequestBuilder::new("https://api.example.com/me");
+    if authenticated {
+        builder = builder.header("Authorization", "Bearer abc");
+    }
+    let request = builder.build();
+    report.text(format!("headers: {:?}", request.headers));
+
+    report.step("Configuring in an if - mutating");
+    report.code("let mut template = RequestTemplate::new(url);");
+    report.code("if authenticated { template.header(\"Authorization\", token); }  // no rebinding");
+    let mut template = RequestTemplate::new("https://api.example.com/me");
+    if authenticated {
+        template.header("Authorization", "Bearer abc");
+    }
+    report.text(format!("headers: {:?}", template.build().headers));
+
+    report.step("One template, many requests");
+    template.method("GET");
+    let requests: Vec<Request> = ["/me", "/me/orders", "/me/settings"]
+        .iter()
+        .map(|path| {
+            let mut request = template.build();
+            request.url.push_str(path.trim_start_matches("/me"));
+            request
+        })
+        .collect();
+    for request in &requests {
+        report.text(format!("{} {} ({} header)", request.method, request.url, request.headers.len()));
+    }
+    report.code("let request = builder.build();");
+    report.code("let again = builder.build();  // ❌ error[E0382]: use of moved value: `builder`");
+    report.text("Checked by tests/compile_fail/builder_used_after_build.rs - the consuming builder is single-use;");
+    report.text("derive Clone and call builder.clone().build() to reuse it, paying for the copy visibly");
+
+    report.step("Keeping a chain's result");
+    report.code("let template = RequestTemplate::new(url).header(\"Accept\", \"json\");  // &mut to a temporary");
+    report.code("template.build();  // ❌ error[E0716]: temporary value dropped while borrowed");
+    report.text("Checked by tests/compile_fail/builder_temporary_dropped.rs");
+    report.text("The chain returns &mut RequestTemplate pointing into a temporary that dies at the `;`");
+    report.code("let mut template = RequestTemplate::new(url);  // fix: own it first,");
+    report.code("template.header(\"Accept\", \"json\");            // then configure through &mut");
+
+    report.gap();
+    report.ok("Consuming: one-shot values (requests, commands, configs) - the chain reads well, build() is free");
+    report.ok("Mutating: templates and loops - reuse and ifs are natural, build() clones");
+    report
+}
+
+// build(self) and partial moves
+pub fn partial_moves() -> DemoReport {
+    let mut report = DemoReport::new("Taking a Request Apart");
+
+    report.code("let RequestBuilder { method, url, headers, body } = self;  // inside build(self)");
+    report.text("Destructuring a value you own moves each field out separately - that's how build() avoids clones");
+
+    report.step("Moving some fields, keeping others");
+    let request = RequestBuilder::new("https://api.example.com/upload").method("PUT").header("Content-Type", "text/plain").body("hello").build();
+    report.code("let body = request.body;          // moves one field out");
+    report.code("println!(\"{}\", request.url);      // ✓ the other fields are still whole");
+    report.code("send(request);                    // ❌ error[E0382]: use of partially moved value: `request`");
+    let body = request.body;
+    report.text(format!("body = {:?}, url = {:?}, headers = {}", body, request.url, request.headers.len()));
+    report.text("Checked by tests/compile_fail/request_partially_moved.rs");
+
+    report.gap();
+    report.ok("Owning the builder means owning its fields - take them one by one, no Option juggling");
+    report.text("With &mut self you'd need mem::take per field (see mem-swap-take-replace)");
+    report
+}
+
+// Go's pointer-receiver builder and what aliasing does to it
+pub fn builder_vs_go() -> DemoReport {
+    let mut report = DemoReport::new("Builders: Go vs Rust");
+
+    report.step("Go");
+    report.code("func (b *Builder) Header(k, v string) *Builder { b.headers = append(b.headers, ...); return b }");
+    report.code("base := NewBuilder(url).Header(\"Accept\", \"json\")");
+    report.code("admin := base.Header(\"X-Admin\", \"1\")  // admin == base: the SAME builder");
+    report.code("user := base.Build()                // carries X-Admin too");
+    report.text("Every builder variable is a pointer; \"deriving\" one from another mutates both");
+
+    report.step("Rust");
+    let base = RequestTemplate::new("https://api.example.com").header("Accept", "json").clone();
+    let mut admin = base.clone();
+    admin.header("X-Admin", "1");
+    report.code("let mut admin = base.clone();  // an explicit copy");
+    report.code("admin.header(\"X-Admin\", \"1\");");
+    report.text(format!("base.build().headers  = {:?}", base.build().headers));
+    report.text(format!("admin.build().headers = {:?}", admin.build().headers));
+    report.code("let admin = base.header(\"X-Admin\", \"1\");  // keeps &mut base alive: base is locked while admin lives");
+
+    report.table(
+        &["", "Go", "Rust consuming", "Rust mutating"],
+        vec![
+            vec!["setter".into(), "func (b *B) X() *B".into(), "fn x(self) -> Self".into(), "fn x(&mut self) -> &mut Self".into()],
+            vec!["reuse after build".into(), "yes, shared state".into(), "no (moved) - clone first".into(), "yes".into()],
+            vec!["two variables, one builder".into(), "silently".into(), "impossible".into(), "only one &mut at a time".into()],
+            vec!["build() copies".into(), "slices alias the builder's".into(), "nothing".into(), "everything it owns".into()],
+            vec!["configure in if".into(), "b.X()".into(), "b = b.x()".into(), "b.x()".into()],
+        ],
+    );
+
+    report.gap();
+    report.ok("Go's builder is always the mutating kind, minus the rule that only one place may hold it");
+    report.warn("Go's Build() copying b.headers by slice header shares its array with the builder - a later Header() may write into a built request");
+    report
+}
+
+pub const DEMO: Demo = Demo {
+    name: "builder-and-ownership",
+    about: "API design with ownership: consuming (self) vs mutating (&mut self) builders, build() costs, reuse",
+    difficulty: Difficulty::Intermediate,
+    tags: &[Tag::Ownership, Tag::Borrowing],
+    examples: &[
+        Example { name: "two-builders", run: two_builders },
+        Example { name: "build-cost", run: build_cost },
+        Example { name: "reuse", run: reuse_and_conditionals },
+        Example { name: "partial-moves", run: partial_moves },
+        Example { name: "vs-go", run: builder_vs_go },
+    ],
+};
+
+pub fn demonstrate_builder_and_ownership() -> Vec<DemoReport> {
+    DEMO.run()
+}
@@ -0,0 +1,392 @@
+// Timeouts and cancellation - Go's context.Context in Rust
+// Go:   ctx, cancel := context.WithCancel(parent); defer cancel(). Every
+//       blocking call takes ctx as its first argument and selects on
+//       ctx.Done(). The runtime can't kill a goroutine either: it has to look.
+// Rust: no context in std, and no convention to pass one. Whoever owns the
+//       shutdown signal decides: a shared Arc<AtomicBool> the worker polls,
+//       a channel whose Sender being dropped means "stop", recv_timeout for a
+//       deadline, and in async code a CancellationToken in tokio::select! -
+//       or simply dropping the future.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::registry::{Demo, Difficulty, Example, Tag};
+use crate::report::{DemoReport, SideBySideRow};
+
+const WORKERS: usize = 3;
+const RUN_FOR: Duration = Duration::from_millis(30);
+const TICK: Duration = Duration::from_millis(2);
+
+struct Node {
+    cancelled: AtomicBool,
+    parent: Option<Arc<Node>>,
+}
+
+/// A cancel flag with parents, like a context.Context tree: cancelling a
+/// token cancels every child made from it, never the parent
+#[derive(Clone)]
+pub struct CancelToken {
+    node: Arc<Node>,
+}
+
+impl Default for CancelToken {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CancelToken {
+    pub fn new() -> Self {
+        CancelToken { node: Arc::new(Node { cancelled: AtomicBool::new(false), parent: None }) }
+    }
+
+    /// context.WithCancel(parent): the child keeps its parent alive, not the other way round
+    pub fn child(&self) -> CancelToken {
+        CancelToken { node: Arc::new(Node { cancelled: AtomicBool::new(false), parent: Some(Arc::clone(&self.node)) }) }
+    }
+
+    pub fn cancel(&self) {
+        self.node.cancelled.store(true, Ordering::Release);
+    }
+
+    /// True once this token or any ancestor was cancelled
+    pub fn is_cancelled(&self) -> bool {
+        let mut node = Some(&self.node);
+        while let Some(current) = node {
+            if current.cancelled.load(Ordering::Acquire) {
+                return true;
+            }
+            node = current.parent.as_ref();
+        }
+        false
+    }
+
+    /// Cancels when the guard goes out of scope - `defer cancel()`
+    pub fn drop_guard(self) -> CancelOnDrop {
+        CancelOnDrop(self)
+    }
+}
+
+/// Cancels its token on drop, on every path out of the scope
+pub struct CancelOnDrop(CancelToken);
+
+impl Drop for CancelOnDrop {
+    fn drop(&mut self) {
+        self.0.cancel();
+    }
+}
+
+/// Does small slices of work until the token says stop; returns the slices done
+pub fn work_until_cancelled(token: &CancelToken) -> u64 {
+    let mut slices = 0;
+    while !token.is_cancelled() {
+        thread::sleep(TICK);
+        slices += 1;
+    }
+    slices
+}
+
+// Arc<AtomicBool> behind a token, polled between slices of work
+pub fn shared_flag() -> DemoReport {
+    let mut report = DemoReport::new("A Shared Flag - context.WithCancel");
+
+    report.code("let token = CancelToken::new();  // Arc<AtomicBool> + parent link");
+    report.code("thread::spawn({ let token = token.clone(); move || while !token.is_cancelled() { work() } })");
+    let token = CancelToken::new();
+    let workers: Vec<_> = (0..WORKERS)
+        .map(|_| {
+            let token = token.clone();  // Each worker owns a handle, like passing ctx
+            thread::spawn(move || work_until_cancelled(&token))
+        })
+        .collect();
+    thread::sleep(RUN_FOR);
+    let cancelled_at = Instant::now();
+    token.cancel();
+    let slices: Vec<u64> = workers.into_iter().map(|worker| worker.join().unwrap()).collect();
+    report.text(format!("Cancelled after {:?}; the workers did {:?} slices", RUN_FOR, slices));
+    report.duration("cancel() → every worker joined", cancelled_at.elapsed());
+    report.text(format!("≤ one slice ({:?}): a worker only notices between slices", TICK));
+
+    report.step("A tree of tokens");
+    report.code("let request = server.child();  let db = request.child();");
+    let server = CancelToken::new();
+    let request = server.child();
+    let db = request.child();
+    let sibling = server.child();
+    request.cancel();
+    report.table(
+        &["token", "cancelled?"],
+        vec![
+            vec!["server".into(), server.is_cancelled().to_string()],
+            vec!["request (cancel() called)".into(), request.is_cancelled().to_string()],
+            vec!["db query (child of request)".into(), db.is_cancelled().to_string()],
+            vec!["other request (sibling)".into(), sibling.is_cancelled().to_string()],
+        ],
+    );
+
+    report.step("defer cancel() is a drop guard");
+    let token = CancelToken::new();
+    let worker = {
+        let token = token.clone();
+        thread::spawn(move || work_until_cancelled(&token))
+    };
+    let handle = |token: CancelToken, input: &str| -> Result<u32, std::num::ParseIntError> {
+        let _cancel = token.drop_guard();  // Moves the token in: only the guard can cancel now
+        thread::sleep(RUN_FOR);
+        let n: u32 = input.parse()?;  // Early return...
+        Ok(n * 2)
+    };
+    let outcome = handle(token, "forty-two");
+    let slices = worker.join().unwrap();
+    report.code("let _cancel = token.drop_guard();  let n: u32 = input.parse()?;  // the guard drops on the way out");
+    report.text(format!("{:?} - the worker still stopped after {} slices", outcome, slices));
+
+    report.gap();
+    report.ok("The token is a value: clone it into each thread that must stop, like passing ctx");
+    report.warn("Cooperative, as in Go: a worker that never checks never stops");
+    report
+}
+
+// The owner of the Sender is the owner of the "keep going" - dropping it cancels
+pub fn channel_signal() -> DemoReport {
+    let mut report = DemoReport::new("A Channel as the Signal - close(done)");
+
+    report.code("loop { match done.recv_timeout(TICK) { Err(Timeout) => work(), _ => break } }");
+    let worker = |done: mpsc::Receiver<()>| {
+        thread::spawn(move || {
+            let mut slices = 0;
+            loop {
+                match done.recv_timeout(TICK) {
+                    Err(RecvTimeoutError::Timeout) => slices += 1,  // Nothing yet: one more slice
+                    Ok(()) => return (slices, "got a stop message"),
+                    Err(RecvTimeoutError::Disconnected) => return (slices, "every Sender dropped"),
+                }
+            }
+        })
+    };
+
+    let (stop, done) = mpsc::channel();
+    let explicit = worker(done);
+    thread::sleep(RUN_FOR);
+    stop.send(()).unwrap();
+    let (slices, why) = explicit.join().unwrap();
+    report.text(format!("stop.send(()) → stopped after {} slices: {}", slices, why));
+
+    let (stop, done) = mpsc::channel::<()>();
+    let dropped = worker(done);
+    thread::sleep(RUN_FOR);
+    drop(stop);  // close(done): no message needed
+    let (slices, why) = dropped.join().unwrap();
+    report.text(format!("drop(stop)     → stopped after {} slices: {}", slices, why));
+
+    report.step("Every exit path cancels");
+    let (stop, done) = mpsc::channel::<()>();
+    let handle = worker(done);
+    let previous_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(|_| {}));  // Keep the expected panic off stderr
+    let owner = thread::spawn(move || {
+        let _stop = stop;  // Owned by this thread: unwinding drops it too
+        thread::sleep(RUN_FOR);
+        panic!("handler panicked");
+    })
+    .join();
+    std::panic::set_hook(previous_hook);
+    let (slices, why) = handle.join().unwrap();
+    report.text(format!("Owner thread panicked (join: {}) → worker stopped after {} slices: {}", if owner.is_err() { "Err" } else { "Ok" }, slices, why));
+
+    report.gap();
+    report.ok("Go's `close(done)` is Rust's drop: the last Sender going away wakes every receiver");
+    report.text("No \"forgot to call cancel()\" leak: the signal lives exactly as long as its owner");
+    report
+}
+
+/// How a bounded wait ended
+#[derive(Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Done(u64),
+    TimedOut,
+}
+
+/// Runs `slices` slices of work on a thread, waits at most `timeout` for the
+/// result, and cancels the worker when it gives up (WithTimeout + defer cancel())
+pub fn with_timeout(slices: u64, timeout: Duration) -> (Outcome, u64) {
+    let token = CancelToken::new();
+    let (results, result) = mpsc::channel();
+    let worker = {
+        let token = token.clone();
+        thread::spawn(move || {
+            let mut done = 0;
+            while done < slices && !token.is_cancelled() {
+                thread::sleep(Duration::from_millis(1));
+                done += 1;
+            }
+            let _ = results.send(done);  // Err if nobody waits any more: fine
+            done
+        })
+    };
+    let outcome = match result.recv_timeout(timeout) {
+        Ok(total) => Outcome::Done(total),
+        Err(_) => Outcome::TimedOut,
+    };
+    token.cancel();
+    let slices_done = worker.join().unwrap();
+    (outcome, slices_done)
+}
+
+// recv_timeout for a deadline, and a deadline passed down as an Instant
+pub fn timeouts() -> DemoReport {
+    let mut report = DemoReport::new("Timeouts - context.WithTimeout");
+
+    report.code("match result.recv_timeout(timeout) { Ok(v) => .., Err(Timeout) => { token.cancel(); .. } }");
+    let budget = Duration::from_millis(20);
+    let rows = [5, 200]
+        .into_iter()
+        .map(|slices| {
+            let start = Instant::now();
+            let (outcome, done) = with_timeout(slices, budget);
+            vec![format!("{} ms of work", slices), format!("{:?}", outcome), format!("{}/{}", done, slices), format!("{:.1?}", start.elapsed())]
+        })
+        .collect();
+    report.table(&["job", "outcome", "slices done", "returned after"], rows);
+    report.text(format!("Budget {:?}: the slow job is abandoned, then cancelled so its thread stops too", budget));
+
+    report.step("A deadline travels as a value");
+    report.code("let query = |rows: u32, deadline: Instant| { for row in 0..rows { if Instant::now() >= deadline { return Err(..) } .. } }");
+    let query = |rows: u32, deadline: Instant| -> Result<u64, &'static str> {
+        let mut sum = 0;
+        for row in 0..rows {
+            if Instant::now() >= deadline {
+                return Err("deadline exceeded");  // ctx.Err() == context.DeadlineExceeded
+            }
+            thread::sleep(Duration::from_micros(500));
+            sum += row as u64;
+        }
+        Ok(sum)
+    };
+    let deadline = Instant::now() + Duration::from_millis(15);
+    report.text(format!("query(10 rows)   → {:?}", query(10, deadline)));
+    report.text(format!("query(1000 rows) → {:?}", query(1000, deadline)));
+
+    report.gap();
+    report.ok("recv_timeout gives up waiting; only the token makes the worker give up working");
+    report.warn("A timed-out Go select leaks the goroutine the same way unless ctx is cancelled");
+    report
+}
+
+// CancellationToken and tokio::select!: cancel by dropping the losing future
+#[cfg(feature = "tokio")]
+pub fn tokio_select() -> DemoReport {
+    use tokio::time::{sleep, timeout};
+    use tokio_util::sync::CancellationToken;
+
+    let mut report = DemoReport::new("tokio::select! with a CancellationToken");
+
+    let rt = tokio::runtime::Builder::new_current_thread().enable_time().build().expect("failed to build tokio runtime");
+    report.code("tokio::select! { _ = token.cancelled() => break, _ = sleep(TICK) => ticks += 1 }");
+    let (ticks, latency) = rt.block_on(async {
+        let parent = CancellationToken::new();
+        let tasks: Vec<_> = (0..WORKERS)
+            .map(|_| {
+                let token = parent.child_token();
+                tokio::spawn(async move {
+                    let mut ticks = 0u64;
+                    loop {
+                        tokio::select! {
+                            _ = token.cancelled() => break,
+                            _ = sleep(TICK) => ticks += 1,
+                        }
+                    }
+                    ticks
+                })
+            })
+            .collect();
+        sleep(RUN_FOR).await;
+        let cancelled_at = Instant::now();
+        parent.cancel();
+        let mut ticks = vec![];
+        for task in tasks {
+            ticks.push(task.await.unwrap());
+        }
+        (ticks, cancelled_at.elapsed())
+    });
+    report.text(format!("{} tasks on child tokens ticked {:?} times", WORKERS, ticks));
+    report.duration("parent.cancel() → every task done", latency);
+    report.text("A waiting task wakes on cancelled() at once: no polling interval to wait out");
+
+    report.step("tokio::time::timeout - drop the future");
+    report.code("timeout(Duration::from_millis(10), sleep(Duration::from_secs(5))).await");
+    let (result, elapsed) = rt.block_on(async {
+        let start = Instant::now();
+        (timeout(Duration::from_millis(10), sleep(Duration::from_secs(5))).await, start.elapsed())
+    });
+    report.text(format!("→ {:?} after {:.1?}", result.map_err(|elapsed| elapsed.to_string()), elapsed));
+    report.ok("The 5 s sleep is dropped mid-.await - no flag to check, nothing left running");
+    report.warn("Only at an .await: a future busy in a loop without one can't be interrupted");
+
+    report.gap();
+    report.ok("token.cancelled() ≈ <-ctx.Done(), child_token() ≈ WithCancel(parent)");
+    report
+}
+
+#[cfg(not(feature = "tokio"))]
+pub fn tokio_select() -> DemoReport {
+    let mut report = DemoReport::new("tokio::select! with a CancellationToken");
+    report.warn("Needs --features tokio: cargo run --features tokio -- run cancellation -e tokio-select");
+    report.code("let token = CancellationToken::new();  let child = token.child_token();");
+    report.code("tokio::select! { _ = child.cancelled() => break, _ = sleep(TICK) => ticks += 1 }");
+    report.code("timeout(Duration::from_millis(10), slow()).await  // Err(Elapsed): slow() dropped");
+    report.text("In async code the cheapest cancellation is drop: a future that isn't polled doesn't run");
+    report
+}
+
+pub fn cancellation_vs_go() -> DemoReport {
+    let mut report = DemoReport::new("Cancellation: Go vs Rust");
+
+    report.side_by_side(vec![
+        SideBySideRow::differs("ctx, cancel := context.WithCancel(parent)", "let token = parent.child();"),
+        SideBySideRow::differs("defer cancel()", "let _cancel = token.drop_guard();"),
+        SideBySideRow::differs("func work(ctx context.Context) {", "thread::spawn(move || {  // token moved in"),
+        SideBySideRow::differs("    select { case <-ctx.Done(): return; default: }", "    if token.is_cancelled() { return }"),
+        SideBySideRow::differs("close(done)", "drop(sender)"),
+        SideBySideRow::differs("context.WithTimeout(ctx, d)", "rx.recv_timeout(d) / tokio::time::timeout(d, fut)"),
+        SideBySideRow::same("ctx.Err() == context.DeadlineExceeded", "Err(RecvTimeoutError::Timeout)"),
+    ]);
+
+    report.table(
+        &["", "Go", "Rust"],
+        vec![
+            vec!["signal lives in".into(), "ctx, passed to every call".into(), "whatever handle you clone or move in".into()],
+            vec!["forgetting cancel()".into(), "go vet warns: context leak".into(), "drop guards, dropped Senders".into()],
+            vec!["stopping a blocked read".into(), "select on ctx.Done()".into(), "recv_timeout loop / select!".into()],
+            vec!["stopping async work".into(), "same as sync".into(), "drop the future".into()],
+            vec!["killing a thread".into(), "impossible".into(), "impossible".into()],
+        ],
+    );
+
+    report.gap();
+    report.ok("Same model - cooperative - but Rust makes the shutdown signal an owned value");
+    report.text("context.WithValue has no counterpart here: pass what you need as arguments");
+    report
+}
+
+pub const DEMO: Demo = Demo {
+    name: "cancellation",
+    about: "Timeouts and cancellation vs Go's context: shared flags with parents, dropped Senders, recv_timeout, CancellationToken + tokio::select!",
+    difficulty: Difficulty::Intermediate,
+    tags: &[Tag::Concurrency, Tag::Ownership],
+    examples: &[
+        Example { name: "shared-flag", run: shared_flag },
+        Example { name: "channel-signal", run: channel_signal },
+        Example { name: "timeouts", run: timeouts },
+        Example { name: "tokio-select", run: tokio_select },
+        Example { name: "vs-go", run: cancellation_vs_go },
+    ],
+};
+
+pub fn demonstrate_cancellation() -> Vec<DemoReport> {
+    DEMO.run()
+}
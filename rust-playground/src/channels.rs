@@ -0,0 +1,352 @@
+// Go channels vs Rust std::sync::mpsc
+// mpsc = Multi-Producer, Single-Consumer
+// channel()         = unbounded (Go has no direct equivalent)
+// sync_channel(n)   = bounded, like make(chan T, n)
+// sync_channel(0)   = rendezvous, like make(chan T)
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::{self, Receiver};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::alloc_stats;
+use crate::registry::{Demo, Difficulty, Example, Tag};
+use crate::report::{format_bytes, DemoReport};
+
+// The backpressure example: a producer that never waits, a consumer that does
+const FLOOD_MESSAGES: usize = 400;
+const FLOOD_PAYLOAD: usize = 4 * 1024;
+const CONSUMER_DELAY: Duration = Duration::from_micros(100);
+const FLOOD_BOUND: usize = 8;
+const SAMPLE_EVERY: Duration = Duration::from_millis(5);
+
+// Unbounded channel - send never blocks
+pub fn channel_example() -> DemoReport {
+    let mut report = DemoReport::new("mpsc::channel() - Unbounded Channel");
+
+    let (tx, rx) = mpsc::channel();
+
+    let producer = thread::spawn(move || {
+        let mut sent = vec![];
+        for i in 1..=3 {
+            let msg = format!("message {}", i);
+            sent.push(format!("Sending:  {:?}", msg));
+            tx.send(msg).unwrap();  // Ownership of the String MOVES into the channel
+            // println!("{}", msg);  // ❌ Error: value borrowed after move
+        }
+        sent
+    });
+
+    for line in producer.join().unwrap() {
+        report.text(line);
+    }
+
+    for _ in 0..3 {
+        let msg = rx.recv().unwrap();  // Receiver now OWNS the String
+        report.text(format!("Received: {:?}", msg));
+    }
+
+    report.gap();
+    report.ok("send() never blocks (queue grows as needed)");
+    report.ok("Sent values are MOVED - sender can't touch them afterwards");
+    report.warn("No Go equivalent: Go channels are always bounded");
+    report
+}
+
+// Bounded channel - like a buffered Go channel
+pub fn sync_channel_example() -> DemoReport {
+    let mut report = DemoReport::new("mpsc::sync_channel(n) - Bounded (Buffered) Channel");
+
+    let (tx, rx) = mpsc::sync_channel(2);  // Like make(chan i32, 2)
+
+    // Both sides append to one log so the interleaving is preserved
+    let log = Arc::new(Mutex::new(Vec::new()));
+
+    let producer_log = Arc::clone(&log);
+    let producer = thread::spawn(move || {
+        for i in 1..=4 {
+            producer_log.lock().unwrap().push(format!("Producer: sending {} ...", i));
+            tx.send(i).unwrap();  // Blocks while the buffer is full
+            producer_log.lock().unwrap().push(format!("Producer: sent {}", i));
+        }
+    });
+
+    // Give the producer time to fill the buffer and block
+    thread::sleep(Duration::from_millis(50));
+    log.lock()
+        .unwrap()
+        .push("Consumer: buffer should be full now (2 items), producer blocked".to_string());
+
+    for value in rx.iter().take(4) {
+        log.lock().unwrap().push(format!("Consumer: received {}", value));
+    }
+    producer.join().unwrap();
+
+    for line in log.lock().unwrap().drain(..) {
+        report.text(line);
+    }
+
+    report.gap();
+    report.ok("send() blocks when the buffer is full (backpressure)");
+    report.ok("sync_channel(0) is a rendezvous, like an unbuffered Go channel");
+    report
+}
+
+// channel()'s Sender and sync_channel()'s SyncSender behind one type
+type SendFn = Box<dyn Fn(Vec<u8>) + Send>;
+
+// One look at the channel while the flood runs
+struct FloodSample {
+    at: Duration,
+    sent: usize,
+    received: usize,
+    live_bytes: Option<usize>,
+}
+
+struct FloodRun {
+    samples: Vec<FloodSample>,
+    producer_finished: Duration,
+    elapsed: Duration,
+    peak_bytes: Option<usize>,
+}
+
+impl FloodRun {
+    fn peak_depth(&self) -> usize {
+        self.samples.iter().map(|s| s.sent - s.received).max().unwrap_or(0)
+    }
+}
+
+// FLOOD_MESSAGES payloads through channel() (bound None) or sync_channel(n),
+// sampled every SAMPLE_EVERY from this thread
+fn flood(bound: Option<usize>) -> FloodRun {
+    let (send, rx): (SendFn, Receiver<Vec<u8>>) = match bound {
+        None => {
+            let (tx, rx) = mpsc::channel();
+            (Box::new(move |payload| tx.send(payload).unwrap()), rx)
+        }
+        Some(bound) => {
+            let (tx, rx) = mpsc::sync_channel(bound);
+            (Box::new(move |payload| tx.send(payload).unwrap()), rx)  // blocks while `bound` are queued
+        }
+    };
+    let sent = Arc::new(AtomicUsize::new(0));
+    let received = Arc::new(AtomicUsize::new(0));
+    let baseline = alloc_stats::live_bytes();
+
+    let ((producer_finished, samples, elapsed), stats) = alloc_stats::measure(|| {
+        let start = Instant::now();
+        let producer_sent = Arc::clone(&sent);
+        let producer = thread::spawn(move || {
+            for i in 0..FLOOD_MESSAGES {
+                send(vec![i as u8; FLOOD_PAYLOAD]);
+                producer_sent.fetch_add(1, Ordering::Relaxed);
+            }
+            start.elapsed()
+        });
+        let consumer_received = Arc::clone(&received);
+        let consumer = thread::spawn(move || {
+            for payload in rx {
+                thread::sleep(CONSUMER_DELAY);  // the slow part
+                drop(payload);
+                consumer_received.fetch_add(1, Ordering::Relaxed);
+            }
+        });
+
+        let mut samples = Vec::new();
+        loop {
+            let received = received.load(Ordering::Relaxed);
+            samples.push(FloodSample {
+                at: start.elapsed(),
+                sent: sent.load(Ordering::Relaxed).max(received),
+                received,
+                live_bytes: alloc_stats::enabled().then(|| alloc_stats::live_bytes().saturating_sub(baseline)),
+            });
+            if received == FLOOD_MESSAGES {
+                break;
+            }
+            thread::sleep(SAMPLE_EVERY);
+        }
+        let producer_finished = producer.join().unwrap();
+        consumer.join().unwrap();
+        (producer_finished, samples, start.elapsed())
+    });
+
+    FloodRun { samples, producer_finished, elapsed, peak_bytes: stats.map(|stats| stats.peak_bytes) }
+}
+
+fn flood_timeline(report: &mut DemoReport, run: &FloodRun) {
+    let bar_unit = FLOOD_MESSAGES / 40;
+    report.table(
+        &["t", "sent", "received", "in flight", "live heap", ""],
+        run.samples
+            .iter()
+            .map(|s| {
+                let queued = s.sent - s.received;
+                vec![
+                    format!("{:.0?}", s.at),
+                    s.sent.to_string(),
+                    s.received.to_string(),
+                    queued.to_string(),
+                    s.live_bytes.map_or("n/a".to_string(), format_bytes),
+                    "█".repeat(queued.div_ceil(bar_unit)),
+                ]
+            })
+            .collect(),
+    );
+    report.text(format!("producer finished after {:.1?}, consumer after {:.1?}", run.producer_finished, run.elapsed));
+}
+
+// Fast producer, slow consumer: queue depth and heap over time
+pub fn backpressure_example() -> DemoReport {
+    let mut report = DemoReport::new("Backpressure - Queue Depth and Memory Over Time");
+
+    report.text(format!(
+        "{} messages of {} each; the producer never waits, the consumer sleeps {:?} per message",
+        FLOOD_MESSAGES,
+        format_bytes(FLOOD_PAYLOAD),
+        CONSUMER_DELAY
+    ));
+    if !alloc_stats::enabled() {
+        report.warn("Heap columns need the counting allocator: --features alloc-stats");
+    }
+
+    report.step("mpsc::channel() - unbounded");
+    let unbounded = flood(None);
+    flood_timeline(&mut report, &unbounded);
+    report.warn("send() never blocks: the whole backlog sits in the queue, payloads and all");
+
+    report.step(format!("mpsc::sync_channel({}) - bounded", FLOOD_BOUND));
+    let bounded = flood(Some(FLOOD_BOUND));
+    flood_timeline(&mut report, &bounded);
+    report.ok("send() blocks once the buffer is full: the producer is held to the consumer's pace");
+
+    report.step("Summary");
+    let peak = |run: &FloodRun| run.peak_bytes.map_or_else(|| format!("~{} (estimated)", format_bytes(run.peak_depth() * FLOOD_PAYLOAD)), format_bytes);
+    report.table(
+        &["channel", "peak in flight (sampled)", "peak heap", "producer done", "all consumed"],
+        vec![
+            vec!["channel()".into(), unbounded.peak_depth().to_string(), peak(&unbounded), format!("{:.1?}", unbounded.producer_finished), format!("{:.1?}", unbounded.elapsed)],
+            vec![format!("sync_channel({})", FLOOD_BOUND), bounded.peak_depth().to_string(), peak(&bounded), format!("{:.1?}", bounded.producer_finished), format!("{:.1?}", bounded.elapsed)],
+        ],
+    );
+    report.text("Same total time: the consumer sets the pace either way - the bound only decides where the backlog waits");
+    report.text("Unbounded, it waits in memory; bounded, it waits in the producer (which isn't allocating meanwhile)");
+
+    report.step("Go");
+    report.code(format!("ch := make(chan []byte, {})  // every Go channel has a bound; 0 = unbuffered", FLOOD_BOUND));
+    report.code("go func() { ch <- payload }()  // the accidental unbounded queue: a parked goroutine per message");
+    report.text("Buffer sizing in Go: a buffer absorbs bursts, it can't fix a consumer that's slower on average");
+    report.text("The goroutine trick queues like channel() does, plus a 2 KiB+ stack per waiting message");
+
+    report.gap();
+    report.ok("Bound every channel between stages that can run at different speeds");
+    report
+}
+
+// Multiple producers - clone the Sender
+pub fn multiple_producers_example() -> DemoReport {
+    let mut report = DemoReport::new("Multiple Producers - Cloning the Sender");
+
+    let (tx, rx) = mpsc::channel();
+    let mut handles = vec![];
+
+    for id in 0..3 {
+        let tx = tx.clone();  // Each producer owns its own Sender
+        handles.push(thread::spawn(move || {
+            for n in 0..2 {
+                tx.send((id, n)).unwrap();
+            }
+        }));
+    }
+    drop(tx);  // Drop the original Sender, or the receiver never sees "closed"
+
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    let mut received: Vec<(i32, i32)> = rx.iter().collect();
+    received.sort();
+    for (id, n) in &received {
+        report.text(format!("Received from producer {}: {}", id, n));
+    }
+    report.count("Messages received", received.len());
+
+    report.gap();
+    report.ok("Sender is Clone - one per producer thread");
+    report.ok("Receiver is NOT Clone - exactly one consumer (the 'sc' in mpsc)");
+    report.warn("Forgetting drop(tx) keeps the channel open forever");
+    report
+}
+
+// Receiver iteration - like `for v := range ch`
+pub fn receiver_iteration_example() -> DemoReport {
+    let mut report = DemoReport::new("Receiver Iteration - for v := range ch");
+
+    let (tx, rx) = mpsc::channel();
+
+    thread::spawn(move || {
+        for word in ["ownership", "borrowing", "channels"] {
+            tx.send(word).unwrap();
+        }
+        // tx dropped here → channel closed (like close(ch) in Go)
+    });
+
+    for word in rx {  // Loop ends when every Sender is dropped
+        report.text(format!("Got: {}", word));
+    }
+
+    report.gap();
+    report.ok("Iteration ends when all Senders are dropped");
+    report.ok("No explicit close(): dropping the Sender IS closing");
+    report.ok("Sending on a closed Go channel panics; here send() returns Err");
+    report
+}
+
+// Side-by-side commentary
+pub fn channels_vs_go() -> DemoReport {
+    let mut report = DemoReport::new("Go Channels vs Rust mpsc");
+
+    report.step("Go");
+    report.code("ch := make(chan int, 2)  // Buffered");
+    report.code("go func() { ch <- 42; close(ch) }()");
+    report.code("for v := range ch { ... }");
+    report.ok("Many senders AND many receivers");
+    report.ok("select { ... } across channels");
+    report.warn("Send on closed channel → panic");
+    report.warn("Nothing stops you sharing the sent value afterwards");
+
+    report.step("Rust");
+    report.code("let (tx, rx) = mpsc::sync_channel(2);  // Bounded");
+    report.code("thread::spawn(move || tx.send(42).unwrap());");
+    report.code("for v in rx { ... }");
+    report.ok("Many senders, ONE receiver");
+    report.ok("Close = drop every Sender");
+    report.ok("send() on a closed channel returns Err (no panic)");
+    report.ok("Sent values are moved - no shared access after send");
+    report.warn("No built-in select (see crossbeam-channel)");
+
+    report.step("Key Insight");
+    report.text("Go: \"Share memory by communicating\" (by convention)");
+    report.text("Rust: Same idea, but ownership transfer is enforced by the compiler");
+    report
+}
+
+pub const DEMO: Demo = Demo {
+    name: "channels",
+    about: "Go channels vs std::sync::mpsc",
+    difficulty: Difficulty::Intermediate,
+    tags: &[Tag::Concurrency],
+    examples: &[
+        Example { name: "unbounded", run: channel_example },
+        Example { name: "bounded", run: sync_channel_example },
+        Example { name: "backpressure", run: backpressure_example },
+        Example { name: "multiple-producers", run: multiple_producers_example },
+        Example { name: "iteration", run: receiver_iteration_example },
+        Example { name: "vs-go", run: channels_vs_go },
+    ],
+};
+
+pub fn demonstrate_channels() -> Vec<DemoReport> {
+    DEMO.run()
+}
@@ -0,0 +1,301 @@
+// Closures - what they capture, how, and where it lives
+// Go:   a closure captures variables by reference. Any captured variable the
+//       closure might outlive escapes to the heap, and the func value is a
+//       pointer to a heap-allocated (code, captured pointers...) struct.
+// Rust: a closure is an anonymous struct holding exactly what it captures -
+//       by &, by &mut, or by value with `move` - and lives wherever you put
+//       it (usually the stack). How it uses the captures picks its trait:
+//       Fn (read), FnMut (mutate), FnOnce (consume).
+
+use std::cell::Cell;
+
+use crate::registry::{Demo, Difficulty, Example, Tag};
+use crate::report::DemoReport;
+
+// By reference, by mutable reference, or by value
+pub fn capture_modes() -> DemoReport {
+    let mut report = DemoReport::new("Capture Modes - &, &mut and move");
+
+    report.step("By reference (the default when reading is enough)");
+    let name = String::from("gopher");
+    let greet = || format!("hello, {}", name);
+    report.text(greet());
+    report.address("name (still usable after)", &name, &name);
+    report.bytes("size_of_val(&greet)", size_of_val(&greet));
+    report.text("= one &String: the closure borrows, nothing is copied");
+
+    report.step("By mutable reference (when the body mutates)");
+    let mut hits = 0;
+    let mut hit = || hits += 1;
+    hit();
+    hit();
+    report.count("hits after two calls", hits);
+    report.text("While `hit` is alive, nothing else may touch `hits` - an ordinary &mut borrow");
+
+    report.step("By value (`move`)");
+    let owned = String::from("moved into the closure");
+    let owned_ptr = owned.as_ptr();
+    let keep = move || owned.len();
+    report.count("keep()", keep());
+    report.bytes("size_of_val(&keep)", size_of_val(&keep));
+    report.text("= the 24-byte String header; the text buffer didn't move");
+    report.ok(format!("buffer still at {:p}", owned_ptr));
+    report.code("println!(\"{}\", owned);  // ❌ E0382: borrow of moved value");
+
+    report.gap();
+    report.ok("The compiler picks the weakest capture that works; `move` forces by-value");
+    report.text("move on a Copy type (i32, &T) copies it - the original stays usable");
+
+    report.step("Go");
+    report.code("greet := func() string { return \"hello, \" + name }  // always by reference");
+    report.text("There is no `move`: to capture a value, copy it into a new variable first");
+    report
+}
+
+fn call_fn(f: impl Fn() -> usize) -> usize {
+    f() + f()
+}
+
+fn call_fn_mut(mut f: impl FnMut() -> usize) -> usize {
+    f() + f()
+}
+
+fn call_fn_once(f: impl FnOnce() -> usize) -> usize {
+    f()
+}
+
+// Fn: FnMut: FnOnce - each is usable where the next one is expected
+pub fn fn_traits() -> DemoReport {
+    let mut report = DemoReport::new("Fn, FnMut, FnOnce - The Closure Traits");
+
+    report.table(
+        &["Trait", "Body may", "Callable", "Captures used as"],
+        vec![
+            vec!["Fn".into(), "read".into(), "any number of times, shared".into(), "&T".into()],
+            vec!["FnMut".into(), "mutate".into(), "many times, one caller at a time".into(), "&mut T".into()],
+            vec!["FnOnce".into(), "consume".into(), "exactly once".into(), "T (moved out)".into()],
+        ],
+    );
+
+    report.step("Fn goes anywhere");
+    let base = 20;
+    let read = || base + 1;
+    report.count("call_fn(read)", call_fn(read));
+    report.count("call_fn_mut(read)", call_fn_mut(read));
+    report.count("call_fn_once(read)", call_fn_once(read));
+
+    report.step("FnMut: not where Fn is required");
+    let mut calls = 0;
+    let mut counting = || {
+        calls += 1;
+        calls
+    };
+    report.count("call_fn_mut(&mut counting)", call_fn_mut(&mut counting));
+    report.count("call_fn_once(counting)", call_fn_once(counting));
+    report.code("call_fn(counting)  // ❌ E0525: expected Fn, this closure is FnMut");
+
+    report.step("FnOnce: consumes what it captured");
+    let log = vec![String::from("a"), String::from("b")];
+    let drain = move || {
+        let lines = log.len();
+        drop(log);
+        lines
+    };
+    report.count("call_fn_once(drain)", call_fn_once(drain));
+    report.code("drain(); drain();  // ❌ E0382: closure moved by the first call");
+    report.text("Checked by tests/compile_fail/fn_once_called_twice.rs");
+
+    report.gap();
+    report.ok("Bounds say what an API will do with your closure: thread::spawn wants FnOnce + Send");
+    report.text("Iterator::map takes FnMut, sort_by takes FnMut, Option::map takes FnOnce");
+
+    report.step("Go");
+    report.code("func apply(f func() int) int { return f() + f() }  // one func type for all three");
+    report.text("Go can't say \"call me once\" - a cleanup func called twice is a runtime bug");
+    report
+}
+
+fn make_adder(n: i32) -> impl Fn(i32) -> i32 {
+    move |x| x + n
+}
+
+fn make_counter() -> impl FnMut() -> u32 {
+    let mut count = 0;
+    move || {
+        count += 1;
+        count
+    }
+}
+
+// Returning closures: impl Fn (no box) vs Box<dyn Fn> (heterogeneous)
+pub fn returning_closures() -> DemoReport {
+    let mut report = DemoReport::new("Returning Closures - impl Fn vs Box<dyn Fn>");
+
+    report.step("impl Fn: the concrete closure type, returned by value");
+    report.code("fn make_adder(n: i32) -> impl Fn(i32) -> i32 { move |x| x + n }");
+    let add5 = make_adder(5);
+    report.count("make_adder(5)(10)", add5(10) as usize);
+    report.bytes("size_of_val(&add5)", size_of_val(&add5));
+    report.text("= just n: no heap, no pointer to it, the call can be inlined");
+
+    let mut counter = make_counter();
+    counter();
+    counter();
+    report.count("make_counter() called 3 times", counter() as usize);
+    report.text("The counter's state lives inside the returned value, not in a dead stack frame");
+    report.code("fn bad() -> impl Fn() -> i32 { let n = 1; || n }  // ❌ E0373: closure may outlive n");
+
+    report.step("Box<dyn Fn>: different closures in one Vec");
+    let offset = 100;
+    let ops: Vec<Box<dyn Fn(i32) -> i32>> = vec![
+        Box::new(|x| x * 2),
+        Box::new(move |x| x + offset),
+        Box::new(make_adder(-1)),
+    ];
+    let results: Vec<i32> = ops.iter().map(|op| op(7)).collect();
+    report.text(format!("ops applied to 7: {:?}", results));
+    report.bytes("size_of::<Box<dyn Fn(i32) -> i32>>()", size_of::<Box<dyn Fn(i32) -> i32>>());
+    report.text("A fat pointer (data, vtable); each capturing closure is one heap allocation");
+    report.text("(x * 2 captures nothing - a zero-size Box doesn't allocate)");
+
+    report.gap();
+    report.ok("Default to impl Fn; reach for Box<dyn Fn> when the types differ at runtime");
+
+    report.step("Go");
+    report.code("func makeAdder(n int) func(int) int { return func(x int) int { return x + n } }");
+    report.text("go build -gcflags=-m: \"func literal escapes to heap\", \"moved to heap: n\"");
+    report
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Point {
+    x: f64,
+    y: f64,
+    z: f64,
+}
+
+// A closure is a struct of its captures - size_of_val shows it
+pub fn environment_layout() -> DemoReport {
+    let mut report = DemoReport::new("Captured Environments - A Closure Is a Struct");
+
+    let id = 7u8;
+    let scale = 2.5f64;
+    let label = String::from("point");
+    let buffer = [0u8; 64];
+    let point = Point { x: 1.0, y: 2.0, z: 3.0 };
+
+    let nothing = || 42;
+    let by_ref = || id as f64 * scale;
+    let by_value = move || id as f64 * scale;
+    let owns_string = move || label.len();
+    let owns_array = move || buffer.len();
+    let one_field = move || point.x;
+
+    report.table(
+        &["Closure", "Captures", "size_of_val"],
+        vec![
+            vec!["|| 42".into(), "nothing".into(), size_of_val(&nothing).to_string()],
+            vec!["|| id * scale".into(), "&u8, &f64".into(), size_of_val(&by_ref).to_string()],
+            vec!["move || id * scale".into(), "u8, f64 (+ padding)".into(), size_of_val(&by_value).to_string()],
+            vec!["move || label.len()".into(), "String header".into(), size_of_val(&owns_string).to_string()],
+            vec!["move || buffer.len()".into(), "[u8; 64]".into(), size_of_val(&owns_array).to_string()],
+            vec!["move || point.x".into(), "point.x only".into(), size_of_val(&one_field).to_string()],
+        ],
+    );
+    report.text(format!("nothing() = {}, owns_string() = {}, owns_array() = {}", nothing(), owns_string(), owns_array()));
+    report.text(format!("by_ref() = {}, by_value() = {}, one_field() = {}", by_ref(), by_value(), one_field()));
+    report.text(format!("point.y = {}, point.z = {} - never captured, still here", point.y, point.z));
+
+    report.gap();
+    report.ok("Non-capturing closures are zero-sized and coerce to plain fn pointers");
+    report.ok("Edition 2021: a closure captures the fields it uses, not the whole struct");
+    report.warn("move || buffer.len() copies all 64 bytes - capture &buffer if that matters");
+
+    report.step("Go");
+    report.text("A func value is one pointer to a heap closure object: code pointer + one");
+    report.text("pointer per captured variable, each of which escaped to the heap too");
+    report
+}
+
+// for i := ... { funcs = append(funcs, func() { print(i) }) }
+pub fn loop_variable_capture() -> DemoReport {
+    let mut report = DemoReport::new("Loop Variable Capture - Both Languages");
+
+    report.step("Go (before 1.22)");
+    report.code("var funcs []func() int");
+    report.code("for i := 0; i < 3; i++ {");
+    report.code("    funcs = append(funcs, func() int { return i })");
+    report.code("}");
+    report.fail("Calling them returns 3 3 3: all three captured the same i");
+    report.text("Fix before 1.22: i := i inside the loop. Go 1.22 gives each iteration its own i");
+
+    report.step("Rust");
+    report.code("for i in 0..3 { funcs.push(|| i); }");
+    report.fail("E0373: closure may outlive the current function, but it borrows `i`");
+    let mut funcs: Vec<Box<dyn Fn() -> usize>> = Vec::new();
+    for i in 0..3 {
+        funcs.push(Box::new(move || i));
+    }
+    let values: Vec<usize> = funcs.iter().map(|f| f()).collect();
+    report.code("for i in 0..3 { funcs.push(Box::new(move || i)); }");
+    report.text(format!("Calling them returns {:?}", values));
+
+    report.step("Sharing on purpose");
+    let total = Cell::new(0);
+    {
+        let shared = &total;
+        let adders: Vec<Box<dyn Fn() + '_>> =
+            (1..=3).map(|i| Box::new(move || shared.set(shared.get() + i)) as Box<dyn Fn()>).collect();
+        for add in &adders {
+            add();
+        }
+    }
+    report.count("total (a Cell every closure borrows)", total.get());
+
+    report.gap();
+    report.ok("Rust's `for` binds a fresh i per iteration, and move makes each closure own it");
+    report.ok("Shared state has to be spelled out: &Cell, Rc<RefCell>, or Arc<Mutex> across threads");
+    report.text("Same bug with goroutines, and Rust's answer: run scoped-threads -e loop-variable");
+    report
+}
+
+pub fn closures_vs_go() -> DemoReport {
+    let mut report = DemoReport::new("Closures: Go vs Rust");
+
+    report.table(
+        &["", "Go", "Rust"],
+        vec![
+            vec!["captures".into(), "by reference, always".into(), "&, &mut or by value (move)".into()],
+            vec!["captured vars".into(), "escape to the heap".into(), "stay where they are (or move into the closure)".into()],
+            vec!["closure value".into(), "pointer to heap object".into(), "anonymous struct, usually on the stack".into()],
+            vec!["type".into(), "func(int) int".into(), "unique type; impl Fn(i32) -> i32".into()],
+            vec!["call once / mutate".into(), "not expressible".into(), "FnOnce / FnMut".into()],
+            vec!["heterogeneous list".into(), "[]func()".into(), "Vec<Box<dyn Fn()>>".into()],
+            vec!["loop variable".into(), "shared before Go 1.22".into(), "fresh per iteration".into()],
+        ],
+    );
+
+    report.gap();
+    report.ok("A Rust closure costs what its captures cost - often nothing beyond the stack");
+    report.text("Go's closures are simpler to write; the price is a heap allocation per escape");
+    report
+}
+
+pub const DEMO: Demo = Demo {
+    name: "closures-and-moves",
+    about: "Closures: Fn/FnMut/FnOnce, move captures and their layout vs Go closures",
+    difficulty: Difficulty::Intermediate,
+    tags: &[Tag::Ownership, Tag::Borrowing],
+    examples: &[
+        Example { name: "capture-modes", run: capture_modes },
+        Example { name: "fn-traits", run: fn_traits },
+        Example { name: "returning", run: returning_closures },
+        Example { name: "layout", run: environment_layout },
+        Example { name: "loop-variable", run: loop_variable_capture },
+        Example { name: "vs-go", run: closures_vs_go },
+    ],
+};
+
+pub fn demonstrate_closures_and_moves() -> Vec<DemoReport> {
+    DEMO.run()
+}
@@ -0,0 +1,213 @@
+// Compare runs - one demo under two feature sets, timings and allocations diffed
+// Features are compile-time, so "std Mutex vs parking_lot" or "system
+// allocator vs mimalloc" means two builds. `compare-run` makes both with
+// `cargo run --features ...` and runs `run <demo> --emit-stats` in each;
+// instead of rendering, the child prints one line per example:
+//
+//   compare example=borrow-basics ns=41250 allocs=12 bytes=1536 peak=1024
+//
+// The parent parses those lines (same format as xlang_bench's), keeps each
+// example's fastest of --runs and prints one table: baseline vs candidate
+// and the change. Both sides always get alloc-stats, so allocations count.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::path::Path;
+use std::process::Command;
+
+use crate::report::{format_bytes, DemoReport};
+use crate::xlang_bench::RunError;
+
+/// One example's numbers from one build
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExampleStats {
+    pub example: String,
+    pub nanos: u64,
+    /// None when the build couldn't count (no alloc-stats)
+    pub allocations: Option<u64>,
+    pub bytes: Option<u64>,
+    pub peak: Option<u64>,
+}
+
+// "-" (or a missing key) means not measured
+fn optional(value: Option<u64>) -> String {
+    value.map_or("-".to_string(), |value| value.to_string())
+}
+
+impl fmt::Display for ExampleStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "compare example={} ns={} allocs={} bytes={} peak={}",
+            self.example,
+            self.nanos,
+            optional(self.allocations),
+            optional(self.bytes),
+            optional(self.peak)
+        )
+    }
+}
+
+impl ExampleStats {
+    /// What `measured` recorded for one example's report
+    pub fn of(example: &str, report: &DemoReport) -> Self {
+        ExampleStats {
+            example: example.to_string(),
+            nanos: report.elapsed.map_or(0, |elapsed| elapsed.as_nanos() as u64),
+            allocations: report.alloc.map(|stats| stats.allocations as u64),
+            bytes: report.alloc.map(|stats| stats.bytes_allocated as u64),
+            peak: report.alloc.map(|stats| stats.peak_bytes as u64),
+        }
+    }
+}
+
+/// The child's output for `--emit-stats`: one line per example, in run order
+pub fn emit<S: AsRef<str>>(examples: &[S], reports: &[DemoReport]) -> String {
+    examples.iter().zip(reports).map(|(example, report)| format!("{}\n", ExampleStats::of(example.as_ref(), report))).collect()
+}
+
+/// Parse one `compare example=...` line; anything else (build noise) is None
+pub fn parse_line(line: &str) -> Option<ExampleStats> {
+    let fields = line.strip_prefix("compare ")?;
+    let fields: HashMap<&str, &str> = fields.split_whitespace().filter_map(|field| field.split_once('=')).collect();
+    let number = |key: &str| fields.get(key).and_then(|value| value.parse::<u64>().ok());
+    Some(ExampleStats {
+        example: fields.get("example")?.to_string(),
+        nanos: number("ns")?,
+        allocations: number("allocs"),
+        bytes: number("bytes"),
+        peak: number("peak"),
+    })
+}
+
+/// Every stats line in a child's output
+pub fn parse_output(output: &str) -> Vec<ExampleStats> {
+    output.lines().filter_map(parse_line).collect()
+}
+
+/// One build to compare: extra Cargo features on top of the defaults
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Side {
+    /// Comma-separated, as for `cargo --features` ("" = defaults only)
+    pub features: String,
+    pub release: bool,
+}
+
+impl Side {
+    pub fn label(&self) -> &str {
+        if self.features.is_empty() { "default" } else { &self.features }
+    }
+
+    /// `cargo run` building this side and running one demo in it
+    pub fn command(&self, manifest: &Path, demo: &str, examples: &[String]) -> Command {
+        let cargo = std::env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+        let mut command = Command::new(cargo);
+        command.args(["run", "--quiet", "--bin", "rust-playground", "--manifest-path"]).arg(manifest);
+        if self.release {
+            command.arg("--release");
+        }
+        let features = if self.features.is_empty() { "alloc-stats".to_string() } else { format!("alloc-stats,{}", self.features) };
+        command.args(["--features", &features, "--", "run", demo]);
+        for example in examples {
+            command.args(["-e", example]);
+        }
+        command.arg("--emit-stats");
+        command
+    }
+
+    /// Run the demo `runs` times in this build; each example keeps its fastest run
+    pub fn run(&self, manifest: &Path, demo: &str, examples: &[String], runs: usize) -> Result<Vec<ExampleStats>, RunError> {
+        let name = format!("cargo run --features {}", self.label());
+        let mut best: Vec<ExampleStats> = Vec::new();
+        for _ in 0..runs.max(1) {
+            let output = self.command(manifest, demo, examples).output().map_err(|error| RunError::Spawn(name.clone(), error))?;
+            if !output.status.success() {
+                return Err(RunError::Failed(name, String::from_utf8_lossy(&output.stderr).into_owned()));
+            }
+            best = fastest(best, parse_output(&String::from_utf8_lossy(&output.stdout)));
+        }
+        Ok(best)
+    }
+}
+
+/// Merge a new run into the best so far: per example, the faster of the two
+pub fn fastest(best: Vec<ExampleStats>, run: Vec<ExampleStats>) -> Vec<ExampleStats> {
+    if best.is_empty() {
+        return run;
+    }
+    best.into_iter()
+        .map(|old| match run.iter().find(|new| new.example == old.example) {
+            Some(new) if new.nanos < old.nanos => new.clone(),
+            _ => old,
+        })
+        .collect()
+}
+
+// +12.5% / -3.0%, or "-" when either side is missing (or zero)
+fn change(baseline: Option<u64>, candidate: Option<u64>) -> String {
+    match (baseline, candidate) {
+        (Some(baseline), Some(candidate)) if baseline > 0 => format!("{:+.1}%", (candidate as f64 - baseline as f64) * 100.0 / baseline as f64),
+        (Some(0), Some(0)) => "±0".to_string(),
+        _ => "-".to_string(),
+    }
+}
+
+fn micros(nanos: Option<u64>) -> String {
+    nanos.map_or("-".to_string(), |nanos| format!("{:.1}", nanos as f64 / 1000.0))
+}
+
+/// The comparison table, one row per example either side ran
+pub fn diff(demo: &str, baseline: (&Side, &[ExampleStats]), candidate: (&Side, &[ExampleStats])) -> DemoReport {
+    let (base_side, base) = baseline;
+    let (cand_side, cand) = candidate;
+    let mut report = DemoReport::new(format!("compare-run {}: {} vs {}", demo, base_side.label(), cand_side.label()));
+
+    let mut examples: Vec<&str> = base.iter().map(|stats| stats.example.as_str()).collect();
+    examples.extend(cand.iter().map(|stats| stats.example.as_str()).filter(|example| !base.iter().any(|stats| stats.example == *example)));
+    let find = |samples: &[ExampleStats], example: &str| samples.iter().find(|stats| stats.example == example).cloned();
+    let bytes = |value: Option<u64>| value.map_or("-".to_string(), |bytes| format_bytes(bytes as usize));
+
+    let mut faster = 0;
+    let rows = examples
+        .iter()
+        .map(|example| {
+            let (base, cand) = (find(base, example), find(cand, example));
+            let nanos = |stats: &Option<ExampleStats>| stats.as_ref().map(|stats| stats.nanos);
+            let allocations = |stats: &Option<ExampleStats>| stats.as_ref().and_then(|stats| stats.allocations);
+            let bytes_of = |stats: &Option<ExampleStats>| stats.as_ref().and_then(|stats| stats.bytes);
+            if matches!((nanos(&base), nanos(&cand)), (Some(base), Some(cand)) if cand < base) {
+                faster += 1;
+            }
+            vec![
+                example.to_string(),
+                micros(nanos(&base)),
+                micros(nanos(&cand)),
+                change(nanos(&base), nanos(&cand)),
+                optional(allocations(&base)),
+                optional(allocations(&cand)),
+                change(allocations(&base), allocations(&cand)),
+                bytes(bytes_of(&base)),
+                bytes(bytes_of(&cand)),
+                change(bytes_of(&base), bytes_of(&cand)),
+            ]
+        })
+        .collect();
+    report.table(&["example", "baseline µs", "candidate µs", "Δ time", "baseline allocs", "candidate allocs", "Δ allocs", "baseline bytes", "candidate bytes", "Δ bytes"], rows);
+
+    report.text(format!("baseline:  --features {}", base_side.label()));
+    report.text(format!("candidate: --features {}", cand_side.label()));
+    report.count("examples the candidate ran faster", faster);
+    for (label, samples, other) in [("baseline", base, cand), ("candidate", cand, base)] {
+        for stats in samples.iter().filter(|stats| !other.iter().any(|other| other.example == stats.example)) {
+            report.warn(format!("{} only: {} (the other build doesn't have it)", label, stats.example));
+        }
+    }
+    if !(base_side.release && cand_side.release) {
+        report.warn("Debug build - add --release for representative timings");
+    }
+
+    report.gap();
+    report.ok("Same demo, same examples: what differs is the features compiled in");
+    report.text("Times include building each report; allocation counts are exact, times vary - raise --runs to steady them");
+    report
+}
@@ -1,98 +1,111 @@
 // Comparing Go vs Rust memory models
 
-#[allow(dead_code)]
-#[derive(Debug)]
-struct LargeObject {
-    id: usize,
-    data: Vec<u8>,
-}
+use crate::registry::{Demo, Difficulty, Example, Tag};
+use crate::report::{DemoReport, SideBySideRow};
+use crate::workload::WorkloadConfig;
+
+// Default size of the generated workload in memory_comparison
+const OBJECT_COUNT: usize = 10;
 
 // Stack allocation in Rust
-pub fn stack_allocation() {
-    println!("\n=== Stack Allocation ===\n");
-    
+pub fn stack_allocation() -> DemoReport {
+    let mut report = DemoReport::new("Stack Allocation");
+
     let x = 42;
     let y = 100;
-    
-    println!("  x at: {:p}, value: {}", &x, x);
-    println!("  y at: {:p}, value: {}", &y, y);
-    println!("  ✓ Allocated on stack");
-    println!("  ✓ Cleaned up when scope ends");
-    println!("  ✓ No heap allocation, no GC!");
+
+    report.address("x at", &x, x.to_string());
+    report.address("y at", &y, y.to_string());
+    report.ok("Allocated on stack");
+    report.ok("Cleaned up when scope ends");
+    report.ok("No heap allocation, no GC!");
+    report
 }
 
 // Heap allocation in Rust - explicit with Box
-pub fn heap_allocation() {
-    println!("\n=== Heap Allocation ===\n");
-    
+pub fn heap_allocation() -> DemoReport {
+    let mut report = DemoReport::new("Heap Allocation");
+
     let x = Box::new(42);  // Explicitly heap-allocated
     let y = Box::new(100);
-    
-    println!("  x points to: {:p}, value: {}", x.as_ref(), x);
-    println!("  y points to: {:p}, value: {}", y.as_ref(), y);
-    println!("  ✓ Box<T> = explicit heap allocation");
-    println!("  ✓ Still cleaned up deterministically (no GC)");
-    println!("  ✓ Owner drops when out of scope");
+
+    report.address("x points to", x.as_ref(), x.to_string());
+    report.address("y points to", y.as_ref(), y.to_string());
+    report.ok("Box<T> = explicit heap allocation");
+    report.ok("Still cleaned up deterministically (no GC)");
+    report.ok("Owner drops when out of scope");
+    report
 }
 
 // Compare: Go allows multiple owners, Rust doesn't
-pub fn ownership_comparison() {
-    println!("\n=== Go vs Rust: Multiple Owners ===\n");
-    
-    println!("Go (allowed):");
-    println!("  user := &User{{...}}");
-    println!("  ptr1 := user  // OK - GC tracks all");
-    println!("  ptr2 := user  // OK - GC tracks all");
-    println!("  ptr3 := user  // OK - GC tracks all");
-    
-    println!("\nRust (not allowed):");
-    println!("  let user = User{{...}};");
-    println!("  let owner2 = user;  // MOVES ownership");
-    println!("  // ❌ user is now invalid!");
-    
-    println!("\nRust alternative (borrowing):");
-    println!("  let user = User{{...}};");
-    println!("  let ref1 = &user;  // Borrow");
-    println!("  let ref2 = &user;  // Borrow");
-    println!("  let ref3 = &user;  // Borrow");
-    println!("  ✓ Multiple borrows OK");
-    println!("  ✓ Original owner still controls lifetime");
+pub fn ownership_comparison() -> DemoReport {
+    let mut report = DemoReport::new("Go vs Rust: Multiple Owners");
+
+    report.step("Go (allowed) vs Rust (not allowed)");
+    report.side_by_side(vec![
+        SideBySideRow::same("user := &User{...}", "let user = User{...};"),
+        SideBySideRow::differs("ptr1 := user  // OK - GC tracks all", "let owner2 = user;  // MOVES ownership"),
+        SideBySideRow::differs("ptr2 := user  // OK - GC tracks all", "// ❌ user is now invalid!"),
+        SideBySideRow::differs("ptr3 := user  // OK - GC tracks all", ""),
+    ]);
+
+    report.step("Go (allowed) vs Rust alternative (borrowing)");
+    report.side_by_side(vec![
+        SideBySideRow::same("user := &User{...}", "let user = User{...};"),
+        SideBySideRow::same("ptr1 := user  // OK - GC tracks all", "let ref1 = &user;  // Borrow"),
+        SideBySideRow::same("ptr2 := user  // OK - GC tracks all", "let ref2 = &user;  // Borrow"),
+        SideBySideRow::same("ptr3 := user  // OK - GC tracks all", "let ref3 = &user;  // Borrow"),
+    ]);
+    report.ok("Multiple borrows OK");
+    report.ok("Original owner still controls lifetime");
+    report
 }
 
 // Memory tracking comparison
-pub fn memory_comparison() {
-    println!("\n=== Memory Allocation Comparison ===\n");
-    
-    let objects: Vec<LargeObject> = (0..10)
-        .map(|i| LargeObject {
-            id: i,
-            data: vec![0u8; 1024],
-        })
-        .collect();
-    
-    println!("  Created 10 LargeObjects (1KB each)");
-    println!("  Total: ~10KB");
-    println!("\n  Go approach:");
-    println!("    - Escape analysis decides heap allocation");
-    println!("    - GC tracks at runtime");
-    println!("    - GC pauses to clean up");
-    println!("\n  Rust approach:");
-    println!("    - Vec<T> explicitly owns heap data");
-    println!("    - Cleaned up when 'objects' goes out of scope");
-    println!("    - NO garbage collector");
-    println!("    - NO runtime overhead");
-    
+pub fn memory_comparison() -> DemoReport {
+    let mut report = DemoReport::new("Memory Allocation Comparison");
+
+    let workload = WorkloadConfig::from_cli(OBJECT_COUNT).generate();
+    let objects = workload.build();
+
+    report.count("Objects created (sizes from the workload)", objects.len());
+    report.bytes("Total", objects.iter().map(|o| o.payload.len()).sum());
+    report.bytes("Largest", objects.iter().map(|o| o.payload.len()).max().unwrap_or(0));
+    report.text(format!("seed {} - change it with --seed / --object-count (see `run workload`)", workload.config.seed));
+
+    report.step("Go approach");
+    report.text("- Escape analysis decides heap allocation");
+    report.text("- GC tracks at runtime");
+    report.text("- GC pauses to clean up");
+
+    report.step("Rust approach");
+    report.text("- Vec<T> explicitly owns heap data");
+    report.text("- Cleaned up when 'objects' goes out of scope");
+    report.text("- NO garbage collector");
+    report.text("- NO runtime overhead");
+
     drop(objects);
     // ✓ After drop(), 'objects' is no longer accessible
     // Uncommenting this would cause a compile error:
     // println!("{:?}", objects);  // ❌ Error: borrow of moved value
-    println!("\n  ✓ Objects dropped deterministically!");
+    report.gap();
+    report.ok("Objects dropped deterministically!");
+    report
 }
 
-pub fn demonstrate_comparisons() {
-    stack_allocation();
-    heap_allocation();
-    ownership_comparison();
-    memory_comparison();
-}
+pub const DEMO: Demo = Demo {
+    name: "comparison",
+    about: "Direct Go vs Rust comparisons",
+    difficulty: Difficulty::Beginner,
+    tags: &[Tag::Ownership, Tag::Allocation],
+    examples: &[
+        Example { name: "stack", run: stack_allocation },
+        Example { name: "heap", run: heap_allocation },
+        Example { name: "ownership", run: ownership_comparison },
+        Example { name: "memory", run: memory_comparison },
+    ],
+};
 
+pub fn demonstrate_comparisons() -> Vec<DemoReport> {
+    DEMO.run()
+}
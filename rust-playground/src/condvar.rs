@@ -0,0 +1,371 @@
+// Condition variables - Go's sync.Cond vs Rust's Condvar
+// Go:   c := sync.NewCond(&mu); mu.Lock(); for !ready { c.Wait() }. The Cond
+//       remembers its Locker, but nothing ties either one to the data they
+//       guard. Most Go code never touches Cond: a channel does the waking.
+// Rust: Condvar::wait takes the MutexGuard and hands it back when it wakes,
+//       so you can only wait while holding the lock on the data you're
+//       checking. Same rule as Go: re-check in a loop (or use wait_while),
+//       because a wakeup only means "look again", not "it's ready".
+
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::registry::{Demo, Difficulty, Example, Tag};
+use crate::report::DemoReport;
+
+const CAPACITY: usize = 4;
+const PRODUCERS: usize = 2;
+const CONSUMERS: usize = 2;
+const ITEMS_PER_PRODUCER: usize = 500;
+const BENCH_ITEMS: usize = 100_000;
+
+struct Slots<T> {
+    items: VecDeque<T>,
+    closed: bool,
+}
+
+/// A fixed-size queue: push blocks while full, pop blocks while empty
+pub struct BoundedBuffer<T> {
+    slots: Mutex<Slots<T>>,
+    not_empty: Condvar,
+    not_full: Condvar,
+    capacity: usize,
+    // How often each side had to go to sleep
+    full_waits: AtomicUsize,
+    empty_waits: AtomicUsize,
+    high_water: AtomicUsize,
+}
+
+impl<T> BoundedBuffer<T> {
+    pub fn new(capacity: usize) -> Self {
+        BoundedBuffer {
+            slots: Mutex::new(Slots { items: VecDeque::with_capacity(capacity), closed: false }),
+            not_empty: Condvar::new(),
+            not_full: Condvar::new(),
+            capacity,
+            full_waits: AtomicUsize::new(0),
+            empty_waits: AtomicUsize::new(0),
+            high_water: AtomicUsize::new(0),
+        }
+    }
+
+    /// Wait for a free slot, then add `item`
+    pub fn push(&self, item: T) {
+        let mut slots = self.slots.lock().unwrap();
+        while slots.items.len() == self.capacity {
+            self.full_waits.fetch_add(1, Ordering::Relaxed);
+            slots = self.not_full.wait(slots).unwrap();
+        }
+        slots.items.push_back(item);
+        self.high_water.fetch_max(slots.items.len(), Ordering::Relaxed);
+        drop(slots);  // Wake a consumer after unlocking, so it doesn't wake into a held lock
+        self.not_empty.notify_one();
+    }
+
+    /// Wait for an item; None once the buffer is closed and drained
+    pub fn pop(&self) -> Option<T> {
+        let mut slots = self
+            .not_empty
+            .wait_while(self.slots.lock().unwrap(), |slots| {
+                let waiting = slots.items.is_empty() && !slots.closed;
+                if waiting {
+                    self.empty_waits.fetch_add(1, Ordering::Relaxed);
+                }
+                waiting
+            })
+            .unwrap();
+        let item = slots.items.pop_front();
+        drop(slots);
+        self.not_full.notify_one();
+        item
+    }
+
+    /// No more pushes: wake every consumer so they can drain and stop
+    pub fn close(&self) {
+        self.slots.lock().unwrap().closed = true;
+        self.not_empty.notify_all();
+    }
+}
+
+// The plain version: one Condvar, an unbounded queue
+pub fn producer_consumer() -> DemoReport {
+    let mut report = DemoReport::new("Mutex<VecDeque> + Condvar - Producer/Consumer");
+
+    report.code("let (queue, ready) = (Mutex::new(VecDeque::new()), Condvar::new());");
+    report.code("// producer");
+    report.code("queue.lock().unwrap().push_back(job); ready.notify_one();");
+    report.code("// consumer");
+    report.code("let mut q = queue.lock().unwrap();");
+    report.code("while q.is_empty() { q = ready.wait(q).unwrap(); }  // wait() unlocks, sleeps, relocks");
+    report.code("let job = q.pop_front();");
+
+    let shared = Arc::new((Mutex::new(VecDeque::new()), Condvar::new()));
+    let consumer = {
+        let shared = Arc::clone(&shared);
+        thread::spawn(move || {
+            let (queue, ready) = &*shared;
+            let mut received = Vec::new();
+            while received.len() < 5 {
+                let mut q = queue.lock().unwrap();
+                while q.is_empty() {
+                    q = ready.wait(q).unwrap();
+                }
+                received.push(q.pop_front().unwrap());
+            }
+            received
+        })
+    };
+    for job in 1..=5 {
+        let (queue, ready) = &*shared;
+        queue.lock().unwrap().push_back(job * 10);
+        ready.notify_one();
+        thread::sleep(Duration::from_millis(1));
+    }
+    let received = consumer.join().unwrap();
+    report.text(format!("consumer received {:?}", received));
+
+    report.gap();
+    report.ok("wait() takes the guard by value: you can't wait without holding the lock");
+    report.ok("It returns the guard re-locked, so the check and the pop see the same state");
+    report.text("The Mutex and Condvar travel together, usually as one Arc<(Mutex<T>, Condvar)>");
+
+    report.step("Go");
+    report.code("mu.Lock(); for len(q) == 0 { cond.Wait() }; job := q[0]; q = q[1:]; mu.Unlock()");
+    report.text("Same loop - but nothing stops code elsewhere touching q without mu");
+    report
+}
+
+// Why the loop: a wakeup is a hint, not a promise
+pub fn spurious_wakeups() -> DemoReport {
+    let mut report = DemoReport::new("Spurious Wakeups - Why It's `while`, Not `if`");
+
+    const WAITERS: usize = 4;
+    // (items, threads currently asleep), "an item arrived", "one more is asleep"
+    let shared = Arc::new((Mutex::new((0usize, 0usize)), Condvar::new(), Condvar::new()));
+    let woken_for_nothing = Arc::new(AtomicUsize::new(0));
+    let handles: Vec<_> = (0..WAITERS)
+        .map(|_| {
+            let (shared, woken_for_nothing) = (Arc::clone(&shared), Arc::clone(&woken_for_nothing));
+            thread::spawn(move || {
+                let (state, cond, asleep) = &*shared;
+                let mut guard = state.lock().unwrap();
+                guard.1 += 1;
+                asleep.notify_one();
+                while guard.0 == 0 {
+                    guard = cond.wait(guard).unwrap();
+                    if guard.0 == 0 {
+                        woken_for_nothing.fetch_add(1, Ordering::Relaxed);  // An `if` would have gone on with no item
+                    }
+                }
+                guard.0 -= 1;
+                guard.1 -= 1;
+            })
+        })
+        .collect();
+
+    // Wait until all of them are asleep, then hand out one item to everybody
+    let (state, cond, asleep) = &*shared;
+    let mut guard = asleep.wait_while(state.lock().unwrap(), |(_, asleep)| *asleep < WAITERS).unwrap();
+    guard.0 = 1;
+    drop(guard);
+    cond.notify_all();
+    thread::sleep(Duration::from_millis(20));
+    // The rest one at a time, so nobody is left waiting
+    for _ in 1..WAITERS {
+        state.lock().unwrap().0 += 1;
+        cond.notify_one();
+    }
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    report.count("threads waiting", WAITERS);
+    report.text("notify_all() with ONE item: every thread wakes, one of them gets it");
+    report.count("wakeups that found nothing to take", woken_for_nothing.load(Ordering::Relaxed));
+    report.text("Plus true spurious wakeups: the OS may return from wait() with no notify at all");
+
+    report.gap();
+    report.code("if q.is_empty() { q = cv.wait(q).unwrap(); }     // ✗ may pop from an empty queue");
+    report.code("while q.is_empty() { q = cv.wait(q).unwrap(); }  // ✓ re-check after every wakeup");
+    report.code("let q = cv.wait_while(q, |q| q.is_empty()).unwrap();  // ✓ the loop, built in");
+    report.code("cv.wait_timeout_while(q, Duration::from_millis(50), |q| q.is_empty())  // ✓ ...and give up");
+    report.ok("wait_while can't be written with the `if` bug");
+    report.warn("notify_all wakes everyone to fight over one item - notify_one when one waiter is enough");
+
+    report.step("Go");
+    report.text("sync.Cond documents the same rule: \"Wait cannot return unless awoken by Broadcast or Signal\",");
+    report.text("but another goroutine can get there first - so the Go idiom is also `for !condition { c.Wait() }`");
+    report
+}
+
+// The worked example: a bounded buffer with two condition variables
+pub fn bounded_buffer() -> DemoReport {
+    let mut report = DemoReport::new("Bounded Buffer - Two Condvars, One Mutex");
+
+    report.code("struct BoundedBuffer<T> { slots: Mutex<Slots<T>>, not_empty: Condvar, not_full: Condvar, capacity }");
+    report.code("push: while full { slots = not_full.wait(slots) }   push_back   not_empty.notify_one()");
+    report.code("pop:  not_empty.wait_while(slots, |s| s.items.is_empty() && !s.closed)   pop_front   not_full.notify_one()");
+
+    let buffer = Arc::new(BoundedBuffer::new(CAPACITY));
+    let producers: Vec<_> = (0..PRODUCERS)
+        .map(|producer| {
+            let buffer = Arc::clone(&buffer);
+            thread::spawn(move || {
+                for i in 0..ITEMS_PER_PRODUCER {
+                    buffer.push(producer * ITEMS_PER_PRODUCER + i);
+                }
+            })
+        })
+        .collect();
+    let consumers: Vec<_> = (0..CONSUMERS)
+        .map(|_| {
+            let buffer = Arc::clone(&buffer);
+            thread::spawn(move || {
+                let mut taken = Vec::new();
+                while let Some(item) = buffer.pop() {
+                    taken.push(item);
+                }
+                taken
+            })
+        })
+        .collect();
+    for producer in producers {
+        producer.join().unwrap();
+    }
+    buffer.close();
+    let mut all: Vec<usize> = consumers.into_iter().flat_map(|consumer| consumer.join().unwrap()).collect();
+    all.sort_unstable();
+
+    report.count("capacity", CAPACITY);
+    report.count("items pushed", PRODUCERS * ITEMS_PER_PRODUCER);
+    report.count("items popped", all.len());
+    report.count("most items ever queued", buffer.high_water.load(Ordering::Relaxed));
+    report.count("producer waits (buffer full)", buffer.full_waits.load(Ordering::Relaxed));
+    report.count("consumer waits (buffer empty)", buffer.empty_waits.load(Ordering::Relaxed));
+    if all.iter().copied().eq(0..PRODUCERS * ITEMS_PER_PRODUCER) {
+        report.ok("Every item arrived exactly once");
+    } else {
+        report.fail("Items lost or duplicated");
+    }
+
+    report.gap();
+    report.ok("Two Condvars: producers wait on not_full, consumers on not_empty - nobody wakes the wrong side");
+    report.ok("close() + notify_all() is the shutdown: consumers drain what's left, then pop() returns None");
+    report.text("Notifying after drop(guard) saves the woken thread from blocking on the lock straight away");
+
+    report.step("Go");
+    report.code("notFull, notEmpty := sync.NewCond(&mu), sync.NewCond(&mu)  // same design, same two conds");
+    report.code("buf := make(chan int, 4)  // ...or the one-liner everyone actually writes");
+    report
+}
+
+// The same buffer as a sync_channel, and what each costs
+pub fn versus_channels() -> DemoReport {
+    let mut report = DemoReport::new("Condvar vs Channel - The Same Bounded Buffer");
+
+    report.code("let (tx, rx) = mpsc::sync_channel(4);  // full → send blocks, empty → recv blocks");
+    report.code("drop(tx);  // close: recv() returns Err once drained");
+
+    let start = Instant::now();
+    let buffer = Arc::new(BoundedBuffer::new(CAPACITY));
+    let producer = {
+        let buffer = Arc::clone(&buffer);
+        thread::spawn(move || {
+            for i in 0..BENCH_ITEMS {
+                buffer.push(i);
+            }
+            buffer.close();
+        })
+    };
+    let mut condvar_sum = 0;
+    while let Some(item) = buffer.pop() {
+        condvar_sum += item;
+    }
+    producer.join().unwrap();
+    let condvar_time = start.elapsed();
+
+    let start = Instant::now();
+    let (tx, rx) = mpsc::sync_channel(CAPACITY);
+    let producer = thread::spawn(move || {
+        for i in 0..BENCH_ITEMS {
+            tx.send(i).unwrap();
+        }
+    });
+    let channel_sum: usize = rx.iter().sum();
+    producer.join().unwrap();
+    let channel_time = start.elapsed();
+
+    report.count("items through a 4-slot buffer", BENCH_ITEMS);
+    report.table(
+        &["Buffer", "Time", "ns / item", "Sum matches"],
+        [("Mutex + 2 Condvars", condvar_time, condvar_sum), ("mpsc::sync_channel(4)", channel_time, channel_sum)]
+            .iter()
+            .map(|(name, elapsed, sum)| {
+                vec![
+                    name.to_string(),
+                    format!("{:.1?}", elapsed),
+                    format!("{:.0}", elapsed.as_nanos() as f64 / BENCH_ITEMS as f64),
+                    (*sum == (0..BENCH_ITEMS).sum::<usize>()).to_string(),
+                ]
+            })
+            .collect(),
+    );
+
+    report.gap();
+    report.ok("The channel is a bounded buffer someone else debugged - reach for it first");
+    report.text("Condvar earns its keep when the condition isn't \"queue has room\": a batch is complete,");
+    report.text("a counter hit zero, a state machine reached a phase - or several conditions share one lock");
+    if cfg!(debug_assertions) {
+        report.warn("Debug build - run with --release for representative numbers");
+    }
+
+    report.step("Go");
+    report.text("Same advice in Go: buffered channels for queues, sync.Cond only for broadcast-style waits");
+    report
+}
+
+pub fn condvar_vs_go() -> DemoReport {
+    let mut report = DemoReport::new("Waiting for a Condition: Go vs Rust");
+
+    report.table(
+        &["", "Go", "Rust"],
+        vec![
+            vec!["create".into(), "sync.NewCond(&mu)".into(), "Condvar::new() next to a Mutex<T>".into()],
+            vec!["wait".into(), "c.Wait() (must hold c.L)".into(), "cv.wait(guard) - takes the guard".into()],
+            vec!["re-check loop".into(), "for !cond { c.Wait() }".into(), "while / wait_while".into()],
+            vec!["wake one / all".into(), "Signal / Broadcast".into(), "notify_one / notify_all".into()],
+            vec!["timeout".into(), "none (use a channel + select)".into(), "wait_timeout / wait_timeout_while".into()],
+            vec!["lock misuse".into(), "Wait without the lock: fatal error at runtime".into(), "can't be written".into()],
+            vec!["usual alternative".into(), "buffered channel".into(), "mpsc::sync_channel".into()],
+        ],
+    );
+
+    report.text("Checked by tests/compile_fail/condvar_wait_without_lock.rs");
+
+    report.gap();
+    report.ok("Rust's version ties the condition, its lock and its data together in the types");
+    report.text("Both languages agree on the rest: loop on the condition, prefer channels when they fit");
+    report
+}
+
+pub const DEMO: Demo = Demo {
+    name: "condvar",
+    about: "Condvar producer/consumer, spurious wakeups and a bounded buffer vs channels and Go's sync.Cond",
+    difficulty: Difficulty::Intermediate,
+    tags: &[Tag::Concurrency],
+    examples: &[
+        Example { name: "producer-consumer", run: producer_consumer },
+        Example { name: "spurious", run: spurious_wakeups },
+        Example { name: "bounded-buffer", run: bounded_buffer },
+        Example { name: "vs-channels", run: versus_channels },
+        Example { name: "vs-go", run: condvar_vs_go },
+    ],
+};
+
+pub fn demonstrate_condvar() -> Vec<DemoReport> {
+    DEMO.run()
+}
@@ -0,0 +1,259 @@
+// Contention - what a shared counter costs as threads are added
+// The same workload (TOTAL_INCREMENTS increments, split evenly across the
+// threads) runs behind Arc<Mutex>, Arc<RwLock> and an AtomicU64 with 1, 2,
+// 4, 8 and 16 threads. Perfect scaling would halve the time per doubling;
+// with one shared cache line it usually gets slower instead.
+//
+// Go has the same curve: sync.Mutex, sync.RWMutex and atomic.Int64 all
+// serialize on one word. Run in release for meaningful numbers
+// (make contention).
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Barrier, Mutex, RwLock};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::registry::{Demo, Difficulty, Example, Tag};
+use crate::report::DemoReport;
+
+pub const THREAD_COUNTS: [usize; 5] = [1, 2, 4, 8, 16];
+pub const TOTAL_INCREMENTS: usize = 1_600_000;
+
+/// How the counter is shared
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Strategy {
+    Mutex,
+    RwLock,
+    Atomic,
+    /// Count privately, publish once per thread - the contention-free baseline
+    Local,
+}
+
+impl Strategy {
+    pub const ALL: [Strategy; 4] = [Strategy::Mutex, Strategy::RwLock, Strategy::Atomic, Strategy::Local];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Strategy::Mutex => "Arc<Mutex<u64>>",
+            Strategy::RwLock => "Arc<RwLock<u64>>",
+            Strategy::Atomic => "Arc<AtomicU64>",
+            Strategy::Local => "local + 1 fetch_add",
+        }
+    }
+}
+
+/// One cell of the scaling table
+#[derive(Debug, Clone, Copy)]
+pub struct Measurement {
+    pub strategy: Strategy,
+    pub threads: usize,
+    pub increments: usize,
+    pub elapsed: Duration,
+    /// Final counter value - always equals `increments`
+    pub total: u64,
+}
+
+impl Measurement {
+    pub fn ns_per_increment(&self) -> f64 {
+        self.elapsed.as_nanos() as f64 / self.increments as f64
+    }
+
+    pub fn increments_per_sec(&self) -> f64 {
+        self.increments as f64 / self.elapsed.as_secs_f64()
+    }
+}
+
+// Starts every worker together; wall time from the first worker starting
+// to the last one finishing (each worker reads the clock itself, so a main
+// thread that is slow to wake up can't miss the work)
+pub(crate) fn timed(threads: usize, work: impl Fn() + Send + Sync + 'static) -> Duration {
+    let work = Arc::new(work);
+    let start_line = Arc::new(Barrier::new(threads));
+    let handles: Vec<_> = (0..threads)
+        .map(|_| {
+            let (work, start_line) = (Arc::clone(&work), Arc::clone(&start_line));
+            thread::spawn(move || {
+                start_line.wait();
+                let start = Instant::now();
+                work();
+                (start, Instant::now())
+            })
+        })
+        .collect();
+    let spans: Vec<(Instant, Instant)> = handles.into_iter().map(|handle| handle.join().unwrap()).collect();
+    let first_start = spans.iter().map(|span| span.0).min().unwrap();
+    let last_end = spans.iter().map(|span| span.1).max().unwrap();
+    last_end - first_start
+}
+
+/// Run `increments` increments split across `threads` threads
+pub fn run(strategy: Strategy, threads: usize, increments: usize) -> Measurement {
+    let threads = threads.max(1);
+    let per_thread = increments / threads;
+    let increments = per_thread * threads;
+
+    let (elapsed, total) = match strategy {
+        Strategy::Mutex => {
+            let counter = Arc::new(Mutex::new(0u64));
+            let shared = Arc::clone(&counter);
+            let elapsed = timed(threads, move || {
+                for _ in 0..per_thread {
+                    *shared.lock().unwrap() += 1;
+                }
+            });
+            (elapsed, *counter.lock().unwrap())
+        }
+        Strategy::RwLock => {
+            let counter = Arc::new(RwLock::new(0u64));
+            let shared = Arc::clone(&counter);
+            let elapsed = timed(threads, move || {
+                for _ in 0..per_thread {
+                    *shared.write().unwrap() += 1;
+                }
+            });
+            (elapsed, *counter.read().unwrap())
+        }
+        Strategy::Atomic => {
+            let counter = Arc::new(AtomicU64::new(0));
+            let shared = Arc::clone(&counter);
+            let elapsed = timed(threads, move || {
+                for _ in 0..per_thread {
+                    shared.fetch_add(1, Ordering::Relaxed);
+                }
+            });
+            (elapsed, counter.load(Ordering::Relaxed))
+        }
+        Strategy::Local => {
+            let counter = Arc::new(AtomicU64::new(0));
+            let shared = Arc::clone(&counter);
+            let elapsed = timed(threads, move || {
+                let mut local = 0u64;
+                for _ in 0..per_thread {
+                    local = std::hint::black_box(local + 1);
+                }
+                shared.fetch_add(local, Ordering::Relaxed);
+            });
+            (elapsed, counter.load(Ordering::Relaxed))
+        }
+    };
+    Measurement { strategy, threads, increments, elapsed, total }
+}
+
+/// Every strategy at every thread count
+pub fn bench(strategies: &[Strategy], thread_counts: &[usize], increments: usize) -> Vec<Measurement> {
+    strategies
+        .iter()
+        .flat_map(|&strategy| thread_counts.iter().map(move |&threads| run(strategy, threads, increments)))
+        .collect()
+}
+
+fn cpus() -> usize {
+    thread::available_parallelism().map_or(1, |n| n.get())
+}
+
+// ns per increment: rows are thread counts, columns are strategies
+pub fn scaling_table() -> DemoReport {
+    let mut report = DemoReport::new("Shared Counter Scaling - ns per Increment");
+
+    report.count("Increments per run", TOTAL_INCREMENTS);
+    report.count("CPUs available", cpus());
+    if cfg!(debug_assertions) {
+        report.warn("Debug build - use make contention (release) for real numbers");
+    }
+
+    let results = bench(&Strategy::ALL, &THREAD_COUNTS, TOTAL_INCREMENTS);
+    let mut header = vec!["Threads"];
+    header.extend(Strategy::ALL.iter().map(|s| s.name()));
+    let rows = THREAD_COUNTS
+        .iter()
+        .map(|&threads| {
+            let mut row = vec![threads.to_string()];
+            row.extend(Strategy::ALL.iter().map(|&strategy| {
+                results
+                    .iter()
+                    .find(|m| m.strategy == strategy && m.threads == threads)
+                    .map_or_else(String::new, |m| format!("{:.1}", m.ns_per_increment()))
+            }));
+            row
+        })
+        .collect();
+    report.table(&header, rows);
+
+    let lost = results.iter().filter(|m| m.total != m.increments as u64).count();
+    report.gap();
+    if lost == 0 {
+        report.ok("Every run counted exactly right - correctness was never the question");
+    } else {
+        report.fail(format!("{} runs lost increments", lost));
+    }
+    report.text("The question is cost: every increment fights for one cache line");
+    report
+}
+
+// Each strategy relative to its own single-thread run
+pub fn slowdown() -> DemoReport {
+    let mut report = DemoReport::new("Adding Threads - Speedup (or Slowdown) vs 1 Thread");
+
+    let strategies = [Strategy::Mutex, Strategy::Atomic, Strategy::Local];
+    let results = bench(&strategies, &THREAD_COUNTS, TOTAL_INCREMENTS);
+    let rows = strategies
+        .iter()
+        .map(|&strategy| {
+            let runs: Vec<_> = results.iter().filter(|m| m.strategy == strategy).collect();
+            let base = runs[0].elapsed.as_secs_f64();
+            let mut row = vec![strategy.name().to_string()];
+            row.extend(runs.iter().map(|m| format!("{:.2}x", base / m.elapsed.as_secs_f64())));
+            row
+        })
+        .collect();
+    let header: Vec<String> = THREAD_COUNTS.iter().map(|threads| format!("{} thr", threads)).collect();
+    let mut header: Vec<&str> = header.iter().map(String::as_str).collect();
+    header.insert(0, "Strategy");
+    report.table(&header, rows);
+
+    report.gap();
+    report.text(">1x = more threads finished the same work faster");
+    report.warn("Mutex and atomic rarely beat 1x: the counter is a serial bottleneck");
+    report.ok("Local counting scales until threads outnumber CPUs - nothing is shared");
+    if cpus() == 1 {
+        report.warn("Only one CPU here: threads take turns, so nothing can scale");
+    }
+    report
+}
+
+pub fn contention_vs_go() -> DemoReport {
+    let mut report = DemoReport::new("Contention in Go and Rust");
+
+    report.step("Go");
+    report.code("func BenchmarkMutex(b *testing.B) {");
+    report.code("    var mu sync.Mutex; var n int64");
+    report.code("    b.RunParallel(func(pb *testing.PB) { for pb.Next() { mu.Lock(); n++; mu.Unlock() } })");
+    report.code("}");
+    report.text("go test -bench . -cpu 1,2,4,8,16 prints the same kind of table");
+    report.text("sync.Mutex spins briefly, then parks goroutines; starvation mode after 1ms");
+
+    report.step("Rust");
+    report.text("std::sync::Mutex is a futex: a short spin, then the OS parks the thread");
+    report.text("Threads are OS threads - 16 threads on 4 CPUs means context switches");
+
+    report.gap();
+    report.ok("Same fix in both languages: don't share the hot counter");
+    report.text("Shard it (one slot per thread/goroutine), batch updates, or send results over a channel");
+    report
+}
+
+pub const DEMO: Demo = Demo {
+    name: "contention-bench",
+    about: "Shared-counter contention: Mutex, RwLock and atomics at 1-16 threads",
+    difficulty: Difficulty::Advanced,
+    tags: &[Tag::Concurrency, Tag::Benchmark],
+    examples: &[
+        Example { name: "table", run: scaling_table },
+        Example { name: "slowdown", run: slowdown },
+        Example { name: "vs-go", run: contention_vs_go },
+    ],
+};
+
+pub fn demonstrate_contention_bench() -> Vec<DemoReport> {
+    DEMO.run()
+}
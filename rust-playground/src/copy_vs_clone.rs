@@ -0,0 +1,228 @@
+// Copy vs Clone - when `let b = a` leaves `a` usable
+// Go:   assignment always copies the value, shallowly. Copy a struct that
+//       holds a slice, a map or a pointer and both copies share what it
+//       points to; a deep copy is code you write (or copy/append/maps.Clone).
+// Rust: assignment always moves the bytes too - the difference is whether
+//       the source stays usable. Types marked Copy (integers, floats, &T,
+//       small plain structs that derive it) do; everything else is moved and
+//       the source is gone. Duplicating an owner is an explicit .clone(),
+//       which for String and Vec means a new heap buffer.
+
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::sync::Arc;
+
+use crate::alloc_stats;
+use crate::registry::{Demo, Difficulty, Example, Tag};
+use crate::report::{DemoReport, SideBySideRow};
+
+/// Small and plain: every field is Copy, so the struct can be
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Point {
+    pub x: f64,
+    pub y: f64,
+}
+
+/// Owns a heap buffer: Clone only
+#[derive(Debug, Clone, PartialEq)]
+pub struct Polyline {
+    pub name: String,
+    pub points: Vec<Point>,
+}
+
+fn nudge(mut point: Point) -> Point {
+    point.x += 1.0;  // Changes the callee's copy
+    point
+}
+
+// Assignment and argument passing copy Copy types, move everything else
+pub fn implicit_copies() -> DemoReport {
+    let mut report = DemoReport::new("Implicit Copies vs Moves");
+
+    report.code("#[derive(Clone, Copy)] struct Point { x: f64, y: f64 }");
+    let a = Point { x: 1.0, y: 2.0 };
+    let b = a;  // Copy: a is still usable
+    let moved = nudge(a);  // ...and again
+    report.text(format!("let b = a;  nudge(a);  → a = {:?}, b = {:?}, nudged = {:?}", a, b, moved));
+    report.address("a", &a, format!("{:?}", a));
+    report.address("b", &b, "its own 16 bytes");
+
+    report.step("A String moves the same 24 bytes");
+    let s1 = String::from("hello");
+    let buffer = s1.as_ptr() as usize;
+    report.code("let s1 = String::from(\"hello\");  let s2 = s1;");
+    let s2 = s1;
+    report.address("s2's heap buffer", s2.as_str(), &s2);
+    report.text(format!("Same buffer as s1 had ({}): only ptr, capacity, len were copied", if s2.as_ptr() as usize == buffer { "yes" } else { "no" }));
+    report.code("println!(\"{}\", s1);  // ❌ error[E0382]: borrow of moved value: `s1`");
+    report.text("Checked by tests/compile_fail/borrow_after_move.rs");
+
+    report.gap();
+    report.ok("Copy and move are both a memcpy of the inline bytes - Copy just keeps the source alive");
+    report.text("No copy constructors: neither can run your code. Clone is the one that can");
+    report
+}
+
+fn copy_row<T: Copy>(name: &str, note: &str) -> Vec<String> {
+    vec![name.to_string(), std::mem::size_of::<T>().to_string(), "Copy + Clone".to_string(), note.to_string()]
+}
+
+fn clone_row<T: Clone>(name: &str, note: &str) -> Vec<String> {
+    vec![name.to_string(), std::mem::size_of::<T>().to_string(), "Clone only".to_string(), note.to_string()]
+}
+
+// Which types are which - the bound on each row's helper proves the column
+pub fn derive_rules() -> DemoReport {
+    let mut report = DemoReport::new("Deriving Copy and Clone");
+
+    report.code("fn copy_row<T: Copy>(..)   fn clone_row<T: Clone>(..)  // each row compiles only if the column is true");
+    report.table(
+        &["type", "size", "traits", "why"],
+        vec![
+            copy_row::<u64>("u64", "plain bits"),
+            copy_row::<char>("char", "plain bits"),
+            copy_row::<Point>("Point", "#[derive(Clone, Copy)], all fields Copy"),
+            copy_row::<&String>("&String", "sharing a shared borrow is fine"),
+            copy_row::<(u8, f32)>("(u8, f32)", "tuples of Copy are Copy"),
+            copy_row::<Option<Point>>("Option<Point>", "enums too"),
+            copy_row::<[u8; 4096]>("[u8; 4096]", "Copy, but 4 KiB per implicit copy"),
+            vec!["&mut String".into(), std::mem::size_of::<&mut String>().to_string(), "neither".into(), "two &mut would alias: moved or reborrowed".into()],
+            clone_row::<String>("String", "owns a heap buffer"),
+            clone_row::<Vec<Point>>("Vec<Point>", "owns a heap buffer"),
+            clone_row::<Polyline>("Polyline", "has a String field"),
+            clone_row::<Rc<String>>("Rc<String>", "clone bumps a count; Copy couldn't"),
+        ],
+    );
+
+    report.step("Copy is a promise, Clone is code");
+    report.code("#[derive(Clone, Copy)]  // Copy: a marker, no methods - it's always a bitwise copy");
+    report.code("impl Clone for Polyline { fn clone(&self) -> Self { .. } }  // derive writes this: clone each field");
+    report.text("Copy requires Clone (a Copy type's clone() must be *self), never the other way round");
+
+    report.gap();
+    report.ok("derive(Copy) works when every field is Copy and the type has no Drop");
+    report.warn("Deriving Copy is part of the API: removing it later breaks every caller that relied on it");
+    report
+}
+
+// A bitwise copy of an owner would free its buffer twice
+pub fn why_not_copy() -> DemoReport {
+    let mut report = DemoReport::new("Why String and Vec Can't Be Copy");
+
+    report.code("#[derive(Clone, Copy)]");
+    report.code("struct Polyline { name: String, points: Vec<Point> }");
+    report.code("// ❌ error[E0204]: the trait `Copy` cannot be implemented for this type");
+    report.text("Checked by tests/compile_fail/derive_copy_with_string.rs");
+
+    report.step("What a bitwise copy would do");
+    let original = Polyline { name: "route".to_string(), points: vec![Point { x: 0.0, y: 0.0 }; 3] };
+    let copy = original.clone();
+    report.table(
+        &["", "points buffer", "name buffer"],
+        vec![
+            vec!["original".into(), format!("{:#x}", original.points.as_ptr() as usize), format!("{:#x}", original.name.as_ptr() as usize)],
+            vec!["bitwise copy (if it compiled)".into(), format!("{:#x}", original.points.as_ptr() as usize), format!("{:#x}", original.name.as_ptr() as usize)],
+            vec![".clone()".into(), format!("{:#x}", copy.points.as_ptr() as usize), format!("{:#x}", copy.name.as_ptr() as usize)],
+        ],
+    );
+    report.fail("Two owners of one buffer: both would free it when dropped - a double free");
+    report.ok(".clone() gives the copy its own buffers; each owner frees its own");
+    report.text("That's also why a type with Drop can't be Copy (E0184): Copy values are never dropped");
+
+    report.gap();
+    report.ok("Copy = \"duplicating the bytes duplicates the value\" - false for anything that owns");
+    report
+}
+
+fn cost_of<R>(f: impl FnOnce() -> R) -> (String, String) {
+    let (_, stats) = alloc_stats::measure(f);
+    match stats {
+        Some(stats) => (stats.allocations.to_string(), stats.bytes_allocated.to_string()),
+        None => ("n/a".to_string(), "n/a (needs --features alloc-stats)".to_string()),
+    }
+}
+
+// What one .clone() costs, counted by the allocator hook
+pub fn clone_cost() -> DemoReport {
+    let mut report = DemoReport::new("What .clone() Costs");
+
+    let point = Point { x: 1.0, y: 2.0 };
+    let name = "a route through town".to_string();
+    let points = vec![point; 1000];
+    let names: Vec<String> = (0..100).map(|i| format!("stop {}", i)).collect();
+    let table: HashMap<String, Vec<Point>> = (0..10).map(|i| (format!("route {}", i), vec![point; 10])).collect();
+    let shared = Rc::new(points.clone());
+    let across_threads = Arc::new(points.clone());
+
+    let rows = vec![
+        ("Point (Copy)", cost_of(|| std::hint::black_box(point)), "16 bytes copied"),
+        ("String (20 chars)", cost_of(|| name.clone()), "one buffer"),
+        ("Vec<Point> × 1000", cost_of(|| points.clone()), "one buffer, a memcpy"),
+        ("Vec<String> × 100", cost_of(|| names.clone()), "the Vec + every String"),
+        ("HashMap<String, Vec<Point>> × 10", cost_of(|| table.clone()), "table + every key + every value"),
+        ("Rc<Vec<Point>>", cost_of(|| Rc::clone(&shared)), "a count += 1"),
+        ("Arc<Vec<Point>>", cost_of(|| Arc::clone(&across_threads)), "an atomic count += 1"),
+    ];
+    report.summary_table(
+        &["clone of", "allocations", "bytes", "what gets copied"],
+        rows.into_iter().map(|(what, (allocations, bytes), note)| vec![what.to_string(), allocations, bytes, note.to_string()]).collect(),
+    );
+
+    report.gap();
+    report.ok("Clone is deep for owners: the cost grows with everything the value owns");
+    report.ok("Rc/Arc::clone shares instead - write Rc::clone(&x) so readers see it's cheap");
+    report.warn("A .clone() to silence the borrow checker is a real allocation; look for a borrow first");
+    report
+}
+
+pub fn copy_vs_go() -> DemoReport {
+    let mut report = DemoReport::new("Assignment: Go vs Rust");
+
+    report.side_by_side(vec![
+        SideBySideRow::same("b := a  // Point: a full copy", "let b = a;  // Point is Copy: a full copy"),
+        SideBySideRow::differs("q := p  // Polyline: shares p.Points", "let q = p;  // moved: p is gone"),
+        SideBySideRow::differs("q.Points[0].X = 9  // p sees it too", "let mut q = p.clone();  // deep: own buffers"),
+        SideBySideRow::differs("q.Points = append(q.Points, pt)  // maybe shared, maybe not", "q.points.push(pt);  // only q changes"),
+        SideBySideRow::differs("r := slices.Clone(p.Points)  // deep copy by hand", "let r = p.points.clone();"),
+    ]);
+
+    report.step("The aliasing Go allows, done in Rust");
+    let p = Polyline { name: "p".to_string(), points: vec![Point { x: 0.0, y: 0.0 }; 2] };
+    let mut q = p.clone();
+    q.points[0].x = 9.0;
+    report.code("let mut q = p.clone();  q.points[0].x = 9.0;");
+    report.text(format!("p.points[0].x = {}, q.points[0].x = {}", p.points[0].x, q.points[0].x));
+    report.text("Sharing on purpose is spelled Rc<RefCell<..>> / Arc<Mutex<..>> - never an accident of assignment");
+
+    report.table(
+        &["", "Go", "Rust"],
+        vec![
+            vec!["`b = a` on a struct".into(), "shallow copy, always".into(), "move, or copy if Copy".into()],
+            vec!["source afterwards".into(), "still usable".into(), "usable only if Copy".into()],
+            vec!["deep copy".into(), "by hand (slices.Clone, maps.Clone)".into(), ".clone() (derive(Clone))".into()],
+            vec!["cost visible in code".into(), "no - every copy looks alike".into(), "yes - .clone() is spelled out".into()],
+        ],
+    );
+
+    report.gap();
+    report.ok("Go's shallow copy shares without saying so; Rust either moves, copies plain bits, or clones");
+    report
+}
+
+pub const DEMO: Demo = Demo {
+    name: "copy-vs-clone",
+    about: "Copy vs Clone: implicit copies vs moves, deriving them, why String can't be Copy, .clone() costs counted vs Go's shallow assignment",
+    difficulty: Difficulty::Beginner,
+    tags: &[Tag::Ownership, Tag::Allocation],
+    examples: &[
+        Example { name: "implicit-copies", run: implicit_copies },
+        Example { name: "derive", run: derive_rules },
+        Example { name: "why-not-copy", run: why_not_copy },
+        Example { name: "clone-cost", run: clone_cost },
+        Example { name: "vs-go", run: copy_vs_go },
+    ],
+};
+
+pub fn demonstrate_copy_vs_clone() -> Vec<DemoReport> {
+    DEMO.run()
+}
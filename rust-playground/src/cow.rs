@@ -0,0 +1,172 @@
+// Cow<str> - borrow when you can, allocate only when you must
+// Cow = Clone on Write: either Borrowed(&'a str) or Owned(String).
+// A function that USUALLY returns its input unchanged can skip the
+// allocation on that path and still return an owned value when it edits.
+// Go: strings are immutable; strings.TrimSpace/ToLower may return the
+// input (a shared header) but any edit builds a new string.
+
+use std::borrow::Cow;
+
+use crate::alloc_stats;
+use crate::registry::{Demo, Difficulty, Example, Tag};
+use crate::report::DemoReport;
+
+// Trim, lowercase and collapse inner whitespace - only allocate if something changes
+pub fn normalize_username(input: &str) -> Cow<'_, str> {
+    let trimmed = input.trim();  // Sub-slice: never allocates
+    let needs_work = trimmed.chars().any(|c| c.is_uppercase())
+        || trimmed.split_whitespace().count() > 1;
+
+    if !needs_work {
+        return Cow::Borrowed(trimmed);
+    }
+
+    let words: Vec<String> = trimmed.split_whitespace().map(str::to_lowercase).collect();
+    Cow::Owned(words.join("_"))
+}
+
+// Escape HTML only if there's something to escape
+fn escape_html(input: &str) -> Cow<'_, str> {
+    if !input.contains(['<', '>', '&']) {
+        return Cow::Borrowed(input);
+    }
+    let mut escaped = String::with_capacity(input.len() + 16);
+    for c in input.chars() {
+        match c {
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '&' => escaped.push_str("&amp;"),
+            _ => escaped.push(c),
+        }
+    }
+    Cow::Owned(escaped)
+}
+
+// Needs the Cow itself, not the str it derefs to
+#[allow(clippy::ptr_arg)]
+fn kind(value: &Cow<'_, str>) -> &'static str {
+    match value {
+        Cow::Borrowed(_) => "Borrowed",
+        Cow::Owned(_) => "Owned",
+    }
+}
+
+fn allocations_of(f: impl FnOnce()) -> String {
+    match alloc_stats::measure(f).1 {
+        Some(stats) => stats.allocations.to_string(),
+        None => "n/a".to_string(),
+    }
+}
+
+// Normalizing user input: most input is already clean
+pub fn normalize_input() -> DemoReport {
+    let mut report = DemoReport::new("Cow<str> - Normalizing User Input");
+
+    let inputs = ["gopher", "  gopher  ", "Gopher", "rusty  gopher", "  Rusty Gopher "];
+    let rows = inputs
+        .iter()
+        .map(|&input| {
+            let result = normalize_username(input);
+            let allocations = allocations_of(|| drop(normalize_username(input)));
+            vec![format!("{:?}", input), format!("{:?}", result), kind(&result).to_string(), allocations]
+        })
+        .collect();
+    report.table(&["input", "normalized", "Cow", "allocations"], rows);
+    if !alloc_stats::enabled() {
+        report.text("(allocation counts need --features alloc-stats)");
+    }
+
+    let clean = "gopher";
+    if let Cow::Borrowed(view) = normalize_username(clean) {
+        report.gap();
+        report.address("input", clean, "caller's string");
+        report.address("Borrowed result", view, "same bytes, zero copies");
+    }
+
+    report.gap();
+    report.ok("Clean input → Cow::Borrowed: 0 allocations");
+    report.ok("Dirty input → Cow::Owned: allocates exactly when it has to");
+    report.ok("Callers just use it as &str (Cow<str> derefs to str)");
+
+    report.step("Go");
+    report.code("func normalize(s string) string {");
+    report.code("    s = strings.TrimSpace(s)        // slice of s, no copy");
+    report.code("    s = strings.ToLower(s)          // copies only if it changes");
+    report.code("    return strings.Join(strings.Fields(s), \"_\")  // always builds a new string");
+    report.code("}");
+    report.text("Go's stdlib has the same trick internally, but the type can't tell you");
+    report
+}
+
+// to_mut(): clone on the first write, then mutate in place
+pub fn clone_on_write() -> DemoReport {
+    let mut report = DemoReport::new("to_mut() - The Clone Happens on First Write");
+
+    let original = String::from("config: default");
+    let mut value: Cow<str> = Cow::Borrowed(&original);
+    report.text(format!("Start: {} {:?}", kind(&value), value));
+
+    value.to_mut().push_str(" + override");  // Borrowed → clones into Owned here
+    report.text(format!("After to_mut().push_str: {} {:?}", kind(&value), value));
+
+    value.to_mut().push_str(" + another");  // Already Owned → no clone
+    report.text(format!("Second write: {} {:?}", kind(&value), value));
+    report.text(format!("Original untouched: {:?}", original));
+
+    report.gap();
+    report.ok("First to_mut() clones; later ones reuse the owned String");
+    report.ok("The original is never modified - that's the borrow checker's job");
+    report.text("into_owned() gives you a String either way (clones only if Borrowed)");
+    report
+}
+
+// Escaping: the realistic "usually a no-op" transform
+pub fn escape_example() -> DemoReport {
+    let mut report = DemoReport::new("Cow in APIs - Escape Only When Needed");
+
+    for input in ["plain text", "a < b && c > d"] {
+        let escaped = escape_html(input);
+        report.text(format!("{:<16} → {} {:?}", format!("{:?}", input), kind(&escaped), escaped));
+    }
+
+    report.gap();
+    report.ok("fn escape(s: &str) -> Cow<'_, str>: the signature documents the fast path");
+    report.text("Std uses it too: String::from_utf8_lossy, Path::to_string_lossy");
+    report
+}
+
+pub fn cow_vs_go() -> DemoReport {
+    let mut report = DemoReport::new("Cow vs Go Strings");
+
+    report.table(
+        &["", "Go", "Rust"],
+        vec![
+            vec!["string type".into(), "one immutable string".into(), "&str (borrowed) / String (owned)".into()],
+            vec!["maybe-modified result".into(), "string (copy or not: hidden)".into(), "Cow<str> (visible in the type)".into()],
+            vec!["edit a shared string".into(), "build a new one".into(), "to_mut() clones once, then edits".into()],
+            vec!["who frees the copy".into(), "GC".into(), "whoever owns the Cow".into()],
+        ],
+    );
+
+    report.gap();
+    report.ok("Cow lets hot paths (already-clean input) run allocation-free");
+    report.warn("Cow<'a, str> carries a lifetime: it can't outlive its input when Borrowed");
+    report
+}
+
+pub const DEMO: Demo = Demo {
+    name: "cow",
+    about: "Cow<str> - clone only on write",
+    difficulty: Difficulty::Intermediate,
+    tags: &[Tag::Allocation, Tag::Borrowing],
+    examples: &[
+        Example { name: "normalize", run: normalize_input },
+        Example { name: "to-mut", run: clone_on_write },
+        Example { name: "escape", run: escape_example },
+        Example { name: "vs-go", run: cow_vs_go },
+    ],
+};
+
+pub fn demonstrate_cow() -> Vec<DemoReport> {
+    DEMO.run()
+}
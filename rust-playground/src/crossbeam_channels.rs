@@ -0,0 +1,342 @@
+// crossbeam - Go's select, MPMC channels and scoped threads
+// Go:   `select` waits on several channels at once, with `case <-time.After(d)`
+//       for a timeout, `default:` to not block, and a nil channel to switch a
+//       case off. Any number of goroutines may receive from one channel.
+// Rust: std::sync::mpsc has no select, and its Receiver can't be shared
+//       without Arc<Mutex<..>>. crossbeam::channel has both: select! with
+//       recv/send arms, after()/tick()/never() channels for timers and
+//       disabled cases, and a Receiver that is Clone. std's mpsc has been
+//       built on crossbeam's code since Rust 1.67, so the benchmark below is
+//       about features, not speed (--features crossbeam).
+
+use std::collections::BTreeMap;
+use std::hint::black_box;
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crossbeam::channel::{self, Receiver, after, never, select, tick};
+
+use crate::registry::{Demo, Difficulty, Example, Tag};
+use crate::report::DemoReport;
+
+/// Messages per run in the channel benchmark
+pub const MESSAGES: usize = 200_000;
+/// Producers in the multi-producer benchmark
+pub const PRODUCERS: usize = 4;
+
+fn ticker(name: &'static str, every: Duration, count: usize) -> Receiver<String> {
+    let (tx, rx) = channel::unbounded();
+    thread::spawn(move || {
+        for i in 1..=count {
+            thread::sleep(every);
+            if tx.send(format!("{} #{}", name, i)).is_err() {
+                return;
+            }
+        }
+    });
+    rx
+}
+
+// select! across two producers and a deadline
+pub fn select_example() -> DemoReport {
+    let mut report = DemoReport::new("select! - Waiting on Several Channels");
+
+    report.code("select! {");
+    report.code("    recv(fast) -> msg => ...,");
+    report.code("    recv(slow) -> msg => ...,");
+    report.code("    recv(deadline) -> _ => break,  // after(150ms): a channel that fires once");
+    report.code("}");
+
+    let fast = ticker("fast", Duration::from_millis(20), 20);
+    let slow = ticker("slow", Duration::from_millis(70), 20);
+    let deadline = after(Duration::from_millis(150));
+    let mut received: BTreeMap<&str, usize> = BTreeMap::new();
+    let mut log = vec![];
+    loop {
+        select! {
+            recv(fast) -> msg => {
+                *received.entry("fast").or_default() += 1;
+                log.push(msg.expect("the ticker outlives the deadline"));
+            }
+            recv(slow) -> msg => {
+                *received.entry("slow").or_default() += 1;
+                log.push(msg.expect("the ticker outlives the deadline"));
+            }
+            recv(deadline) -> _ => {
+                log.push("deadline".to_string());
+                break;
+            }
+        }
+    }
+    report.text(log.join(", "));
+    report.table(&["channel", "messages"], received.iter().map(|(name, count)| vec![name.to_string(), count.to_string()]).collect());
+
+    report.gap();
+    report.ok("Whichever channel is ready first wins; if several are, one is picked at random (like Go)");
+    report.ok("The timeout is just another channel - after() is Go's time.After");
+    report.text("Dropping fast/slow disconnects them: the ticker threads' next send fails and they exit");
+
+    report.step("Go");
+    report.code("for {");
+    report.code("    select {");
+    report.code("    case m := <-fast:  ...");
+    report.code("    case m := <-slow:  ...");
+    report.code("    case <-deadline:   return  // deadline := time.After(150 * time.Millisecond)");
+    report.code("    }");
+    report.code("}");
+    report
+}
+
+// default, timeouts, tick and never - the rest of Go's select vocabulary
+pub fn timeouts_and_default() -> DemoReport {
+    let mut report = DemoReport::new("Timeouts, default and the nil-Channel Trick");
+
+    let (tx, rx) = channel::bounded::<u32>(1);
+
+    report.step("default - don't block");
+    report.code("select! { recv(rx) -> v => .., default => \"nothing yet\" }  // Go: default:");
+    let empty = select! {
+        recv(rx) -> value => format!("got {:?}", value),
+        default => "nothing yet".to_string(),
+    };
+    report.text(empty);
+    tx.send(7).unwrap();
+    report.code("select! { send(tx, 8) -> r => .., default => \"full\" }  // a send arm, buffer of 1 already used");
+    let full = select! {
+        send(tx, 8) -> result => format!("sent: {:?}", result),
+        default => "full - would block".to_string(),
+    };
+    report.text(full);
+
+    report.step("default(duration) - block, but not forever");
+    report.code("select! { recv(rx) -> v => .., default(Duration::from_millis(30)) => \"timed out\" }");
+    let first = select! {
+        recv(rx) -> value => format!("got {:?}", value),
+        default(Duration::from_millis(30)) => "timed out".to_string(),
+    };
+    let start = Instant::now();
+    let second = select! {
+        recv(rx) -> value => format!("got {:?}", value),
+        default(Duration::from_millis(30)) => "timed out".to_string(),
+    };
+    report.text(format!("first: {}, second: {} after {:?}", first, second, start.elapsed()));
+    report.text(format!("single channel shorthand: rx.recv_timeout(30ms) = {:?}", rx.recv_timeout(Duration::from_millis(30))));
+
+    report.step("never() - switching a case off");
+    report.code("let updates = if paused { never() } else { live.clone() };  // Go: updates = nil");
+    let live = tick(Duration::from_millis(10));
+    let mut counts = vec![];
+    for paused in [false, true] {
+        let updates = if paused { never() } else { live.clone() };
+        let stop = after(Duration::from_millis(55));
+        let mut ticks = 0;
+        loop {
+            select! {
+                recv(updates) -> _ => ticks += 1,
+                recv(stop) -> _ => break,
+            }
+        }
+        counts.push(vec![if paused { "paused (never())" } else { "live (tick(10ms))" }.to_string(), ticks.to_string()]);
+    }
+    report.table(&["updates", "ticks in 55ms"], counts);
+
+    report.gap();
+    report.ok("never() is a channel that never delivers - the select arm stays in the code but can't fire");
+    report.text("In Go a nil channel blocks forever, so `ch = nil` disables its case; Rust has no nil Receiver, hence never()");
+    report
+}
+
+// Receiver is Clone: many consumers on one channel without a Mutex
+pub fn mpmc() -> DemoReport {
+    let mut report = DemoReport::new("MPMC - Sharing One Receiver Between Workers");
+
+    report.code("let (tx, rx) = crossbeam::channel::bounded(16);");
+    report.code("for id in 0..4 { let rx = rx.clone(); thread::spawn(move || for job in rx { .. }); }");
+
+    let (tx, rx) = channel::bounded::<u64>(16);
+    let (done_tx, done_rx) = channel::unbounded();
+    let workers: Vec<_> = (0..4)
+        .map(|id| {
+            let (rx, done_tx) = (rx.clone(), done_tx.clone());
+            thread::spawn(move || {
+                let mut handled = 0;
+                for job in rx {
+                    black_box(job);
+                    thread::sleep(Duration::from_micros(200));
+                    handled += 1;
+                }
+                done_tx.send((id, handled)).unwrap();
+            })
+        })
+        .collect();
+    drop((rx, done_tx));
+    for job in 0..200 {
+        tx.send(job).unwrap();
+    }
+    drop(tx);  // Closing: every worker's `for job in rx` ends
+    for worker in workers {
+        worker.join().unwrap();
+    }
+    let mut handled: Vec<(usize, usize)> = done_rx.iter().collect();
+    handled.sort();
+    report.table(&["worker", "jobs"], handled.iter().map(|(id, jobs)| vec![id.to_string(), jobs.to_string()]).collect());
+    report.count("total", handled.iter().map(|(_, jobs)| jobs).sum::<usize>());
+
+    report.gap();
+    report.ok("Each job went to exactly one worker; the channel is the work queue");
+    report.ok("Dropping the last Sender closes it for all of them - Go's close(jobs) + range");
+    report.text("With std: mpsc::Receiver is !Sync and not Clone - the usual workaround is Arc<Mutex<Receiver<T>>>");
+    report.text("(see `run sync-primitives -e worker-pool`), which serializes the receives through one lock");
+    report
+}
+
+// crossbeam::scope, the ancestor of std::thread::scope
+pub fn scoped_threads() -> DemoReport {
+    let mut report = DemoReport::new("crossbeam::scope vs std::thread::scope");
+
+    let readings = vec![3.5, 4.0, 2.5, 5.0, 4.5, 3.0];
+    let halves = readings.split_at(readings.len() / 2);
+
+    report.code("crossbeam::scope(|s| { s.spawn(|_| sum(left)); s.spawn(|_| sum(right)); })  // -> Result");
+    let crossbeam_total: f64 = crossbeam::scope(|s| {
+        let left = s.spawn(|_| halves.0.iter().sum::<f64>());
+        let right = s.spawn(|_| halves.1.iter().sum::<f64>());
+        left.join().unwrap() + right.join().unwrap()
+    })
+    .expect("neither thread panicked");
+
+    report.code("thread::scope(|s| { s.spawn(|| sum(left)); s.spawn(|| sum(right)); })      // std since 1.63");
+    let std_total: f64 = thread::scope(|s| {
+        let left = s.spawn(|| halves.0.iter().sum::<f64>());
+        let right = s.spawn(|| halves.1.iter().sum::<f64>());
+        left.join().unwrap() + right.join().unwrap()
+    });
+    report.text(format!("crossbeam: {}, std: {}, readings still owned here: {:?}", crossbeam_total, std_total, readings));
+
+    report.step("A panicking thread");
+    let previous_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(|_| {}));  // Keep the expected panic off stderr
+    let outcome = crossbeam::scope(|s| {
+        s.spawn(|_| panic!("sensor offline"));
+    });
+    std::panic::set_hook(previous_hook);
+    report.text(format!("crossbeam::scope returned Err: {}", outcome.is_err()));
+    report.text("std::thread::scope re-raises the panic instead - catch it with catch_unwind if you must");
+
+    report.gap();
+    report.ok("Both borrow `readings` from the stack - no Arc, because every thread is joined before the scope returns");
+    report.text("crossbeam passes the scope into each closure (|s| ..) so threads can spawn siblings; std does that with a captured &Scope");
+    report.text("New code: std::thread::scope. crossbeam::scope remains for its Result-returning panic handling");
+    report
+}
+
+/// Time one std mpsc run: `producers` threads sending MESSAGES in total
+pub fn std_mpsc(producers: usize, bound: Option<usize>) -> Duration {
+    let start = Instant::now();
+    let received = match bound {
+        Some(bound) => {
+            let (tx, rx) = mpsc::sync_channel::<usize>(bound);
+            spawn_senders(producers, move |i| tx.send(i).unwrap());
+            rx.iter().count()
+        }
+        None => {
+            let (tx, rx) = mpsc::channel::<usize>();
+            spawn_senders(producers, move |i| tx.send(i).unwrap());
+            rx.iter().count()
+        }
+    };
+    assert_eq!(received, MESSAGES / producers * producers);
+    start.elapsed()
+}
+
+/// The same run on crossbeam::channel
+pub fn crossbeam_channel(producers: usize, bound: Option<usize>) -> Duration {
+    let start = Instant::now();
+    let (tx, rx) = match bound {
+        Some(bound) => channel::bounded::<usize>(bound),
+        None => channel::unbounded::<usize>(),
+    };
+    spawn_senders(producers, move |i| tx.send(i).unwrap());
+    let received = rx.iter().count();
+    assert_eq!(received, MESSAGES / producers * producers);
+    start.elapsed()
+}
+
+// Each producer gets a clone of `send` (and with it, of the Sender)
+fn spawn_senders(producers: usize, send: impl Fn(usize) + Clone + Send + 'static) {
+    for _ in 0..producers {
+        let send = send.clone();
+        thread::spawn(move || {
+            for i in 0..MESSAGES / producers {
+                send(i);
+            }
+        });
+    }
+}
+
+// std::sync::mpsc vs crossbeam::channel, same message counts
+pub fn benchmark() -> DemoReport {
+    let mut report = DemoReport::new("std::sync::mpsc vs crossbeam::channel");
+
+    let per_message = |elapsed: Duration| format!("{:.0} ns", elapsed.as_nanos() as f64 / MESSAGES as f64);
+    let mut rows = vec![];
+    for (shape, producers) in [("1 → 1", 1), ("4 → 1", PRODUCERS)] {
+        for (kind, bound) in [("unbounded", None), ("bounded(128)", Some(128)), ("rendezvous (0)", Some(0))] {
+            let (std_time, crossbeam_time) = (std_mpsc(producers, bound), crossbeam_channel(producers, bound));
+            rows.push(vec![shape.to_string(), kind.to_string(), per_message(std_time), per_message(crossbeam_time)]);
+        }
+    }
+    report.table(&["producers → consumers", "channel", "std mpsc / msg", "crossbeam / msg"], rows);
+    report.count("messages per run", MESSAGES);
+
+    report.gap();
+    report.ok("Close, usually within noise: since Rust 1.67 std::sync::mpsc is crossbeam-channel's algorithm");
+    report.text("Pick crossbeam for select!, Clone receivers and timer channels - not for raw speed");
+    report.text("Rendezvous channels are slow in both: every message is a thread handoff (Go's unbuffered chan, same cost)");
+    if cfg!(debug_assertions) {
+        report.warn("Debug build - run with --release (make crossbeam) for representative numbers");
+    }
+    report
+}
+
+pub fn crossbeam_vs_go() -> DemoReport {
+    let mut report = DemoReport::new("Channels: Go vs std vs crossbeam");
+
+    report.table(
+        &["", "Go", "std::sync::mpsc", "crossbeam::channel"],
+        vec![
+            vec!["wait on several".into(), "select".into(), "-".into(), "select! / Select".into()],
+            vec!["timeout".into(), "case <-time.After(d)".into(), "recv_timeout".into(), "recv(after(d)), default(d)".into()],
+            vec!["don't block".into(), "default:".into(), "try_recv".into(), "default =>, try_recv".into()],
+            vec!["disable a case".into(), "ch = nil".into(), "-".into(), "never()".into()],
+            vec!["periodic".into(), "time.Ticker".into(), "-".into(), "tick(d)".into()],
+            vec!["receivers".into(), "any number".into(), "one (!Sync, not Clone)".into(), "any number (Clone)".into()],
+            vec!["close".into(), "close(ch), by the sender".into(), "drop every Sender".into(), "drop every Sender".into()],
+            vec!["send on closed".into(), "panic".into(), "Err(SendError)".into(), "Err(SendError)".into()],
+        ],
+    );
+
+    report.gap();
+    report.ok("crossbeam::channel is the closest thing to Go channels in Rust - with ownership: sent values move");
+    report
+}
+
+pub const DEMO: Demo = Demo {
+    name: "crossbeam",
+    about: "crossbeam: select! like Go's select, timeouts, never(), MPMC receivers, scoped threads, vs std mpsc",
+    difficulty: Difficulty::Intermediate,
+    tags: &[Tag::Concurrency, Tag::Benchmark],
+    examples: &[
+        Example { name: "select", run: select_example },
+        Example { name: "timeouts", run: timeouts_and_default },
+        Example { name: "mpmc", run: mpmc },
+        Example { name: "scope", run: scoped_threads },
+        Example { name: "benchmark", run: benchmark },
+        Example { name: "vs-go", run: crossbeam_vs_go },
+    ],
+};
+
+pub fn demonstrate_crossbeam() -> Vec<DemoReport> {
+    DEMO.run()
+}
@@ -0,0 +1,248 @@
+// Deadlock - the sharp edge of Mutex
+// Go:   the runtime notices when EVERY goroutine is blocked and dies with
+//       "fatal error: all goroutines are asleep - deadlock!". If anything
+//       else is still running (a server, a ticker) a deadlock just hangs.
+// Rust: no detector at all - two threads waiting on each other's Mutex
+//       block forever, and the borrow checker can't see it coming. The
+//       defences are the same as in Go: one global lock order, try_lock
+//       with back-off, or a watchdog that notices when work stops.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc;
+use std::sync::{Arc, Barrier, Mutex, TryLockError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::registry::{Demo, Difficulty, Example, Tag};
+use crate::report::DemoReport;
+
+const WATCHDOG_TIMEOUT: Duration = Duration::from_millis(200);
+const TRANSFERS: usize = 10_000;
+
+struct Account {
+    name: &'static str,
+    balance: Mutex<i64>,
+}
+
+fn accounts() -> (Arc<Account>, Arc<Account>) {
+    (
+        Arc::new(Account { name: "A", balance: Mutex::new(1_000) }),
+        Arc::new(Account { name: "B", balance: Mutex::new(1_000) }),
+    )
+}
+
+// Waits like Mutex::lock, except it gives up once `abort` is set - so the
+// demo can release its deadlocked threads. With plain lock() they'd wait
+// until the process exits.
+fn lock_or_abort<'a>(mutex: &'a Mutex<i64>, abort: &AtomicBool) -> Option<std::sync::MutexGuard<'a, i64>> {
+    loop {
+        match mutex.try_lock() {
+            Ok(guard) => return Some(guard),
+            Err(TryLockError::Poisoned(poisoned)) => return Some(poisoned.into_inner()),
+            Err(TryLockError::WouldBlock) if abort.load(Ordering::Relaxed) => return None,
+            Err(TryLockError::WouldBlock) => thread::sleep(Duration::from_millis(1)),
+        }
+    }
+}
+
+// Thread 1 locks A then B, thread 2 locks B then A
+pub fn two_mutex_deadlock() -> DemoReport {
+    let mut report = DemoReport::new("Two Mutexes, Opposite Order - A Deadlock");
+
+    report.code("thread 1: let a = from.lock(); let b = to.lock();  // A then B");
+    report.code("thread 2: let b = from.lock(); let a = to.lock();  // B then A");
+
+    let (a, b) = accounts();
+    let both_hold_first = Arc::new(Barrier::new(2));
+    let abort = Arc::new(AtomicBool::new(false));
+    let (done_tx, done_rx) = mpsc::channel();
+
+    let handles: Vec<_> = [(Arc::clone(&a), Arc::clone(&b)), (Arc::clone(&b), Arc::clone(&a))]
+        .into_iter()
+        .enumerate()
+        .map(|(id, (from, to))| {
+            let (both_hold_first, abort, done_tx) = (Arc::clone(&both_hold_first), Arc::clone(&abort), done_tx.clone());
+            thread::spawn(move || {
+                let mut first = from.balance.lock().unwrap();
+                both_hold_first.wait();  // make the bad interleaving certain, not just likely
+                let Some(mut second) = lock_or_abort(&to.balance, &abort) else {
+                    return format!("thread {} held {}, gave up waiting for {}", id + 1, from.name, to.name);
+                };
+                *first -= 10;
+                *second += 10;
+                done_tx.send(id).unwrap();
+                format!("thread {} transferred {} → {}", id + 1, from.name, to.name)
+            })
+        })
+        .collect();
+    drop(done_tx);
+
+    report.step("Watchdog");
+    report.code("done_rx.recv_timeout(Duration::from_millis(200))");
+    let started = Instant::now();
+    let finished = (0..2).take_while(|_| done_rx.recv_timeout(WATCHDOG_TIMEOUT).is_ok()).count();
+    report.count("threads finished before the timeout", finished);
+    if finished < 2 {
+        report.fail(format!("No progress after {:?}: deadlock", started.elapsed()));
+        report.text("thread 1 holds A and waits for B; thread 2 holds B and waits for A");
+        abort.store(true, Ordering::Relaxed);
+    } else {
+        report.ok("Both finished - the threads didn't interleave badly this time");
+    }
+    for handle in handles {
+        report.text(handle.join().unwrap());
+    }
+    report.text("Once one side gives up and drops its lock, the other one can finish");
+
+    report.gap();
+    report.warn("With plain lock() both threads would be stuck until the process exits");
+    report.text("Rust can't kill a thread; a watchdog can only report, log and abort the process");
+    report.text("This compiles cleanly: deadlock freedom is not one of Rust's guarantees");
+    report
+}
+
+// Transfer both ways, many times, always locking the lower-named account first
+fn transfer_ordered(from: &Account, to: &Account, amount: i64) {
+    let (first, second) = if from.name < to.name { (from, to) } else { (to, from) };
+    let mut first_balance = first.balance.lock().unwrap();
+    let mut second_balance = second.balance.lock().unwrap();
+    let (from_balance, to_balance) = if std::ptr::eq(first, from) {
+        (&mut *first_balance, &mut *second_balance)
+    } else {
+        (&mut *second_balance, &mut *first_balance)
+    };
+    *from_balance -= amount;
+    *to_balance += amount;
+}
+
+// Fix 1: every thread takes the locks in the same global order
+pub fn lock_ordering() -> DemoReport {
+    let mut report = DemoReport::new("Fix 1: One Global Lock Order");
+
+    report.code("let (first, second) = if from.name < to.name { (from, to) } else { (to, from) };");
+    report.code("let f = first.balance.lock(); let s = second.balance.lock();");
+
+    let (a, b) = accounts();
+    let start = Instant::now();
+    thread::scope(|s| {
+        s.spawn(|| (0..TRANSFERS).for_each(|_| transfer_ordered(&a, &b, 1)));
+        s.spawn(|| (0..TRANSFERS).for_each(|_| transfer_ordered(&b, &a, 1)));
+    });
+    report.count("transfers each way", TRANSFERS);
+    report.duration("elapsed", start.elapsed());
+    let total = *a.balance.lock().unwrap() + *b.balance.lock().unwrap();
+    report.count("A + B afterwards (started at 2000)", total as usize);
+
+    report.gap();
+    report.ok("A cycle of waiting needs two threads taking locks in opposite orders - now impossible");
+    report.text("Order by anything stable: an id, a name, or the Mutex's address");
+    report.text("Better still: one Mutex around both balances, if the data allows it");
+    report
+}
+
+// Fix 2: try_lock the second lock and back off instead of waiting
+pub fn try_lock_backoff() -> DemoReport {
+    let mut report = DemoReport::new("Fix 2: try_lock and Back Off");
+
+    report.code("let first = from.lock();");
+    report.code("match to.try_lock() {");
+    report.code("    Ok(second) => { /* transfer */ }");
+    report.code("    Err(TryLockError::WouldBlock) => { drop(first); yield_now(); /* retry */ }");
+    report.code("}");
+
+    let (a, b) = accounts();
+    let transfer = |from: &Account, to: &Account| {
+        let mut retries = 0;
+        for _ in 0..TRANSFERS {
+            loop {
+                let mut first = from.balance.lock().unwrap();
+                match to.balance.try_lock() {
+                    Ok(mut second) => {
+                        *first -= 1;
+                        *second += 1;
+                        break;
+                    }
+                    Err(TryLockError::WouldBlock) => {
+                        drop(first);
+                        retries += 1;
+                        thread::yield_now();
+                    }
+                    Err(TryLockError::Poisoned(err)) => panic!("{}", err),
+                }
+            }
+        }
+        retries
+    };
+    let (retries_ab, retries_ba) = thread::scope(|s| {
+        let ab = s.spawn(|| transfer(&a, &b));
+        let ba = s.spawn(|| transfer(&b, &a));
+        (ab.join().unwrap(), ba.join().unwrap())
+    });
+    report.count("transfers each way", TRANSFERS);
+    report.count("back-offs (A→B thread)", retries_ab);
+    report.count("back-offs (B→A thread)", retries_ba);
+    let total = *a.balance.lock().unwrap() + *b.balance.lock().unwrap();
+    report.count("A + B afterwards", total as usize);
+
+    report.step("Same thread, same lock");
+    let solo = Mutex::new(0);
+    let _held = solo.lock().unwrap();
+    let again = matches!(solo.try_lock(), Err(TryLockError::WouldBlock));
+    report.code("let held = m.lock(); m.try_lock()");
+    report.text(format!("→ WouldBlock: {}", again));
+    report.warn("m.lock() here instead would deadlock (or panic) - std's Mutex isn't re-entrant");
+
+    report.gap();
+    report.ok("Never waits while holding a lock, so no cycle can form");
+    report.warn("Under heavy contention both sides can keep backing off - add jitter or fall back to ordering");
+    report
+}
+
+pub fn deadlock_vs_go() -> DemoReport {
+    let mut report = DemoReport::new("Deadlocks: Go vs Rust");
+
+    report.step("Go");
+    report.code("var a, b sync.Mutex");
+    report.code("go func() { a.Lock(); time.Sleep(ms); b.Lock() }()");
+    report.code("b.Lock(); time.Sleep(ms); a.Lock()");
+    report.code("// fatal error: all goroutines are asleep - deadlock!");
+    report.code("// goroutine 1 [sync.Mutex.Lock]: ...");
+    report.text("Only when every goroutine is blocked - with an HTTP server running it just hangs");
+
+    report.step("Rust");
+    report.text("No runtime detector: the process hangs, CPU at 0%");
+    report.code("gdb -p <pid> -batch -ex 'thread apply all bt'  // who's waiting in futex_wait");
+    report.text("parking_lot's deadlock_detection feature finds lock cycles at runtime");
+
+    report.table(
+        &["", "Go", "Rust"],
+        vec![
+            vec!["compile-time check".into(), "none".into(), "none".into()],
+            vec!["runtime detection".into(), "all-goroutines-asleep only".into(), "none in std".into()],
+            vec!["re-entrant Mutex".into(), "no (self-deadlock)".into(), "no (deadlock or panic)".into()],
+            vec!["try_lock".into(), "Mutex.TryLock (1.18)".into(), "Mutex::try_lock".into()],
+            vec!["lock held by".into(), "convention".into(), "the guard's scope".into()],
+        ],
+    );
+
+    report.gap();
+    report.ok("RAII guards make forgetting Unlock impossible; they don't make lock order safe");
+    report
+}
+
+pub const DEMO: Demo = Demo {
+    name: "deadlock",
+    about: "Deadlock: two Mutexes in opposite order, a watchdog, lock ordering and try_lock vs Go's detector",
+    difficulty: Difficulty::Intermediate,
+    tags: &[Tag::Concurrency],
+    examples: &[
+        Example { name: "deadlock", run: two_mutex_deadlock },
+        Example { name: "lock-ordering", run: lock_ordering },
+        Example { name: "try-lock", run: try_lock_backoff },
+        Example { name: "vs-go", run: deadlock_vs_go },
+    ],
+};
+
+pub fn demonstrate_deadlock() -> Vec<DemoReport> {
+    DEMO.run()
+}
@@ -0,0 +1,254 @@
+// Go's defer vs Rust's Drop guards (RAII)
+// Go:   f, _ := os.Open(p); defer f.Close()  - cleanup registered by the CALLER,
+//       runs when the FUNCTION returns
+// Rust: let f = File::open(p)?;              - cleanup built into the TYPE,
+//       runs when the VALUE's scope ends (any scope, any exit path)
+
+use std::cell::{Cell, RefCell};
+use std::fs::{self, File};
+use std::io::Write;
+use std::path::PathBuf;
+use std::rc::Rc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+use crate::registry::{Demo, Difficulty, Example, Tag};
+use crate::report::DemoReport;
+
+type Log = Rc<RefCell<Vec<String>>>;
+
+// TempFileGuards currently alive (open handles)
+static OPEN_FILES: AtomicUsize = AtomicUsize::new(0);
+
+fn note(log: &Log, line: impl Into<String>) {
+    log.borrow_mut().push(line.into());
+}
+
+fn record_log(report: &mut DemoReport, log: &Log) {
+    for event in log.borrow_mut().drain(..) {
+        report.text(event);
+    }
+}
+
+/// Temp file that is closed and deleted when the guard drops
+pub struct TempFileGuard {
+    name: String,
+    path: PathBuf,
+    file: Option<File>,
+    log: Log,
+}
+
+impl TempFileGuard {
+    fn create(name: &str, log: &Log) -> std::io::Result<Self> {
+        let path = std::env::temp_dir().join(format!("rust-playground-{}-{}", std::process::id(), name));
+        let file = File::create(&path)?;
+        OPEN_FILES.fetch_add(1, Ordering::Relaxed);
+        note(log, format!("open   {}", name));
+        Ok(TempFileGuard { name: name.to_string(), path, file: Some(file), log: Rc::clone(log) })
+    }
+
+    fn write_line(&mut self, line: &str) -> std::io::Result<()> {
+        let file = self.file.as_mut().expect("file is open until drop");
+        writeln!(file, "{}", line)
+    }
+}
+
+impl Drop for TempFileGuard {
+    fn drop(&mut self) {
+        drop(self.file.take());  // Close the handle first
+        let _ = fs::remove_file(&self.path);
+        OPEN_FILES.fetch_sub(1, Ordering::Relaxed);
+        note(&self.log, format!("close  {} (and delete)", self.name));
+    }
+}
+
+/// Logs how long its scope took
+pub struct TimingGuard {
+    label: &'static str,
+    start: Instant,
+    log: Log,
+}
+
+impl TimingGuard {
+    fn start(label: &'static str, log: &Log) -> Self {
+        TimingGuard { label, start: Instant::now(), log: Rc::clone(log) }
+    }
+}
+
+impl Drop for TimingGuard {
+    fn drop(&mut self) {
+        note(&self.log, format!("timing {} took {:?}", self.label, self.start.elapsed()));
+    }
+}
+
+/// A tiny lock: held while the guard is alive
+pub struct Lock {
+    held: Cell<bool>,
+}
+
+pub struct LockGuard<'a> {
+    lock: &'a Lock,
+    log: Log,
+}
+
+impl Lock {
+    fn new() -> Self {
+        Lock { held: Cell::new(false) }
+    }
+
+    fn acquire(&self, log: &Log) -> LockGuard<'_> {
+        assert!(!self.held.replace(true), "lock already held");
+        note(log, "lock   acquired");
+        LockGuard { lock: self, log: Rc::clone(log) }
+    }
+
+    fn is_held(&self) -> bool {
+        self.held.get()
+    }
+}
+
+impl Drop for LockGuard<'_> {
+    fn drop(&mut self) {
+        self.lock.held.set(false);
+        note(&self.log, "lock   released");
+    }
+}
+
+// A realistic function: three resources, four ways out
+fn export_report(rows: &[i64], lock: &Lock, log: &Log) -> Result<usize, String> {
+    let _timer = TimingGuard::start("export_report", log);
+    let _guard = lock.acquire(log);
+    let mut out = TempFileGuard::create("export.csv", log).map_err(|e| e.to_string())?;
+
+    if rows.is_empty() {
+        return Err("nothing to export".into());  // Early return #1: guards still run
+    }
+    for row in rows {
+        if *row < 0 {
+            return Err(format!("negative value {}", row));  // Early return #2
+        }
+        out.write_line(&row.to_string()).map_err(|e| e.to_string())?;  // Early return #3 (?)
+    }
+    note(log, format!("wrote  {} rows to {}", rows.len(), out.name));
+    Ok(rows.len())
+}
+
+// The three guard types in one function with early returns
+pub fn guards_with_early_returns() -> DemoReport {
+    let mut report = DemoReport::new("Drop Guards - Cleanup on Every Exit Path");
+    let events = Log::default();
+    let lock = Lock::new();
+
+    for rows in [vec![1, 2, 3], vec![], vec![4, -1, 5]] {
+        let result = export_report(&rows, &lock, &events);
+        report.step(format!("export_report({:?}) → {:?}", rows, result));
+        record_log(&mut report, &events);
+        report.text(format!("lock held afterwards? {}", lock.is_held()));
+    }
+
+    report.gap();
+    report.ok("Every return path - Ok, Err, ? - releases all three guards");
+    report.ok("Reverse order: file closed, then lock released, then timer stops");
+    report.ok("The function body never mentions cleanup");
+
+    report.step("Go");
+    report.code("defer timer(\"exportReport\")()");
+    report.code("mu.Lock(); defer mu.Unlock()");
+    report.code("f, err := os.CreateTemp(\"\", \"export\"); if err != nil { return err }");
+    report.code("defer func() { f.Close(); os.Remove(f.Name()) }()");
+    report.warn("Same result - but every caller must remember every defer");
+    report
+}
+
+// defer in a loop: nothing runs until the FUNCTION returns
+pub fn defer_in_loop() -> DemoReport {
+    let mut report = DemoReport::new("The defer-in-Loop Pitfall");
+    let events = Log::default();
+
+    report.step("Go (all files stay open until the function returns)");
+    report.code("for _, name := range names {");
+    report.code("    f, _ := os.Open(name)");
+    report.code("    defer f.Close()  // queued, NOT run at the end of the iteration");
+    report.code("    process(f)");
+    report.code("}");
+    report.warn("10,000 names → 10,000 open file descriptors → \"too many open files\"");
+    report.text("Usual fix: wrap the body in func() { ... }() to get a scope");
+
+    report.step("Rust (each iteration's guard drops at the end of that iteration)");
+    let mut max_open = 0;
+    for name in ["a.txt", "b.txt", "c.txt"] {
+        let mut file = TempFileGuard::create(name, &events).expect("temp dir is writable");
+        file.write_line("processed").expect("write to temp file");
+        max_open = max_open.max(OPEN_FILES.load(Ordering::Relaxed));
+    }  // ← `file` dropped here, every iteration
+    record_log(&mut report, &events);
+    report.count("Max files open at once", max_open);
+
+    report.gap();
+    report.ok("Loop body is a scope: guards die every iteration");
+    report.ok("Any block { } works the same - no closure trick needed");
+    report
+}
+
+// Drop guards fire during panics too (like deferred calls in Go)
+pub fn guards_during_panic() -> DemoReport {
+    let mut report = DemoReport::new("Guards Run While Unwinding a Panic");
+    let events = Log::default();
+    let lock = Lock::new();
+
+    let previous_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(|_| {}));
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        let _guard = lock.acquire(&events);
+        let _timer = TimingGuard::start("doomed work", &events);
+        std::thread::sleep(Duration::from_millis(1));
+        panic!("something broke");
+    }));
+    std::panic::set_hook(previous_hook);
+
+    record_log(&mut report, &events);
+    report.text(format!("panicked: {}, lock held afterwards: {}", result.is_err(), lock.is_held()));
+
+    report.gap();
+    report.ok("Unwinding drops every live value: locks released, files closed");
+    report.text("Go: deferred calls also run on panic - this is the one place they agree");
+    report.warn("With panic = \"abort\" in Cargo.toml, nothing is unwound");
+    report
+}
+
+pub fn defer_vs_drop_summary() -> DemoReport {
+    let mut report = DemoReport::new("defer vs Drop");
+
+    report.table(
+        &["", "Go defer", "Rust Drop"],
+        vec![
+            vec!["declared by".into(), "the caller, each time".into(), "the type, once".into()],
+            vec!["runs at".into(), "function return".into(), "end of the owning scope".into()],
+            vec!["in a loop".into(), "piles up until return".into(), "every iteration".into()],
+            vec!["order".into(), "LIFO".into(), "reverse declaration (LIFO)".into()],
+            vec!["moved to another owner".into(), "still runs here".into(), "runs where the new owner ends".into()],
+            vec!["forgettable?".into(), "yes".into(), "only via mem::forget / leaks".into()],
+        ],
+    );
+
+    report.gap();
+    report.ok("Want defer-like ad-hoc cleanup? A closure guard: struct Defer<F: FnMut()>(F)");
+    report
+}
+
+pub const DEMO: Demo = Demo {
+    name: "defer-vs-drop",
+    about: "Go's defer vs Rust Drop guards (RAII)",
+    difficulty: Difficulty::Beginner,
+    tags: &[Tag::Ownership],
+    examples: &[
+        Example { name: "early-returns", run: guards_with_early_returns },
+        Example { name: "defer-in-loop", run: defer_in_loop },
+        Example { name: "panic", run: guards_during_panic },
+        Example { name: "summary", run: defer_vs_drop_summary },
+    ],
+};
+
+pub fn demonstrate_defer_vs_drop() -> Vec<DemoReport> {
+    DEMO.run()
+}
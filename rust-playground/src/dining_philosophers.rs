@@ -0,0 +1,450 @@
+// Dining philosophers - one problem, three ways to share the forks
+// Go:   the textbook versions are a sync.Mutex per fork (lock the lower-numbered
+//       one first), a waiter goroutine that hands out both forks over channels,
+//       or a goroutine per fork. Get the order wrong and the runtime's
+//       "all goroutines are asleep" check may catch it - if nothing else runs.
+// Rust: the same three designs with Arc<Mutex<_>>, mpsc and owned actor
+//       threads. Ownership rules out data races on the forks, not deadlocks:
+//       lock left-then-right everywhere and five threads wait forever, with
+//       no detector at all. `run dining-philosophers --deadlock` shows it.
+
+use std::collections::VecDeque;
+use std::hint::black_box;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Barrier, Mutex, MutexGuard, TryLockError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::registry::{Demo, Difficulty, Example, Tag};
+use crate::report::{DemoReport, SideBySideRow};
+
+/// Philosophers (and forks) around the table
+pub const PHILOSOPHERS: usize = 5;
+/// Meals per philosopher in registry runs
+pub const MEALS: usize = 200;
+const WATCHDOG_TIMEOUT: Duration = Duration::from_millis(200);
+
+// Used when the demo runs from the registry (`run dining-philosophers --deadlock`)
+static DEADLOCK: AtomicBool = AtomicBool::new(false);
+
+/// Let the deadlock example really deadlock (a watchdog still ends it)
+pub fn set_deadlock(enabled: bool) {
+    DEADLOCK.store(enabled, Ordering::Relaxed);
+}
+
+pub fn deadlock_enabled() -> bool {
+    DEADLOCK.load(Ordering::Relaxed)
+}
+
+fn left(seat: usize) -> usize {
+    seat
+}
+
+fn right(seat: usize) -> usize {
+    (seat + 1) % PHILOSOPHERS
+}
+
+// Lower-numbered fork first: the last philosopher reaches right, not left
+fn in_order(seat: usize) -> (usize, usize) {
+    (left(seat).min(right(seat)), left(seat).max(right(seat)))
+}
+
+// A few hundred instructions with both forks held
+fn eat() {
+    for bite in 0..100 {
+        black_box(bite);
+    }
+}
+
+/// What one dinner measured
+pub struct Dinner {
+    pub meals: usize,
+    pub elapsed: Duration,
+    /// How often each fork was picked up - every fork serves two neighbours
+    pub fork_uses: Vec<usize>,
+}
+
+impl Dinner {
+    pub fn meals_per_sec(&self) -> f64 {
+        self.meals as f64 / self.elapsed.as_secs_f64().max(f64::EPSILON)
+    }
+
+    /// Every fork used 2 × meals, every philosopher fed
+    pub fn fair(&self, meals: usize) -> bool {
+        self.meals == PHILOSOPHERS * meals && self.fork_uses.iter().all(|&uses| uses == 2 * meals)
+    }
+}
+
+/// One Arc<Mutex<usize>> per fork, always locked lower index first
+pub fn dine_with_mutexes(meals: usize) -> Dinner {
+    let forks: Arc<Vec<Mutex<usize>>> = Arc::new((0..PHILOSOPHERS).map(|_| Mutex::new(0)).collect());
+    let start = Instant::now();
+    let philosophers: Vec<_> = (0..PHILOSOPHERS)
+        .map(|seat| {
+            let forks = Arc::clone(&forks);
+            thread::spawn(move || {
+                let (first, second) = in_order(seat);
+                for _ in 0..meals {
+                    let mut first = forks[first].lock().unwrap();
+                    let mut second = forks[second].lock().unwrap();
+                    *first += 1;
+                    *second += 1;
+                    eat();
+                }  // both guards drop here: forks back on the table
+                meals
+            })
+        })
+        .collect();
+    let eaten = philosophers.into_iter().map(|philosopher| philosopher.join().unwrap()).sum();
+    let elapsed = start.elapsed();
+    Dinner { meals: eaten, elapsed, fork_uses: forks.iter().map(|fork| *fork.lock().unwrap()).collect() }
+}
+
+enum ToWaiter {
+    Hungry(usize),
+    Finished(usize),
+}
+
+// Owns every fork: grants both at once or queues the request, so nobody
+// ever holds one fork while waiting for the other
+fn waiter(requests: Receiver<ToWaiter>, grants: Vec<Sender<()>>) -> Vec<usize> {
+    let mut on_table = [true; PHILOSOPHERS];
+    let mut fork_uses = vec![0; PHILOSOPHERS];
+    let mut queue = VecDeque::new();
+    for request in requests {
+        match request {
+            ToWaiter::Hungry(seat) => queue.push_back(seat),
+            ToWaiter::Finished(seat) => {
+                on_table[left(seat)] = true;
+                on_table[right(seat)] = true;
+            }
+        }
+        // First come, first served, among those whose forks are both free
+        queue.retain(|&seat| {
+            if !(on_table[left(seat)] && on_table[right(seat)]) {
+                return true;
+            }
+            on_table[left(seat)] = false;
+            on_table[right(seat)] = false;
+            fork_uses[left(seat)] += 1;
+            fork_uses[right(seat)] += 1;
+            grants[seat].send(()).unwrap();
+            false
+        });
+    }
+    fork_uses  // every philosopher dropped its Sender: dinner's over
+}
+
+/// A waiter thread owns the forks; philosophers ask for them over a channel
+pub fn dine_with_waiter(meals: usize) -> Dinner {
+    let (requests_tx, requests_rx) = mpsc::channel();
+    let (grants, granted): (Vec<_>, Vec<_>) = (0..PHILOSOPHERS).map(|_| mpsc::channel::<()>()).unzip();
+    let start = Instant::now();
+    let waiter = thread::spawn(move || waiter(requests_rx, grants));
+    let philosophers: Vec<_> = granted
+        .into_iter()
+        .enumerate()
+        .map(|(seat, granted)| {
+            let requests = requests_tx.clone();
+            thread::spawn(move || {
+                for _ in 0..meals {
+                    requests.send(ToWaiter::Hungry(seat)).unwrap();
+                    granted.recv().unwrap();  // both forks, or nothing
+                    eat();
+                    requests.send(ToWaiter::Finished(seat)).unwrap();
+                }
+                meals
+            })
+        })
+        .collect();
+    drop(requests_tx);
+    let eaten = philosophers.into_iter().map(|philosopher| philosopher.join().unwrap()).sum();
+    let fork_uses = waiter.join().unwrap();
+    Dinner { meals: eaten, elapsed: start.elapsed(), fork_uses }
+}
+
+enum ToFork {
+    PickUp(Sender<()>),
+    PutDown,
+}
+
+// One thread per fork: its state is a local, its only interface a channel
+fn fork_actor(inbox: Receiver<ToFork>) -> usize {
+    let mut held = false;
+    let mut waiting: VecDeque<Sender<()>> = VecDeque::new();
+    let mut uses = 0;
+    for message in inbox {
+        match message {
+            ToFork::PickUp(reply) if !held => {
+                held = true;
+                uses += 1;
+                reply.send(()).unwrap();
+            }
+            ToFork::PickUp(reply) => waiting.push_back(reply),
+            ToFork::PutDown => match waiting.pop_front() {
+                Some(next) => {
+                    uses += 1;
+                    next.send(()).unwrap();  // handed straight on, never back on the table
+                }
+                None => held = false,
+            },
+        }
+    }
+    uses
+}
+
+/// Every fork is an actor; philosophers message the lower fork first, then the higher
+pub fn dine_with_fork_actors(meals: usize) -> Dinner {
+    let (inboxes, forks): (Vec<Sender<ToFork>>, Vec<_>) = (0..PHILOSOPHERS)
+        .map(|_| {
+            let (tx, rx) = mpsc::channel();
+            (tx, thread::spawn(move || fork_actor(rx)))
+        })
+        .unzip();
+    let start = Instant::now();
+    let philosophers: Vec<_> = (0..PHILOSOPHERS)
+        .map(|seat| {
+            let (first, second) = in_order(seat);
+            let (first, second) = (inboxes[first].clone(), inboxes[second].clone());
+            thread::spawn(move || {
+                let (reply, granted) = mpsc::channel();
+                for _ in 0..meals {
+                    first.send(ToFork::PickUp(reply.clone())).unwrap();
+                    granted.recv().unwrap();
+                    second.send(ToFork::PickUp(reply.clone())).unwrap();
+                    granted.recv().unwrap();
+                    eat();
+                    first.send(ToFork::PutDown).unwrap();
+                    second.send(ToFork::PutDown).unwrap();
+                }
+                meals
+            })
+        })
+        .collect();
+    drop(inboxes);
+    let eaten = philosophers.into_iter().map(|philosopher| philosopher.join().unwrap()).sum();
+    let elapsed = start.elapsed();
+    Dinner { meals: eaten, elapsed, fork_uses: forks.into_iter().map(|fork| fork.join().unwrap()).collect() }
+}
+
+/// One way of sharing the forks
+pub struct Design {
+    pub name: &'static str,
+    pub dine: fn(usize) -> Dinner,
+}
+
+pub const DESIGNS: &[Design] = &[
+    Design { name: "Arc<Mutex> per fork", dine: dine_with_mutexes },
+    Design { name: "waiter thread + channels", dine: dine_with_waiter },
+    Design { name: "actor per fork", dine: dine_with_fork_actors },
+];
+
+fn add_dinner(report: &mut DemoReport, dinner: &Dinner) {
+    report.count("meals eaten", dinner.meals);
+    report.duration("dinner took", dinner.elapsed);
+    report.text(format!("{:.0} meals/s; fork uses {:?}", dinner.meals_per_sec(), dinner.fork_uses));
+    if dinner.fair(MEALS) {
+        report.ok(format!("Everyone ate {} times, every fork was picked up {} times", MEALS, 2 * MEALS));
+    } else {
+        report.fail("Meals or fork uses don't add up");
+    }
+}
+
+// Arc<Mutex> per fork, ordered locking
+pub fn mutexes() -> DemoReport {
+    let mut report = DemoReport::new("Arc<Mutex> per Fork, Lower Fork First");
+
+    report.code("let forks: Arc<Vec<Mutex<usize>>> = Arc::new((0..5).map(|_| Mutex::new(0)).collect());");
+    report.code("let (first, second) = (left.min(right), left.max(right));  // philosopher 4: fork 0, then 4");
+    report.code("let mut a = forks[first].lock().unwrap(); let mut b = forks[second].lock().unwrap();");
+    add_dinner(&mut report, &dine_with_mutexes(MEALS));
+
+    report.gap();
+    report.ok("One global order (lowest fork first) means no cycle of waiters - no deadlock");
+    report.text("Guards drop at the end of the loop body: putting the forks down can't be forgotten");
+    report.warn("The order lives in your head, not in the types: one philosopher that grabs left first brings it back");
+    report
+}
+
+// A waiter thread and two channels
+pub fn waiter_channels() -> DemoReport {
+    let mut report = DemoReport::new("A Waiter Thread Owns the Forks");
+
+    report.code("requests.send(ToWaiter::Hungry(seat)); granted.recv();  // both forks or nothing");
+    report.code("eat(); requests.send(ToWaiter::Finished(seat));");
+    report.code("waiter: for request in requests { ... queue.retain(|seat| !both_free(seat) || grant(seat)) }");
+    add_dinner(&mut report, &dine_with_waiter(MEALS));
+
+    report.gap();
+    report.ok("The forks are a plain [bool; 5] owned by one thread: no locks, nothing to order");
+    report.ok("Nobody holds one fork while waiting for the other, so the cycle can't form");
+    report.text("Dinner ends when every philosopher drops its Sender - the waiter's `for` loop runs out");
+    report.warn("Every meal is two messages through one thread: the waiter is the bottleneck");
+    report
+}
+
+// A thread per fork, ordered requests
+pub fn fork_actors() -> DemoReport {
+    let mut report = DemoReport::new("One Actor per Fork");
+
+    report.code("enum ToFork { PickUp(Sender<()>), PutDown }");
+    report.code("fork: for message in inbox { PickUp(reply) if !held => grant, PickUp(reply) => queue, PutDown => hand on }");
+    report.code("first.send(PickUp(reply.clone())); granted.recv(); second.send(PickUp(reply.clone())); granted.recv();");
+    add_dinner(&mut report, &dine_with_fork_actors(MEALS));
+
+    report.gap();
+    report.ok("Each fork's state is a local in its own thread - no Mutex anywhere");
+    report.warn("Message passing doesn't prevent deadlock: the philosophers still ask the lower fork first");
+    report.text("Ask left then right here and it hangs exactly like the Mutex version - recv() waits forever");
+    report
+}
+
+// Waits like Mutex::lock, except it gives up once `abort` is set
+fn lock_or_abort<'a>(fork: &'a Mutex<usize>, abort: &AtomicBool) -> Option<MutexGuard<'a, usize>> {
+    loop {
+        match fork.try_lock() {
+            Ok(guard) => return Some(guard),
+            Err(TryLockError::Poisoned(poisoned)) => return Some(poisoned.into_inner()),
+            Err(TryLockError::WouldBlock) if abort.load(Ordering::Relaxed) => return None,
+            Err(TryLockError::WouldBlock) => thread::sleep(Duration::from_millis(1)),
+        }
+    }
+}
+
+// Everyone picks up the left fork, then reaches for the right one
+pub fn deadlock() -> DemoReport {
+    let mut report = DemoReport::new("Left Fork, Then Right - A Deadlock");
+
+    report.code("let left = forks[seat].lock().unwrap(); let right = forks[(seat + 1) % 5].lock().unwrap();");
+    if !deadlock_enabled() {
+        report.text("Five philosophers pick up their left fork at the same moment: every fork is held, every right fork is someone's left");
+        report.warn("Skipped - rerun with `run dining-philosophers --deadlock -e deadlock` to watch it happen");
+        report.text("(a watchdog notices after 200ms and lets the threads give up)");
+        return report;
+    }
+
+    let forks: Arc<Vec<Mutex<usize>>> = Arc::new((0..PHILOSOPHERS).map(|_| Mutex::new(0)).collect());
+    let all_hold_left = Arc::new(Barrier::new(PHILOSOPHERS));
+    let abort = Arc::new(AtomicBool::new(false));
+    let (done_tx, done_rx) = mpsc::channel();
+    let philosophers: Vec<_> = (0..PHILOSOPHERS)
+        .map(|seat| {
+            let (forks, all_hold_left, abort, done_tx) = (Arc::clone(&forks), Arc::clone(&all_hold_left), Arc::clone(&abort), done_tx.clone());
+            thread::spawn(move || {
+                let mut left_fork = forks[left(seat)].lock().unwrap();
+                all_hold_left.wait();  // make the bad interleaving certain, not just likely
+                let Some(mut right_fork) = lock_or_abort(&forks[right(seat)], &abort) else {
+                    return format!("philosopher {} held fork {}, gave up waiting for fork {}", seat, left(seat), right(seat));
+                };
+                *left_fork += 1;
+                *right_fork += 1;
+                done_tx.send(seat).unwrap();
+                if abort.load(Ordering::Relaxed) {
+                    format!("philosopher {} ate - after the watchdog, once fork {} came free", seat, right(seat))
+                } else {
+                    format!("philosopher {} ate", seat)
+                }
+            })
+        })
+        .collect();
+    drop(done_tx);
+
+    report.step("Watchdog");
+    report.code("done_rx.recv_timeout(Duration::from_millis(200))");
+    let started = Instant::now();
+    let fed = (0..PHILOSOPHERS).take_while(|_| done_rx.recv_timeout(WATCHDOG_TIMEOUT).is_ok()).count();
+    report.count("philosophers fed before the timeout", fed);
+    if fed < PHILOSOPHERS {
+        report.fail(format!("No meal after {:?}: deadlock - each holds one fork and waits for a neighbour's", started.elapsed()));
+        abort.store(true, Ordering::Relaxed);
+    } else {
+        report.ok("Everyone ate - the threads didn't interleave badly this time");
+    }
+    for philosopher in philosophers {
+        report.text(philosopher.join().unwrap());
+    }
+
+    report.gap();
+    report.warn("With plain lock() on both forks all five threads would wait until the process exits");
+    report.text("Fixes: lower fork first (mutexes), a waiter handing out pairs (waiter), or let only four sit down");
+    report
+}
+
+// The three designs side by side
+pub fn throughput() -> DemoReport {
+    let mut report = DemoReport::new("Dining Philosophers - Throughput");
+
+    report.text(format!("{} philosophers, {} meals each, the same tiny meal in every design", PHILOSOPHERS, MEALS));
+    if cfg!(debug_assertions) {
+        report.warn("Debug build - use --release for real numbers");
+    }
+    let dinners: Vec<(&str, Dinner)> = DESIGNS.iter().map(|design| (design.name, (design.dine)(MEALS))).collect();
+    let fastest = dinners.iter().map(|(_, dinner)| dinner.meals_per_sec()).fold(0.0, f64::max).max(f64::EPSILON);
+    report.summary_table(
+        &["design", "time", "meals/s", "vs fastest", "fair"],
+        dinners
+            .iter()
+            .map(|(name, dinner)| {
+                vec![
+                    name.to_string(),
+                    format!("{:.1?}", dinner.elapsed),
+                    format!("{:.0}", dinner.meals_per_sec()),
+                    format!("{:.2}x", dinner.meals_per_sec() / fastest),
+                    if dinner.fair(MEALS) { "yes" } else { "NO" }.to_string(),
+                ]
+            })
+            .collect(),
+    );
+
+    report.gap();
+    report.text("A meal is a lock + unlock of two Mutexes, or four to six channel messages and thread wake-ups");
+    report.ok("Shared memory wins on raw speed; the message-passing designs win on what you can't get wrong");
+    report
+}
+
+pub fn philosophers_vs_go() -> DemoReport {
+    let mut report = DemoReport::new("Dining Philosophers: Go vs Rust");
+
+    report.side_by_side(vec![
+        SideBySideRow::differs("forks := make([]sync.Mutex, 5)", "let forks = Arc::new((0..5).map(|_| Mutex::new(0)).collect::<Vec<_>>());"),
+        SideBySideRow::differs("forks[a].Lock(); forks[b].Lock()", "let a = forks[a].lock().unwrap(); let b = forks[b].lock().unwrap();"),
+        SideBySideRow::differs("forks[b].Unlock(); forks[a].Unlock()", "}  // guards drop, in reverse order"),
+        SideBySideRow::same("go waiter(requests, grants)", "thread::spawn(move || waiter(requests, grants))"),
+        SideBySideRow::same("requests <- seat; <-grants[seat]", "requests.send(Hungry(seat)); granted.recv();"),
+    ]);
+
+    report.table(
+        &["", "Go", "Rust"],
+        vec![
+            vec!["left-then-right deadlock".into(), "fatal error, if nothing else runs".into(), "hangs silently".into()],
+            vec!["forgotten Unlock".into(), "deadlock later".into(), "impossible (guard)".into()],
+            vec!["fork shared without a lock".into(), "race, caught by -race at best".into(), "doesn't compile (not Sync)".into()],
+            vec!["waiter / actor state".into(), "goroutine locals".into(), "moved into the thread".into()],
+            vec!["per philosopher".into(), "a goroutine (~2KB stack)".into(), "an OS thread (~8MB reserved)".into()],
+        ],
+    );
+
+    report.gap();
+    report.ok("Both languages leave deadlock to you - the designs that avoid it are the same");
+    report
+}
+
+pub const DEMO: Demo = Demo {
+    name: "dining-philosophers",
+    about: "Five philosophers three ways: ordered Arc<Mutex> forks, a waiter over channels, fork actors - throughput and a --deadlock variant",
+    difficulty: Difficulty::Advanced,
+    tags: &[Tag::Concurrency, Tag::Benchmark],
+    examples: &[
+        Example { name: "mutexes", run: mutexes },
+        Example { name: "waiter", run: waiter_channels },
+        Example { name: "fork-actors", run: fork_actors },
+        Example { name: "deadlock", run: deadlock },
+        Example { name: "throughput", run: throughput },
+        Example { name: "vs-go", run: philosophers_vs_go },
+    ],
+};
+
+pub fn demonstrate_dining_philosophers() -> Vec<DemoReport> {
+    DEMO.run()
+}
@@ -0,0 +1,105 @@
+// Doctor - what this build can run, and how to get the rest
+// Optional Cargo features decide which demos exist at all (async-await needs
+// tokio, the allocation counts need the counting allocator), and a few
+// things need tools Cargo doesn't install: Go for xlang_bench and go-ffi,
+// nightly Miri for examples/miri, Graphviz to draw `graph` output. This
+// checks both for the binary it's compiled into (`playground-cli doctor`).
+
+use std::process::Command;
+
+use crate::alloc_stats;
+use crate::registry::DemoRegistry;
+use crate::report::DemoReport;
+
+/// An optional Cargo feature and what it unlocks
+pub struct Feature {
+    pub name: &'static str,
+    pub enabled: bool,
+    pub unlocks: &'static str,
+}
+
+pub const FEATURES: &[Feature] = &[
+    Feature { name: "tui", enabled: cfg!(feature = "tui"), unlocks: "`tui`: step through demos in a terminal UI" },
+    Feature { name: "alloc-stats", enabled: cfg!(feature = "alloc-stats"), unlocks: "the counting allocator: allocation counts, --leak-check" },
+    Feature { name: "mimalloc", enabled: cfg!(feature = "mimalloc"), unlocks: "mimalloc as the global allocator (allocator-backends, compare-run)" },
+    Feature { name: "jemalloc", enabled: cfg!(feature = "jemalloc"), unlocks: "jemalloc as the global allocator (allocator-backends, compare-run)" },
+    Feature { name: "alloc-sites", enabled: cfg!(feature = "alloc-sites"), unlocks: "--alloc-sites: top allocation sites per demo" },
+    Feature { name: "tokio", enabled: cfg!(feature = "tokio"), unlocks: "the async-await demo, cancellation's tokio-select example" },
+    Feature { name: "parking_lot", enabled: cfg!(feature = "parking_lot"), unlocks: "the parking-lot demo, parking_lot columns in the lock benchmarks" },
+    Feature { name: "crossbeam", enabled: cfg!(feature = "crossbeam"), unlocks: "the crossbeam demo (select!, bounded channels)" },
+    Feature { name: "tracing", enabled: cfg!(feature = "tracing"), unlocks: "--trace: a span per demo and example as JSON lines on stderr" },
+    Feature { name: "go-ffi", enabled: cfg!(feature = "go-ffi"), unlocks: "the ffi-with-go demo (links a Go c-archive; needs go)" },
+];
+
+/// A program some demos shell out to
+pub struct Tool {
+    pub name: &'static str,
+    pub program: &'static str,
+    pub args: &'static [&'static str],
+    pub needed_for: &'static str,
+    pub install: &'static str,
+}
+
+pub const TOOLS: &[Tool] = &[
+    Tool { name: "go", program: "go", args: &["version"], needed_for: "xlang_bench, --features go-ffi, golang-playground", install: "https://go.dev/dl/" },
+    Tool { name: "miri (nightly)", program: "cargo", args: &["+nightly", "miri", "--version"], needed_for: "make miri (examples/miri)", install: "rustup +nightly component add miri" },
+    Tool { name: "graphviz", program: "dot", args: &["-V"], needed_for: "make graphs (PNG from `graph` output)", install: "Graphviz (apt/brew install graphviz)" },
+];
+
+/// First line the tool prints for its version, None when it can't be run
+pub fn tool_version(tool: &Tool) -> Option<String> {
+    let output = Command::new(tool.program).args(tool.args).output().ok().filter(|output| output.status.success())?;
+    // dot -V writes to stderr
+    let text = if output.stdout.is_empty() { output.stderr } else { output.stdout };
+    String::from_utf8_lossy(&text).lines().next().map(|line| line.trim().to_string())
+}
+
+/// Does the counting allocator see a Box::new? (None when it's not built in)
+pub fn allocator_hook_works() -> Option<bool> {
+    let (_, stats) = alloc_stats::measure(|| std::hint::black_box(Box::new(42u64)));
+    stats.map(|stats| stats.allocations == 1)
+}
+
+/// Features, tools and how to turn on what's missing
+pub fn diagnose() -> DemoReport {
+    let mut report = DemoReport::new("Doctor - What This Build Can Run");
+
+    report.step("Cargo features (compiled into this binary)");
+    report.table(
+        &["feature", "enabled", "unlocks"],
+        FEATURES.iter().map(|feature| vec![feature.name.to_string(), if feature.enabled { "yes" } else { "no" }.to_string(), feature.unlocks.to_string()]).collect(),
+    );
+    report.count("demos registered", DemoRegistry::builtin().demos().len());
+    match allocator_hook_works() {
+        Some(true) => report.ok("Counting allocator installed: Box::new(42) counted as 1 allocation"),
+        Some(false) => report.fail("Counting allocator installed but didn't count Box::new(42) - please report this"),
+        None => report.text("No counting allocator: allocation columns will say n/a"),
+    };
+    let missing: Vec<&str> = FEATURES.iter().filter(|feature| !feature.enabled && feature.name != "go-ffi").map(|feature| feature.name).collect();
+    if missing.is_empty() {
+        report.ok("Every feature that needs only Cargo is on");
+    } else {
+        report.text("Turn the rest on (go-ffi also needs go, see below):");
+        report.code(format!("cargo run --features {} -- list", missing.join(",")));
+    }
+
+    report.step("Tools outside Cargo");
+    let found: Vec<Option<String>> = TOOLS.iter().map(tool_version).collect();
+    report.table(
+        &["tool", "found", "needed for"],
+        TOOLS
+            .iter()
+            .zip(&found)
+            .map(|(tool, version)| vec![tool.name.to_string(), version.clone().unwrap_or_else(|| "no".to_string()), tool.needed_for.to_string()])
+            .collect(),
+    );
+    for (tool, version) in TOOLS.iter().zip(&found) {
+        if version.is_none() {
+            report.warn(format!("{}: `{} {}` failed - install: {}", tool.name, tool.program, tool.args.join(" "), tool.install));
+        }
+    }
+
+    report.gap();
+    report.text("Shell completions for playground-cli: playground-cli completions bash|zsh|fish");
+    report
+}
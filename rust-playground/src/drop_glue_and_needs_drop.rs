@@ -0,0 +1,268 @@
+// Drop glue - the cleanup code the compiler writes for every type
+// Go:   the GC has to find garbage, so the allocator records which objects
+//       hold pointers. Pointer-free ones ([]byte, [N]int) go in "noscan"
+//       spans the collector never reads; a []*T or []string is scanned on
+//       every cycle, element by element, for as long as it's alive.
+// Rust: nothing is scanned. Instead each type gets "drop glue": the code
+//       that runs when a value dies - its Drop impl, then its fields' glue.
+//       For u8, i64, &T or [u32; N] the glue is empty, and
+//       std::mem::needs_drop::<T>() says so at compile time. Generic code
+//       (Vec::clear, drop(Vec<T>)) asks it to skip the per-element loop,
+//       so clearing a Vec<u8> is O(1) and a Vec<String> is a free per element.
+
+use std::cell::Cell;
+use std::fs::File;
+use std::hint::black_box;
+use std::marker::PhantomData;
+use std::mem::{self, ManuallyDrop};
+use std::ptr;
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+
+use crate::alloc_stats;
+use crate::registry::{Demo, Difficulty, Example, Tag};
+use crate::report::{DemoReport, SideBySideRow};
+
+/// Elements per Vec in the clear-cost benchmark
+pub const ELEMENTS: usize = 200_000;
+
+thread_local! {
+    static DROPS: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Counts its drops (per thread) - a type whose glue calls a Drop impl
+pub struct Counted;
+
+impl Drop for Counted {
+    fn drop(&mut self) {
+        DROPS.with(|drops| drops.set(drops.get() + 1));
+    }
+}
+
+/// Drops of `Counted` on this thread so far
+pub fn drops() -> usize {
+    DROPS.with(Cell::get)
+}
+
+/// No Drop impl - but its fields have glue, so it has glue too
+pub struct Pair {
+    pub left: Counted,
+    pub right: Counted,
+    pub id: u32,
+}
+
+/// Only plain data: no glue at all
+#[derive(Clone, Copy)]
+pub struct Point {
+    pub x: f64,
+    pub y: f64,
+}
+
+/// A type and whether it has drop glue
+pub struct GlueRow {
+    pub name: &'static str,
+    pub needs_drop: bool,
+    pub why: &'static str,
+}
+
+/// needs_drop for a spread of types, answered by the compiler
+pub fn glue_table() -> Vec<GlueRow> {
+    macro_rules! row {
+        ($t:ty, $why:expr) => {
+            GlueRow { name: stringify!($t), needs_drop: mem::needs_drop::<$t>(), why: $why }
+        };
+    }
+    vec![
+        row!(u8, "plain bytes"),
+        row!([u64; 1024], "an array of plain data is plain data"),
+        row!(Point, "only f64 fields (and Copy: Copy types never have glue)"),
+        row!(&String, "a borrow doesn't own what it points to"),
+        row!(&'static str, "points into the binary"),
+        row!(PhantomData<String>, "zero-sized marker: owns no String"),
+        row!(ManuallyDrop<String>, "glue switched off on purpose"),
+        row!(String, "frees its buffer"),
+        row!(Vec<u8>, "frees its buffer (but not per element)"),
+        row!(Box<u8>, "frees the box"),
+        row!(Option<Box<u8>>, "Some needs the Box freed"),
+        row!(Rc<u8>, "decrements the count, maybe frees"),
+        row!(File, "closes the descriptor"),
+        row!(Counted, "has a Drop impl"),
+        row!(Pair, "no Drop impl, but its fields have glue"),
+        row!((u32, String), "one field with glue is enough"),
+    ]
+}
+
+/// Empty `items` the way Vec::clear does: drop each element in place, but
+/// only when T has drop glue. Returns the elements it ran glue for.
+pub fn clear_in_place<T>(items: &mut Vec<T>) -> usize {
+    let len = items.len();
+    // SAFETY: len goes to 0 first, so a panicking drop below can leak the
+    // rest but never drop one twice
+    unsafe { items.set_len(0) };
+    if !mem::needs_drop::<T>() {
+        return 0;  // Compiled out for u8, u64, Point, ...: O(1)
+    }
+    let base = items.as_mut_ptr();
+    for index in 0..len {
+        // SAFETY: index < the old len, each element is dropped exactly once
+        unsafe { ptr::drop_in_place(base.add(index)) };
+    }
+    len
+}
+
+/// One row of the clear-cost benchmark
+pub struct ClearRun {
+    pub name: &'static str,
+    pub needs_drop: bool,
+    pub elapsed: Duration,
+    /// None without alloc-stats
+    pub frees: Option<usize>,
+}
+
+/// Time clear() on a Vec made by `build` (only the clear is timed)
+pub fn time_clear<T>(name: &'static str, build: impl FnOnce() -> Vec<T>) -> ClearRun {
+    let mut items = build();
+    let start = Instant::now();
+    let ((), stats) = alloc_stats::measure(|| items.clear());
+    let elapsed = start.elapsed();
+    black_box(&items);
+    ClearRun { name, needs_drop: mem::needs_drop::<T>(), elapsed, frees: stats.map(|stats| stats.deallocations) }
+}
+
+// What the compiler says about each type
+pub fn needs_drop() -> DemoReport {
+    let mut report = DemoReport::new("needs_drop::<T>() - Which Types Have Drop Glue");
+
+    report.code("const NEEDS: bool = std::mem::needs_drop::<T>();  // a compile-time constant");
+    report.table(
+        &["type", "needs_drop", "why"],
+        glue_table().into_iter().map(|row| vec![row.name.to_string(), row.needs_drop.to_string(), row.why.to_string()]).collect(),
+    );
+
+    report.gap();
+    report.ok("Glue is structural: a type needs drop if it has a Drop impl or any field that needs drop");
+    report.text("Copy and Drop exclude each other: a bitwise copy can't also own a cleanup");
+    report.code("#[derive(Clone, Copy)] struct Handle(u32); impl Drop for Handle { .. }  // ❌ error[E0184]");
+    report
+}
+
+// Glue the compiler writes for a struct with no Drop impl
+pub fn generated_glue() -> DemoReport {
+    let mut report = DemoReport::new("Drop Glue Without a Drop Impl");
+
+    report.code("struct Pair { left: Counted, right: Counted, id: u32 }  // no impl Drop");
+    let before = drops();
+    {
+        let pair = Pair { left: Counted, right: Counted, id: 7 };
+        black_box(pair.id);
+    }
+    report.count("Counted drops when a Pair went out of scope", drops() - before);
+    report.text("The glue for Pair: drop left, drop right, nothing for id - written by the compiler");
+
+    report.step("Moving a field out");
+    let before = drops();
+    let kept = {
+        let pair = Pair { left: Counted, right: Counted, id: 8 };
+        pair.left  // Moved out: the glue for what's left of `pair` drops only `right`
+    };
+    report.count("drops at the end of the block (right only)", drops() - before);
+    drop(kept);
+    report.count("drops after drop(kept)", drops() - before);
+
+    report.step("Vec<Pair>: the glue runs once per element");
+    let before = drops();
+    let mut pairs: Vec<Pair> = (0..1_000).map(|id| Pair { left: Counted, right: Counted, id }).collect();
+    let glued = clear_in_place(&mut pairs);
+    report.count("elements clear_in_place ran glue for", glued);
+    report.count("Counted drops", drops() - before);
+
+    report.gap();
+    report.ok("You only write Drop for the type that owns the resource; containers inherit it");
+    report.text("Go has no equivalent to write: the GC decides when memory dies, not the type");
+    report
+}
+
+// The same clear(), with and without a loop over the elements
+pub fn clear_cost() -> DemoReport {
+    let mut report = DemoReport::new("Clearing a Vec - Glue Decides O(1) or O(n)");
+
+    report.count("elements per Vec", ELEMENTS);
+    if cfg!(debug_assertions) {
+        report.warn("Debug build - run with --release for real numbers");
+    }
+    let runs = [
+        time_clear("Vec<u8>", || vec![7u8; ELEMENTS]),
+        time_clear("Vec<u64>", || vec![7u64; ELEMENTS]),
+        time_clear("Vec<Point>", || vec![Point { x: 1.0, y: 2.0 }; ELEMENTS]),
+        time_clear("Vec<String> (all empty)", || vec![String::new(); ELEMENTS]),
+        time_clear("Vec<String> (16 bytes each)", || (0..ELEMENTS).map(|_| "x".repeat(16)).collect()),
+        time_clear("Vec<Box<u64>>", || (0..ELEMENTS as u64).map(Box::new).collect()),
+    ];
+    report.summary_table(
+        &["Vec", "needs_drop", "clear()", "ns/element", "frees"],
+        runs.iter()
+            .map(|run| {
+                vec![
+                    run.name.to_string(),
+                    run.needs_drop.to_string(),
+                    format!("{:.1?}", run.elapsed),
+                    format!("{:.2}", run.elapsed.as_nanos() as f64 / ELEMENTS as f64),
+                    run.frees.map_or("n/a".to_string(), |frees| frees.to_string()),
+                ]
+            })
+            .collect(),
+    );
+    if !alloc_stats::enabled() {
+        report.text("(free counts need --features alloc-stats)");
+    }
+
+    report.gap();
+    report.ok("No glue: clear() sets len = 0 and returns, whatever the length");
+    report.text("Empty Strings: the loop runs (String has glue) but each check finds no buffer to free");
+    report.text("Owned buffers: one free() per element - the cost Go's GC spreads over its cycles instead");
+    report.text("The buffer of the Vec itself stays allocated: clear() keeps the capacity");
+    report
+}
+
+pub fn glue_vs_go() -> DemoReport {
+    let mut report = DemoReport::new("Drop Glue vs Go's Pointer Scanning");
+
+    report.side_by_side(vec![
+        SideBySideRow::differs("make([]byte, n)  // noscan span: the GC never reads it", "vec![0u8; n]  // needs_drop::<u8>() == false: no glue"),
+        SideBySideRow::differs("make([]string, n)  // scanned on every GC cycle", "Vec<String>  // glue runs once, when it's dropped"),
+        SideBySideRow::differs("s = s[:0]  // old strings live on until the GC finds them", "v.clear()  // each String freed right here"),
+        SideBySideRow::differs("runtime.SetFinalizer(p, f)  // per object, eventually", "impl Drop for T  // per type, exactly at the end of scope"),
+    ]);
+
+    report.table(
+        &["", "Go", "Rust"],
+        vec![
+            vec!["the compiler tracks".into(), "which words are pointers (GC bitmaps)".into(), "which types need cleanup (drop glue)".into()],
+            vec!["pointer-free data".into(), "never scanned".into(), "never visited on drop".into()],
+            vec!["data with pointers".into(), "scanned each cycle while alive".into(), "visited once, when it dies".into()],
+            vec!["cost grows with".into(), "live heap × GC cycles".into(), "elements dropped".into()],
+        ],
+    );
+
+    report.gap();
+    report.ok("Both runtimes skip plain data - Go when marking, Rust when dropping");
+    report.text("Go pays for pointerful data while it's alive; Rust pays once, at the moment it's freed");
+    report
+}
+
+pub const DEMO: Demo = Demo {
+    name: "drop-glue-and-needs-drop",
+    about: "Drop glue and mem::needs_drop: which types have it, compiler-written glue, Vec<u8> vs Vec<String> clear cost, vs Go's noscan spans",
+    difficulty: Difficulty::Advanced,
+    tags: &[Tag::Ownership, Tag::Layout, Tag::Benchmark],
+    examples: &[
+        Example { name: "needs-drop", run: needs_drop },
+        Example { name: "generated", run: generated_glue },
+        Example { name: "clear-cost", run: clear_cost },
+        Example { name: "vs-go", run: glue_vs_go },
+    ],
+};
+
+pub fn demonstrate_drop_glue_and_needs_drop() -> Vec<DemoReport> {
+    DEMO.run()
+}
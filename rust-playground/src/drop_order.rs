@@ -0,0 +1,188 @@
+// Drop trait and destructor ordering - proving cleanup is deterministic
+// Every type here logs when its destructor runs, so the order is
+// observed, not just claimed in comments.
+// Go: defer runs LIFO at function exit; finalizers run "eventually" (maybe never)
+
+use std::cell::RefCell;
+use std::mem::ManuallyDrop;
+use std::rc::Rc;
+
+use crate::registry::{Demo, Difficulty, Example, Tag};
+use crate::report::DemoReport;
+
+type DropLog = Rc<RefCell<Vec<String>>>;
+
+// A value that records its own destruction
+struct Noisy {
+    name: &'static str,
+    log: DropLog,
+}
+
+impl Noisy {
+    fn new(name: &'static str, log: &DropLog) -> Self {
+        log.borrow_mut().push(format!("create {}", name));
+        Noisy { name, log: Rc::clone(log) }
+    }
+}
+
+impl Drop for Noisy {
+    fn drop(&mut self) {
+        self.log.borrow_mut().push(format!("drop   {}", self.name));
+    }
+}
+
+// A struct whose fields also log: shows outer-then-fields ordering
+struct Connection {
+    log: DropLog,
+    _socket: Noisy,
+    _buffer: Noisy,
+}
+
+impl Drop for Connection {
+    fn drop(&mut self) {
+        // Runs FIRST - fields are still alive and usable here
+        self.log.borrow_mut().push("drop   Connection (fields still alive)".to_string());
+    }
+}
+
+fn record_log(report: &mut DemoReport, log: &DropLog) {
+    for event in log.borrow_mut().drain(..) {
+        report.text(event);
+    }
+}
+
+// Locals drop in REVERSE declaration order
+pub fn scope_drop_order() -> DemoReport {
+    let mut report = DemoReport::new("Scope Drop Order - Reverse Declaration");
+    let log = DropLog::default();
+
+    {
+        let _first = Noisy::new("first", &log);
+        let _second = Noisy::new("second", &log);
+        let _third = Noisy::new("third", &log);
+        log.borrow_mut().push("-- scope ends --".to_string());
+    }
+
+    record_log(&mut report, &log);
+
+    report.gap();
+    report.ok("Dropped third → second → first (like a stack)");
+    report.ok("Later values may borrow earlier ones, so they must die first");
+    report.ok("Same LIFO order as Go's defer - but automatic");
+    report
+}
+
+// Struct: outer Drop first, then fields in DECLARATION order
+pub fn field_drop_order() -> DemoReport {
+    let mut report = DemoReport::new("Field Drop Order - Outer First, Then Fields");
+    let log = DropLog::default();
+
+    {
+        let _conn = Connection {
+            log: Rc::clone(&log),
+            _socket: Noisy::new("socket (field 1)", &log),
+            _buffer: Noisy::new("buffer (field 2)", &log),
+        };
+        log.borrow_mut().push("-- scope ends --".to_string());
+    }
+
+    record_log(&mut report, &log);
+
+    report.gap();
+    report.ok("Connection::drop runs before its fields are destroyed");
+    report.ok("Fields drop in declaration order (NOT reversed)");
+    report.ok("Vec elements drop front to back, too");
+    report
+}
+
+// std::mem::drop - end a value's life early
+pub fn explicit_drop() -> DemoReport {
+    let mut report = DemoReport::new("std::mem::drop - Ending Ownership Early");
+    let log = DropLog::default();
+
+    {
+        let lock = Noisy::new("lock guard", &log);
+        let _data = Noisy::new("data", &log);
+
+        drop(lock);  // Moves `lock` into drop() → destroyed right now
+        log.borrow_mut().push("... still working, lock already released".to_string());
+        // println!("{}", lock.name);  // ❌ Error: borrow of moved value
+
+        log.borrow_mut().push("-- scope ends --".to_string());
+    }
+
+    record_log(&mut report, &log);
+
+    report.gap();
+    report.ok("drop(x) is just `fn drop<T>(_x: T) {}` - it takes ownership");
+    report.ok("Compiler forbids using x afterwards");
+    report.text("Typical use: release a MutexGuard before slow work");
+    report
+}
+
+// ManuallyDrop - opt out of automatic destruction
+pub fn manually_drop_example() -> DemoReport {
+    let mut report = DemoReport::new("ManuallyDrop - Opting Out of Drop");
+    let log = DropLog::default();
+
+    {
+        let _auto = Noisy::new("auto", &log);
+        let _never = ManuallyDrop::new(Noisy::new("manual (never dropped)", &log));
+        let mut later = ManuallyDrop::new(Noisy::new("manual (dropped by hand)", &log));
+
+        log.borrow_mut().push("-- dropping `later` by hand --".to_string());
+        // SAFETY: `later` is not used again after this
+        unsafe { ManuallyDrop::drop(&mut later) };
+
+        log.borrow_mut().push("-- scope ends --".to_string());
+    }
+
+    record_log(&mut report, &log);
+    report.count("Rc strong_count of the log (leaked clone inside `_never`)", Rc::strong_count(&log));
+
+    report.gap();
+    report.ok("ManuallyDrop<T> has the same layout as T, but no drop glue");
+    report.warn("The 'never' value leaked: its destructor didn't run");
+    report.warn("ManuallyDrop::drop is unsafe - calling it twice is a double free");
+    report
+}
+
+pub fn drop_vs_go() -> DemoReport {
+    let mut report = DemoReport::new("Drop vs Go's defer and Finalizers");
+
+    report.step("Go defer");
+    report.code("f, _ := os.Open(path)");
+    report.code("defer f.Close()  // Runs at FUNCTION exit, LIFO");
+    report.ok("Deterministic, but you must remember to write it");
+    report.warn("Tied to the function, not to the value's lifetime");
+
+    report.step("Go finalizer");
+    report.code("runtime.SetFinalizer(obj, func(o *T) { ... })");
+    report.warn("Runs whenever the GC gets to it - or never");
+    report.warn("Order between objects is unspecified");
+
+    report.step("Rust Drop");
+    report.code("impl Drop for File { fn drop(&mut self) { close(self.fd) } }");
+    report.ok("Runs exactly when the owner goes out of scope");
+    report.ok("Order is specified: locals reversed, fields in order");
+    report.ok("Can't be forgotten - the type does it, not the caller");
+    report
+}
+
+pub const DEMO: Demo = Demo {
+    name: "drop-order",
+    about: "Drop trait and destructor ordering vs Go's defer",
+    difficulty: Difficulty::Beginner,
+    tags: &[Tag::Ownership],
+    examples: &[
+        Example { name: "scope", run: scope_drop_order },
+        Example { name: "fields", run: field_drop_order },
+        Example { name: "explicit-drop", run: explicit_drop },
+        Example { name: "manually-drop", run: manually_drop_example },
+        Example { name: "vs-go", run: drop_vs_go },
+    ],
+};
+
+pub fn demonstrate_drop_order() -> Vec<DemoReport> {
+    DEMO.run()
+}
@@ -0,0 +1,239 @@
+// Dynamically sized types and fat pointers - how wide is a pointer?
+// Go:   every type has a size known at compile time. A slice is a 24-byte
+//       header value (ptr, len, cap), a string 16 (ptr, len), an interface
+//       16 (type/itab, data). They're ordinary structs the runtime builds.
+// Rust: [T], str and dyn Trait have no size of their own - they're
+//       "dynamically sized types" (DSTs) and only exist behind a pointer.
+//       That pointer carries the missing piece as a second word: the length
+//       for slices and str, the vtable for trait objects. &T, Box<T>, Rc<T>
+//       and *const T are all thin (one word) or fat (two) by the same rule.
+
+use std::any::Any;
+use std::fmt::{Debug, Display};
+use std::rc::Rc;
+
+use crate::registry::{Demo, Difficulty, Example, Tag};
+use crate::report::DemoReport;
+
+/// A header followed by an unsized payload - a user-defined DST.
+/// Build it sized (`Packet<[u8; N]>`) and let `&Packet<[u8]>` coerce.
+#[derive(Debug)]
+pub struct Packet<P: ?Sized> {
+    pub id: u32,
+    pub payload: P,
+}
+
+impl Packet<[u8]> {
+    pub fn checksum(&self) -> u32 {
+        self.payload.iter().map(|&byte| byte as u32).sum::<u32>() ^ self.id
+    }
+}
+
+/// The words of a pointer, read as integers: the address, then the metadata of a fat one.
+/// The layout of &[T] / &dyn is not a stable guarantee - fine for looking, not for relying on.
+pub fn words<T: ?Sized>(pointer: &T) -> Vec<usize> {
+    let data = (pointer as *const T).cast::<()>() as usize;
+    if size_of::<&T>() == size_of::<usize>() {
+        return vec![data];
+    }
+    assert_eq!(size_of::<&T>(), 2 * size_of::<usize>(), "a fat pointer is two words");
+    // SAFETY: `pointer` is exactly two words (asserted above), so transmute_copy reads only
+    // its own bytes, and any bits are a valid usize. Address first, metadata second is the
+    // current fat-pointer layout, not a guarantee - so the first word is checked too.
+    let [first, metadata]: [usize; 2] = unsafe { std::mem::transmute_copy(&pointer) };
+    assert_eq!(first, data, "fat-pointer layout changed: the data pointer isn't the first word");
+    vec![first, metadata]
+}
+
+fn width_row<T: ?Sized>(pointee: &str, metadata: &str) -> Vec<String> {
+    let shape = if size_of::<&T>() == size_of::<usize>() { "thin" } else { "fat" };
+    vec![
+        pointee.to_string(),
+        size_of::<&T>().to_string(),
+        size_of::<Box<T>>().to_string(),
+        size_of::<Rc<T>>().to_string(),
+        size_of::<*const T>().to_string(),
+        shape.to_string(),
+        metadata.to_string(),
+    ]
+}
+
+// One table: the pointee decides the pointer's width, not the pointer type
+pub fn pointer_widths() -> DemoReport {
+    let mut report = DemoReport::new("Pointer Widths - Thin and Fat");
+
+    report.table(
+        &["T", "&T", "Box<T>", "Rc<T>", "*const T", "", "second word"],
+        vec![
+            width_row::<i32>("i32", "-"),
+            width_row::<[i32; 4]>("[i32; 4]", "- (len is in the type)"),
+            width_row::<String>("String", "-"),
+            width_row::<[i32]>("[i32]", "element count"),
+            width_row::<str>("str", "byte length"),
+            width_row::<dyn Display>("dyn Display", "vtable pointer"),
+            width_row::<dyn Any + Send>("dyn Any + Send", "vtable pointer (auto traits add nothing)"),
+            width_row::<Packet<[u8]>>("Packet<[u8]>", "payload length"),
+        ],
+    );
+
+    report.gap();
+    report.ok("Sized T → one word. Unsized T → two: the address plus what the type can't tell you");
+    report.ok("Box, Rc, &, *const follow the same rule - the width belongs to the pointee");
+    report.text("[i32; 4] is sized: its length is part of the type, so &[i32; 4] is thin");
+    report.text("&&str / Box<Box<dyn Display>> are thin again - the usual trick to pass a DST through one word (FFI, AtomicPtr)");
+
+    report.step("Go");
+    report.code("unsafe.Sizeof(&x)          // 8: Go pointers are always thin");
+    report.code("unsafe.Sizeof([]int32{})   // 24: slices are a header VALUE, not a pointer");
+    report.code("unsafe.Sizeof(\"\")          // 16");
+    report.code("unsafe.Sizeof(fmt.Stringer(nil))  // 16");
+    report
+}
+
+// The length word, in slices, str and Box<str>
+pub fn slice_metadata() -> DemoReport {
+    let mut report = DemoReport::new("Slices - the Length Lives in the Pointer");
+
+    let numbers = [10, 20, 30, 40, 50];
+    let whole: &[i32] = &numbers;
+    let middle: &[i32] = &numbers[1..4];
+    let rows = [("&numbers", whole), ("&numbers[1..4]", middle), ("&numbers[..0]", &numbers[..0])]
+        .into_iter()
+        .map(|(name, slice)| {
+            let words = words(slice);
+            vec![name.to_string(), format!("{:#x}", words[0]), words[1].to_string(), size_of_val(slice).to_string()]
+        })
+        .collect();
+    report.table(&["pointer", "word 0 (data)", "word 1 (len)", "size_of_val"], rows);
+    report.ok("Sub-slicing makes a new fat pointer: data moves by 4 bytes, len shrinks - nothing is copied");
+    report.ok("size_of_val = len × size_of::<i32>() - computed from the pointer, there's no header in memory");
+
+    report.step("str, String and Box<str>");
+    let owned = String::from("héllo");
+    let text: &str = &owned;
+    report.text(format!("{:?}: the &str's len word is {} - it counts bytes, and é takes 2", text, words(text)[1]));
+    let mut growable = String::with_capacity(64);
+    growable.push_str("frozen");
+    let boxed: Box<str> = growable.into_boxed_str();
+    report.table(
+        &["type", "size", "fields"],
+        vec![
+            vec!["String".into(), size_of::<String>().to_string(), "ptr, len, capacity".into()],
+            vec!["Box<str>".into(), size_of::<Box<str>>().to_string(), "ptr, len (fat pointer, owning)".into()],
+            vec!["&str".into(), size_of::<&str>().to_string(), "ptr, len (fat pointer, borrowed)".into()],
+        ],
+    );
+    report.text(format!("into_boxed_str() dropped the capacity: {:?} is exactly {} heap bytes", boxed, boxed.len()));
+    report.ok("Box<str> / Box<[T]>: for strings and buffers that will never grow - 8 bytes smaller, no slack");
+
+    report.step("Go");
+    report.code("s := nums[1:4]  // new header: {ptr+4, len 3, cap 4} - 24 bytes, cap kept");
+    report.text("Go slices carry cap so append can grow in place; a Rust slice can't grow at all");
+    report
+}
+
+// What unsized means: no locals, no by-value - but fine behind a pointer
+pub fn unsized_types() -> DemoReport {
+    let mut report = DemoReport::new("DSTs - Types Without a Size");
+
+    report.code("let text: str = *\"hello\";  // ❌ error[E0277]: the size for values of type `str` cannot be known at compilation time");
+    report.text("Checked by tests/compile_fail/unsized_local.rs");
+    report.text("A local needs a stack slot of a fixed size. str, [T], dyn Trait don't have one");
+
+    report.step("?Sized: accepting DSTs in generics");
+    report.code("fn words<T: ?Sized>(pointer: &T) -> ...  // T: Sized is implied everywhere else");
+    report.text(format!("words(&5i32)      = {} word", words(&5i32).len()));
+    report.text(format!("words(\"str\")      = {} words", words("str").len()));
+    report.text(format!("words(&5 as &dyn Debug) = {} words", words(&5 as &dyn Debug).len()));
+
+    report.step("A DST of your own");
+    report.code("struct Packet<P: ?Sized> { id: u32, payload: P }");
+    report.code("let sized: Packet<[u8; 6]> = Packet { id: 7, payload: *b\"GET /\\n\" };");
+    report.code("let packet: &Packet<[u8]> = &sized;  // unsizing coercion: length moves into the pointer");
+    let sized: Packet<[u8; 6]> = Packet { id: 7, payload: *b"GET /\n" };
+    let packet: &Packet<[u8]> = &sized;
+    let boxed: Box<Packet<[u8]>> = Box::new(Packet { id: 8, payload: [0u8; 100] });
+    report.table(
+        &["value", "pointer size", "word 1", "size_of_val", "checksum"],
+        vec![
+            vec!["&Packet<[u8; 6]>".into(), size_of::<&Packet<[u8; 6]>>().to_string(), "-".into(), size_of_val(&sized).to_string(), "-".into()],
+            vec!["&Packet<[u8]> (6)".into(), size_of::<&Packet<[u8]>>().to_string(), words(packet)[1].to_string(), size_of_val(packet).to_string(), packet.checksum().to_string()],
+            vec!["Box<Packet<[u8]>> (100)".into(), size_of::<Box<Packet<[u8]>>>().to_string(), words(&*boxed)[1].to_string(), size_of_val(&*boxed).to_string(), boxed.checksum().to_string()],
+        ],
+    );
+
+    report.gap();
+    report.ok("One impl on Packet<[u8]> serves every payload length - the length is data, not a type");
+    report.text("size_of_val = header (4) + payload, rounded up to the u32 alignment");
+    report.text("Only the last field may be unsized: its offset must be known without knowing its length");
+    report
+}
+
+// The vtable word: same data, different traits, different tables
+pub fn vtable_metadata() -> DemoReport {
+    let mut report = DemoReport::new("Trait Objects - the Vtable Lives in the Pointer");
+
+    let value: u64 = 42;
+    let label = String::from("forty-two");
+    let rows = [
+        ("&value as &dyn Debug", words(&value as &dyn Debug), size_of_val(&value as &dyn Debug)),
+        ("&value as &dyn Display", words(&value as &dyn Display), size_of_val(&value as &dyn Display)),
+        ("&label as &dyn Debug", words(&label as &dyn Debug), size_of_val(&label as &dyn Debug)),
+        ("&label as &dyn Display", words(&label as &dyn Display), size_of_val(&label as &dyn Display)),
+    ]
+    .into_iter()
+    .map(|(name, words, size)| vec![name.to_string(), format!("{:#x}", words[0]), format!("{:#x}", words[1]), size.to_string()])
+    .collect();
+    report.table(&["pointer", "word 0 (data)", "word 1 (vtable)", "size_of_val"], rows);
+
+    report.gap();
+    report.ok("Same data word, different vtables: one table per (concrete type, trait) pair");
+    report.ok("size_of_val reads size from the vtable: 8 for u64, 24 for String - the pointer alone can't know");
+    report.text("A vtable: drop_in_place, size, align, then the trait's methods - static data in the binary");
+    report.text("See `run traits-vs-interfaces -e fat-pointers` for dispatch through it");
+
+    report.step("Go");
+    report.code("var d fmt.Stringer = v   // (itab, data)");
+    report.text("Same two words, opposite order. Go stores non-pointer values in interfaces by pointer to a heap copy;");
+    report.text("a Rust &dyn points wherever the value already is - stack included");
+    report
+}
+
+pub fn dst_vs_go() -> DemoReport {
+    let mut report = DemoReport::new("Fat Pointers: Go vs Rust");
+
+    report.table(
+        &["", "Go", "Rust"],
+        vec![
+            vec!["view of a sequence".into(), "[]T: ptr, len, cap (24)".into(), "&[T]: ptr, len (16)".into()],
+            vec!["text".into(), "string: ptr, len (16)".into(), "&str / Box<str>: ptr, len (16)".into()],
+            vec!["growable buffer".into(), "the same []T".into(), "Vec<T> / String: ptr, len, cap (24)".into()],
+            vec!["dynamic dispatch".into(), "interface: itab, data (16)".into(), "&dyn / Box<dyn>: data, vtable (16)".into()],
+            vec!["unsized types".into(), "none - every type has a size".into(), "[T], str, dyn Trait, custom tails".into()],
+            vec!["plain pointer".into(), "*T: always 8".into(), "&T: 8 if T: Sized, else 16".into()],
+        ],
+    );
+
+    report.gap();
+    report.ok("Same machine shapes - Go builds them as header structs, Rust as two-word pointers to a DST");
+    report.text("Rust splits Go's slice in two: the view (&[T], no cap) and the owner (Vec<T>, with cap)");
+    report
+}
+
+pub const DEMO: Demo = Demo {
+    name: "dst-and-fat-pointers",
+    about: "Dynamically sized types and fat pointers: slices, str, dyn Trait and pointer widths",
+    difficulty: Difficulty::Intermediate,
+    tags: &[Tag::Layout],
+    examples: &[
+        Example { name: "pointer-widths", run: pointer_widths },
+        Example { name: "slice-metadata", run: slice_metadata },
+        Example { name: "unsized", run: unsized_types },
+        Example { name: "vtables", run: vtable_metadata },
+        Example { name: "vs-go", run: dst_vs_go },
+    ],
+};
+
+pub fn demonstrate_dst_and_fat_pointers() -> Vec<DemoReport> {
+    DEMO.run()
+}
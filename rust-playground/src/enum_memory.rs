@@ -0,0 +1,417 @@
+// Enums in memory - tagged unions, discriminants and niches
+// Go:   no sum types. A "one of" is an interface value (two words: type +
+//       pointer, the payload usually boxed on the heap) or a struct with a
+//       kind field and room for every variant's fields at once. A type
+//       switch or `switch s.Kind` doesn't have to cover every case.
+// Rust: an enum is a tagged union stored inline: the largest variant plus
+//       a tag, rounded up to the alignment. The tag is often free - it hides
+//       in bit patterns a payload can never have (a null pointer, bool's
+//       2..=255, char past 0x10FFFF): the niche. #[repr(u8)] pins the tag's
+//       size and values when you need to see them.
+
+use std::cmp::Ordering;
+use std::hint::black_box;
+use std::mem::{align_of, discriminant, size_of, transmute};
+use std::num::NonZeroU8;
+use std::time::{Duration, Instant};
+
+use crate::alloc_stats;
+use crate::registry::{Demo, Difficulty, Example, Tag};
+use crate::report::{DemoReport, SideBySideRow};
+
+/// Events fed to both state machines
+pub const EVENTS: usize = 200_000;
+
+fn allocations_of<R>(f: impl FnOnce() -> R) -> (R, String) {
+    let (result, stats) = alloc_stats::measure(f);
+    (result, stats.map_or("n/a (needs --features alloc-stats)".to_string(), |stats| stats.allocations.to_string()))
+}
+
+fn size_row<T>(name: &str, why: &str) -> Vec<String> {
+    vec![name.to_string(), size_of::<T>().to_string(), align_of::<T>().to_string(), why.to_string()]
+}
+
+#[allow(dead_code)]
+enum Shape {
+    Circle(f64),
+    Rect(f64, f64),
+}
+
+#[allow(dead_code)]
+enum Message {
+    Quit,
+    Move { x: i32, y: i32 },
+    Write(String),
+    Color(u8, u8, u8),
+}
+
+// Go's struct+flag version of Shape, field for field
+#[allow(dead_code)]
+#[repr(C)]
+struct ShapeWithKind {
+    kind: u8,
+    radius: f64,
+    width: f64,
+    height: f64,
+}
+
+// Largest variant + tag, and when the tag costs nothing
+pub fn sizes() -> DemoReport {
+    let mut report = DemoReport::new("Enum Sizes - Largest Variant Plus a Tag");
+
+    report.code("enum Shape { Circle(f64), Rect(f64, f64) }");
+    report.code("enum Message { Quit, Move { x: i32, y: i32 }, Write(String), Color(u8, u8, u8) }");
+    report.table(
+        &["type", "size", "align", "why"],
+        vec![
+            size_row::<Ordering>("Ordering", "fieldless: just the tag, 1 byte"),
+            size_row::<Shape>("Shape", "16 (Rect) + tag, rounded up to 8: f64 has no niche"),
+            size_row::<ShapeWithKind>("Go-style struct + kind", "every variant's fields side by side"),
+            size_row::<Message>("Message", "= String: the tag hides in String's capacity"),
+            size_row::<String>("String", "ptr, capacity, len"),
+            size_row::<Result<u64, u8>>("Result<u64, u8>", "8 + tag, rounded up to 8"),
+            size_row::<Result<u32, ()>>("Result<u32, ()>", "4 + tag, rounded up to 4"),
+            size_row::<Option<Vec<u8>>>("Option<Vec<u8>>", "Vec's pointer is never null: None is"),
+            size_row::<Result<(), Box<dyn std::error::Error>>>("Result<(), Box<dyn Error>>", "a fat pointer; Ok is the null niche"),
+        ],
+    );
+
+    report.gap();
+    report.ok(format!("Stored inline: a Vec<Shape> is one allocation, {} bytes per shape", size_of::<Shape>()));
+    report.text("The tag is whatever's left after the biggest variant - often padding that was there anyway");
+    report.warn("One big variant makes every value big: Box the rare large payload (clippy::large_enum_variant)");
+    report
+}
+
+/// A bytecode instruction with pinned tag values
+#[allow(dead_code)]
+#[repr(u8)]
+pub enum Opcode {
+    Nop = 0,
+    Push(u32) = 1,
+    Jump { offset: i16 } = 7,
+    Halt = 0xFF,
+}
+
+#[repr(u8)]
+#[derive(Debug, Clone, Copy)]
+enum Level {
+    Debug = 10,
+    Info = 20,
+    Warn = 30,
+}
+
+impl Opcode {
+    /// The tag byte, read from memory
+    pub fn tag(&self) -> u8 {
+        // SAFETY: #[repr(u8)] lays an enum out as a union of #[repr(C)]
+        // structs that all start with the u8 tag, so byte 0 is the tag
+        unsafe { *(self as *const Opcode as *const u8) }
+    }
+}
+
+// #[repr(u8)], explicit values, `as`, and mem::discriminant
+pub fn discriminants() -> DemoReport {
+    let mut report = DemoReport::new("Discriminants - What the Tag Holds");
+
+    report.code("#[repr(u8)] enum Level { Debug = 10, Info = 20, Warn = 30 }");
+    report.table(
+        &["variant", "as u8"],
+        [Level::Debug, Level::Info, Level::Warn].iter().map(|&level| vec![format!("{:?}", level), (level as u8).to_string()]).collect(),
+    );
+    report.text(format!("size_of::<Level>() = {} - same as a u8, like Go's `type Level uint8` + iota", size_of::<Level>()));
+
+    report.step("Variants with fields");
+    report.code("#[repr(u8)] enum Opcode { Nop = 0, Push(u32) = 1, Jump { offset: i16 } = 7, Halt = 0xFF }");
+    let program = [Opcode::Nop, Opcode::Push(42), Opcode::Jump { offset: -3 }, Opcode::Halt];
+    report.table(
+        &["value", "tag byte in memory"],
+        program
+            .iter()
+            .map(|op| {
+                let name = match op {
+                    Opcode::Nop => "Nop".to_string(),
+                    Opcode::Push(n) => format!("Push({})", n),
+                    Opcode::Jump { offset } => format!("Jump {{ offset: {} }}", offset),
+                    Opcode::Halt => "Halt".to_string(),
+                };
+                vec![name, format!("{:#04x}", op.tag())]
+            })
+            .collect(),
+    );
+    let push = &program[1];
+    if let Opcode::Push(operand) = push {
+        let offset = operand as *const u32 as usize - push as *const Opcode as usize;
+        report.text(format!("size_of::<Opcode>() = {}: tag at offset 0, Push's u32 at offset {}", size_of::<Opcode>(), offset));
+    }
+    report.code("Opcode::Push(42) as u8  // ❌ error[E0605]: non-primitive cast: `Opcode` as `u8`");
+    report.text("Checked by tests/compile_fail/enum_cast_with_fields.rs - `as` only works on fieldless enums");
+
+    report.step("Comparing variants, not payloads");
+    let same = discriminant(&Opcode::Push(1)) == discriminant(&Opcode::Push(2));
+    report.code("mem::discriminant(&Push(1)) == mem::discriminant(&Push(2))");
+    report.text(format!("= {} - an opaque tag you can compare and hash, for any enum", same));
+
+    report.gap();
+    report.ok("Without a repr the compiler picks the tag's size and values - and may use a niche instead");
+    report.text("Pin them (#[repr(u8)], #[repr(C, u8)]) for FFI, file formats, or to read the tag like above");
+    report
+}
+
+#[allow(dead_code)]
+#[derive(Clone, Copy)]
+enum Cell {
+    Empty,
+    Wall,
+    Item(bool),
+}
+
+// The bit patterns that say "None"
+pub fn niches() -> DemoReport {
+    let mut report = DemoReport::new("Niches - Where None Hides");
+
+    // SAFETY: each pair has the same size and every byte of the enum is
+    // initialized (no padding), so reading it as an integer is sound
+    let rows = unsafe {
+        vec![
+            vec!["Option<bool>".into(), size_of::<Option<bool>>().to_string(), format!("{}u8", transmute::<Option<bool>, u8>(None)), "bool only uses 0 and 1".into()],
+            vec!["Option<Option<bool>>".into(), size_of::<Option<Option<bool>>>().to_string(), format!("{}u8", transmute::<Option<Option<bool>>, u8>(None)), "Some(None) took 2".into()],
+            vec!["Option<Ordering>".into(), size_of::<Option<Ordering>>().to_string(), format!("{}i8", transmute::<Option<Ordering>, i8>(None)), "Ordering is -1, 0, 1".into()],
+            vec!["Option<NonZeroU8>".into(), size_of::<Option<NonZeroU8>>().to_string(), format!("{}u8", transmute::<Option<NonZeroU8>, u8>(None)), "0 is never a NonZeroU8".into()],
+            vec!["Option<char>".into(), size_of::<Option<char>>().to_string(), format!("{:#x}", transmute::<Option<char>, u32>(None)), "one past char::MAX".into()],
+            vec!["Option<&u64>".into(), size_of::<Option<&u64>>().to_string(), format!("{:#x}", transmute::<Option<&u64>, usize>(None)), "references are never null".into()],
+            vec!["Cell::Empty".into(), size_of::<Cell>().to_string(), format!("{}u8", transmute::<Cell, u8>(Cell::Empty)), "Item(bool) leaves 2..=255 free".into()],
+            vec!["Cell::Wall".into(), size_of::<Cell>().to_string(), format!("{}u8", transmute::<Cell, u8>(Cell::Wall)), "...for every dataless variant".into()],
+        ]
+    };
+    report.table(&["value", "size", "bit pattern", "why it's free"], rows);
+    report.code("enum Cell { Empty, Wall, Item(bool) }  // 1 byte: Item(false)=0, Item(true)=1, Empty=2, Wall=3");
+
+    report.step("Niches inside structs");
+    report.text(format!("Option<(u32, bool)> = {} bytes - the niche can be any field's", size_of::<Option<(u32, bool)>>()));
+    report.text(format!("Option<u32> = {} bytes - every u32 is valid, so the tag needs room of its own", size_of::<Option<u32>>()));
+    report.text(format!("Option<Option<u32>> = {} bytes - the tag of the inner Option has spare values", size_of::<Option<Option<u32>>>()));
+
+    report.gap();
+    report.ok("A niche is an invalid bit pattern of the payload; the compiler spends it on the tag");
+    report.warn("These values are layout details, not guarantees - only Option<&T>, Option<Box<T>>, Option<NonZero*> and friends are promised");
+    report
+}
+
+// The same little protocol as an enum and as trait objects
+
+/// What happens to a connection
+#[derive(Debug, Clone, Copy)]
+pub enum Event {
+    Open,
+    Data(u32),
+    Flush,
+    Close,
+}
+
+/// A deterministic mix of events
+pub fn events(count: usize) -> Vec<Event> {
+    (0..count)
+        .map(|i| match i % 8 {
+            0 => Event::Open,
+            1 | 2 | 4 | 5 => Event::Data((i % 97) as u32),
+            3 | 6 => Event::Flush,
+            _ => Event::Close,
+        })
+        .collect()
+}
+
+/// The enum version: one 8-byte value, replaced on every event
+#[derive(Debug, Clone, Copy)]
+pub enum Conn {
+    Idle,
+    Open { buffered: u32 },
+    Flushing { pending: u32 },
+    Closed,
+}
+
+impl Conn {
+    pub fn on(self, event: Event, flushed: &mut u64) -> Conn {
+        match (self, event) {
+            (Conn::Idle | Conn::Closed, Event::Open) => Conn::Open { buffered: 0 },
+            (Conn::Idle, _) => Conn::Idle,
+            (Conn::Closed, _) => Conn::Closed,
+            (Conn::Open { buffered }, Event::Data(n)) => Conn::Open { buffered: buffered + n },
+            (Conn::Open { buffered }, Event::Flush) => Conn::Flushing { pending: buffered },
+            (Conn::Open { .. }, Event::Close) => Conn::Closed,
+            (Conn::Open { buffered }, Event::Open) => Conn::Open { buffered },
+            (Conn::Flushing { pending }, event) => {
+                *flushed += pending as u64;
+                Conn::Open { buffered: 0 }.on(event, flushed)
+            }
+        }
+    }
+}
+
+/// Bytes flushed by the enum state machine
+pub fn run_enum(events: &[Event]) -> u64 {
+    let mut flushed = 0;
+    let mut state = Conn::Idle;
+    for &event in events {
+        state = state.on(event, &mut flushed);
+    }
+    flushed
+}
+
+/// The trait-object version: each state its own type, Go-interface style
+pub trait ConnState {
+    fn on(self: Box<Self>, event: Event, flushed: &mut u64) -> Box<dyn ConnState>;
+}
+
+struct Idle;
+struct Open {
+    buffered: u32,
+}
+struct Flushing {
+    pending: u32,
+}
+struct Closed;
+
+impl ConnState for Idle {
+    fn on(self: Box<Self>, event: Event, _: &mut u64) -> Box<dyn ConnState> {
+        match event {
+            Event::Open => Box::new(Open { buffered: 0 }),
+            _ => self,
+        }
+    }
+}
+
+impl ConnState for Open {
+    fn on(mut self: Box<Self>, event: Event, _: &mut u64) -> Box<dyn ConnState> {
+        match event {
+            Event::Data(n) => {
+                self.buffered += n;
+                self  // same Box, updated in place
+            }
+            Event::Flush => Box::new(Flushing { pending: self.buffered }),
+            Event::Close => Box::new(Closed),
+            Event::Open => self,
+        }
+    }
+}
+
+impl ConnState for Flushing {
+    fn on(self: Box<Self>, event: Event, flushed: &mut u64) -> Box<dyn ConnState> {
+        *flushed += self.pending as u64;
+        let open: Box<dyn ConnState> = Box::new(Open { buffered: 0 });
+        open.on(event, flushed)
+    }
+}
+
+impl ConnState for Closed {
+    fn on(self: Box<Self>, event: Event, _: &mut u64) -> Box<dyn ConnState> {
+        match event {
+            Event::Open => Box::new(Open { buffered: 0 }),
+            _ => self,
+        }
+    }
+}
+
+/// Bytes flushed by the trait-object state machine
+pub fn run_dyn(events: &[Event]) -> u64 {
+    let mut flushed = 0;
+    let mut state: Box<dyn ConnState> = Box::new(Idle);
+    for &event in events {
+        state = state.on(event, &mut flushed);
+    }
+    flushed
+}
+
+fn best_of_three(run: impl Fn() -> u64) -> Duration {
+    (0..3)
+        .map(|_| {
+            let start = Instant::now();
+            black_box(run());
+            start.elapsed()
+        })
+        .min()
+        .unwrap_or_default()
+}
+
+// enum match vs Box<dyn State> on the same event stream
+pub fn state_machine() -> DemoReport {
+    let mut report = DemoReport::new("State Machine: enum vs Box<dyn State>");
+
+    let events = events(EVENTS);
+    report.code("enum Conn { Idle, Open { buffered: u32 }, Flushing { pending: u32 }, Closed }  // state = state.on(event)");
+    report.code("trait ConnState { fn on(self: Box<Self>, event: Event, ..) -> Box<dyn ConnState>; }  // one struct per state");
+    if cfg!(debug_assertions) {
+        report.warn("Debug build - use --release for real numbers");
+    }
+
+    let (enum_flushed, enum_allocs) = allocations_of(|| run_enum(&events));
+    let (dyn_flushed, dyn_allocs) = allocations_of(|| run_dyn(&events));
+    let enum_time = best_of_three(|| run_enum(black_box(&events)));
+    let dyn_time = best_of_three(|| run_dyn(black_box(&events)));
+    let per_event = |time: Duration| format!("{:.2}", time.as_nanos() as f64 / EVENTS as f64);
+    report.summary_table(
+        &["state machine", "state size", "time", "ns/event", "allocs"],
+        vec![
+            vec!["enum Conn + match".into(), format!("{} B inline", size_of::<Conn>()), format!("{:.1?}", enum_time), per_event(enum_time), enum_allocs],
+            vec!["Box<dyn ConnState>".into(), format!("{} B + heap", size_of::<Box<dyn ConnState>>()), format!("{:.1?}", dyn_time), per_event(dyn_time), dyn_allocs],
+        ],
+    );
+    if enum_flushed == dyn_flushed {
+        report.ok(format!("Both flushed {} bytes over {} events", enum_flushed, EVENTS));
+    } else {
+        report.fail(format!("The machines disagree: {} vs {} bytes flushed", enum_flushed, dyn_flushed));
+    }
+
+    report.gap();
+    report.ok("enum: the state is a u32 and a tag in a register; the match compiles to a jump table");
+    report.warn("dyn: a vtable call per event and a fresh Box per state change (Idle and Closed are free - zero-sized)");
+    report.text("Trait objects win when states come from outside the crate: a closed set is an enum's job");
+    report
+}
+
+pub fn enum_vs_go() -> DemoReport {
+    let mut report = DemoReport::new("Sum Types: Go vs Rust");
+
+    report.side_by_side(vec![
+        SideBySideRow::differs("type Shape interface{ Area() float64 }", "enum Shape { Circle(f64), Rect(f64, f64) }"),
+        SideBySideRow::differs("var s Shape = Circle{r: 1}  // 16 B + boxed Circle", "let s = Shape::Circle(1.0);  // 24 B, inline"),
+        SideBySideRow::differs("switch v := s.(type) { case Circle: ... }", "match s { Shape::Circle(r) => ..., Shape::Rect(w, h) => ... }"),
+        SideBySideRow::differs("}  // forgot Rect: compiles", "}  // forgot Rect: error[E0004]"),
+        SideBySideRow::same("const ( Debug Level = iota; Info; Warn )", "#[repr(u8)] enum Level { Debug, Info, Warn }"),
+    ]);
+
+    report.table(
+        &["", "Go", "Rust"],
+        vec![
+            vec!["sum type".into(), "interface or struct + kind".into(), "enum".into()],
+            vec!["size of a Shape".into(), "16 B (itab, data) + heap payload".into(), format!("{} B, no heap", size_of::<Shape>())],
+            vec!["struct + kind".into(), format!("{} B: all fields, always", size_of::<ShapeWithKind>()), "-".into()],
+            vec!["[]Shape of 1000".into(), "16 KB of interfaces + up to 1000 objects".into(), format!("one {} KB buffer", size_of::<Shape>())],
+            vec!["missing case".into(), "compiles".into(), "E0004".into()],
+            vec!["nil / empty".into(), "nil interface, zero struct".into(), format!("Option<Shape> ({} B: a spare tag value)", size_of::<Option<Shape>>())],
+        ],
+    );
+
+    report.gap();
+    report.ok("Go's interface boxing is the GC's work; a Rust enum is a value like an int");
+    report.text("Go's own sum-type proposals keep stalling on exactly this: what is the zero value of a sum?");
+    report
+}
+
+pub const DEMO: Demo = Demo {
+    name: "enum-memory",
+    about: "Enums as tagged unions: sizes, #[repr(u8)] discriminants, niche bit patterns, enum vs Box<dyn> state machines vs Go's interfaces",
+    difficulty: Difficulty::Intermediate,
+    tags: &[Tag::Layout, Tag::Benchmark],
+    examples: &[
+        Example { name: "sizes", run: sizes },
+        Example { name: "discriminants", run: discriminants },
+        Example { name: "niches", run: niches },
+        Example { name: "state-machine", run: state_machine },
+        Example { name: "vs-go", run: enum_vs_go },
+    ],
+};
+
+pub fn demonstrate_enum_memory() -> Vec<DemoReport> {
+    DEMO.run()
+}
@@ -0,0 +1,212 @@
+// Error handling - Result/Option vs Go's (value, err) and nil
+// Go:   func f() (T, error) - two return values, checked by convention
+//       if err != nil { return nil, err }
+// Rust: fn f() -> Result<T, E> - ONE value that is either Ok(T) or Err(E);
+//       you can't reach the T without deciding what to do with the E.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::num::ParseIntError;
+use std::panic;
+
+use crate::registry::{Demo, Difficulty, Example, Tag};
+use crate::report::DemoReport;
+
+/// Everything that can go wrong loading a config entry
+#[derive(Debug)]
+pub enum ConfigError {
+    Missing(String),
+    NotANumber { key: String, source: ParseIntError },
+    OutOfRange { key: String, value: i64 },
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::Missing(key) => write!(f, "missing key {:?}", key),
+            ConfigError::NotANumber { key, source } => write!(f, "{:?} is not a number: {}", key, source),
+            ConfigError::OutOfRange { key, value } => write!(f, "{:?} = {} is out of range", key, value),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ConfigError::NotANumber { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+fn sample_config() -> HashMap<&'static str, &'static str> {
+    HashMap::from([("port", "8080"), ("workers", "many"), ("timeout", "-5")])
+}
+
+// Each `?` is Go's `if err != nil { return err }`
+fn read_number(config: &HashMap<&str, &str>, key: &str) -> Result<i64, ConfigError> {
+    let raw = config.get(key).ok_or_else(|| ConfigError::Missing(key.to_string()))?;
+    let value: i64 = raw
+        .parse()
+        .map_err(|source| ConfigError::NotANumber { key: key.to_string(), source })?;
+    if value < 0 {
+        return Err(ConfigError::OutOfRange { key: key.to_string(), value });
+    }
+    Ok(value)
+}
+
+// Result<T, E> and the ? operator
+pub fn result_and_question_mark() -> DemoReport {
+    let mut report = DemoReport::new("Result<T, E> and ? - Errors Are Values");
+
+    let config = sample_config();
+    for key in ["port", "workers", "timeout", "retries"] {
+        match read_number(&config, key) {
+            Ok(value) => report.ok(format!("{} = {}", key, value)),
+            Err(err) => report.fail(format!("{}", err)),
+        };
+    }
+
+    report.step("Rust");
+    report.code("let raw = config.get(key).ok_or(Missing(key))?;");
+    report.code("let value: i64 = raw.parse().map_err(|e| NotANumber { key, source: e })?;");
+    report.step("Go");
+    report.code("raw, ok := config[key]");
+    report.code("if !ok { return 0, fmt.Errorf(\"missing key %q\", key) }");
+    report.code("value, err := strconv.Atoi(raw)");
+    report.code("if err != nil { return 0, fmt.Errorf(\"%q: %w\", key, err) }");
+
+    report.gap();
+    report.ok("? = early return on Err, converting with From/map_err");
+    report.ok("Ignoring a Result is a compiler warning (#[must_use])");
+    report.warn("Go: `value, _ := strconv.Atoi(raw)` silently uses 0");
+    report
+}
+
+// Option<T> instead of nil
+pub fn option_instead_of_nil() -> DemoReport {
+    let mut report = DemoReport::new("Option<T> - No nil to Forget");
+
+    let users = HashMap::from([(1, "alice"), (2, "bob")]);
+
+    let found: Option<&&str> = users.get(&1);
+    let missing = users.get(&42);
+    report.text(format!("users.get(&1)  → {:?}", found));
+    report.text(format!("users.get(&42) → {:?}", missing));
+
+    let name = missing.copied().unwrap_or("guest");  // Must handle None to get a &str
+    report.text(format!("missing.unwrap_or(\"guest\") → {:?}", name));
+    let shout = found.map(|name| name.to_uppercase());
+    report.text(format!("found.map(to_uppercase) → {:?}", shout));
+
+    report.gap();
+    report.ok("Option<&T> is pointer-sized (None = null) but can't be dereferenced unchecked");
+    report.ok("The type says \"might be absent\" - references are never null");
+
+    report.step("Go");
+    report.code("var u *User = findUser(42)  // nil");
+    report.code("fmt.Println(u.Name)         // panic: nil pointer dereference");
+    report.warn("Nothing in *User says it might be nil");
+    report
+}
+
+// unwrap/expect: explicit panics
+pub fn unwrap_pitfalls() -> DemoReport {
+    let mut report = DemoReport::new("unwrap() Pitfalls - Opting Into a Panic");
+
+    let config = sample_config();
+
+    // Silence the default panic message; we report it ourselves
+    let previous_hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let outcome = panic::catch_unwind(|| read_number(&config, "workers").unwrap());
+    panic::set_hook(previous_hook);
+
+    match outcome {
+        Ok(value) => report.text(format!("unexpected value {}", value)),
+        Err(payload) => {
+            let message = payload
+                .downcast_ref::<String>()
+                .cloned()
+                .unwrap_or_else(|| "<non-string panic>".to_string());
+            report.fail(format!("read_number(\"workers\").unwrap() panicked: {}", message))
+        }
+    };
+
+    report.step("Better");
+    report.code(".expect(\"workers must be set by the installer\")  // panics with context");
+    report.code(".unwrap_or(4)                                     // default");
+    report.code(".unwrap_or_default()                              // T::default()");
+    report.code("?                                                 // let the caller decide");
+
+    report.gap();
+    report.ok("unwrap() is greppable: every possible panic site is visible");
+    report.warn("Fine in tests and prototypes; in libraries, return the error");
+    report.text("Go's panic is for bugs too - errors are still returned, not thrown");
+    report
+}
+
+// Custom error enums: callers can match on what went wrong
+pub fn custom_error_enum() -> DemoReport {
+    let mut report = DemoReport::new("Custom Error Enums vs errors.Is / errors.As");
+
+    let config = sample_config();
+    for key in ["workers", "timeout", "retries"] {
+        let Err(err) = read_number(&config, key) else { continue };
+        let action = match &err {
+            ConfigError::Missing(_) => "use default",
+            ConfigError::NotANumber { .. } => "reject config",
+            ConfigError::OutOfRange { .. } => "clamp to 0",
+        };
+        let cause = std::error::Error::source(&err).map(|source| format!(" (caused by: {})", source));
+        report.text(format!("{:<8} → {}{}", key, action, cause.unwrap_or_default()));
+    }
+
+    report.step("Rust");
+    report.code("match err { ConfigError::Missing(_) => ..., ConfigError::NotANumber { .. } => ... }");
+    report.ok("Exhaustive: add a variant and every match that misses it fails to compile");
+    report.step("Go");
+    report.code("var numErr *strconv.NumError");
+    report.code("if errors.As(err, &numErr) { ... } else if errors.Is(err, ErrMissing) { ... }");
+    report.warn("Open set: new error kinds slip through without a compile error");
+    report
+}
+
+pub fn errors_vs_go() -> DemoReport {
+    let mut report = DemoReport::new("Error Model: Rust vs Go");
+
+    report.table(
+        &["", "Go", "Rust"],
+        vec![
+            vec!["fallible fn".into(), "(T, error)".into(), "Result<T, E>".into()],
+            vec!["propagate".into(), "if err != nil { return err }".into(), "?".into()],
+            vec!["wrap".into(), "fmt.Errorf(\"...: %w\", err)".into(), "map_err / From / source()".into()],
+            vec!["inspect".into(), "errors.Is / errors.As".into(), "match on the enum".into()],
+            vec!["absent value".into(), "nil".into(), "Option<T>".into()],
+            vec!["unrecoverable".into(), "panic".into(), "panic! / unwrap / expect".into()],
+        ],
+    );
+
+    report.gap();
+    report.ok("Same philosophy: errors are values, not exceptions");
+    report.ok("Rust adds: can't use the T without handling the E");
+    report
+}
+
+pub const DEMO: Demo = Demo {
+    name: "error-handling",
+    about: "Result/Option vs Go's (value, err) and nil",
+    difficulty: Difficulty::Beginner,
+    tags: &[Tag::Ownership],
+    examples: &[
+        Example { name: "result", run: result_and_question_mark },
+        Example { name: "option", run: option_instead_of_nil },
+        Example { name: "unwrap", run: unwrap_pitfalls },
+        Example { name: "custom-error", run: custom_error_enum },
+        Example { name: "vs-go", run: errors_vs_go },
+    ],
+};
+
+pub fn demonstrate_error_handling() -> Vec<DemoReport> {
+    DEMO.run()
+}
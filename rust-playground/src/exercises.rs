@@ -0,0 +1,179 @@
+// Exercises - small broken programs to fix (`cargo run -- exercise next`)
+// Each file in exercises/ fails to compile on purpose and carries an
+// `// I AM NOT DONE` marker. `exercise next` compiles the first unfinished
+// one with rustc and shows the errors; once it compiles, runs cleanly and the
+// marker is gone, it's recorded in exercises/.progress and the next one is
+// up. exercises/solutions/ has a fixed version of each.
+
+use std::collections::BTreeSet;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Line that keeps a compiling exercise from counting as finished
+pub const NOT_DONE_MARKER: &str = "// I AM NOT DONE";
+
+/// One broken program
+#[derive(Debug, Clone, Copy)]
+pub struct Exercise {
+    pub name: &'static str,
+    /// The demo that explains the fix (`run <demo>`)
+    pub demo: &'static str,
+    pub hint: &'static str,
+}
+
+impl Exercise {
+    /// exercises/<name>.rs
+    pub fn path(&self) -> PathBuf {
+        dir().join(format!("{}.rs", self.name))
+    }
+
+    /// exercises/solutions/<name>.rs
+    pub fn solution(&self) -> PathBuf {
+        dir().join("solutions").join(format!("{}.rs", self.name))
+    }
+}
+
+/// In the order `exercise next` hands them out
+pub const EXERCISES: &[Exercise] = &[
+    Exercise {
+        name: "01_move_semantics",
+        demo: "ownership",
+        hint: "Passing a String moves it. Let greet borrow it (&str), or give it a clone.",
+    },
+    Exercise {
+        name: "02_mutable_borrows",
+        demo: "borrow-checker",
+        hint: "A borrow lasts until its last use. Use `first` before creating `last`.",
+    },
+    Exercise {
+        name: "03_dangling_reference",
+        demo: "stack-vs-heap-escape",
+        hint: "Nothing outlives `text` unless it's moved out: return the String, not &String.",
+    },
+    Exercise {
+        name: "04_lifetimes",
+        demo: "lifetimes",
+        hint: "fn longest<'a>(a: &'a str, b: &'a str) -> &'a str",
+    },
+    Exercise {
+        name: "05_shared_mutation",
+        demo: "rc-weak",
+        hint: "Rc<RefCell<i32>>: Rc shares, RefCell allows borrow_mut() through a shared reference.",
+    },
+    Exercise {
+        name: "06_threads",
+        demo: "send-sync",
+        hint: "Rc → Arc and RefCell → Mutex; borrow_mut() becomes lock().unwrap().",
+    },
+    Exercise {
+        name: "07_move_closures",
+        demo: "closures-and-moves",
+        hint: "thread::spawn needs a 'static closure: add `move`, so the thread owns `words`.",
+    },
+];
+
+/// Where the exercise files live (next to Cargo.toml)
+pub fn dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("exercises")
+}
+
+/// Where one exercise stands
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    /// rustc's diagnostics
+    CompileError(String),
+    /// It compiled, but an assert in main failed
+    Failed(String),
+    /// Compiles and passes, but the NOT DONE marker is still there
+    MarkerLeft,
+    Done,
+}
+
+/// Compile `path` with rustc, run it, and look for the marker
+pub fn check(path: &Path) -> io::Result<Outcome> {
+    let source = fs::read_to_string(path)?;
+    // One directory per check: tests run several at once in one process
+    static BUILDS: AtomicUsize = AtomicUsize::new(0);
+    let build = BUILDS.fetch_add(1, Ordering::Relaxed);
+    let build_dir = std::env::temp_dir().join(format!("rust-playground-exercise-{}-{}", std::process::id(), build));
+    fs::create_dir_all(&build_dir)?;
+    let binary = build_dir.join("exercise");
+
+    let rustc = std::env::var_os("RUSTC").unwrap_or_else(|| "rustc".into());
+    let compiled = Command::new(rustc)
+        .args(["--edition", "2024", "--crate-name", "exercise", "--color", "never", "-o"])
+        .arg(&binary)
+        .arg(path)
+        .output()?;
+    if !compiled.status.success() {
+        let _ = fs::remove_dir_all(&build_dir);
+        return Ok(Outcome::CompileError(String::from_utf8_lossy(&compiled.stderr).into_owned()));
+    }
+
+    let ran = Command::new(&binary).output();
+    let _ = fs::remove_dir_all(&build_dir);
+    let ran = ran?;
+    if !ran.status.success() {
+        return Ok(Outcome::Failed(String::from_utf8_lossy(&ran.stderr).into_owned()));
+    }
+    if source.lines().any(|line| line.trim() == NOT_DONE_MARKER) {
+        return Ok(Outcome::MarkerLeft);
+    }
+    Ok(Outcome::Done)
+}
+
+/// Finished exercises, one name per line in a state file
+#[derive(Debug)]
+pub struct Progress {
+    path: PathBuf,
+    done: BTreeSet<String>,
+}
+
+impl Progress {
+    /// exercises/.progress (missing file = nothing done yet)
+    pub fn load() -> io::Result<Self> {
+        Progress::load_from(dir().join(".progress"))
+    }
+
+    pub fn load_from(path: PathBuf) -> io::Result<Self> {
+        let done = match fs::read_to_string(&path) {
+            Ok(text) => text.lines().map(str::trim).filter(|line| !line.is_empty()).map(String::from).collect(),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => BTreeSet::new(),
+            Err(err) => return Err(err),
+        };
+        Ok(Progress { path, done })
+    }
+
+    pub fn is_done(&self, exercise: &Exercise) -> bool {
+        self.done.contains(exercise.name)
+    }
+
+    pub fn finished(&self) -> usize {
+        EXERCISES.iter().filter(|exercise| self.is_done(exercise)).count()
+    }
+
+    /// The first exercise not finished yet
+    pub fn current(&self) -> Option<&'static Exercise> {
+        EXERCISES.iter().find(|exercise| !self.is_done(exercise))
+    }
+
+    /// Record `exercise` as finished and save
+    pub fn complete(&mut self, exercise: &Exercise) -> io::Result<()> {
+        self.done.insert(exercise.name.to_string());
+        self.save()
+    }
+
+    /// Forget everything (the exercise files themselves are untouched)
+    pub fn reset(&mut self) -> io::Result<()> {
+        self.done.clear();
+        self.save()
+    }
+
+    fn save(&self) -> io::Result<()> {
+        let text: String = self.done.iter().map(|name| format!("{}\n", name)).collect();
+        fs::write(&self.path, text)
+    }
+}
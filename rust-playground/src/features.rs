@@ -0,0 +1,218 @@
+// Feature matrix - what this binary was built with, and does it work
+// `playground-cli doctor` checks the machine (go, Miri, Graphviz);
+// `rust-playground features` checks the binary. For every Cargo feature
+// it says whether it's compiled in, which demos it adds to the registry,
+// and runs a small self-test that really uses it: a tokio task, a Go call
+// through the c-archive, a Box counted by the allocator hook.
+//
+// A feature that's compiled in but fails its self-test is a bug; one
+// that's compiled out gets the `cargo run --features ...` line to add it.
+
+use crate::alloc_stats;
+use crate::doctor::{self, FEATURES};
+use crate::registry::DemoRegistry;
+use crate::report::DemoReport;
+
+/// What one Cargo feature adds, and how to check it's really there
+pub struct Capability {
+    pub feature: &'static str,
+    /// Demos the registry only has with this feature on
+    pub demos: &'static [&'static str],
+    /// None when the feature is compiled out; Err when it's in but broken
+    pub self_test: fn() -> Option<Result<String, String>>,
+}
+
+pub const CAPABILITIES: &[Capability] = &[
+    Capability { feature: "tui", demos: &[], self_test: tui },
+    Capability { feature: "alloc-stats", demos: &[], self_test: allocator_hook },
+    Capability { feature: "alloc-sites", demos: &[], self_test: alloc_sites },
+    Capability { feature: "mimalloc", demos: &[], self_test: mimalloc },
+    Capability { feature: "jemalloc", demos: &[], self_test: jemalloc },
+    Capability { feature: "tokio", demos: &["async-await"], self_test: tokio },
+    Capability { feature: "parking_lot", demos: &["parking-lot"], self_test: parking_lot },
+    Capability { feature: "crossbeam", demos: &["crossbeam"], self_test: crossbeam },
+    Capability { feature: "tracing", demos: &[], self_test: tracing },
+    Capability { feature: "go-ffi", demos: &["ffi-with-go"], self_test: go_ffi },
+];
+
+fn tui() -> Option<Result<String, String>> {
+    cfg!(feature = "tui").then(|| Ok("the `tui` subcommand is available".to_string()))
+}
+
+fn allocator_hook() -> Option<Result<String, String>> {
+    doctor::allocator_hook_works().map(|works| match works {
+        true => Ok("Box::new(42) counted as 1 allocation".to_string()),
+        false => Err("Box::new(42) wasn't counted as 1 allocation".to_string()),
+    })
+}
+
+fn alloc_sites() -> Option<Result<String, String>> {
+    // Profiling is a process-wide switch, so this only checks what it sits on
+    cfg!(feature = "alloc-sites").then(|| match allocator_hook() {
+        Some(Ok(_)) => Ok("--alloc-sites can sample through the counting allocator".to_string()),
+        _ => Err("alloc-sites is on but the counting allocator isn't counting".to_string()),
+    })
+}
+
+// Both backends: a few allocations of different sizes, then which one won
+fn global_allocator(name: &str) -> Result<String, String> {
+    let blocks: Vec<Vec<u8>> = [16, 4096, 1 << 20].iter().map(|&size| vec![1u8; size]).collect();
+    if blocks.iter().map(Vec::len).sum::<usize>() != 16 + 4096 + (1 << 20) {
+        return Err(format!("{} handed back a short block", name));
+    }
+    Ok(match alloc_stats::backend() {
+        backend if backend == name => format!("{} is the global allocator", name),
+        backend => format!("compiled in, but {} is the global allocator (it wins when both are on)", backend),
+    })
+}
+
+fn mimalloc() -> Option<Result<String, String>> {
+    cfg!(feature = "mimalloc").then(|| global_allocator("mimalloc"))
+}
+
+fn jemalloc() -> Option<Result<String, String>> {
+    cfg!(feature = "jemalloc").then(|| global_allocator("jemalloc"))
+}
+
+#[cfg(feature = "tokio")]
+fn tokio() -> Option<Result<String, String>> {
+    let runtime = match tokio::runtime::Builder::new_current_thread().build() {
+        Ok(runtime) => runtime,
+        Err(err) => return Some(Err(format!("couldn't build a runtime: {}", err))),
+    };
+    Some(match runtime.block_on(async { tokio::spawn(async { 21 * 2 }).await }) {
+        Ok(42) => Ok("a current-thread runtime ran a spawned task".to_string()),
+        Ok(other) => Err(format!("the task returned {}, not 42", other)),
+        Err(err) => Err(format!("the task failed: {}", err)),
+    })
+}
+
+#[cfg(not(feature = "tokio"))]
+fn tokio() -> Option<Result<String, String>> {
+    None
+}
+
+#[cfg(feature = "parking_lot")]
+fn parking_lot() -> Option<Result<String, String>> {
+    let lock = parking_lot::Mutex::new(0);
+    *lock.lock() += 1;
+    Some(match (lock.try_lock().map(|guard| *guard), std::mem::size_of_val(&lock)) {
+        (Some(1), size) => Ok(format!("locked and unlocked a parking_lot::Mutex<i32> ({} bytes)", size)),
+        _ => Err("a parking_lot::Mutex stayed locked".to_string()),
+    })
+}
+
+#[cfg(not(feature = "parking_lot"))]
+fn parking_lot() -> Option<Result<String, String>> {
+    None
+}
+
+#[cfg(feature = "crossbeam")]
+fn crossbeam() -> Option<Result<String, String>> {
+    let (sender, receiver) = crossbeam::channel::bounded(1);
+    let sent = std::thread::scope(|scope| scope.spawn(|| sender.send(7)).join());
+    Some(match (sent, receiver.recv()) {
+        (Ok(Ok(())), Ok(7)) => Ok("sent across threads on a bounded(1) channel".to_string()),
+        _ => Err("a value sent on a crossbeam channel didn't arrive".to_string()),
+    })
+}
+
+#[cfg(not(feature = "crossbeam"))]
+fn crossbeam() -> Option<Result<String, String>> {
+    None
+}
+
+#[cfg(feature = "tracing")]
+fn tracing() -> Option<Result<String, String>> {
+    let subscriber = crate::telemetry::json_subscriber(std::io::sink);
+    let recorded = tracing::subscriber::with_default(subscriber, || !tracing::info_span!("features").is_disabled());
+    Some(match recorded {
+        true => Ok("a span was recorded by the --trace=json subscriber".to_string()),
+        false => Err("the JSON subscriber ignored a span".to_string()),
+    })
+}
+
+#[cfg(not(feature = "tracing"))]
+fn tracing() -> Option<Result<String, String>> {
+    None
+}
+
+#[cfg(feature = "go-ffi")]
+fn go_ffi() -> Option<Result<String, String>> {
+    Some(match crate::ffi_with_go::go_sum(&[1, 2, 3]) {
+        6 => Ok("called Go through the c-archive: GoSum([1, 2, 3]) = 6".to_string()),
+        other => Err(format!("GoSum([1, 2, 3]) returned {}", other)),
+    })
+}
+
+#[cfg(not(feature = "go-ffi"))]
+fn go_ffi() -> Option<Result<String, String>> {
+    None
+}
+
+/// The capability for a Cargo feature
+pub fn capability(feature: &str) -> Option<&'static Capability> {
+    CAPABILITIES.iter().find(|capability| capability.feature == feature)
+}
+
+/// Every feature: compiled in, demos added, self-test; then how to get the rest
+pub fn matrix() -> DemoReport {
+    let mut report = DemoReport::new("Feature Matrix - What This Binary Was Built With");
+
+    let registry = DemoRegistry::builtin();
+    let mut broken = Vec::new();
+    let rows = FEATURES
+        .iter()
+        .map(|feature| {
+            let capability = capability(feature.name);
+            let demos = capability.map_or(&[][..], |capability| capability.demos);
+            let unregistered: Vec<&str> = demos.iter().copied().filter(|demo| registry.get(demo).is_none()).collect();
+            let demos = demos.iter().map(|demo| format!("{}{}", demo, if unregistered.contains(demo) { " (not built)" } else { "" })).collect::<Vec<_>>().join(", ");
+            let self_test = match capability.and_then(|capability| (capability.self_test)()) {
+                Some(Ok(_)) if feature.enabled && !unregistered.is_empty() => Err(format!("{} isn't registered", unregistered.join(", "))),
+                Some(result) => result,
+                None => Ok(String::new()),
+            };
+            let result = match self_test {
+                Ok(found) if found.is_empty() => "-".to_string(),
+                Ok(found) => format!("ok: {}", found),
+                Err(problem) => {
+                    broken.push(feature.name);
+                    format!("FAILED: {}", problem)
+                }
+            };
+            vec![feature.name.to_string(), if feature.enabled { "yes" } else { "no" }.to_string(), if demos.is_empty() { "-".to_string() } else { demos }, result]
+        })
+        .collect();
+    report.table(&["feature", "compiled in", "adds demos", "self-test"], rows);
+    report.count("demos registered", registry.demos().len());
+
+    report.gap();
+    for name in &broken {
+        report.fail(format!("{} is compiled in but its self-test failed - please report this", name));
+    }
+    let missing: Vec<_> = FEATURES.iter().filter(|feature| !feature.enabled).collect();
+    if missing.is_empty() {
+        report.ok("Every feature is compiled in");
+        return report;
+    }
+    if broken.is_empty() {
+        report.ok("Every compiled-in feature passed its self-test");
+    }
+    report.step("How to enable the rest");
+    for feature in &missing {
+        report.text(format!("{:<12} {}", feature.name, feature.unlocks));
+    }
+    // The two allocators are alternatives: suggest mimalloc, mention jemalloc
+    let cargo_only: Vec<&str> = missing.iter().map(|feature| feature.name).filter(|name| !matches!(*name, "go-ffi" | "jemalloc")).collect();
+    if !cargo_only.is_empty() {
+        report.code(format!("cargo run --features {} -- features", cargo_only.join(",")));
+    }
+    if missing.iter().any(|feature| feature.name == "jemalloc") {
+        report.text("mimalloc and jemalloc both replace the global allocator - build with one of them");
+    }
+    if missing.iter().any(|feature| feature.name == "go-ffi") {
+        report.text("go-ffi also needs `go` on PATH (playground-cli doctor checks)");
+    }
+    report
+}
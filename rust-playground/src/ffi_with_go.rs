@@ -0,0 +1,293 @@
+// FFI with Go - one process, two memory managers
+// Go:   cgo's //export turns a Go func into a C symbol, and -buildmode=c-archive
+//       packs those symbols, the Go runtime and its GC into a .a that any C
+//       linker - rustc included - can use. The cgo pointer rules keep the GC's
+//       heap to itself: C may borrow a Go pointer for one call but never keep
+//       it, and Go never returns one.
+// Rust: the same C ABI from the other side. extern "C" declarations for the Go
+//       functions, CString/CStr for strings, slices as pointer + length, and
+//       Drop wrappers so everything Go allocated goes back to Go. On both
+//       sides the rule is the same: whoever allocates, frees.
+//
+// Needs a Go toolchain: build.rs runs `go build -buildmode=c-archive` on
+// ../golang-playground/ffi/goexport (--features go-ffi). The other direction,
+// Go calling a Rust cdylib, is examples/ffi/rust_exports.rs (make go-calls-rust).
+
+use std::ffi::{c_char, CStr, CString};
+use std::hint::black_box;
+use std::ptr::NonNull;
+use std::time::Instant;
+
+use crate::alloc_stats;
+use crate::registry::{Demo, Difficulty, Example, Tag};
+use crate::report::{DemoReport, SideBySideRow};
+
+// Calls in the call-cost example
+const CALLS: usize = 100_000;
+
+// golang-playground/ffi/goexport/main.go; build.rs links the archive
+unsafe extern "C" {
+    fn GoGreeting(name: *const c_char) -> *mut c_char;
+    fn GoFreeString(s: *mut c_char);
+    fn GoSum(values: *const i64, len: usize) -> i64;
+    fn GoFill(buf: *mut c_char, capacity: usize) -> usize;
+    safe fn GoCounterNew() -> usize;
+    safe fn GoCounterAdd(handle: usize, n: i64) -> i64;
+    fn GoCounterFree(handle: usize);
+    safe fn GoCollect() -> i64;
+    safe fn GoNoop();
+}
+
+/// A string Go allocated with C.CString: freed by Go's GoFreeString, never by Rust
+pub struct GoString {
+    ptr: NonNull<c_char>,
+}
+
+impl GoString {
+    pub fn as_c_str(&self) -> &CStr {
+        unsafe { CStr::from_ptr(self.ptr.as_ptr()) }
+    }
+
+    /// Where Go's malloc put it
+    pub fn as_ptr(&self) -> *const c_char {
+        self.ptr.as_ptr()
+    }
+
+    /// Copy into a Rust String; the Go copy is still freed on drop
+    pub fn to_string_lossy(&self) -> String {
+        self.as_c_str().to_string_lossy().into_owned()
+    }
+}
+
+impl Drop for GoString {
+    fn drop(&mut self) {
+        unsafe { GoFreeString(self.ptr.as_ptr()) };
+    }
+}
+
+/// Go's greeting for `name`; `name` is borrowed by Go for the call only
+pub fn go_greeting(name: &str) -> GoString {
+    let name = CString::new(name).expect("no NUL in the name");  // lives until the end of this fn
+    let ptr = unsafe { GoGreeting(name.as_ptr()) };
+    GoString { ptr: NonNull::new(ptr).expect("C.CString never returns NULL") }
+}
+
+/// Go sums the slice in place: no copy, and Go keeps nothing after returning
+pub fn go_sum(values: &[i64]) -> i64 {
+    unsafe { GoSum(values.as_ptr(), values.len()) }
+}
+
+/// Go writes into `buf` (owned by Rust) and returns the bytes it used
+pub fn go_fill(buf: &mut [u8]) -> &str {
+    let used = unsafe { GoFill(buf.as_mut_ptr().cast(), buf.len()) };
+    std::str::from_utf8(&buf[..used]).expect("Go wrote UTF-8")
+}
+
+/// A Go object held through a cgo.Handle; Drop deletes the handle
+pub struct GoCounter {
+    handle: usize,
+}
+
+impl GoCounter {
+    pub fn new() -> Self {
+        GoCounter { handle: GoCounterNew() }
+    }
+
+    /// Add `n` on the Go side; returns Go's running total
+    pub fn add(&mut self, n: i64) -> i64 {
+        GoCounterAdd(self.handle, n)
+    }
+}
+
+impl Default for GoCounter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for GoCounter {
+    fn drop(&mut self) {
+        unsafe { GoCounterFree(self.handle) };
+    }
+}
+
+/// Force a Go GC cycle; returns the number of cycles so far
+pub fn go_collect() -> i64 {
+    GoCollect()
+}
+
+fn allocations_of<R>(f: impl FnOnce() -> R) -> (R, String) {
+    let (result, stats) = alloc_stats::measure(f);
+    (result, stats.map_or("n/a (needs --features alloc-stats)".to_string(), |stats| stats.allocations.to_string()))
+}
+
+// Strings both ways: CString in, C.CString out, and who frees each
+pub fn strings() -> DemoReport {
+    let mut report = DemoReport::new("Strings Across the Boundary");
+
+    let name = CString::new("ferris").expect("no NUL");
+    report.address("Rust CString \"ferris\" (Rust's allocator)", name.as_bytes_with_nul(), format!("{:?}", name));
+    let (greeting, rust_allocations) = allocations_of(|| unsafe { GoGreeting(name.as_ptr()) });
+    let greeting = GoString { ptr: NonNull::new(greeting).expect("C.CString never returns NULL") };
+    report.address("Go's C.CString result (C's malloc)", greeting.as_c_str().to_bytes_with_nul(), format!("{:?}", greeting.as_c_str()));
+    report.text(format!("Rust allocations during the call: {} - Go's malloc and GC heap aren't Rust's allocator", rust_allocations));
+    report.code("let name = CString::new(\"ferris\")?;         // NUL-terminated copy, owned by Rust");
+    report.code("let ptr = unsafe { GoGreeting(name.as_ptr()) };  // Go borrows name, returns C memory");
+    report.ok("Go copied the name (C.GoString) - it keeps nothing of Rust's after returning");
+
+    report.step("Freeing Go's string");
+    report.code("impl Drop for GoString { fn drop(&mut self) { unsafe { GoFreeString(self.ptr) } } }");
+    let copied = greeting.to_string_lossy();
+    drop(greeting);
+    report.text(format!("copied into a Rust String first: {:?}", copied));
+    report.ok("GoString's Drop calls Go's GoFreeString (C.free): the allocator that made it frees it");
+    report.fail("CString::from_raw(ptr) on Go's pointer: Rust's allocator freeing malloc's memory - UB");
+    report.warn("It works by accident while Rust's allocator is malloc - swap in jemalloc or mimalloc and it corrupts the heap");
+
+    report.step("The dangling temporary");
+    report.code("GoGreeting(CString::new(\"x\")?.as_ptr());  // ✓ the temporary lives until the `;`");
+    report.code("let ptr = CString::new(\"x\")?.as_ptr();      // ⚠ the CString is dropped right here...");
+    report.code("GoGreeting(ptr);                              // ...so Go reads freed memory");
+    report.text("rustc warns (dangling_pointers_from_temporaries): bind the CString to a name first");
+
+    report.gap();
+    report.ok("Every string crosses as a copy; neither side's memory manager reaches into the other");
+    report
+}
+
+// Slices as pointer + length: borrowed by Go, and a buffer Go writes into
+pub fn slices() -> DemoReport {
+    let mut report = DemoReport::new("Slices - Borrowed for One Call");
+
+    let values: Vec<i64> = (1..=10).collect();
+    report.address("Rust Vec<i64> data", values.as_slice(), format!("{:?}", values));
+    let (sum, allocations) = allocations_of(|| go_sum(&values));
+    report.text(format!("GoSum(values.as_ptr(), values.len()) = {} - Rust allocations: {}", sum, allocations));
+    report.code("for _, v := range unsafe.Slice(values, int(length)) { total += v }  // a Go view of Rust's memory");
+    report.ok("No copy: Go reads Rust's buffer through unsafe.Slice");
+    report.warn("Go must drop the view before returning - the borrow ends when the call does");
+    report.text("The Rust side is safe because &values can't be mutated or freed during the call");
+
+    report.step("Caller-allocated output");
+    let mut buffer = [0u8; 64];
+    report.address("Rust [u8; 64] on the stack", &buffer, "zeroed");
+    let written = go_fill(&mut buffer).to_string();
+    report.text(format!("GoFill wrote {} bytes: {:?}", written.len(), written));
+    report.ok("Rust owns the buffer, Go only writes into it: nothing to free on either side");
+    report.text("The C idiom for output (snprintf, read) - and the cheapest way to get data out of Go");
+
+    report.gap();
+    report.ok("Pointer + length is the FFI slice: &[T] ↔ unsafe.Slice(ptr, len)");
+    report
+}
+
+// Go objects behind cgo.Handle, freed by a Drop impl
+pub fn handles() -> DemoReport {
+    let mut report = DemoReport::new("Go Objects - cgo.Handle and Drop");
+
+    report.code("return &counter{}  // ❌ panic: runtime error: cgo result has Go pointer");
+    report.text("Go can't hand out pointers into its heap: the GC would lose track of them");
+    report.code("return C.uintptr_t(cgo.NewHandle(&counter{}))  // ✓ a number the handle table maps to the object");
+
+    let mut counter = GoCounter::new();
+    let before = go_collect();
+    let mut after = before;
+    let mut totals = Vec::new();
+    for n in [10, 20, 30] {
+        totals.push(counter.add(n));
+        after = go_collect();
+    }
+    report.text(format!("counter.add(10/20/30) with a Go GC after each: totals {:?}", totals));
+    report.count("Go GC cycles during the adds", (after - before) as usize);
+    report.ok("The handle table keeps the counter reachable: the GC runs, the counter survives");
+
+    report.step("Freeing it");
+    report.code("impl Drop for GoCounter { fn drop(&mut self) { unsafe { GoCounterFree(self.handle) } } }");
+    drop(counter);
+    go_collect();
+    report.ok("Drop deletes the handle; the next GC collects the counter");
+    report.warn("Forget the Delete and the object leaks - the GC can't know Rust is done with it");
+    report.text("The same pattern the other way: Box::into_raw to Go, Box::from_raw back (examples/ffi/rust_exports.rs)");
+
+    report.gap();
+    report.ok("RAII on the Rust side turns Go's explicit Delete() into scope-based cleanup");
+    report
+}
+
+#[inline(never)]
+fn rust_noop() {
+    black_box(());
+}
+
+// What one crossing costs
+pub fn call_cost() -> DemoReport {
+    let mut report = DemoReport::new("Call Cost - Rust → Go vs Rust → Rust");
+
+    GoNoop();  // the first call from a thread attaches it to the Go runtime
+    let start = Instant::now();
+    for _ in 0..CALLS {
+        GoNoop();
+    }
+    let go = start.elapsed();
+    let start = Instant::now();
+    for _ in 0..CALLS {
+        rust_noop();
+    }
+    let rust = start.elapsed();
+
+    let per_call = |elapsed: std::time::Duration| format!("{:.1} ns", elapsed.as_nanos() as f64 / CALLS as f64);
+    report.table(
+        &["call", "total", "per call"],
+        vec![
+            vec!["GoNoop() via cgo".into(), format!("{:?}", go), per_call(go)],
+            vec!["#[inline(never)] Rust fn".into(), format!("{:?}", rust), per_call(rust)],
+        ],
+    );
+
+    report.gap();
+    report.text("Each Go call switches from the C stack to a goroutine stack and tells the scheduler");
+    report.ok("Batch across the boundary: one call with a slice beats a call per element");
+    report.warn("Rust → Go is a function call to the CPU, but a runtime transition to Go");
+    report
+}
+
+// The reverse direction: Go linking a Rust cdylib
+pub fn go_calls_rust() -> DemoReport {
+    let mut report = DemoReport::new("Go Calling Rust - a cdylib");
+
+    report.text("examples/ffi/rust_exports.rs is built with crate-type = [\"cdylib\"];");
+    report.text("golang-playground/ffi/callrust links it and calls it through cgo:");
+    report.side_by_side(vec![
+        SideBySideRow::same("name := C.CString(\"gopher\")", "#[unsafe(no_mangle)] pub unsafe extern \"C\" fn rp_greeting(name: *const c_char)"),
+        SideBySideRow::same("greeting := C.rp_greeting(name)", "CString::new(...)?.into_raw()  // ownership to Go"),
+        SideBySideRow::differs("C.rp_string_free(greeting)  // not C.free", "drop(CString::from_raw(s))  // back to Rust's allocator"),
+        SideBySideRow::same("C.rp_sum(&values[0], len)", "slice::from_raw_parts(values, len).iter().sum()"),
+        SideBySideRow::same("counter := C.rp_counter_new()", "Box::into_raw(Box::new(RpCounter { .. }))"),
+        SideBySideRow::differs("defer C.rp_counter_free(counter)", "drop(Box::from_raw(counter))"),
+    ]);
+
+    report.gap();
+    report.ok("Same rule as Rust → Go: the language that allocated gets the pointer back to free it");
+    report.ok("Go passing &values[0] is allowed: the memory holds no Go pointers and Rust keeps none");
+    report.warn("Go's GC doesn't see Rust's heap: a missing rp_counter_free is a leak no GC will fix");
+    report.text("Run it: make go-calls-rust");
+    report
+}
+
+pub const DEMO: Demo = Demo {
+    name: "ffi-with-go",
+    about: "Rust calling a Go c-archive and Go calling a Rust cdylib: CString lifetimes, borrowed slices, cgo.Handle, who frees what",
+    difficulty: Difficulty::Advanced,
+    tags: &[Tag::Unsafe, Tag::Allocation, Tag::Ownership],
+    examples: &[
+        Example { name: "strings", run: strings },
+        Example { name: "slices", run: slices },
+        Example { name: "handles", run: handles },
+        Example { name: "call-cost", run: call_cost },
+        Example { name: "go-calls-rust", run: go_calls_rust },
+    ],
+};
+
+pub fn demonstrate_ffi_with_go() -> Vec<DemoReport> {
+    DEMO.run()
+}
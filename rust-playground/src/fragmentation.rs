@@ -0,0 +1,241 @@
+// Heap fragmentation - free memory you can't use
+// Go:   the GC never moves objects either. Its allocator segregates sizes
+//       (one size class per 8 KiB span), so a 64 B and a 1 KiB object never
+//       share a span - but a span with one live object can't be given back,
+//       and the scavenger only returns pages that are entirely free.
+// Rust: malloc (glibc here) carves every size out of one heap. Interleave
+//       short- and long-lived objects of different sizes, free the short ones,
+//       and you get holes that only smaller requests can fill. Nothing will
+//       ever slide the survivors together - addresses are part of the program
+//       (& references, raw pointers, FFI). The fix is layout: group objects by
+//       lifetime, e.g. an arena per phase.
+
+use std::hint::black_box;
+
+use crate::arena_allocation::BumpArena;
+use crate::process_stats;
+use crate::registry::{Demo, Difficulty, Example, Tag};
+use crate::report::{format_bytes, DemoReport};
+
+const PAIRS: usize = 10_000;
+const SMALL: usize = 64;
+const LARGE: usize = 1024;
+const BIGGER: usize = 2 * LARGE;
+
+fn rss() -> String {
+    process_stats::rss().map_or("n/a".to_string(), format_bytes)
+}
+
+/// Live blocks and the address range they're spread over
+#[derive(Debug, Default)]
+pub struct Footprint {
+    pub live_bytes: usize,
+    pub low: usize,
+    pub high: usize,
+}
+
+impl Footprint {
+    pub fn of<'a>(blocks: impl IntoIterator<Item = &'a [u8]>) -> Footprint {
+        blocks.into_iter().fold(Footprint::default(), Footprint::extend)
+    }
+
+    /// The footprint with one more live block
+    pub fn extend(self, block: &[u8]) -> Footprint {
+        let start = block.as_ptr() as usize;
+        let low = if self.live_bytes == 0 { start } else { self.low.min(start) };
+        Footprint { live_bytes: self.live_bytes + block.len(), low, high: self.high.max(start + block.len()) }
+    }
+
+    /// Bytes between the lowest and highest live block
+    pub fn span(&self) -> usize {
+        self.high.saturating_sub(self.low)
+    }
+
+    /// Share of the span that isn't live data (holes + allocator headers)
+    pub fn waste(&self) -> f64 {
+        if self.span() == 0 { 0.0 } else { 1.0 - self.live_bytes as f64 / self.span() as f64 }
+    }
+
+    pub fn contains(&self, block: &[u8]) -> bool {
+        (self.low..self.high).contains(&(block.as_ptr() as usize))
+    }
+
+    fn row(&self, phase: &str) -> Vec<String> {
+        vec![phase.to_string(), format_bytes(self.live_bytes), format_bytes(self.span()), format!("{:.0}%", self.waste() * 100.0), rss()]
+    }
+}
+
+// Filled, not zeroed: calloc could hand back untouched pages that never count toward RSS
+fn block(size: usize, fill: usize) -> Box<[u8]> {
+    vec![fill as u8 | 1; size].into_boxed_slice()
+}
+
+// Alternating sizes, free the large ones, then ask for bigger blocks
+pub fn swiss_cheese() -> DemoReport {
+    let mut report = DemoReport::new("Swiss Cheese - Alternating Sizes, Every Other Freed");
+
+    report.code(format!("for i in 0..{} {{ sessions.push(block({})); buffers.push(block({})); }}  // interleaved in the heap", PAIRS, SMALL, LARGE));
+    let mut rows = vec![Footprint::default().row("start")];
+    rows[0][1] = "-".into();
+    rows[0][2] = "-".into();
+    rows[0][3] = "-".into();
+
+    let mut sessions: Vec<Box<[u8]>> = Vec::with_capacity(PAIRS);  // Long-lived
+    let mut buffers: Vec<Box<[u8]>> = Vec::with_capacity(PAIRS);  // Short-lived
+    for i in 0..PAIRS {
+        sessions.push(block(SMALL, i));
+        buffers.push(block(LARGE, i));
+    }
+    let all = Footprint::of(sessions.iter().chain(&buffers).map(|block| &block[..]));
+    rows.push(all.row("allocated 64 B + 1 KiB pairs"));
+
+    report.code("drop(buffers);  // every other block, all the 1 KiB ones");
+    drop(buffers);
+    let survivors = Footprint::of(sessions.iter().map(|block| &block[..]));
+    rows.push(survivors.row("freed the 1 KiB buffers"));
+
+    report.code(format!("let bigger: Vec<_> = (0..{}).map(|i| block({})).collect();  // the same bytes, in 2 KiB pieces", PAIRS / 2, BIGGER));
+    let bigger: Vec<Box<[u8]>> = (0..PAIRS / 2).map(|i| block(BIGGER, i)).collect();
+    let reused_bigger = bigger.iter().filter(|block| survivors.contains(block)).count();
+    let with_bigger = Footprint::of(sessions.iter().chain(&bigger).map(|block| &block[..]));
+    rows.push(with_bigger.row("allocated 2 KiB blocks"));
+
+    let same_size: Vec<Box<[u8]>> = (0..PAIRS).map(|i| block(LARGE, i)).collect();
+    let reused_same = same_size.iter().filter(|block| survivors.contains(block)).count();
+    let with_same = Footprint::of(sessions.iter().chain(&bigger).chain(&same_size).map(|block| &block[..]));
+    rows.push(with_same.row("allocated 1 KiB blocks again"));
+
+    report.table(&["phase", "live", "address span", "not live", "RSS"], rows);
+    report.text(format!("2 KiB blocks placed inside the old span: {} of {}", reused_bigger, bigger.len()));
+    report.text(format!("1 KiB blocks placed inside the old span: {} of {}", reused_same, same_size.len()));
+    black_box((&sessions, &bigger, &same_size));
+
+    report.gap();
+    report.warn("After the free, most of the span is holes - but each hole is ~1 KiB between two live sessions");
+    report.warn("A 2 KiB request fits in none of them: the heap grows while 10 MiB sits free");
+    report.ok("Requests that fit (1 KiB again) land back in the holes - fragmentation is about SIZE mismatch");
+    report.text("RSS doesn't drop after the free: glibc keeps small holes mapped for reuse (malloc_trim would return whole free pages)");
+    report
+}
+
+// Group by lifetime: the short-lived blocks get their own arena
+pub fn arena_fix() -> DemoReport {
+    let mut report = DemoReport::new("The Fix - One Arena per Lifetime");
+
+    report.code("let sessions: Vec<Box<[u8; 64]>>       // long-lived, allocated first, together");
+    report.code("let buffers = BumpArena::<[u8; 1024]>::new();  // short-lived, in a few big chunks");
+    let sessions: Vec<Box<[u8]>> = (0..PAIRS).map(|i| block(SMALL, i)).collect();
+    let survivors = Footprint::of(sessions.iter().map(|block| &block[..]));
+
+    let buffers = BumpArena::<[u8; LARGE]>::new();
+    let mut arena_region = Footprint::default();
+    for i in 0..PAIRS {
+        let buffer = buffers.alloc([i as u8 | 1; LARGE]);
+        arena_region = arena_region.extend(&buffer[..]);
+    }
+    let chunks = buffers.chunk_count();
+    let rss_with_arena = rss();
+    report.code("drop(buffers);  // a handful of frees, each returning one contiguous chunk");
+    drop(buffers);
+    let rss_after_drop = rss();
+
+    let bigger: Vec<Box<[u8]>> = (0..PAIRS / 2).map(|i| block(BIGGER, i)).collect();
+    let reused = bigger.iter().filter(|block| arena_region.contains(block)).count();
+    let everything = Footprint::of(sessions.iter().chain(&bigger).map(|block| &block[..]));
+    black_box((&sessions, &bigger));
+
+    report.table(
+        &["", "value"],
+        vec![
+            vec!["sessions' span".into(), format!("{} for {} live ({:.0}% not live)", format_bytes(survivors.span()), format_bytes(survivors.live_bytes), survivors.waste() * 100.0)],
+            vec!["arena chunks holding 10,000 buffers".into(), chunks.to_string()],
+            vec!["RSS with the arena / after dropping it".into(), format!("{} / {}", rss_with_arena, rss_after_drop)],
+            vec!["2 KiB blocks placed where the arena was".into(), format!("{} of {}", reused, bigger.len())],
+            vec!["sessions + 2 KiB blocks span".into(), format!("{} for {} live", format_bytes(everything.span()), format_bytes(everything.live_bytes))],
+        ],
+    );
+
+    report.gap();
+    report.ok("No short-lived block sits between two sessions - what isn't live is mostly malloc's per-block header");
+    report.ok("Dropped chunks are either unmapped (RSS falls) or left as a few large free regions the 2 KiB blocks fit in");
+    report.text("Which one depends on the heap's history: run this example alone (-e arena-fix), then after swiss-cheese");
+    report.text("Same objects, same sizes - only the placement changed. See `run arena-allocation` for the arena itself");
+
+    report.step("Go");
+    report.text("No arenas in the language (the experimental `arena` package was shelved);");
+    report.text("sync.Pool and size classes are what keep Go's heap dense");
+    report
+}
+
+// What a moving (compacting) collector would do with the same heap
+pub fn compaction() -> DemoReport {
+    let mut report = DemoReport::new("Compaction - What a Moving GC Would Do");
+
+    let sessions: Vec<Box<[u8]>> = (0..PAIRS).map(|i| block(SMALL, i)).collect();
+    let buffers: Vec<Box<[u8]>> = (0..PAIRS).map(|i| block(LARGE, i)).collect();
+    let mut interleaved: Vec<Box<[u8]>> = Vec::with_capacity(2 * PAIRS);
+    for (session, buffer) in sessions.into_iter().zip(buffers) {
+        interleaved.push(session);
+        interleaved.push(buffer);
+    }
+    // Free every other block (the buffers) and look at what's left
+    let survivors: Vec<Box<[u8]>> = interleaved.into_iter().step_by(2).collect();
+    let footprint = Footprint::of(survivors.iter().map(|block| &block[..]));
+
+    report.table(
+        &["heap after freeing the buffers", "span", "holes"],
+        vec![
+            vec!["non-moving (malloc, Go's GC)".into(), format_bytes(footprint.span()), format!("{:.0}%", footprint.waste() * 100.0)],
+            vec!["after compaction (JVM, .NET)".into(), format_bytes(footprint.live_bytes), "0%".into()],
+        ],
+    );
+    black_box(&survivors);
+
+    report.gap();
+    report.text("A compacting collector copies survivors next to each other and rewrites every pointer to them");
+    report.text("That needs to know where every pointer is - Rust can't: &T, *const T and FFI hold raw addresses");
+    report.ok("So Rust never pays for moving (no copying pause, stable addresses - Pin relies on it)");
+    report.warn("...and never gets the free defragmentation either - layout is your job");
+
+    report.step("Go");
+    report.text("Go's GC doesn't compact either. Its defence is size classes: 64 B and 1 KiB objects live in different spans,");
+    report.text("so this exact pattern doesn't interleave. Same-size every-other frees still leave half-empty spans");
+    report.text("that can't be released - visible as HeapInuse well above HeapAlloc in runtime.MemStats");
+    report
+}
+
+pub fn fragmentation_vs_go() -> DemoReport {
+    let mut report = DemoReport::new("Fragmentation: Go vs Rust");
+
+    report.table(
+        &["", "Go", "Rust (system malloc)"],
+        vec![
+            vec!["objects move?".into(), "never (non-moving GC)".into(), "never (addresses are values)".into()],
+            vec!["mixed sizes interleave?".into(), "no: one size class per span".into(), "yes: one heap, all sizes".into()],
+            vec!["memory returned to the OS".into(), "scavenger: fully free pages".into(), "free of mmap'd chunks; malloc_trim".into()],
+            vec!["inspect it".into(), "HeapInuse vs HeapAlloc, HeapIdle".into(), "RSS vs live bytes (this demo)".into()],
+            vec!["mitigation".into(), "sync.Pool, fewer pointers".into(), "arenas per lifetime, pools, size-classed allocators (jemalloc, mimalloc)".into()],
+        ],
+    );
+
+    report.gap();
+    report.ok("Neither compacts; both depend on allocation patterns - Rust lets you pick the allocator and the layout");
+    report
+}
+
+pub const DEMO: Demo = Demo {
+    name: "fragmentation",
+    about: "Heap fragmentation: alternating sizes, every other freed, RSS vs live bytes, arenas as the fix",
+    difficulty: Difficulty::Advanced,
+    tags: &[Tag::Allocation],
+    examples: &[
+        Example { name: "swiss-cheese", run: swiss_cheese },
+        Example { name: "arena-fix", run: arena_fix },
+        Example { name: "compaction", run: compaction },
+        Example { name: "vs-go", run: fragmentation_vs_go },
+    ],
+};
+
+pub fn demonstrate_fragmentation() -> Vec<DemoReport> {
+    DEMO.run()
+}
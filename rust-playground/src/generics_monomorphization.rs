@@ -0,0 +1,294 @@
+// Generics and monomorphization - what `fn f<T>` turns into
+// Go:   generics (1.18+) use GC-shape stenciling: one compiled copy per
+//       "shape" of type argument. Every pointer type shares one shape, so
+//       f[*User] and f[*Order] are the same code plus a runtime dictionary
+//       that says which methods to call. Before generics (and still for
+//       `any`), everything went through interface values instead.
+// Rust: full monomorphization. Every distinct T gets its own copy of the
+//       function, specialized and inlinable - zero runtime cost, paid for in
+//       binary size and compile time. `dyn Trait` is the opt-in for one copy.
+
+use std::any::{Any, type_name};
+use std::borrow::Cow;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use crate::alloc_stats;
+use crate::registry::{Demo, Difficulty, Example, Tag};
+use crate::report::DemoReport;
+
+const VALUES: usize = 10_000;
+const ROUNDS: usize = if cfg!(debug_assertions) { 20 } else { 200 };
+
+/// The textbook generic: one definition, a copy per T it's used with
+pub fn largest<T: PartialOrd + Copy>(items: &[T]) -> T {
+    let mut largest = items[0];
+    for &item in items {
+        if item > largest {
+            largest = item;
+        }
+    }
+    largest
+}
+
+/// The non-generic part, compiled once
+pub fn count_words_inner(text: &str) -> usize {
+    text.split_whitespace().count()
+}
+
+/// A thin generic shim over it: each instantiation is just `as_ref()` + call
+pub fn count_words<S: AsRef<str>>(text: S) -> usize {
+    count_words_inner(text.as_ref())
+}
+
+// Where a function's machine code starts
+fn address(function: *const ()) -> String {
+    format!("{:p}", function)
+}
+
+// One instantiation per type, with the addresses to prove it
+pub fn instantiations() -> DemoReport {
+    let mut report = DemoReport::new("One Generic Function, One Copy per Type");
+
+    report.code("fn largest<T: PartialOrd + Copy>(items: &[T]) -> T { ... }");
+    report.text(format!("largest(&[3, 7, 2])          = {}", largest(&[3, 7, 2])));
+    report.text(format!("largest(&[1.5, -2.0])        = {}", largest(&[1.5, -2.0])));
+    report.text(format!("largest(&[\"go\", \"rust\"])     = {:?}", largest(&["go", "rust"])));
+
+    let copies = [
+        (type_name::<i32>(), address(largest::<i32> as fn(&[i32]) -> i32 as *const ())),
+        (type_name::<f64>(), address(largest::<f64> as fn(&[f64]) -> f64 as *const ())),
+        (type_name::<&str>(), address(largest::<&'static str> as fn(&[&'static str]) -> &'static str as *const ())),
+    ];
+    report.table(
+        &["Instantiation", "Machine code at"],
+        copies.iter().map(|(name, address)| vec![format!("largest::<{}>", name), address.clone()]).collect(),
+    );
+    let distinct = {
+        let mut addresses: Vec<&String> = copies.iter().map(|(_, address)| address).collect();
+        addresses.sort();
+        addresses.dedup();
+        addresses.len()
+    };
+    report.count("separate functions in the binary", distinct);
+
+    report.gap();
+    report.ok("Three types, three functions: `>` is a signed compare, a float compare and a memcmp respectively");
+    report.ok("Each copy is as fast as if you'd written it by hand for that type - and can be inlined");
+    report.text("Identical machine code may be merged by the linker, so two copies can share an address");
+
+    report.step("Go");
+    report.code("func Largest[T cmp.Ordered](items []T) T { ... }");
+    report.text("int, float64 and string have different GC shapes: Go also compiles three copies here");
+    report
+}
+
+// Keeping the copies small: the inner-function trick std uses
+pub fn code_size() -> DemoReport {
+    let mut report = DemoReport::new("Code Size - Keep the Generic Part Thin");
+
+    report.code("pub fn count_words<S: AsRef<str>>(text: S) -> usize {");
+    report.code("    count_words_inner(text.as_ref())  // the real work: compiled once");
+    report.code("}");
+    report.text(format!("count_words(\"a b c\") = {}", count_words("a b c")));
+    report.text(format!("count_words(String::from(\"a b\")) = {}", count_words(String::from("a b"))));
+    report.text(format!("count_words(Cow::Borrowed(\"a\")) = {}", count_words(Cow::Borrowed("a"))));
+
+    report.table(
+        &["Function", "Address", "Copies"],
+        vec![
+            vec!["count_words::<&str>".into(), address(count_words::<&'static str> as fn(&'static str) -> usize as *const ()), "shim".into()],
+            vec!["count_words::<String>".into(), address(count_words::<String> as fn(String) -> usize as *const ()), "shim".into()],
+            vec!["count_words::<Cow<str>>".into(), address(count_words::<Cow<'static, str>> as fn(Cow<'static, str>) -> usize as *const ()), "shim".into()],
+            vec!["count_words_inner".into(), address(count_words_inner as fn(&str) -> usize as *const ()), "1, shared".into()],
+        ],
+    );
+
+    report.gap();
+    report.ok("Three tiny shims + one real body, instead of three copies of the whole body");
+    report.text("std does this everywhere: fs::read_to_string<P: AsRef<Path>> calls an inner fn(&Path)");
+    report.warn("A big generic function used with 20 types is 20 big functions - compile time too");
+    report.text("Measure it: `cargo llvm-lines` counts LLVM IR lines per generic, `cargo bloat` bytes per function");
+
+    report.step("Go");
+    report.text("Stenciling by GC shape caps the copies: all pointer type arguments share one");
+    report.text("- smaller binaries, but calls through the dictionary can't be inlined");
+    report
+}
+
+/// A generic struct: its layout depends entirely on T
+pub struct Tagged<T> {
+    pub id: u32,
+    pub value: T,
+}
+
+fn sizes<T>() -> Vec<String> {
+    vec![
+        type_name::<T>().rsplit("::").next().unwrap_or_default().to_string(),
+        size_of::<T>().to_string(),
+        size_of::<Option<T>>().to_string(),
+        size_of::<[T; 4]>().to_string(),
+        size_of::<Tagged<T>>().to_string(),
+        size_of::<Vec<T>>().to_string(),
+        size_of::<Box<T>>().to_string(),
+    ]
+}
+
+// size_of for generic containers across type arguments
+pub fn generic_layout() -> DemoReport {
+    let mut report = DemoReport::new("Generic Containers - Layout per Type Argument");
+
+    report.code("struct Tagged<T> { id: u32, value: T }");
+    report.table(
+        &["T", "T", "Option<T>", "[T; 4]", "Tagged<T>", "Vec<T>", "Box<T>"],
+        vec![sizes::<u8>(), sizes::<u64>(), sizes::<&u64>(), sizes::<String>(), sizes::<[u8; 100]>(), sizes::<()>()],
+    );
+    report.text("(sizes in bytes)");
+
+    report.gap();
+    report.ok("Each instantiation gets its own layout: Tagged<u8> packs into 8 bytes, Tagged<u64> needs 16");
+    report.ok("Vec<T> and Box<T> are the same size for every T - the T lives on the heap");
+    report.ok("Option<&u64> and Option<String> are free: the null pointer is the None niche");
+    report.text("Unsized T changes the pointer instead: Box<[u8]> and Box<dyn Any> are 16 bytes (fat)");
+    report.text(format!("  size_of::<Box<[u8]>>() = {}, size_of::<Box<dyn Any>>() = {}", size_of::<Box<[u8]>>(), size_of::<Box<dyn Any>>()));
+
+    report.step("Go");
+    report.code("type Tagged[T any] struct { ID uint32; Value T }  // same per-T layout");
+    report.text("...but a T stored in an `any` is always 16 bytes (type, data), usually with the value boxed");
+    report
+}
+
+/// What the benchmark adds up
+pub trait Number {
+    fn value(&self) -> i64;
+}
+
+impl Number for i64 {
+    fn value(&self) -> i64 {
+        *self
+    }
+}
+
+/// Static dispatch: a copy of this loop for i64, fully inlined
+pub fn sum_generic<N: Number>(items: &[N]) -> i64 {
+    items.iter().map(Number::value).sum()
+}
+
+/// Dynamic dispatch without boxing: the values stay put, every call is indirect
+pub fn sum_dyn(items: &[&dyn Number]) -> i64 {
+    items.iter().map(|item| item.value()).sum()
+}
+
+/// Go before generics: every value boxed behind an interface
+pub fn sum_boxed(items: &[Box<dyn Number>]) -> i64 {
+    items.iter().map(|item| item.value()).sum()
+}
+
+/// Go's `[]any` + type assertion
+pub fn sum_any(items: &[Box<dyn Any>]) -> i64 {
+    items.iter().filter_map(|item| item.downcast_ref::<i64>()).sum()
+}
+
+fn time_rounds(mut sum: impl FnMut() -> i64) -> Duration {
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        black_box(sum());
+    }
+    start.elapsed()
+}
+
+fn allocations_of<R>(build: impl FnOnce() -> R) -> (R, String) {
+    let (built, stats) = alloc_stats::measure(build);
+    (built, stats.map_or("n/a".to_string(), |stats| stats.allocations.to_string()))
+}
+
+// Generic vs dyn vs "everything through an interface"
+pub fn benchmark() -> DemoReport {
+    let mut report = DemoReport::new("Benchmark - Generic vs dyn vs Everything-an-Interface");
+
+    let (values, values_allocs) = allocations_of(|| (0..VALUES as i64).collect::<Vec<i64>>());
+    let (refs, refs_allocs) = allocations_of(|| values.iter().map(|n| n as &dyn Number).collect::<Vec<_>>());
+    let (boxed, boxed_allocs) = allocations_of(|| (0..VALUES as i64).map(|n| Box::new(n) as Box<dyn Number>).collect::<Vec<_>>());
+    let (any, any_allocs) = allocations_of(|| (0..VALUES as i64).map(|n| Box::new(n) as Box<dyn Any>).collect::<Vec<_>>());
+
+    let expected = sum_generic(&values);
+    let rows = [
+        ("&[i64] + generic", "monomorphized, inlined", values_allocs, sum_generic(&values), time_rounds(|| sum_generic(black_box(&values)))),
+        ("&[&dyn Number]", "vtable call per item", refs_allocs, sum_dyn(&refs), time_rounds(|| sum_dyn(black_box(&refs)))),
+        ("&[Box<dyn Number>]", "vtable + pointer chase", boxed_allocs, sum_boxed(&boxed), time_rounds(|| sum_boxed(black_box(&boxed)))),
+        ("&[Box<dyn Any>] + downcast", "type check + pointer chase", any_allocs, sum_any(&any), time_rounds(|| sum_any(black_box(&any)))),
+    ];
+    report.count("values summed per round", VALUES);
+    report.count("rounds", ROUNDS);
+    report.table(
+        &["Design", "Dispatch", "Allocations to build", "ns / value", "Same sum?"],
+        rows.iter()
+            .map(|(design, dispatch, allocations, sum, elapsed)| {
+                vec![
+                    design.to_string(),
+                    dispatch.to_string(),
+                    allocations.clone(),
+                    format!("{:.2}", elapsed.as_nanos() as f64 / (VALUES * ROUNDS) as f64),
+                    if *sum == expected { "yes".into() } else { format!("no: {}", sum) },
+                ]
+            })
+            .collect(),
+    );
+    if !alloc_stats::enabled() {
+        report.text("(allocation counts need --features alloc-stats)");
+    }
+    if cfg!(debug_assertions) {
+        report.warn("Debug build - run with --release to see the real gap");
+    }
+
+    report.gap();
+    report.ok("Generic: the compiler sees i64 + i64 in a loop and vectorizes it");
+    report.text("&dyn leaves the values in place, but an indirect call per item blocks inlining and vectorizing");
+    report.warn("Boxing every value adds an allocation each and a cache miss per read - the real price of []any");
+
+    report.step("Go");
+    report.code("func Sum[T Number](xs []T) T     // stenciled per shape: int64 gets its own copy");
+    report.code("func SumAny(xs []any) (t int64) { for _, x := range xs { t += x.(int64) } }");
+    report.text("Go's []any row matches the Box<dyn Any> row: a heap value per element (except 0-255, which are cached)");
+    report
+}
+
+pub fn generics_vs_go() -> DemoReport {
+    let mut report = DemoReport::new("Generics: Go vs Rust");
+
+    report.table(
+        &["", "Go generics", "Rust generics"],
+        vec![
+            vec!["compiled as".into(), "one copy per GC shape + dictionary".into(), "one copy per type".into()],
+            vec!["f[*A], f[*B]".into(), "same code, different dictionary".into(), "two functions".into()],
+            vec!["method call on T".into(), "through the dictionary (indirect)".into(), "direct, inlinable".into()],
+            vec!["binary size".into(), "smaller".into(), "larger - keep generic parts thin".into()],
+            vec!["runtime polymorphism".into(), "interfaces (always boxed values)".into(), "dyn Trait (you choose &dyn or Box)".into()],
+            vec!["constraints".into(), "type sets (~int | ~float64)".into(), "traits (methods, assoc types)".into()],
+        ],
+    );
+
+    report.gap();
+    report.ok("Rust picks speed by default and makes one-copy dyn an explicit choice");
+    report.ok("Go picks one-copy-ish by default; performance-critical code often avoids generics");
+    report.text("See `run traits-vs-interfaces` for trait objects and Go interface values");
+    report
+}
+
+pub const DEMO: Demo = Demo {
+    name: "generics-monomorphization",
+    about: "Monomorphization vs Go's GC-shape stenciling: copies per type, code size, layout and dispatch cost",
+    difficulty: Difficulty::Intermediate,
+    tags: &[Tag::Layout, Tag::Benchmark],
+    examples: &[
+        Example { name: "instantiations", run: instantiations },
+        Example { name: "code-size", run: code_size },
+        Example { name: "layout", run: generic_layout },
+        Example { name: "benchmark", run: benchmark },
+        Example { name: "vs-go", run: generics_vs_go },
+    ],
+};
+
+pub fn demonstrate_generics_monomorphization() -> Vec<DemoReport> {
+    DEMO.run()
+}
@@ -0,0 +1,235 @@
+// Globals - Go's package-level vars and sync.Once vs OnceLock, LazyLock, thread_local!
+// Go:   var routes = map[string]int{...}   // initialized before main(), mutable by anyone
+//       var once sync.Once; once.Do(load)  // lazy, exactly once
+// Rust: a static is immutable and must be built at compile time. Anything
+//       else is spelled out: OnceLock (set once), LazyLock (built on first
+//       use), Mutex/atomics (mutable), thread_local! (one copy per thread)
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{LazyLock, Mutex, OnceLock};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::registry::{Demo, Difficulty, Example, Tag};
+use crate::report::DemoReport;
+
+const THREADS: usize = 4;
+
+#[derive(Debug)]
+pub struct Config {
+    pub workers: usize,
+    pub endpoint: String,
+}
+
+static CONFIG: OnceLock<Config> = OnceLock::new();
+static CONFIG_LOADS: AtomicUsize = AtomicUsize::new(0);
+
+fn load_config() -> Config {
+    CONFIG_LOADS.fetch_add(1, Ordering::Relaxed);
+    thread::sleep(Duration::from_millis(10));  // Pretend to read a file
+    Config { workers: 8, endpoint: "https://example.com".to_string() }
+}
+
+// OnceLock - sync.Once plus the value it guards
+pub fn once_lock() -> DemoReport {
+    let mut report = DemoReport::new("OnceLock - sync.Once That Holds the Value");
+
+    let handles: Vec<_> = (0..THREADS)
+        .map(|_| thread::spawn(|| CONFIG.get_or_init(load_config) as *const Config as usize))
+        .collect();
+    let seen: Vec<usize> = handles.into_iter().map(|handle| handle.join().unwrap()).collect();
+
+    report.count("Threads racing to initialize", THREADS);
+    report.count("load_config() calls", CONFIG_LOADS.load(Ordering::Relaxed));
+    report.text(format!(
+        "Every thread got the same &Config: {}",
+        seen.iter().all(|addr| *addr == seen[0])
+    ));
+    report.text(format!("CONFIG = {:?}", CONFIG.get().unwrap()));
+
+    report.gap();
+    report.ok("Losers of the race block until the winner's value is ready");
+    report.ok("get_or_init hands out &'static Config - no lock on later reads");
+    report.text("CONFIG.set(other) now returns Err: the value can never change");
+
+    report.step("Go");
+    report.code("var (once sync.Once; config *Config)");
+    report.code("func GetConfig() *Config { once.Do(func() { config = load() }); return config }");
+    report.warn("Go: nothing stops code from assigning config directly, skipping once");
+    report
+}
+
+static INIT_ORDER: Mutex<Vec<&str>> = Mutex::new(Vec::new());
+
+static STATUS_TEXT: LazyLock<HashMap<u16, &str>> = LazyLock::new(|| {
+    INIT_ORDER.lock().unwrap().push("STATUS_TEXT built");
+    HashMap::from([(200, "OK"), (404, "Not Found"), (500, "Internal Server Error")])
+});
+
+// LazyLock - a package-level var with a non-const initializer
+pub fn lazy_lock() -> DemoReport {
+    let mut report = DemoReport::new("LazyLock - Package-Level var, Built on First Use");
+
+    report.step("A static must be const-evaluable");
+    report.code("static STATUS_TEXT: HashMap<u16, &str> = HashMap::from([...]);");
+    report.fail("E0015: cannot call non-const associated function in statics");
+    report.code("static STATUS_TEXT: LazyLock<HashMap<u16, &str>> = LazyLock::new(|| HashMap::from([...]));");
+
+    report.step("When does the initializer run");
+    INIT_ORDER.lock().unwrap().push("demo started");
+    let start = Instant::now();
+    let first = STATUS_TEXT[&404];
+    let first_time = start.elapsed();
+    let start = Instant::now();
+    let second = STATUS_TEXT[&500];
+    let second_time = start.elapsed();
+    for (i, event) in INIT_ORDER.lock().unwrap().iter().enumerate() {
+        report.text(format!("{}. {}", i + 1, event));
+    }
+    report.duration(format!("first access ({})", first), first_time);
+    report.duration(format!("second access ({})", second), second_time);
+
+    report.gap();
+    report.ok("Built on first deref, once, thread-safe - then a plain &HashMap");
+    report.text("Never touched → never built: no startup cost, unlike Go's init order");
+
+    report.step("Go");
+    report.code("var statusText = map[int]string{200: \"OK\", 404: \"Not Found\"}");
+    report.text("Go runs every package-level initializer (and init()) before main()");
+    report
+}
+
+thread_local! {
+    static SCRATCH: RefCell<Vec<u8>> = const { RefCell::new(Vec::new()) };
+    static REQUESTS_SEEN: RefCell<usize> = const { RefCell::new(0) };
+}
+
+fn handle_request(body: &[u8]) -> usize {
+    REQUESTS_SEEN.with_borrow_mut(|seen| *seen += 1);
+    SCRATCH.with_borrow_mut(|scratch| {
+        scratch.clear();  // Reuses this thread's buffer - no allocation after warm-up
+        scratch.extend_from_slice(body);
+        scratch.capacity()
+    })
+}
+
+// thread_local! - one copy per thread, no synchronization
+pub fn thread_locals() -> DemoReport {
+    let mut report = DemoReport::new("thread_local! - One Copy per Thread");
+
+    let handles: Vec<_> = (0..THREADS)
+        .map(|t| {
+            thread::spawn(move || {
+                let requests = (t + 1) * 10;
+                let mut capacity = 0;
+                for _ in 0..requests {
+                    capacity = handle_request(b"GET /health");
+                }
+                (t, REQUESTS_SEEN.with_borrow(|seen| *seen), capacity)
+            })
+        })
+        .collect();
+    let rows = handles
+        .into_iter()
+        .map(|handle| {
+            let (t, seen, capacity) = handle.join().unwrap();
+            vec![format!("thread {}", t), seen.to_string(), capacity.to_string()]
+        })
+        .collect();
+    report.table(&["Thread", "REQUESTS_SEEN", "SCRATCH capacity"], rows);
+    report.count("main thread's REQUESTS_SEEN", REQUESTS_SEEN.with_borrow(|seen| *seen));
+
+    report.gap();
+    report.ok("Each thread counted only its own requests - no lock, no atomic");
+    report.ok("RefCell is enough: a thread-local is never shared, so it needn't be Sync");
+    report.text("Dropped when its thread exits");
+
+    report.step("Go");
+    report.text("No goroutine-local storage, on purpose - pass a context.Context or a parameter");
+    report.text("sync.Pool is the closest tool for reusable per-worker buffers");
+    report
+}
+
+static HITS: AtomicUsize = AtomicUsize::new(0);
+static RECENT: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+// Mutable globals: atomics and Mutex have const constructors
+pub fn mutable_statics() -> DemoReport {
+    let mut report = DemoReport::new("Mutable Globals - static mut vs Atomics and Mutex");
+
+    report.step("The Go habit");
+    report.code("var hits int");
+    report.code("func hit() { hits++ }  // racy once two goroutines call it");
+
+    report.step("Rust: static mut");
+    report.code("static mut HITS: usize = 0;");
+    report.code("HITS += 1;");
+    report.fail("E0133: use of mutable static is unsafe and requires unsafe block");
+    report.text("Checked by tests/compile_fail/static_mut_without_unsafe.rs");
+    report.warn("Wrapping it in unsafe compiles - and races exactly like Go (see make data-race)");
+
+    report.step("Rust: interior mutability in a plain static");
+    report.code("static HITS: AtomicUsize = AtomicUsize::new(0);");
+    report.code("static RECENT: Mutex<Vec<String>> = Mutex::new(Vec::new());");
+    let (hits_before, recent_before) = (HITS.load(Ordering::Relaxed), RECENT.lock().unwrap().len());
+    thread::scope(|scope| {
+        for t in 0..THREADS {
+            scope.spawn(move || {
+                for i in 0..1_000 {
+                    HITS.fetch_add(1, Ordering::Relaxed);
+                    if i == 0 {
+                        RECENT.lock().unwrap().push(format!("thread {}", t));
+                    }
+                }
+            });
+        }
+    });
+    report.count("HITS added (expected 4000)", HITS.load(Ordering::Relaxed) - hits_before);
+    report.count("RECENT entries added", RECENT.lock().unwrap().len() - recent_before);
+
+    report.gap();
+    report.ok("The static itself never changes - the atomic or lock inside does");
+    report.ok("Mutex::new and AtomicUsize::new are const fn: no LazyLock needed");
+    report
+}
+
+pub fn globals_vs_go() -> DemoReport {
+    let mut report = DemoReport::new("Globals: Go vs Rust");
+
+    report.table(
+        &["Go", "Rust", "Notes"],
+        vec![
+            vec!["const Max = 10".into(), "const MAX: usize = 10".into(), "inlined at each use".into()],
+            vec!["var x = computed()".into(), "static X: LazyLock<T>".into(), "built on first use, not before main".into()],
+            vec!["sync.Once + var".into(), "static X: OnceLock<T>".into(), "set once, from anywhere".into()],
+            vec!["var n int (mutated)".into(), "static N: AtomicUsize".into(), "or Mutex<T>; static mut needs unsafe".into()],
+            vec!["(none)".into(), "thread_local!".into(), "per-thread, no Sync needed".into()],
+            vec!["init()".into(), "(none)".into(), "explicit setup in main, or LazyLock".into()],
+        ],
+    );
+
+    report.gap();
+    report.ok("Every global is immutable unless its type says how it synchronizes");
+    report.text("Prefer passing state down over globals - in both languages");
+    report
+}
+
+pub const DEMO: Demo = Demo {
+    name: "globals",
+    about: "Globals: OnceLock, LazyLock, thread_local! and mutable statics vs Go's package-level vars",
+    difficulty: Difficulty::Intermediate,
+    tags: &[Tag::Concurrency, Tag::Ownership],
+    examples: &[
+        Example { name: "once-lock", run: once_lock },
+        Example { name: "lazy-lock", run: lazy_lock },
+        Example { name: "thread-local", run: thread_locals },
+        Example { name: "mutable-static", run: mutable_statics },
+        Example { name: "vs-go", run: globals_vs_go },
+    ],
+};
+
+pub fn demonstrate_globals() -> Vec<DemoReport> {
+    DEMO.run()
+}
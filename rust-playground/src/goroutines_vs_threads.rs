@@ -0,0 +1,265 @@
+// Goroutines vs OS threads vs async tasks - measured, not just described
+// Go: M:N scheduler, goroutines start with a ~2KB growable stack
+// Rust: thread::spawn = 1:1 OS thread with a fixed stack
+//       async fn = state machine, sized exactly by the compiler
+
+use std::collections::VecDeque;
+use std::future::Future;
+use std::hint::black_box;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{mpsc, Arc, RwLock};
+use std::task::{Context, Poll, Waker};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::process_stats;
+use crate::registry::{Demo, Difficulty, Example, Tag};
+use crate::report::{format_bytes, DemoReport};
+
+pub const DEFAULT_COUNT: usize = 1_000;
+
+// Count used when the demo runs from the registry (`run goroutines-vs-threads -n N`)
+static COUNT: AtomicUsize = AtomicUsize::new(DEFAULT_COUNT);
+
+/// Spawn this many threads/tasks in registry runs
+pub fn set_count(count: usize) {
+    COUNT.store(count, Ordering::Relaxed);
+}
+
+pub fn count() -> usize {
+    COUNT.load(Ordering::Relaxed)
+}
+
+// Stack size requested for every spawned thread (std's default is 2 MiB)
+const THREAD_STACK_SIZE: usize = 2 * 1024 * 1024;
+
+// Go's initial goroutine stack (runtime/stack.go: _StackMin)
+const GOROUTINE_STACK_SIZE: usize = 2 * 1024;
+
+// What it cost to have `count` units of concurrency alive at once
+#[derive(Debug, Clone)]
+pub struct SpawnCost {
+    pub count: usize,
+    pub spawn_time: Duration,
+    pub rss_delta: Option<usize>,
+    pub reserved_per_unit: usize,
+    // Why spawning stopped short (thread or memory limits); `count` is how many did start
+    pub spawn_error: Option<String>,
+}
+
+impl SpawnCost {
+    pub fn per_spawn(&self) -> Duration {
+        self.spawn_time / self.count.max(1) as u32
+    }
+}
+
+// Spawn N OS threads and keep them all alive while measuring.
+// No Barrier: it needs all N to arrive, and spawning can fail part-way (thread
+// or memory limits) - then the threads that did start are measured and let go.
+pub fn measure_threads(count: usize) -> SpawnCost {
+    let (alive, started) = mpsc::channel();
+    let gate = Arc::new(RwLock::new(()));
+    let before = process_stats::rss();
+
+    let closed = gate.write().unwrap();  // Every thread waits on this until measured
+    let start = Instant::now();
+    let mut handles = Vec::new();
+    let mut spawn_error = None;
+    for i in 0..count {
+        let alive = alive.clone();
+        let gate = Arc::clone(&gate);
+        let spawned = thread::Builder::new().stack_size(THREAD_STACK_SIZE).spawn(move || {
+            alive.send(()).unwrap();  // "I'm alive"
+            drop(gate.read());  // Stay alive until measured
+            black_box(i)
+        });
+        match spawned {
+            Ok(handle) => handles.push(handle),
+            Err(err) => {
+                spawn_error = Some(err.to_string());
+                break;
+            }
+        }
+    }
+    started.iter().take(handles.len()).for_each(drop);
+    let spawn_time = start.elapsed();
+
+    let rss_delta = before.zip(process_stats::rss()).map(|(b, a)| a.saturating_sub(b));
+    drop(closed);
+    let spawned = handles.len();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    SpawnCost { count: spawned, spawn_time, rss_delta, reserved_per_unit: THREAD_STACK_SIZE, spawn_error }
+}
+
+// Future that suspends once, so every task is genuinely parked mid-flight
+struct YieldOnce(bool);
+
+impl Future for YieldOnce {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<()> {
+        if self.0 {
+            Poll::Ready(())
+        } else {
+            self.0 = true;
+            Poll::Pending
+        }
+    }
+}
+
+async fn task_body(id: usize) -> usize {
+    let local = [id; 4];  // Lives inside the task's state machine, not on a stack
+    YieldOnce(false).await;
+    black_box(local.iter().sum())
+}
+
+type Task = Pin<Box<dyn Future<Output = usize>>>;
+
+// Spawn N async tasks on a tiny single-threaded executor
+pub fn measure_async_tasks(count: usize) -> SpawnCost {
+    let before = process_stats::rss();
+
+    let start = Instant::now();
+    let mut queue: VecDeque<Task> = (0..count).map(|i| Box::pin(task_body(i)) as Task).collect();
+
+    // First poll: every task runs until YieldOnce and is now suspended
+    let mut cx = Context::from_waker(Waker::noop());
+    let mut parked = VecDeque::with_capacity(count);
+    while let Some(mut task) = queue.pop_front() {
+        if task.as_mut().poll(&mut cx).is_pending() {
+            parked.push_back(task);
+        }
+    }
+    let spawn_time = start.elapsed();
+
+    let rss_delta = before.zip(process_stats::rss()).map(|(b, a)| a.saturating_sub(b));
+    let reserved_per_unit = std::mem::size_of_val(&task_body(0));
+
+    // Drive every task to completion
+    while let Some(mut task) = parked.pop_front() {
+        if task.as_mut().poll(&mut cx).is_pending() {
+            parked.push_back(task);
+        }
+    }
+
+    SpawnCost { count, spawn_time, rss_delta, reserved_per_unit, spawn_error: None }
+}
+
+fn record_cost(report: &mut DemoReport, cost: &SpawnCost) {
+    report.count("Spawned", cost.count);
+    if let Some(err) = &cost.spawn_error {
+        report.warn(format!("Spawning stopped after {} threads: {}", cost.count, err));
+    }
+    report.duration("Spawn time (total)", cost.spawn_time);
+    report.duration("Spawn time (each)", cost.per_spawn());
+    report.bytes("Reserved per unit", cost.reserved_per_unit);
+    match cost.rss_delta {
+        Some(delta) => report.bytes("RSS growth", delta),
+        None => report.text("RSS growth: (not available on this OS)"),
+    };
+}
+
+pub fn thread_spawn_example(count: usize) -> DemoReport {
+    let mut report = DemoReport::new(format!("{} OS Threads (thread::spawn)", count));
+
+    let cost = measure_threads(count);
+    record_cost(&mut report, &cost);
+
+    report.gap();
+    report.ok("Real OS threads: preemptive, run in parallel");
+    report.warn("Each reserves a full stack (virtual memory, touched lazily)");
+    report.warn("Spawning costs a syscall + kernel scheduling");
+    report
+}
+
+pub fn async_task_example(count: usize) -> DemoReport {
+    let mut report = DemoReport::new(format!("{} Async Tasks (async fn state machines)", count));
+
+    let cost = measure_async_tasks(count);
+    record_cost(&mut report, &cost);
+
+    report.gap();
+    report.ok("Each task is a heap-allocated state machine, sized at compile-time");
+    report.ok("No stack per task - locals that live across .await go in the struct");
+    report.warn("Needs an executor to run (tokio, async-std, or a hand-rolled one)");
+    report
+}
+
+pub fn spawn_cost_table(count: usize) -> DemoReport {
+    let mut report =
+        DemoReport::new(format!("Goroutines vs Threads vs Async Tasks ({} each)", count));
+
+    let threads = measure_threads(count);
+    let tasks = measure_async_tasks(count);
+
+    let rss = |cost: &SpawnCost| cost.rss_delta.map(format_bytes).unwrap_or_else(|| "n/a".into());
+    let row = |model: &str, cost: &SpawnCost| {
+        vec![
+            model.to_string(),
+            format!("{:?}", cost.per_spawn()),
+            format_bytes(cost.reserved_per_unit),
+            rss(cost),
+        ]
+    };
+
+    report.table(
+        &["Model", "Spawn/unit", "Reserved/unit", "RSS growth"],
+        vec![
+            row("OS thread", &threads),
+            row("Async task", &tasks),
+            vec![
+                "Goroutine (Go)".to_string(),
+                "~0.3-1µs".to_string(),
+                format_bytes(GOROUTINE_STACK_SIZE),
+                format_bytes(GOROUTINE_STACK_SIZE * count),
+            ],
+        ],
+    );
+
+    report.gap();
+    report.text("(Goroutine row: Go runtime defaults, not measured here)");
+    if let Some(err) = &threads.spawn_error {
+        report.warn(format!("OS thread row: only {} of {} threads spawned - {}", threads.count, count, err));
+    }
+
+    report.step("Key Insight");
+    report.text("Go: one model (goroutines) - cheap, growable stacks, GC-scanned");
+    report.text("Rust: choose - OS threads (simple, heavy) or async tasks (tiny, needs executor)");
+    report
+}
+
+fn threads_with_count() -> DemoReport {
+    thread_spawn_example(count())
+}
+
+fn tasks_with_count() -> DemoReport {
+    async_task_example(count())
+}
+
+fn table_with_count() -> DemoReport {
+    spawn_cost_table(count())
+}
+
+pub const DEMO: Demo = Demo {
+    name: "goroutines-vs-threads",
+    about: "Spawn cost of OS threads vs async tasks vs goroutines (measured)",
+    difficulty: Difficulty::Intermediate,
+    tags: &[Tag::Concurrency, Tag::Benchmark],
+    examples: &[
+        Example { name: "threads", run: threads_with_count },
+        Example { name: "async-tasks", run: tasks_with_count },
+        Example { name: "table", run: table_with_count },
+    ],
+};
+
+pub fn demonstrate_goroutines_vs_threads(count: usize) -> Vec<DemoReport> {
+    vec![
+        DemoReport::measured(|| thread_spawn_example(count)),
+        DemoReport::measured(|| async_task_example(count)),
+        DemoReport::measured(|| spawn_cost_table(count)),
+    ]
+}
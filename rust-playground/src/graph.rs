@@ -0,0 +1,256 @@
+// Ownership graphs as Graphviz DOT (`cargo run -- graph tree | dot -Tpng -o tree.png`)
+// The Rc/Weak demos are about shapes: who owns whom, which edges keep
+// something alive and which don't. Each graph here is built from real Rc
+// values, so the counts on the nodes are what Rc::strong_count and
+// Rc::weak_count returned - solid edges own (strong), dashed ones don't (weak).
+// Go has no such picture to draw: every pointer is an edge the GC traces,
+// and cycles are collected like anything else.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::rc::{Rc, Weak};
+
+/// Whether an edge keeps its target alive
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EdgeKind {
+    Strong,
+    Weak,
+}
+
+/// One Rc allocation, or a stack variable holding one
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GraphNode {
+    pub label: String,
+    /// None for stack variables
+    pub counts: Option<(usize, usize)>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GraphEdge {
+    pub from: usize,
+    pub to: usize,
+    pub kind: EdgeKind,
+    pub label: String,
+}
+
+/// Nodes and edges recorded from live Rc values
+#[derive(Debug, Default)]
+pub struct OwnershipGraph {
+    pub title: String,
+    nodes: Vec<GraphNode>,
+    edges: Vec<GraphEdge>,
+    notes: Vec<String>,
+    // Rc::as_ptr address → node index, so the same allocation is one node
+    by_address: HashMap<usize, usize>,
+}
+
+impl OwnershipGraph {
+    pub fn new(title: impl Into<String>) -> Self {
+        OwnershipGraph { title: title.into(), ..OwnershipGraph::default() }
+    }
+
+    /// The node for `rc`, with its counts as of this call
+    pub fn rc<T>(&mut self, rc: &Rc<T>, label: impl Into<String>) -> usize {
+        let address = Rc::as_ptr(rc) as *const () as usize;
+        if let Some(&index) = self.by_address.get(&address) {
+            return index;
+        }
+        self.nodes.push(GraphNode { label: label.into(), counts: Some((Rc::strong_count(rc), Rc::weak_count(rc))) });
+        self.by_address.insert(address, self.nodes.len() - 1);
+        self.nodes.len() - 1
+    }
+
+    /// A stack variable: not counted, but it owns what it points to
+    pub fn variable(&mut self, name: impl Into<String>) -> usize {
+        self.nodes.push(GraphNode { label: name.into(), counts: None });
+        self.nodes.len() - 1
+    }
+
+    pub fn strong(&mut self, from: usize, to: usize, label: impl Into<String>) {
+        self.edges.push(GraphEdge { from, to, kind: EdgeKind::Strong, label: label.into() });
+    }
+
+    pub fn weak(&mut self, from: usize, to: usize, label: impl Into<String>) {
+        self.edges.push(GraphEdge { from, to, kind: EdgeKind::Weak, label: label.into() });
+    }
+
+    /// A line under the title
+    pub fn note(&mut self, note: impl Into<String>) {
+        self.notes.push(note.into());
+    }
+
+    pub fn nodes(&self) -> &[GraphNode] {
+        &self.nodes
+    }
+
+    pub fn edges(&self) -> &[GraphEdge] {
+        &self.edges
+    }
+
+    /// The graph as a DOT digraph
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::new();
+        let mut caption = self.title.clone();
+        for note in &self.notes {
+            caption.push('\n');
+            caption.push_str(note);
+        }
+        caption.push_str("\nsolid = strong (owns)   dashed = weak (doesn't)");
+
+        // Writing to a String never fails
+        let _ = writeln!(dot, "digraph ownership {{");
+        let _ = writeln!(dot, "    label={}; labelloc=t; fontname=\"monospace\";", quote(&caption));
+        let _ = writeln!(dot, "    node [fontname=\"monospace\"]; edge [fontname=\"monospace\", fontsize=10];");
+        for (index, node) in self.nodes.iter().enumerate() {
+            match node.counts {
+                Some((strong, weak)) => {
+                    let label = format!("{}\nstrong {} · weak {}", node.label, strong, weak);
+                    let _ = writeln!(dot, "    n{} [shape=box, style=rounded, label={}];", index, quote(&label));
+                }
+                None => {
+                    let _ = writeln!(dot, "    n{} [shape=plaintext, label={}];", index, quote(&node.label));
+                }
+            }
+        }
+        for edge in &self.edges {
+            let style = match edge.kind {
+                EdgeKind::Strong => "solid",
+                EdgeKind::Weak => "dashed, color=gray40",
+            };
+            let _ = writeln!(dot, "    n{} -> n{} [style={}, label={}];", edge.from, edge.to, style, quote(&edge.label));
+        }
+        let _ = writeln!(dot, "}}");
+        dot
+    }
+}
+
+// A DOT string literal
+fn quote(text: &str) -> String {
+    let escaped = text.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n");
+    format!("\"{}\"", escaped)
+}
+
+struct TreeNode {
+    value: i32,
+    parent: RefCell<Weak<TreeNode>>,
+    children: RefCell<Vec<Rc<TreeNode>>>,
+}
+
+impl TreeNode {
+    fn new(value: i32) -> Rc<TreeNode> {
+        Rc::new(TreeNode { value, parent: RefCell::new(Weak::new()), children: RefCell::new(Vec::new()) })
+    }
+}
+
+/// rc-weak's tree: children owned through Rc, parent pointers Weak
+pub fn tree() -> OwnershipGraph {
+    let branch = TreeNode::new(5);
+    for value in [3, 4] {
+        let leaf = TreeNode::new(value);
+        *leaf.parent.borrow_mut() = Rc::downgrade(&branch);
+        branch.children.borrow_mut().push(leaf);
+    }
+
+    let mut graph = OwnershipGraph::new("Tree: Rc down, Weak up");
+    let root = graph.variable("let branch");
+    let branch_node = graph.rc(&branch, format!("TreeNode {}", branch.value));
+    graph.strong(root, branch_node, "");
+    for (index, leaf) in branch.children.borrow().iter().enumerate() {
+        let leaf_node = graph.rc(leaf, format!("TreeNode {}", leaf.value));
+        graph.strong(branch_node, leaf_node, format!("children[{}]", index));
+        if leaf.parent.borrow().upgrade().is_some() {
+            graph.weak(leaf_node, branch_node, "parent");
+        }
+    }
+    graph.note("Dropping `branch` takes strong to 0: the whole tree is freed");
+    graph
+}
+
+struct ListNode {
+    name: &'static str,
+    next: RefCell<Option<Rc<ListNode>>>,
+    prev: RefCell<Weak<ListNode>>,
+}
+
+impl ListNode {
+    fn new(name: &'static str) -> Rc<ListNode> {
+        Rc::new(ListNode { name, next: RefCell::new(None), prev: RefCell::new(Weak::new()) })
+    }
+}
+
+/// leak-check's cycle: a → b → a, both strong
+pub fn cycle() -> OwnershipGraph {
+    let a = ListNode::new("a");
+    let b = ListNode::new("b");
+    *a.next.borrow_mut() = Some(Rc::clone(&b));
+    *b.next.borrow_mut() = Some(Rc::clone(&a));
+
+    let mut graph = OwnershipGraph::new("Cycle: strong both ways");
+    for (name, node) in [("let a", &a), ("let b", &b)] {
+        let variable = graph.variable(name);
+        let index = graph.rc(node, format!("ListNode {}", node.name));
+        graph.strong(variable, index, "");
+    }
+    for node in [&a, &b] {
+        if let Some(next) = node.next.borrow().as_ref() {
+            let (from, to) = (graph.rc(node, ""), graph.rc(next, ""));
+            graph.strong(from, to, "next");
+        }
+    }
+
+    // What's left once the variables go out of scope
+    let (weak_a, weak_b) = (Rc::downgrade(&a), Rc::downgrade(&b));
+    drop(a);
+    drop(b);
+    graph.note(format!(
+        "After `a` and `b` drop: strong {} and {} - unreachable, never freed",
+        weak_a.strong_count(),
+        weak_b.strong_count()
+    ));
+    // Break the cycle so this function doesn't leak itself
+    if let Some(a) = weak_a.upgrade() {
+        a.next.borrow_mut().take();
+    }
+    graph
+}
+
+/// The fix: one direction Weak
+pub fn weak_cycle() -> OwnershipGraph {
+    let a = ListNode::new("a");
+    let b = ListNode::new("b");
+    *a.next.borrow_mut() = Some(Rc::clone(&b));
+    *b.prev.borrow_mut() = Rc::downgrade(&a);
+
+    let mut graph = OwnershipGraph::new("Cycle broken: next is Rc, prev is Weak");
+    for (name, node) in [("let a", &a), ("let b", &b)] {
+        let variable = graph.variable(name);
+        let index = graph.rc(node, format!("ListNode {}", node.name));
+        graph.strong(variable, index, "");
+    }
+    let (a_node, b_node) = (graph.rc(&a, ""), graph.rc(&b, ""));
+    graph.strong(a_node, b_node, "next");
+    graph.weak(b_node, a_node, "prev");
+    graph.note("Dropping `a` takes its strong count to 0, which then releases b");
+    graph
+}
+
+/// A graph the CLI can draw by name
+#[derive(Debug, Clone, Copy)]
+pub struct NamedGraph {
+    pub name: &'static str,
+    pub about: &'static str,
+    pub build: fn() -> OwnershipGraph,
+}
+
+/// Every graph `cargo run -- graph <NAME>` can draw
+pub const GRAPHS: &[NamedGraph] = &[
+    NamedGraph { name: "tree", about: "rc-weak: parent owns children, children point back with Weak", build: tree },
+    NamedGraph { name: "cycle", about: "leak-check: two Rc pointing at each other - leaked", build: cycle },
+    NamedGraph { name: "weak-cycle", about: "the same pair with the back edge made Weak", build: weak_cycle },
+];
+
+/// Build the graph called `name`
+pub fn build(name: &str) -> Option<OwnershipGraph> {
+    GRAPHS.iter().find(|graph| graph.name == name).map(|graph| (graph.build)())
+}
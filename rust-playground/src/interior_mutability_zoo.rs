@@ -0,0 +1,402 @@
+// Interior mutability zoo - one counter, five cells
+// Go:   any pointer can mutate what it points to; whether that's safe across
+//       goroutines is up to you (sync.Mutex, sync.RWMutex, sync/atomic - or
+//       a data race the compiler never mentions).
+// Rust: &T normally means "nobody mutates this". The types below are the
+//       sanctioned exceptions, each trading something different for
+//       mutation through &self:
+//         Cell         copy in/out, no check, one thread
+//         RefCell      borrow flag checked at runtime, one thread, panics
+//         Mutex        lock, any thread, blocks (and poisons)
+//         RwLock       many readers or one writer, any thread, blocks
+//         AtomicUsize  one hardware instruction, any thread, integers only
+
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::hint::black_box;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Mutex, RwLock};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::registry::{Demo, Difficulty, Example, Tag};
+use crate::report::DemoReport;
+
+const INCREMENTS: usize = if cfg!(debug_assertions) { 1_000_000 } else { 10_000_000 };
+
+/// A counter you can bump through a shared reference
+pub trait SharedCounter {
+    fn increment(&self);
+    fn get(&self) -> usize;
+}
+
+impl SharedCounter for Cell<usize> {
+    fn increment(&self) {
+        self.set(self.get() + 1);
+    }
+
+    fn get(&self) -> usize {
+        Cell::get(self)
+    }
+}
+
+impl SharedCounter for RefCell<usize> {
+    fn increment(&self) {
+        *self.borrow_mut() += 1;
+    }
+
+    fn get(&self) -> usize {
+        *self.borrow()
+    }
+}
+
+impl SharedCounter for Mutex<usize> {
+    fn increment(&self) {
+        *self.lock().unwrap() += 1;
+    }
+
+    fn get(&self) -> usize {
+        *self.lock().unwrap()
+    }
+}
+
+impl SharedCounter for RwLock<usize> {
+    fn increment(&self) {
+        *self.write().unwrap() += 1;
+    }
+
+    fn get(&self) -> usize {
+        *self.read().unwrap()
+    }
+}
+
+impl SharedCounter for AtomicUsize {
+    fn increment(&self) {
+        self.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn get(&self) -> usize {
+        self.load(Ordering::Relaxed)
+    }
+}
+
+/// One row of the zoo: a name and a fresh counter of that kind
+pub struct Specimen {
+    pub name: &'static str,
+    pub size: usize,
+    pub check: &'static str,
+    pub threads: &'static str,
+    pub failure: &'static str,
+    pub go: &'static str,
+    pub counter: Box<dyn SharedCounter>,
+}
+
+/// Every cell in the zoo, each holding a counter at zero
+pub fn specimens() -> Vec<Specimen> {
+    vec![
+        Specimen {
+            name: "Cell<usize>",
+            size: size_of::<Cell<usize>>(),
+            check: "none - get/set copy",
+            threads: "Send, !Sync",
+            failure: "can't fail",
+            go: "a plain int",
+            counter: Box::new(Cell::new(0)),
+        },
+        Specimen {
+            name: "RefCell<usize>",
+            size: size_of::<RefCell<usize>>(),
+            check: "borrow flag",
+            threads: "Send, !Sync",
+            failure: "panic (BorrowMutError)",
+            go: "a plain int",
+            counter: Box::new(RefCell::new(0)),
+        },
+        Specimen {
+            name: "Mutex<usize>",
+            size: size_of::<Mutex<usize>>(),
+            check: "lock word",
+            threads: "Send + Sync",
+            failure: "blocks; poisoned by a panic",
+            go: "sync.Mutex + int",
+            counter: Box::new(Mutex::new(0)),
+        },
+        Specimen {
+            name: "RwLock<usize>",
+            size: size_of::<RwLock<usize>>(),
+            check: "reader count + writer bit",
+            threads: "Send + Sync",
+            failure: "blocks; poisoned by a panic",
+            go: "sync.RWMutex + int",
+            counter: Box::new(RwLock::new(0)),
+        },
+        Specimen {
+            name: "AtomicUsize",
+            size: size_of::<AtomicUsize>(),
+            check: "none - the CPU does it",
+            threads: "Send + Sync",
+            failure: "can't fail",
+            go: "atomic.Uint64",
+            counter: Box::new(AtomicUsize::new(0)),
+        },
+    ]
+}
+
+/// Time `count` increments through `counter`
+pub fn time_increments(counter: &dyn SharedCounter, count: usize) -> Duration {
+    let start = Instant::now();
+    for _ in 0..count {
+        black_box(counter).increment();
+    }
+    start.elapsed()
+}
+
+// The same counter, written five ways
+pub fn same_counter() -> DemoReport {
+    let mut report = DemoReport::new("One Counter, Five Kinds of Interior Mutability");
+
+    report.code("trait SharedCounter { fn increment(&self); fn get(&self) -> usize; }  // &self, not &mut self");
+    report.code("impl SharedCounter for Cell<usize>    { fn increment(&self) { self.set(self.get() + 1) } }");
+    report.code("impl SharedCounter for RefCell<usize> { fn increment(&self) { *self.borrow_mut() += 1 } }");
+    report.code("impl SharedCounter for Mutex<usize>   { fn increment(&self) { *self.lock().unwrap() += 1 } }");
+    report.code("impl SharedCounter for RwLock<usize>  { fn increment(&self) { *self.write().unwrap() += 1 } }");
+    report.code("impl SharedCounter for AtomicUsize    { fn increment(&self) { self.fetch_add(1, Relaxed); } }");
+
+    let specimens = specimens();
+    for specimen in &specimens {
+        let shared: &dyn SharedCounter = specimen.counter.as_ref();
+        let (first, second) = (shared, shared);  // Two shared references, both mutate
+        first.increment();
+        second.increment();
+        first.increment();
+        report.text(format!("{:<15} after 3 increments through 2 &-refs: {}", specimen.name, shared.get()));
+    }
+
+    report.gap();
+    report.ok("Same trait, same &self signature - the difference is only in what each one checks");
+    report.text("Plain usize can't implement it: `self.0 += 1` needs &mut self");
+
+    report.step("Go");
+    report.code("func (c *Counter) Increment() { c.n++ }  // every pointer may mutate; nothing to choose");
+    report
+}
+
+// Size, runtime check, thread-safety and failure mode side by side
+pub fn hierarchy() -> DemoReport {
+    let mut report = DemoReport::new("What Each One Costs - Size, Checks, Threads");
+
+    report.table(
+        &["Type", "size_of", "Runtime check", "Threads", "When it goes wrong", "Go"],
+        specimens()
+            .iter()
+            .map(|specimen| {
+                vec![
+                    specimen.name.to_string(),
+                    format!("{} B", specimen.size),
+                    specimen.check.to_string(),
+                    specimen.threads.to_string(),
+                    specimen.failure.to_string(),
+                    specimen.go.to_string(),
+                ]
+            })
+            .collect(),
+    );
+    report.text(format!("(usize alone is {} B - the rest is the cell's bookkeeping)", size_of::<usize>()));
+
+    report.step("!Sync is a compile error, not a race");
+    report.code("let hits = Cell::new(0);");
+    report.code("thread::scope(|s| { s.spawn(|| hits.set(hits.get() + 1)); });");
+    report.fail("E0277: `Cell<usize>` cannot be shared between threads safely");
+    report.text("Checked by tests/compile_fail/cell_shared_across_threads.rs");
+    let hits = AtomicUsize::new(0);
+    thread::scope(|s| {
+        for _ in 0..4 {
+            s.spawn(|| hits.fetch_add(1, Ordering::Relaxed));
+        }
+    });
+    report.ok(format!("Swap in AtomicUsize and it compiles: {} hits from 4 threads", hits.load(Ordering::Relaxed)));
+
+    report.gap();
+    report.ok("Go down the list only as far as you need: one thread → Cell/RefCell, integers → atomics, the rest → locks");
+    report.text("Cell and RefCell are Send: a whole counter can MOVE to another thread, just not be shared");
+    report
+}
+
+/// Collatz sequence lengths memoized in a RefCell<HashMap>
+#[derive(Debug, Default)]
+pub struct CollatzCache {
+    lengths: RefCell<HashMap<u64, u32>>,
+    pub misses: Cell<usize>,
+}
+
+impl CollatzCache {
+    /// Steps from `n` down to 1; recursion re-enters the cache through &self
+    pub fn length(&self, n: u64) -> u32 {
+        if n <= 1 {
+            return 0;
+        }
+        if let Some(&length) = self.lengths.borrow().get(&n) {
+            return length;
+        }  // ...and the Ref is dropped here, before the recursive call
+        self.misses.set(self.misses.get() + 1);
+        let next = if n.is_multiple_of(2) { n / 2 } else { 3 * n + 1 };
+        let length = 1 + self.length(next);
+        self.lengths.borrow_mut().insert(n, length);
+        length
+    }
+
+    pub fn len(&self) -> usize {
+        self.lengths.borrow().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lengths.borrow().is_empty()
+    }
+}
+
+/// The same cache, shareable between threads
+#[derive(Debug, Default)]
+pub struct SharedCollatzCache {
+    lengths: RwLock<HashMap<u64, u32>>,
+    pub misses: AtomicUsize,
+}
+
+impl SharedCollatzCache {
+    pub fn length(&self, n: u64) -> u32 {
+        if n <= 1 {
+            return 0;
+        }
+        if let Some(&length) = self.lengths.read().unwrap().get(&n) {
+            return length;
+        }
+        self.misses.fetch_add(1, Ordering::Relaxed);
+        let next = if n.is_multiple_of(2) { n / 2 } else { 3 * n + 1 };
+        let length = 1 + self.length(next);
+        self.lengths.write().unwrap().insert(n, length);
+        length
+    }
+
+    pub fn len(&self) -> usize {
+        self.lengths.read().unwrap().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lengths.read().unwrap().is_empty()
+    }
+}
+
+// A memo cache behind &self: RefCell for one thread, RwLock for many
+pub fn cache() -> DemoReport {
+    let mut report = DemoReport::new("A Memo Cache Behind &self - RefCell vs RwLock");
+
+    report.step("One thread: RefCell<HashMap>");
+    report.code("fn length(&self, n: u64) -> u32 {");
+    report.code("    if let Some(&len) = self.lengths.borrow().get(&n) { return len; }  // Ref dropped at the }");
+    report.code("    let len = 1 + self.length(next(n));                                  // re-enters the cache");
+    report.code("    self.lengths.borrow_mut().insert(n, len);");
+    report.code("}");
+    let cache = CollatzCache::default();
+    let first = cache.length(27);
+    let misses = cache.misses.get();
+    let second = cache.length(27);
+    report.text(format!("length(27) = {} - {} misses filled {} entries", first, misses, cache.len()));
+    report.text(format!("length(27) again = {} - {} new misses", second, cache.misses.get() - misses));
+    report.warn("Hold the Ref across the recursive call (`match self.lengths.borrow().get(..)`) and the insert panics: already borrowed");
+    report.text("The borrow rules still hold - RefCell checks them at runtime instead of compile time");
+
+    report.step("Many threads: RwLock<HashMap>");
+    let shared = SharedCollatzCache::default();
+    let shared = &shared;
+    let lengths: Vec<u32> = thread::scope(|s| {
+        let handles: Vec<_> = [27u64, 97, 871, 6171].iter().map(|&n| s.spawn(move || shared.length(n))).collect();
+        handles.into_iter().map(|handle| handle.join().unwrap()).collect()
+    });
+    report.text(format!("lengths of 27, 97, 871, 6171 from 4 threads: {:?}", lengths));
+    report.count("entries shared by all of them", shared.len());
+    report.text("Readers run in parallel; a miss takes the write lock only for the insert");
+
+    report.gap();
+    report.ok("Same code shape - borrow()/borrow_mut() become read()/write(), Cell becomes an atomic");
+    report.text("Two threads missing on the same n both compute it: harmless here, a real cost for expensive values");
+
+    report.step("Go");
+    report.code("var mu sync.RWMutex; cache := map[uint64]uint32{}");
+    report.code("mu.RLock(); v, ok := cache[n]; mu.RUnlock()  // forget the lock: fatal error: concurrent map read and map write");
+    report
+}
+
+// Single-threaded cost of one increment through each
+pub fn benchmark() -> DemoReport {
+    let mut report = DemoReport::new("Benchmark - ns per Increment, One Thread");
+
+    report.count("increments per row", INCREMENTS);
+    let rows: Vec<(Specimen, Duration)> = specimens()
+        .into_iter()
+        .map(|specimen| {
+            let elapsed = time_increments(specimen.counter.as_ref(), INCREMENTS);
+            (specimen, elapsed)
+        })
+        .collect();
+    let fastest = rows.iter().map(|(_, elapsed)| *elapsed).min().unwrap_or_default().max(Duration::from_nanos(1));
+    report.table(
+        &["Type", "ns / increment", "vs fastest", "Final count"],
+        rows.iter()
+            .map(|(specimen, elapsed)| {
+                vec![
+                    specimen.name.to_string(),
+                    format!("{:.2}", elapsed.as_nanos() as f64 / INCREMENTS as f64),
+                    format!("{:.1}×", elapsed.as_secs_f64() / fastest.as_secs_f64()),
+                    specimen.counter.get().to_string(),
+                ]
+            })
+            .collect(),
+    );
+    if cfg!(debug_assertions) {
+        report.warn("Debug build - run with --release for representative numbers");
+    }
+
+    report.gap();
+    report.ok("Cell is a plain add; RefCell adds a flag check; atomics a locked instruction");
+    report.ok("Mutex/RwLock uncontended: one atomic to lock, one to unlock - still no syscall");
+    report.text("These are the no-contention numbers; `run contention-bench` shows what threads do to them");
+    report
+}
+
+pub fn zoo_vs_go() -> DemoReport {
+    let mut report = DemoReport::new("Choosing a Cell: Go vs Rust");
+
+    report.table(
+        &["You want", "Go", "Rust"],
+        vec![
+            vec!["mutate via a shared pointer, 1 thread".into(), "just do it".into(), "Cell (Copy) / RefCell".into()],
+            vec!["a counter across goroutines".into(), "atomic.Int64".into(), "AtomicUsize / AtomicU64".into()],
+            vec!["a struct across goroutines".into(), "sync.Mutex next to it".into(), "Mutex<T> around it".into()],
+            vec!["read-mostly shared data".into(), "sync.RWMutex".into(), "RwLock<T>".into()],
+            vec!["forgot the lock".into(), "data race (go run -race)".into(), "doesn't compile".into()],
+            vec!["mutable borrow twice".into(), "-".into(), "RefCell panics / Mutex deadlocks".into()],
+        ],
+    );
+
+    report.gap();
+    report.ok("Go's lock sits BESIDE the data; Rust's cell wraps it - no access without going through it");
+    report.text("RefCell and Cell have no Go counterpart because Go doesn't distinguish shared from mutable");
+    report
+}
+
+pub const DEMO: Demo = Demo {
+    name: "interior-mutability-zoo",
+    about: "Cell vs RefCell vs Mutex vs RwLock vs AtomicUsize: one counter, sizes, checks and ns/op",
+    difficulty: Difficulty::Intermediate,
+    tags: &[Tag::Borrowing, Tag::Concurrency, Tag::Benchmark],
+    examples: &[
+        Example { name: "counter", run: same_counter },
+        Example { name: "hierarchy", run: hierarchy },
+        Example { name: "cache", run: cache },
+        Example { name: "benchmark", run: benchmark },
+        Example { name: "vs-go", run: zoo_vs_go },
+    ],
+};
+
+pub fn demonstrate_interior_mutability_zoo() -> Vec<DemoReport> {
+    DEMO.run()
+}
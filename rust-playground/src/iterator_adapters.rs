@@ -0,0 +1,286 @@
+// Iterators - lazy adapter chains that compile down to loops
+// Go:   for i, x := range xs { ... } is the one loop; filtering or mapping
+//       means an explicit loop that appends to a new slice (and grows it).
+// Rust: xs.iter().filter(..).map(..) builds a struct of nested adapters.
+//       Nothing runs until a consumer (sum, collect, for) pulls items, and
+//       after inlining the chain is the same machine code as the hand loop.
+//       Which iterator you ask for is an ownership choice: iter() borrows,
+//       iter_mut() borrows mutably, into_iter() consumes.
+
+use std::cell::RefCell;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use crate::registry::{Demo, Difficulty, Example, Tag};
+use crate::report::DemoReport;
+
+const LEN: usize = 1_000_000;
+const ROUNDS: usize = 20;
+
+// map / filter / collect and what collect allocates
+pub fn chains() -> DemoReport {
+    let mut report = DemoReport::new("Iterator Chains - map, filter, collect");
+
+    let prices = vec![120u32, 45, 300, 80, 999, 15];
+    report.text(format!("prices = {:?}", prices));
+
+    report.code("let total: u32 = prices.iter().filter(|&&p| p >= 100).sum();");
+    let total: u32 = prices.iter().filter(|&&p| p >= 100).sum();
+    report.count("total of prices >= 100", total as usize);
+
+    report.code("let with_tax: Vec<u32> = prices.iter().map(|p| p * 120 / 100).collect();");
+    let with_tax: Vec<u32> = prices.iter().map(|p| p * 120 / 100).collect();
+    report.text(format!("with_tax = {:?}", with_tax));
+    report.count("with_tax.capacity()", with_tax.capacity());
+    report.text("map keeps the length known (size_hint), so collect allocates once, exactly");
+
+    report.code("let cheap: Vec<String> = prices.iter().filter(..).map(|p| format!(\"${}\", p)).collect();");
+    let cheap: Vec<String> = prices.iter().filter(|&&p| p < 100).map(|p| format!("${}", p)).collect();
+    report.text(format!("cheap = {:?}", cheap));
+
+    report.gap();
+    report.ok("No intermediate Vec between filter and map - items flow through one at a time");
+    report.text("sum, count, max, any, find, fold consume without allocating at all");
+
+    report.step("Go");
+    report.code("var total uint32");
+    report.code("for _, p := range prices { if p >= 100 { total += p } }");
+    report.text("Same work, spelled as a loop - Go has no adapter library in the standard library");
+    report
+}
+
+// Adapters are lazy: nothing happens until something pulls
+pub fn laziness() -> DemoReport {
+    let mut report = DemoReport::new("Laziness - Nothing Runs Until Consumed");
+
+    let log = RefCell::new(Vec::new());
+    let numbers = [1, 2, 3, 4, 5, 6, 7, 8];
+
+    let chain = numbers
+        .iter()
+        .map(|n| {
+            log.borrow_mut().push(format!("map({})", n));
+            n * 10
+        })
+        .filter(|n| {
+            log.borrow_mut().push(format!("filter({})", n));
+            n % 20 == 0
+        });
+    report.code("let chain = numbers.iter().map(..).filter(..);");
+    report.count("closure calls after building the chain", log.borrow().len());
+    report.bytes("size_of_val(&chain)", size_of_val(&chain));
+    report.text("The chain is just a struct: the slice iterator plus two closures");
+
+    let first_two: Vec<i32> = chain.take(2).collect();
+    report.code("let first_two: Vec<_> = chain.take(2).collect();");
+    report.text(format!("first_two = {:?}", first_two));
+    report.text(format!("calls: {}", log.borrow().join(" → ")));
+    report.count("closure calls in total", log.borrow().len());
+    report.ok(format!("Stopped after item 4 - items 5..{} were never touched", numbers.len()));
+
+    report.gap();
+    report.ok("Each item goes through the whole chain before the next one starts");
+    report.warn("A chain that's never consumed does nothing - rustc warns: unused `Filter` that must be used");
+
+    report.step("Go");
+    report.text("Go loops are eager; stopping early means a break, and the iter package");
+    report.text("(Go 1.23 range-over-func) is the first standard way to build lazy sequences");
+    report
+}
+
+// iter() / iter_mut() / into_iter(): borrow, borrow mutably, or consume
+pub fn iter_vs_into_iter() -> DemoReport {
+    let mut report = DemoReport::new("iter() vs iter_mut() vs into_iter() - Who Owns the Items");
+
+    report.table(
+        &["Call", "for loop form", "Yields", "Collection afterwards"],
+        vec![
+            vec!["v.iter()".into(), "for x in &v".into(), "&T".into(), "unchanged, usable".into()],
+            vec!["v.iter_mut()".into(), "for x in &mut v".into(), "&mut T".into(), "modified in place".into()],
+            vec!["v.into_iter()".into(), "for x in v".into(), "T".into(), "moved - gone".into()],
+        ],
+    );
+
+    let mut names = vec![String::from("ana"), String::from("bo"), String::from("cy")];
+
+    report.step("iter(): read through &String");
+    let lengths: Vec<usize> = names.iter().map(|name| name.len()).collect();
+    report.text(format!("lengths = {:?}, names still = {:?}", lengths, names));
+
+    report.step("iter_mut(): change each String in place");
+    for name in names.iter_mut() {
+        name.make_ascii_uppercase();
+    }
+    report.text(format!("names = {:?}", names));
+
+    report.step("into_iter(): take the Strings out");
+    let first_ptr = names[0].as_ptr();
+    let owned: Vec<String> = names.into_iter().filter(|name| name.len() > 2).collect();
+    report.text(format!("owned = {:?}", owned));
+    report.ok(format!("owned[0]'s text is still at {:p} - moved, not copied", first_ptr));
+    report.code("println!(\"{:?}\", names);  // ❌ E0382: borrow of moved value: `names`");
+    report.text("Checked by tests/compile_fail/use_after_into_iter.rs");
+
+    report.gap();
+    report.ok("The loop header says whether the loop can keep, change or consume the items");
+
+    report.step("Go");
+    report.code("for _, name := range names { name = strings.ToUpper(name) }  // no effect: name is a copy");
+    report.code("for i := range names { names[i] = strings.ToUpper(names[i]) }  // what you meant");
+    report.text("range always copies each element; there is no consuming form");
+    report
+}
+
+fn time_rounds(mut f: impl FnMut() -> u64) -> Duration {
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        black_box(f());
+    }
+    start.elapsed()
+}
+
+// Sum of squares of the even numbers, written three ways
+#[allow(clippy::needless_range_loop)]  // the hand-indexed loop is the point
+fn index_loop(values: &[u64]) -> u64 {
+    let mut total = 0;
+    for i in 0..values.len() {
+        if values[i].is_multiple_of(2) {
+            total += values[i] * values[i];
+        }
+    }
+    total
+}
+
+fn iterator_chain(values: &[u64]) -> u64 {
+    values.iter().filter(|v| v.is_multiple_of(2)).map(|&v| v * v).sum()
+}
+
+fn collect_between(values: &[u64]) -> u64 {
+    let evens: Vec<u64> = values.iter().copied().filter(|v| v.is_multiple_of(2)).collect();
+    evens.iter().map(|v| v * v).sum()
+}
+
+// The zero-cost claim, measured
+pub fn zero_cost_benchmark() -> DemoReport {
+    let mut report = DemoReport::new("Zero-Cost Iterators - Chain vs Index Loop");
+
+    let values: Vec<u64> = (0..LEN as u64).collect();
+    let expected = index_loop(&values);
+    let per_item = |time: Duration| format!("{:.2}", time.as_nanos() as f64 / (LEN * ROUNDS) as f64);
+
+    let loop_time = time_rounds(|| index_loop(black_box(&values)));
+    let chain_time = time_rounds(|| iterator_chain(black_box(&values)));
+    let collect_time = time_rounds(|| collect_between(black_box(&values)));
+
+    report.count("items per round", LEN);
+    report.table(
+        &["Version", "Time", "ns/item", "Heap allocs"],
+        vec![
+            vec!["for i in 0..len { values[i] ... }".into(), format!("{:?}", loop_time), per_item(loop_time), "0".into()],
+            vec!["iter().filter().map().sum()".into(), format!("{:?}", chain_time), per_item(chain_time), "0".into()],
+            vec!["collect() evens, then sum".into(), format!("{:?}", collect_time), per_item(collect_time), "1 + regrowths".into()],
+        ],
+    );
+    if iterator_chain(&values) == expected && collect_between(&values) == expected {
+        report.ok("All three agree on the result");
+    } else {
+        report.fail("Results differ");
+    }
+
+    report.gap();
+    report.ok("Chain ≈ loop: both compile to the same vectorized loop in release");
+    report.text("The chain also skips the bounds check values[i] needs (the optimizer usually removes it too)");
+    report.warn("The cost that shows up is the intermediate Vec, not the iterator");
+    if cfg!(debug_assertions) {
+        report.warn("Debug build - adapters aren't inlined; run with --release for the real comparison");
+    }
+    report
+}
+
+// Go's filter idiom: range + append grows the slice as it goes
+pub fn append_growth() -> DemoReport {
+    let mut report = DemoReport::new("Go's range + append vs collect()");
+
+    report.step("Go");
+    report.code("var evens []uint64");
+    report.code("for _, v := range values { if v%2 == 0 { evens = append(evens, v) } }");
+    report.text("append doubles (then ~1.25x) the backing array when full: copy + garbage each time");
+
+    let values: Vec<u64> = (0..100_000).collect();
+
+    report.step("Rust, push in a loop (the literal port)");
+    let mut pushed = Vec::new();
+    let mut growths = 0;
+    for &v in &values {
+        if v.is_multiple_of(2) {
+            let before = pushed.capacity();
+            pushed.push(v);
+            if pushed.capacity() != before {
+                growths += 1;
+            }
+        }
+    }
+    report.count("reallocations", growths);
+    report.count("final capacity", pushed.capacity());
+
+    report.step("Rust, collect()");
+    let evens: Vec<u64> = values.iter().copied().filter(|v| v.is_multiple_of(2)).collect();
+    report.count("len", evens.len());
+    report.count("capacity", evens.capacity());
+    report.text("filter can't know how many survive, so collect grows like append does");
+    let doubled: Vec<u64> = values.iter().map(|v| v * 2).collect();
+    report.text(format!(
+        "map keeps the exact length: len {} = capacity {}, one allocation",
+        doubled.len(),
+        doubled.capacity()
+    ));
+
+    report.step("Knowing the size up front");
+    report.code("let mut evens = Vec::with_capacity(values.len() / 2);  // Go: make([]uint64, 0, len(values)/2)");
+    report.code("let total: u64 = values.iter().filter(..).sum();  // no Vec at all");
+
+    report.gap();
+    report.ok("Same growth strategy in both languages - but Rust frees the old buffer on the spot");
+    report.ok("Often the best fix in either language: don't build the slice, consume the sequence");
+    report
+}
+
+pub fn iterators_vs_go() -> DemoReport {
+    let mut report = DemoReport::new("Iterators: Go vs Rust");
+
+    report.table(
+        &["", "Go", "Rust"],
+        vec![
+            vec!["basic loop".into(), "for i, x := range xs".into(), "for (i, x) in xs.iter().enumerate()".into()],
+            vec!["element".into(), "a copy".into(), "&T, &mut T or T - you choose".into()],
+            vec!["map / filter".into(), "a loop + append".into(), "adapters, lazy".into()],
+            vec!["early exit".into(), "break".into(), "take, find, any, take_while".into()],
+            vec!["custom sequences".into(), "iter.Seq (Go 1.23)".into(), "impl Iterator for T".into()],
+            vec!["cost".into(), "plain loop".into(), "plain loop after inlining".into()],
+        ],
+    );
+
+    report.gap();
+    report.ok("Zero-cost means: no slower than the loop you'd write by hand");
+    report.text("It does not mean free - a collect() still allocates");
+    report
+}
+
+pub const DEMO: Demo = Demo {
+    name: "iterator-adapters",
+    about: "Iterator chains: laziness, iter vs into_iter, zero-cost benchmark vs Go's for-range",
+    difficulty: Difficulty::Beginner,
+    tags: &[Tag::Ownership, Tag::Borrowing, Tag::Benchmark],
+    examples: &[
+        Example { name: "chains", run: chains },
+        Example { name: "laziness", run: laziness },
+        Example { name: "ownership", run: iter_vs_into_iter },
+        Example { name: "benchmark", run: zero_cost_benchmark },
+        Example { name: "append", run: append_growth },
+        Example { name: "vs-go", run: iterators_vs_go },
+    ],
+};
+
+pub fn demonstrate_iterator_adapters() -> Vec<DemoReport> {
+    DEMO.run()
+}
@@ -0,0 +1,191 @@
+// Memory layout - size_of, align_of and field offsets
+// Go:   fields are laid out in declaration order; padding is your problem
+//       (unsafe.Sizeof / unsafe.Alignof / unsafe.Offsetof)
+// Rust: default repr may REORDER fields to minimize padding;
+//       #[repr(C)] pins declaration order, like Go and C
+
+use std::mem::{align_of, offset_of, size_of};
+use std::num::NonZeroU32;
+
+use crate::registry::{Demo, Difficulty, Example, Tag};
+use crate::report::DemoReport;
+
+// Same fields, two layouts
+#[allow(dead_code)]
+struct Reordered {
+    a: u8,
+    b: u64,
+    c: u16,
+}
+
+#[allow(dead_code)]
+#[repr(C)]
+struct Declared {
+    a: u8,
+    b: u64,
+    c: u16,
+}
+
+#[allow(dead_code)]
+enum Shape {
+    Circle { radius: f32 },
+    Rect { width: f32, height: f32 },
+    Empty,
+}
+
+fn layout_row<T>(name: &str) -> Vec<String> {
+    vec![name.to_string(), size_of::<T>().to_string(), align_of::<T>().to_string()]
+}
+
+fn field_row(field: &str, offset: usize, size: usize) -> Vec<String> {
+    vec![field.to_string(), offset.to_string(), size.to_string()]
+}
+
+// Primitive and pointer sizes
+pub fn primitive_sizes() -> DemoReport {
+    let mut report = DemoReport::new("size_of / align_of - Primitives and Pointers");
+
+    report.table(
+        &["Type", "size", "align"],
+        vec![
+            layout_row::<u8>("u8"),
+            layout_row::<u16>("u16"),
+            layout_row::<u32>("u32"),
+            layout_row::<u64>("u64"),
+            layout_row::<u128>("u128"),
+            layout_row::<usize>("usize"),
+            layout_row::<char>("char"),
+            layout_row::<bool>("bool"),
+            layout_row::<&u8>("&u8"),
+            layout_row::<Box<u64>>("Box<u64>"),
+            layout_row::<&[u8]>("&[u8] (fat)"),
+            layout_row::<&str>("&str (fat)"),
+            layout_row::<&dyn Fn()>("&dyn Fn() (fat)"),
+            layout_row::<String>("String"),
+            layout_row::<Vec<u8>>("Vec<u8>"),
+            layout_row::<()>("()"),
+        ],
+    );
+
+    report.gap();
+    report.ok("Alignment = the address must be a multiple of it");
+    report.ok("Slices, str and dyn Trait are fat pointers: (ptr, len) or (ptr, vtable)");
+    report.text("char is 4 bytes (a Unicode scalar); Go's rune is int32 - same size");
+
+    report.step("Go");
+    report.code("unsafe.Sizeof(\"\")           // 16: {ptr, len}");
+    report.code("unsafe.Sizeof([]byte{})     // 24: {ptr, len, cap}");
+    report.code("unsafe.Sizeof(any(nil))     // 16: {type, data}");
+    report.code("unsafe.Sizeof(struct{}{})   // 0");
+    report
+}
+
+// Padding: same fields, default repr vs repr(C)
+pub fn padding_and_reordering() -> DemoReport {
+    let mut report = DemoReport::new("Padding - Default repr vs #[repr(C)]");
+
+    report.step("struct { a: u8, b: u64, c: u16 } - default repr");
+    report.table(
+        &["field", "offset", "size"],
+        vec![
+            field_row("a: u8", offset_of!(Reordered, a), size_of::<u8>()),
+            field_row("b: u64", offset_of!(Reordered, b), size_of::<u64>()),
+            field_row("c: u16", offset_of!(Reordered, c), size_of::<u16>()),
+        ],
+    );
+    report.bytes("size_of::<Reordered>()", size_of::<Reordered>());
+
+    report.step("Same fields with #[repr(C)]");
+    report.table(
+        &["field", "offset", "size"],
+        vec![
+            field_row("a: u8", offset_of!(Declared, a), size_of::<u8>()),
+            field_row("b: u64", offset_of!(Declared, b), size_of::<u64>()),
+            field_row("c: u16", offset_of!(Declared, c), size_of::<u16>()),
+        ],
+    );
+    report.bytes("size_of::<Declared>()", size_of::<Declared>());
+
+    report.gap();
+    report.ok("Default repr reordered the fields to pack them: 16 bytes, not 24");
+    report.warn("That order is unspecified - never rely on it (or transmute it)");
+    report.ok("repr(C) keeps declaration order: 7 bytes of padding after `a`");
+    report.text("Use repr(C) for FFI and stable layouts; otherwise let the compiler pack");
+
+    report.step("Go (declaration order, always)");
+    report.code("type T struct { a uint8; b uint64; c uint16 }");
+    report.code("unsafe.Sizeof(T{})        // 24 - same as repr(C)");
+    report.code("unsafe.Offsetof(T{}.b)    // 8");
+    report.warn("Go devs reorder fields by hand (or run fieldalignment) to save memory");
+    report
+}
+
+// Niche optimization: Option<Box<T>> is pointer-sized
+pub fn enum_niches() -> DemoReport {
+    let mut report = DemoReport::new("Enums and Niches - Option<Box<T>> Is Free");
+
+    report.table(
+        &["Type", "size", "align"],
+        vec![
+            layout_row::<Box<u64>>("Box<u64>"),
+            layout_row::<Option<Box<u64>>>("Option<Box<u64>>"),
+            layout_row::<&u64>("&u64"),
+            layout_row::<Option<&u64>>("Option<&u64>"),
+            layout_row::<u32>("u32"),
+            layout_row::<Option<u32>>("Option<u32>"),
+            layout_row::<NonZeroU32>("NonZeroU32"),
+            layout_row::<Option<NonZeroU32>>("Option<NonZeroU32>"),
+            layout_row::<bool>("bool"),
+            layout_row::<Option<bool>>("Option<bool>"),
+            layout_row::<Shape>("enum Shape"),
+        ],
+    );
+
+    report.gap();
+    report.ok("Box and & can never be null → None uses the null bit pattern");
+    report.ok("Option<NonZeroU32> uses 0 as None: no extra tag byte");
+    report.warn("Option<u32> has no spare bit pattern → tag + padding doubles it");
+    report.text("enum Shape = largest variant (8 bytes) + tag, rounded to align");
+
+    report.step("Go");
+    report.code("var p *User  // nil is the zero value - same 8 bytes");
+    report.text("Same size as Option<Box<User>>, but nothing forces a nil check");
+    report
+}
+
+pub fn layout_vs_go() -> DemoReport {
+    let mut report = DemoReport::new("Layout Rules: Rust vs Go");
+
+    report.table(
+        &["", "Rust", "Go"],
+        vec![
+            vec!["field order".into(), "compiler's choice (default repr)".into(), "declaration order".into()],
+            vec!["stable layout".into(), "#[repr(C)]".into(), "always".into()],
+            vec!["nullable pointer".into(), "Option<Box<T>>, same size".into(), "*T, nil allowed".into()],
+            vec!["sum types".into(), "enum with tag + niches".into(), "interface (16 bytes)".into()],
+            vec!["zero-sized types".into(), "(), PhantomData - 0 bytes".into(), "struct{} - 0 bytes".into()],
+            vec!["inspect".into(), "size_of, align_of, offset_of!".into(), "unsafe.Sizeof/Alignof/Offsetof".into()],
+        ],
+    );
+
+    report.gap();
+    report.ok("Both give you the tools - Rust also does the packing for you");
+    report
+}
+
+pub const DEMO: Demo = Demo {
+    name: "layout",
+    about: "Memory layout - size_of, align_of, padding, niches",
+    difficulty: Difficulty::Intermediate,
+    tags: &[Tag::Layout],
+    examples: &[
+        Example { name: "sizes", run: primitive_sizes },
+        Example { name: "padding", run: padding_and_reordering },
+        Example { name: "niches", run: enum_niches },
+        Example { name: "vs-go", run: layout_vs_go },
+    ],
+};
+
+pub fn demonstrate_layout() -> Vec<DemoReport> {
+    DEMO.run()
+}
@@ -0,0 +1,263 @@
+// Leak checking - is everything a demo allocated freed when it returns?
+// Needs the counting allocator (`--features alloc-stats`). A LeakChecker
+// snapshots the live heap, and after the code under test returns, whatever
+// is still live - minus what the caller deliberately keeps - is a leak.
+// `run --leak-check` does this around every demo; the report itself is the
+// one thing a demo hands back, so its own heap size is subtracted. A demo
+// that leaks on purpose declares it (DemoReport::leak_on_purpose): those
+// bytes are listed in the check, only the rest counts as a leak.
+//
+// Go has no equivalent number: garbage is "live" until the next GC cycle,
+// so a leak shows up as a heap that keeps growing across runtime.GC() calls.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+use crate::alloc_stats;
+use crate::registry::{Demo, Difficulty, Example, Tag};
+use crate::report::{DemoReport, format_bytes};
+
+/// What `run --leak-check` does when a demo leaves memory behind
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LeakPolicy {
+    /// Add a ⚠️ note to the demo's report
+    Warn,
+    /// Add a ❌ note and remember the demo, so the run can exit non-zero
+    Fail,
+}
+
+const OFF: u8 = 0;
+
+static POLICY: AtomicU8 = AtomicU8::new(OFF);
+static LEAKY_DEMOS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+/// Check every `DemoReport::measured` run from now on
+pub fn enable(policy: LeakPolicy) {
+    POLICY.store(policy as u8 + 1, Ordering::Relaxed);
+}
+
+/// The active policy (None when off, or when the counting allocator isn't built in)
+pub fn policy() -> Option<LeakPolicy> {
+    match POLICY.load(Ordering::Relaxed) {
+        _ if !alloc_stats::enabled() => None,
+        OFF => None,
+        1 => Some(LeakPolicy::Warn),
+        _ => Some(LeakPolicy::Fail),
+    }
+}
+
+/// Titles of the demos that failed the check under `LeakPolicy::Fail`
+pub fn leaky_demos() -> Vec<String> {
+    LEAKY_DEMOS.lock().unwrap().clone()
+}
+
+/// Live-heap snapshot taken before the code under test runs
+#[derive(Debug)]
+pub struct LeakChecker {
+    baseline: usize,
+}
+
+impl LeakChecker {
+    pub fn start() -> Self {
+        LeakChecker { baseline: alloc_stats::live_bytes() }
+    }
+
+    /// Bytes still live since `start`, not counting `kept` bytes the caller
+    /// holds on purpose (None without the counting allocator)
+    pub fn leaked(&self, kept: usize) -> Option<usize> {
+        alloc_stats::enabled().then(|| alloc_stats::live_bytes().saturating_sub(self.baseline + kept))
+    }
+
+    /// Run `f` and report what it left live (its return value excluded when
+    /// `kept` says how big it is)
+    pub fn check<R>(f: impl FnOnce() -> R, kept: impl FnOnce(&R) -> usize) -> (R, Option<usize>) {
+        let checker = LeakChecker::start();
+        let result = f();
+        let leaked = checker.leaked(kept(&result));
+        (result, leaked)
+    }
+}
+
+// Called by DemoReport::measured with the report the demo returned
+pub(crate) fn review(report: &mut DemoReport, leaked: usize) {
+    let Some(policy) = policy() else { return };
+    report.leaked_bytes = Some(leaked);
+    if leaked == 0 {
+        return;
+    }
+    report.step("Leak check");
+    let on_purpose: usize = report.leaked_on_purpose.iter().map(|(_, bytes)| bytes).sum();
+    for (what, bytes) in report.leaked_on_purpose.clone() {
+        report.text(format!("{} live on purpose: {}", format_bytes(bytes), what));
+    }
+    let unexpected = leaked.saturating_sub(on_purpose);
+    if unexpected == 0 {
+        report.ok("Nothing else still live");
+        return;
+    }
+    let message = format!("{} still live after the demo returned", format_bytes(unexpected));
+    match policy {
+        LeakPolicy::Warn => report.warn(message),
+        LeakPolicy::Fail => {
+            LEAKY_DEMOS.lock().unwrap().push(report.title.clone());
+            report.fail(message)
+        }
+    };
+    report.text("Not always a bug: lazily built statics and std's per-thread caches live until exit");
+}
+
+fn leak_line(report: &mut DemoReport, label: &str, leaked: Option<usize>) {
+    match leaked {
+        Some(0) => report.ok(format!("{}: 0 B leaked", label)),
+        Some(bytes) => report.fail(format!("{}: {} leaked", label, format_bytes(bytes))),
+        None => report.text(format!("{}: build with --features alloc-stats to measure", label)),
+    };
+}
+
+// Scope end frees everything - the checker agrees
+pub fn scope_frees_everything() -> DemoReport {
+    let mut report = DemoReport::new("Leak Check - Scope End Frees Everything");
+
+    let (_, leaked) = LeakChecker::check(
+        || {
+            let names: Vec<String> = (0..100).map(|i| format!("user-{}", i)).collect();
+            let boxed = Box::new([0u8; 4096]);
+            names.len() + boxed.len()
+        },
+        |_| 0,
+    );
+    report.code("let names: Vec<String> = ...; let boxed = Box::new([0u8; 4096]);");
+    leak_line(&mut report, "after the closure returned", leaked);
+
+    report.gap();
+    report.ok("101 Strings, a Vec and a Box - all dropped at the closing brace");
+    report.text("This is the check `run --leak-check` applies to every demo");
+    report
+}
+
+// mem::forget skips Drop: the checker catches it
+pub fn forget_leaks() -> DemoReport {
+    let mut report = DemoReport::new("mem::forget - Safe, and a Leak");
+
+    let mut parts = (0, 0, 0);
+    let (_, leaked) = LeakChecker::check(
+        || {
+            let mut buffer = vec![7u8; 1024];
+            parts = (buffer.as_mut_ptr() as usize, buffer.len(), buffer.capacity());
+            std::mem::forget(buffer);
+        },
+        |_| 0,
+    );
+    report.code("let buffer = vec![7u8; 1024];");
+    report.code("std::mem::forget(buffer);  // no unsafe needed");
+    leak_line(&mut report, "after forget", leaked);
+
+    // Give the memory back so this demo itself passes --leak-check
+    let (ptr, len, capacity) = parts;
+    // SAFETY: the parts of a forgotten Vec<u8>, rebuilt exactly once
+    drop(unsafe { Vec::from_raw_parts(ptr as *mut u8, len, capacity) });
+
+    report.gap();
+    report.warn("Leaking is memory-safe in Rust: forget, Box::leak and Rc cycles need no unsafe");
+    report.ok("What Rust rules out is use-after-free, not leaks");
+    report
+}
+
+// Box::leak on purpose: turns a Box into &'static mut
+pub fn box_leak() -> DemoReport {
+    let mut report = DemoReport::new("Box::leak - A Deliberate 'static");
+
+    let mut leaked_ptr = std::ptr::null_mut();
+    let (_, leaked) = LeakChecker::check(
+        || {
+            let config: &'static mut String = Box::leak(Box::new(String::from("listen=0.0.0.0:8080")));
+            leaked_ptr = config as *mut String;
+        },
+        |_| 0,
+    );
+    report.code("let config: &'static mut String = Box::leak(Box::new(String::from(...)));");
+    leak_line(&mut report, "after Box::leak", leaked);
+    report.text("= the Box (24 B String header) + its text buffer");
+
+    // SAFETY: the pointer came from Box::leak and is not used again
+    drop(unsafe { Box::from_raw(leaked_ptr) });
+    report.code("drop(unsafe { Box::from_raw(ptr) });  // reclaims it");
+
+    report.gap();
+    report.ok("Fine for once-per-process config; a bug inside a loop");
+    report
+}
+
+struct Node {
+    _payload: [u8; 64],
+    next: Option<Rc<RefCell<Node>>>,
+}
+
+// Rc cycles: nobody calls forget, and it still leaks
+pub fn rc_cycle_leak() -> DemoReport {
+    let mut report = DemoReport::new("Rc Cycle - A Leak Without Any Leak Function");
+
+    let mut escape_hatch = None;
+    let (_, leaked) = LeakChecker::check(
+        || {
+            let a = Rc::new(RefCell::new(Node { _payload: [0; 64], next: None }));
+            let b = Rc::new(RefCell::new(Node { _payload: [0; 64], next: Some(Rc::clone(&a)) }));
+            a.borrow_mut().next = Some(Rc::clone(&b));
+            escape_hatch = Some(Rc::downgrade(&a));  // a Weak, so we can clean up below
+        },
+        |_| 0,
+    );
+    report.code("a.next = Some(b.clone()); b.next = Some(a.clone());");
+    leak_line(&mut report, "after a and b went out of scope", leaked);
+
+    // Break the cycle through the Weak, then everything is freed
+    let before = alloc_stats::live_bytes();
+    if let Some(a) = escape_hatch.take().and_then(|weak| weak.upgrade()) {
+        a.borrow_mut().next = None;
+    }
+    if alloc_stats::enabled() {
+        let freed = before.saturating_sub(alloc_stats::live_bytes());
+        report.text(format!("a.next = None through the Weak freed {}", format_bytes(freed)));
+    }
+
+    report.gap();
+    report.ok("Make one direction Weak (see rc-weak) - the checker verifies the fix");
+    report.text("Draw both: cargo run -q -- graph cycle, then graph weak-cycle (DOT output)");
+    report
+}
+
+pub fn leak_check_vs_go() -> DemoReport {
+    let mut report = DemoReport::new("Finding Leaks: Go vs Rust");
+
+    report.step("Rust");
+    report.code("cargo run --features alloc-stats -- --leak-check run ownership");
+    report.code("cargo run --features alloc-stats -- --leak-check=fail  # exit 1 if any demo leaks");
+    report.text("Exact: every byte is freed at a known point, so 'still live' means leaked (or static)");
+
+    report.step("Go");
+    report.code("runtime.GC(); runtime.ReadMemStats(&m)  // before and after, compare HeapAlloc");
+    report.code("go tool pprof -inuse_space heap.out");
+    report.text("Approximate: you force a GC and look for growth; goroutine leaks are the classic case");
+    report.text("go.uber.org/goleak checks for goroutines still running at the end of a test");
+    report
+}
+
+pub const DEMO: Demo = Demo {
+    name: "leak-check",
+    about: "Leak checks: scope end, mem::forget, Box::leak and Rc cycles caught by the counting allocator",
+    difficulty: Difficulty::Intermediate,
+    tags: &[Tag::Allocation],
+    examples: &[
+        Example { name: "scope", run: scope_frees_everything },
+        Example { name: "forget", run: forget_leaks },
+        Example { name: "box-leak", run: box_leak },
+        Example { name: "rc-cycle", run: rc_cycle_leak },
+        Example { name: "vs-go", run: leak_check_vs_go },
+    ],
+};
+
+pub fn demonstrate_leak_check() -> Vec<DemoReport> {
+    DEMO.run()
+}
@@ -1,4 +1,164 @@
+pub mod actor_model;
+#[cfg(feature = "alloc-sites")]
+pub mod alloc_sites;
+pub mod alloc_stats;
+pub mod allocator_backends;
+pub mod arena_allocation;
+#[cfg(feature = "tokio")]
+pub mod async_await;
+pub mod atomics;
 pub mod borrow_checker;
+pub mod browser;
+pub mod box_dyn_error;
+pub mod builder_and_ownership;
+pub mod cancellation;
+pub mod channels;
+pub mod closures_and_moves;
+pub mod compare_run;
 pub mod comparison;
+pub mod condvar;
+pub mod contention_bench;
+pub mod copy_vs_clone;
+pub mod cow;
+#[cfg(feature = "crossbeam")]
+pub mod crossbeam_channels;
+pub mod deadlock;
+pub mod defer_vs_drop;
+pub mod dining_philosophers;
+pub mod doctor;
+pub mod drop_glue_and_needs_drop;
+pub mod drop_order;
+pub mod dst_and_fat_pointers;
+pub mod enum_memory;
+pub mod error_handling;
+pub mod exercises;
+pub mod features;
+#[cfg(feature = "go-ffi")]
+pub mod ffi_with_go;
+pub mod fragmentation;
+pub mod generics_monomorphization;
+pub mod globals;
+pub mod goroutines_vs_threads;
+pub mod graph;
+pub mod interior_mutability_zoo;
+pub mod iterator_adapters;
+pub mod latency;
+pub mod layout;
+pub mod leak_check;
+pub mod lifetimes;
+pub mod match_ownership;
+pub mod mem_swap_take_replace;
+pub mod memory_ordering;
+pub mod memviz;
+pub mod mpsc_pipeline;
+pub mod object_pool;
+pub mod option_vs_nil;
+pub mod ownership;
+#[cfg(feature = "parking_lot")]
+pub mod parking_lot_locks;
+pub mod partial_moves;
+pub mod phantomdata_variance;
+pub mod pin_self_referential;
+pub mod process_stats;
+pub mod quiz;
+pub mod raii_resources;
+pub mod rc_tracker;
 pub mod rc_weak;
+pub mod registry;
+pub mod render;
+pub mod report;
+pub mod retain_split_borrow;
+pub mod rwlock;
+pub mod scoped_threads;
+pub mod send_sync;
+pub mod shadowing_and_rebinding;
+pub mod slices_and_strings;
+pub mod smart_pointers;
+pub mod stack_vs_heap_escape;
+pub mod static_lifetime_and_leak;
+pub mod stepper;
+pub mod stress;
+pub mod string_concat_bench;
+pub mod string_encoding;
+pub mod sync_primitives;
+pub mod telemetry;
+pub mod thread_local_storage;
+pub mod timing;
+pub mod traits_vs_interfaces;
+#[cfg(feature = "tui")]
+pub mod tui;
+pub mod two_owners_compile_fail_gallery;
+pub mod unsafe_rust;
+pub mod vec_growth;
+pub mod vecdeque_and_collections_memory;
+pub mod workload;
+pub mod xlang_bench;
 
+// Library facade - the playground without the CLI, for tools that embed it
+// (a course's grading tool, a notebook, a web front end). Demos are looked
+// up by their registry names: "rc-weak", or "rc-weak::weak" for one example.
+
+use std::fmt;
+use std::sync::LazyLock;
+
+use crate::registry::{Demo, DemoRegistry, Example};
+use crate::report::DemoReport;
+
+static REGISTRY: LazyLock<DemoRegistry> = LazyLock::new(DemoRegistry::builtin);
+
+/// Every built-in demo in menu order: name, about, difficulty, tags, examples
+pub fn demos() -> &'static [&'static Demo] {
+    REGISTRY.demos()
+}
+
+/// Every example of every demo, in menu order
+pub fn run_all() -> Vec<DemoReport> {
+    demos().iter().flat_map(|demo| demo.run()).collect()
+}
+
+/// Run one demo, or one example of it
+///
+/// `path` is `"<demo>"` or `"<demo>::<example>"` with the names `list`
+/// prints; underscores work in place of dashes, so `"rc_weak::arc_mutex"`
+/// and `"rc-weak::arc-mutex"` are the same example.
+pub fn run(path: &str) -> Result<Vec<DemoReport>, UnknownDemo> {
+    match find(path)? {
+        (demo, None) => Ok(demo.run()),
+        (_, Some(example)) => Ok(vec![DemoReport::measured(example.run)]),
+    }
+}
+
+/// Look up `path` (see [`run`]) without running anything
+pub fn find(path: &str) -> Result<(&'static Demo, Option<&'static Example>), UnknownDemo> {
+    let normalized = path.trim().replace('_', "-");
+    let (demo_name, example_name) = match normalized.split_once("::") {
+        Some((demo, example)) => (demo, Some(example)),
+        None => (normalized.as_str(), None),
+    };
+    let demo = REGISTRY.get(demo_name).ok_or_else(|| UnknownDemo::Demo(path.to_string()))?;
+    match example_name {
+        None => Ok((demo, None)),
+        Some(name) => demo
+            .example(name)
+            .map(|example| (demo, Some(example)))
+            .ok_or_else(|| UnknownDemo::Example { demo: demo.name, example: name.to_string() }),
+    }
+}
+
+/// What [`run`] couldn't find
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UnknownDemo {
+    Demo(String),
+    Example { demo: &'static str, example: String },
+}
+
+impl fmt::Display for UnknownDemo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UnknownDemo::Demo(name) => write!(f, "no demo `{}` (see rust_playground::demos())", name),
+            UnknownDemo::Example { demo, example } => write!(f, "demo `{}` has no example `{}`", demo, example),
+        }
+    }
+}
+
+impl std::error::Error for UnknownDemo {}
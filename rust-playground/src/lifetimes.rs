@@ -0,0 +1,190 @@
+// Lifetime annotations - naming how long a borrow is valid
+// 'a is not a runtime thing: it's a compile-time proof that references
+// never outlive their data. Go never needs this: if a pointer might
+// outlive its stack frame, escape analysis silently moves it to the heap.
+
+use crate::registry::{Demo, Difficulty, Example, Tag};
+use crate::report::DemoReport;
+
+// Explicit 'a: the returned reference lives as long as BOTH inputs
+fn longest<'a>(x: &'a str, y: &'a str) -> &'a str {
+    if x.len() >= y.len() { x } else { y }
+}
+
+// Struct holding a reference: it can't outlive the text it points into
+#[derive(Debug)]
+struct Excerpt<'a> {
+    part: &'a str,
+}
+
+impl<'a> Excerpt<'a> {
+    // Elided: &self in, &str out → output borrows from self
+    fn part(&self) -> &str {
+        self.part
+    }
+
+    // Explicit: the returned slice borrows from the ORIGINAL text ('a),
+    // not from the Excerpt itself - so it can outlive the Excerpt
+    fn original(&self) -> &'a str {
+        self.part
+    }
+}
+
+// Elision rule 1: one input reference → output gets its lifetime
+fn first_word(s: &str) -> &str {
+    s.split_whitespace().next().unwrap_or("")
+}
+
+// 'static: lives for the whole program (baked into the binary)
+fn language_name() -> &'static str {
+    "Rust"
+}
+
+// Functions with explicit 'a parameters
+pub fn explicit_lifetimes() -> DemoReport {
+    let mut report = DemoReport::new("Explicit Lifetime Parameters ('a)");
+
+    let string1 = String::from("ownership");
+    let result;
+    {
+        let string2 = String::from("borrow");
+        result = longest(&string1, &string2);
+        report.address("string1", string1.as_str(), &string1);
+        report.address("string2", string2.as_str(), &string2);
+        report.address("longest()", result, result);
+    } // string2 dropped here
+
+    // ❌ Using `result` here would fail to compile:
+    // println!("{}", result);  // Error: `string2` does not live long enough
+    // The compiler can't know WHICH input was returned, so 'a = the shorter one
+    // (tests/compile_fail/longest_outlives_input.rs)
+
+    report.code("fn longest<'a>(x: &'a str, y: &'a str) -> &'a str");
+    report.ok("Returned reference points INTO one of the inputs (no copy)");
+    report.ok("'a = \"valid while both inputs are alive\"");
+
+    report.step("Go");
+    report.code("func longest(x, y *string) *string { ... }");
+    report.text("No annotation needed: GC keeps both strings alive while any pointer exists");
+    report
+}
+
+// Structs that hold references
+pub fn struct_with_reference() -> DemoReport {
+    let mut report = DemoReport::new("Structs Holding References");
+
+    let novel = String::from("Call me Ishmael. Some years ago...");
+    let first_sentence = novel.split('.').next().unwrap();
+
+    let original;
+    {
+        let excerpt = Excerpt { part: first_sentence };
+        report.address("novel buffer", novel.as_str(), format!("{:?}", novel));
+        report.address("excerpt.part", excerpt.part(), format!("{:?}", excerpt));
+        original = excerpt.original();
+    } // excerpt dropped - but `original` borrows from `novel`, not from excerpt
+
+    report.address("original()", original, original);
+    report.ok("Excerpt<'a> points into novel's buffer - zero copies");
+    report.ok("original() returns &'a str, so it outlives the Excerpt");
+    report.fail("drop(novel) while an Excerpt exists → compile error");
+
+    report.step("Go");
+    report.code("type Excerpt struct { Part string }  // string header shares the backing bytes");
+    report.text("Safe only because the GC keeps the backing array alive");
+    report
+}
+
+// Lifetime elision - the rules that let you skip annotations
+pub fn lifetime_elision() -> DemoReport {
+    let mut report = DemoReport::new("Lifetime Elision");
+
+    let sentence = String::from("borrowing is cheap");
+    let word = first_word(&sentence);
+
+    report.address("sentence", sentence.as_str(), &sentence);
+    report.address("first_word()", word, word);
+
+    report.step("What you write vs what the compiler sees");
+    report.code("fn first_word(s: &str) -> &str");
+    report.code("fn first_word<'a>(s: &'a str) -> &'a str");
+    report.code("fn part(&self) -> &str");
+    report.code("fn part<'b>(&'b self) -> &'b str");
+
+    report.step("The three rules");
+    report.text("1. Each input reference gets its own lifetime");
+    report.text("2. Exactly one input lifetime → it's used for every output");
+    report.text("3. A &self / &mut self input → its lifetime is used for outputs");
+    report.ok("Most functions never need explicit 'a");
+    report.warn("Two reference inputs + a reference output → you must annotate");
+    report
+}
+
+// 'static - data that lives for the whole program
+pub fn static_lifetime() -> DemoReport {
+    let mut report = DemoReport::new("'static Lifetime");
+
+    let literal: &'static str = "hello";
+    let name = language_name();
+    let owned = String::from("hello");
+
+    report.address("string literal", literal, literal);
+    report.address("language_name()", name, name);
+    report.address("String (heap)", owned.as_str(), &owned);
+
+    report.ok("Literals live in the binary's read-only data, not stack or heap");
+    report.ok("&'static str can be returned from anywhere, stored anywhere");
+    report.warn("T: 'static means \"owns its data\" - not \"lives forever\"");
+
+    report.step("Go");
+    report.code("func languageName() string { return \"Go\" }");
+    report.text("Every string is GC-managed; there's no distinction to make");
+    report
+}
+
+// Where Go's escape analysis would quietly heap-allocate
+pub fn lifetimes_vs_escape_analysis() -> DemoReport {
+    let mut report = DemoReport::new("Lifetimes vs Go Escape Analysis");
+
+    report.step("Go (compiles, value escapes to heap)");
+    report.code("func newUser() *User {");
+    report.code("    u := User{Name: \"Alice\"}");
+    report.code("    return &u  // moved to heap: u");
+    report.code("}");
+    report.ok("Always compiles");
+    report.warn("Hidden heap allocation + GC work (see `make escape`)");
+
+    report.step("Rust (rejected)");
+    report.code("fn new_user<'a>() -> &'a User {");
+    report.code("    let u = User { name: \"Alice\".into() };");
+    report.code("    &u  // ❌ returns a reference to data owned by the current function");
+    report.code("}");
+
+    report.step("Rust (pick explicitly)");
+    report.code("fn new_user() -> User       // Move it out (often no copy: RVO)");
+    report.code("fn new_user() -> Box<User>  // Heap, visible in the signature");
+    report.ok("Where data lives is part of the function's type");
+
+    report.step("Key Insight");
+    report.text("Go: the compiler decides stack vs heap for you");
+    report.text("Rust: lifetimes prove borrows are valid - you decide where data lives");
+    report
+}
+
+pub const DEMO: Demo = Demo {
+    name: "lifetimes",
+    about: "Lifetime annotations, elision and 'static vs Go escape analysis",
+    difficulty: Difficulty::Intermediate,
+    tags: &[Tag::Borrowing],
+    examples: &[
+        Example { name: "explicit", run: explicit_lifetimes },
+        Example { name: "struct-ref", run: struct_with_reference },
+        Example { name: "elision", run: lifetime_elision },
+        Example { name: "static", run: static_lifetime },
+        Example { name: "vs-escape", run: lifetimes_vs_escape_analysis },
+    ],
+};
+
+pub fn demonstrate_lifetimes() -> Vec<DemoReport> {
+    DEMO.run()
+}
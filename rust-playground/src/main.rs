@@ -1,144 +1,600 @@
-use rust_playground::{borrow_checker, comparison, rc_weak};
+use std::io::{BufReader, IsTerminal, Stdin, Stdout};
+use std::sync::{LazyLock, Mutex, OnceLock};
 
-#[allow(dead_code)]
-#[derive(Debug)]
-struct User {
-    name: String,
-    age: u32,
+use clap::builder::{PossibleValuesParser, TypedValueParser};
+use clap::{Arg, ArgAction, ArgMatches, FromArgMatches, Parser, Subcommand, ValueEnum};
+use rust_playground::registry::{Demo, DemoRegistry, Tag};
+use rust_playground::render::{Colored, Csv, Html, Markdown, Plain, Renderer, Verbose, Verbosity};
+use rust_playground::report::{DemoReport, NoteKind};
+use rust_playground::stepper::{StepMode, Stepper};
+use rust_playground::timing::Timings;
+use rust_playground::exercises::{self, Outcome, Progress};
+use rust_playground::compare_run::{self, Side};
+use rust_playground::{dining_philosophers, features, goroutines_vs_threads, graph, leak_check, memviz, quiz, stress, workload};
+#[cfg(feature = "alloc-sites")]
+use rust_playground::alloc_sites;
+#[cfg(feature = "alloc-stats")]
+use rust_playground::leak_check::LeakPolicy;
+#[cfg(feature = "tracing")]
+use rust_playground::telemetry::{self, TraceFormat};
+#[cfg(feature = "tui")]
+use rust_playground::tui;
+
+// Command-line interface: pick the demo you're teaching instead of running everything
+#[derive(Parser)]
+#[command(name = "rust-playground", version)]
+#[command(about = "Rust ownership & borrowing playground (compared with Go's GC)")]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+    /// Output format (default: color on a terminal, plain otherwise)
+    #[arg(long, global = true, value_enum)]
+    format: Option<Format>,
+    /// More detail: -v adds raw addresses, units and allocator counters; -vv a little more
+    #[arg(short, long, global = true, action = ArgAction::Count, conflicts_with = "quiet")]
+    verbose: u8,
+    /// Only the measured numbers (counts, timings, numeric tables) - no tutorial text
+    #[arg(short, long, global = true)]
+    quiet: bool,
+    /// Only demos with this tag, for `run`, `list` and `tui` (repeatable)
+    #[arg(long = "tag", global = true, value_name = "TAG", value_parser = tag_parser())]
+    tags: Vec<Tag>,
+    /// Ask multiple-choice questions after each demo and print a score
+    #[arg(long, global = true)]
+    quiz: bool,
+    /// Teach mode: page through each demo's output, Enter before each step (--step=preview: show its code first)
+    #[arg(long, global = true, value_enum, value_name = "MODE", conflicts_with = "quiz")]
+    #[arg(num_args = 0..=1, require_equals = true, default_missing_value = "pause")]
+    step: Option<Step>,
+    /// Seed for the generated workloads (same seed = same allocations, see `run workload`)
+    #[arg(long, global = true, value_name = "SEED", default_value_t = workload::DEFAULT_SEED)]
+    seed: u64,
+    /// Objects per generated workload, instead of each demo's default
+    #[arg(long, global = true, value_name = "N", value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..))]
+    object_count: Option<usize>,
+    /// Print the top allocation sites after each demo (like a Go heap profile)
+    #[cfg(feature = "alloc-sites")]
+    #[arg(long, global = true)]
+    alloc_sites: bool,
+    /// With --alloc-sites: one stack trace per this many bytes (1 = every allocation;
+    /// use ~65536 for allocation-heavy demos like latency)
+    #[cfg(feature = "alloc-sites")]
+    #[arg(long, global = true, value_name = "BYTES", default_value_t = alloc_sites::DEFAULT_SAMPLE_RATE)]
+    sample_rate: usize,
+    /// Flag memory still live after each demo returns (--leak-check=fail: exit 1)
+    #[cfg(feature = "alloc-stats")]
+    #[arg(long, global = true, value_enum, value_name = "POLICY")]
+    #[arg(num_args = 0..=1, require_equals = true, default_missing_value = "warn")]
+    leak_check: Option<LeakCheck>,
+    /// Print a span per demo and example on stderr (JSON lines; --trace=text for people)
+    #[cfg(feature = "tracing")]
+    #[arg(long, global = true, value_enum, value_name = "FORMAT")]
+    #[arg(num_args = 0..=1, require_equals = true, default_missing_value = "json")]
+    trace: Option<Trace>,
+    /// Draw the addresses each demo printed as a stack/heap picture
+    #[arg(long, global = true)]
+    memviz: bool,
+    /// For compare-run: print one stats line per example instead of the report
+    #[arg(long, global = true, hide = true)]
+    emit_stats: bool,
 }
 
-fn main() {
-    println!("=== Rust Ownership & Borrowing Playground ===\n");
+#[cfg(feature = "alloc-stats")]
+#[derive(Clone, Copy, ValueEnum)]
+enum LeakCheck {
+    /// Add a warning to the leaking demo's output
+    Warn,
+    /// Also exit with status 1 at the end
+    Fail,
+}
 
-    // Example 1: Ownership (Single Owner)
-    println!("1. Ownership - Single Owner");
-    ownership_example();
+#[cfg(feature = "tracing")]
+#[derive(Clone, Copy, ValueEnum)]
+enum Trace {
+    /// One JSON object per line, for dashboards
+    Json,
+    /// Readable lines
+    Text,
+}
 
-    // Example 2: Move Semantics (Transfer Ownership)
-    println!("\n2. Move Semantics");
-    move_example();
+#[derive(Clone, Copy, ValueEnum)]
+enum Step {
+    /// Wait for Enter before each step
+    Pause,
+    /// Print the step's code, then wait before its output
+    Preview,
+}
 
-    // Example 3: Borrowing (References)
-    println!("\n3. Borrowing - Immutable References");
-    borrowing_example();
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    /// Plain text
+    Plain,
+    /// ANSI-colored text
+    Color,
+    /// Markdown, for pasting into course notes
+    Markdown,
+    /// One self-contained HTML page, to hand out after a run
+    Html,
+    /// CSV, one row per example: name, duration_ns, allocs, bytes, peak_rss
+    Csv,
+}
 
-    // Example 4: Mutable Borrowing
-    println!("\n4. Mutable Borrowing");
-    mutable_borrowing_example();
+impl Format {
+    // Color only when a human is looking (and hasn't asked for NO_COLOR)
+    fn detect() -> Format {
+        let no_color = std::env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty());
+        if std::io::stdout().is_terminal() && !no_color {
+            Format::Color
+        } else {
+            Format::Plain
+        }
+    }
 
-    // Example 5: Lifetimes & Stack Cleanup
-    println!("\n5. Deterministic Cleanup (No GC!)");
-    deterministic_cleanup();
+    fn renderer(self) -> Box<dyn Renderer> {
+        match self {
+            Format::Plain => Box::new(Plain),
+            Format::Color => Box::new(Colored),
+            Format::Markdown => Box::new(Markdown),
+            Format::Html => Box::new(Html),
+            Format::Csv => Box::new(Csv),
+        }
+    }
+}
 
-    // Example 6: Borrow Checker Rules
-    borrow_checker::demonstrate_borrow_checker();
+#[derive(Subcommand)]
+enum Command {
+    /// List every demo module and its examples
+    List,
+    /// Run one demo module (runs everything when no module is given)
+    Run {
+        #[command(subcommand)]
+        demo: Option<DemoChoice>,
+    },
+    /// Step through demos one at a time in an interactive terminal UI
+    #[cfg(feature = "tui")]
+    Tui,
+    /// Fix small broken programs in exercises/, one at a time
+    Exercise {
+        #[command(subcommand)]
+        action: ExerciseAction,
+    },
+    /// Print an Rc/Weak ownership graph as Graphviz DOT
+    Graph {
+        /// Which graph to draw
+        #[arg(value_parser = PossibleValuesParser::new(graph::GRAPHS.iter().map(|graph| graph.name)))]
+        name: String,
+        /// Write the DOT here instead of stdout
+        #[arg(short, long, value_name = "FILE")]
+        output: Option<std::path::PathBuf>,
+    },
+    /// Which optional features this binary has, a self-test of each, and how to add the rest
+    Features,
+    /// Run one demo under two feature sets and diff its timings and allocations
+    CompareRun {
+        /// Demo to run (as for `run`; both builds need to have it)
+        demo: String,
+        /// Features for the first build, e.g. "" (defaults only)
+        #[arg(long, value_name = "FEATURES", default_value = "")]
+        baseline: String,
+        /// Features for the second build, e.g. parking_lot
+        #[arg(long, value_name = "FEATURES")]
+        candidate: String,
+        /// Only these examples (repeatable)
+        #[arg(short = 'e', long = "example", value_name = "EXAMPLE")]
+        examples: Vec<String>,
+        /// Build both sides with --release (recommended for timings)
+        #[arg(long)]
+        release: bool,
+        /// Runs per side; each example keeps its fastest
+        #[arg(long, value_name = "N", default_value_t = 3, value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..))]
+        runs: usize,
+    },
+}
+
+#[derive(Subcommand)]
+enum ExerciseAction {
+    /// Compile the current exercise; move on once it's fixed
+    Next,
+    /// Every exercise and whether it's done
+    List,
+    /// A hint for the current exercise
+    Hint,
+    /// Start over (forgets progress, keeps your edits)
+    Reset,
+}
 
-    // Example 7: Go vs Rust Comparisons
-    comparison::demonstrate_comparisons();
+static REGISTRY: LazyLock<DemoRegistry> = LazyLock::new(DemoRegistry::builtin);
 
-    // Example 8: Rc and Weak - Multiple Ownership
-    rc_weak::demonstrate_rc();
+// Set once from --step; print_reports pauses between steps when it is. One for
+// the whole run, so `q` in one demo keeps running through the rest
+static STEPPER: OnceLock<Mutex<Stepper<BufReader<Stdin>, Stdout>>> = OnceLock::new();
+
+fn tag_parser() -> impl TypedValueParser<Value = Tag> {
+    PossibleValuesParser::new(Tag::ALL.map(Tag::name)).map(|name| name.parse::<Tag>().expect("listed above"))
 }
 
-// Example 1: Ownership - each value has ONE owner
-fn ownership_example() {
-    let user = User {
-        name: String::from("Alice"),
-        age: 30,
-    };
-    
-    println!("  Owner: {:p} -> {:?}", &user, user);
-    println!("  ✓ Single owner: 'user' owns the data");
-    
-    // user goes out of scope here - automatically cleaned up!
-}
-
-// Example 2: Move semantics - ownership transfers
-fn move_example() {
-    let user1 = User {
-        name: String::from("Bob"),
-        age: 25,
-    };
-    
-    println!("  user1 owns:     {:p} -> {:?}", &user1, user1);
-    
-    let user2 = user1;  // Ownership MOVES to user2
-    
-    println!("  user2 owns:     {:p} -> {:?}", &user2, user2);
-    println!("  ✗ user1 is no longer valid (moved!)");
-    
-    // Uncommenting this would cause a compile error:
-    // println!("{:?}", user1);  // ❌ Error: value borrowed after move
-}
-
-// Example 3: Borrowing - multiple immutable references allowed
-fn borrowing_example() {
-    let user = User {
-        name: String::from("Charlie"),
-        age: 35,
+// `run <demo> [-e <example>]...`: one subcommand per registered demo
+struct DemoChoice {
+    demo: &'static Demo,
+    // Empty = every example
+    examples: Vec<String>,
+    count: Option<usize>,
+    threads: Option<usize>,
+    iters: Option<usize>,
+    deadlock: bool,
+}
+
+impl DemoChoice {
+    fn run(&self) -> Vec<DemoReport> {
+        if let Some(count) = self.count {
+            goroutines_vs_threads::set_count(count);
+        }
+        if let Some(threads) = self.threads {
+            stress::set_threads(threads);
+        }
+        if let Some(iters) = self.iters {
+            stress::set_iters(iters);
+        }
+        if self.deadlock {
+            dining_philosophers::set_deadlock(true);
+        }
+        if self.examples.is_empty() {
+            return self.demo.run();
+        }
+        self.demo.run_examples(&self.examples)
+    }
+
+    // What run() runs, in order
+    fn example_names(&self) -> Vec<&str> {
+        if self.examples.is_empty() {
+            return self.demo.examples.iter().map(|example| example.name).collect();
+        }
+        self.examples.iter().map(String::as_str).collect()
+    }
+}
+
+impl FromArgMatches for DemoChoice {
+    fn from_arg_matches(matches: &ArgMatches) -> Result<Self, clap::Error> {
+        let (name, args) = matches
+            .subcommand()
+            .ok_or_else(|| clap::Error::new(clap::error::ErrorKind::MissingSubcommand))?;
+        let demo = REGISTRY
+            .get(name)
+            .ok_or_else(|| clap::Error::new(clap::error::ErrorKind::InvalidSubcommand))?;
+        let examples = args.get_many::<String>("examples").into_iter().flatten().cloned().collect();
+        let count = args.try_get_one::<usize>("count").ok().flatten().copied();
+        let threads = args.try_get_one::<usize>("threads").ok().flatten().copied();
+        let iters = args.try_get_one::<usize>("iters").ok().flatten().copied();
+        let deadlock = args.try_get_one::<bool>("deadlock").ok().flatten().copied().unwrap_or(false);
+        Ok(DemoChoice { demo, examples, count, threads, iters, deadlock })
+    }
+
+    fn update_from_arg_matches(&mut self, matches: &ArgMatches) -> Result<(), clap::Error> {
+        *self = DemoChoice::from_arg_matches(matches)?;
+        Ok(())
+    }
+}
+
+impl Subcommand for DemoChoice {
+    fn augment_subcommands(cmd: clap::Command) -> clap::Command {
+        let cmd = REGISTRY.demos().iter().fold(cmd, |cmd, demo| {
+            let examples = Arg::new("examples")
+                .short('e')
+                .long("example")
+                .value_name("EXAMPLE")
+                .help("Only run these examples (repeatable)")
+                .action(ArgAction::Append)
+                .value_parser(PossibleValuesParser::new(demo.examples.iter().map(|example| example.name)));
+            cmd.subcommand(clap::Command::new(demo.name).about(demo.about).arg(examples))
+        });
+        // The demos with knobs of their own
+        let cmd = cmd.mut_subcommand(stress::DEMO.name, |demo| {
+            demo.arg(
+                Arg::new("threads")
+                    .long("threads")
+                    .value_name("N")
+                    .help(format!("Threads per workload [default: {}]", stress::DEFAULT_THREADS))
+                    .value_parser(clap::builder::RangedU64ValueParser::<usize>::new().range(1..)),
+            )
+            .arg(
+                Arg::new("iters")
+                    .long("iters")
+                    .value_name("M")
+                    .help(format!("Updates per thread [default: {}]", stress::DEFAULT_ITERS))
+                    .value_parser(clap::builder::RangedU64ValueParser::<usize>::new().range(1..)),
+            )
+        });
+        let cmd = cmd.mut_subcommand(dining_philosophers::DEMO.name, |demo| {
+            demo.arg(
+                Arg::new("deadlock")
+                    .long("deadlock")
+                    .help("Let the deadlock example really lock left-then-right (a watchdog ends it)")
+                    .action(ArgAction::SetTrue),
+            )
+        });
+        cmd.mut_subcommand(goroutines_vs_threads::DEMO.name, |demo| {
+            demo.arg(
+                Arg::new("count")
+                    .short('n')
+                    .long("count")
+                    .value_name("COUNT")
+                    .help(format!("How many threads/tasks to spawn [default: {}]", goroutines_vs_threads::DEFAULT_COUNT))
+                    .value_parser(clap::builder::RangedU64ValueParser::<usize>::new().range(1..)),
+            )
+        })
+    }
+
+    fn augment_subcommands_for_update(cmd: clap::Command) -> clap::Command {
+        DemoChoice::augment_subcommands(cmd)
+    }
+
+    fn has_subcommand(name: &str) -> bool {
+        REGISTRY.get(name).is_some()
+    }
+}
+
+fn main() {
+    let cli = Cli::parse();
+    let format = cli.format.unwrap_or_else(Format::detect).renderer();
+    let renderer = Verbose { renderer: format.as_ref(), verbosity: Verbosity::from_flags(cli.quiet, cli.verbose) };
+    let renderer = &renderer;
+
+    workload::set_seed(cli.seed);
+    if let Some(count) = cli.object_count {
+        workload::set_object_count(count);
+    }
+    #[cfg(feature = "alloc-sites")]
+    if cli.alloc_sites {
+        alloc_sites::enable(cli.sample_rate);
+    }
+    if cli.memviz {
+        memviz::enable();
+    }
+    #[cfg(feature = "alloc-stats")]
+    if let Some(check) = cli.leak_check {
+        leak_check::enable(match check {
+            LeakCheck::Warn => LeakPolicy::Warn,
+            LeakCheck::Fail => LeakPolicy::Fail,
+        });
+    }
+    #[cfg(feature = "tracing")]
+    if let Some(trace) = cli.trace {
+        telemetry::init(match trace {
+            Trace::Json => TraceFormat::Json,
+            Trace::Text => TraceFormat::Text,
+        });
+    }
+
+    if let Some(step) = cli.step {
+        if !matches!(cli.format.unwrap_or_else(Format::detect), Format::Plain | Format::Color) {
+            eprintln!("--step needs terminal output (--format plain or color)");
+            std::process::exit(2);
+        }
+        let mode = match step {
+            Step::Pause => StepMode::Pause,
+            Step::Preview => StepMode::Preview,
+        };
+        STEPPER.get_or_init(|| Mutex::new(Stepper::new(BufReader::new(std::io::stdin()), std::io::stdout(), mode)));
+    }
+
+    if cli.emit_stats {
+        let Some(Command::Run { demo: Some(choice) }) = cli.command else {
+            eprintln!("--emit-stats works with `run <demo>`");
+            std::process::exit(2);
+        };
+        print!("{}", compare_run::emit(&choice.example_names(), &choice.run()));
+        return;
+    }
+
+    if cli.quiz {
+        run_with_quiz(renderer, cli.command, &cli.tags);
+    } else {
+        run_command(renderer, cli.command, &cli.tags);
+    }
+
+    let leaky = leak_check::leaky_demos();
+    if !leaky.is_empty() {
+        eprintln!("--leak-check=fail: {} demo(s) left memory live:", leaky.len());
+        for title in leaky {
+            eprintln!("  {}", title);
+        }
+        std::process::exit(1);
+    }
+}
+
+fn run_command(renderer: &dyn Renderer, command: Option<Command>, tags: &[Tag]) {
+    match command {
+        Some(Command::List) => list_demos(tags),
+        Some(Command::Run { demo: Some(choice) }) => document(renderer, || print_reports(renderer, choice.run())),
+        Some(Command::Run { demo: None }) | None => document(renderer, || run_all(renderer, tags)),
+        #[cfg(feature = "tui")]
+        Some(Command::Tui) => {
+            if let Err(err) = run_tui(tags) {
+                eprintln!("tui: {}", err);
+                std::process::exit(1);
+            }
+        }
+        Some(Command::Exercise { action }) => match run_exercise(action) {
+            Ok(true) => {}
+            Ok(false) => std::process::exit(1),
+            Err(err) => {
+                eprintln!("exercise: {}", err);
+                std::process::exit(2);
+            }
+        },
+        Some(Command::Graph { name, output }) => {
+            let dot = graph::build(&name).expect("name checked by clap").to_dot();
+            match output {
+                Some(path) => {
+                    if let Err(err) = std::fs::write(&path, dot) {
+                        eprintln!("graph: {}: {}", path.display(), err);
+                        std::process::exit(1);
+                    }
+                }
+                None => print!("{}", dot),
+            }
+        }
+        Some(Command::Features) => {
+            let report = features::matrix();
+            let broken = report.notes(NoteKind::Fail).count() > 0;
+            document(renderer, || print_reports(renderer, vec![report]));
+            if broken {
+                std::process::exit(1);
+            }
+        }
+        Some(Command::CompareRun { demo, baseline, candidate, examples, release, runs }) => {
+            let manifest = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("Cargo.toml");
+            let baseline = Side { features: baseline, release };
+            let candidate = Side { features: candidate, release };
+            let mut results = Vec::new();
+            for side in [&baseline, &candidate] {
+                eprintln!("compare-run: building and running {} with --features {} ({} runs)...", demo, side.label(), runs);
+                match side.run(&manifest, &demo, &examples, runs) {
+                    Ok(stats) => results.push(stats),
+                    Err(err) => {
+                        eprintln!("compare-run: {}", err);
+                        std::process::exit(1);
+                    }
+                }
+            }
+            let report = compare_run::diff(&demo, (&baseline, &results[0]), (&candidate, &results[1]));
+            document(renderer, || print_reports(renderer, vec![report]));
+        }
+    }
+}
+
+// Ok(false) = the current exercise isn't fixed yet (exit status 1, like a failing test)
+fn run_exercise(action: ExerciseAction) -> std::io::Result<bool> {
+    let mut progress = Progress::load()?;
+    let total = exercises::EXERCISES.len();
+    match action {
+        ExerciseAction::Next => {
+            while let Some(exercise) = progress.current() {
+                let position = progress.finished() + 1;
+                let outcome = exercises::check(&exercise.path())?;
+                if outcome == Outcome::Done {
+                    progress.complete(exercise)?;
+                    println!("✓ {} done ({}/{})", exercise.name, position, total);
+                    continue;
+                }
+                println!("Exercise {}/{}: {}\n", position, total, exercise.path().display());
+                match outcome {
+                    Outcome::CompileError(errors) => print!("{}", errors),
+                    Outcome::Failed(output) => println!("It compiles, but main failed:\n{}", output),
+                    Outcome::MarkerLeft => println!("It compiles and passes! Delete the `{}` line to move on.", exercises::NOT_DONE_MARKER),
+                    Outcome::Done => unreachable!("handled above"),
+                }
+                println!("\nStuck? cargo run -- exercise hint   ·   background: cargo run -- run {}", exercise.demo);
+                return Ok(false);
+            }
+            println!("All {} exercises done. Solutions to compare with: {}", total, exercises::dir().join("solutions").display());
+        }
+        ExerciseAction::List => {
+            for exercise in exercises::EXERCISES {
+                let mark = if progress.is_done(exercise) { "✓" } else { " " };
+                println!("  [{}] {:<24} run {}", mark, exercise.name, exercise.demo);
+            }
+            println!("\n{}/{} done", progress.finished(), total);
+        }
+        ExerciseAction::Hint => match progress.current() {
+            Some(exercise) => println!("{}: {}", exercise.name, exercise.hint),
+            None => println!("All {} exercises done - no hints needed", total),
+        },
+        ExerciseAction::Reset => {
+            progress.reset()?;
+            println!("Progress cleared; `exercise next` starts from {}", exercises::EXERCISES[0].name);
+        }
+    }
+    Ok(true)
+}
+
+// --quiz: questions after the chosen demo, or after every demo in turn
+fn run_with_quiz(renderer: &dyn Renderer, command: Option<Command>, tags: &[Tag]) {
+    let choice = match command {
+        Some(Command::Run { demo }) => demo,
+        None => None,
+        _ => {
+            eprintln!("--quiz works with `run` (one demo or all of them)");
+            std::process::exit(2);
+        }
     };
-    
-    println!("  Owner:  {:p} -> {:?}", &user, user);
-    
-    // Multiple immutable borrows are OK!
-    let ref1 = &user;
-    let ref2 = &user;
-    let ref3 = &user;
-    
-    println!("  Ref1:   {:p} -> {:?}", ref1, ref1);
-    println!("  Ref2:   {:p} -> {:?}", ref2, ref2);
-    println!("  Ref3:   {:p} -> {:?}", ref3, ref3);
-    println!("  ✓ Multiple immutable borrows allowed");
-    println!("  ✓ All point to same memory (like Go)");
-    println!("  ✓ But owner maintains control!");
-}
-
-// Example 4: Mutable borrowing - only ONE mutable reference allowed
-fn mutable_borrowing_example() {
-    let mut user = User {
-        name: String::from("Diana"),
-        age: 28,
+    let mut quiz = quiz::Quiz::new(std::io::stdin().lock(), std::io::stdout());
+    let mut result = Ok(());
+    // A demo's registry name is also its key in data/quiz.txt
+    document(renderer, || {
+        result = match choice {
+            Some(choice) => {
+                print_reports(renderer, choice.run());
+                quiz.ask_demo(choice.demo.name)
+            }
+            None => REGISTRY.tagged(tags).try_for_each(|demo| {
+                print_reports(renderer, demo.run());
+                quiz.ask_demo(demo.name)
+            }),
+        }
+    });
+    if let Err(err) = result.and_then(|()| quiz.summary()) {
+        eprintln!("quiz: {}", err);
+        std::process::exit(1);
+    }
+}
+
+// Wrap a run in the renderer's prologue/epilogue (HTML's page, CSV's header row)
+fn document(renderer: &dyn Renderer, body: impl FnOnce()) {
+    let mut text = String::new();
+    renderer.begin(&mut text).expect("writing to a String never fails");
+    print!("{}", text);
+    body();
+    text.clear();
+    renderer.end(&mut text).expect("writing to a String never fails");
+    print!("{}", text);
+}
+
+// The one place demo output gets printed
+fn print_reports(renderer: &dyn Renderer, reports: Vec<DemoReport>) {
+    let Some(stepper) = STEPPER.get() else {
+        print!("{}", renderer.render_all(&reports));
+        return;
     };
-    
-    println!("  Original: {:?}", user);
-    
-    // Only ONE mutable borrow at a time!
-    let user_ref = &mut user;
-    user_ref.age = 29;
-    
-    println!("  After modification: {:?}", user_ref);
-    println!("  ✓ Only ONE mutable borrow at a time");
-    println!("  ✓ Prevents data races at compile-time!");
-    
-    // Uncommenting this would cause a compile error:
-    // let ref2 = &mut user;  // ❌ Error: cannot borrow as mutable more than once
-}
-
-// Example 5: Deterministic cleanup - no GC needed!
-fn deterministic_cleanup() {
-    println!("  Creating users...");
-    
-    {
-        let user1 = User {
-            name: String::from("Eve"),
-            age: 40,
-        };
-        println!("    user1 created: {:?}", user1);
-        
-        {
-            let user2 = User {
-                name: String::from("Frank"),
-                age: 45,
-            };
-            println!("    user2 created: {:?}", user2);
-            
-            println!("    user2 scope ends → cleaned up immediately");
-        } // user2 dropped here - deterministic!
-        
-        println!("    user1 scope ends → cleaned up immediately");
-    } // user1 dropped here - deterministic!
-    
-    println!("  ✓ No garbage collector needed");
-    println!("  ✓ Memory freed at end of scope (RAII)");
-    println!("  ✓ Zero runtime overhead!");
+    let mut stepper = stepper.lock().expect("only the main thread steps");
+    if let Err(err) = reports.iter().try_for_each(|report| stepper.show(renderer, report)) {
+        eprintln!("step: {}", err);
+        std::process::exit(1);
+    }
+}
+
+fn run_all(renderer: &dyn Renderer, tags: &[Tag]) {
+    let mut banner = String::new();
+    renderer
+        .banner("Rust Ownership & Borrowing Playground", &mut banner)
+        .expect("writing to a String never fails");
+    print!("{}", banner);
+
+    let mut timings = Timings::new();
+    for demo in REGISTRY.tagged(tags) {
+        print_reports(renderer, timings.run(demo));
+    }
+    print_reports(renderer, vec![timings.summary()]);
+}
+
+// Same menu as `list`, each entry running the demo's full example list
+#[cfg(feature = "tui")]
+fn run_tui(tags: &[Tag]) -> std::io::Result<()> {
+    let menu = REGISTRY
+        .tagged(tags)
+        .map(|demo| tui::MenuItem { name: demo.name.to_string(), about: demo.about.to_string() })
+        .collect();
+
+    tui::run(menu, |name| REGISTRY.get(name).map(Demo::run).unwrap_or_default())
+}
+
+fn list_demos(tags: &[Tag]) {
+    println!("Available demos (rust-playground run <DEMO> [-e <EXAMPLE>]...):\n");
+    for demo in REGISTRY.tagged(tags) {
+        println!("  {:<16} {}", demo.name, demo.about);
+        let tag_names: Vec<&str> = demo.tags.iter().map(|tag| tag.name()).collect();
+        println!("  {:<16} {} · {}", "", demo.difficulty, tag_names.join(", "));
+        let examples: Vec<&str> = demo.examples.iter().map(|example| example.name).collect();
+        println!("  {:<16} examples: {}", "", examples.join(", "));
+    }
 }
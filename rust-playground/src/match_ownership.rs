@@ -0,0 +1,260 @@
+// match and ownership - what a pattern does to the value it matches
+// Go:   `switch v := x.(type)` and `switch s.Kind` read a copy: a case can't
+//       take anything away from x, so there's nothing to track. Cases don't
+//       have to cover every value, and fallthrough is opt-in.
+// Rust: `match` matches a place. A binding by value MOVES that part out of it
+//       (or copies, for Copy types); `ref`/`ref mut` or matching through a
+//       reference borrows it instead; `_` doesn't bind at all. Matching on
+//       `&Option<T>` binds `&T` by default ("match ergonomics"), and moving
+//       out from behind a reference is E0507. Every match is exhaustive.
+
+use std::cell::RefCell;
+
+use crate::registry::{Demo, Difficulty, Example, Tag};
+use crate::report::{DemoReport, SideBySideRow};
+
+#[derive(Debug, Clone)]
+pub enum Message {
+    Text(String),
+    Ping(u32),
+    Quit,
+}
+
+impl Message {
+    /// One-line summary that only borrows the payload
+    pub fn describe(&self) -> String {
+        match self {
+            Message::Text(text) => format!("text of {} bytes", text.len()),  // text: &String
+            Message::Ping(n) => format!("ping #{}", n),                       // n: &u32
+            Message::Quit => "quit".to_string(),
+        }
+    }
+}
+
+/// A linked stack for the while-let walk
+pub struct Frame {
+    pub name: String,
+    pub caller: Option<Box<Frame>>,
+}
+
+// By-value bindings move, Copy bindings copy, `_` leaves the value alone
+pub fn moves() -> DemoReport {
+    let mut report = DemoReport::new("match Moves What It Binds");
+
+    let message = Message::Text(String::from("hello, gopher"));
+    if let Message::Text(text) = &message {
+        report.address("message's String buffer", text.as_str(), format!("{:?}", text));
+    }
+    report.code("match message { Message::Text(text) => ..., _ => ... }  // text: String, moved out");
+    let taken = match message {
+        Message::Text(text) => text,
+        _ => String::new(),
+    };
+    report.address("`text` after the match", taken.as_str(), format!("{:?}", taken));
+    report.ok("Same buffer: the match moved the String out of `message`, it didn't copy it");
+    report.code("println!(\"{:?}\", message);  // ❌ error[E0382]: borrow of partially moved value: `message`");
+    report.text("Checked by tests/compile_fail/match_moves_scrutinee.rs");
+
+    report.step("Copy bindings and `_` don't move");
+    let ping = Message::Ping(7);
+    let n = match ping {
+        Message::Ping(n) => n,  // u32 is Copy
+        _ => 0,
+    };
+    report.text(format!("Ping(n) bound n = {} by copy; ping is still {:?}", n, ping));
+    let message = Message::Text(String::from("still here"));
+    let is_text = match message {
+        Message::Text(_) => true,  // `_` matches without binding: nothing moves
+        _ => false,
+    };
+    report.text(format!("Text(_) matched ({}); message is still {:?}", is_text, message));
+    report.code("Message::Text(_s) => ...  // ⚠ `_s` is a binding: it DOES move");
+
+    report.step("Matching on a reference borrows");
+    report.code("match &message { Message::Text(text) => ..., }  // text: &String");
+    report.text(format!("message.describe() = {:?} - `match self` on &self never moves", message.describe()));
+
+    report.gap();
+    report.ok("The scrutinee is a place: by-value patterns move out of it, like `let`");
+    report.text("Want to keep the value? Match on &value, use `ref`, or bind with `_`");
+    report
+}
+
+// ref and ref mut: borrow one part, move or copy another
+pub fn ref_patterns() -> DemoReport {
+    let mut report = DemoReport::new("ref and ref mut Patterns");
+
+    let message = Message::Text(String::from("borrowed"));
+    report.code("match message { Message::Text(ref text) => text.len(), _ => 0 }  // text: &String");
+    let len = match message {
+        Message::Text(ref text) => text.len(),
+        _ => 0,
+    };
+    report.text(format!("len = {} and message is whole: {:?}", len, message));
+
+    report.step("ref mut: edit in place");
+    let mut message = Message::Text(String::from("draft"));
+    match message {
+        Message::Text(ref mut text) => text.push_str(" (edited)"),
+        Message::Ping(ref mut n) => *n += 1,
+        Message::Quit => {}
+    }
+    report.code("match message { Message::Text(ref mut text) => text.push_str(\" (edited)\"), ... }");
+    report.text(format!("message = {:?}", message));
+    report.text("Same as `match &mut message { Message::Text(text) => ... }` - the form you'll see more");
+
+    report.step("Mixing modes in one pattern");
+    let pair = (String::from("kept"), String::from("taken"));
+    let (ref kept, taken) = pair;
+    report.code("let (ref kept, taken) = pair;  // borrow .0, move .1");
+    report.text(format!("kept = {:?}, taken = {:?}, pair.0 still = {:?}", kept, taken, pair.0));
+
+    report.step("@ bindings");
+    let describe = |n: u32| match n {
+        0 => "zero".to_string(),
+        small @ 1..=9 => format!("small ({})", small),
+        big => format!("big ({})", big),
+    };
+    report.code("small @ 1..=9 => ...  // test the range AND bind the value");
+    report.text(format!("3 → {}, 42 → {}", describe(3), describe(42)));
+
+    report.gap();
+    report.ok("ref / ref mut pick the binding mode per name: borrow here, move there");
+    report
+}
+
+// &Option<T>: default binding modes, as_ref, and E0507
+pub fn ergonomics() -> DemoReport {
+    let mut report = DemoReport::new("Match Ergonomics on &Option<T>");
+
+    let nickname: Option<String> = Some(String::from("ferris"));
+    let borrowed: &Option<String> = &nickname;
+    report.code("match borrowed { Some(name) => ..., None => ... }  // borrowed: &Option<String>, name: &String");
+    if let Some(name) = borrowed {
+        report.address("name (a &String into the Option)", name.as_str(), format!("{:?}", name));
+    }
+    report.ok("Matching a reference against a non-reference pattern switches the bindings to `ref`");
+    report.text("Before Rust 1.26 you had to write `&Some(ref name)` or `Some(ref name)` with *borrowed");
+
+    report.step("Moving out from behind a reference");
+    report.code("match *borrowed { Some(name) => ..., None => ... }  // ❌ error[E0507]: cannot move out of `borrowed` as enum variant `Some` which is behind a shared reference");
+    report.text("Checked by tests/compile_fail/match_move_out_of_ref.rs");
+    report.code("match names[0] { Some(name) => ..., None => ... }  // ❌ error[E0507]: cannot move out of index of `Vec<Option<String>>`");
+    report.text("Checked by tests/compile_fail/match_move_out_of_index.rs");
+    report.text("Fixes: drop the * (borrow), `.clone()` the value, or `.take()` it if you own the Vec (&mut)");
+
+    report.step("The Option adapters say the same thing in one call");
+    let mut slots = vec![Some(String::from("first")), None];
+    let peeked: Option<&str> = slots[0].as_deref();
+    report.text(format!("slots[0].as_deref() = {:?}  // Option<&str>, borrows", peeked));
+    if let Some(name) = slots[0].as_mut() {
+        name.make_ascii_uppercase();
+    }
+    report.text(format!("slots[0].as_mut() → uppercased in place: {:?}", slots[0]));
+    let taken = slots[0].take();
+    report.text(format!("slots[0].take() = {:?}, leaving {:?}", taken, slots));
+
+    report.gap();
+    report.ok("`&Option<T>` → `Option<&T>`: as_ref(); `&mut` → as_mut(); String → &str: as_deref()");
+    report
+}
+
+// if let, while let, let chains and the 2024 temporary scope
+pub fn if_let_while_let() -> DemoReport {
+    let mut report = DemoReport::new("if let and while let");
+
+    let mut stack = vec![String::from("a"), String::from("b"), String::from("c")];
+    let mut popped = Vec::new();
+    while let Some(top) = stack.pop() {  // pop() returns an owned Option<String>: top is moved out
+        popped.push(top);
+    }
+    report.code("while let Some(top) = stack.pop() { popped.push(top); }");
+    report.text(format!("popped = {:?}, stack = {:?}", popped, stack));
+
+    report.step("Walking a linked list by value");
+    let frames = Frame {
+        name: "main".into(),
+        caller: Some(Box::new(Frame { name: "serve".into(), caller: Some(Box::new(Frame { name: "handle".into(), caller: None })) })),
+    };
+    let mut cursor = Some(Box::new(frames));
+    let mut names = Vec::new();
+    while let Some(frame) = cursor {
+        names.push(frame.name);   // partial move out of the Box'd frame
+        cursor = frame.caller;     // ...and the rest moves into the cursor
+    }
+    report.code("while let Some(frame) = cursor { names.push(frame.name); cursor = frame.caller; }");
+    report.text(format!("names = {:?} - each frame is freed as the cursor moves past it", names));
+
+    report.step("let chains (edition 2024)");
+    let host = Some("localhost");
+    let port: Option<u16> = "8080".parse().ok();
+    if let Some(host) = host
+        && let Some(port) = port
+        && port > 1024
+    {
+        report.text(format!("{}:{} - two matches and a condition, no nesting", host, port));
+    }
+    report.code("if let Some(host) = host && let Some(port) = port && port > 1024 { ... }");
+
+    report.step("if let's temporaries (edition 2024)");
+    let cache = RefCell::new(vec![1, 2, 3]);
+    let first = if let Some(first) = cache.borrow().first().copied() {
+        first
+    } else {
+        cache.borrow_mut().push(0);  // 2021: BorrowMutError, the borrow() was still alive here
+        0
+    };
+    report.code("if let Some(x) = cache.borrow().first().copied() { x } else { cache.borrow_mut().push(0); 0 }");
+    report.text(format!("first = {}", first));
+    report.ok("Since 2024 the scrutinee's temporaries (the Ref guard) are dropped before `else`");
+    report.warn("A `match` still keeps them for every arm - the same code as a match panics");
+
+    report.gap();
+    report.ok("if let / while let are one-arm matches: same moves, same borrows");
+    report
+}
+
+pub fn match_vs_go() -> DemoReport {
+    let mut report = DemoReport::new("Go's switch vs Rust's match");
+
+    report.side_by_side(vec![
+        SideBySideRow::differs("switch m := msg.(type) {  // m is a copy", "match msg {  // msg is a place"),
+        SideBySideRow::differs("case Text: use(m.Body)  // msg still usable", "Message::Text(body) => use(body),  // body moved out"),
+        SideBySideRow::same("case Ping: log(m.N)", "Message::Ping(n) => log(n),  // u32: copied"),
+        SideBySideRow::differs("}  // no Quit case: compiles, does nothing", "}  // ❌ error[E0004]: non-exhaustive patterns: `Message::Quit` not covered"),
+    ]);
+    report.text("Checked by tests/compile_fail/match_non_exhaustive.rs");
+
+    report.table(
+        &["", "Go switch", "Rust match"],
+        vec![
+            vec!["value matched".into(), "a copy".into(), "a place: moved from or borrowed".into()],
+            vec!["missing case".into(), "falls through to nothing".into(), "E0004 unless `_ =>`".into()],
+            vec!["fallthrough".into(), "opt-in keyword".into(), "none - use `A | B =>`".into()],
+            vec!["bind part of a value".into(), "type switch only".into(), "any pattern, nested".into()],
+            vec!["borrow instead".into(), "switch on a pointer".into(), "match &x, ref, ref mut".into()],
+        ],
+    );
+
+    report.gap();
+    report.ok("Go devs' usual E0507: matching `*self` or `v[i]` by value - match the reference instead");
+    report
+}
+
+pub const DEMO: Demo = Demo {
+    name: "match-ownership",
+    about: "match moves vs borrows, ref/ref mut, ergonomics on &Option<T>, E0507, if let/while let chains vs Go's switch",
+    difficulty: Difficulty::Intermediate,
+    tags: &[Tag::Ownership, Tag::Borrowing],
+    examples: &[
+        Example { name: "moves", run: moves },
+        Example { name: "ref-patterns", run: ref_patterns },
+        Example { name: "ergonomics", run: ergonomics },
+        Example { name: "if-let-while-let", run: if_let_while_let },
+        Example { name: "vs-go", run: match_vs_go },
+    ],
+};
+
+pub fn demonstrate_match_ownership() -> Vec<DemoReport> {
+    DEMO.run()
+}
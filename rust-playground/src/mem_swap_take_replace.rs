@@ -0,0 +1,291 @@
+// mem::swap, take and replace - moving a value out from behind &mut
+// Go:   `p := b.pending; b.pending = nil` - copy the slice header, then reset
+//       the field. Two statements, and in between two slices share one array;
+//       forget the second line and they keep sharing it.
+// Rust: a &mut T lets you change the value but not leave a hole in it - the
+//       owner still drops that field later. So moving out is always a swap:
+//       put something valid back in the same step. mem::take puts Default
+//       back, mem::replace puts your value back, mem::swap trades two places,
+//       Option::take leaves None. Only the inline part moves; heap buffers
+//       stay where they are.
+
+use std::mem;
+
+use crate::alloc_stats;
+use crate::registry::{Demo, Difficulty, Example, Tag};
+use crate::report::DemoReport;
+
+/// Log lines waiting to be written out
+#[derive(Debug, Default)]
+pub struct Batcher {
+    pub pending: Vec<String>,
+    pub flushed: usize,
+}
+
+impl Batcher {
+    /// Hand the whole batch to the caller and start a new, empty one
+    pub fn flush(&mut self) -> Vec<String> {
+        self.flushed += self.pending.len();
+        mem::take(&mut self.pending)
+    }
+}
+
+/// A connection whose Active state owns a session that has no Default
+#[derive(Debug)]
+pub enum Connection {
+    Idle,
+    Active(Session),
+    Closed { bytes_sent: usize },
+}
+
+#[derive(Debug)]
+pub struct Session {
+    pub peer: String,
+    pub bytes_sent: usize,
+}
+
+impl Connection {
+    /// Move the Session out of Active, leaving Closed behind
+    pub fn close(&mut self) -> Option<Session> {
+        match mem::replace(self, Connection::Idle) {
+            Connection::Active(session) => {
+                *self = Connection::Closed { bytes_sent: session.bytes_sent };
+                Some(session)
+            }
+            other => {
+                *self = other;  // Not active: put it back unchanged
+                None
+            }
+        }
+    }
+}
+
+/// A minimal singly linked stack: every operation is an Option::take
+#[derive(Debug, Default)]
+pub struct Stack {
+    head: Option<Box<StackNode>>,
+    len: usize,
+}
+
+#[derive(Debug)]
+struct StackNode {
+    value: u32,
+    next: Option<Box<StackNode>>,
+}
+
+impl Stack {
+    pub fn push(&mut self, value: u32) {
+        let next = self.head.take();
+        self.head = Some(Box::new(StackNode { value, next }));
+        self.len += 1;
+    }
+
+    pub fn pop(&mut self) -> Option<u32> {
+        let node = self.head.take()?;
+        self.head = node.next;
+        self.len -= 1;
+        Some(node.value)
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.head.is_none()
+    }
+}
+
+fn allocations_of<R>(f: impl FnOnce() -> R) -> (R, String) {
+    let (result, stats) = alloc_stats::measure(f);
+    (result, stats.map_or("n/a (needs --features alloc-stats)".to_string(), |stats| stats.allocations.to_string()))
+}
+
+// The move the borrow checker rejects, and why
+pub fn naive_move() -> DemoReport {
+    let mut report = DemoReport::new("Moving Out of &mut - the Naive Version");
+
+    report.code("fn flush(&mut self) -> Vec<String> {");
+    report.code("    self.pending  // ❌ error[E0507]: cannot move out of `self.pending` which is behind a mutable reference");
+    report.code("}");
+    report.text("Checked by tests/compile_fail/move_out_of_mut_ref.rs");
+
+    report.gap();
+    report.text("The caller still owns the Batcher and will drop it - including `pending`");
+    report.text("If flush() could move the Vec out, that drop would free its buffer a second time");
+    report.ok("So the rule: through &mut you may replace a value, never leave it empty");
+    report.text("Cloning compiles (`self.pending.clone()`) but copies every String - the next examples move instead");
+
+    report.step("Go");
+    report.code("func (b *Batcher) Flush() []string {");
+    report.code("    p := b.pending");
+    report.code("    b.pending = nil  // forget this line: caller and Batcher share one backing array");
+    report.code("    return p");
+    report.code("}");
+    report
+}
+
+// mem::take: move out, leave Default::default() behind
+pub fn take() -> DemoReport {
+    let mut report = DemoReport::new("mem::take - Move Out, Leave the Default");
+
+    let mut batcher = Batcher::default();
+    batcher.pending.extend(["GET /".to_string(), "GET /health".to_string(), "POST /login".to_string()]);
+    report.address("pending's buffer before", batcher.pending.as_slice(), format!("{:?}", batcher.pending));
+
+    report.code("fn flush(&mut self) -> Vec<String> { mem::take(&mut self.pending) }");
+    let (batch, allocations) = allocations_of(|| batcher.flush());
+    report.address("returned batch's buffer", batch.as_slice(), format!("{:?}", batch));
+    report.text(format!("batcher.pending afterwards: {:?}, capacity {}", batcher.pending, batcher.pending.capacity()));
+    report.text(format!("allocations during flush(): {}", allocations));
+
+    report.gap();
+    report.ok("Same buffer address: only the 24-byte Vec header moved - no element was copied");
+    report.ok("Vec::new() doesn't allocate, so leaving an empty Vec behind is free");
+    report.text("Works for anything with Default: String, HashMap, Option (→ None), u64 (→ 0)");
+    report
+}
+
+// mem::replace: move out, leave a value you choose
+pub fn replace() -> DemoReport {
+    let mut report = DemoReport::new("mem::replace - Swap In a Value You Choose");
+
+    report.code("match mem::replace(self, Connection::Idle) {  // take the old state out...");
+    report.code("    Connection::Active(session) => { *self = Connection::Closed { .. }; Some(session) }");
+    report.code("    other => { *self = other; None }            // ...and decide what goes back");
+    report.code("}");
+
+    let mut connection = Connection::Active(Session { peer: "10.0.0.7:443".into(), bytes_sent: 1_024 });
+    report.text(format!("before:           {:?}", connection));
+    let session = connection.close();
+    report.text(format!("close() returned: {:?}", session));
+    report.text(format!("after:            {:?}", connection));
+    report.text(format!("close() again:    {:?} (already closed, left as is)", connection.close()));
+
+    report.step("Replacing one field");
+    let mut title = String::from("draft");
+    let old = mem::replace(&mut title, String::from("final"));
+    report.code("let old = mem::replace(&mut title, String::from(\"final\"));");
+    report.text(format!("old = {:?}, title = {:?}", old, title));
+
+    report.gap();
+    report.ok("Session has no Default, so take() isn't an option - replace() takes any placeholder");
+    report.ok("The state machine owns its data: moving the session out is the transition itself");
+
+    report.step("Go");
+    report.code("s := c.session; c.session = nil; c.state = Closed  // three writes, any order, none checked");
+    report
+}
+
+// mem::swap: trade two places
+pub fn swap() -> DemoReport {
+    let mut report = DemoReport::new("mem::swap - Double Buffering Without Copies");
+
+    let mut front: Vec<u8> = vec![1; 4096];  // What readers see
+    let mut back: Vec<u8> = vec![2; 4096];  // What the writer fills
+    let (front_before, back_before) = (front.as_ptr() as usize, back.as_ptr() as usize);
+    report.code("mem::swap(&mut front, &mut back);  // publish the new frame");
+    let (_, allocations) = allocations_of(|| mem::swap(&mut front, &mut back));
+    report.table(
+        &["", "buffer before", "buffer after", "first byte"],
+        vec![
+            vec!["front".into(), format!("{:#x}", front_before), format!("{:#x}", front.as_ptr() as usize), front[0].to_string()],
+            vec!["back".into(), format!("{:#x}", back_before), format!("{:#x}", back.as_ptr() as usize), back[0].to_string()],
+        ],
+    );
+    report.text(format!("allocations during swap: {}", allocations));
+    report.bytes("bytes actually moved (two Vec headers)", 2 * size_of::<Vec<u8>>());
+
+    report.step("Inside a slice");
+    let mut queue = vec!["a", "b", "c", "d"];
+    queue.swap(0, 3);
+    report.code("queue.swap(0, 3);  // two elements of ONE slice");
+    report.code("mem::swap(&mut queue[0], &mut queue[3]);  // ❌ error[E0499]: cannot borrow `queue` as mutable more than once");
+    report.text("Checked by tests/compile_fail/swap_two_slice_elements.rs - the checker sees one `queue`, not two slots");
+    report.text(format!("queue = {:?}", queue));
+
+    report.gap();
+    report.ok("4 KiB frames, 48 bytes moved: each Vec keeps its buffer, they just trade owners");
+    report.ok("Two &mut to two different places - the borrow checker proves they don't overlap");
+
+    report.step("Go");
+    report.code("front, back = back, front  // same trick: swaps the slice headers");
+    report
+}
+
+// Option::take: the linked-list workhorse
+pub fn option_take() -> DemoReport {
+    let mut report = DemoReport::new("Option::take - Unlinking Nodes");
+
+    report.code("fn push(&mut self, value: u32) {");
+    report.code("    let next = self.head.take();  // head is None for a moment - but still valid");
+    report.code("    self.head = Some(Box::new(Node { value, next }));");
+    report.code("}");
+    report.code("fn pop(&mut self) -> Option<u32> {");
+    report.code("    let node = self.head.take()?;");
+    report.code("    self.head = node.next;");
+    report.code("    Some(node.value)");
+    report.code("}");
+
+    let mut stack = Stack::default();
+    for value in [1, 2, 3] {
+        stack.push(value);
+    }
+    report.count("len after pushing 1, 2, 3", stack.len());
+    let popped: Vec<u32> = std::iter::from_fn(|| stack.pop()).collect();
+    report.text(format!("popped: {:?}, empty: {}", popped, stack.is_empty()));
+
+    report.step("The rest of the family");
+    let mut slot = Some(5);
+    let old = slot.replace(6);
+    report.text(format!("Some(5).replace(6) → returns {:?}, leaves {:?}", old, slot));
+    let taken = slot.take_if(|value| *value > 5);
+    report.text(format!("take_if(|v| *v > 5) → {:?}, leaves {:?}", taken, slot));
+    let mut cached: Option<String> = None;
+    let value = cached.get_or_insert_with(|| "computed once".to_string());
+    report.text(format!("get_or_insert_with(..) → {:?}", value));
+
+    report.gap();
+    report.ok("`self.head = self.head.unwrap().next` is E0507 again: unwrap() moves head out from behind &mut self");
+    report.ok("take() empties head first, so the old node is ours to pick apart");
+    report
+}
+
+pub fn mem_vs_go() -> DemoReport {
+    let mut report = DemoReport::new("Moving Out of a Field: Go vs Rust");
+
+    report.table(
+        &["You want", "Go", "Rust"],
+        vec![
+            vec!["take a field, leave it empty".into(), "p := s.f; s.f = nil".into(), "mem::take(&mut s.f)".into()],
+            vec!["take a field, leave a value".into(), "p := s.f; s.f = v".into(), "mem::replace(&mut s.f, v)".into()],
+            vec!["exchange two values".into(), "a, b = b, a".into(), "mem::swap(&mut a, &mut b)".into()],
+            vec!["unlink a node".into(), "n := l.head; l.head = n.next".into(), "let n = l.head.take()?; l.head = n.next".into()],
+            vec!["forget to reset".into(), "two owners of one array".into(), "can't happen - it's one call".into()],
+            vec!["cost".into(), "copy the header".into(), "copy the header".into()],
+        ],
+    );
+
+    report.gap();
+    report.ok("Same machine code as Go's two assignments - Rust just won't let you write only the first one");
+    report
+}
+
+pub const DEMO: Demo = Demo {
+    name: "mem-swap-take-replace",
+    about: "Moving out from behind &mut: mem::take, mem::replace, mem::swap and Option::take",
+    difficulty: Difficulty::Intermediate,
+    tags: &[Tag::Ownership, Tag::Borrowing],
+    examples: &[
+        Example { name: "naive-move", run: naive_move },
+        Example { name: "take", run: take },
+        Example { name: "replace", run: replace },
+        Example { name: "swap", run: swap },
+        Example { name: "option-take", run: option_take },
+        Example { name: "vs-go", run: mem_vs_go },
+    ],
+};
+
+pub fn demonstrate_mem_swap_take_replace() -> Vec<DemoReport> {
+    DEMO.run()
+}
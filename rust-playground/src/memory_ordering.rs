@@ -0,0 +1,252 @@
+// Memory orderings - the "memory model" part, run as experiments
+// Go:   the Go memory model defines happens-before through channels,
+//       sync.Mutex, sync.Once, WaitGroup and sync/atomic - and since Go 1.19
+//       every sync/atomic operation is sequentially consistent. You never
+//       name an ordering; a racy program is simply wrong (and can corrupt
+//       multiword values like interfaces and slices).
+// Rust: the C++20 model. The same synchronization (spawn/join, channels,
+//       Mutex) gives happens-before for free; on raw atomics you choose:
+//       Relaxed (atomicity only), Release/Acquire (a one-way handoff) or
+//       SeqCst (one global order). The experiments below run the classic
+//       litmus tests many times and count the outcomes each ordering allows.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Barrier};
+use std::thread;
+
+use crate::registry::{Demo, Difficulty, Example, Tag};
+use crate::report::DemoReport;
+
+const ROUNDS: usize = if cfg!(debug_assertions) { 2_000 } else { 20_000 };
+// Nothing overlaps on one CPU, so there's no point waiting for 20,000 rounds of it
+const SINGLE_CPU_ROUNDS: usize = 1_000;
+
+/// What one litmus test saw over all rounds
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LitmusResult {
+    pub rounds: usize,
+    /// Rounds that ended in the outcome the test is looking for
+    pub observed: usize,
+}
+
+// Two workers run one round at a time: a start barrier lines them up, an end
+// barrier lets the main thread read the results before the next round
+fn litmus(
+    rounds: usize,
+    first: impl Fn(usize) + Send + Sync + 'static,
+    second: impl Fn(usize) + Send + Sync + 'static,
+    mut check: impl FnMut(usize) -> bool,
+) -> LitmusResult {
+    let barrier = Arc::new(Barrier::new(3));
+    let workers: Vec<thread::JoinHandle<()>> = [Box::new(first) as Box<dyn Fn(usize) + Send + Sync>, Box::new(second)]
+        .into_iter()
+        .map(|work| {
+            let barrier = Arc::clone(&barrier);
+            thread::spawn(move || {
+                for round in 1..=rounds {
+                    barrier.wait();
+                    work(round);
+                    barrier.wait();
+                }
+            })
+        })
+        .collect();
+
+    let mut observed = 0;
+    for round in 1..=rounds {
+        barrier.wait();
+        barrier.wait();
+        if check(round) {
+            observed += 1;
+        }
+    }
+    for worker in workers {
+        worker.join().unwrap();
+    }
+    LitmusResult { rounds, observed }
+}
+
+/// Message passing: write two payload words, then raise a flag; the reader
+/// waits for the flag and counts rounds where the payload was stale
+pub fn message_passing(store: Ordering, load: Ordering, rounds: usize) -> LitmusResult {
+    let cells = Arc::new([AtomicUsize::new(0), AtomicUsize::new(0), AtomicUsize::new(0)]);  // payload ×2, flag
+    let stale = Arc::new(AtomicUsize::new(0));
+    let (writer, reader, seen) = (Arc::clone(&cells), Arc::clone(&cells), Arc::clone(&stale));
+    litmus(
+        rounds,
+        move |round| {
+            writer[0].store(round, Ordering::Relaxed);
+            writer[1].store(round, Ordering::Relaxed);
+            writer[2].store(round, store);
+        },
+        move |round| {
+            while reader[2].load(load) != round {
+                thread::yield_now();
+            }
+            let payload_ok = reader[0].load(Ordering::Relaxed) == round && reader[1].load(Ordering::Relaxed) == round;
+            seen.store(usize::from(!payload_ok), Ordering::Relaxed);
+        },
+        |_| stale.load(Ordering::Relaxed) == 1,
+    )
+}
+
+/// Store buffering: each thread stores to its own variable, then loads the
+/// other's. Both loads returning 0 means each store was "still in flight"
+pub fn store_buffering(store: Ordering, load: Ordering, rounds: usize) -> LitmusResult {
+    // x, y, r1, r2
+    let cells = Arc::new([AtomicUsize::new(0), AtomicUsize::new(0), AtomicUsize::new(0), AtomicUsize::new(0)]);
+    let (left, right, results) = (Arc::clone(&cells), Arc::clone(&cells), Arc::clone(&cells));
+    litmus(
+        rounds,
+        move |round| {
+            left[0].store(round, store);
+            let r1 = left[1].load(load);
+            left[2].store(r1, Ordering::Relaxed);
+        },
+        move |round| {
+            right[1].store(round, store);
+            let r2 = right[0].load(load);
+            right[3].store(r2, Ordering::Relaxed);
+        },
+        // "0" for this round = saw the previous round's value
+        |round| results[2].load(Ordering::Relaxed) < round && results[3].load(Ordering::Relaxed) < round,
+    )
+}
+
+fn cores() -> usize {
+    thread::available_parallelism().map_or(1, usize::from)
+}
+
+fn rounds() -> usize {
+    if cores() == 1 { SINGLE_CPU_ROUNDS } else { ROUNDS }
+}
+
+fn hardware_note(report: &mut DemoReport) {
+    report.text(format!("{} on {} CPU(s), {} rounds per row", std::env::consts::ARCH, cores(), rounds()));
+    if cores() == 1 {
+        report.warn("One CPU: the threads never truly overlap, so weak outcomes are very unlikely to show");
+    }
+}
+
+// Release/Acquire message passing
+pub fn release_acquire() -> DemoReport {
+    let mut report = DemoReport::new("Message Passing - Release/Acquire");
+
+    report.code("// writer                                  // reader");
+    report.code("data.store(round, Relaxed);                while flag.load(Acquire) != round {}");
+    report.code("flag.store(round, Release);                assert_eq!(data.load(Relaxed), round);");
+    hardware_note(&mut report);
+
+    let paired = message_passing(Ordering::Release, Ordering::Acquire, rounds());
+    let relaxed = message_passing(Ordering::Relaxed, Ordering::Relaxed, rounds());
+    report.table(
+        &["Flag store / load", "Stale payload seen", "Allowed?"],
+        vec![
+            vec!["Release / Acquire".into(), paired.observed.to_string(), "no - happens-before".into()],
+            vec!["Relaxed / Relaxed".into(), relaxed.observed.to_string(), "yes".into()],
+        ],
+    );
+    if paired.observed > 0 {
+        report.fail("Release/Acquire showed a stale payload - that's a bug in the experiment or the platform");
+    }
+
+    report.gap();
+    report.ok("Release: every write before the store is visible to whoever Acquires that value");
+    report.ok("The payload itself can stay Relaxed (or be plain data) - the flag carries the ordering");
+    report.text("This is what Mutex unlock/lock, channel send/recv and thread::spawn/join do inside");
+    if std::env::consts::ARCH == "x86_64" {
+        report.text("x86-64 never reorders store→store or load→load (TSO), so Relaxed shows 0 here too;");
+        report.text("on ARM/Apple Silicon the Relaxed row can go above zero - the compiler may reorder on any CPU");
+    }
+
+    report.step("Go");
+    report.code("data = 42; ready.Store(true)        // atomic.Bool: seq-cst, which includes release");
+    report.code("for !ready.Load() {}; use(data)     // ...and acquire: data == 42 is guaranteed");
+    report.text("Go has no Relaxed row: the weaker, cheaper option isn't offered");
+    report
+}
+
+// Store buffering: what Relaxed allows, and why SeqCst exists
+pub fn relaxed_reordering() -> DemoReport {
+    let mut report = DemoReport::new("Store Buffering - Reordering You Can Count");
+
+    report.code("// thread 1                 // thread 2");
+    report.code("x.store(1, ORD);            y.store(1, ORD);");
+    report.code("r1 = y.load(ORD);           r2 = x.load(ORD);");
+    report.text("Interleave them any way you like: at least one of r1, r2 must be 1... unless stores are delayed");
+    hardware_note(&mut report);
+
+    let rows = [
+        ("Relaxed", Ordering::Relaxed, Ordering::Relaxed, "yes"),
+        ("Release / Acquire", Ordering::Release, Ordering::Acquire, "yes"),
+        ("SeqCst", Ordering::SeqCst, Ordering::SeqCst, "no"),
+    ];
+    let results: Vec<LitmusResult> = rows.iter().map(|(_, store, load, _)| store_buffering(*store, *load, rounds())).collect();
+    report.table(
+        &["Ordering", "r1 == r2 == 0", "Allowed?"],
+        rows.iter()
+            .zip(&results)
+            .map(|((name, _, _, allowed), result)| {
+                vec![name.to_string(), format!("{} / {}", result.observed, result.rounds), allowed.to_string()]
+            })
+            .collect(),
+    );
+    if results[2].observed > 0 {
+        report.fail("SeqCst showed r1 == r2 == 0 - that outcome is forbidden");
+    } else {
+        report.ok("SeqCst: never both zero - all SeqCst operations fit in one global order");
+    }
+
+    report.gap();
+    report.text("Each core's store sits in its store buffer while the following load reads the old value");
+    report.text("from cache - even x86 does this, so the Relaxed and Release/Acquire rows show it on multi-core x86");
+    report.warn("Release/Acquire doesn't help: a Release store followed by an Acquire load may still swap");
+    report.text("That's the case SeqCst is for: Dekker/Peterson locks, \"I set my flag, did you set yours?\"");
+
+    report.step("Go");
+    report.code("x.Store(1); r1 := y.Load()  // sync/atomic is seq-cst: r1 == r2 == 0 can't happen");
+    report.text("Go always pays for the SeqCst row (an XCHG or MFENCE per store on x86)");
+    report
+}
+
+// Ordering cheat sheet against the Go memory model's happens-before rules
+pub fn happens_before_vs_go() -> DemoReport {
+    let mut report = DemoReport::new("Happens-Before: The Go Memory Model vs Rust");
+
+    report.table(
+        &["Edge", "Go", "Rust"],
+        vec![
+            vec!["start a thread".into(), "`go f()` hb f starts".into(), "thread::spawn hb the closure starts".into()],
+            vec!["wait for it".into(), "wg.Done() hb wg.Wait() returns".into(), "thread ends hb join() returns".into()],
+            vec!["channel".into(), "send hb the receive completes".into(), "send hb recv (mpsc, sync_channel)".into()],
+            vec!["lock".into(), "Unlock hb the next Lock".into(), "guard drop hb the next lock()".into()],
+            vec!["once".into(), "once.Do(f): f hb every Do returns".into(), "OnceLock::get_or_init, same".into()],
+            vec!["atomics".into(), "always seq-cst".into(), "Relaxed / Release+Acquire / SeqCst".into()],
+            vec!["data race".into(), "undefined-ish, can corrupt interfaces".into(), "impossible without unsafe".into()],
+        ],
+    );
+
+    report.gap();
+    report.ok("Above the atomics row the two models agree: use the sync primitives and you get happens-before");
+    report.ok("Rust's type system stops the data race; Go's race detector finds it at runtime");
+    report.text("Orderings only matter when you build synchronization yourself out of atomics");
+    report.text("Rule of thumb: SeqCst until you can write down why Acquire/Release (or Relaxed) is enough");
+    report.text("See `run atomics` for the API and `run stress -e lost-updates` for races that orderings don't fix");
+    report
+}
+
+pub const DEMO: Demo = Demo {
+    name: "memory-ordering",
+    about: "Relaxed/Release/Acquire/SeqCst as litmus tests: message passing, store buffering, Go's happens-before",
+    difficulty: Difficulty::Advanced,
+    tags: &[Tag::Concurrency],
+    examples: &[
+        Example { name: "release-acquire", run: release_acquire },
+        Example { name: "store-buffering", run: relaxed_reordering },
+        Example { name: "vs-go", run: happens_before_vs_go },
+    ],
+};
+
+pub fn demonstrate_memory_ordering() -> Vec<DemoReport> {
+    DEMO.run()
+}
@@ -0,0 +1,174 @@
+// Memory picture - the addresses a demo printed, drawn as stack vs heap
+// `report.address(..)` prints bare hex numbers; `run --memviz` adds a step
+// after each demo that sorts them by address and draws which region of the
+// address space each one is in, highest first (where the stack lives):
+//
+//   high addresses
+//   +-- stack -------------------------------------
+//   |  0x7ffd5a3c0f60  +24     b
+//   |  0x7ffd5a3c0f48  +0      a
+//   +-- ... 42.1 TiB apart ...
+//   +-- heap --------------------------------------
+//   |  0x55d0c1a2b2c0  +0      a's buffer
+//   low addresses
+//
+// The regions come from /proc/self/maps, read right after the demo returns
+// (Linux). Elsewhere only "near this thread's stack" can be told apart.
+// Offsets are from the lowest address in the same block.
+
+use std::cmp::Reverse;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use crate::report::{format_bytes, DemoReport, Entry};
+
+/// Heading of the step `annotate` adds
+pub const HEADING: &str = "Address space";
+
+// How far from a local of ours still counts as our stack (no /proc/self/maps)
+const STACK_WINDOW: usize = 8 << 20;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// Draw the picture after every `DemoReport::measured` run from now on
+pub fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+pub fn enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Which part of the address space an address is in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Region {
+    /// This thread's stack: locals, the inline part of String/Vec/Box
+    Stack,
+    /// The brk heap: small allocations
+    Heap,
+    /// Anonymous mappings: large allocations, allocator arenas, other threads' stacks
+    Mapped,
+    /// Mapped from a file: the binary's statics and literals, shared libraries
+    Static,
+    /// Not in any mapping (or no map to look in)
+    Unknown,
+}
+
+impl Region {
+    pub fn name(self) -> &'static str {
+        match self {
+            Region::Stack => "stack",
+            Region::Heap => "heap",
+            Region::Mapped => "mmap (big allocations, arenas, other threads' stacks)",
+            Region::Static => "static (binary, libraries)",
+            Region::Unknown => "heap or static (no /proc/self/maps to tell)",
+        }
+    }
+}
+
+/// One line of /proc/self/maps
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Mapping {
+    pub start: usize,
+    pub end: usize,
+    pub region: Region,
+}
+
+/// Parse /proc/self/maps text: `start-end perms offset dev inode [path]`
+pub fn parse_maps(maps: &str) -> Vec<Mapping> {
+    maps.lines()
+        .filter_map(|line| {
+            let mut fields = line.split_whitespace();
+            let (start, end) = fields.next()?.split_once('-')?;
+            let path = fields.nth(4).unwrap_or("");
+            let region = match path {
+                "[stack]" => Region::Stack,
+                "[heap]" => Region::Heap,
+                "" => Region::Mapped,
+                path if path.starts_with('/') => Region::Static,
+                _ => Region::Unknown,  // [vdso], [vvar], ...
+            };
+            Some(Mapping { start: usize::from_str_radix(start, 16).ok()?, end: usize::from_str_radix(end, 16).ok()?, region })
+        })
+        .collect()
+}
+
+/// The process's mappings at one moment, plus where this thread's stack is
+#[derive(Debug, Clone, Default)]
+pub struct AddressSpace {
+    pub mappings: Vec<Mapping>,
+    /// The address of a local in `current()`
+    pub stack_hint: usize,
+}
+
+impl AddressSpace {
+    /// Read /proc/self/maps now (empty where there is none)
+    pub fn current() -> Self {
+        let local = 0u8;
+        let maps = std::fs::read_to_string("/proc/self/maps").unwrap_or_default();
+        AddressSpace { mappings: parse_maps(&maps), stack_hint: &local as *const u8 as usize }
+    }
+
+    pub fn region(&self, addr: usize) -> Region {
+        let near_stack = addr.abs_diff(self.stack_hint) < STACK_WINDOW;
+        match self.mappings.iter().find(|mapping| (mapping.start..mapping.end).contains(&addr)) {
+            // A spawned thread's stack is an anonymous mapping too
+            Some(mapping) if mapping.region == Region::Mapped && (mapping.start..mapping.end).contains(&self.stack_hint) => Region::Stack,
+            Some(mapping) => mapping.region,
+            None if self.mappings.is_empty() && near_stack => Region::Stack,
+            None => Region::Unknown,
+        }
+    }
+}
+
+/// The picture for every address `report` recorded, highest address first
+/// (empty when it recorded none)
+pub fn diagram(report: &DemoReport, space: &AddressSpace) -> Vec<String> {
+    let mut addresses: Vec<(usize, &str, Region)> = report
+        .entries()
+        .filter_map(|entry| match entry {
+            Entry::Address { label, addr, .. } => Some((*addr, label.as_str(), space.region(*addr))),
+            _ => None,
+        })
+        .collect();
+    if addresses.is_empty() {
+        return Vec::new();
+    }
+    addresses.sort_by_key(|(addr, _, _)| Reverse(*addr));
+
+    // Runs of neighbouring addresses in the same region
+    let mut blocks: Vec<Vec<(usize, &str, Region)>> = Vec::new();
+    for address in addresses {
+        match blocks.last_mut() {
+            Some(block) if block[0].2 == address.2 => block.push(address),
+            _ => blocks.push(vec![address]),
+        }
+    }
+
+    let mut lines = vec!["high addresses".to_string()];
+    for (index, block) in blocks.iter().enumerate() {
+        if index > 0 {
+            let above = blocks[index - 1].last().expect("blocks are never empty").0;
+            lines.push(format!("+-- ... {} apart ...", format_bytes(above - block[0].0)));
+        }
+        let name = block[0].2.name();
+        lines.push(format!("+-- {} {}", name, "-".repeat(44usize.saturating_sub(name.len()))));
+        let lowest = block.last().expect("blocks are never empty").0;
+        for (addr, label, _) in block {
+            lines.push(format!("|  {:#014x}  {:<8} {}", addr, format!("+{}", addr - lowest), label));
+        }
+    }
+    lines.push("low addresses".to_string());
+    lines
+}
+
+/// Add the picture as a last step (nothing when the report has no addresses)
+pub fn annotate(report: &mut DemoReport) {
+    let lines = diagram(report, &AddressSpace::current());
+    if lines.is_empty() {
+        return;
+    }
+    report.step(HEADING);
+    for line in lines {
+        report.code(line);
+    }
+}
@@ -0,0 +1,308 @@
+// Pipelines - generator → workers → aggregator over channels
+// Go:   the pipelines blog post: gen() returns <-chan int, sq() reads one
+//       channel and returns another, merge() fans several back into one.
+//       Each stage closes its output when its input is drained. Sending a
+//       pointer or slice shares it - both ends can still touch it.
+// Rust: the same shape with mpsc, but a send MOVES the value: the stage
+//       that sent a Vec can't touch it again, so a job belongs to exactly
+//       one stage at a time. Channels close when the last Sender drops, and
+//       sync_channel(n) bounds the queue, which is what gives backpressure.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::{self, Receiver, Sender, SyncSender};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::registry::{Demo, Difficulty, Example, Tag};
+use crate::report::DemoReport;
+
+const JOBS: usize = 2_000;
+const PAYLOAD: usize = 4 * 1024;
+const WORKER_COUNTS: [usize; 4] = [1, 2, 4, 8];
+
+/// What flows through the pipeline - owned by one stage at a time
+struct Job {
+    id: usize,
+    payload: Vec<u8>,
+}
+
+// Unbounded or bounded, behind one send()
+enum Outbox<T> {
+    Unbounded(Sender<T>),
+    Bounded(SyncSender<T>),
+}
+
+impl<T> Outbox<T> {
+    fn new(bound: Option<usize>) -> (Outbox<T>, Receiver<T>) {
+        match bound {
+            None => {
+                let (tx, rx) = mpsc::channel();
+                (Outbox::Unbounded(tx), rx)
+            }
+            Some(bound) => {
+                let (tx, rx) = mpsc::sync_channel(bound);
+                (Outbox::Bounded(tx), rx)
+            }
+        }
+    }
+
+    fn send(&self, value: T) -> Result<(), mpsc::SendError<T>> {
+        match self {
+            Outbox::Unbounded(tx) => tx.send(value),
+            Outbox::Bounded(tx) => tx.send(value),
+        }
+    }
+}
+
+/// Numbers from one pipeline run
+struct PipelineRun {
+    elapsed: Duration,
+    checksum: u64,
+    per_worker: Vec<usize>,
+    /// Most jobs created but not yet picked up by a worker
+    max_queued: usize,
+}
+
+// Stage 1: like Go's gen() - owns its Sender, returns the Receiver
+fn generate(jobs: usize, bound: Option<usize>, queued: Arc<AtomicUsize>, max_queued: Arc<AtomicUsize>) -> Receiver<Job> {
+    let (out, rx) = Outbox::new(bound);
+    thread::spawn(move || {
+        for id in 0..jobs {
+            let job = Job { id, payload: vec![(id % 251) as u8; PAYLOAD] };
+            let now = queued.fetch_add(1, Ordering::SeqCst) + 1;
+            max_queued.fetch_max(now, Ordering::SeqCst);
+            if out.send(job).is_err() {
+                break;  // Everyone downstream hung up
+            }
+        }
+        // `out` drops here: the workers' recv() returns Err once the queue drains
+    });
+    rx
+}
+
+fn checksum(job: &Job) -> u64 {
+    job.payload.iter().fold(job.id as u64, |sum, &byte| sum.wrapping_mul(31).wrapping_add(byte as u64))
+}
+
+// Stage 2: like Go's sq() started N times - fan-out over one shared Receiver,
+// fan-in through clones of one Sender (Go's merge() needs a WaitGroup for this)
+fn work(
+    jobs: Receiver<Job>,
+    workers: usize,
+    delay: Duration,
+    queued: Arc<AtomicUsize>,
+) -> (Receiver<(usize, u64)>, Vec<thread::JoinHandle<usize>>) {
+    let jobs = Arc::new(Mutex::new(jobs));
+    let (results_tx, results_rx) = mpsc::channel();
+    let handles = (0..workers)
+        .map(|worker| {
+            let (jobs, results_tx, queued) = (Arc::clone(&jobs), results_tx.clone(), Arc::clone(&queued));
+            thread::spawn(move || {
+                let mut done = 0;
+                loop {
+                    let job = jobs.lock().unwrap().recv();
+                    let Ok(job) = job else { break };
+                    queued.fetch_sub(1, Ordering::SeqCst);
+                    if !delay.is_zero() {
+                        thread::sleep(delay);
+                    }
+                    results_tx.send((worker, checksum(&job))).unwrap();
+                    done += 1;
+                    // `job` and its 4 KiB payload are freed right here, by the worker
+                }
+                done
+            })
+        })
+        .collect();
+    (results_rx, handles)
+}
+
+fn run_pipeline(workers: usize, bound: Option<usize>, delay: Duration) -> PipelineRun {
+    let queued = Arc::new(AtomicUsize::new(0));
+    let max_queued = Arc::new(AtomicUsize::new(0));
+    let start = Instant::now();
+
+    let jobs = generate(JOBS, bound, Arc::clone(&queued), Arc::clone(&max_queued));
+    let (results, handles) = work(jobs, workers, delay, queued);
+    // Stage 3: the aggregator - rx.iter() ends when every worker's Sender is gone
+    let checksum = results.iter().fold(0u64, |sum, (_, value)| sum.wrapping_add(value));
+
+    let per_worker = handles.into_iter().map(|handle| handle.join().unwrap()).collect();
+    PipelineRun { elapsed: start.elapsed(), checksum, per_worker, max_queued: max_queued.load(Ordering::SeqCst) }
+}
+
+// generator → N workers → aggregator
+pub fn three_stages() -> DemoReport {
+    let mut report = DemoReport::new("Three-Stage Pipeline - generate → work → aggregate");
+
+    report.code("let jobs = generate(JOBS);                      // Receiver<Job>");
+    report.code("let (results, workers) = work(jobs, 4);        // Receiver<(worker, u64)>");
+    report.code("let checksum = results.iter().fold(0, ...);    // ends when the workers finish");
+
+    let run = run_pipeline(4, None, Duration::ZERO);
+    report.count("jobs", JOBS);
+    report.bytes("payload per job", PAYLOAD);
+    report.duration("elapsed", run.elapsed);
+    report.text(format!("checksum = {:#x}", run.checksum));
+    report.table(
+        &["Worker", "Jobs"],
+        run.per_worker.iter().enumerate().map(|(worker, jobs)| vec![format!("w{}", worker), jobs.to_string()]).collect(),
+    );
+
+    report.gap();
+    report.ok("Shutdown needs no signal: the generator's Sender drops → workers drain and exit → results ends");
+    report.text("Fan-out: the workers share one Receiver behind a Mutex (std's Receiver isn't Clone)");
+    report.text("Fan-in: each worker owns a clone of one Sender - no merge() goroutines, no WaitGroup");
+
+    report.step("Go");
+    report.code("func gen(nums ...int) <-chan int { out := make(chan int); go func() { ...; close(out) }(); return out }");
+    report.code("func sq(in <-chan int) <-chan int { ... }");
+    report.code("func merge(cs ...<-chan int) <-chan int { var wg sync.WaitGroup; ...; go func() { wg.Wait(); close(out) }() }");
+    report.code("for n := range merge(sq(in), sq(in), sq(in), sq(in)) { sum += n }");
+    report
+}
+
+// A send moves the job - same heap buffer, new owner
+pub fn ownership_transfer() -> DemoReport {
+    let mut report = DemoReport::new("Sending Moves - One Owner per Stage");
+
+    let (tx, rx) = mpsc::channel::<Job>();
+    let job = Job { id: 7, payload: vec![1; PAYLOAD] };
+    let sent_at = job.payload.as_ptr();
+    report.address("payload before send", &job.payload, format!("{} bytes at {:p}", PAYLOAD, sent_at));
+    tx.send(job).unwrap();
+    report.code("tx.send(job);");
+    report.code("job.payload[0] = 9;  // ❌ E0382: borrow of moved value: `job`");
+    report.text("Checked by tests/compile_fail/use_after_send.rs");
+
+    let received = thread::spawn(move || {
+        let job = rx.recv().unwrap();
+        (job.payload.as_ptr() as usize, job.payload.len())
+    })
+    .join()
+    .unwrap();
+    report.text(format!("received on another thread: {} bytes at {:#x}", received.1, received.0));
+    if received.0 == sent_at as usize {
+        report.ok("Same buffer: only the 24-byte Vec header travelled through the channel");
+    } else {
+        report.fail("The payload was copied");
+    }
+
+    report.gap();
+    report.ok("No stage can read a job another stage is still changing - no data race possible");
+    report.text("The worker that receives a job frees it; nothing waits for a GC");
+
+    report.step("Go");
+    report.code("out <- job           // sends the slice header; both sides share the array");
+    report.code("job.Payload[0] = 9   // compiles - and races with the receiver (go test -race)");
+    report.text("Convention (\"don't touch after send\") is the only thing keeping stages apart");
+    report
+}
+
+// Items per second as workers are added
+pub fn throughput() -> DemoReport {
+    let mut report = DemoReport::new("Pipeline Throughput - 1 to 8 Workers");
+
+    report.count("jobs per run", JOBS);
+    report.count("CPUs available", thread::available_parallelism().map_or(1, |n| n.get()));
+    if cfg!(debug_assertions) {
+        report.warn("Debug build - use --release for real numbers");
+    }
+
+    let runs: Vec<(usize, PipelineRun)> = WORKER_COUNTS.iter().map(|&workers| (workers, run_pipeline(workers, None, Duration::ZERO))).collect();
+    let base = runs[0].1.elapsed.as_secs_f64();
+    report.table(
+        &["Workers", "Time", "Jobs/s", "Speedup"],
+        runs.iter()
+            .map(|(workers, run)| {
+                vec![
+                    workers.to_string(),
+                    format!("{:?}", run.elapsed),
+                    format!("{:.0}", JOBS as f64 / run.elapsed.as_secs_f64()),
+                    format!("{:.2}x", base / run.elapsed.as_secs_f64()),
+                ]
+            })
+            .collect(),
+    );
+    let checksums_agree = runs.iter().all(|(_, run)| run.checksum == runs[0].1.checksum);
+    if checksums_agree {
+        report.ok("Every run produced the same checksum");
+    } else {
+        report.fail("Checksums differ between runs");
+    }
+
+    report.gap();
+    report.text("Workers scale until the generator (one thread allocating payloads) or the shared Receiver's Mutex is the bottleneck");
+    report.text("Go's goroutines are cheaper to start, but the channel ops cost about the same");
+    report
+}
+
+// Fast producer, slow consumer: what piles up in between
+pub fn backpressure() -> DemoReport {
+    let mut report = DemoReport::new("Backpressure - Unbounded vs sync_channel(16)");
+
+    let delay = Duration::from_micros(50);
+    report.text(format!("Generator as fast as it can; 2 workers that take {:?} per job", delay));
+
+    let unbounded = run_pipeline(2, None, delay);
+    let bounded = run_pipeline(2, Some(16), delay);
+    let peak_memory = |queued: usize| format!("~{} KiB", queued * PAYLOAD / 1024);
+    report.table(
+        &["Channel", "Most jobs in flight", "Payload memory held", "Time"],
+        vec![
+            vec!["mpsc::channel()".into(), unbounded.max_queued.to_string(), peak_memory(unbounded.max_queued), format!("{:?}", unbounded.elapsed)],
+            vec!["mpsc::sync_channel(16)".into(), bounded.max_queued.to_string(), peak_memory(bounded.max_queued), format!("{:?}", bounded.elapsed)],
+        ],
+    );
+
+    report.gap();
+    report.warn("Unbounded: the generator runs ahead and every queued job holds its payload");
+    report.ok("Bounded: send() blocks when 16 are waiting, so the producer slows to the workers' pace");
+    report.text("Same total time - the work didn't change, only how much sits in memory waiting for it");
+
+    report.step("Go");
+    report.code("out := make(chan Job, 16)  // Go channels are always bounded (0 = unbuffered)");
+    report.text("Go gets backpressure by default; Rust's mpsc::channel() opts out of it");
+    report
+}
+
+pub fn pipelines_vs_go() -> DemoReport {
+    let mut report = DemoReport::new("Pipelines: Go vs Rust");
+
+    report.table(
+        &["", "Go", "Rust"],
+        vec![
+            vec!["stage output".into(), "<-chan T".into(), "Receiver<T>".into()],
+            vec!["end of stream".into(), "close(out)".into(), "last Sender dropped".into()],
+            vec!["fan-out".into(), "N goroutines ranging one chan".into(), "N threads sharing Arc<Mutex<Receiver>>".into()],
+            vec!["fan-in".into(), "merge() + WaitGroup + close".into(), "clone the Sender".into()],
+            vec!["send a slice".into(), "shares the array".into(), "moves the Vec".into()],
+            vec!["default buffer".into(), "bounded (0 = unbuffered)".into(), "unbounded (channel)".into()],
+            vec!["cancellation".into(), "done channel / context".into(), "drop the Receiver: send() errs".into()],
+        ],
+    );
+
+    report.gap();
+    report.ok("Same architecture; Rust's version can't share a job between stages by accident");
+    report.text("crossbeam-channel adds a cloneable Receiver and select! - closer still to Go");
+    report
+}
+
+pub const DEMO: Demo = Demo {
+    name: "mpsc-pipeline",
+    about: "Fan-out/fan-in pipeline over mpsc: moved jobs, throughput, backpressure vs Go pipelines",
+    difficulty: Difficulty::Intermediate,
+    tags: &[Tag::Concurrency, Tag::Ownership, Tag::Benchmark],
+    examples: &[
+        Example { name: "stages", run: three_stages },
+        Example { name: "ownership", run: ownership_transfer },
+        Example { name: "throughput", run: throughput },
+        Example { name: "backpressure", run: backpressure },
+        Example { name: "vs-go", run: pipelines_vs_go },
+    ],
+};
+
+pub fn demonstrate_mpsc_pipeline() -> Vec<DemoReport> {
+    DEMO.run()
+}
@@ -0,0 +1,388 @@
+// Object pools - reuse allocations instead of making new ones
+// Go:   sync.Pool. Get() returns any cached object (or calls New), Put() hands
+//       it back. Per-P caches make it cheap; every GC cycle moves the cache to
+//       a victim list and the next one empties it - a pool is a hint, and
+//       objects may vanish at any time. You Reset() them yourself.
+// Rust: no GC, so nothing purges a pool behind your back. A Vec of idle
+//       objects behind a Mutex is the whole idea; a guard puts the object
+//       back on Drop, so forgetting Put() isn't possible. thread_local!
+//       gives the lock-free per-thread variant (Go's per-P cache).
+
+use std::cell::RefCell;
+use std::hint::black_box;
+use std::ops::{Deref, DerefMut};
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::alloc_stats;
+use crate::registry::{Demo, Difficulty, Example, Tag};
+use crate::report::{format_bytes, DemoReport};
+
+const BUFFER_SIZE: usize = 4096;
+const REQUESTS: usize = 1_000;
+const BENCH_ROUNDS: usize = 100_000;
+
+/// A typed pool: idle objects in a Vec, guarded by a Mutex
+pub struct Pool<T> {
+    idle: Mutex<Vec<T>>,
+    make: fn() -> T,
+    reset: fn(&mut T),
+    max_idle: usize,
+}
+
+impl<T> Pool<T> {
+    /// `make` builds a new object when the pool is empty, `reset` runs on the way back in
+    pub fn new(make: fn() -> T, reset: fn(&mut T)) -> Self {
+        Pool { idle: Mutex::new(Vec::new()), make, reset, max_idle: usize::MAX }
+    }
+
+    /// Keep at most `max_idle` objects; extra ones returned are dropped
+    pub fn with_max_idle(mut self, max_idle: usize) -> Self {
+        self.max_idle = max_idle;
+        self
+    }
+
+    /// An idle object, or a new one. It goes back when the guard drops.
+    pub fn get(&self) -> Pooled<'_, T> {
+        let reused = self.idle.lock().unwrap().pop();
+        Pooled { pool: self, value: Some(reused.unwrap_or_else(self.make)) }
+    }
+
+    fn put(&self, mut value: T) {
+        (self.reset)(&mut value);
+        let mut idle = self.idle.lock().unwrap();
+        if idle.len() < self.max_idle {
+            idle.push(value);
+        }
+    }
+
+    /// Objects waiting to be reused
+    pub fn idle(&self) -> usize {
+        self.idle.lock().unwrap().len()
+    }
+
+    /// Drop every idle object - what Go's GC does to a sync.Pool, but only when you ask
+    pub fn clear(&self) {
+        self.idle.lock().unwrap().clear();
+    }
+}
+
+/// A pooled object; derefs to T and returns to its pool on Drop
+pub struct Pooled<'a, T> {
+    pool: &'a Pool<T>,
+    value: Option<T>,
+}
+
+impl<T> Pooled<'_, T> {
+    /// Keep the object for good - it won't go back to the pool
+    pub fn detach(mut self) -> T {
+        self.value.take().expect("value is only taken once")
+    }
+}
+
+impl<T> Deref for Pooled<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        self.value.as_ref().expect("value is present until drop")
+    }
+}
+
+impl<T> DerefMut for Pooled<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        self.value.as_mut().expect("value is present until drop")
+    }
+}
+
+impl<T> Drop for Pooled<'_, T> {
+    fn drop(&mut self) {
+        if let Some(value) = self.value.take() {
+            self.pool.put(value);
+        }
+    }
+}
+
+/// The buffer pool the examples share: 4 KiB Vec<u8>s, cleared on return
+pub fn buffer_pool() -> Pool<Vec<u8>> {
+    Pool::new(|| Vec::with_capacity(BUFFER_SIZE), Vec::clear)
+}
+
+thread_local! {
+    // One free list per thread: no lock, no sharing
+    static LOCAL_BUFFERS: RefCell<Vec<Vec<u8>>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Run `f` with a buffer from this thread's free list (thread_local variant)
+pub fn with_local_buffer<R>(f: impl FnOnce(&mut Vec<u8>) -> R) -> R {
+    let mut buffer = LOCAL_BUFFERS.with(|buffers| buffers.borrow_mut().pop()).unwrap_or_else(|| Vec::with_capacity(BUFFER_SIZE));
+    let result = f(&mut buffer);
+    buffer.clear();
+    LOCAL_BUFFERS.with(|buffers| buffers.borrow_mut().push(buffer));
+    result
+}
+
+/// Buffers idle in this thread's free list
+pub fn local_idle() -> usize {
+    LOCAL_BUFFERS.with(|buffers| buffers.borrow().len())
+}
+
+/// Free this thread's idle buffers now rather than at thread exit
+pub fn clear_local() {
+    LOCAL_BUFFERS.with(|buffers| buffers.borrow_mut().clear());
+}
+
+// A request handler's work: render a response into a scratch buffer
+fn render(buffer: &mut Vec<u8>, request: usize) -> usize {
+    for line in 0..64 {
+        buffer.extend_from_slice(format!("request {} line {}\n", request, line).as_bytes());
+    }
+    buffer.len()
+}
+
+fn allocations_of<R>(f: impl FnOnce() -> R) -> (R, String) {
+    let (result, stats) = alloc_stats::measure(f);
+    (result, stats.map_or("n/a (needs --features alloc-stats)".to_string(), |stats| stats.allocations.to_string()))
+}
+
+// The same job without format!(): the buffer is the only allocation
+fn render_fixed(buffer: &mut Vec<u8>, request: usize) -> usize {
+    for line in 0..64u8 {
+        buffer.extend_from_slice(&(request as u64).to_le_bytes());
+        buffer.extend_from_slice(&[line; 24]);
+    }
+    buffer.len()
+}
+
+// The same 1,000 requests, fresh buffers vs pooled
+pub fn reuse() -> DemoReport {
+    let mut report = DemoReport::new("Reuse - Fewer Allocations with a Pool");
+
+    report.code("let pool = Pool::new(|| Vec::with_capacity(4096), Vec::clear);");
+    report.code("let mut buffer = pool.get();  // Pooled<Vec<u8>>: derefs to Vec<u8>, goes back on drop");
+    let pool = buffer_pool();
+    let first = {
+        let buffer = pool.get();
+        buffer.as_ptr() as usize
+    };
+    let second = pool.get();
+    report.address("second get()'s buffer", second.as_slice(), format!("same as the first: {}", second.as_ptr() as usize == first));
+    drop(second);
+
+    let (fresh, fresh_allocations) = allocations_of(|| {
+        (0..REQUESTS)
+            .map(|request| {
+                let mut buffer = Vec::with_capacity(BUFFER_SIZE);
+                render_fixed(&mut buffer, request)
+            })
+            .sum::<usize>()
+    });
+    let pool = buffer_pool();
+    let (pooled, pooled_allocations) = allocations_of(|| {
+        (0..REQUESTS)
+            .map(|request| {
+                let mut buffer = pool.get();
+                render_fixed(&mut buffer, request)
+            })
+            .sum::<usize>()
+    });
+    black_box((fresh, pooled));
+
+    report.step(format!("{} requests, one {} scratch buffer each", REQUESTS, format_bytes(BUFFER_SIZE)));
+    report.table(
+        &["strategy", "allocations"],
+        vec![
+            vec!["Vec::with_capacity per request".into(), fresh_allocations],
+            vec!["pool.get() per request".into(), pooled_allocations],
+        ],
+    );
+    report.count("buffers idle in the pool afterwards", pool.idle());
+
+    report.gap();
+    report.ok("One buffer serves every request: get() pops it, the guard's Drop pushes it back");
+    report.ok("Vec::clear keeps the capacity - the 4 KiB is allocated once");
+    report.text("Sequential requests need one buffer; N concurrent ones grow the pool to N");
+    report
+}
+
+// Per-thread free lists: the lock-free variant
+pub fn thread_local_pool() -> DemoReport {
+    let mut report = DemoReport::new("thread_local! Pool - One Free List per Thread");
+
+    report.code("thread_local! { static LOCAL_BUFFERS: RefCell<Vec<Vec<u8>>> = ... }");
+    report.code("with_local_buffer(|buffer| render(buffer, request))  // pop, use, clear, push - no lock");
+
+    let threads = 4;
+    let per_thread = REQUESTS / threads;
+    let (idle, allocations) = allocations_of(|| {
+        thread::scope(|scope| {
+            let workers: Vec<_> = (0..threads)
+                .map(|worker| {
+                    scope.spawn(move || {
+                        for request in 0..per_thread {
+                            with_local_buffer(|buffer| render_fixed(buffer, worker * per_thread + request));
+                        }
+                        local_idle()
+                    })
+                })
+                .collect();
+            workers.into_iter().map(|worker| worker.join().unwrap()).collect::<Vec<_>>()
+        })
+    });
+    report.count("threads", threads);
+    report.count("requests per thread", per_thread);
+    report.text(format!("buffers idle per thread at the end: {:?}", idle));
+    report.text(format!("allocations (buffers + thread startup): {}", allocations));
+
+    report.gap();
+    report.ok("Each thread allocates its buffer once and reuses it - no Mutex on the hot path");
+    report.warn("Buffers die with their thread: a thread-per-request server gets no reuse");
+    report.text("Go's sync.Pool is both at once: a per-P cache, with stealing from other Ps when empty");
+    report
+}
+
+// Go's GC purges pools; Rust pools keep what you give them, so bound them
+pub fn purging() -> DemoReport {
+    let mut report = DemoReport::new("Who Empties the Pool?");
+
+    report.step("Go");
+    report.code("bufPool.Put(buf)  // may be gone after the next GC - or two, with the victim cache");
+    report.code("runtime.GC(); runtime.GC(); bufPool.Get()  // New() again");
+    report.text("Good for garbage-heavy code: an idle pool shrinks by itself");
+
+    report.step("Rust: nothing is purged unless you decide");
+    let pool = buffer_pool().with_max_idle(2);
+    {
+        let burst: Vec<_> = (0..8).map(|_| pool.get()).collect();
+        report.count("buffers out during a burst", burst.len());
+    }
+    report.count("idle after the burst (max_idle = 2)", pool.idle());
+    pool.clear();
+    report.count("idle after pool.clear()", pool.idle());
+    report.text("A cap (or a periodic clear) is the policy Go's GC supplies for you");
+
+    report.step("Reset on the way in");
+    let pool = buffer_pool();
+    {
+        let mut buffer = pool.get();
+        buffer.extend_from_slice(b"secret token");
+    }
+    let buffer = pool.get();
+    report.text(format!("next get() sees len {} (capacity {}): reset ran in put()", buffer.len(), buffer.capacity()));
+    report.code("// Go: forget buf.Reset() and the next Get() reads the last request's bytes");
+    drop(buffer);
+
+    report.step("Don't keep giants");
+    let pool: Pool<Vec<u8>> = Pool::new(Vec::new, |buffer| {
+        buffer.clear();
+        buffer.shrink_to(64 * 1024);  // like fmt's pp cache: oversized buffers aren't kept as-is
+    });
+    {
+        let mut buffer = pool.get();
+        buffer.resize(4 * 1024 * 1024, 0);
+    }
+    let buffer = pool.get();
+    report.text(format!("after one 4 MiB request, the pooled buffer's capacity is {}", format_bytes(buffer.capacity())));
+    drop(buffer);
+
+    report.gap();
+    report.ok("The guard's Drop returns the object - no forgotten Put()");
+    report.ok("Reset and size limits live in the pool, not at every call site");
+    report.warn("A pool that only grows is a leak with good intentions: cap it");
+    report
+}
+
+fn time_rounds(rounds: usize, mut f: impl FnMut(usize) -> usize) -> Duration {
+    let start = Instant::now();
+    for round in 0..rounds {
+        black_box(f(round));
+    }
+    start.elapsed()
+}
+
+// Fresh allocation vs Mutex pool vs thread_local pool
+pub fn benchmark() -> DemoReport {
+    let mut report = DemoReport::new("Benchmark - Pool vs Fresh Allocation");
+
+    let pool = buffer_pool();
+    let fresh = time_rounds(BENCH_ROUNDS, |round| {
+        let mut buffer = Vec::with_capacity(BUFFER_SIZE);
+        render_fixed(&mut buffer, round)
+    });
+    let pooled = time_rounds(BENCH_ROUNDS, |round| render_fixed(&mut pool.get(), round));
+    let local = time_rounds(BENCH_ROUNDS, |round| with_local_buffer(|buffer| render_fixed(buffer, round)));
+    clear_local();  // the main thread lives on; don't leave its buffer behind
+    let formatted_fresh = time_rounds(BENCH_ROUNDS / 10, |round| render(&mut Vec::with_capacity(BUFFER_SIZE), round));
+    let formatted_pooled = time_rounds(BENCH_ROUNDS / 10, |round| render(&mut pool.get(), round));
+
+    let per_round = |elapsed: Duration, rounds: usize| format!("{:.1} ns", elapsed.as_nanos() as f64 / rounds as f64);
+    report.text(format!("{} rounds: get a {} buffer, write 2 KiB into it, give it back", BENCH_ROUNDS, format_bytes(BUFFER_SIZE)));
+    report.table(
+        &["strategy", "total", "per round"],
+        vec![
+            vec!["Vec::with_capacity".into(), format!("{:?}", fresh), per_round(fresh, BENCH_ROUNDS)],
+            vec!["Pool (Mutex<Vec<T>>)".into(), format!("{:?}", pooled), per_round(pooled, BENCH_ROUNDS)],
+            vec!["thread_local! free list".into(), format!("{:?}", local), per_round(local, BENCH_ROUNDS)],
+        ],
+    );
+
+    report.step("When the work itself allocates");
+    report.text(format!("{} rounds of 64 format!() lines each", BENCH_ROUNDS / 10));
+    report.table(
+        &["strategy", "per round"],
+        vec![
+            vec!["fresh buffer".into(), per_round(formatted_fresh, BENCH_ROUNDS / 10)],
+            vec!["pooled buffer".into(), per_round(formatted_pooled, BENCH_ROUNDS / 10)],
+        ],
+    );
+
+    report.gap();
+    report.ok("malloc of a 4 KiB block is already fast - the allocator caches it too");
+    report.ok("An uncontended Mutex costs a couple of atomics; thread_local! costs none");
+    report.warn("Once the work allocates on its own, the pool's saving disappears in the noise");
+    report.text("Measure before pooling: the win is fewer large allocations, and less GC work in Go");
+    report
+}
+
+pub fn pool_vs_go() -> DemoReport {
+    let mut report = DemoReport::new("Object Pools: Go vs Rust");
+
+    report.step("Go");
+    report.code("var bufPool = sync.Pool{New: func() any { return new(bytes.Buffer) }}");
+    report.code("buf := bufPool.Get().(*bytes.Buffer); buf.Reset(); defer bufPool.Put(buf)");
+
+    report.table(
+        &["", "Go sync.Pool", "Rust Pool<T>"],
+        vec![
+            vec!["element type".into(), "any (type assertion on Get)".into(), "T".into()],
+            vec!["return to pool".into(), "Put() by hand".into(), "guard's Drop".into()],
+            vec!["reset".into(), "by the caller, before Put".into(), "reset fn in the pool".into()],
+            vec!["idle objects dropped".into(), "by the GC, any cycle".into(), "never - cap or clear()".into()],
+            vec!["per-core caching".into(), "built in (per-P)".into(), "thread_local! variant".into()],
+            vec!["main payoff".into(), "less garbage for the GC".into(), "fewer malloc/free calls".into()],
+        ],
+    );
+
+    report.gap();
+    report.ok("Same shape; Rust's pool is deterministic, Go's is best-effort by design");
+    report.text("Arenas (`run arena-allocation`) are the other reuse pattern: free everything at once");
+    report
+}
+
+pub const DEMO: Demo = Demo {
+    name: "object-pool",
+    about: "sync.Pool in Rust: a Mutex pool with Drop guards, a thread_local pool, purging and a benchmark",
+    difficulty: Difficulty::Intermediate,
+    tags: &[Tag::Allocation, Tag::Concurrency, Tag::Benchmark],
+    examples: &[
+        Example { name: "reuse", run: reuse },
+        Example { name: "thread-local", run: thread_local_pool },
+        Example { name: "purging", run: purging },
+        Example { name: "benchmark", run: benchmark },
+        Example { name: "vs-go", run: pool_vs_go },
+    ],
+};
+
+pub fn demonstrate_object_pool() -> Vec<DemoReport> {
+    DEMO.run()
+}
@@ -0,0 +1,233 @@
+// Option vs nil - absence in the type, not in the value
+// Go:   every pointer, map, slice, channel, func and interface can be nil, and
+//       nothing in the type says whether this one might be. Forget the check
+//       and the program panics at runtime: "nil pointer dereference".
+// Rust: references and Box are never null. "Might be absent" is a different
+//       type, Option<T>, and you can't reach the T without handling None.
+//       The compiler stores None in the null bit pattern, so Option<&T> is
+//       exactly as big as Go's *T - the safety costs nothing at runtime.
+
+use std::collections::HashMap;
+use std::num::NonZeroU64;
+use std::ptr::NonNull;
+use std::rc::Rc;
+
+use crate::registry::{Demo, Difficulty, Example, Tag};
+use crate::report::DemoReport;
+
+#[derive(Debug)]
+pub struct User {
+    pub id: u32,
+    pub name: String,
+    pub email: Option<String>,
+    pub manager: Option<u32>,
+}
+
+/// A tiny user table: lookups return Option, never a null pointer
+pub struct Directory {
+    users: HashMap<u32, User>,
+}
+
+impl Directory {
+    pub fn sample() -> Self {
+        let users = [
+            User { id: 1, name: "alice".into(), email: Some("alice@example.com".into()), manager: None },
+            User { id: 2, name: "bob".into(), email: None, manager: Some(1) },
+            User { id: 3, name: "carol".into(), email: Some("carol@example.com".into()), manager: Some(2) },
+            User { id: 4, name: "dave".into(), email: Some("dave@example.com".into()), manager: Some(99) },
+        ];
+        Directory { users: users.into_iter().map(|user| (user.id, user)).collect() }
+    }
+
+    pub fn find(&self, id: u32) -> Option<&User> {
+        self.users.get(&id)
+    }
+
+    /// Three lookups that can each come up empty; `?` returns None at the first one
+    pub fn manager_email(&self, id: u32) -> Option<&str> {
+        let user = self.find(id)?;
+        let manager = self.find(user.manager?)?;
+        manager.email.as_deref()
+    }
+}
+
+fn size_row<T>(name: &str, go: &str) -> Vec<String> {
+    vec![name.to_string(), size_of::<T>().to_string(), go.to_string()]
+}
+
+// Option<&T>, Option<Box<T>> and friends are pointer-sized
+pub fn pointer_sized() -> DemoReport {
+    let mut report = DemoReport::new("Option<&T> Is Pointer-Sized");
+
+    report.table(
+        &["Rust type", "size", "Go equivalent"],
+        vec![
+            size_row::<&u64>("&u64", "*uint64 (8, may be nil)"),
+            size_row::<Option<&u64>>("Option<&u64>", "*uint64 (8)"),
+            size_row::<Box<u64>>("Box<u64>", "*uint64 (8, may be nil)"),
+            size_row::<Option<Box<u64>>>("Option<Box<u64>>", "*uint64 (8)"),
+            size_row::<Option<Rc<u64>>>("Option<Rc<u64>>", "-"),
+            size_row::<Option<NonNull<u64>>>("Option<NonNull<u64>>", "unsafe.Pointer (8)"),
+            size_row::<Option<fn()>>("Option<fn()>", "func() (8, may be nil)"),
+            size_row::<Option<&[u8]>>("Option<&[u8]>", "[]byte (24, may be nil)"),
+            size_row::<Option<NonZeroU64>>("Option<NonZeroU64>", "-"),
+            size_row::<Option<u64>>("Option<u64>", "*uint64 or (uint64, bool)"),
+        ],
+    );
+
+    // SAFETY: Option<&T> is guaranteed to have the same layout as a nullable pointer
+    let none_bits = unsafe { std::mem::transmute::<Option<&u64>, usize>(None) };
+    report.text(format!("None::<&u64> as raw bits: {:#x} - it IS the null pointer", none_bits));
+
+    report.gap();
+    report.ok("& and Box can never be null, so None takes the null bit pattern: no tag, no extra byte");
+    report.ok("Same for Rc, NonNull, fn pointers, slices and NonZero integers");
+    report.warn("Option<u64> has no unused bit pattern → 8 bytes of tag + padding, 16 in total");
+    report.text("So there's no performance reason to reach for a raw nullable pointer");
+    report
+}
+
+// ? on Option: early return at the first None
+pub fn question_mark() -> DemoReport {
+    let mut report = DemoReport::new("? on Option - Early Return at the First None");
+
+    let directory = Directory::sample();
+    report.code("fn manager_email(&self, id: u32) -> Option<&str> {");
+    report.code("    let user = self.find(id)?;              // no such user → None");
+    report.code("    let manager = self.find(user.manager?)?; // no manager, or a dangling id → None");
+    report.code("    manager.email.as_deref()                  // manager has no email → None");
+    report.code("}");
+    report.table(
+        &["id", "manager_email(id)", "why"],
+        [(3, "carol → bob, who has no email"), (2, "bob → alice"), (1, "alice has no manager"), (4, "dave's manager 99 doesn't exist"), (42, "no user 42")]
+            .into_iter()
+            .map(|(id, why)| vec![id.to_string(), format!("{:?}", directory.manager_email(id)), why.to_string()])
+            .collect(),
+    );
+
+    report.gap();
+    report.ok("Four ways to be absent, four `?` - and no way to forget one");
+    report.ok("Option::ok_or(err)? turns None into an error when the caller needs to know why");
+
+    report.step("Go");
+    report.code("u := d.Find(id)");
+    report.code("if u == nil || u.Manager == nil { return \"\" }");
+    report.code("m := d.Find(*u.Manager)");
+    report.code("if m == nil { return \"\" }");
+    report.code("return *m.Email  // forgot m.Email == nil → panics when the manager is bob");
+    report.warn("Each check is a line you have to remember; the compiler doesn't know which pointers may be nil");
+    report
+}
+
+// unwrap_or, map, and_then, filter, let-else
+pub fn combinators() -> DemoReport {
+    let mut report = DemoReport::new("unwrap_or, map, and_then - Handling None Inline");
+
+    let directory = Directory::sample();
+    let bob = directory.find(2);
+    let nobody = directory.find(42);
+
+    let rows = vec![
+        vec!["find(42).map(|u| u.name.as_str()).unwrap_or(\"guest\")".into(), format!("{:?}", nobody.map(|user| user.name.as_str()).unwrap_or("guest"))],
+        vec!["find(2).map(|u| u.name.len())".into(), format!("{:?}", bob.map(|user| user.name.len()))],
+        vec!["find(2).and_then(|u| u.email.as_deref())".into(), format!("{:?}", bob.and_then(|user| user.email.as_deref()))],
+        vec!["find(3).and_then(|u| u.email.as_deref())".into(), format!("{:?}", directory.find(3).and_then(|user| user.email.as_deref()))],
+        vec!["find(2).filter(|u| u.manager.is_none()).map(|u| u.id)".into(), format!("{:?}", bob.filter(|user| user.manager.is_none()).map(|user| user.id))],
+        vec!["find(42).map(|u| u.id).ok_or(\"no such user\")".into(), format!("{:?}", nobody.map(|user| user.id).ok_or("no such user"))],
+        vec!["find(2).is_some_and(|u| u.email.is_none())".into(), bob.is_some_and(|user| user.email.is_none()).to_string()],
+    ];
+    report.table(&["Expression", "Result"], rows);
+
+    report.step("Pattern matching");
+    report.code("let Some(user) = directory.find(id) else { return; };  // let-else: bail early, then `user` is a plain &User");
+    report.code("if let Some(email) = &user.email { send(email); }");
+    let Some(user) = directory.find(3) else {
+        report.fail("carol should exist");
+        return report;
+    };
+    if let Some(email) = &user.email {
+        report.text(format!("{} → {}", user.name, email));
+    }
+
+    report.step("Taking a value out");
+    let mut slot = Some(String::from("session token"));
+    let taken = slot.take();
+    report.text(format!("slot.take() → {:?}, slot is now {:?}", taken, slot));
+    let previous = slot.replace(String::from("new token"));
+    report.text(format!("slot.replace(..) → {:?}, slot is now {:?}", previous, slot));
+
+    report.gap();
+    report.ok("Every combinator handles None once, in one place - the value inside is never \"maybe null\"");
+    report.ok("take()/replace() move the value out and leave None behind - no dangling pointer to the old one");
+
+    report.step("Go");
+    report.code("name := \"guest\"; if u != nil { name = u.Name }  // unwrap_or, by hand, every time");
+    report
+}
+
+// The nil panics Go compiles and Rust rejects
+pub fn nil_panics() -> DemoReport {
+    let mut report = DemoReport::new("Go's nil Panics and Their Rust Counterparts");
+
+    report.step("Go");
+    report.code("var u *User;              fmt.Println(u.Name)   // panic: invalid memory address or nil pointer dereference");
+    report.code("var m map[string]int;     m[\"hits\"]++           // panic: assignment to entry in nil map");
+    report.code("var f func();             f()                   // panic: nil pointer dereference");
+    report.code("var err error = (*MyErr)(nil); err != nil       // true! a typed nil inside an interface");
+    report.warn("All four compile - the nil only shows up when that line runs");
+
+    report.step("Rust");
+    report.code("let u: Option<&User> = directory.find(42);");
+    report.code("println!(\"{}\", u.name);   // ❌ error[E0609]: no field `name` on type `Option<&User>`");
+    report.text("Checked by tests/compile_fail/option_field_without_handling.rs");
+    report.code("let mut m: HashMap<&str, i32> = HashMap::new();  // no nil map: an empty one allocates nothing");
+    let mut hits: HashMap<&str, i32> = HashMap::new();
+    *hits.entry("hits").or_insert(0) += 1;
+    report.text(format!("*m.entry(\"hits\").or_insert(0) += 1 → {:?}", hits));
+    report.code("let f: Option<fn()> = None;  // a function that might be missing says so");
+    report.code("let err: Option<Box<dyn Error>> = None;  // None is None - no typed-nil trap");
+
+    report.gap();
+    report.ok("A possibly-absent value has a different type, so using it as present is a compile error");
+    report.ok("unwrap()/expect() still exist - but they're a visible, greppable decision, not an accident");
+    report
+}
+
+pub fn option_vs_go() -> DemoReport {
+    let mut report = DemoReport::new("Absence: Go nil vs Rust Option");
+
+    report.table(
+        &["", "Go", "Rust"],
+        vec![
+            vec!["\"might be absent\"".into(), "any pointer, map, func, interface".into(), "only Option<T>".into()],
+            vec!["forgetting the check".into(), "runtime panic".into(), "compile error".into()],
+            vec!["size of a maybe-pointer".into(), "8 bytes".into(), "8 bytes (null niche)".into()],
+            vec!["maybe-int".into(), "*int or (int, bool)".into(), "Option<i64> (16 bytes, inline)".into()],
+            vec!["chaining lookups".into(), "if x == nil { return } per step".into(), "? per step".into()],
+            vec!["default value".into(), "if/else by hand".into(), "unwrap_or / unwrap_or_default".into()],
+            vec!["asserting presence".into(), "just dereference".into(), "unwrap() / expect(\"why\")".into()],
+        ],
+    );
+
+    report.gap();
+    report.ok("Same machine representation - the difference is that Rust's type system knows which pointers can be null");
+    report
+}
+
+pub const DEMO: Demo = Demo {
+    name: "option-vs-nil",
+    about: "Null safety with Option, the null-pointer niche, ? on Option, and Go's nil panics",
+    difficulty: Difficulty::Beginner,
+    tags: &[Tag::Layout, Tag::Borrowing],
+    examples: &[
+        Example { name: "pointer-sized", run: pointer_sized },
+        Example { name: "question-mark", run: question_mark },
+        Example { name: "combinators", run: combinators },
+        Example { name: "nil-panics", run: nil_panics },
+        Example { name: "vs-go", run: option_vs_go },
+    ],
+};
+
+pub fn demonstrate_option_vs_nil() -> Vec<DemoReport> {
+    DEMO.run()
+}
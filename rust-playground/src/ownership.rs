@@ -0,0 +1,147 @@
+// Core ownership & borrowing examples
+// Single owner, moves, borrows and deterministic cleanup
+
+use crate::registry::{Demo, Difficulty, Example, Tag};
+use crate::report::DemoReport;
+
+#[allow(dead_code)]
+#[derive(Debug)]
+struct User {
+    name: String,
+    age: u32,
+}
+
+// Example 1: Ownership - each value has ONE owner
+pub fn ownership_example() -> DemoReport {
+    let mut report = DemoReport::new("1. Ownership - Single Owner");
+
+    let user = User {
+        name: String::from("Alice"),
+        age: 30,
+    };
+
+    report.address("Owner", &user, format!("{:?}", user));
+    report.ok("Single owner: 'user' owns the data");
+
+    // user goes out of scope here - automatically cleaned up!
+    report
+}
+
+// Example 2: Move semantics - ownership transfers
+pub fn move_example() -> DemoReport {
+    let mut report = DemoReport::new("2. Move Semantics");
+
+    let user1 = User {
+        name: String::from("Bob"),
+        age: 25,
+    };
+
+    report.address("user1 owns", &user1, format!("{:?}", user1));
+
+    let user2 = user1;  // Ownership MOVES to user2
+
+    report.address("user2 owns", &user2, format!("{:?}", user2));
+    report.fail("user1 is no longer valid (moved!)");
+
+    // Uncommenting this would cause a compile error (tests/compile_fail/borrow_after_move.rs):
+    // println!("{:?}", user1);  // ❌ Error: value borrowed after move
+    report
+}
+
+// Example 3: Borrowing - multiple immutable references allowed
+pub fn borrowing_example() -> DemoReport {
+    let mut report = DemoReport::new("3. Borrowing - Immutable References");
+
+    let user = User {
+        name: String::from("Charlie"),
+        age: 35,
+    };
+
+    report.address("Owner", &user, format!("{:?}", user));
+
+    // Multiple immutable borrows are OK!
+    let ref1 = &user;
+    let ref2 = &user;
+    let ref3 = &user;
+
+    report.address("Ref1", ref1, format!("{:?}", ref1));
+    report.address("Ref2", ref2, format!("{:?}", ref2));
+    report.address("Ref3", ref3, format!("{:?}", ref3));
+    report.ok("Multiple immutable borrows allowed");
+    report.ok("All point to same memory (like Go)");
+    report.ok("But owner maintains control!");
+    report
+}
+
+// Example 4: Mutable borrowing - only ONE mutable reference allowed
+pub fn mutable_borrowing_example() -> DemoReport {
+    let mut report = DemoReport::new("4. Mutable Borrowing");
+
+    let mut user = User {
+        name: String::from("Diana"),
+        age: 28,
+    };
+
+    report.text(format!("Original: {:?}", user));
+
+    // Only ONE mutable borrow at a time!
+    let user_ref = &mut user;
+    user_ref.age = 29;
+
+    report.text(format!("After modification: {:?}", user_ref));
+    report.ok("Only ONE mutable borrow at a time");
+    report.ok("Prevents data races at compile-time!");
+
+    // Uncommenting this would cause a compile error (tests/compile_fail/double_mutable_borrow.rs):
+    // let ref2 = &mut user;  // ❌ Error: cannot borrow as mutable more than once
+    report
+}
+
+// Example 5: Deterministic cleanup - no GC needed!
+pub fn deterministic_cleanup() -> DemoReport {
+    let mut report = DemoReport::new("5. Deterministic Cleanup (No GC!)");
+    report.text("Creating users...");
+
+    {
+        let user1 = User {
+            name: String::from("Eve"),
+            age: 40,
+        };
+        report.text(format!("  user1 created: {:?}", user1));
+
+        {
+            let user2 = User {
+                name: String::from("Frank"),
+                age: 45,
+            };
+            report.text(format!("  user2 created: {:?}", user2));
+
+            report.text("  user2 scope ends → cleaned up immediately");
+        } // user2 dropped here - deterministic!
+
+        report.text("  user1 scope ends → cleaned up immediately");
+    } // user1 dropped here - deterministic!
+
+    report.ok("No garbage collector needed");
+    report.ok("Memory freed at end of scope (RAII)");
+    report.ok("Zero runtime overhead!");
+    report
+}
+
+pub const DEMO: Demo = Demo {
+    name: "ownership",
+    about: "Single owner, moves, borrows and deterministic cleanup",
+    difficulty: Difficulty::Beginner,
+    tags: &[Tag::Ownership],
+    examples: &[
+        Example { name: "ownership", run: ownership_example },
+        Example { name: "move", run: move_example },
+        Example { name: "borrowing", run: borrowing_example },
+        Example { name: "mutable-borrowing", run: mutable_borrowing_example },
+        Example { name: "cleanup", run: deterministic_cleanup },
+    ],
+};
+
+pub fn demonstrate_ownership() -> Vec<DemoReport> {
+    DEMO.run()
+}
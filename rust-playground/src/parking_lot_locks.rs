@@ -0,0 +1,235 @@
+// std::sync vs parking_lot - the same locks, measured side by side
+// Go:   sync.Mutex is two 32-bit words (state + semaphore), no poisoning,
+//       spins briefly and then parks the goroutine in the runtime.
+// Rust: std's Mutex is a futex word plus a poison flag; parking_lot's is a
+//       single byte that keeps its waiters in a global hash table of parking
+//       spots. No poisoning, so lock() returns the guard directly. Both run
+//       the rwlock and contention-bench workloads here so the numbers are
+//       from your machine, not a blog post (--features parking_lot).
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+use crate::contention_bench::{self, Strategy, THREAD_COUNTS, TOTAL_INCREMENTS};
+use crate::registry::{Demo, Difficulty, Example, Tag};
+use crate::report::DemoReport;
+use crate::rwlock::{self, READERS, READS_PER_READER, RouteTable, WRITES};
+
+impl RouteTable for parking_lot::Mutex<HashMap<usize, Vec<u64>>> {
+    fn lookup(&self, key: usize) -> u64 {
+        self.lock()[&key].iter().sum()
+    }
+
+    fn update(&self, key: usize, value: u64) {
+        self.lock().insert(key, vec![value; 32]);
+    }
+}
+
+impl RouteTable for parking_lot::RwLock<HashMap<usize, Vec<u64>>> {
+    fn lookup(&self, key: usize) -> u64 {
+        self.read()[&key].iter().sum()
+    }
+
+    fn update(&self, key: usize, value: u64) {
+        self.write().insert(key, vec![value; 32]);
+    }
+}
+
+fn size_row(lock: &str, std_size: usize, parking_lot_size: usize) -> Vec<String> {
+    vec![lock.into(), std_size.to_string(), parking_lot_size.to_string()]
+}
+
+// What each lock costs in bytes before it protects anything
+pub fn sizes() -> DemoReport {
+    let mut report = DemoReport::new("Lock Sizes - std::sync vs parking_lot");
+
+    report.table(
+        &["Type", "std::sync (bytes)", "parking_lot (bytes)"],
+        vec![
+            size_row("Mutex<()>", size_of::<std::sync::Mutex<()>>(), size_of::<parking_lot::Mutex<()>>()),
+            size_row("Mutex<u8>", size_of::<std::sync::Mutex<u8>>(), size_of::<parking_lot::Mutex<u8>>()),
+            size_row("Mutex<u64>", size_of::<std::sync::Mutex<u64>>(), size_of::<parking_lot::Mutex<u64>>()),
+            size_row("RwLock<()>", size_of::<std::sync::RwLock<()>>(), size_of::<parking_lot::RwLock<()>>()),
+            size_row("RwLock<u64>", size_of::<std::sync::RwLock<u64>>(), size_of::<parking_lot::RwLock<u64>>()),
+            size_row("Condvar", size_of::<std::sync::Condvar>(), size_of::<parking_lot::Condvar>()),
+        ],
+    );
+
+    report.gap();
+    report.text("std Mutex: a u32 futex word + a poison bool, padded to the data's alignment");
+    report.text("parking_lot Mutex: one byte (locked + parked bits); waiters live in a global table");
+    report.ok(format!(
+        "10,000 per-item Mutex<u8> locks: {} KB with parking_lot vs {} KB with std",
+        10_000 * size_of::<parking_lot::Mutex<u8>>() / 1000,
+        10_000 * size_of::<std::sync::Mutex<u8>>() / 1000
+    ));
+    report.warn("Alignment eats the difference once the data is a u64 or bigger");
+
+    report.step("Go");
+    report.code("unsafe.Sizeof(sync.Mutex{})    // 8: state int32 + sema uint32");
+    report.code("unsafe.Sizeof(sync.RWMutex{})  // 24");
+    report
+}
+
+// The contention-bench counter on a std Mutex and a parking_lot Mutex
+pub fn counter_contention() -> DemoReport {
+    let mut report = DemoReport::new("Shared Counter - std Mutex vs parking_lot Mutex (ns per increment)");
+
+    report.count("Increments per run", TOTAL_INCREMENTS);
+    report.count("CPUs available", thread::available_parallelism().map_or(1, |n| n.get()));
+    if cfg!(debug_assertions) {
+        report.warn("Debug build - use --release for real numbers");
+    }
+
+    let rows = THREAD_COUNTS
+        .iter()
+        .map(|&threads| {
+            let std_mutex = contention_bench::run(Strategy::Mutex, threads, TOTAL_INCREMENTS);
+            let per_thread = TOTAL_INCREMENTS / threads;
+            let counter = Arc::new(parking_lot::Mutex::new(0u64));
+            let shared = Arc::clone(&counter);
+            let elapsed = contention_bench::timed(threads, move || {
+                for _ in 0..per_thread {
+                    *shared.lock() += 1;
+                }
+            });
+            let ns = |elapsed: Duration| elapsed.as_nanos() as f64 / (per_thread * threads) as f64;
+            let ratio = std_mutex.elapsed.as_secs_f64() / elapsed.as_secs_f64();
+            vec![
+                threads.to_string(),
+                format!("{:.1}", std_mutex.ns_per_increment()),
+                format!("{:.1}", ns(elapsed)),
+                format!("{:.2}x", ratio),
+            ]
+        })
+        .collect();
+    report.table(&["Threads", "std::sync::Mutex", "parking_lot::Mutex", "parking_lot speedup"], rows);
+
+    report.gap();
+    report.text("Uncontended, both are one compare-and-swap - expect a tie at 1 thread");
+    report.text("Contended, parking_lot spins adaptively before parking and hands off fairly every ~0.5ms");
+    report.warn("Linux's futex-based std Mutex (Rust 1.62+) closed most of the gap - measure, don't assume");
+    report
+}
+
+// The rwlock demo's read-heavy route table on all four locks
+pub fn read_heavy() -> DemoReport {
+    let mut report = DemoReport::new("Read-Heavy Workload - std vs parking_lot, Mutex vs RwLock");
+
+    let reads = READERS * READS_PER_READER;
+    report.count("Reader threads", READERS);
+    report.count("Lookups", reads);
+    report.count("Writes", WRITES);
+
+    let runs = [
+        ("std::sync::Mutex", rwlock::read_heavy_workload(Arc::new(std::sync::Mutex::new(rwlock::routes())))),
+        ("std::sync::RwLock", rwlock::read_heavy_workload(Arc::new(std::sync::RwLock::new(rwlock::routes())))),
+        ("parking_lot::Mutex", rwlock::read_heavy_workload(Arc::new(parking_lot::Mutex::new(rwlock::routes())))),
+        ("parking_lot::RwLock", rwlock::read_heavy_workload(Arc::new(parking_lot::RwLock::new(rwlock::routes())))),
+    ];
+    let fastest = runs.iter().map(|&(_, elapsed)| elapsed).min().unwrap();
+    report.table(
+        &["Lock", "Time", "Lookups/s", "vs fastest"],
+        runs.iter()
+            .map(|&(lock, elapsed)| {
+                vec![
+                    lock.into(),
+                    format!("{:?}", elapsed),
+                    format!("{:.0}", reads as f64 / elapsed.as_secs_f64()),
+                    format!("{:.2}x", elapsed.as_secs_f64() / fastest.as_secs_f64()),
+                ]
+            })
+            .collect(),
+    );
+
+    report.gap();
+    report.text("Same RouteTable trait and workload as run rwlock -e throughput");
+    report.text("Mutex vs RwLock usually matters more than std vs parking_lot");
+    report
+}
+
+// What changes in the code, not just the numbers
+pub fn api_differences() -> DemoReport {
+    let mut report = DemoReport::new("API Differences - No Poisoning, No unwrap()");
+
+    report.step("std");
+    report.code("let mut n = counter.lock().unwrap();  // Result: the lock may be poisoned");
+    report.step("parking_lot");
+    report.code("let mut n = counter.lock();  // the guard, always");
+
+    let counter = Arc::new(parking_lot::Mutex::new(0));
+    let shared = Arc::clone(&counter);
+    let previous_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(|_| {}));
+    let joined = thread::spawn(move || {
+        let mut guard = shared.lock();
+        *guard += 1;
+        panic!("panicked while holding the lock");
+    })
+    .join();
+    std::panic::set_hook(previous_hook);
+    if joined.is_err() {
+        report.fail("a thread panicked while holding the parking_lot Mutex");
+    }
+    report.text(format!("counter.lock() afterwards = {} - unlocked, not poisoned", *counter.lock()));
+    report.warn("Like Go: the half-done update is visible to the next locker, with no warning");
+
+    report.step("Extras std doesn't have");
+    let locked = counter.try_lock_for(Duration::from_millis(10)).is_some();
+    report.code("counter.try_lock_for(Duration::from_millis(10))  // timed lock");
+    report.text(format!("→ acquired: {}", locked));
+    report.code("let guard = lock.upgradable_read(); let w = RwLockUpgradableReadGuard::upgrade(guard);");
+    report.code("MutexGuard::unlock_fair(guard)  // hand the lock straight to the next waiter");
+    report.text("Cargo feature deadlock_detection: a background check for lock cycles (run deadlock)");
+
+    report.gap();
+    report.ok("std::sync::Mutex::new is const too (1.63+), so statics need neither crate");
+    report.text("Pick parking_lot for size, timeouts or upgradable reads - not by habit");
+    report
+}
+
+pub fn parking_lot_vs_go() -> DemoReport {
+    let mut report = DemoReport::new("Locks in Go, std and parking_lot");
+
+    report.table(
+        &["", "Go sync.Mutex", "std::sync::Mutex", "parking_lot::Mutex"],
+        vec![
+            vec![
+                "size (no data)".into(),
+                "8 bytes".into(),
+                format!("{} bytes", size_of::<std::sync::Mutex<()>>()),
+                format!("{} byte", size_of::<parking_lot::Mutex<()>>()),
+            ],
+            vec!["waiting".into(), "spin, then park goroutine".into(), "spin, then futex".into(), "adaptive spin, then park".into()],
+            vec!["fairness".into(), "starvation mode after 1ms".into(), "unspecified".into(), "eventual, every ~0.5ms".into()],
+            vec!["panic while locked".into(), "unlocked by defer".into(), "poisoned".into(), "unlocked".into()],
+            vec!["timed lock".into(), "no".into(), "no".into(), "try_lock_for".into()],
+            vec!["where".into(), "stdlib".into(), "stdlib".into(), "crate".into()],
+        ],
+    );
+
+    report.gap();
+    report.ok("parking_lot and Go agree on poisoning: there isn't any");
+    report.text("std keeps poisoning as a seatbelt for half-finished updates");
+    report
+}
+
+pub const DEMO: Demo = Demo {
+    name: "parking-lot",
+    about: "std::sync vs parking_lot: lock sizes, counter contention and the read-heavy workload",
+    difficulty: Difficulty::Advanced,
+    tags: &[Tag::Concurrency, Tag::Layout, Tag::Benchmark],
+    examples: &[
+        Example { name: "sizes", run: sizes },
+        Example { name: "contention", run: counter_contention },
+        Example { name: "read-heavy", run: read_heavy },
+        Example { name: "api", run: api_differences },
+        Example { name: "vs-go", run: parking_lot_vs_go },
+    ],
+};
+
+pub fn demonstrate_parking_lot_locks() -> Vec<DemoReport> {
+    DEMO.run()
+}
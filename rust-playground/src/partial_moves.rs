@@ -0,0 +1,212 @@
+// Partial moves - taking one field out of a struct
+// Go:   `n := u.Name` copies the string header; `v := u` copies the whole
+//       struct, field by field. Both stay usable, and every slice, map and
+//       pointer field is now shared by the two copies.
+// Rust: `let name = user.name;` moves just that field. The compiler tracks
+//       ownership per field: the other fields stay usable, `user` as a whole
+//       doesn't - until the hole is filled again. Destructuring and struct
+//       update syntax (`..base`) are partial moves too. Types with a Drop
+//       impl can't be taken apart at all: their drop() needs every field.
+
+use std::mem;
+
+use crate::registry::{Demo, Difficulty, Example, Tag};
+use crate::report::DemoReport;
+
+#[derive(Debug, Clone, Default)]
+pub struct User {
+    pub id: u32,
+    pub name: String,
+    pub email: String,
+    pub roles: Vec<String>,
+}
+
+impl User {
+    pub fn sample() -> User {
+        User { id: 7, name: "gopher".into(), email: "gopher@example.com".into(), roles: vec!["admin".into(), "dev".into()] }
+    }
+
+    pub fn describe(&self) -> String {
+        format!("#{} {} <{}> {:?}", self.id, self.name, self.email, self.roles)
+    }
+}
+
+/// Flushes on drop - and that's why its fields can't be moved out
+#[derive(Debug)]
+pub struct LogFile {
+    pub path: String,
+    pub lines: Vec<String>,
+}
+
+impl Drop for LogFile {
+    fn drop(&mut self) {
+        // Would write self.lines to self.path; both must still be there
+        let _ = (&self.path, self.lines.len());
+    }
+}
+
+// Moving one field out; the rest stays
+pub fn field_move() -> DemoReport {
+    let mut report = DemoReport::new("Moving One Field Out");
+
+    let mut user = User::sample();
+    report.address("user.name's buffer before", user.name.as_str(), format!("{:?}", user.name));
+    report.code("let name = user.name;  // moves the String; user.name is now a hole");
+    let name = user.name;
+    report.address("name's buffer after", name.as_str(), format!("{:?}", name));
+    report.ok("Same buffer - only the 24-byte String header moved, nothing was copied");
+
+    report.step("What's still usable");
+    report.code("user.id, user.email, user.roles  // ✓ untouched fields");
+    report.text(format!("id = {}, email = {:?}, roles = {:?}", user.id, user.email, user.roles));
+    report.code("user.describe()  // ❌ error[E0382]: borrow of partially moved value: `user`");
+    report.text("Checked by tests/compile_fail/partial_move_then_method.rs - &self needs every field");
+    report.code("send(user);      // ❌ error[E0382]: use of partially moved value: `user`");
+    report.text("Checked by tests/compile_fail/request_partially_moved.rs");
+
+    report.step("Filling the hole");
+    report.code("user.name = String::from(\"ferris\");  // assigning the field makes `user` whole again");
+    user.name = String::from("ferris");
+    report.text(user.describe());
+
+    report.gap();
+    report.ok("Ownership is tracked per field, at compile time - no runtime flag says \"moved\"");
+    report.text("At the end of scope only the fields still owned are dropped; `name` drops separately");
+    report
+}
+
+// let/match patterns move, copy or borrow field by field
+pub fn destructuring() -> DemoReport {
+    let mut report = DemoReport::new("Destructuring - Partial Moves in Patterns");
+
+    report.code("let User { name, roles, .. } = user;  // moves name and roles; id and email stay in user");
+    let user = User::sample();
+    let User { name, roles, .. } = user;
+    report.text(format!("name = {:?}, roles = {:?}, user.email still = {:?}", name, roles, user.email));
+
+    report.step("Borrowing in a pattern");
+    let user = User::sample();
+    report.code("let User { ref name, id, .. } = user;  // ref borrows, id (u32) copies - nothing moves");
+    let User { ref name, id, .. } = user;
+    report.text(format!("name = {:?}, id = {} - and user is still whole: {}", name, id, user.describe()));
+    report.code("let User { name, .. } = &user;           // same thing: matching through & binds references");
+    let User { name, .. } = &user;
+    report.text(format!("name: &String = {:?}", name));
+
+    report.step("match with a guard");
+    let user = User::sample();
+    let first_role = match user {
+        User { mut roles, .. } if !roles.is_empty() => roles.swap_remove(0),  // moves roles into the arm
+        _ => String::from("guest"),
+    };
+    report.code("match user { User { mut roles, .. } if !roles.is_empty() => roles.swap_remove(0), _ => \"guest\" }");
+    report.text(format!("first role = {:?}, user.name still = {:?}", first_role, user.name));
+
+    report.step("Closures capture fields, not structs (edition 2021)");
+    let mut user = User::sample();
+    let mut add_role = |role: &str| user.roles.push(role.to_string());  // captures &mut user.roles only
+    add_role("ops");
+    let email = &user.email;  // ✓ a different field - no conflict with the closure
+    add_role("oncall");
+    report.code("let mut add_role = |r: &str| user.roles.push(r.into());  // borrows user.roles mutably");
+    report.code("let email = &user.email;                               // ✓ disjoint field, allowed meanwhile");
+    report.text(format!("email = {:?}, roles = {:?}", email, user.roles));
+
+    report.gap();
+    report.ok("A pattern decides per field: move (by value), copy (Copy types), or borrow (ref / matching on &)");
+    report.ok("Disjoint fields are independent places - borrows and moves of one don't touch the others");
+    report
+}
+
+// ..base moves whatever it fills in
+pub fn struct_update() -> DemoReport {
+    let mut report = DemoReport::new("Struct Update Syntax - ..base Moves Too");
+
+    let base = User::sample();
+    report.code("let copy = User { id: 8, email: \"new@example.com\".into(), ..base };");
+    let copy = User { id: 8, email: "new@example.com".into(), ..base };
+    report.text(copy.describe());
+    report.text(format!("base.id = {} and base.email = {:?} still usable: they weren't taken", base.id, base.email));
+    report.code("println!(\"{}\", base.name);  // ❌ error[E0382]: borrow of moved value: `base.name`");
+    report.text("Checked by tests/compile_fail/struct_update_moves_base.rs - ..base moved name and roles into `copy`");
+
+    report.step("Ways to keep the base");
+    report.code("User { id: 9, ..base.clone() }        // pay for a deep copy, explicitly");
+    report.code("User { id: 9, ..Default::default() }  // the base is a temporary - moving out of it is free");
+    let fresh = User { id: 9, ..Default::default() };
+    report.text(format!("{:?}", fresh));
+    report.code("User { name: mem::take(&mut base.name), ..}  // take one field, leave \"\" behind");
+    let mut template = User::sample();
+    let taken = User { id: 10, name: mem::take(&mut template.name), ..Default::default() };
+    report.text(format!("taken.name = {:?}, template.name = {:?}", taken.name, template.name));
+
+    report.gap();
+    report.ok("..base is shorthand for `field: base.field` for every field not listed - the usual move rules apply");
+    report.ok("Copy fields (id: u32) are copied, so base keeps them; String/Vec fields are moved");
+    report
+}
+
+// A Drop impl needs the whole value, so no partial moves out of it
+pub fn drop_types() -> DemoReport {
+    let mut report = DemoReport::new("Types with Drop Can't Be Taken Apart");
+
+    report.code("impl Drop for LogFile { fn drop(&mut self) { /* write self.lines to self.path */ } }");
+    report.code("let lines = log.lines;  // ❌ error[E0509]: cannot move out of type `LogFile`, which implements the `Drop` trait");
+    report.text("Checked by tests/compile_fail/partial_move_out_of_drop_type.rs");
+    report.text("drop() gets &mut self with every field - there's no way to run it on a struct with a hole");
+
+    report.step("The workarounds");
+    let mut log = LogFile { path: "/var/log/app.log".into(), lines: vec!["started".into(), "ready".into()] };
+    report.code("let lines = mem::take(&mut log.lines);  // leave an empty Vec; drop() still sees a valid LogFile");
+    let lines = mem::take(&mut log.lines);
+    report.text(format!("lines = {:?}, log = {:?}", lines, log));
+    report.text("Or store Option<T> and .take() it, or add an into_parts(self) method that does the same inside");
+
+    report.gap();
+    report.ok("Partial moves are for plain data; a type with a destructor stays in one piece");
+    report
+}
+
+pub fn partial_moves_vs_go() -> DemoReport {
+    let mut report = DemoReport::new("Taking Fields: Go vs Rust");
+
+    report.step("Go");
+    report.code("name := u.Name    // copies the string header; u.Name still readable");
+    report.code("v := u            // copies every field: v.Roles and u.Roles share one array");
+    report.code("v.Roles[0] = \"x\"  // ...so this changes u.Roles[0] too");
+    report.code("v := User{ID: 8}  // no update syntax: list the fields, the rest are zero values");
+
+    report.table(
+        &["", "Go", "Rust"],
+        vec![
+            vec!["take a field".into(), "copy its header, both usable".into(), "move it, the struct has a hole".into()],
+            vec!["copy a struct".into(), "shallow: slices/maps/pointers shared".into(), "move, or .clone() (deep)".into()],
+            vec!["use the rest".into(), "always".into(), "other fields yes, the whole no".into()],
+            vec!["fill from another".into(), "-".into(), "..base (moves what it fills)".into()],
+            vec!["finalizers / Drop".into(), "no effect on field access".into(), "no partial moves at all".into()],
+        ],
+    );
+
+    report.gap();
+    report.ok("Go never leaves a hole because it never moves - it shares");
+    report.ok("Rust leaves a hole instead of sharing, and makes you fill it or stop using the whole");
+    report
+}
+
+pub const DEMO: Demo = Demo {
+    name: "partial-moves",
+    about: "Moving fields out of structs: per-field ownership, destructuring, ..base updates, Drop types",
+    difficulty: Difficulty::Intermediate,
+    tags: &[Tag::Ownership],
+    examples: &[
+        Example { name: "field-move", run: field_move },
+        Example { name: "destructuring", run: destructuring },
+        Example { name: "struct-update", run: struct_update },
+        Example { name: "drop-types", run: drop_types },
+        Example { name: "vs-go", run: partial_moves_vs_go },
+    ],
+};
+
+pub fn demonstrate_partial_moves() -> Vec<DemoReport> {
+    DEMO.run()
+}
@@ -0,0 +1,272 @@
+// PhantomData and variance - type parameters that hold no data
+// Go:   a generic type may ignore its type parameter: `type ID[T any] int64`
+//       compiles, and ID[User] / ID[Order] are distinct types. Go has no
+//       lifetimes and no destructors, so an unused parameter means nothing
+//       else. (Before 1.18: one named type per ID, or interface{} boxing.)
+// Rust: an unused parameter is an error (E0392) - the compiler derives three
+//       things from how T is used: variance (may Id<&'static str> stand in
+//       for Id<&'a str>?), drop check (does dropping this drop a T?) and auto
+//       traits (Send/Sync). PhantomData<X> says "act as if I stored an X":
+//       zero bytes at runtime, all three answers at compile time.
+
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+
+use crate::alloc_stats;
+use crate::registry::{Demo, Difficulty, Example, Tag};
+use crate::report::DemoReport;
+
+/// A u64 that knows what it identifies. `fn() -> T` keeps Id Send + Sync and
+/// covariant whatever T is, without claiming to own a T.
+pub struct Id<T> {
+    raw: u64,
+    _entity: PhantomData<fn() -> T>,
+}
+
+impl<T> Id<T> {
+    pub const fn new(raw: u64) -> Self {
+        Id { raw, _entity: PhantomData }
+    }
+
+    pub fn raw(self) -> u64 {
+        self.raw
+    }
+}
+
+// Written out: #[derive] would demand T: Clone, T: PartialEq... for a u64
+impl<T> Clone for Id<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Id<T> {}
+
+impl<T> PartialEq for Id<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.raw == other.raw
+    }
+}
+
+impl<T> Eq for Id<T> {}
+
+impl<T> Hash for Id<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.raw.hash(state);
+    }
+}
+
+impl<T> fmt::Debug for Id<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let entity = std::any::type_name::<T>().rsplit("::").next().unwrap_or("?");
+        write!(f, "Id<{}>({})", entity, self.raw)
+    }
+}
+
+#[derive(Debug)]
+pub struct User {
+    pub name: String,
+}
+
+#[derive(Debug)]
+pub struct Order {
+    pub user: Id<User>,
+    pub total_cents: u64,
+}
+
+/// Two tables whose keys can't be mixed up
+#[derive(Debug, Default)]
+pub struct Store {
+    users: HashMap<Id<User>, User>,
+    orders: HashMap<Id<Order>, Order>,
+}
+
+impl Store {
+    pub fn user(&self, id: Id<User>) -> Option<&User> {
+        self.users.get(&id)
+    }
+
+    pub fn order(&self, id: Id<Order>) -> Option<&Order> {
+        self.orders.get(&id)
+    }
+
+    /// Follows an order to its user: the types say which table each id belongs to
+    pub fn buyer(&self, order: Id<Order>) -> Option<&User> {
+        self.user(self.order(order)?.user)
+    }
+}
+
+/// A lifetime-carrying marker, covariant like &'a T
+pub struct Covariant<T>(PhantomData<T>);
+
+/// Cell<T> is invariant in T, and so is anything holding PhantomData<Cell<T>>
+pub struct Invariant<T>(PhantomData<Cell<T>>);
+
+fn allocations_of<R>(f: impl FnOnce() -> R) -> (R, String) {
+    let (result, stats) = alloc_stats::measure(f);
+    (result, stats.map_or("n/a (needs --features alloc-stats)".to_string(), |stats| stats.allocations.to_string()))
+}
+
+// Typed IDs: same u64, different types
+pub fn typed_ids() -> DemoReport {
+    let mut report = DemoReport::new("Typed IDs - Id<User> vs Id<Order>");
+
+    report.code("struct Id<T> { raw: u64, _entity: PhantomData<fn() -> T> }");
+    let alice = Id::<User>::new(1);
+    let order = Id::<Order>::new(1);
+    let mut store = Store::default();
+    store.users.insert(alice, User { name: "alice".into() });
+    store.orders.insert(order, Order { user: alice, total_cents: 4_200 });
+
+    report.text(format!("alice = {:?}, order = {:?} - same raw value {}", alice, order, alice.raw()));
+    report.text(format!("store.buyer(order) = {:?}", store.buyer(order).map(|user| &user.name)));
+    report.text(format!("store.order(order).total_cents = {:?}", store.order(order).map(|order| order.total_cents)));
+    report.code("store.order(alice)  // ❌ error[E0308]: mismatched types: expected `Id<Order>`, found `Id<User>`");
+    report.text("Checked by tests/compile_fail/typed_id_mismatch.rs");
+
+    report.step("Why PhantomData at all");
+    report.code("struct Id<T> { raw: u64 }  // ❌ error[E0392]: type parameter `T` is never used");
+    report.text("Checked by tests/compile_fail/unused_type_parameter.rs");
+    report.text("Rust infers variance, drop check and Send/Sync from the fields; with no field mentioning T");
+    report.text("there's nothing to infer from. PhantomData<fn() -> T> answers: covariant, owns no T, always Send + Sync");
+
+    report.gap();
+    report.ok("Mixing up two u64 keys is now a type error, and Id<T> is still a Copy u64 in a register");
+    report.text("Clone/Copy/Eq/Hash are written by hand: derive would add `T: Clone` etc. - User isn't Clone");
+    report
+}
+
+// PhantomData costs nothing
+pub fn zero_sized() -> DemoReport {
+    let mut report = DemoReport::new("Zero-Sized - the Proof");
+
+    report.table(
+        &["type", "size_of", "align_of"],
+        vec![
+            vec!["PhantomData<String>".into(), size_of::<PhantomData<String>>().to_string(), align_of::<PhantomData<String>>().to_string()],
+            vec!["PhantomData<[u64; 1024]>".into(), size_of::<PhantomData<[u64; 1024]>>().to_string(), align_of::<PhantomData<[u64; 1024]>>().to_string()],
+            vec!["u64".into(), size_of::<u64>().to_string(), align_of::<u64>().to_string()],
+            vec!["Id<User>".into(), size_of::<Id<User>>().to_string(), align_of::<Id<User>>().to_string()],
+            vec!["Id<[u64; 1024]>".into(), size_of::<Id<[u64; 1024]>>().to_string(), align_of::<Id<[u64; 1024]>>().to_string()],
+            vec!["Option<Id<User>>".into(), size_of::<Option<Id<User>>>().to_string(), align_of::<Option<Id<User>>>().to_string()],
+        ],
+    );
+
+    report.step("A million of them");
+    let (markers, allocations) = allocations_of(|| vec![PhantomData::<String>; 1_000_000]);
+    report.count("Vec<PhantomData<String>> len", markers.len());
+    report.text(format!("allocations to build it: {}", allocations));
+
+    report.gap();
+    report.ok("PhantomData<X> has size 0 and alignment 1 whatever X is - Id<[u64; 1024]> is still 8 bytes");
+    report.ok("A Vec of ZSTs never allocates: it only counts");
+    report.text("Option<Id<User>> is 16: u64 has no spare bit pattern for None (see `run option-vs-nil`)");
+    report
+}
+
+// Variance: which substitutions of T are allowed
+pub fn variance() -> DemoReport {
+    let mut report = DemoReport::new("Variance - What PhantomData<X> Promises");
+
+    report.code("fn shorten<'a>(x: Covariant<&'static str>) -> Covariant<&'a str> { x }  // ✓");
+    fn shorten<'a>(x: Covariant<&'static str>) -> Covariant<&'a str> {
+        x
+    }
+    let _ = shorten(Covariant(PhantomData));
+    report.text("A longer-lived &'static str may stand in for a &'a str: Covariant<T> follows T");
+    report.code("fn shorten<'a>(x: Invariant<&'static str>) -> Invariant<&'a str> { x }  // ❌ error: lifetime may not live long enough");
+    report.text("Checked by tests/compile_fail/invariant_phantom_lifetime.rs");
+    report.text("Through a Cell you could write a short-lived &str into a slot still typed &'static - so no substitution at all");
+
+    report.step("Picking the marker");
+    report.table(
+        &["marker", "variance in T", "owns a T (drop check)", "Send/Sync"],
+        vec![
+            vec!["PhantomData<T>".into(), "covariant".into(), "yes".into(), "like T".into()],
+            vec!["PhantomData<&'a T>".into(), "covariant".into(), "no".into(), "like &T".into()],
+            vec!["PhantomData<*const T>".into(), "covariant".into(), "no".into(), "neither".into()],
+            vec!["PhantomData<fn() -> T>".into(), "covariant".into(), "no".into(), "always both".into()],
+            vec!["PhantomData<fn(T)>".into(), "contravariant".into(), "no".into(), "always both".into()],
+            vec!["PhantomData<Cell<T>> / <fn(T) -> T>".into(), "invariant".into(), "yes / no".into(), "never Sync / both".into()],
+        ],
+    );
+
+    report.gap();
+    report.ok("Id<T> uses fn() -> T: IDs don't own users, and an Id<Rc<..>> should still cross threads");
+    report.ok("A raw-pointer container (your own Vec<T>) uses PhantomData<T>: it does own its elements");
+    report
+}
+
+// Drop check: PhantomData<T> says "dropping me drops T"
+#[allow(clippy::needless_late_init)]  // declaring `held` before `name` is the point
+pub fn drop_check() -> DemoReport {
+    let mut report = DemoReport::new("Drop Check - Does Dropping Me Touch T?");
+
+    report.code("struct Inspector<'a>(&'a String);");
+    report.code("impl Drop for Inspector<'_> { fn drop(&mut self) { println!(\"{}\", self.0) } }");
+    report.code("let inspector; let name = String::from(\"x\"); inspector = Inspector(&name);");
+    report.code("// ❌ error[E0597]: `name` does not live long enough - inspector is dropped after name and reads it");
+    report.text("Checked by tests/compile_fail/drop_check_inspector.rs");
+
+    report.step("The same shape with a plain reference is fine");
+    let held: Vec<&String>;
+    let name = String::from("gopher");
+    held = vec![&name];
+    report.code("let held: Vec<&String>; let name = String::from(\"gopher\"); held = vec![&name];  // ✓");
+    report.text(format!("held = {:?} - dropped after name, and that's allowed", held));
+    report.text("Vec's Drop is marked #[may_dangle] (nightly-only): \"I won't look at T, I'll only drop it\"");
+    report.text("Its PhantomData<T> completes the sentence: \"...and dropping T is part of dropping me\"");
+    report.text("&String has no drop glue, so a dangling &String inside the Vec is never touched - accepted.");
+    report.text("Inspector does have a Drop that reads - rejected, in a Vec or not");
+
+    report.gap();
+    report.ok("Owning containers built on raw pointers need PhantomData<T> so drop check sees the T they own");
+    report.text("Without it, a may_dangle container could drop a T that borrows freed data - unsound");
+    report
+}
+
+pub fn phantom_vs_go() -> DemoReport {
+    let mut report = DemoReport::new("Phantom Types: Go vs Rust");
+
+    report.step("Go");
+    report.code("type ID[T any] int64  // T unused - allowed");
+    report.code("var u ID[User] = 1; var o ID[Order] = u  // ❌ cannot use u (ID[User]) as ID[Order]");
+    report.text("Go gets typed IDs for free: no lifetimes to vary, no destructors to check");
+
+    report.table(
+        &["", "Go", "Rust"],
+        vec![
+            vec!["unused type parameter".into(), "allowed".into(), "E0392 - add PhantomData".into()],
+            vec!["typed IDs".into(), "ID[User] / ID[Order]".into(), "Id<User> / Id<Order>".into()],
+            vec!["runtime cost".into(), "none (8 bytes)".into(), "none (8 bytes)".into()],
+            vec!["variance".into(), "no subtyping of generics".into(), "chosen by the marker".into()],
+            vec!["drop check".into(), "no destructors".into(), "PhantomData<T> = owns a T".into()],
+            vec!["thread safety".into(), "unchecked".into(), "Send/Sync follow the marker".into()],
+        ],
+    );
+
+    report.gap();
+    report.ok("Same zero-cost newtype in both; Rust's marker also carries lifetime and ownership facts");
+    report
+}
+
+pub const DEMO: Demo = Demo {
+    name: "phantomdata-variance",
+    about: "PhantomData: typed Id<User>/Id<Order>, zero-sized proof, variance markers and drop check",
+    difficulty: Difficulty::Advanced,
+    tags: &[Tag::Ownership, Tag::Layout],
+    examples: &[
+        Example { name: "typed-ids", run: typed_ids },
+        Example { name: "zero-sized", run: zero_sized },
+        Example { name: "variance", run: variance },
+        Example { name: "drop-check", run: drop_check },
+        Example { name: "vs-go", run: phantom_vs_go },
+    ],
+};
+
+pub fn demonstrate_phantomdata_variance() -> Vec<DemoReport> {
+    DEMO.run()
+}
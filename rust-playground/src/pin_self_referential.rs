@@ -0,0 +1,170 @@
+// Pin and self-referential structs
+// A struct that points into ITSELF breaks when it moves: the pointer keeps
+// the old address. Rust moves values freely (memcpy), so this needs a rule:
+// Pin<P> promises the pointee won't move again, and PhantomPinned opts a
+// type out of Unpin so safe code can't take that promise back.
+// Go: values never move once the GC has them - `&x` stays valid forever,
+// so self-references just work (at the cost of the GC tracking them).
+
+use std::marker::PhantomPinned;
+use std::pin::Pin;
+use std::ptr;
+
+use crate::registry::{Demo, Difficulty, Example, Tag};
+use crate::report::DemoReport;
+
+// Naive: `cursor` points at `buffer`, inside the same struct
+struct Parser {
+    buffer: [u8; 16],
+    cursor: *const u8,  // Meant to point into `buffer`
+}
+
+impl Parser {
+    fn new(text: &[u8]) -> Self {
+        let mut buffer = [0; 16];
+        buffer[..text.len()].copy_from_slice(text);
+        Parser { buffer, cursor: ptr::null() }
+    }
+
+    // Must be called AFTER the parser is in its final place
+    fn init(&mut self) {
+        self.cursor = self.buffer.as_ptr();
+    }
+
+    fn cursor_is_valid(&self) -> bool {
+        ptr::eq(self.cursor, self.buffer.as_ptr())
+    }
+}
+
+fn text(buffer: &[u8; 16]) -> String {
+    String::from_utf8_lossy(buffer).trim_end_matches('\0').to_string()
+}
+
+// Same shape, but !Unpin: once pinned it can never move again
+pub struct PinnedParser {
+    buffer: [u8; 16],
+    cursor: *const u8,
+    _pin: PhantomPinned,
+}
+
+impl PinnedParser {
+    /// Build it directly on the heap and wire up the self-pointer there
+    pub fn new(text: &[u8]) -> Pin<Box<Self>> {
+        let mut buffer = [0; 16];
+        buffer[..text.len()].copy_from_slice(text);
+        let mut boxed = Box::pin(PinnedParser { buffer, cursor: ptr::null(), _pin: PhantomPinned });
+
+        let cursor = boxed.buffer.as_ptr();
+        // SAFETY: we only write a field; nothing is moved out of the pin
+        unsafe { boxed.as_mut().get_unchecked_mut().cursor = cursor };
+        boxed
+    }
+
+    pub fn cursor_is_valid(self: Pin<&Self>) -> bool {
+        ptr::eq(self.cursor, self.buffer.as_ptr())
+    }
+
+    pub fn current(self: Pin<&Self>) -> u8 {
+        // SAFETY: cursor points into our own buffer, and we're pinned
+        unsafe { *self.cursor }
+    }
+}
+
+// A self-pointer goes stale the moment the value moves
+pub fn moving_breaks_self_reference() -> DemoReport {
+    let mut report = DemoReport::new("Self-Reference + Move = Dangling Pointer");
+
+    let mut parser = Parser::new(b"GET / HTTP/1.1");
+    parser.init();
+    report.address("parser.buffer (before move)", &parser.buffer, text(&parser.buffer));
+    report.text(format!("cursor = {:p}, valid: {}", parser.cursor, parser.cursor_is_valid()));
+
+    let moved = parser;  // memcpy to a new stack slot - cursor is copied as-is
+    report.gap();
+    report.address("moved.buffer (after move)", &moved.buffer, text(&moved.buffer));
+    report.text(format!("cursor = {:p}, valid: {}", moved.cursor, moved.cursor_is_valid()));
+
+    let boxed = Box::new(moved);  // And again, into the heap
+    report.address("boxed.buffer (after Box::new)", &boxed.buffer, text(&boxed.buffer));
+    report.text(format!("cursor = {:p}, valid: {}", boxed.cursor, boxed.cursor_is_valid()));
+
+    report.gap();
+    report.fail("cursor still holds the OLD address - reading it is use-after-move");
+    report.ok("We only compare the pointer here; dereferencing it would be UB");
+    report.text("Safe Rust can't even express this with &T: the borrow checker forbids it");
+    report
+}
+
+// Pin<Box<T>> + PhantomPinned: the address is fixed for good
+pub fn pin_box_example() -> DemoReport {
+    let mut report = DemoReport::new("Pin<Box<T>> + PhantomPinned - Address Fixed");
+
+    let parser = PinnedParser::new(b"GET / HTTP/1.1");
+    report.address("pinned.buffer", &parser.buffer, text(&parser.buffer));
+    report.text(format!("cursor valid: {}, current byte: {:?}", parser.as_ref().cursor_is_valid(), parser.as_ref().current() as char));
+
+    let handed_over = parser;  // Moves the Box pointer, NOT the heap value
+    report.address("after moving the Pin<Box>", &handed_over.buffer, text(&handed_over.buffer));
+    report.text(format!("cursor valid: {}", handed_over.as_ref().cursor_is_valid()));
+
+    report.gap();
+    report.ok("Moving Pin<Box<T>> moves the pointer; the pinned value stays put");
+    report.ok("PhantomPinned makes the type !Unpin → no safe &mut T to swap or replace it");
+    report.code("std::mem::swap(&mut *a, &mut *b);  // ❌ DerefMut needs T: Unpin");
+    report.text("Checked by tests/compile_fail/pin_swap_not_unpin.rs");
+    report
+}
+
+// Where you meet Pin for real: async fn state machines
+pub fn pin_and_async() -> DemoReport {
+    let mut report = DemoReport::new("Why Pin Exists - async fn Futures");
+
+    report.code("async fn handle(buf: Vec<u8>) {");
+    report.code("    let line = &buf[..];     // borrow of a local ...");
+    report.code("    read_more().await;       // ... that lives across an .await");
+    report.code("    parse(line);");
+    report.code("}");
+
+    report.gap();
+    report.ok("The compiler turns this into a struct holding `buf` AND `line` → self-referential");
+    report.ok("Future::poll takes self: Pin<&mut Self>, so a started future never moves");
+    report.text("Most types are Unpin (i32, String, Vec<T>): Pin is a no-op for them");
+    report.text("tokio::pin!(fut) / Box::pin(fut) is how you poll a future by reference");
+    report
+}
+
+pub fn pin_vs_go() -> DemoReport {
+    let mut report = DemoReport::new("Self-References: Rust vs Go");
+
+    report.step("Go");
+    report.code("type Parser struct { buf [16]byte; cursor *byte }");
+    report.code("p := &Parser{}; p.cursor = &p.buf[0]  // p escapes → heap");
+    report.ok("Never dangles: heap objects don't move, the GC keeps them alive");
+    report.text("(goroutine STACKS do move when they grow - the runtime rewrites pointers into them)");
+
+    report.step("Rust");
+    report.text("- Values move by memcpy; nothing rewrites pointers");
+    report.text("- Self-references need Pin (or indices/offsets instead of pointers)");
+    report.text("- Unpin is the default; only special types opt out");
+
+    report.step("Key Insight");
+    report.text("Go pays for never-moving objects with a GC; Rust makes \"must not move\" a type");
+    report
+}
+
+pub const DEMO: Demo = Demo {
+    name: "pin-self-referential",
+    about: "Pin and self-referential structs",
+    difficulty: Difficulty::Advanced,
+    tags: &[Tag::Layout, Tag::Borrowing],
+    examples: &[
+        Example { name: "move-breaks", run: moving_breaks_self_reference },
+        Example { name: "pin-box", run: pin_box_example },
+        Example { name: "async", run: pin_and_async },
+        Example { name: "vs-go", run: pin_vs_go },
+    ],
+};
+
+pub fn demonstrate_pin_self_referential() -> Vec<DemoReport> {
+    DEMO.run()
+}
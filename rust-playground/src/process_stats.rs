@@ -0,0 +1,186 @@
+// Process memory - what the OS says the process holds
+// The counting allocator (alloc-stats) sees every malloc; the OS sees pages.
+// The two disagree on purpose: freed memory the allocator keeps for reuse
+// still counts as resident, and so do code, stacks and untouched-but-mapped
+// pages once written. RSS is the number `top`, Kubernetes limits and Go
+// developers reading runtime.MemStats.Sys / HeapSys compare against.
+//
+//   Linux    /proc/self/status: VmRSS, VmHWM (peak, resettable per demo)
+//   macOS    task_info(MACH_TASK_BASIC_INFO): resident_size, resident_size_max
+//   Windows  K32GetProcessMemoryInfo: WorkingSetSize, PeakWorkingSetSize
+//   other    unavailable (wasm has no process)
+
+/// Resident memory right now and its high-water mark
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ProcessMemory {
+    pub rss: usize,
+    pub peak_rss: usize,
+}
+
+/// RSS before and after one demo
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MemoryDelta {
+    pub before: ProcessMemory,
+    pub after: ProcessMemory,
+    /// True when the peak was reset before the demo, so `after.peak_rss` is the demo's own
+    pub own_peak: bool,
+}
+
+impl MemoryDelta {
+    /// Growth in RSS across the demo (negative when it gave pages back)
+    pub fn rss_change(&self) -> isize {
+        self.after.rss as isize - self.before.rss as isize
+    }
+}
+
+/// Read the current numbers, or None where the platform has no API for it
+pub fn sample() -> Option<ProcessMemory> {
+    imp::sample()
+}
+
+/// Whether this platform reports anything
+pub fn supported() -> bool {
+    sample().is_some()
+}
+
+/// Current resident set size in bytes
+pub fn rss() -> Option<usize> {
+    sample().map(|memory| memory.rss)
+}
+
+/// Start a new high-water mark at the current RSS. Only Linux can do this
+/// (echo 5 > /proc/self/clear_refs); elsewhere the peak is the process's.
+pub fn reset_peak() -> bool {
+    imp::reset_peak()
+}
+
+/// Run `f` and record RSS around it
+pub fn measure<R>(f: impl FnOnce() -> R) -> (R, Option<MemoryDelta>) {
+    let own_peak = reset_peak();
+    let before = sample();
+    let result = f();
+    let delta = before.zip(sample()).map(|(before, after)| MemoryDelta { before, after, own_peak });
+    (result, delta)
+}
+
+/// "+1.2 MiB" / "-64.0 KiB"
+pub fn format_change(bytes: isize) -> String {
+    let sign = if bytes < 0 { "-" } else { "+" };
+    format!("{}{}", sign, crate::report::format_bytes(bytes.unsigned_abs()))
+}
+
+#[cfg(target_os = "linux")]
+mod imp {
+    use super::ProcessMemory;
+
+    fn kib(status: &str, key: &str) -> Option<usize> {
+        let line = status.lines().find(|line| line.starts_with(key))?;
+        line[key.len()..].split_whitespace().next()?.parse::<usize>().ok().map(|kib| kib * 1024)
+    }
+
+    pub fn sample() -> Option<ProcessMemory> {
+        let status = std::fs::read_to_string("/proc/self/status").ok()?;
+        Some(ProcessMemory { rss: kib(&status, "VmRSS:")?, peak_rss: kib(&status, "VmHWM:")? })
+    }
+
+    pub fn reset_peak() -> bool {
+        std::fs::write("/proc/self/clear_refs", "5").is_ok()
+    }
+}
+
+#[cfg(target_os = "macos")]
+mod imp {
+    use super::ProcessMemory;
+
+    const MACH_TASK_BASIC_INFO: u32 = 20;
+
+    #[repr(C)]
+    #[derive(Default)]
+    struct TimeValue {
+        seconds: i32,
+        microseconds: i32,
+    }
+
+    // <mach/task_info.h>, packed to 4 bytes like the C header
+    #[repr(C, packed(4))]
+    #[derive(Default)]
+    struct MachTaskBasicInfo {
+        virtual_size: u64,
+        resident_size: u64,
+        resident_size_max: u64,
+        user_time: TimeValue,
+        system_time: TimeValue,
+        policy: i32,
+        suspend_count: i32,
+    }
+
+    unsafe extern "C" {
+        static mach_task_self_: u32;
+        fn task_info(task: u32, flavor: u32, info: *mut MachTaskBasicInfo, count: *mut u32) -> i32;
+    }
+
+    pub fn sample() -> Option<ProcessMemory> {
+        let mut info = MachTaskBasicInfo::default();
+        let mut count = (size_of::<MachTaskBasicInfo>() / size_of::<u32>()) as u32;
+        // SAFETY: info is a correctly sized, writable MACH_TASK_BASIC_INFO and count says so
+        let status = unsafe { task_info(mach_task_self_, MACH_TASK_BASIC_INFO, &mut info, &mut count) };
+        (status == 0).then(|| ProcessMemory { rss: info.resident_size as usize, peak_rss: info.resident_size_max as usize })
+    }
+
+    pub fn reset_peak() -> bool {
+        false
+    }
+}
+
+#[cfg(windows)]
+mod imp {
+    use std::ffi::c_void;
+
+    use super::ProcessMemory;
+
+    // PROCESS_MEMORY_COUNTERS from <psapi.h>
+    #[repr(C)]
+    #[derive(Default)]
+    struct ProcessMemoryCounters {
+        cb: u32,
+        page_fault_count: u32,
+        peak_working_set_size: usize,
+        working_set_size: usize,
+        quota_peak_paged_pool_usage: usize,
+        quota_paged_pool_usage: usize,
+        quota_peak_non_paged_pool_usage: usize,
+        quota_non_paged_pool_usage: usize,
+        pagefile_usage: usize,
+        peak_pagefile_usage: usize,
+    }
+
+    #[link(name = "kernel32")]
+    unsafe extern "system" {
+        fn GetCurrentProcess() -> *mut c_void;
+        fn K32GetProcessMemoryInfo(process: *mut c_void, counters: *mut ProcessMemoryCounters, cb: u32) -> i32;
+    }
+
+    pub fn sample() -> Option<ProcessMemory> {
+        let mut counters = ProcessMemoryCounters { cb: size_of::<ProcessMemoryCounters>() as u32, ..Default::default() };
+        // SAFETY: GetCurrentProcess returns a pseudo-handle that needs no closing; counters.cb is its size
+        let ok = unsafe { K32GetProcessMemoryInfo(GetCurrentProcess(), &mut counters, counters.cb) };
+        (ok != 0).then(|| ProcessMemory { rss: counters.working_set_size, peak_rss: counters.peak_working_set_size })
+    }
+
+    pub fn reset_peak() -> bool {
+        false
+    }
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos", windows)))]
+mod imp {
+    use super::ProcessMemory;
+
+    pub fn sample() -> Option<ProcessMemory> {
+        None
+    }
+
+    pub fn reset_peak() -> bool {
+        false
+    }
+}
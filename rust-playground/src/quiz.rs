@@ -0,0 +1,228 @@
+// Quiz - multiple-choice knowledge checks after each demo (`--quiz`)
+// Questions live in data/quiz.txt (embedded at build time), grouped by the
+// `run` subcommand they follow. Answers are read from any BufRead, so the
+// same code drives a workshop terminal or a scripted run.
+
+use std::io::{self, BufRead, Write};
+use std::sync::OnceLock;
+
+const QUESTIONS: &str = include_str!("../data/quiz.txt");
+
+/// One multiple-choice question
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Question {
+    /// The demo it belongs to (e.g. "rc-weak")
+    pub demo: String,
+    pub prompt: String,
+    /// Code shown under the prompt
+    pub code: Vec<String>,
+    pub choices: Vec<String>,
+    /// Index into `choices`
+    pub answer: usize,
+    pub explanation: String,
+}
+
+/// Every embedded question, in file order
+pub fn questions() -> &'static [Question] {
+    static PARSED: OnceLock<Vec<Question>> = OnceLock::new();
+    PARSED.get_or_init(|| parse(QUESTIONS).unwrap_or_else(|err| panic!("data/quiz.txt: {}", err)))
+}
+
+/// The questions asked after one demo
+pub fn questions_for(demo: &str) -> impl Iterator<Item = &'static Question> + use<'_> {
+    questions().iter().filter(move |question| question.demo == demo)
+}
+
+/// Questions in the line-based format documented at the top of data/quiz.txt
+pub fn parse(text: &str) -> Result<Vec<Question>, String> {
+    let mut questions: Vec<Question> = Vec::new();
+    let mut demo: Option<String> = None;
+    let mut right_answers = 0;  // '+' lines seen for the current question
+
+    for (number, line) in text.lines().enumerate() {
+        let line = line.trim_end();
+        let number = number + 1;
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let (marker, rest) = line.split_at(line.chars().next().map_or(0, char::len_utf8));
+        let rest = rest.strip_prefix(' ').unwrap_or(rest);
+
+        if let Some(name) = line.strip_prefix("== ") {
+            demo = Some(name.trim().to_string());
+            continue;
+        }
+        if marker == "?" {
+            let demo = demo.clone().ok_or(format!("line {}: question before any `== demo`", number))?;
+            check_answers(questions.last(), right_answers)?;
+            right_answers = 0;
+            questions.push(Question {
+                demo,
+                prompt: rest.to_string(),
+                code: vec![],
+                choices: vec![],
+                answer: 0,
+                explanation: String::new(),
+            });
+            continue;
+        }
+
+        let question = questions.last_mut().ok_or(format!("line {}: `{}` outside a question", number, marker))?;
+        match marker {
+            "|" => question.code.push(rest.to_string()),
+            "-" => question.choices.push(rest.to_string()),
+            "+" => {
+                right_answers += 1;
+                question.answer = question.choices.len();
+                question.choices.push(rest.to_string());
+            }
+            ">" => question.explanation = rest.to_string(),
+            _ => return Err(format!("line {}: unknown marker `{}`", number, marker)),
+        }
+    }
+    check_answers(questions.last(), right_answers)?;
+    Ok(questions)
+}
+
+fn check_answers(question: Option<&Question>, right_answers: usize) -> Result<(), String> {
+    match question {
+        Some(question) if right_answers != 1 => Err(format!(
+            "{:?} needs exactly one `+` answer, found {}",
+            question.prompt, right_answers
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// How the player answered one question
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Answer {
+    Correct,
+    Wrong,
+    Skipped,
+    /// The player typed `q` or input ended: ask nothing more
+    Quit,
+}
+
+/// Asks questions and keeps score
+pub struct Quiz<R, W> {
+    input: R,
+    output: W,
+    correct: usize,
+    asked: usize,
+    missed: Vec<&'static Question>,
+    finished: bool,
+}
+
+impl<R: BufRead, W: Write> Quiz<R, W> {
+    pub fn new(input: R, output: W) -> Self {
+        Quiz { input, output, correct: 0, asked: 0, missed: vec![], finished: false }
+    }
+
+    /// Ask every question for one demo (does nothing after a quit)
+    pub fn ask_demo(&mut self, demo: &str) -> io::Result<()> {
+        let questions: Vec<_> = questions_for(demo).collect();
+        if questions.is_empty() || self.finished {
+            return Ok(());
+        }
+        writeln!(self.output, "\n--- Quiz: {} ({} question{}) ---", demo, questions.len(), plural(questions.len()))?;
+        for question in questions {
+            if self.ask(question)? == Answer::Quit {
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    pub fn ask(&mut self, question: &'static Question) -> io::Result<Answer> {
+        writeln!(self.output, "\n{}", question.prompt)?;
+        for line in &question.code {
+            writeln!(self.output, "    {}", line)?;
+        }
+        for (i, choice) in question.choices.iter().enumerate() {
+            writeln!(self.output, "  {}) {}", letter(i), choice)?;
+        }
+
+        let answer = loop {
+            write!(self.output, "Your answer [{}-{}, s = skip, q = quit]: ", letter(0), letter(question.choices.len() - 1))?;
+            self.output.flush()?;
+
+            let mut line = String::new();
+            if self.input.read_line(&mut line)? == 0 {
+                writeln!(self.output)?;
+                break Answer::Quit;
+            }
+            match line.trim().to_ascii_lowercase().as_str() {
+                "q" => break Answer::Quit,
+                "s" | "" => break Answer::Skipped,
+                choice => match parse_choice(choice, question.choices.len()) {
+                    Some(i) if i == question.answer => break Answer::Correct,
+                    Some(_) => break Answer::Wrong,
+                    None => writeln!(self.output, "  (answer with a letter or number)")?,
+                },
+            }
+        };
+
+        match answer {
+            Answer::Correct => writeln!(self.output, "✓ Correct. {}", question.explanation)?,
+            Answer::Wrong => writeln!(
+                self.output,
+                "❌ It's {}) {}. {}",
+                letter(question.answer),
+                question.choices[question.answer],
+                question.explanation
+            )?,
+            Answer::Skipped | Answer::Quit => {}
+        }
+        match answer {
+            Answer::Correct => {
+                self.asked += 1;
+                self.correct += 1;
+            }
+            Answer::Wrong | Answer::Skipped => {
+                self.asked += 1;
+                self.missed.push(question);
+            }
+            Answer::Quit => self.finished = true,
+        }
+        Ok(answer)
+    }
+
+    /// (correct, asked) so far
+    pub fn score(&self) -> (usize, usize) {
+        (self.correct, self.asked)
+    }
+
+    /// Final score plus the questions worth revisiting
+    pub fn summary(&mut self) -> io::Result<()> {
+        if self.asked == 0 {
+            return Ok(());
+        }
+        writeln!(self.output, "\n=== Quiz Score: {}/{} ({}%) ===", self.correct, self.asked, self.correct * 100 / self.asked)?;
+        if !self.missed.is_empty() {
+            writeln!(self.output, "\nWorth another look:")?;
+            for question in &self.missed {
+                writeln!(self.output, "  [{}] {}", question.demo, question.prompt)?;
+                writeln!(self.output, "      → {}", question.choices[question.answer])?;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn letter(i: usize) -> char {
+    (b'a' + i as u8) as char
+}
+
+fn plural(n: usize) -> &'static str {
+    if n == 1 { "" } else { "s" }
+}
+
+// "b" or "2" → Some(1)
+fn parse_choice(choice: &str, count: usize) -> Option<usize> {
+    let index = match choice.as_bytes() {
+        [c @ b'a'..=b'z'] => (c - b'a') as usize,
+        _ => choice.parse::<usize>().ok()?.checked_sub(1)?,
+    };
+    (index < count).then_some(index)
+}
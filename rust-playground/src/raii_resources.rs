@@ -0,0 +1,320 @@
+// RAII beyond memory - files, sockets and lock guards are owned values too
+// Go:   a resource is a value plus a promise: f.Close(), conn.Close(),
+//       mu.Unlock() - usually a defer right after the open. Forget it and the
+//       handle stays open until a finalizer happens to run (files), or forever
+//       (a connection a goroutine still reads, a mutex never unlocked).
+// Rust: the handle IS the owner. Drop closes the file, hangs up the socket,
+//       unlocks the mutex - when the owner goes out of scope, on every path.
+//       Guards borrow what they guard, so the compiler also rejects a guard
+//       that would outlive its resource. Leaking one takes mem::forget, and
+//       the leak checker sees what it left behind.
+
+use std::fs::{self, File};
+use std::io::{BufWriter, Write};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+use crate::leak_check::LeakChecker;
+use crate::registry::{Demo, Difficulty, Example, Tag};
+use crate::report::{format_bytes, DemoReport, SideBySideRow};
+
+/// Bytes each Connection buffers (a socket's read buffer)
+pub const BUFFER: usize = 8 * 1024;
+
+// Open descriptors, where the OS lists them (/proc/self/fd on Linux)
+fn open_descriptors() -> Option<usize> {
+    fs::read_dir("/proc/self/fd").ok().map(|entries| entries.count())
+}
+
+/// A log file: buffered writes, flushed and closed (and deleted) on drop
+pub struct LogFile {
+    path: PathBuf,
+    writer: BufWriter<File>,
+}
+
+impl LogFile {
+    pub fn create(name: &str) -> std::io::Result<Self> {
+        let path = std::env::temp_dir().join(format!("rust-playground-{}-{}", std::process::id(), name));
+        Ok(LogFile { writer: BufWriter::new(File::create(&path)?), path })
+    }
+
+    pub fn line(&mut self, text: &str) -> std::io::Result<()> {
+        writeln!(self.writer, "{}", text)
+    }
+}
+
+impl Drop for LogFile {
+    fn drop(&mut self) {
+        // Drop can't return errors: flush here so a failure is at least not silent data loss
+        let _ = self.writer.flush();
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+/// The far end of the mock connections: counts who is connected
+#[derive(Debug, Default)]
+pub struct Server {
+    open: AtomicUsize,
+    accepted: AtomicUsize,
+}
+
+impl Server {
+    pub fn new() -> Arc<Server> {
+        Arc::new(Server::default())
+    }
+
+    pub fn open_connections(&self) -> usize {
+        self.open.load(Ordering::Relaxed)
+    }
+
+    pub fn accepted(&self) -> usize {
+        self.accepted.load(Ordering::Relaxed)
+    }
+}
+
+/// A TcpStream stand-in: a read buffer and a slot on the server, both
+/// given back when the connection drops
+pub struct Connection {
+    server: Arc<Server>,
+    buffer: Vec<u8>,
+}
+
+impl Connection {
+    pub fn connect(server: &Arc<Server>) -> Connection {
+        server.open.fetch_add(1, Ordering::Relaxed);
+        server.accepted.fetch_add(1, Ordering::Relaxed);
+        Connection { server: Arc::clone(server), buffer: vec![0; BUFFER] }
+    }
+
+    /// "Send" a request and "read" the reply into the buffer
+    pub fn request(&mut self, body: &str) -> usize {
+        let reply = body.len().min(self.buffer.len());
+        self.buffer[..reply].copy_from_slice(&body.as_bytes()[..reply]);
+        reply
+    }
+}
+
+impl Drop for Connection {
+    fn drop(&mut self) {
+        self.server.open.fetch_sub(1, Ordering::Relaxed);  // The FIN
+    }
+}
+
+// A File is an OS handle; dropping it closes the descriptor
+pub fn files() -> DemoReport {
+    let mut report = DemoReport::new("Files: Drop Closes the Handle");
+
+    let before = open_descriptors();
+    report.code("let mut log = LogFile::create(\"raii.log\")?;  // BufWriter<File> inside");
+    let descriptors = match LogFile::create("raii.log") {
+        Ok(mut log) => {
+            for i in 0..3 {
+                if let Err(err) = log.line(&format!("line {}", i)) {
+                    report.fail(format!("write failed: {}", err));
+                }
+            }
+            let open = open_descriptors();
+            report.code("log.line(..)?;  // × 3, still in the BufWriter's buffer");
+            open
+            // log drops here: flush, close, delete
+        }
+        Err(err) => {
+            report.fail(format!("couldn't create a temp file: {}", err));
+            None
+        }
+    };
+    let after = open_descriptors();
+    match (before, descriptors, after) {
+        (Some(before), Some(open), Some(after)) => {
+            report.table(
+                &["", "open descriptors"],
+                vec![
+                    vec!["before create".into(), before.to_string()],
+                    vec!["while `log` is alive".into(), open.to_string()],
+                    vec!["after its scope ends".into(), after.to_string()],
+                ],
+            )
+        }
+        _ => report.text("(this OS doesn't list open descriptors in /proc/self/fd)"),
+    };
+
+    report.step("Drop order inside LogFile");
+    report.code("impl Drop for LogFile { fn drop(&mut self) { let _ = self.writer.flush(); .. } }");
+    report.text("1. LogFile::drop runs first: flush the buffer, delete the file");
+    report.text("2. then its fields drop: BufWriter (flushes again - nothing left), then File (close(2))");
+    report.warn("Drop can't return an error: call log.flush()? yourself where a failed write matters");
+
+    report.gap();
+    report.ok("No Close() to forget - the descriptor lives exactly as long as the File value");
+    report
+}
+
+// A connection owns a buffer and a slot on the server; both come back on drop
+pub fn connections() -> DemoReport {
+    let mut report = DemoReport::new("Connections: Hanging Up Is Drop");
+
+    let server = Server::new();
+    report.code("for request in requests { let mut conn = Connection::connect(&server); conn.request(..); }");
+    let mut most = 0;
+    for i in 0..100 {
+        let mut conn = Connection::connect(&server);
+        conn.request(&format!("GET /item/{}", i));
+        most = most.max(server.open_connections());
+    }
+    report.count("connections accepted", server.accepted());
+    report.count("most open at once", most);
+    report.count("open after the loop", server.open_connections());
+
+    report.step("Moving the connection moves the responsibility");
+    let conn = Connection::connect(&server);
+    let pool = vec![conn];  // The Vec owns it now
+    report.code("let pool = vec![conn];  // conn moved: the pool will hang up");
+    report.count("open while pooled", server.open_connections());
+    drop(pool);
+    report.code("drop(pool);");
+    report.count("open after dropping the pool", server.open_connections());
+
+    report.gap();
+    report.ok("Every exit path - loop iteration, early return, `?`, panic - hangs up exactly once");
+    report.text("The owner that closes is whoever holds the value last, so passing it on needs no protocol");
+    report
+}
+
+// A MutexGuard is the lock; the critical section is its scope
+pub fn lock_guards() -> DemoReport {
+    let mut report = DemoReport::new("Lock Guards: Unlocking Is Drop");
+
+    let queue = Mutex::new(vec![1, 2, 3]);
+    report.code("let guard = queue.lock().unwrap();");
+    {
+        let guard = queue.lock().unwrap();
+        report.text(format!("guard sees {:?}; try_lock() from here: {}", *guard, if queue.try_lock().is_ok() { "acquired" } else { "WouldBlock - still held" }));
+    }
+    report.text(format!("after the block: try_lock() {}", if queue.try_lock().is_ok() { "acquired" } else { "still blocked" }));
+
+    report.step("Where the guard ends");
+    report.code("let len = queue.lock().unwrap().len();  // temporary guard: unlocked at the `;`");
+    let len = queue.lock().unwrap().len();
+    report.text(format!("len = {}; held afterwards: {}", len, queue.try_lock().is_err()));
+    report.code("let guard = queue.lock().unwrap();  // named: held to the end of the scope");
+    let guard = queue.lock().unwrap();
+    report.text(format!("held while `guard` is alive: {}", queue.try_lock().is_err()));
+    report.code("drop(guard);  // end the critical section early");
+    drop(guard);
+    report.text(format!("held after drop(guard): {}", queue.try_lock().is_err()));
+
+    report.gap();
+    report.ok("No Unlock() to forget, and no unlocking twice: the guard is moved into drop");
+    report.warn("A named guard held across slow work holds the lock across it - scope it tightly");
+    report
+}
+
+// Guards borrow their resource, so they can't outlive it
+pub fn guard_lifetime() -> DemoReport {
+    let mut report = DemoReport::new("A Guard Can't Outlive Its Resource");
+
+    report.code("impl<T> Mutex<T> { fn lock(&self) -> LockResult<MutexGuard<'_, T>> }  // the guard borrows the mutex");
+    report.code("let mut guard;");
+    report.code("{ let queue = Mutex::new(vec![1]); guard = queue.lock().unwrap(); }");
+    report.code("// ❌ error[E0597]: `queue` does not live long enough");
+    report.code("guard.push(2);");
+    report.text("Checked by tests/compile_fail/guard_outlives_mutex.rs");
+
+    report.step("Every guard in std is tied to its owner the same way");
+    report.table(
+        &["guard", "borrows", "released by"],
+        vec![
+            vec!["MutexGuard<'a, T>".into(), "&'a Mutex<T>".into(), "Drop: unlock".into()],
+            vec!["RwLockReadGuard<'a, T>".into(), "&'a RwLock<T>".into(), "Drop: one reader less".into()],
+            vec!["Ref<'a, T> / RefMut<'a, T>".into(), "&'a RefCell<T>".into(), "Drop: borrow flag reset".into()],
+            vec!["vec::Drain<'a, T>".into(), "&'a mut Vec<T>".into(), "Drop: close the gap".into()],
+        ],
+    );
+    report.text("To outlive the scope, the resource moves out with the guard - Arc<Mutex<T>> - or the data is moved out of it");
+
+    report.gap();
+    report.ok("Use-after-close of a lock is a compile error, not a race you find in production");
+    report
+}
+
+// mem::forget skips Drop: the resource stays open and its memory stays live
+pub fn forgotten() -> DemoReport {
+    let mut report = DemoReport::new("A Forgotten Connection, Caught");
+
+    report.code("let conn = Connection::connect(&server);");
+    report.code("std::mem::forget(conn);  // safe, but Drop never runs");
+    let (open, leaked) = LeakChecker::check(
+        || {
+            let server = Server::new();
+            std::mem::forget(Connection::connect(&server));
+            server.open_connections()
+        },
+        |_| 0,
+    );
+    report.count("connections the server still has open", open);
+    match leaked {
+        Some(bytes) => {
+            report.leak_on_purpose("a forgotten Connection", bytes);
+            report.fail(format!("leak checker: {} still live after the code returned", format_bytes(bytes)));
+            report.text(format!("= the {} read buffer + the server the connection's Arc keeps alive", format_bytes(BUFFER)));
+            report.text("Declared with leak_on_purpose, so `run --leak-check` lists it instead of flagging the demo")
+        }
+        None => report.text("Build with --features alloc-stats to let the leak checker measure it"),
+    };
+
+    report.step("Leaking is safe, and rare");
+    report.text("mem::forget, Box::leak and an Rc cycle are the only ways out of Drop - all visible in the code");
+    report.text("Safe code must tolerate it (no memory unsafety), so leaking can't be what keeps data valid");
+
+    report.gap();
+    report.ok("A leak takes a deliberate call - and `--leak-check` measures what it cost");
+    report
+}
+
+pub fn raii_vs_go() -> DemoReport {
+    let mut report = DemoReport::new("Resources: Go vs Rust");
+
+    report.side_by_side(vec![
+        SideBySideRow::differs("f, err := os.Create(p); defer f.Close()", "let f = File::create(p)?;  // closes at scope end"),
+        SideBySideRow::differs("conn, _ := net.Dial(..)  // forgot defer conn.Close()", "let conn = TcpStream::connect(..)?;  // nothing to forget"),
+        SideBySideRow::differs("mu.Lock(); defer mu.Unlock()", "let guard = mu.lock().unwrap();"),
+        SideBySideRow::differs("mu.Unlock(); mu.Unlock()  // fatal error: unlock of unlocked mutex", "drop(guard); drop(guard);  // ❌ E0382: use of moved value"),
+        SideBySideRow::differs("for _, p := range paths { f, _ := os.Open(p); defer f.Close() }  // all open till return", "for p in paths { let f = File::open(p)?; }  // one open at a time"),
+    ]);
+
+    report.table(
+        &["", "Go", "Rust"],
+        vec![
+            vec!["who closes".into(), "the caller, via Close()/defer".into(), "the type's Drop".into()],
+            vec!["when".into(), "function return (defer)".into(), "end of the owner's scope".into()],
+            vec!["forgot to close".into(), "fd open until a finalizer runs, if ever".into(), "can't - only mem::forget skips it".into()],
+            vec!["close errors".into(), "returned by Close()".into(), "lost in Drop: flush()/sync_all() first".into()],
+            vec!["use after close".into(), "compiles; returns os.ErrClosed".into(), "E0382 / E0597 at compile time".into()],
+        ],
+    );
+
+    report.gap();
+    report.ok("Go ties cleanup to a function call you write; Rust ties it to the value you own");
+    report.text("go vet's lostcancel and linters like bodyclose catch some forgotten Close() calls - Drop needs no linter");
+    report
+}
+
+pub const DEMO: Demo = Demo {
+    name: "raii-resources",
+    about: "RAII beyond memory: files, connections and lock guards closed by Drop, guards that can't outlive their resource, a forgotten one caught vs Go's Close()/defer",
+    difficulty: Difficulty::Intermediate,
+    tags: &[Tag::Ownership, Tag::Borrowing],
+    examples: &[
+        Example { name: "files", run: files },
+        Example { name: "connections", run: connections },
+        Example { name: "lock-guards", run: lock_guards },
+        Example { name: "guard-lifetime", run: guard_lifetime },
+        Example { name: "forgotten", run: forgotten },
+        Example { name: "vs-go", run: raii_vs_go },
+    ],
+};
+
+pub fn demonstrate_raii_resources() -> Vec<DemoReport> {
+    DEMO.run()
+}
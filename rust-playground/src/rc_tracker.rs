@@ -0,0 +1,119 @@
+// Rc counts over time - strong/weak snapshots at labeled points
+// Go:   no counts to look at: the GC decides reachability when it runs.
+// Rust: Rc::strong_count / Rc::weak_count are plain fields next to the value.
+//       RcTracker holds a Weak (so it never keeps the value alive), records
+//       both counts whenever you call snapshot(), and prints the history as a
+//       table and a sparkline - in the demos or in your own examples:
+//
+//         let data = Rc::new(42);
+//         let mut tracker = RcTracker::new(&data);
+//         tracker.snapshot("created");
+//         let extra = Rc::clone(&data);
+//         tracker.snapshot("cloned");
+//         drop((data, extra));
+//         tracker.snapshot("dropped");  // strong 0: freed
+//         println!("{}", tracker);
+
+use std::fmt;
+use std::rc::{Rc, Weak};
+
+use crate::report::DemoReport;
+
+const BARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// Counts of one Rc allocation at a labeled point
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Snapshot {
+    pub label: String,
+    pub strong: usize,
+    /// Weak references other than the tracker's own
+    pub weak: usize,
+}
+
+impl Snapshot {
+    /// The value has been dropped: no strong references were left
+    pub fn freed(&self) -> bool {
+        self.strong == 0
+    }
+}
+
+/// Records strong/weak counts of one Rc without owning it
+pub struct RcTracker<T: ?Sized> {
+    target: Weak<T>,
+    snapshots: Vec<Snapshot>,
+}
+
+impl<T: ?Sized> RcTracker<T> {
+    /// Track `rc`; holds a Weak, so the value is still freed when the last Rc goes
+    pub fn new(rc: &Rc<T>) -> Self {
+        RcTracker { target: Rc::downgrade(rc), snapshots: Vec::new() }
+    }
+
+    /// The counts right now, without recording them
+    pub fn current(&self) -> (usize, usize) {
+        let strong = self.target.strong_count();
+        // Weak::weak_count is 0 once the value is gone; otherwise it includes ours
+        let weak = self.target.weak_count().saturating_sub(1);
+        (strong, weak)
+    }
+
+    /// Record the counts under `label` and return them
+    pub fn snapshot(&mut self, label: impl Into<String>) -> Snapshot {
+        let (strong, weak) = self.current();
+        let snapshot = Snapshot { label: label.into(), strong, weak };
+        self.snapshots.push(snapshot.clone());
+        snapshot
+    }
+
+    pub fn snapshots(&self) -> &[Snapshot] {
+        &self.snapshots
+    }
+
+    /// Largest strong count seen so far
+    pub fn peak_strong(&self) -> usize {
+        self.snapshots.iter().map(|s| s.strong).max().unwrap_or(0)
+    }
+
+    /// One bar per snapshot, scaled to the peak strong count; ' ' for freed
+    pub fn sparkline(&self) -> String {
+        let peak = self.peak_strong();
+        self.snapshots
+            .iter()
+            .map(|s| match s.strong {
+                0 => ' ',
+                strong => BARS[(strong * BARS.len()).div_ceil(peak.max(1)).clamp(1, BARS.len()) - 1],
+            })
+            .collect()
+    }
+
+    /// Add the history to a report: a table of every snapshot and the sparkline
+    pub fn add_to(&self, report: &mut DemoReport) {
+        let rows = self
+            .snapshots
+            .iter()
+            .map(|s| {
+                let bar = if s.freed() { "(freed)".to_string() } else { "█".repeat(s.strong) };
+                vec![s.label.clone(), s.strong.to_string(), s.weak.to_string(), bar]
+            })
+            .collect();
+        report.table(&["point", "strong", "weak", ""], rows);
+        report.text(format!("strong over time: {}  (peak {})", self.sparkline(), self.peak_strong()));
+    }
+}
+
+// The timeline as plain text: one line per snapshot, then the sparkline
+impl<T: ?Sized> fmt::Display for RcTracker<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let width = self.snapshots.iter().map(|s| s.label.chars().count()).max().unwrap_or(0);
+        for s in &self.snapshots {
+            writeln!(f, "{:<width$}  strong {}  weak {}", s.label, s.strong, s.weak, width = width)?;
+        }
+        write!(f, "{}", self.sparkline())
+    }
+}
+
+impl<T: ?Sized> fmt::Debug for RcTracker<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RcTracker").field("snapshots", &self.snapshots).finish()
+    }
+}
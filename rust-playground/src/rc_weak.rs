@@ -10,6 +10,11 @@ use std::cell::RefCell;
 use std::sync::{Arc, Mutex};
 use std::thread;
 
+use crate::rc_tracker::RcTracker;
+use crate::registry::{Demo, Difficulty, Example, Tag};
+use crate::report::{DemoReport, SideBySideRow};
+
+#[allow(dead_code)]
 #[derive(Debug)]
 struct Node {
     value: i32,
@@ -18,285 +23,327 @@ struct Node {
 }
 
 // Rc<T> allows multiple owners (like Go!)
-pub fn rc_example() {
-    println!("\n=== Rc<T> - Reference Counted (Multiple Owners) ===\n");
-    
+pub fn rc_example() -> DemoReport {
+    let mut report = DemoReport::new("Rc<T> - Reference Counted (Multiple Owners)");
+
     let data = Rc::new(42);
-    
-    println!("  Original Rc:  {:p}, value: {}, strong_count: {}", 
-             data.as_ref(), data, Rc::strong_count(&data));
-    
+    let mut tracker = RcTracker::new(&data);
+
+    report.address("Original Rc", data.as_ref(), data.to_string());
+    report.count("strong_count", tracker.snapshot("created").strong);
+
     let ref1 = Rc::clone(&data);  // Increment ref count
     let ref2 = Rc::clone(&data);  // Increment ref count
     let ref3 = Rc::clone(&data);  // Increment ref count
-    
-    println!("  After cloning:");
-    println!("    ref1: {:p}, strong_count: {}", ref1.as_ref(), Rc::strong_count(&ref1));
-    println!("    ref2: {:p}, strong_count: {}", ref2.as_ref(), Rc::strong_count(&ref2));
-    println!("    ref3: {:p}, strong_count: {}", ref3.as_ref(), Rc::strong_count(&ref3));
-    
-    println!("\n  ✓ All point to same memory (like Go!)");
-    println!("  ✓ Reference counted at RUNTIME");
-    println!("  ⚠️ Not thread-safe (use Arc<T> for threads)");
-    
+
+    report.step("After cloning");
+    report.address("ref1", ref1.as_ref(), ref1.to_string());
+    report.address("ref2", ref2.as_ref(), ref2.to_string());
+    report.address("ref3", ref3.as_ref(), ref3.to_string());
+    report.count("strong_count after cloning", tracker.snapshot("3 clones").strong);
+
+    report.gap();
+    report.ok("All point to same memory (like Go!)");
+    report.ok("Reference counted at RUNTIME");
+    report.warn("Not thread-safe (use Arc<T> for threads)");
+
     drop(ref1);
-    println!("\n  After dropping ref1, strong_count: {}", Rc::strong_count(&data));
-    
+    report.gap();
+    report.count("strong_count after dropping ref1", tracker.snapshot("drop ref1").strong);
+
     drop(ref2);
     drop(ref3);
-    println!("  After dropping all refs, strong_count: {}", Rc::strong_count(&data));
-    println!("  When last reference drops, memory is freed!");
+    report.count("strong_count after dropping all refs", tracker.snapshot("drop ref2, ref3").strong);
+
+    drop(data);
+    tracker.snapshot("drop data");
+    report.step("Timeline (rc_tracker::RcTracker)");
+    tracker.add_to(&mut report);
+    report.text("When last reference drops, memory is freed!");
+    report
 }
 
 // Weak<T> prevents reference cycles
-pub fn weak_example() {
-    println!("\n=== Weak<T> - Preventing Reference Cycles ===\n");
-    
+pub fn weak_example() -> DemoReport {
+    let mut report = DemoReport::new("Weak<T> - Preventing Reference Cycles");
+
     let parent = Rc::new(Node {
         value: 1,
         parent: None,
         children: vec![],
     });
-    
-    println!("  Parent created, strong_count: {}", Rc::strong_count(&parent));
-    
+
+    let mut tracker = RcTracker::new(&parent);
+    report.count("Parent created, strong_count", tracker.snapshot("parent created").strong);
+
     let child = Rc::new(Node {
         value: 2,
         parent: Some(Rc::downgrade(&parent)),  // Weak reference!
         children: vec![],
     });
-    
-    println!("  Child created with Weak parent reference");
-    println!("    Parent strong_count: {}", Rc::strong_count(&parent));
-    println!("    Parent weak_count: {}", Rc::weak_count(&parent));
-    
+
+    report.step("Child created with Weak parent reference");
+    let counts = tracker.snapshot("child holds Weak");
+    report.count("Parent strong_count", counts.strong);
+    report.count("Parent weak_count", counts.weak);
+
     // Try to access parent through weak reference
     if let Some(parent_ref) = child.parent.as_ref().and_then(|w| w.upgrade()) {
-        println!("    Parent value accessed through Weak: {}", parent_ref.value);
+        tracker.snapshot("upgrade() in scope");
+        report.text(format!("Parent value accessed through Weak: {}", parent_ref.value));
     }
-    
-    println!("\n  ✓ Weak doesn't increase strong_count");
-    println!("  ✓ Prevents memory leaks from cycles");
-    println!("  ✓ upgrade() returns Option (might be dropped)");
+    tracker.snapshot("upgrade dropped");
+    drop(parent);
+    tracker.snapshot("parent dropped");
+    report.text(format!("child.parent.upgrade() after the parent is dropped: {:?}", child.parent.as_ref().and_then(|w| w.upgrade()).map(|p| p.value)));
+    report.step("Parent timeline");
+    tracker.add_to(&mut report);
+
+    report.gap();
+    report.ok("Weak doesn't increase strong_count");
+    report.ok("Prevents memory leaks from cycles");
+    report.ok("upgrade() returns Option (might be dropped)");
+    report.text("See the leak it prevents: make rc-cycle-leak");
+    report.text("Draw the tree: cargo run -q -- graph tree | dot -Tpng -o tree.png");
+    report
 }
 
 // Comparison: Go vs Rust reference counting
-pub fn rc_comparison() {
-    println!("\n=== Reference Counting: Go vs Rust ===\n");
-    
-    println!("Go (automatic):");
-    println!("  user := &User{{...}}");
-    println!("  ptr1 := user  // GC tracks automatically");
-    println!("  ptr2 := user  // GC tracks automatically");
-    println!("  ptr3 := user  // GC tracks automatically");
-    println!("  ✓ Automatic reference counting");
-    println!("  ⚠️ GC overhead, stop-the-world pauses");
-    
-    println!("\nRust (explicit with Rc):");
-    println!("  let data = Rc::new(42);");
-    println!("  let ref1 = Rc::clone(&data);  // Explicit clone");
-    println!("  let ref2 = Rc::clone(&data);  // Explicit clone");
-    println!("  let ref3 = Rc::clone(&data);  // Explicit clone");
-    println!("  ✓ Explicit reference counting");
-    println!("  ✓ No GC, no stop-the-world");
-    println!("  ✓ Deterministic cleanup");
-    println!("  ⚠️ Small runtime cost (increment/decrement counter)");
-    
-    println!("\nRust (default ownership):");
-    println!("  let data = 42;");
-    println!("  let ref1 = &data;  // Just borrows");
-    println!("  let ref2 = &data;  // Just borrows");
-    println!("  ✓ ZERO runtime cost!");
-    println!("  ✓ Compile-time checks only");
+pub fn rc_comparison() -> DemoReport {
+    let mut report = DemoReport::new("Reference Counting: Go vs Rust");
+
+    report.step("Go (automatic) vs Rust (explicit with Rc)");
+    report.side_by_side(vec![
+        SideBySideRow::same("user := &User{...}", "let data = Rc::new(42);"),
+        SideBySideRow::differs("ptr1 := user  // GC tracks automatically", "let ref1 = Rc::clone(&data);  // Explicit clone"),
+        SideBySideRow::differs("ptr2 := user  // GC tracks automatically", "let ref2 = Rc::clone(&data);  // Explicit clone"),
+        SideBySideRow::differs("ptr3 := user  // GC tracks automatically", "let ref3 = Rc::clone(&data);  // Explicit clone"),
+    ]);
+    report.ok("Go: the GC finds every pointer - no counting in your code");
+    report.warn("Go: GC overhead, stop-the-world pauses");
+    report.ok("Rust: explicit reference counting");
+    report.ok("No GC, no stop-the-world");
+    report.ok("Deterministic cleanup");
+    report.warn("Small runtime cost (increment/decrement counter)");
+
+    report.step("Rust (default ownership)");
+    report.code("let data = 42;");
+    report.code("let ref1 = &data;  // Just borrows");
+    report.code("let ref2 = &data;  // Just borrows");
+    report.ok("ZERO runtime cost!");
+    report.ok("Compile-time checks only");
+    report
 }
 
 // Show the cost difference
-pub fn cost_comparison() {
-    println!("\n=== Cost Comparison ===\n");
-    
-    println!("Rust borrowing (zero cost):");
-    println!("  let data = vec![1, 2, 3];");
-    println!("  let ref1 = &data;  // No cost");
-    println!("  let ref2 = &data;  // No cost");
-    println!("  Cost: 0 bytes, 0 cycles");
-    
-    println!("\nRust Rc (small cost):");
-    println!("  let data = Rc::new(vec![1, 2, 3]);");
-    println!("  let ref1 = Rc::clone(&data);  // Increment counter");
-    println!("  let ref2 = Rc::clone(&data);  // Increment counter");
-    println!("  Cost: Extra pointer + 2 counters (~16 bytes)");
-    println!("        Atomic increment/decrement operations");
-    
-    println!("\nGo GC (runtime cost):");
-    println!("  data := []int{{1, 2, 3}}");
-    println!("  ref1 := data  // GC tracks");
-    println!("  ref2 := data  // GC tracks");
-    println!("  Cost: GC tracking + periodic collection");
-    println!("        Stop-the-world pauses");
-    println!("        Memory overhead for GC metadata");
+pub fn cost_comparison() -> DemoReport {
+    let mut report = DemoReport::new("Cost Comparison");
+
+    report.step("Rust borrowing (zero cost)");
+    report.code("let data = vec![1, 2, 3];");
+    report.code("let ref1 = &data;  // No cost");
+    report.code("let ref2 = &data;  // No cost");
+    report.text("Cost: 0 bytes, 0 cycles");
+
+    report.step("Rust Rc (small cost)");
+    report.code("let data = Rc::new(vec![1, 2, 3]);");
+    report.code("let ref1 = Rc::clone(&data);  // Increment counter");
+    report.code("let ref2 = Rc::clone(&data);  // Increment counter");
+    report.text("Cost: Extra pointer + 2 counters (~16 bytes)");
+    report.text("      Atomic increment/decrement operations");
+
+    report.step("Go GC (runtime cost)");
+    report.code("data := []int{1, 2, 3}");
+    report.code("ref1 := data  // GC tracks");
+    report.code("ref2 := data  // GC tracks");
+    report.text("Cost: GC tracking + periodic collection");
+    report.text("      Stop-the-world pauses");
+    report.text("      Memory overhead for GC metadata");
+
+    report.gap();
+    report.text("Measure it yourself: cargo bench --bench ownership_costs");
+    report
 }
 
 // RefCell - interior mutability with runtime checks
-pub fn refcell_example() {
-    println!("\n=== RefCell<T> - Interior Mutability (Runtime Checks) ===\n");
-    
+pub fn refcell_example() -> DemoReport {
+    let mut report = DemoReport::new("RefCell<T> - Interior Mutability (Runtime Checks)");
+
     let data = RefCell::new(42);
-    
-    println!("  Original value: {}", data.borrow());
-    
+
+    report.text(format!("Original value: {}", data.borrow()));
+
     // Multiple immutable borrows OK
     {
         let borrow1 = data.borrow();
         let borrow2 = data.borrow();
-        println!("  Immutable borrows: {} and {}", borrow1, borrow2);
+        report.text(format!("Immutable borrows: {} and {}", borrow1, borrow2));
     } // Borrows dropped here
-    
+
     // Mutable borrow OK (after immutable borrows done)
     {
         let mut borrow_mut = data.borrow_mut();
         *borrow_mut = 100;
-        println!("  After mutation: {}", borrow_mut);
+        report.text(format!("After mutation: {}", borrow_mut));
     }
-    
-    println!("\n  ✓ Borrowing rules checked at RUNTIME");
-    println!("  ✓ Allows mutation through immutable reference");
-    println!("  ⚠️ Panics if you violate rules (not compile error!)");
-    
+
+    report.gap();
+    report.ok("Borrowing rules checked at RUNTIME");
+    report.ok("Allows mutation through immutable reference");
+    report.warn("Panics if you violate rules (not compile error!)");
+
     // This would panic at runtime:
     // let borrow = data.borrow();
     // let mut_borrow = data.borrow_mut();  // 💥 Panic!
+    report
 }
 
 // Rc<RefCell<T>> - The common pattern
-pub fn rc_refcell_example() {
-    println!("\n=== Rc<RefCell<T>> - Multiple Owners + Mutability ===\n");
-    
+pub fn rc_refcell_example() -> DemoReport {
+    let mut report = DemoReport::new("Rc<RefCell<T>> - Multiple Owners + Mutability");
+
     let data = Rc::new(RefCell::new(vec![1, 2, 3]));
-    
+
     let ref1 = Rc::clone(&data);
     let ref2 = Rc::clone(&data);
     let ref3 = Rc::clone(&data);
-    
-    println!("  Original: {:?}", data.borrow());
-    
+
+    report.text(format!("Original: {:?}", data.borrow()));
+
     // Mutate through ref1
     ref1.borrow_mut().push(4);
-    println!("  After ref1.push(4): {:?}", data.borrow());
-    
+    report.text(format!("After ref1.push(4): {:?}", data.borrow()));
+
     // Mutate through ref2
     ref2.borrow_mut().push(5);
-    println!("  After ref2.push(5): {:?}", data.borrow());
-    
+    report.text(format!("After ref2.push(5): {:?}", data.borrow()));
+
     // All refs see the changes!
-    println!("  Via ref3: {:?}", ref3.borrow());
-    
-    println!("\n  ✓ Multiple owners (Rc)");
-    println!("  ✓ Shared mutability (RefCell)");
-    println!("  ✓ Like Go's behavior, but explicit!");
-    println!("  ⚠️ Runtime borrow checking (can panic)");
+    report.text(format!("Via ref3: {:?}", ref3.borrow()));
+
+    report.gap();
+    report.ok("Multiple owners (Rc)");
+    report.ok("Shared mutability (RefCell)");
+    report.ok("Like Go's behavior, but explicit!");
+    report.warn("Runtime borrow checking (can panic)");
+    report
 }
 
 // Compare with Go
-pub fn refcell_vs_go() {
-    println!("\n=== RefCell vs Go Mutability ===\n");
-    
-    println!("Go (automatic, no checks):");
-    println!("  data := []int{{1, 2, 3}}");
-    println!("  ref1 := &data");
-    println!("  ref2 := &data");
-    println!("  *ref1 = append(*ref1, 4)  // Mutate");
-    println!("  *ref2 = append(*ref2, 5)  // Mutate");
-    println!("  ✓ No borrow checking");
-    println!("  ⚠️ Possible data races with goroutines");
-    
-    println!("\nRust with Rc<RefCell<T>> (single-threaded):");
-    println!("  let data = Rc::new(RefCell::new(vec![1, 2, 3]));");
-    println!("  let ref1 = Rc::clone(&data);");
-    println!("  let ref2 = Rc::clone(&data);");
-    println!("  ref1.borrow_mut().push(4);  // Runtime check");
-    println!("  ref2.borrow_mut().push(5);  // Runtime check");
-    println!("  ✓ Explicit ownership (Rc)");
-    println!("  ✓ Runtime borrow checking (RefCell)");
-    println!("  ✓ No data races (not thread-safe, won't compile)");
-    
-    println!("\nRust with Arc<Mutex<T>> (thread-safe):");
-    println!("  let data = Arc::new(Mutex::new(vec![1, 2, 3]));");
-    println!("  ✓ Multiple owners across threads");
-    println!("  ✓ Safe concurrent mutation");
-    println!("  ✓ Compiler enforces thread safety");
+pub fn refcell_vs_go() -> DemoReport {
+    let mut report = DemoReport::new("RefCell vs Go Mutability");
+
+    report.step("Go (automatic, no checks)");
+    report.code("data := []int{1, 2, 3}");
+    report.code("ref1 := &data");
+    report.code("ref2 := &data");
+    report.code("*ref1 = append(*ref1, 4)  // Mutate");
+    report.code("*ref2 = append(*ref2, 5)  // Mutate");
+    report.ok("No borrow checking");
+    report.warn("Possible data races with goroutines");
+
+    report.step("Rust with Rc<RefCell<T>> (single-threaded)");
+    report.code("let data = Rc::new(RefCell::new(vec![1, 2, 3]));");
+    report.code("let ref1 = Rc::clone(&data);");
+    report.code("let ref2 = Rc::clone(&data);");
+    report.code("ref1.borrow_mut().push(4);  // Runtime check");
+    report.code("ref2.borrow_mut().push(5);  // Runtime check");
+    report.ok("Explicit ownership (Rc)");
+    report.ok("Runtime borrow checking (RefCell)");
+    report.ok("No data races (not thread-safe, won't compile)");
+
+    report.step("Rust with Arc<Mutex<T>> (thread-safe)");
+    report.code("let data = Arc::new(Mutex::new(vec![1, 2, 3]));");
+    report.ok("Multiple owners across threads");
+    report.ok("Safe concurrent mutation");
+    report.ok("Compiler enforces thread safety");
+    report
 }
 
 // Arc<Mutex<T>> - thread-safe version
-pub fn arc_mutex_example() {
-    println!("\n=== Arc<Mutex<T>> - Thread-Safe Rc<RefCell<T>> ===\n");
-    
+pub fn arc_mutex_example() -> DemoReport {
+    let mut report = DemoReport::new("Arc<Mutex<T>> - Thread-Safe Rc<RefCell<T>>");
+
     let data = Arc::new(Mutex::new(vec![1, 2, 3]));
-    
-    println!("  Original: {:?}", data.lock().unwrap());
-    
+
+    report.text(format!("Original: {:?}", data.lock().unwrap()));
+
     let mut handles = vec![];
-    
+
     // Spawn 3 threads, each adds a number
     for i in 0..3 {
         let data_clone = Arc::clone(&data);
         let handle = thread::spawn(move || {
             let mut vec = data_clone.lock().unwrap();
             vec.push(i + 10);
-            println!("    Thread {} added {}", i, i + 10);
+            format!("  Thread {} added {}", i, i + 10)
         });
         handles.push(handle);
     }
-    
+
     // Wait for all threads
     for handle in handles {
-        handle.join().unwrap();
+        report.text(handle.join().unwrap());
     }
-    
-    println!("  Final: {:?}", data.lock().unwrap());
-    
-    println!("\n  Arc = Atomic Rc (thread-safe ref counting)");
-    println!("  Mutex = Thread-safe RefCell (locks instead of panics)");
-    println!("  ✓ Can share across threads");
-    println!("  ✓ Prevents data races");
-    println!("  ⚠️ Higher cost (atomic ops + locking)");
+
+    report.text(format!("Final: {:?}", data.lock().unwrap()));
+
+    report.gap();
+    report.text("Arc = Atomic Rc (thread-safe ref counting)");
+    report.text("Mutex = Thread-safe RefCell (locks instead of panics)");
+    report.ok("Can share across threads");
+    report.ok("Prevents data races");
+    report.warn("Higher cost (atomic ops + locking)");
+    report.text("Three threads prove little - under load: cargo run --release -- run stress --threads 16");
+    report
 }
 
 // Compare all three
-pub fn compare_all_three() {
-    println!("\n=== Comparison: Rc<RefCell> vs Arc<Mutex> vs Go ===\n");
-    
-    println!("Rc<RefCell<T>> (single-threaded):");
-    println!("  - Multiple owners: Rc (ref counting)");
-    println!("  - Mutability: RefCell (runtime checks, can panic)");
-    println!("  - Thread-safe: ❌ No");
-    println!("  - Cost: Low (ref counting + borrow checks)");
-    
-    println!("\nArc<Mutex<T>> (thread-safe):");
-    println!("  - Multiple owners: Arc (atomic ref counting)");
-    println!("  - Mutability: Mutex (locks, blocks threads)");
-    println!("  - Thread-safe: ✅ Yes");
-    println!("  - Cost: Higher (atomic ops + locking)");
-    
-    println!("\nGo (automatic):");
-    println!("  - Multiple owners: ✅ Automatic (GC)");
-    println!("  - Mutability: ✅ Automatic (no checks)");
-    println!("  - Thread-safe: ⚠️ Manual sync needed");
-    println!("  - Cost: High (GC overhead + potential races)");
-    
-    println!("\nKey Insight:");
-    println!("  Rust: Choose your tradeoff explicitly");
-    println!("  Go: One size fits all (GC)");
-}
+pub fn compare_all_three() -> DemoReport {
+    let mut report = DemoReport::new("Comparison: Rc<RefCell> vs Arc<Mutex> vs Go");
+
+    report.step("Rc<RefCell<T>> (single-threaded)");
+    report.text("- Multiple owners: Rc (ref counting)");
+    report.text("- Mutability: RefCell (runtime checks, can panic)");
+    report.text("- Thread-safe: ❌ No");
+    report.text("- Cost: Low (ref counting + borrow checks)");
+
+    report.step("Arc<Mutex<T>> (thread-safe)");
+    report.text("- Multiple owners: Arc (atomic ref counting)");
+    report.text("- Mutability: Mutex (locks, blocks threads)");
+    report.text("- Thread-safe: ✅ Yes");
+    report.text("- Cost: Higher (atomic ops + locking)");
 
-pub fn demonstrate_rc() {
-    rc_example();
-    weak_example();
-    rc_comparison();
-    cost_comparison();
-    refcell_example();
-    rc_refcell_example();
-    refcell_vs_go();
-    arc_mutex_example();
-    compare_all_three();
+    report.step("Go (automatic)");
+    report.text("- Multiple owners: ✅ Automatic (GC)");
+    report.text("- Mutability: ✅ Automatic (no checks)");
+    report.text("- Thread-safe: ⚠️ Manual sync needed");
+    report.text("- Cost: High (GC overhead + potential races)");
+
+    report.step("Key Insight");
+    report.text("Rust: Choose your tradeoff explicitly");
+    report.text("Go: One size fits all (GC)");
+    report
 }
 
+pub const DEMO: Demo = Demo {
+    name: "rc-weak",
+    about: "Rc<T>, Weak<T>, RefCell<T> and Arc<Mutex<T>>",
+    difficulty: Difficulty::Intermediate,
+    tags: &[Tag::Ownership, Tag::Concurrency],
+    examples: &[
+        Example { name: "rc", run: rc_example },
+        Example { name: "weak", run: weak_example },
+        Example { name: "rc-comparison", run: rc_comparison },
+        Example { name: "cost", run: cost_comparison },
+        Example { name: "refcell", run: refcell_example },
+        Example { name: "rc-refcell", run: rc_refcell_example },
+        Example { name: "refcell-vs-go", run: refcell_vs_go },
+        Example { name: "arc-mutex", run: arc_mutex_example },
+        Example { name: "compare-all", run: compare_all_three },
+    ],
+};
+
+pub fn demonstrate_rc() -> Vec<DemoReport> {
+    DEMO.run()
+}
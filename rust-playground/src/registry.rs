@@ -0,0 +1,286 @@
+// Demo registry - the one list of everything the playground can run
+// Every demo module describes itself with a `DEMO` constant: name, one-line
+// about, difficulty, tags and its examples in order. The CLI's `run`, `list`,
+// `--tag` filter, `--quiz` and the TUI all walk this registry, so adding a
+// demo means writing the module and registering its DEMO in `builtin()`.
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::report::DemoReport;
+use crate::telemetry::DemoSpan;
+use crate::{
+    actor_model, allocator_backends, arena_allocation, atomics, borrow_checker, box_dyn_error,
+    builder_and_ownership, cancellation, channels, closures_and_moves, comparison, condvar,
+    contention_bench, copy_vs_clone, cow, deadlock, defer_vs_drop, dining_philosophers,
+    drop_glue_and_needs_drop, drop_order, dst_and_fat_pointers, enum_memory, error_handling,
+    fragmentation, generics_monomorphization, globals, goroutines_vs_threads,
+    interior_mutability_zoo, iterator_adapters, latency, layout, leak_check, lifetimes,
+    match_ownership, mem_swap_take_replace, memory_ordering, mpsc_pipeline, object_pool,
+    option_vs_nil, ownership, partial_moves, phantomdata_variance, pin_self_referential,
+    raii_resources, rc_weak, retain_split_borrow, rwlock, scoped_threads, send_sync,
+    shadowing_and_rebinding, slices_and_strings, smart_pointers, stack_vs_heap_escape,
+    static_lifetime_and_leak, stress, string_concat_bench, string_encoding, sync_primitives,
+    thread_local_storage, traits_vs_interfaces, two_owners_compile_fail_gallery, unsafe_rust,
+    vec_growth, vecdeque_and_collections_memory, workload,
+};
+#[cfg(feature = "tokio")]
+use crate::async_await;
+#[cfg(feature = "crossbeam")]
+use crate::crossbeam_channels;
+#[cfg(feature = "go-ffi")]
+use crate::ffi_with_go;
+#[cfg(feature = "parking_lot")]
+use crate::parking_lot_locks;
+
+/// How much Rust a demo assumes you already know
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Difficulty {
+    Beginner,
+    Intermediate,
+    Advanced,
+}
+
+impl Difficulty {
+    pub fn name(self) -> &'static str {
+        match self {
+            Difficulty::Beginner => "beginner",
+            Difficulty::Intermediate => "intermediate",
+            Difficulty::Advanced => "advanced",
+        }
+    }
+}
+
+impl fmt::Display for Difficulty {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Topic a demo covers (`--tag` on the CLI)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tag {
+    /// Moves, drops, single and shared owners
+    Ownership,
+    /// References, lifetimes and the borrow checker
+    Borrowing,
+    /// Threads, channels, locks, atomics, async
+    Concurrency,
+    /// Where memory comes from and when it's freed
+    Allocation,
+    /// Sizes, alignment and pointer representation
+    Layout,
+    /// Raw pointers and what the compiler stops checking
+    Unsafe,
+    /// Timed measurements - run these with --release
+    Benchmark,
+}
+
+impl Tag {
+    pub const ALL: [Tag; 7] = [
+        Tag::Ownership,
+        Tag::Borrowing,
+        Tag::Concurrency,
+        Tag::Allocation,
+        Tag::Layout,
+        Tag::Unsafe,
+        Tag::Benchmark,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Tag::Ownership => "ownership",
+            Tag::Borrowing => "borrowing",
+            Tag::Concurrency => "concurrency",
+            Tag::Allocation => "allocation",
+            Tag::Layout => "layout",
+            Tag::Unsafe => "unsafe",
+            Tag::Benchmark => "benchmark",
+        }
+    }
+}
+
+impl fmt::Display for Tag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for Tag {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Tag::ALL
+            .into_iter()
+            .find(|tag| tag.name() == s)
+            .ok_or_else(|| format!("unknown tag `{}`", s))
+    }
+}
+
+/// One runnable example inside a demo (`run <demo> -e <example>`)
+#[derive(Debug, Clone, Copy)]
+pub struct Example {
+    pub name: &'static str,
+    pub run: fn() -> DemoReport,
+}
+
+/// A demo module as the CLI sees it
+#[derive(Debug, Clone, Copy)]
+pub struct Demo {
+    /// Subcommand name, also the demo's key in data/quiz.txt
+    pub name: &'static str,
+    pub about: &'static str,
+    pub difficulty: Difficulty,
+    pub tags: &'static [Tag],
+    /// In the order `run <demo>` runs them
+    pub examples: &'static [Example],
+}
+
+impl Demo {
+    pub fn has_tag(&self, tag: Tag) -> bool {
+        self.tags.contains(&tag)
+    }
+
+    pub fn example(&self, name: &str) -> Option<&'static Example> {
+        self.examples.iter().find(|example| example.name == name)
+    }
+
+    /// Every example, each one measured
+    pub fn run(&self) -> Vec<DemoReport> {
+        self.measure(self.examples.iter())
+    }
+
+    /// Only the named examples, in the order given (unknown names are skipped)
+    pub fn run_examples<S: AsRef<str>>(&self, names: impl IntoIterator<Item = S>) -> Vec<DemoReport> {
+        self.measure(names.into_iter().filter_map(|name| self.example(name.as_ref())))
+    }
+
+    // One demo span around the examples' own (see telemetry.rs)
+    fn measure<'a>(&self, examples: impl Iterator<Item = &'a Example>) -> Vec<DemoReport> {
+        let span = DemoSpan::enter(self.name);
+        let reports: Vec<DemoReport> = examples
+            .map(|example| {
+                let mut report = DemoReport::measured(example.run);
+                report.name = Some(format!("{}::{}", self.name, example.name));
+                report
+            })
+            .collect();
+        span.close(&reports);
+        reports
+    }
+}
+
+/// Demos in menu order
+#[derive(Debug, Default)]
+pub struct DemoRegistry {
+    demos: Vec<&'static Demo>,
+}
+
+impl DemoRegistry {
+    pub fn new() -> Self {
+        DemoRegistry::default()
+    }
+
+    /// Every demo built into this binary (feature-gated ones only when enabled)
+    pub fn builtin() -> Self {
+        let mut registry = DemoRegistry::new();
+        registry.register(&ownership::DEMO);
+        registry.register(&borrow_checker::DEMO);
+        registry.register(&comparison::DEMO);
+        registry.register(&rc_weak::DEMO);
+        registry.register(&channels::DEMO);
+        registry.register(&goroutines_vs_threads::DEMO);
+        registry.register(&lifetimes::DEMO);
+        #[cfg(feature = "tokio")]
+        registry.register(&async_await::DEMO);
+        registry.register(&drop_order::DEMO);
+        registry.register(&slices_and_strings::DEMO);
+        registry.register(&send_sync::DEMO);
+        registry.register(&atomics::DEMO);
+        registry.register(&layout::DEMO);
+        registry.register(&arena_allocation::DEMO);
+        registry.register(&cow::DEMO);
+        registry.register(&error_handling::DEMO);
+        registry.register(&latency::DEMO);
+        registry.register(&defer_vs_drop::DEMO);
+        registry.register(&pin_self_referential::DEMO);
+        registry.register(&scoped_threads::DEMO);
+        registry.register(&smart_pointers::DEMO);
+        registry.register(&stack_vs_heap_escape::DEMO);
+        registry.register(&rwlock::DEMO);
+        registry.register(&globals::DEMO);
+        registry.register(&contention_bench::DEMO);
+        registry.register(&traits_vs_interfaces::DEMO);
+        registry.register(&leak_check::DEMO);
+        registry.register(&unsafe_rust::DEMO);
+        registry.register(&closures_and_moves::DEMO);
+        registry.register(&sync_primitives::DEMO);
+        registry.register(&iterator_adapters::DEMO);
+        registry.register(&deadlock::DEMO);
+        #[cfg(feature = "parking_lot")]
+        registry.register(&parking_lot_locks::DEMO);
+        registry.register(&string_encoding::DEMO);
+        registry.register(&mpsc_pipeline::DEMO);
+        registry.register(&box_dyn_error::DEMO);
+        registry.register(&condvar::DEMO);
+        registry.register(&stress::DEMO);
+        registry.register(&memory_ordering::DEMO);
+        registry.register(&vec_growth::DEMO);
+        registry.register(&interior_mutability_zoo::DEMO);
+        #[cfg(feature = "crossbeam")]
+        registry.register(&crossbeam_channels::DEMO);
+        registry.register(&generics_monomorphization::DEMO);
+        registry.register(&option_vs_nil::DEMO);
+        registry.register(&thread_local_storage::DEMO);
+        registry.register(&mem_swap_take_replace::DEMO);
+        registry.register(&fragmentation::DEMO);
+        registry.register(&dst_and_fat_pointers::DEMO);
+        registry.register(&builder_and_ownership::DEMO);
+        registry.register(&partial_moves::DEMO);
+        registry.register(&phantomdata_variance::DEMO);
+        registry.register(&object_pool::DEMO);
+        registry.register(&workload::DEMO);
+        registry.register(&shadowing_and_rebinding::DEMO);
+        #[cfg(feature = "go-ffi")]
+        registry.register(&ffi_with_go::DEMO);
+        registry.register(&match_ownership::DEMO);
+        registry.register(&string_concat_bench::DEMO);
+        registry.register(&dining_philosophers::DEMO);
+        registry.register(&static_lifetime_and_leak::DEMO);
+        registry.register(&enum_memory::DEMO);
+        registry.register(&cancellation::DEMO);
+        registry.register(&copy_vs_clone::DEMO);
+        registry.register(&vecdeque_and_collections_memory::DEMO);
+        registry.register(&raii_resources::DEMO);
+        registry.register(&retain_split_borrow::DEMO);
+        registry.register(&allocator_backends::DEMO);
+        registry.register(&actor_model::DEMO);
+        registry.register(&drop_glue_and_needs_drop::DEMO);
+        registry.register(&two_owners_compile_fail_gallery::DEMO);
+        registry
+    }
+
+    /// Add a demo at the end of the menu
+    ///
+    /// Panics if the name is taken - it's a subcommand and a quiz key.
+    pub fn register(&mut self, demo: &'static Demo) {
+        assert!(self.get(demo.name).is_none(), "demo `{}` registered twice", demo.name);
+        self.demos.push(demo);
+    }
+
+    pub fn demos(&self) -> &[&'static Demo] {
+        &self.demos
+    }
+
+    pub fn get(&self, name: &str) -> Option<&'static Demo> {
+        self.demos.iter().copied().find(|demo| demo.name == name)
+    }
+
+    /// Demos carrying any of `tags` (all demos when `tags` is empty)
+    pub fn tagged<'a>(&'a self, tags: &'a [Tag]) -> impl Iterator<Item = &'static Demo> + 'a {
+        self.demos
+            .iter()
+            .copied()
+            .filter(move |demo| tags.is_empty() || tags.iter().any(|&tag| demo.has_tag(tag)))
+    }
+}
@@ -0,0 +1,887 @@
+// Renderers - one DemoReport, several output formats
+// Demos only build reports; a Renderer decides what they look like:
+//   Plain    - the classic terminal text (also DemoReport's Display)
+//   Colored  - the same layout with ANSI colors
+//   Markdown - headings, fenced code and pipe tables for course notes
+//   Html     - one self-contained page (inline CSS, no scripts) to hand out
+//   Json     - the report's structure as data, for a web page to lay out
+//   Csv      - only the measured costs, one row per report, for spreadsheets
+// Entry::SideBySide puts Go and Rust in two columns, differing rows highlighted.
+// Verbose wraps any of them: -q keeps only the measured numbers, -v/-vv add
+// raw addresses, raw units and the allocator's full counters.
+// The terminal renderers can also cut a report into pieces, one per step,
+// for `--step` to show with a pause in between.
+
+use std::fmt::{self, Write};
+
+use crate::memviz;
+use crate::report::{format_bytes, DemoReport, Entry, NoteKind, SideBySideRow, Step, Unit};
+
+/// Turns reports into text
+pub trait Renderer {
+    /// Banner printed once before a run of several demos
+    fn banner(&self, text: &str, out: &mut dyn Write) -> fmt::Result;
+
+    /// One demo's report
+    fn report(&self, report: &DemoReport, out: &mut dyn Write) -> fmt::Result;
+
+    /// Written once before any other output (document prologue)
+    fn begin(&self, _out: &mut dyn Write) -> fmt::Result {
+        Ok(())
+    }
+
+    /// Written once after all other output
+    fn end(&self, _out: &mut dyn Write) -> fmt::Result {
+        Ok(())
+    }
+
+    /// Convenience: render a report into a String
+    fn render(&self, report: &DemoReport) -> String {
+        let mut out = String::new();
+        self.report(report, &mut out).expect("writing to a String never fails");
+        out
+    }
+
+    /// Several reports into one String - exactly what the CLI prints for them
+    fn render_all(&self, reports: &[DemoReport]) -> String {
+        reports.iter().map(|report| self.render(report)).collect()
+    }
+
+    /// The report cut up for `--step`: the title first, then one piece per step.
+    /// Formats that only make sense whole (a page, a document) come back as one piece.
+    fn pieces(&self, report: &DemoReport) -> Vec<Piece> {
+        vec![Piece { preview: String::new(), text: self.render(report) }]
+    }
+}
+
+/// One piece of a report shown by `--step`
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Piece {
+    /// The step's code, to show before the step runs (`--step=preview`)
+    pub preview: String,
+    pub text: String,
+}
+
+/// Plain terminal text
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Plain;
+
+/// Plain layout with ANSI colors
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Colored;
+
+/// GitHub-flavored Markdown
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Markdown;
+
+/// A standalone HTML page
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Html;
+
+/// One JSON object per report; several render as an array
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Json;
+
+/// A header line, then one row of costs per measured report
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Csv;
+
+/// The columns `Csv` writes; a cost the build couldn't measure is left empty
+pub const CSV_HEADER: &str = "name,duration_ns,allocs,bytes,peak_rss";
+
+/// How much of each report to show
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub enum Verbosity {
+    /// Counts, measurements and numeric tables only (`-q`)
+    Quiet,
+    /// The tutorial as written
+    #[default]
+    Normal,
+    /// Plus a "Details" step: raw addresses, raw units, allocator counters (`-v`)
+    Verbose,
+    /// Plus gaps between neighbouring addresses and the report's own heap size (`-vv`)
+    VeryVerbose,
+}
+
+impl Verbosity {
+    /// From the CLI flags: `-q` wins, then one level per `-v`
+    pub fn from_flags(quiet: bool, verbose: u8) -> Verbosity {
+        match (quiet, verbose) {
+            (true, _) => Verbosity::Quiet,
+            (false, 0) => Verbosity::Normal,
+            (false, 1) => Verbosity::Verbose,
+            (false, _) => Verbosity::VeryVerbose,
+        }
+    }
+
+    /// `report` as this level shows it; None when quiet leaves nothing to show
+    pub fn apply(self, report: &DemoReport) -> Option<DemoReport> {
+        match self {
+            Verbosity::Quiet => measured_only(report),
+            Verbosity::Normal => Some(report.clone()),
+            Verbosity::Verbose | Verbosity::VeryVerbose => Some(with_details(report, self == Verbosity::VeryVerbose)),
+        }
+    }
+}
+
+// Quiet: drop the prose, keep whatever was measured
+fn measured_only(report: &DemoReport) -> Option<DemoReport> {
+    let measured = |entry: &Entry| match entry {
+        Entry::Count { .. } | Entry::Measurement { .. } => true,
+        Entry::Table { header, rows } => numeric_columns(rows, header.len()).contains(&true),
+        _ => false,
+    };
+    let steps: Vec<Step> = report
+        .steps
+        .iter()
+        .map(|step| Step { heading: step.heading.clone(), entries: step.entries.iter().filter(|entry| measured(entry)).cloned().collect() })
+        .filter(|step| !step.entries.is_empty())
+        .collect();
+    if steps.is_empty() && report.alloc.is_none() {
+        return None;
+    }
+    Some(DemoReport { steps, ..report.clone() })
+}
+
+// Verbose: the report unchanged, then a "Details" step with the raw values
+fn with_details(report: &DemoReport, very: bool) -> DemoReport {
+    let mut detailed = report.clone();
+    let addresses: Vec<(&str, usize)> = report
+        .entries()
+        .filter_map(|entry| match entry {
+            Entry::Address { label, addr, .. } => Some((label.as_str(), *addr)),
+            _ => None,
+        })
+        .collect();
+    let measurements: Vec<Vec<String>> = report
+        .entries()
+        .filter_map(|entry| match entry {
+            Entry::Measurement { label, value, unit } => {
+                let unit = match unit {
+                    Unit::Nanoseconds => "ns",
+                    Unit::Bytes => "bytes",
+                };
+                Some(vec![label.clone(), format!("{} {}", value, unit)])
+            }
+            _ => None,
+        })
+        .collect();
+    if addresses.is_empty() && measurements.is_empty() && report.alloc.is_none() && !very {
+        return detailed;
+    }
+
+    detailed.step("Details");
+    if !addresses.is_empty() {
+        let mut rows = Vec::new();
+        for (index, (label, addr)) in addresses.iter().enumerate() {
+            // The largest power of two dividing the address, capped at a page
+            let alignment = 1usize << addr.trailing_zeros().min(12);
+            let mut row = vec![label.to_string(), format!("{:#018x}", addr), addr.to_string(), alignment.to_string()];
+            if very {
+                let gap = index.checked_sub(1).map(|previous| *addr as i128 - addresses[previous].1 as i128);
+                row.push(gap.map_or("-".to_string(), |gap| format!("{:+}", gap)));
+            }
+            rows.push(row);
+        }
+        if very {
+            detailed.table(&["address of", "hex", "decimal", "aligned to", "from previous"], rows);
+        } else {
+            detailed.table(&["address of", "hex", "decimal", "aligned to"], rows);
+        }
+    }
+    if !measurements.is_empty() {
+        detailed.table(&["measurement", "raw value"], measurements);
+    }
+    if let Some(stats) = report.alloc {
+        detailed.table(
+            &["allocator counter", "value"],
+            vec![
+                vec!["allocations".into(), stats.allocations.to_string()],
+                vec!["deallocations".into(), stats.deallocations.to_string()],
+                vec!["not freed yet (allocations - deallocations)".into(), stats.allocations.saturating_sub(stats.deallocations).to_string()],
+                vec!["bytes allocated".into(), stats.bytes_allocated.to_string()],
+                vec!["peak bytes above the start".into(), stats.peak_bytes.to_string()],
+            ],
+        );
+    }
+    if very {
+        detailed.bytes("the report itself (its Strings and Vecs)", report.heap_size());
+        if let Some(leaked) = report.leaked_bytes {
+            detailed.bytes("left live after the demo (--leak-check)", leaked);
+        }
+    }
+    detailed
+}
+
+/// Any renderer, showing each report at a [`Verbosity`]
+pub struct Verbose<'a> {
+    pub renderer: &'a dyn Renderer,
+    pub verbosity: Verbosity,
+}
+
+impl Renderer for Verbose<'_> {
+    fn banner(&self, text: &str, out: &mut dyn Write) -> fmt::Result {
+        self.renderer.banner(text, out)
+    }
+
+    fn report(&self, report: &DemoReport, out: &mut dyn Write) -> fmt::Result {
+        match self.verbosity.apply(report) {
+            Some(report) => self.renderer.report(&report, out),
+            None => Ok(()),
+        }
+    }
+
+    fn begin(&self, out: &mut dyn Write) -> fmt::Result {
+        self.renderer.begin(out)
+    }
+
+    fn end(&self, out: &mut dyn Write) -> fmt::Result {
+        self.renderer.end(out)
+    }
+
+    fn pieces(&self, report: &DemoReport) -> Vec<Piece> {
+        self.verbosity.apply(report).map(|report| self.renderer.pieces(&report)).unwrap_or_default()
+    }
+
+    // Through the inner renderer, so Json still gets one array
+    fn render_all(&self, reports: &[DemoReport]) -> String {
+        let shown: Vec<DemoReport> = reports.iter().filter_map(|report| self.verbosity.apply(report)).collect();
+        self.renderer.render_all(&shown)
+    }
+}
+
+// What a piece of text is, so the terminal renderers can style it
+#[derive(Clone, Copy)]
+enum Role {
+    Title,
+    Heading,
+    Code,
+    Note(NoteKind),
+    Label,
+    Address,
+    Dim,
+    Differs,
+}
+
+// Plain and Colored share one layout; only the styling differs
+trait Style {
+    fn paint(&self, role: Role, text: &str) -> String;
+}
+
+impl Style for Plain {
+    fn paint(&self, _role: Role, text: &str) -> String {
+        text.to_string()
+    }
+}
+
+impl Style for Colored {
+    fn paint(&self, role: Role, text: &str) -> String {
+        let code = match role {
+            Role::Title => "1;36",
+            Role::Heading => "1",
+            Role::Code => "33",
+            Role::Note(NoteKind::Ok) => "32",
+            Role::Note(NoteKind::Warn) => "33",
+            Role::Note(NoteKind::Fail) => "31",
+            Role::Label => "1",
+            Role::Address => "34",
+            Role::Dim => "2",
+            Role::Differs => "1;35",
+        };
+        format!("\x1b[{}m{}\x1b[0m", code, text)
+    }
+}
+
+impl Renderer for Plain {
+    fn banner(&self, text: &str, out: &mut dyn Write) -> fmt::Result {
+        terminal_banner(self, text, out)
+    }
+
+    fn report(&self, report: &DemoReport, out: &mut dyn Write) -> fmt::Result {
+        terminal_report(self, report, out)
+    }
+
+    fn pieces(&self, report: &DemoReport) -> Vec<Piece> {
+        terminal_pieces(self, report)
+    }
+}
+
+impl Renderer for Colored {
+    fn banner(&self, text: &str, out: &mut dyn Write) -> fmt::Result {
+        terminal_banner(self, text, out)
+    }
+
+    fn report(&self, report: &DemoReport, out: &mut dyn Write) -> fmt::Result {
+        terminal_report(self, report, out)
+    }
+
+    fn pieces(&self, report: &DemoReport) -> Vec<Piece> {
+        terminal_pieces(self, report)
+    }
+}
+
+fn terminal_banner(style: &dyn Style, text: &str, out: &mut dyn Write) -> fmt::Result {
+    writeln!(out, "{}", style.paint(Role::Title, &format!("=== {} ===", text)))
+}
+
+fn terminal_report(style: &dyn Style, report: &DemoReport, out: &mut dyn Write) -> fmt::Result {
+    writeln!(out, "\n{}", style.paint(Role::Title, &format!("=== {} ===", report.title)))?;
+    for step in visible_steps(report) {
+        terminal_step(style, step, out)?;
+    }
+    terminal_footer(style, report, out)
+}
+
+fn terminal_step(style: &dyn Style, step: &Step, out: &mut dyn Write) -> fmt::Result {
+    writeln!(out)?;
+    if let Some(heading) = &step.heading {
+        writeln!(out, "{}", style.paint(Role::Heading, &format!("{}:", heading)))?;
+    }
+    for entry in &step.entries {
+        terminal_entry(style, entry, out)?;
+    }
+    Ok(())
+}
+
+fn terminal_footer(style: &dyn Style, report: &DemoReport, out: &mut dyn Write) -> fmt::Result {
+    if let Some(footer) = alloc_footer(report) {
+        writeln!(out)?;
+        writeln!(out, "  {}", style.paint(Role::Dim, &format!("[heap, incl. report text] {}", footer)))?;
+    }
+    Ok(())
+}
+
+// The same text as terminal_report, split before each step; the footer
+// rides along with the last piece
+fn terminal_pieces(style: &dyn Style, report: &DemoReport) -> Vec<Piece> {
+    let mut title = String::new();
+    writeln!(title, "\n{}", style.paint(Role::Title, &format!("=== {} ===", report.title))).expect("writing to a String never fails");
+    let mut pieces = vec![Piece { preview: String::new(), text: title }];
+    for step in visible_steps(report) {
+        let mut piece = Piece::default();
+        let code: Vec<&String> = step.entries.iter().filter_map(|entry| if let Entry::Code(code) = entry { Some(code) } else { None }).collect();
+        if !code.is_empty() {
+            let heading = step.heading.as_deref().map(|heading| format!(" {}", style.paint(Role::Heading, heading))).unwrap_or_default();
+            writeln!(piece.preview, "  {}{}", style.paint(Role::Dim, "next:"), heading).expect("writing to a String never fails");
+            for code in code {
+                writeln!(piece.preview, "    {}", style.paint(Role::Code, code)).expect("writing to a String never fails");
+            }
+        }
+        terminal_step(style, step, &mut piece.text).expect("writing to a String never fails");
+        pieces.push(piece);
+    }
+    let last = pieces.last_mut().expect("the title is always there");
+    terminal_footer(style, report, &mut last.text).expect("writing to a String never fails");
+    pieces
+}
+
+fn terminal_entry(style: &dyn Style, entry: &Entry, out: &mut dyn Write) -> fmt::Result {
+    match entry {
+        Entry::Text(text) => writeln!(out, "  {}", text),
+        Entry::Code(code) => writeln!(out, "  {}", style.paint(Role::Code, code)),
+        Entry::Note(kind, text) => {
+            writeln!(out, "  {}", style.paint(Role::Note(*kind), &format!("{} {}", kind.symbol(), text)))
+        }
+        Entry::Address { label, addr, value } => writeln!(
+            out,
+            "  {}: {} -> {}",
+            style.paint(Role::Label, label),
+            style.paint(Role::Address, &format!("{:#x}", addr)),
+            value
+        ),
+        Entry::Count { label, value } => writeln!(out, "  {}: {}", style.paint(Role::Label, label), value),
+        Entry::Measurement { label, value, unit } => {
+            writeln!(out, "  {}: {}", style.paint(Role::Label, label), unit.format(*value))
+        }
+        Entry::Table { header, rows } => {
+            let widths = column_widths(header, rows);
+            let numeric = numeric_columns(rows, widths.len());
+            let header_line = pad_row(header, &widths, &numeric);
+            writeln!(out, "  {}", style.paint(Role::Heading, &header_line))?;
+            let total = widths.iter().sum::<usize>() + 2 * widths.len().saturating_sub(1);
+            writeln!(out, "  {}", style.paint(Role::Dim, &"-".repeat(total)))?;
+            for row in rows {
+                writeln!(out, "  {}", pad_row(row, &widths, &numeric))?;
+            }
+            Ok(())
+        }
+        Entry::SideBySide(rows) => terminal_side_by_side(style, rows, out),
+    }
+}
+
+// Go │ Rust; a differing row gets ≠ in the gutter and its own color
+fn terminal_side_by_side(style: &dyn Style, rows: &[SideBySideRow], out: &mut dyn Write) -> fmt::Result {
+    let width = rows.iter().map(|row| row.go.chars().count()).max().unwrap_or(0).max("Go".len());
+    let rust_width = rows.iter().map(|row| row.rust.chars().count()).max().unwrap_or(0).max("Rust".len());
+    writeln!(out, "  {} {} {}", style.paint(Role::Heading, &format!("{:<width$}", "Go")), style.paint(Role::Dim, "│"), style.paint(Role::Heading, "Rust"))?;
+    writeln!(out, "  {}", style.paint(Role::Dim, &format!("{}┼{}", "─".repeat(width + 1), "─".repeat(rust_width + 1))))?;
+    for row in rows {
+        let (role, gutter) = if row.differs { (Role::Differs, "≠") } else { (Role::Code, "│") };
+        let go = format!("{:<width$}", row.go);
+        let line = format!("{} {} {}", style.paint(role, &go), style.paint(if row.differs { role } else { Role::Dim }, gutter), style.paint(role, &row.rust));
+        writeln!(out, "  {}", line.trim_end())?;
+    }
+    Ok(())
+}
+
+impl Renderer for Markdown {
+    fn banner(&self, text: &str, out: &mut dyn Write) -> fmt::Result {
+        writeln!(out, "# {}", text)
+    }
+
+    fn report(&self, report: &DemoReport, out: &mut dyn Write) -> fmt::Result {
+        writeln!(out, "\n## {}", report.title)?;
+        for step in visible_steps(report) {
+            writeln!(out)?;
+            if let Some(heading) = &step.heading {
+                writeln!(out, "**{}**\n", heading)?;
+            }
+            markdown_entries(step, out)?;
+        }
+        if let Some(footer) = alloc_footer(report) {
+            writeln!(out, "\n_Heap (incl. report text): {}_", footer)?;
+        }
+        Ok(())
+    }
+}
+
+// Guess the fence language from the step heading ("Go (allowed)" → go)
+fn fence_language(step: &Step) -> &'static str {
+    match &step.heading {
+        Some(heading) if heading.starts_with("Go") => "go",
+        Some(heading) if heading == memviz::HEADING => "text",
+        _ => "rust",
+    }
+}
+
+fn markdown_entries(step: &Step, out: &mut dyn Write) -> fmt::Result {
+    let mut in_code = false;
+    for entry in &step.entries {
+        let is_code = matches!(entry, Entry::Code(_));
+        if is_code && !in_code {
+            writeln!(out, "```{}", fence_language(step))?;
+        } else if !is_code && in_code {
+            writeln!(out, "```")?;
+        }
+        in_code = is_code;
+
+        match entry {
+            Entry::Code(code) => writeln!(out, "{}", code)?,
+            Entry::Text(text) => writeln!(out, "- {}", text.trim_start())?,
+            Entry::Note(kind, text) => writeln!(out, "- {} {}", kind.symbol(), text)?,
+            Entry::Address { label, addr, value } => writeln!(out, "- {}: `{:#x}` → {}", label, addr, value)?,
+            Entry::Count { label, value } => writeln!(out, "- {}: **{}**", label, value)?,
+            Entry::Measurement { label, value, unit } => {
+                writeln!(out, "- {}: **{}**", label, unit.format(*value))?
+            }
+            Entry::Table { header, rows } => {
+                let numeric = numeric_columns(rows, header.len());
+                writeln!(out, "\n| {} |", escape_cells(header).join(" | "))?;
+                let align: Vec<&str> = numeric.iter().map(|n| if *n { "---:" } else { "---" }).collect();
+                writeln!(out, "| {} |", align.join(" | "))?;
+                for row in rows {
+                    writeln!(out, "| {} |", escape_cells(row).join(" | "))?;
+                }
+                writeln!(out)?;
+            }
+            Entry::SideBySide(rows) => {
+                writeln!(out, "\n| Go | | Rust |\n| --- | :-: | --- |")?;
+                for row in rows {
+                    let gutter = if row.differs { "≠" } else { "" };
+                    writeln!(out, "| {} | {} | {} |", markdown_code(&row.go), gutter, markdown_code(&row.rust))?;
+                }
+                writeln!(out)?;
+            }
+        }
+    }
+    if in_code {
+        writeln!(out, "```")?;
+    }
+    Ok(())
+}
+
+const HTML_STYLE: &str = "
+body { font: 15px/1.5 system-ui, sans-serif; max-width: 60em; margin: 2em auto; padding: 0 1em; color: #222; }
+h1 { border-bottom: 2px solid #ce422b; }
+section { border: 1px solid #ddd; border-radius: 6px; padding: 0 1em 1em; margin: 1.5em 0; }
+h2 { color: #ce422b; }
+h3 { margin-bottom: .3em; }
+.machine { color: #666; }
+ul { list-style: none; padding-left: 0; }
+.ok { color: #1a7f37; } .warn { color: #9a6700; } .fail { color: #cf222e; }
+.num { font-weight: bold; font-variant-numeric: tabular-nums; }
+.addr { font-family: monospace; color: #0550ae; }
+details { margin: .5em 0; }
+summary { cursor: pointer; color: #555; }
+pre { background: #f6f8fa; padding: .6em; overflow-x: auto; margin: .3em 0; }
+table { border-collapse: collapse; margin: .5em 0; }
+th, td { border: 1px solid #ddd; padding: .2em .6em; }
+td.right { text-align: right; }
+table.side-by-side td { font-family: monospace; white-space: pre; }
+table.side-by-side tr.differs td { background: #fff8c5; }
+.alloc { color: #666; font-size: 90%; }
+";
+
+impl Renderer for Html {
+    fn banner(&self, text: &str, out: &mut dyn Write) -> fmt::Result {
+        writeln!(out, "<h1>{}</h1>", escape_html(text))?;
+        let cpus = std::thread::available_parallelism().map_or(1, |n| n.get());
+        let build = if cfg!(debug_assertions) { "debug" } else { "release" };
+        writeln!(
+            out,
+            "<p class=\"machine\">rust-playground {} · {} {} · {} CPUs · {} build</p>",
+            env!("CARGO_PKG_VERSION"),
+            std::env::consts::OS,
+            std::env::consts::ARCH,
+            cpus,
+            build
+        )
+    }
+
+    fn report(&self, report: &DemoReport, out: &mut dyn Write) -> fmt::Result {
+        writeln!(out, "<section>\n<h2>{}</h2>", escape_html(&report.title))?;
+        for step in visible_steps(report) {
+            if let Some(heading) = &step.heading {
+                writeln!(out, "<h3>{}</h3>", escape_html(heading))?;
+            }
+            html_entries(step, out)?;
+        }
+        if let Some(footer) = alloc_footer(report) {
+            writeln!(out, "<p class=\"alloc\">Heap (incl. report text): {}</p>", footer)?;
+        }
+        writeln!(out, "</section>")
+    }
+
+    fn begin(&self, out: &mut dyn Write) -> fmt::Result {
+        writeln!(out, "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">")?;
+        writeln!(out, "<title>Rust Ownership &amp; Borrowing Playground</title>")?;
+        writeln!(out, "<style>{}</style>\n</head>\n<body>", HTML_STYLE)
+    }
+
+    fn end(&self, out: &mut dyn Write) -> fmt::Result {
+        writeln!(out, "</body>\n</html>")
+    }
+}
+
+// Runs of code lines become one collapsible block; everything else is a list item
+fn html_entries(step: &Step, out: &mut dyn Write) -> fmt::Result {
+    let mut in_code = false;
+    let mut in_list = false;
+    for entry in &step.entries {
+        let is_code = matches!(entry, Entry::Code(_));
+        let is_item = !is_code && !matches!(entry, Entry::Table { .. } | Entry::SideBySide(_));
+        if in_code && !is_code {
+            writeln!(out, "</code></pre></details>")?;
+        }
+        if in_list && !is_item {
+            writeln!(out, "</ul>")?;
+        }
+        if is_code && !in_code {
+            let language = if fence_language(step) == "go" { "Go" } else { "Rust" };
+            write!(out, "<details open><summary>{} code</summary><pre><code>", language)?;
+        }
+        if is_item && !in_list {
+            writeln!(out, "<ul>")?;
+        }
+        in_code = is_code;
+        in_list = is_item;
+
+        match entry {
+            Entry::Code(code) => writeln!(out, "{}", escape_html(code))?,
+            Entry::Text(text) => writeln!(out, "<li>{}</li>", escape_html(text.trim_start()))?,
+            Entry::Note(kind, text) => {
+                let class = match kind {
+                    NoteKind::Ok => "ok",
+                    NoteKind::Warn => "warn",
+                    NoteKind::Fail => "fail",
+                };
+                writeln!(out, "<li class=\"{}\">{} {}</li>", class, kind.symbol(), escape_html(text))?
+            }
+            Entry::Address { label, addr, value } => writeln!(
+                out,
+                "<li>{}: <span class=\"addr\">{:#x}</span> → {}</li>",
+                escape_html(label),
+                addr,
+                escape_html(value)
+            )?,
+            Entry::Count { label, value } => {
+                writeln!(out, "<li>{}: <span class=\"num\">{}</span></li>", escape_html(label), value)?
+            }
+            Entry::Measurement { label, value, unit } => writeln!(
+                out,
+                "<li>{}: <span class=\"num\">{}</span></li>",
+                escape_html(label),
+                unit.format(*value)
+            )?,
+            Entry::Table { header, rows } => {
+                let numeric = numeric_columns(rows, header.len());
+                write!(out, "<table>\n<tr>")?;
+                for cell in header {
+                    write!(out, "<th>{}</th>", escape_html(cell))?;
+                }
+                writeln!(out, "</tr>")?;
+                for row in rows {
+                    write!(out, "<tr>")?;
+                    for (cell, right) in row.iter().zip(numeric.iter().chain(std::iter::repeat(&false))) {
+                        let class = if *right { " class=\"right\"" } else { "" };
+                        write!(out, "<td{}>{}</td>", class, escape_html(cell))?;
+                    }
+                    writeln!(out, "</tr>")?;
+                }
+                writeln!(out, "</table>")?;
+            }
+            Entry::SideBySide(rows) => {
+                writeln!(out, "<table class=\"side-by-side\">\n<tr><th>Go</th><th>Rust</th></tr>")?;
+                for row in rows {
+                    let class = if row.differs { " class=\"differs\"" } else { "" };
+                    writeln!(out, "<tr{}><td>{}</td><td>{}</td></tr>", class, escape_html(&row.go), escape_html(&row.rust))?;
+                }
+                writeln!(out, "</table>")?;
+            }
+        }
+    }
+    if in_code {
+        writeln!(out, "</code></pre></details>")?;
+    }
+    if in_list {
+        writeln!(out, "</ul>")?;
+    }
+    Ok(())
+}
+
+// {"title": .., "steps": [{"heading": .., "entries": [{"kind": "text", ..}]}], "alloc": ..}
+impl Renderer for Json {
+    // JSON has nowhere to put a banner
+    fn banner(&self, _text: &str, _out: &mut dyn Write) -> fmt::Result {
+        Ok(())
+    }
+
+    fn report(&self, report: &DemoReport, out: &mut dyn Write) -> fmt::Result {
+        write!(out, "{{\"title\":{},\"steps\":[", json_string(&report.title))?;
+        for (index, step) in visible_steps(report).enumerate() {
+            if index > 0 {
+                write!(out, ",")?;
+            }
+            let heading = step.heading.as_deref().map_or("null".to_string(), json_string);
+            write!(out, "{{\"heading\":{},\"entries\":[", heading)?;
+            for (index, entry) in step.entries.iter().enumerate() {
+                if index > 0 {
+                    write!(out, ",")?;
+                }
+                json_entry(entry, out)?;
+            }
+            write!(out, "]}}")?;
+        }
+        write!(out, "],\"alloc\":")?;
+        match report.alloc {
+            Some(stats) => write!(
+                out,
+                "{{\"allocations\":{},\"deallocations\":{},\"bytes_allocated\":{},\"peak_bytes\":{}}}",
+                stats.allocations, stats.deallocations, stats.bytes_allocated, stats.peak_bytes
+            )?,
+            None => write!(out, "null")?,
+        }
+        write!(out, "}}")
+    }
+
+    fn render_all(&self, reports: &[DemoReport]) -> String {
+        let objects: Vec<String> = reports.iter().map(|report| self.render(report)).collect();
+        format!("[{}]", objects.join(","))
+    }
+}
+
+fn json_entry(entry: &Entry, out: &mut dyn Write) -> fmt::Result {
+    match entry {
+        Entry::Text(text) => write!(out, "{{\"kind\":\"text\",\"text\":{}}}", json_string(text)),
+        Entry::Code(code) => write!(out, "{{\"kind\":\"code\",\"code\":{}}}", json_string(code)),
+        Entry::Note(kind, text) => {
+            let kind = match kind {
+                NoteKind::Ok => "ok",
+                NoteKind::Warn => "warn",
+                NoteKind::Fail => "fail",
+            };
+            write!(out, "{{\"kind\":\"{}\",\"text\":{}}}", kind, json_string(text))
+        }
+        Entry::Address { label, addr, value } => write!(
+            out,
+            "{{\"kind\":\"address\",\"label\":{},\"address\":\"{:#x}\",\"value\":{}}}",
+            json_string(label),
+            addr,
+            json_string(value)
+        ),
+        Entry::Count { label, value } => {
+            write!(out, "{{\"kind\":\"count\",\"label\":{},\"value\":{}}}", json_string(label), value)
+        }
+        Entry::Measurement { label, value, unit } => {
+            let unit_name = match unit {
+                Unit::Nanoseconds => "ns",
+                Unit::Bytes => "bytes",
+            };
+            // NaN and infinity aren't JSON numbers
+            let number = if value.is_finite() { value.to_string() } else { "null".to_string() };
+            write!(
+                out,
+                "{{\"kind\":\"measurement\",\"label\":{},\"value\":{},\"unit\":\"{}\",\"display\":{}}}",
+                json_string(label),
+                number,
+                unit_name,
+                json_string(&unit.format(*value))
+            )
+        }
+        Entry::Table { header, rows } => {
+            let row = |cells: &[String]| format!("[{}]", cells.iter().map(|cell| json_string(cell)).collect::<Vec<_>>().join(","));
+            let rows: Vec<String> = rows.iter().map(|cells| row(cells)).collect();
+            write!(out, "{{\"kind\":\"table\",\"header\":{},\"rows\":[{}]}}", row(header), rows.join(","))
+        }
+        Entry::SideBySide(rows) => {
+            let rows: Vec<String> = rows
+                .iter()
+                .map(|row| format!("{{\"go\":{},\"rust\":{},\"differs\":{}}}", json_string(&row.go), json_string(&row.rust), row.differs))
+                .collect();
+            write!(out, "{{\"kind\":\"side_by_side\",\"rows\":[{}]}}", rows.join(","))
+        }
+    }
+}
+
+// name,duration_ns,allocs,bytes,peak_rss
+impl Renderer for Csv {
+    // A banner isn't a row
+    fn banner(&self, _text: &str, _out: &mut dyn Write) -> fmt::Result {
+        Ok(())
+    }
+
+    // Reports nothing measured (the run summary, compare-run's diff) have no row
+    fn report(&self, report: &DemoReport, out: &mut dyn Write) -> fmt::Result {
+        let Some(elapsed) = report.elapsed else {
+            return Ok(());
+        };
+        let cell = |value: Option<usize>| value.map_or(String::new(), |value| value.to_string());
+        writeln!(
+            out,
+            "{},{},{},{},{}",
+            csv_field(report.name.as_deref().unwrap_or(&report.title)),
+            elapsed.as_nanos(),
+            cell(report.alloc.map(|stats| stats.allocations)),
+            cell(report.alloc.map(|stats| stats.bytes_allocated)),
+            cell(report.memory.map(|memory| memory.after.peak_rss)),
+        )
+    }
+
+    fn begin(&self, out: &mut dyn Write) -> fmt::Result {
+        writeln!(out, "{}", CSV_HEADER)
+    }
+}
+
+/// `text` as one CSV field, quoted only when it has to be (RFC 4180)
+pub fn csv_field(text: &str) -> String {
+    if text.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text.to_string()
+    }
+}
+
+/// `text` as a quoted JSON string
+pub fn json_string(text: &str) -> String {
+    let mut quoted = String::with_capacity(text.len() + 2);
+    quoted.push('"');
+    for c in text.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            c if (c as u32) < 0x20 => quoted.push_str(&format!("\\u{:04x}", c as u32)),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+// `code` in a table cell; an empty side stays empty
+fn markdown_code(code: &str) -> String {
+    if code.is_empty() { String::new() } else { format!("`{}`", code.replace('|', "\\|")) }
+}
+
+fn escape_cells(cells: &[String]) -> Vec<String> {
+    cells.iter().map(|cell| cell.replace('|', "\\|")).collect()
+}
+
+// Steps with something to show (empty gaps are skipped)
+fn visible_steps(report: &DemoReport) -> impl Iterator<Item = &Step> {
+    report
+        .steps
+        .iter()
+        .filter(|step| step.heading.is_some() || !step.entries.is_empty())
+}
+
+fn alloc_footer(report: &DemoReport) -> Option<String> {
+    report.alloc.map(|stats| {
+        format!(
+            "{} allocs, {} frees, {} allocated, peak +{}",
+            stats.allocations,
+            stats.deallocations,
+            format_bytes(stats.bytes_allocated),
+            format_bytes(stats.peak_bytes)
+        )
+    })
+}
+
+// Column widths: widest cell per column
+fn column_widths(header: &[String], rows: &[Vec<String>]) -> Vec<usize> {
+    let mut widths: Vec<usize> = header.iter().map(|h| h.chars().count()).collect();
+    for row in rows {
+        for (i, cell) in row.iter().enumerate() {
+            if i < widths.len() {
+                widths[i] = widths[i].max(cell.chars().count());
+            }
+        }
+    }
+    widths
+}
+
+// Numeric columns (every cell starts with a digit) are right-aligned
+fn numeric_columns(rows: &[Vec<String>], columns: usize) -> Vec<bool> {
+    (0..columns)
+        .map(|i| {
+            !rows.is_empty()
+                && rows.iter().all(|row| {
+                    row.get(i)
+                        .and_then(|cell| cell.chars().next())
+                        .is_some_and(|c| c.is_ascii_digit())
+                })
+        })
+        .collect()
+}
+
+fn pad_row(cells: &[String], widths: &[usize], numeric: &[bool]) -> String {
+    let line: Vec<String> = cells
+        .iter()
+        .zip(widths)
+        .zip(numeric)
+        .map(|((cell, width), numeric)| {
+            if *numeric {
+                format!("{:>width$}", cell, width = width)
+            } else {
+                format!("{:<width$}", cell, width = width)
+            }
+        })
+        .collect();
+    line.join("  ").trim_end().to_string()
+}
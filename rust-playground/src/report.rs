@@ -0,0 +1,412 @@
+// Structured demo output
+// Every demo returns a DemoReport instead of printing directly.
+// The data (steps, addresses, counts, notes) can be inspected by other
+// tools and tests; printing is just one renderer over it (render.rs).
+
+use std::fmt;
+use std::time::{Duration, Instant};
+
+#[cfg(feature = "alloc-sites")]
+use crate::alloc_sites::{self, AllocSite};
+use crate::alloc_stats::{self, AllocStats};
+use crate::leak_check::{self, LeakChecker};
+use crate::memviz;
+use crate::process_stats::{self, MemoryDelta};
+use crate::render::{Plain, Renderer};
+use crate::telemetry::ExampleSpan;
+
+/// The result of running one demo: a title and an ordered list of steps.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DemoReport {
+    pub title: String,
+    /// `demo::example` when it ran through the registry (set by `Demo::run`)
+    pub name: Option<String>,
+    pub steps: Vec<Step>,
+    /// Heap activity while the demo ran (with the `alloc-stats` feature)
+    pub alloc: Option<AllocStats>,
+    /// Bytes the demo left live, report excluded (with `--leak-check`)
+    pub leaked_bytes: Option<usize>,
+    /// Results repeated in the end-of-run summary (see `summary_table`)
+    pub summary: Vec<Entry>,
+    /// Memory the demo leaks deliberately, and what for (see `leak_on_purpose`)
+    pub leaked_on_purpose: Vec<(&'static str, usize)>,
+    /// Wall-clock time of the run (set by `measured`)
+    pub elapsed: Option<Duration>,
+    /// RSS around the run, its peak reset at the start where the OS allows (set by `measured`)
+    pub memory: Option<MemoryDelta>,
+}
+
+/// One logical step of a demo, optionally with a heading ("Go (allowed):").
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Step {
+    pub heading: Option<String>,
+    pub entries: Vec<Entry>,
+}
+
+/// A single piece of demo output.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Entry {
+    /// Explanatory prose
+    Text(String),
+    /// A code snippet (Rust or Go)
+    Code(String),
+    /// A takeaway: ✓ / ⚠️ / ❌
+    Note(NoteKind, String),
+    /// Where a value lives in memory
+    Address { label: String, addr: usize, value: String },
+    /// A counter observed while running (strong_count, items received, ...)
+    Count { label: String, value: usize },
+    /// A measured quantity (time, bytes)
+    Measurement { label: String, value: f64, unit: Unit },
+    /// Tabular results
+    Table { header: Vec<String>, rows: Vec<Vec<String>> },
+    /// Go and Rust code in two columns, line against line
+    SideBySide(Vec<SideBySideRow>),
+}
+
+/// One line of Go next to the Rust line doing the same job ("" when one side has none)
+#[derive(Debug, Clone, PartialEq)]
+pub struct SideBySideRow {
+    pub go: String,
+    pub rust: String,
+    /// The two lines behave differently - renderers highlight the row
+    pub differs: bool,
+}
+
+impl SideBySideRow {
+    pub fn same(go: impl Into<String>, rust: impl Into<String>) -> Self {
+        SideBySideRow { go: go.into(), rust: rust.into(), differs: false }
+    }
+
+    pub fn differs(go: impl Into<String>, rust: impl Into<String>) -> Self {
+        SideBySideRow { go: go.into(), rust: rust.into(), differs: true }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NoteKind {
+    Ok,
+    Warn,
+    Fail,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Unit {
+    Nanoseconds,
+    Bytes,
+}
+
+impl DemoReport {
+    pub fn new(title: impl Into<String>) -> Self {
+        DemoReport {
+            title: title.into(),
+            name: None,
+            steps: vec![Step::default()],
+            alloc: None,
+            leaked_bytes: None,
+            summary: Vec::new(),
+            leaked_on_purpose: Vec::new(),
+            elapsed: None,
+            memory: None,
+        }
+    }
+
+    /// Run a demo and attach the allocations it made (incl. building the report)
+    pub fn measured(demo: impl FnOnce() -> DemoReport) -> DemoReport {
+        let span = ExampleSpan::enter();
+        let checker = LeakChecker::start();
+        // RSS is read outside the clock and the allocation counts
+        let ((report, elapsed), memory) = process_stats::measure(|| {
+            let start = Instant::now();
+            #[cfg(feature = "alloc-sites")]
+            let report = alloc_sites::profile(|| alloc_stats::measure(demo));
+            #[cfg(not(feature = "alloc-sites"))]
+            let report = alloc_stats::measure(demo);
+            (report, start.elapsed())
+        });
+        #[cfg(feature = "alloc-sites")]
+        let ((mut report, stats), sites) = report;
+        #[cfg(not(feature = "alloc-sites"))]
+        let (mut report, stats) = report;
+
+        report.elapsed = Some(elapsed);
+        report.alloc = stats;
+        report.memory = memory;
+        // Before anything else runs and maps or unmaps memory
+        if memviz::enabled() {
+            memviz::annotate(&mut report);
+        }
+        // The profiler keeps its samples until the end, so skip the check under it
+        #[cfg(feature = "alloc-sites")]
+        let profiling = alloc_sites::enabled();
+        #[cfg(not(feature = "alloc-sites"))]
+        let profiling = false;
+        if let Some(leaked) = checker.leaked(report.heap_size()).filter(|_| !profiling) {
+            leak_check::review(&mut report, leaked);
+        }
+        #[cfg(feature = "alloc-sites")]
+        if let Some(sites) = sites {
+            report.alloc_sites(&sites);
+        }
+        span.close(&report);
+        report
+    }
+
+    // Top allocation sites as a final step (pprof -top style)
+    #[cfg(feature = "alloc-sites")]
+    fn alloc_sites(&mut self, sites: &[AllocSite]) {
+        const TOP: usize = 10;
+        let rate = alloc_sites::sample_rate();
+        if rate == 1 {
+            self.step("Top allocation sites");
+        } else {
+            self.step(format!("Top allocation sites (sampled every ~{})", format_bytes(rate)));
+        }
+        if sites.is_empty() {
+            self.text("No sampled allocations - try a smaller --sample-rate");
+            return;
+        }
+        let rows = sites
+            .iter()
+            .take(TOP)
+            .map(|site| {
+                vec![
+                    format_bytes(site.bytes),
+                    site.allocations.to_string(),
+                    site.location.clone(),
+                    site.callers.join(" ← "),
+                ]
+            })
+            .collect();
+        self.table(&["bytes", "allocs", "site", "called from"], rows);
+        if sites.len() > TOP {
+            self.text(format!("... and {} more sites", sites.len() - TOP));
+        }
+        self.text("(the heap totals below include the profiler's own bookkeeping)");
+        self.text("Go: go test -memprofile mem.out && go tool pprof -top mem.out");
+    }
+
+    /// Start a new step with a heading
+    pub fn step(&mut self, heading: impl Into<String>) -> &mut Self {
+        self.steps.push(Step {
+            heading: Some(heading.into()),
+            entries: vec![],
+        });
+        self
+    }
+
+    /// Start a new step without a heading (rendered as a blank-line break)
+    pub fn gap(&mut self) -> &mut Self {
+        self.steps.push(Step::default());
+        self
+    }
+
+    pub fn push(&mut self, entry: Entry) -> &mut Self {
+        self.steps
+            .last_mut()
+            .expect("a report always has at least one step")
+            .entries
+            .push(entry);
+        self
+    }
+
+    pub fn text(&mut self, text: impl Into<String>) -> &mut Self {
+        self.push(Entry::Text(text.into()))
+    }
+
+    pub fn code(&mut self, code: impl Into<String>) -> &mut Self {
+        self.push(Entry::Code(code.into()))
+    }
+
+    pub fn ok(&mut self, text: impl Into<String>) -> &mut Self {
+        self.push(Entry::Note(NoteKind::Ok, text.into()))
+    }
+
+    pub fn warn(&mut self, text: impl Into<String>) -> &mut Self {
+        self.push(Entry::Note(NoteKind::Warn, text.into()))
+    }
+
+    pub fn fail(&mut self, text: impl Into<String>) -> &mut Self {
+        self.push(Entry::Note(NoteKind::Fail, text.into()))
+    }
+
+    pub fn address<T: ?Sized>(
+        &mut self,
+        label: impl Into<String>,
+        ptr: &T,
+        value: impl Into<String>,
+    ) -> &mut Self {
+        self.push(Entry::Address {
+            label: label.into(),
+            addr: ptr as *const T as *const () as usize,
+            value: value.into(),
+        })
+    }
+
+    pub fn count(&mut self, label: impl Into<String>, value: usize) -> &mut Self {
+        self.push(Entry::Count {
+            label: label.into(),
+            value,
+        })
+    }
+
+    pub fn duration(&mut self, label: impl Into<String>, value: Duration) -> &mut Self {
+        self.push(Entry::Measurement {
+            label: label.into(),
+            value: value.as_nanos() as f64,
+            unit: Unit::Nanoseconds,
+        })
+    }
+
+    pub fn bytes(&mut self, label: impl Into<String>, value: usize) -> &mut Self {
+        self.push(Entry::Measurement {
+            label: label.into(),
+            value: value as f64,
+            unit: Unit::Bytes,
+        })
+    }
+
+    pub fn table(&mut self, header: &[&str], rows: Vec<Vec<String>>) -> &mut Self {
+        self.push(Entry::Table {
+            header: header.iter().map(|h| h.to_string()).collect(),
+            rows,
+        })
+    }
+
+    /// A table that also goes into the run summary (timing.rs) - for benchmark results
+    pub fn summary_table(&mut self, header: &[&str], rows: Vec<Vec<String>>) -> &mut Self {
+        self.table(header, rows);
+        let table = self.entries().last().cloned().expect("the table was just pushed");
+        self.summary.push(table);
+        self
+    }
+
+    /// Declare bytes this demo leaves live on purpose (a leaked &'static, an
+    /// interner): `--leak-check` lists them instead of flagging them
+    pub fn leak_on_purpose(&mut self, what: &'static str, bytes: usize) -> &mut Self {
+        self.leaked_on_purpose.push((what, bytes));
+        self
+    }
+
+    /// Go on the left, Rust on the right; rows built with SideBySideRow::same / ::differs
+    pub fn side_by_side(&mut self, rows: Vec<SideBySideRow>) -> &mut Self {
+        self.push(Entry::SideBySide(rows))
+    }
+
+    /// Heap bytes owned by the report itself (every String and Vec buffer)
+    pub fn heap_size(&self) -> usize {
+        fn strings(strings: &[String]) -> usize {
+            strings.iter().map(String::capacity).sum()
+        }
+        let entry_size = |entry: &Entry| -> usize {
+            match entry {
+                Entry::Text(text) | Entry::Code(text) | Entry::Note(_, text) => text.capacity(),
+                Entry::Address { label, value, .. } => label.capacity() + value.capacity(),
+                Entry::Count { label, .. } | Entry::Measurement { label, .. } => label.capacity(),
+                Entry::Table { header, rows } => {
+                    header.capacity() * size_of::<String>()
+                        + strings(header)
+                        + rows.capacity() * size_of::<Vec<String>>()
+                        + rows.iter().map(|row| row.capacity() * size_of::<String>() + strings(row)).sum::<usize>()
+                }
+                Entry::SideBySide(rows) => {
+                    rows.capacity() * size_of::<SideBySideRow>()
+                        + rows.iter().map(|row| row.go.capacity() + row.rust.capacity()).sum::<usize>()
+                }
+            }
+        };
+        let entries = |step: &Step| -> usize { step.entries.iter().map(entry_size).sum() };
+        self.title.capacity()
+            + self.steps.capacity() * size_of::<Step>()
+            + self
+                .steps
+                .iter()
+                .map(|step| {
+                    step.heading.as_ref().map_or(0, String::capacity)
+                        + step.entries.capacity() * size_of::<Entry>()
+                        + entries(step)
+                })
+                .sum::<usize>()
+            + self.summary.capacity() * size_of::<Entry>()
+            + self.summary.iter().map(entry_size).sum::<usize>()
+            + self.leaked_on_purpose.capacity() * size_of::<(&str, usize)>()
+    }
+
+    /// All entries across every step, in order
+    pub fn entries(&self) -> impl Iterator<Item = &Entry> {
+        self.steps.iter().flat_map(|step| step.entries.iter())
+    }
+
+    /// Look up a recorded count by label (first match)
+    pub fn count_of(&self, label: &str) -> Option<usize> {
+        self.entries().find_map(|entry| match entry {
+            Entry::Count { label: l, value } if l == label => Some(*value),
+            _ => None,
+        })
+    }
+
+    /// Plain text lines across every step, in order
+    pub fn texts(&self) -> impl Iterator<Item = &str> {
+        self.entries().filter_map(|entry| match entry {
+            Entry::Text(text) => Some(text.as_str()),
+            _ => None,
+        })
+    }
+
+    /// The rows of every table, in order
+    pub fn tables(&self) -> impl Iterator<Item = &[Vec<String>]> {
+        self.entries().filter_map(|entry| match entry {
+            Entry::Table { rows, .. } => Some(rows.as_slice()),
+            _ => None,
+        })
+    }
+
+    /// Notes of one kind, e.g. every ✓ takeaway
+    pub fn notes(&self, kind: NoteKind) -> impl Iterator<Item = &str> {
+        self.entries().filter_map(move |entry| match entry {
+            Entry::Note(k, text) if *k == kind => Some(text.as_str()),
+            _ => None,
+        })
+    }
+}
+
+impl NoteKind {
+    pub fn symbol(self) -> &'static str {
+        match self {
+            NoteKind::Ok => "✓",
+            NoteKind::Warn => "⚠️",
+            NoteKind::Fail => "❌",
+        }
+    }
+}
+
+impl Unit {
+    pub fn format(self, value: f64) -> String {
+        match self {
+            Unit::Nanoseconds => format!("{:?}", Duration::from_nanos(value as u64)),
+            Unit::Bytes => format_bytes(value as usize),
+        }
+    }
+}
+
+pub fn format_bytes(bytes: usize) -> String {
+    const GIB: f64 = 1024.0 * 1024.0 * 1024.0;
+    if bytes as f64 >= 1024.0 * GIB {
+        format!("{:.1} TiB", bytes as f64 / (1024.0 * GIB))
+    } else if bytes as f64 >= GIB {
+        format!("{:.1} GiB", bytes as f64 / GIB)
+    } else if bytes >= 1024 * 1024 {
+        format!("{:.1} MiB", bytes as f64 / (1024.0 * 1024.0))
+    } else if bytes >= 1024 {
+        format!("{:.1} KiB", bytes as f64 / 1024.0)
+    } else {
+        format!("{} B", bytes)
+    }
+}
+
+// The plain-text renderer (see render.rs for the others)
+impl fmt::Display for DemoReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Plain.report(self, f)
+    }
+}
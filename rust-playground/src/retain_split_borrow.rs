@@ -0,0 +1,267 @@
+// Split borrows - two &mut into one struct or one slice, and when they're allowed
+// Go:   take as many pointers into a struct or a slice as you like:
+//       a := &s.A; b := &s.B; p := &v[0]; q := &v[1]. Nothing checks that
+//       they don't overlap, and an append can leave p pointing at the old array.
+// Rust: the borrow checker tracks places. `&mut s.a` and `&mut s.b` are two
+//       different places, so both at once is fine. But `v[i]` is a call to
+//       IndexMut that borrows all of `v`, and `s.a_mut()` borrows all of `s`:
+//       from outside, the checker can't see they'd be disjoint. The fixes are
+//       APIs that prove it: split_at_mut, iter_mut, chunks_mut,
+//       get_disjoint_mut, and methods that hand out several fields at once.
+
+use crate::registry::{Demo, Difficulty, Example, Tag};
+use crate::report::{DemoReport, SideBySideRow};
+
+/// A game entity: position and inventory are updated together
+#[derive(Debug, Clone, Default)]
+pub struct Player {
+    pub name: String,
+    pub position: (i32, i32),
+    pub inventory: Vec<String>,
+}
+
+impl Player {
+    pub fn position_mut(&mut self) -> &mut (i32, i32) {
+        &mut self.position
+    }
+
+    pub fn inventory_mut(&mut self) -> &mut Vec<String> {
+        &mut self.inventory
+    }
+
+    /// Both at once: inside the impl the checker sees the two fields
+    pub fn parts_mut(&mut self) -> (&mut (i32, i32), &mut Vec<String>) {
+        (&mut self.position, &mut self.inventory)
+    }
+}
+
+/// Readings with a threshold, filtered in place
+#[derive(Debug, Clone, Default)]
+pub struct Sensor {
+    pub readings: Vec<i32>,
+    pub threshold: i32,
+    pub dropped: usize,
+}
+
+impl Sensor {
+    pub fn keeps(&self, reading: i32) -> bool {
+        reading >= self.threshold
+    }
+
+    /// Keep readings at or above the threshold: the closure captures
+    /// `self.threshold` and `self.dropped` only, not `self`
+    pub fn filter(&mut self) {
+        self.readings.retain(|&reading| {
+            let keep = reading >= self.threshold;
+            if !keep {
+                self.dropped += 1;
+            }
+            keep
+        });
+    }
+}
+
+/// Pour every bucket into the next one, ending with all items in the last:
+/// two &mut into one slice per step
+pub fn cascade(buckets: &mut [Vec<String>]) {
+    for i in 1..buckets.len() {
+        let (before, after) = buckets.split_at_mut(i);
+        after[0].append(&mut before[i - 1]);
+    }
+}
+
+// Two fields of one struct: fine directly, not through accessor methods
+pub fn fields() -> DemoReport {
+    let mut report = DemoReport::new("Disjoint Fields: Two &mut at Once");
+
+    let mut player = Player { name: "ferris".into(), ..Player::default() };
+    report.code("let position = &mut player.position;");
+    report.code("let inventory = &mut player.inventory;  // a different place: allowed");
+    let position = &mut player.position;
+    let inventory = &mut player.inventory;
+    position.0 += 3;
+    inventory.push("map".into());
+    report.address("&mut player.position", position, format!("{:?}", position));
+    report.address("&mut player.inventory", inventory, format!("{:?}", inventory));
+    report.text(format!("{} bytes apart, no overlap: the checker tracks player.position and player.inventory as separate places", (inventory as *const Vec<String> as usize).abs_diff(position as *const (i32, i32) as usize)));
+
+    report.step("Through methods");
+    report.code("let position = player.position_mut();   // borrows ALL of player");
+    report.code("let inventory = player.inventory_mut();");
+    report.code("// ❌ error[E0499]: cannot borrow `player` as mutable more than once at a time");
+    report.text("Checked by tests/compile_fail/split_borrow_through_methods.rs - a signature says `&mut self`, not which fields");
+    report.code("let (position, inventory) = player.parts_mut();  // one method, both fields");
+    let (position, inventory) = player.parts_mut();
+    position.1 -= 1;
+    inventory.push("torch".into());
+    report.text(format!("player = {:?}", player));
+
+    report.gap();
+    report.ok("Field by field, the checker splits the borrow for you");
+    report.warn("Getters hide the split: inside the impl, hand out every field you need in one call");
+    report
+}
+
+// v[i] borrows all of v; split_at_mut and friends prove the halves disjoint
+pub fn slices() -> DemoReport {
+    let mut report = DemoReport::new("Two Elements of One Vec");
+
+    report.code("let first = &mut scores[0];");
+    report.code("let last = &mut scores[3];  // ❌ error[E0499]: cannot borrow `scores` as mutable more than once");
+    report.text("Checked by tests/compile_fail/two_mut_indexes.rs - v[i] is IndexMut::index_mut(&mut v, i)");
+
+    report.step("APIs that prove the split");
+    let mut scores = vec![10, 20, 30, 40];
+    let (front, back) = scores.split_at_mut(2);
+    std::mem::swap(&mut front[0], &mut back[1]);
+    report.code("let (front, back) = scores.split_at_mut(2);  mem::swap(&mut front[0], &mut back[1]);");
+    report.text(format!("scores = {:?}", scores));
+
+    if let Some((first, rest)) = scores.split_first_mut() {
+        *first += rest.iter().sum::<i32>();
+    }
+    report.code("if let Some((first, rest)) = scores.split_first_mut() { *first += rest.iter().sum(); }");
+    report.text(format!("scores = {:?}", scores));
+
+    if let Ok([a, b]) = scores.get_disjoint_mut([1, 3]) {
+        std::mem::swap(a, b);
+    }
+    report.code("if let Ok([a, b]) = scores.get_disjoint_mut([1, 3]) { mem::swap(a, b); }");
+    report.text(format!("scores = {:?}", scores));
+    let overlap = scores.get_disjoint_mut([2, 2]).map(|_| ());
+    report.code("scores.get_disjoint_mut([2, 2])");
+    report.text(format!("→ {:?}: overlapping indices are checked at run time instead", overlap));
+
+    report.step("A loop of split_at_mut");
+    let mut buckets: Vec<Vec<String>> = vec![vec!["a".into()], vec!["b".into(), "c".into()], vec![], vec!["d".into()]];
+    report.code("buckets[i].append(&mut buckets[i - 1]);  // ❌ E0499, like two_mut_indexes.rs: two &mut into `buckets`");
+    report.code("let (before, after) = buckets.split_at_mut(i);  after[0].append(&mut before[i - 1]);");
+    cascade(&mut buckets);
+    report.text(format!("buckets = {:?}", buckets));
+    report.text("(v[i] += v[i - 1] needs no split: v[i - 1] is copied out before v[i] is borrowed)");
+
+    report.gap();
+    report.ok("split_at_mut is one bounds check and unsafe inside - see unsafe-rust: safe-abstraction");
+    report.ok("get_disjoint_mut covers arbitrary indices: Err for overlap or out of bounds, never UB");
+    report
+}
+
+// iter_mut hands out a &mut per element - each one disjoint
+pub fn iter_mut() -> DemoReport {
+    let mut report = DemoReport::new("iter_mut and chunks_mut: Many &mut, None Overlapping");
+
+    let mut prices = vec![100, 250, 399, 75];
+    report.code("for price in prices.iter_mut() { *price = *price * 9 / 10; }");
+    for price in prices.iter_mut() {
+        *price = *price * 9 / 10;
+    }
+    report.text(format!("prices = {:?}", prices));
+
+    let refs: Vec<&mut i32> = prices.iter_mut().collect();
+    report.code("let refs: Vec<&mut i32> = prices.iter_mut().collect();  // all alive at once");
+    report.count("&mut i32 alive at the same time", refs.len());
+    for (index, price) in refs.into_iter().enumerate() {
+        *price += index as i32;
+    }
+    report.text(format!("prices = {:?}", prices));
+
+    report.step("Pairs and chunks");
+    let mut pixels = vec![0u8; 12];
+    for (index, pixel) in pixels.chunks_mut(3).enumerate() {
+        pixel.copy_from_slice(&[index as u8, 128, 255 - index as u8]);
+    }
+    report.code("for (i, pixel) in pixels.chunks_mut(3).enumerate() { pixel.copy_from_slice(&[i, 128, 255 - i]); }");
+    report.text(format!("pixels = {:?}", pixels));
+
+    let (left, right) = pixels.split_at_mut(6);
+    for (l, r) in left.iter_mut().zip(right.iter_mut()) {
+        std::mem::swap(l, r);
+    }
+    report.code("let (left, right) = pixels.split_at_mut(6);  for (l, r) in left.iter_mut().zip(right) { mem::swap(l, r); }");
+    report.text(format!("pixels = {:?}", pixels));
+    report.text("There's no windows_mut: windows overlap, so two of them alive at once would alias");
+
+    report.gap();
+    report.ok("An iterator of &mut is a promise: every item is a different element");
+    report
+}
+
+// retain's closure can capture other fields - but not call &self methods
+pub fn retain() -> DemoReport {
+    let mut report = DemoReport::new("retain: Filtering a Field by Its Neighbours");
+
+    let mut sensor = Sensor { readings: vec![3, 12, 7, 15, 9, 21], threshold: 10, dropped: 0 };
+    report.code("self.readings.retain(|&r| { let keep = r >= self.threshold; if !keep { self.dropped += 1 } keep });");
+    sensor.filter();
+    report.text(format!("readings = {:?}, dropped = {}", sensor.readings, sensor.dropped));
+    report.text("Since edition 2021 a closure captures the fields it names (self.threshold, self.dropped), not all of self");
+
+    report.step("Calling a method in the closure");
+    report.code("self.readings.retain(|&r| self.keeps(r));");
+    report.code("// ❌ error[E0502]: cannot borrow `self.readings` as mutable because it is also borrowed as immutable");
+    report.text("Checked by tests/compile_fail/retain_calls_self_method.rs - keeps(&self) borrows all of self, self.readings included");
+    report.table(
+        &["fix", "how"],
+        vec![
+            vec!["name the fields".into(), "|&r| r >= self.threshold  - captures one field".into()],
+            vec!["copy it out first".into(), "let threshold = self.threshold; ..retain(|&r| r >= threshold)".into()],
+            vec!["split the struct".into(), "let Sensor { readings, threshold, .. } = self;  readings.retain(|&r| r >= *threshold)".into()],
+            vec!["take the Vec".into(), "let mut readings = mem::take(&mut self.readings); readings.retain(|&r| self.keeps(r)); self.readings = readings;".into()],
+        ],
+    );
+    let mut other = Sensor { readings: vec![1, 20], threshold: 5, dropped: 0 };
+    let mut readings = std::mem::take(&mut other.readings);
+    readings.retain(|&reading| other.keeps(reading));
+    other.readings = readings;
+    report.text(format!("take-the-Vec fix: readings = {:?}", other.readings));
+
+    report.gap();
+    report.ok("retain is in place: one pass, kept items shift down, no new buffer");
+    report.warn("A &self method is a borrow of every field - including the one being filtered");
+    report
+}
+
+pub fn split_vs_go() -> DemoReport {
+    let mut report = DemoReport::new("Split Borrows: Go vs Rust");
+
+    report.side_by_side(vec![
+        SideBySideRow::same("pos, inv := &p.Pos, &p.Inv", "let (pos, inv) = (&mut p.position, &mut p.inventory);"),
+        SideBySideRow::differs("a, b := p.PosPtr(), p.InvPtr()", "let (a, b) = p.parts_mut();  // one method: two calls would be E0499"),
+        SideBySideRow::differs("a, b := &v[0], &v[3]", "let [a, b] = v.get_disjoint_mut([0, 3])?;"),
+        SideBySideRow::differs("a, b := &v[1], &v[1]  // same element, both writable", "v.get_disjoint_mut([1, 1])  // Err(OverlappingIndices)"),
+        SideBySideRow::differs("p := &v[0]; v = append(v, x)  // p may point at the old array", "let p = &v[0]; v.push(x);  // ❌ E0502 (see slices-and-strings)"),
+        SideBySideRow::same("v = slices.DeleteFunc(v, func(x int) bool { return x < t })", "v.retain(|&x| x >= t);"),
+    ]);
+
+    report.table(
+        &["", "Go", "Rust"],
+        vec![
+            vec!["two pointers into one struct".into(), "always allowed".into(), "allowed for distinct fields".into()],
+            vec!["two pointers into one slice".into(), "always allowed, may alias".into(), "split_at_mut / get_disjoint_mut".into()],
+            vec!["pointer across append".into(), "compiles, may go stale".into(), "rejected at compile time".into()],
+            vec!["in-place filter".into(), "slices.DeleteFunc".into(), "Vec::retain / retain_mut".into()],
+        ],
+    );
+
+    report.gap();
+    report.ok("Go never asks whether two pointers overlap; Rust asks, and the split APIs answer");
+    report
+}
+
+pub const DEMO: Demo = Demo {
+    name: "retain-split-borrow",
+    about: "Split borrows: two &mut to disjoint fields, split_at_mut / get_disjoint_mut vs v[i] twice, iter_mut, retain closures vs Go's free aliasing",
+    difficulty: Difficulty::Intermediate,
+    tags: &[Tag::Borrowing],
+    examples: &[
+        Example { name: "fields", run: fields },
+        Example { name: "slices", run: slices },
+        Example { name: "iter-mut", run: iter_mut },
+        Example { name: "retain", run: retain },
+        Example { name: "vs-go", run: split_vs_go },
+    ],
+};
+
+pub fn demonstrate_retain_split_borrow() -> Vec<DemoReport> {
+    DEMO.run()
+}
@@ -0,0 +1,271 @@
+// RwLock - Go's sync.RWMutex vs std::sync::RwLock
+// Go:   mu.RLock(); defer mu.RUnlock() - many readers, one writer, and the
+//       data sits next to the lock (nothing stops you touching it unlocked)
+// Rust: lock.read() / lock.write() return guards; the data is INSIDE the
+//       lock, and a panic while writing poisons it for everyone after
+
+use std::collections::HashMap;
+use std::panic;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Barrier, Mutex, RwLock};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::registry::{Demo, Difficulty, Example, Tag};
+use crate::report::DemoReport;
+
+pub(crate) const READERS: usize = 4;
+pub(crate) const READS_PER_READER: usize = 20_000;
+pub(crate) const WRITES: usize = 20;
+const ROUTES: usize = 64;
+
+// Readers really do hold the lock at the same time
+pub fn concurrent_readers() -> DemoReport {
+    let mut report = DemoReport::new("Many Readers at Once - RwLock::read");
+
+    let config = Arc::new(RwLock::new(vec!["debug".to_string(), "port=8080".to_string()]));
+    let inside = Arc::new(AtomicUsize::new(0));
+    let most_inside = Arc::new(AtomicUsize::new(0));
+    let all_reading = Arc::new(Barrier::new(READERS));
+
+    let handles: Vec<_> = (0..READERS)
+        .map(|_| {
+            let (config, inside, most_inside) = (Arc::clone(&config), Arc::clone(&inside), Arc::clone(&most_inside));
+            let all_reading = Arc::clone(&all_reading);
+            thread::spawn(move || {
+                let guard = config.read().unwrap();
+                let now = inside.fetch_add(1, Ordering::SeqCst) + 1;
+                most_inside.fetch_max(now, Ordering::SeqCst);
+                all_reading.wait();  // Every reader waits here WHILE holding its guard
+                inside.fetch_sub(1, Ordering::SeqCst);
+                guard.len()
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    report.count("Readers", READERS);
+    report.count("Most guards held at once", most_inside.load(Ordering::SeqCst));
+    report.gap();
+    report.ok("All readers met at a Barrier while holding read guards");
+    report.text("With a Mutex this would deadlock: the second reader never gets in");
+
+    report.step("Writers are exclusive");
+    {
+        let mut guard = config.write().unwrap();
+        guard.push("verbose".to_string());
+        report.text(format!("try_read() while a write guard is alive: {}", lock_state(config.try_read().is_ok())));
+    }
+    report.text(format!("try_read() after the write guard dropped: {}", lock_state(config.try_read().is_ok())));
+
+    report.step("Go");
+    report.code("mu.RLock()");
+    report.code("defer mu.RUnlock()");
+    report.code("_ = config  // the lock and the data are separate variables");
+    report.ok("Rust: no guard, no data - you can't read config without read()");
+    report
+}
+
+fn lock_state(acquired: bool) -> &'static str {
+    if acquired { "acquired" } else { "WouldBlock" }
+}
+
+// The locks behind one interface so the workload is identical (parking_lot's
+// implementations live in parking_lot_locks.rs)
+pub(crate) trait RouteTable: Send + Sync {
+    fn lookup(&self, key: usize) -> u64;
+    fn update(&self, key: usize, value: u64);
+}
+
+impl RouteTable for Mutex<HashMap<usize, Vec<u64>>> {
+    fn lookup(&self, key: usize) -> u64 {
+        self.lock().unwrap()[&key].iter().sum()
+    }
+
+    fn update(&self, key: usize, value: u64) {
+        self.lock().unwrap().insert(key, vec![value; 32]);
+    }
+}
+
+impl RouteTable for RwLock<HashMap<usize, Vec<u64>>> {
+    fn lookup(&self, key: usize) -> u64 {
+        self.read().unwrap()[&key].iter().sum()
+    }
+
+    fn update(&self, key: usize, value: u64) {
+        self.write().unwrap().insert(key, vec![value; 32]);
+    }
+}
+
+pub(crate) fn routes() -> HashMap<usize, Vec<u64>> {
+    (0..ROUTES).map(|key| (key, vec![key as u64; 32])).collect()
+}
+
+// READERS threads doing lookups while one writer updates now and then
+pub(crate) fn read_heavy_workload(table: Arc<dyn RouteTable>) -> Duration {
+    let start = Instant::now();
+    let writer = {
+        let table = Arc::clone(&table);
+        thread::spawn(move || {
+            for i in 0..WRITES {
+                table.update(i % ROUTES, i as u64);
+                thread::sleep(Duration::from_micros(200));
+            }
+        })
+    };
+    let readers: Vec<_> = (0..READERS)
+        .map(|r| {
+            let table = Arc::clone(&table);
+            thread::spawn(move || {
+                let mut checksum = 0u64;
+                for i in 0..READS_PER_READER {
+                    checksum = checksum.wrapping_add(table.lookup((i + r) % ROUTES));
+                }
+                checksum
+            })
+        })
+        .collect();
+    for reader in readers {
+        std::hint::black_box(reader.join().unwrap());
+    }
+    writer.join().unwrap();
+    start.elapsed()
+}
+
+// Same read-heavy workload behind a Mutex and an RwLock
+pub fn read_heavy_throughput() -> DemoReport {
+    let mut report = DemoReport::new("Read-Heavy Workload - Mutex vs RwLock");
+
+    let reads = READERS * READS_PER_READER;
+    let cpus = thread::available_parallelism().map_or(1, |n| n.get());
+    report.count("Reader threads", READERS);
+    report.count("Lookups", reads);
+    report.count("Writes", WRITES);
+    report.count("CPUs available", cpus);
+
+    let mutex = read_heavy_workload(Arc::new(Mutex::new(routes())));
+    let rwlock = read_heavy_workload(Arc::new(RwLock::new(routes())));
+    let per_second = |elapsed: Duration| format!("{:.0}", reads as f64 / elapsed.as_secs_f64());
+
+    report.table(
+        &["Lock", "Time", "Lookups/s"],
+        vec![
+            vec!["Mutex<HashMap>".into(), format!("{:?}", mutex), per_second(mutex)],
+            vec!["RwLock<HashMap>".into(), format!("{:?}", rwlock), per_second(rwlock)],
+        ],
+    );
+
+    report.gap();
+    if cpus == 1 {
+        report.warn("One CPU: readers can't overlap, so RwLock only adds bookkeeping");
+    } else if rwlock < mutex {
+        report.ok(format!("RwLock: {:.1}x faster - lookups run in parallel", mutex.as_secs_f64() / rwlock.as_secs_f64()));
+    } else {
+        report.warn("RwLock didn't win this run - short critical sections favor a plain Mutex");
+    }
+    report.text("RwLock pays off when reads dominate AND hold the lock long enough to overlap");
+    report.text("Tiny critical sections (a counter) → Mutex or an atomic is usually faster");
+
+    report.step("Go");
+    report.code("var mu sync.RWMutex");
+    report.code("mu.RLock(); v := routes[key]; mu.RUnlock()");
+    report.text("Same trade-off: RWMutex's reader count is itself a contended atomic");
+    report
+}
+
+// A panic inside write() poisons the lock
+pub fn poisoning() -> DemoReport {
+    let mut report = DemoReport::new("Poisoning - A Panicking Writer Marks the Data Suspect");
+
+    let balances = Arc::new(RwLock::new(vec![100, 100]));
+
+    report.step("A writer panics halfway through a transfer");
+    // Silence the default panic message; the join result tells us
+    let previous_hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let writer = {
+        let balances = Arc::clone(&balances);
+        thread::spawn(move || {
+            let mut guard = balances.write().unwrap();
+            guard[0] -= 50;
+            panic!("network error before crediting account 1");
+        })
+    };
+    let joined = writer.join();
+    panic::set_hook(previous_hook);
+    if joined.is_err() {
+        report.fail("writer thread panicked - join() = Err");
+    }
+    report.text(format!("is_poisoned() = {}", balances.is_poisoned()));
+
+    report.step("Every later read() sees the poison");
+    match balances.read() {
+        Ok(guard) => report.text(format!("read() = Ok({:?})", *guard)),
+        Err(poisoned) => {
+            report.warn("read() = Err(PoisonError)");
+            report.text(format!("data inside: {:?} - 50 debited, never credited", *poisoned.into_inner()))
+        }
+    };
+
+    report.step("Recover deliberately");
+    report.code("let mut guard = lock.write().unwrap_or_else(PoisonError::into_inner);");
+    {
+        let mut guard = balances.write().unwrap_or_else(|poisoned| poisoned.into_inner());
+        guard[1] += 50;  // Finish the transfer
+    }
+    balances.clear_poison();
+    report.text(format!("after repair + clear_poison(): {:?}", *balances.read().unwrap()));
+
+    report.gap();
+    report.ok("The half-done update can't be read by accident - you must opt in");
+    report.text("Most code just .unwrap()s: a poisoned lock then panics loudly, not silently");
+
+    report.step("Go - no poisoning");
+    report.code("mu.Lock()");
+    report.code("defer mu.Unlock()  // runs during the panic");
+    report.code("balances[0] -= 50");
+    report.code("panic(\"network error\")");
+    report.warn("If a recover() catches it, the next RLock() reads [50 100] - no warning");
+    report
+}
+
+pub fn rwlock_vs_go() -> DemoReport {
+    let mut report = DemoReport::new("sync.RWMutex vs std::sync::RwLock");
+
+    report.table(
+        &["", "Go sync.RWMutex", "Rust RwLock<T>"],
+        vec![
+            vec!["read".into(), "RLock() / RUnlock()".into(), "read() → guard, unlocks on drop".into()],
+            vec!["write".into(), "Lock() / Unlock()".into(), "write() → guard, unlocks on drop".into()],
+            vec!["try".into(), "TryRLock / TryLock".into(), "try_read / try_write".into()],
+            vec!["data".into(), "beside the lock".into(), "inside the lock".into()],
+            vec!["panic in writer".into(), "unlocked, no trace".into(), "poisoned".into()],
+            vec!["forgotten unlock".into(), "deadlock".into(), "impossible (guard)".into()],
+        ],
+    );
+
+    report.gap();
+    report.warn("Both: taking read() twice on one thread can deadlock if a writer queues in between");
+    report.warn("Both: no upgrade - holding read() and calling write() deadlocks");
+    report.text("std's RwLock doesn't promise a fairness policy; Go blocks new readers once a writer waits");
+    report
+}
+
+pub const DEMO: Demo = Demo {
+    name: "rwlock",
+    about: "RwLock vs Go's sync.RWMutex: concurrent readers, read-heavy throughput, poisoning",
+    difficulty: Difficulty::Intermediate,
+    tags: &[Tag::Concurrency],
+    examples: &[
+        Example { name: "readers", run: concurrent_readers },
+        Example { name: "throughput", run: read_heavy_throughput },
+        Example { name: "poisoning", run: poisoning },
+        Example { name: "vs-go", run: rwlock_vs_go },
+    ],
+};
+
+pub fn demonstrate_rwlock() -> Vec<DemoReport> {
+    DEMO.run()
+}
@@ -0,0 +1,180 @@
+// Scoped threads - borrowing stack data across threads
+// thread::spawn needs 'static closures: anything shared must be moved in or
+// put behind an Arc. thread::scope guarantees every thread it spawns is
+// joined before the scope returns, so threads may borrow the caller's locals.
+// Go: closures capture anything (the GC keeps it alive); sync.WaitGroup
+// waits, but nothing checks that you actually called wg.Wait().
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use crate::registry::{Demo, Difficulty, Example, Tag};
+use crate::report::DemoReport;
+
+const WORKERS: usize = 4;
+
+// Threads read a borrowed slice - no Arc, no clone
+pub fn borrow_stack_data() -> DemoReport {
+    let mut report = DemoReport::new("thread::scope - Threads Borrow Local Data");
+
+    let numbers: Vec<u64> = (1..=1_000).collect();
+    report.address("numbers (owned by this function)", &numbers[0], format!("{} items", numbers.len()));
+
+    let chunk_size = numbers.len().div_ceil(WORKERS);
+    let partials: Vec<u64> = thread::scope(|s| {
+        let handles: Vec<_> = numbers
+            .chunks(chunk_size)
+            .map(|chunk| s.spawn(|| chunk.iter().sum::<u64>()))  // Borrows `chunk`
+            .collect();
+        handles.into_iter().map(|h| h.join().unwrap()).collect()
+    });  // ← every thread is joined here, so the borrows end here
+
+    for (i, partial) in partials.iter().enumerate() {
+        report.text(format!("worker {}: partial sum = {}", i, partial));
+    }
+    report.count("Total", partials.iter().sum::<u64>() as usize);
+    report.text(format!("numbers still usable afterwards: len = {}", numbers.len()));
+
+    report.gap();
+    report.ok("Closures borrow slices of `numbers` - no move, no Arc, no clone");
+    report.ok("The scope can't return until all threads finish → borrows can't dangle");
+    report.code("thread::spawn(|| chunk.iter().sum())  // ❌ borrowed data may not live long enough");
+    report
+}
+
+// The rc_weak Arc<Mutex> example, redone with a scope
+pub fn scoped_vs_arc_mutex() -> DemoReport {
+    let mut report = DemoReport::new("Scoped Threads vs Arc<Mutex<T>>");
+
+    report.step("With thread::spawn (see rc_weak: arc-mutex)");
+    let data = Arc::new(Mutex::new(vec![1, 2, 3]));
+    let handles: Vec<_> = (0..3)
+        .map(|i| {
+            let data = Arc::clone(&data);
+            thread::spawn(move || data.lock().unwrap().push(i + 10))
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+    report.text(format!("Final: {:?}", data.lock().unwrap()));
+    report.text("Arc: one heap allocation + an atomic increment/decrement per thread");
+
+    report.step("With thread::scope: shared data stays on the stack");
+    let data = Mutex::new(vec![1, 2, 3]);
+    thread::scope(|s| {
+        for i in 0..3 {
+            let data = &data;
+            s.spawn(move || data.lock().unwrap().push(i + 10));
+        }
+    });
+    report.text(format!("Final: {:?}", data.lock().unwrap()));
+    report.text("&Mutex<Vec<_>> is enough - Mutex is Sync, so &Mutex is Send");
+
+    report.step("Disjoint &mut chunks: no lock at all");
+    let mut pixels = vec![0u8; 16];
+    thread::scope(|s| {
+        for (i, chunk) in pixels.chunks_mut(4).enumerate() {
+            s.spawn(move || chunk.fill(i as u8 + 1));  // Each thread owns one &mut slice
+        }
+    });
+    report.text(format!("pixels: {:?}", pixels));
+
+    report.gap();
+    report.ok("Arc is for data that must outlive the spawning function");
+    report.ok("If the work finishes before you return, borrow it with a scope");
+    report.ok("chunks_mut hands out non-overlapping &mut → parallel writes without a Mutex");
+    report
+}
+
+// Go's captured loop variable bug, and why Rust can't have it
+pub fn captured_loop_variable() -> DemoReport {
+    let mut report = DemoReport::new("Captured Loop Variables - Go's Classic Bug");
+
+    report.step("Go (before 1.22)");
+    report.code("for i := 0; i < 3; i++ {");
+    report.code("    go func() { fmt.Println(i) }()  // captures the ONE variable i");
+    report.code("}");
+    report.fail("Typically prints 3 3 3: every goroutine reads i after the loop is done");
+    report.warn("It's also a data race: the loop writes i while goroutines read it");
+    report.text("Go 1.22 made loop variables per-iteration - old code and go.mod < 1.22 keep the bug");
+
+    report.step("Rust");
+    report.code("for i in 0..3 {");
+    report.code("    thread::spawn(|| println!(\"{}\", i));  // ❌ E0373: closure may outlive i");
+    report.code("}");
+    report.text("Checked by tests/compile_fail/spawn_borrows_loop_variable.rs");
+
+    report.code("thread::spawn(move || println!(\"{}\", i));  // ✓ each closure gets its own copy");
+    let seen = Mutex::new(Vec::new());
+    thread::scope(|s| {
+        for i in 0..3 {
+            let seen = &seen;
+            s.spawn(move || seen.lock().unwrap().push(i));
+        }
+    });
+    let mut seen = seen.into_inner().unwrap();
+    seen.sort_unstable();
+    report.text(format!("Values seen by the threads: {:?}", seen));
+
+    report.step("Shared counters must say so");
+    let counter = AtomicUsize::new(0);
+    thread::scope(|s| {
+        for _ in 0..3 {
+            s.spawn(|| counter.fetch_add(1, Ordering::Relaxed));
+        }
+    });
+    report.count("counter (AtomicUsize, borrowed)", counter.into_inner());
+
+    report.gap();
+    report.ok("move copies i into each closure - there is no shared loop variable");
+    report.ok("To really share a variable, the type must be Sync (atomic, Mutex, ...)");
+    report
+}
+
+pub fn scoped_threads_vs_go() -> DemoReport {
+    let mut report = DemoReport::new("Scoped Threads: Rust vs Go");
+
+    report.step("Go");
+    report.code("var wg sync.WaitGroup");
+    report.code("for _, chunk := range chunks {");
+    report.code("    wg.Add(1)");
+    report.code("    go func() { defer wg.Done(); sum(chunk) }()");
+    report.code("}");
+    report.code("wg.Wait()  // forget this and main moves on while goroutines still run");
+
+    report.step("Rust");
+    report.code("thread::scope(|s| {");
+    report.code("    for chunk in data.chunks(n) { s.spawn(|| sum(chunk)); }");
+    report.code("});  // implicit join of every spawned thread");
+
+    report.gap();
+    report.table(
+        &["", "Go WaitGroup", "thread::scope"],
+        vec![
+            vec!["join".into(), "manual Add/Done/Wait".into(), "automatic at scope end".into()],
+            vec!["borrow locals".into(), "yes (GC keeps them alive)".into(), "yes (checked lifetimes)".into()],
+            vec!["forgot to wait".into(), "silent bug".into(), "impossible".into()],
+            vec!["panic in a worker".into(), "crashes the process".into(), "re-raised at scope end".into()],
+        ],
+    );
+    report
+}
+
+pub const DEMO: Demo = Demo {
+    name: "scoped-threads",
+    about: "Scoped threads vs WaitGroup and captured loop variables",
+    difficulty: Difficulty::Intermediate,
+    tags: &[Tag::Concurrency, Tag::Borrowing],
+    examples: &[
+        Example { name: "borrow", run: borrow_stack_data },
+        Example { name: "vs-arc", run: scoped_vs_arc_mutex },
+        Example { name: "loop-variable", run: captured_loop_variable },
+        Example { name: "vs-go", run: scoped_threads_vs_go },
+    ],
+};
+
+pub fn demonstrate_scoped_threads() -> Vec<DemoReport> {
+    DEMO.run()
+}
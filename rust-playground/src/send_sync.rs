@@ -0,0 +1,179 @@
+// Send and Sync - the marker traits that make data races a compile error
+// Send: a value can be MOVED to another thread
+// Sync: a &T can be SHARED between threads (T: Sync ⇔ &T: Send)
+// The compiler derives both automatically from a type's fields.
+// Go: any value can be handed to any goroutine; races are found (maybe)
+// at runtime with `go run -race`.
+
+use std::cell::{Cell, RefCell};
+use std::marker::PhantomData;
+use std::rc::Rc;
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use crate::registry::{Demo, Difficulty, Example, Tag};
+use crate::report::DemoReport;
+
+// Compile-time probes: these only build if T has the trait
+fn is_send<T: Send>() {}
+fn is_sync<T: Sync>() {}
+
+// Deliberately !Send and !Sync: a raw-pointer PhantomData opts out of both
+pub struct ThreadBound {
+    id: u32,
+    _not_send: PhantomData<*const ()>,
+}
+
+impl ThreadBound {
+    pub fn new(id: u32) -> Self {
+        ThreadBound { id, _not_send: PhantomData }
+    }
+
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+}
+
+// Rc can't cross threads: its count is a plain, non-atomic integer
+pub fn rc_is_not_send() -> DemoReport {
+    let mut report = DemoReport::new("Rc<T> Is Not Send");
+
+    let local = Rc::new(vec![1, 2, 3]);
+    let clone = Rc::clone(&local);
+    report.count("strong_count (non-atomic)", Rc::strong_count(&local));
+    report.text(format!("Single-thread use is fine: {:?}", clone));
+
+    report.step("Rejected by the compiler");
+    report.code("let data = Rc::new(vec![1, 2, 3]);");
+    report.code("thread::spawn(move || println!(\"{:?}\", data));");
+    report.code("// ❌ error[E0277]: `Rc<Vec<i32>>` cannot be sent between threads safely");
+    report.text("Checked by tests/compile_fail/rc_not_send.rs");
+
+    report.gap();
+    report.ok("Two threads bumping the same count could lose an update → use-after-free");
+    report.ok("So Rc<T> is !Send and !Sync - the race can't even be written");
+    report
+}
+
+// Arc works: atomic count, so Arc<T>: Send + Sync when T: Send + Sync
+pub fn arc_is_send() -> DemoReport {
+    let mut report = DemoReport::new("Arc<T> Is Send + Sync");
+
+    let shared = Arc::new(vec![1, 2, 3]);
+    let handles: Vec<_> = (0..3)
+        .map(|i| {
+            let shared = Arc::clone(&shared);  // Atomic increment
+            thread::spawn(move || shared.iter().sum::<i32>() * i)
+        })
+        .collect();
+    let results: Vec<i32> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+
+    report.text(format!("Each thread summed the shared Vec: {:?}", results));
+    report.count("strong_count after joins", Arc::strong_count(&shared));
+
+    report.gap();
+    report.ok("Arc's count is atomic → clone/drop from any thread is safe");
+    report.ok("Arc gives shared READ access; mutation still needs Mutex/atomics");
+    report.warn("Arc<RefCell<T>> is !Send: RefCell's borrow flag isn't atomic");
+    report
+}
+
+// The auto-trait table: what the compiler derives for common types
+pub fn marker_traits() -> DemoReport {
+    let mut report = DemoReport::new("What Send and Sync Mean");
+
+    // These lines are the proof: swap any `yes` below and this fn stops compiling
+    is_send::<Vec<i32>>();
+    is_sync::<Vec<i32>>();
+    is_send::<Arc<Mutex<i32>>>();
+    is_sync::<Arc<Mutex<i32>>>();
+    is_send::<Cell<i32>>();
+    is_send::<RefCell<i32>>();
+    is_send::<Mutex<i32>>();
+    is_sync::<Mutex<i32>>();
+
+    report.table(
+        &["Type", "Send", "Sync", "Why"],
+        vec![
+            vec!["i32, String, Vec<T>".into(), "yes".into(), "yes".into(), "plain owned data".into()],
+            vec!["Rc<T>".into(), "no".into(), "no".into(), "non-atomic refcount".into()],
+            vec!["Arc<T>".into(), "yes*".into(), "yes*".into(), "atomic refcount (*if T: Send + Sync)".into()],
+            vec!["Cell<T>, RefCell<T>".into(), "yes".into(), "no".into(), "unsynchronized interior mutability".into()],
+            vec!["Mutex<T>".into(), "yes".into(), "yes".into(), "lock serializes access".into()],
+            vec!["*const T, *mut T".into(), "no".into(), "no".into(), "compiler can't reason about them".into()],
+            vec!["MutexGuard<T>".into(), "no".into(), "yes".into(), "must unlock on the locking thread".into()],
+        ],
+    );
+
+    report.gap();
+    report.ok("Auto traits: a struct is Send if all its fields are Send");
+    report.ok("thread::spawn requires F: Send + 'static - that's the whole check");
+    report.warn("unsafe impl Send is a promise the compiler can't verify");
+    report
+}
+
+// A type that opts out of Send on purpose
+pub fn not_send_type() -> DemoReport {
+    let mut report = DemoReport::new("A Deliberately !Send Type");
+
+    let bound = ThreadBound::new(7);
+    report.text(format!("ThreadBound {{ id: {} }} used on the thread that made it", bound.id()));
+
+    report.step("Definition");
+    report.code("pub struct ThreadBound {");
+    report.code("    id: u32,");
+    report.code("    _not_send: PhantomData<*const ()>,  // raw pointer → !Send, !Sync");
+    report.code("}");
+
+    report.step("Rejected by the compiler");
+    report.code("let bound = ThreadBound::new(7);");
+    report.code("thread::spawn(move || bound.id());");
+    report.code("// ❌ error[E0277]: `*const ()` cannot be sent between threads safely");
+    report.text("Checked by tests/compile_fail/thread_bound_not_send.rs");
+
+    report.gap();
+    report.ok("Zero-sized marker: no runtime cost, just a type-level fact");
+    report.text("Real uses: GUI handles, thread-local resources, FFI pointers");
+    report
+}
+
+pub fn send_sync_vs_go() -> DemoReport {
+    let mut report = DemoReport::new("Send/Sync vs Go's Share-Anything Model");
+
+    report.step("Go (compiles, races at runtime)");
+    report.code("counts := map[string]int{}");
+    report.code("for i := 0; i < 10; i++ {");
+    report.code("    go func() { counts[\"hits\"]++ }()  // concurrent map writes");
+    report.code("}");
+    report.warn("Compiles fine; may crash with \"fatal error: concurrent map writes\"");
+    report.warn("`go run -race` only catches races that actually happen in that run");
+
+    report.step("Rust (rejected at compile-time)");
+    report.code("let mut counts = HashMap::new();");
+    report.code("thread::spawn(|| *counts.entry(\"hits\").or_insert(0) += 1);");
+    report.code("// ❌ closure may outlive the current function / cannot borrow as mutable");
+    report.ok("Fix is explicit: Arc<Mutex<HashMap<..>>> or a channel");
+
+    report.step("Key Insight");
+    report.text("Go: \"don't communicate by sharing memory\" is a guideline");
+    report.text("Rust: Send/Sync make it a type-checked rule");
+    report
+}
+
+pub const DEMO: Demo = Demo {
+    name: "send-sync",
+    about: "Send/Sync marker traits - why Rc can't cross threads",
+    difficulty: Difficulty::Intermediate,
+    tags: &[Tag::Concurrency],
+    examples: &[
+        Example { name: "rc-not-send", run: rc_is_not_send },
+        Example { name: "arc-send", run: arc_is_send },
+        Example { name: "marker-traits", run: marker_traits },
+        Example { name: "not-send-type", run: not_send_type },
+        Example { name: "vs-go", run: send_sync_vs_go },
+    ],
+};
+
+pub fn demonstrate_send_sync() -> Vec<DemoReport> {
+    DEMO.run()
+}
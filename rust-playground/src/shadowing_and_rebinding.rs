@@ -0,0 +1,243 @@
+// Shadowing and rebinding - the same name for a new variable
+// Go:   `x := ...` in an inner scope (if, for, a block) declares a NEW x that
+//       hides the outer one until the block ends. In the same scope, :=
+//       needs at least one new name on the left and reuses the rest - and
+//       can never change a variable's type.
+// Rust: `let x = ...` always makes a new variable, in any scope, of any type.
+//       The old one isn't dropped or overwritten: it's just unnamed, and
+//       lives - with any borrows of it - until its scope ends. `let else`
+//       binds or bails out in one line.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use crate::registry::{Demo, Difficulty, Example, Tag};
+use crate::report::{DemoReport, SideBySideRow};
+
+type DropLog = Rc<RefCell<Vec<String>>>;
+
+// Logs its own drop, so shadowing vs assignment is observed
+struct Noisy {
+    name: &'static str,
+    log: DropLog,
+}
+
+impl Noisy {
+    fn new(name: &'static str, log: &DropLog) -> Self {
+        log.borrow_mut().push(format!("create {}", name));
+        Noisy { name, log: Rc::clone(log) }
+    }
+}
+
+impl Drop for Noisy {
+    fn drop(&mut self) {
+        self.log.borrow_mut().push(format!("drop   {}", self.name));
+    }
+}
+
+fn record_log(report: &mut DemoReport, log: &DropLog) {
+    for event in log.borrow_mut().drain(..) {
+        report.text(event);
+    }
+}
+
+/// `key=value` lines into a map; lines without '=' or with a bad port are skipped
+pub fn parse_config(text: &str) -> HashMap<String, u16> {
+    let mut ports = HashMap::new();
+    for line in text.lines() {
+        let Some((key, value)) = line.split_once('=') else {
+            continue;  // `else` must diverge: continue, return, break or panic
+        };
+        let Ok(port) = value.trim().parse::<u16>() else {
+            continue;
+        };
+        ports.insert(key.trim().to_string(), port);
+    }
+    ports
+}
+
+// One name, three variables, three types
+pub fn rebinding() -> DemoReport {
+    let mut report = DemoReport::new("Rebinding - New Variable, Same Name");
+
+    let port = " 8080 ";
+    report.address("port: &str", &port, format!("{:?}", port));
+    let port = port.trim();
+    report.address("port: &str (trimmed)", &port, format!("{:?}", port));
+    let port: u16 = port.parse().expect("a valid port");
+    report.address("port: u16", &port, port.to_string());
+    report.code("let port = \" 8080 \";  let port = port.trim();  let port: u16 = port.parse()?;");
+    report.ok("Three `let`s, three variables - each may have its own type");
+    report.text("No port_str / port_trimmed / port_num: the stale versions can't be used by mistake");
+
+    report.step("Freezing: shadow a mut variable with an immutable one");
+    let mut names = vec!["gopher", "ferris"];
+    names.sort();
+    let names = names;  // from here on, immutable
+    report.code("let mut names = vec![...]; names.sort(); let names = names;");
+    report.code("names.push(\"crab\");  // ❌ error[E0596]: cannot borrow `names` as mutable, as it is not declared as mutable");
+    report.text("Checked by tests/compile_fail/frozen_by_shadowing.rs");
+    report.text(format!("names = {:?} - moved into the new binding, no copy", names));
+
+    report.step("Shadowing in a block ends with the block");
+    let level = "outer";
+    {
+        let level = 2;
+        report.text(format!("inside the block: level = {}", level));
+    }
+    report.text(format!("after the block: level = {:?}", level));
+
+    report.gap();
+    report.ok("Shadowing is a compile-time naming thing: nothing is assigned, dropped or converted");
+    report.text("Assignment (`x = ...`) needs `mut` and the same type; shadowing (`let x = ...`) needs neither");
+    report
+}
+
+// Shadowing doesn't drop; assignment does
+pub fn drops() -> DemoReport {
+    let mut report = DemoReport::new("Shadowing Doesn't Drop the Old Value");
+    let log = DropLog::default();
+
+    report.code("let guard = Noisy::new(\"first\"); let guard = Noisy::new(\"second\");");
+    {
+        let _guard = Noisy::new("first", &log);
+        let _guard = Noisy::new("second", &log);
+        log.borrow_mut().push("-- end of scope --".to_string());
+    }
+    record_log(&mut report, &log);
+    report.ok("\"first\" outlives its name: dropped at the end of scope, after \"second\" (reverse order)");
+
+    report.step("Assignment drops the old value right away");
+    report.code("let mut guard = Noisy::new(\"first\"); guard = Noisy::new(\"second\");");
+    {
+        let mut guard = Noisy::new("first", &log);
+        let _ = guard.name;
+        guard = Noisy::new("second", &log);
+        log.borrow_mut().push("-- end of scope --".to_string());
+        let _ = guard.name;
+    }
+    record_log(&mut report, &log);
+    report.ok("The old value is dropped the moment it's overwritten");
+
+    report.gap();
+    report.warn("Shadowing a lock guard does NOT release the lock - use drop(guard) or a block");
+    report.text("let _guard = m.lock(); let _guard = m.lock();  // the second lock() deadlocks: the first is still held");
+    report
+}
+
+// The old binding lives on - and so do borrows of it
+pub fn borrows() -> DemoReport {
+    let mut report = DemoReport::new("Shadowing and Borrows");
+
+    let name = String::from("gopher");
+    let first = &name;
+    let name = name.to_uppercase();
+    report.code("let name = String::from(\"gopher\"); let first = &name; let name = name.to_uppercase();");
+    report.text(format!("first = {:?}, name = {:?}", first, name));
+    report.ok("`first` still points at the old String: it lives until the scope ends, just unnamed");
+
+    report.step("Shadowing that ends a borrow");
+    report.code("let mut users = vec![\"ann\".to_string()];");
+    report.code("let first = &users[0];            // shared borrow of users");
+    report.code("users.push(\"bob\".into());         // ❌ error[E0502]: cannot borrow `users` as mutable because it is also borrowed as immutable");
+    report.code("println!(\"{}\", first);            //    ...because `first` is still used here");
+    report.text("Checked by tests/compile_fail/shadowing_borrow_still_live.rs");
+    let mut users = vec!["ann".to_string()];
+    let first = &users[0];
+    let first = first.clone();  // last use of the reference: the borrow of `users` ends here
+    users.push("bob".into());
+    report.code("let first = first.clone();        // ✓ shadow the &String with an owned String");
+    report.code("users.push(\"bob\".into());         // ✓ the borrow's last use was the clone");
+    report.text(format!("first = {:?}, users = {:?}", first, users));
+    report.text("Same name, later uses unchanged - but they now read a String that borrows nothing");
+
+    report.gap();
+    report.ok("A borrow lasts until its last use, not until the name is gone");
+    report.ok("Shadowing swaps what the name refers to, so the old reference's last use can move up");
+    report
+}
+
+// let-else: bind or leave
+pub fn let_else() -> DemoReport {
+    let mut report = DemoReport::new("let else - Bind or Bail Out");
+
+    report.code("let Some((key, value)) = line.split_once('=') else { continue };");
+    report.code("let Ok(port) = value.trim().parse::<u16>() else { continue };");
+    let config = "http = 8080\n# comment\ndebug = on\nadmin=9090";
+    let ports = parse_config(config);
+    let mut sorted: Vec<_> = ports.iter().collect();
+    sorted.sort();
+    report.text(format!("parse_config({:?})", config));
+    report.text(format!("= {:?}", sorted));
+    report.ok("The happy path stays unindented; `key`, `value`, `port` are in scope below the let");
+
+    report.step("The else must diverge");
+    report.code("let Some(x) = maybe else { println!(\"none\") };  // ❌ error[E0308]: `else` clause of `let...else` does not diverge");
+    report.text("Checked by tests/compile_fail/let_else_must_diverge.rs");
+    report.text("return, continue, break or panic! - the bindings can't exist if the pattern didn't match");
+
+    report.step("Go");
+    report.code("key, value, ok := strings.Cut(line, \"=\"); if !ok { continue }");
+    report.code("port, err := strconv.Atoi(value); if err != nil { continue }");
+    report.text("Same shape - and key/value/port exist (as zero values) even on the path that skipped them");
+
+    report.gap();
+    report.ok("let-else = match with one useful arm, without the rightward drift");
+    report
+}
+
+pub fn shadowing_vs_go() -> DemoReport {
+    let mut report = DemoReport::new("Shadowing: Go's := vs Rust's let");
+
+    report.step("Redeclaring in the same scope");
+    report.side_by_side(vec![
+        SideBySideRow::same("x := 1", "let x = 1;"),
+        SideBySideRow::differs("x := \"one\"  // ❌ no new variables on left side of :=", "let x = \"one\";  // ✓ new variable, new type"),
+        SideBySideRow::differs("y, x := 2, 3  // reuses x: plain assignment, same type", "let (y, x) = (2, 3);  // two new variables"),
+    ]);
+
+    report.step("The nested-scope trap");
+    report.side_by_side(vec![
+        SideBySideRow::same("var err error", "let mut result: Result<(), String> = Ok(());"),
+        SideBySideRow::same("if ok {", "if ok {"),
+        SideBySideRow::differs("    _, err := save()  // NEW err, hides the outer one", "    result = save();  // assignment: needs `mut`, same type"),
+        SideBySideRow::same("}", "}"),
+        SideBySideRow::differs("return err  // always nil", "return result;"),
+    ]);
+    report.text("Go: `go vet` won't catch it by default; the shadow analyzer (golang.org/x/tools/.../shadow) does");
+    report.text("Rust: writing `let result = save();` inside the if would shadow too - but it warns: unused variable");
+
+    report.table(
+        &["", "Go", "Rust"],
+        vec![
+            vec!["new variable, same scope".into(), "only if one name on the left is new".into(), "always (let)".into()],
+            vec!["change the type".into(), "never".into(), "yes".into()],
+            vec!["old variable".into(), "hidden until the block ends".into(), "hidden, still alive, still borrowed".into()],
+            vec!["old value dropped".into(), "GC, when unreachable".into(), "at the end of its scope".into()],
+            vec!["bind-or-bail".into(), "v, ok := ...; if !ok { return }".into(), "let Some(v) = ... else { return };".into()],
+        ],
+    );
+
+    report.gap();
+    report.ok("Both hide names; Rust makes it an explicit, always-new `let`, checked for use and borrows");
+    report
+}
+
+pub const DEMO: Demo = Demo {
+    name: "shadowing-and-rebinding",
+    about: "Shadowing: rebinding with new types, drops and borrows of the hidden variable, let-else, Go's :=",
+    difficulty: Difficulty::Beginner,
+    tags: &[Tag::Ownership, Tag::Borrowing],
+    examples: &[
+        Example { name: "rebinding", run: rebinding },
+        Example { name: "drops", run: drops },
+        Example { name: "borrows", run: borrows },
+        Example { name: "let-else", run: let_else },
+        Example { name: "vs-go", run: shadowing_vs_go },
+    ],
+};
+
+pub fn demonstrate_shadowing_and_rebinding() -> Vec<DemoReport> {
+    DEMO.run()
+}
@@ -0,0 +1,164 @@
+// Go slices/strings vs Rust Vec/&[T] and String/&str
+// Go:   a slice is ONE type ([]T) - header {ptr, len, cap} that may or may not
+//       share its backing array with other slices.
+// Rust: ownership splits it in two:
+//       Vec<T>  {ptr, len, cap}  owns the buffer, can grow
+//       &[T]    {ptr, len}       borrows a window into someone else's buffer
+
+use std::mem::size_of;
+
+use crate::registry::{Demo, Difficulty, Example, Tag};
+use crate::report::DemoReport;
+
+// Vec<T> owns, &[T] borrows
+pub fn vec_vs_slice() -> DemoReport {
+    let mut report = DemoReport::new("Vec<T> vs &[T] - Owned Buffer vs Borrowed View");
+
+    let numbers: Vec<i32> = vec![10, 20, 30, 40, 50];
+    let view: &[i32] = &numbers;
+
+    let header = format!("len={} cap={}", numbers.len(), numbers.capacity());
+    report.address("Vec buffer", numbers.as_slice(), header);
+    report.address("&[i32] view", view, format!("len={}", view.len()));
+    report.bytes("size_of::<Vec<i32>>() (ptr + len + cap)", size_of::<Vec<i32>>());
+    report.bytes("size_of::<&[i32]>()   (ptr + len)", size_of::<&[i32]>());
+
+    report.gap();
+    report.ok("Same buffer address - a slice never copies");
+    report.ok("&[T] has no capacity: it can't grow what it doesn't own");
+
+    report.step("Go");
+    report.code("nums := []int{10, 20, 30, 40, 50}  // header: {ptr, len=5, cap=5}");
+    report.code("view := nums                        // same header, same array");
+    report.text("Both are []int - the type doesn't say who owns the array");
+    report
+}
+
+// String owns UTF-8 bytes, &str borrows them
+pub fn string_vs_str() -> DemoReport {
+    let mut report = DemoReport::new("String vs &str");
+
+    let owned = String::from("hello, gopher");
+    let borrowed: &str = &owned[7..];
+    let literal: &str = "hello";
+
+    let header = format!("{:?} len={} cap={}", owned, owned.len(), owned.capacity());
+    report.address("String buffer", owned.as_str(), header);
+    report.address("&owned[7..]", borrowed, format!("{:?} (7 bytes further)", borrowed));
+    report.address("literal", literal, format!("{:?} (read-only data in the binary)", literal));
+    report.bytes("size_of::<String>()", size_of::<String>());
+    report.bytes("size_of::<&str>()", size_of::<&str>());
+
+    report.gap();
+    report.ok("&str = (ptr, len) into someone else's UTF-8 bytes");
+    report.ok("Functions should take &str: accepts String, literals, and sub-slices");
+
+    report.step("Go");
+    report.code("s := \"hello, gopher\"  // header: {ptr, len}, immutable");
+    report.code("sub := s[7:]           // shares bytes, GC keeps whole string alive");
+    report.warn("A tiny substring can pin a huge string in memory");
+    report
+}
+
+// Sub-slicing shares memory in both languages
+pub fn slicing_shares_memory() -> DemoReport {
+    let mut report = DemoReport::new("Slicing Shares Memory");
+
+    let mut data = vec![1, 2, 3, 4, 5, 6];
+    {
+        let (left, right) = data.split_at_mut(3);
+        report.address("left  = data[..3]", left, format!("{:?}", left));
+        report.address("right = data[3..]", right, format!("{:?}", right));
+        right[0] = 40;  // Writes straight into data's buffer
+    }
+    report.address("data after right[0] = 40", data.as_slice(), format!("{:?}", data));
+
+    report.gap();
+    report.ok("right[0] IS data[3] - 12 bytes (3 × i32) further into the buffer");
+    report.ok("split_at_mut gives two non-overlapping &mut views - proven disjoint");
+
+    report.step("Go");
+    report.code("left, right := data[:3], data[3:]");
+    report.code("right[0] = 40  // also visible via data[3]");
+    report.text("Same sharing - but Go lets BOTH halves overlap and mutate freely");
+    report
+}
+
+// Capacity growth: when the buffer moves
+pub fn capacity_growth() -> DemoReport {
+    let mut report = DemoReport::new("Capacity Growth - When the Buffer Moves");
+
+    let mut v: Vec<u64> = Vec::new();
+    let mut last_capacity = v.capacity();
+    let mut rows = vec![];
+
+    // Row per growth, not per moved pointer: realloc may grow in place
+    for i in 0..17 {
+        v.push(i);
+        if v.capacity() != last_capacity {
+            rows.push(vec![
+                v.len().to_string(),
+                v.capacity().to_string(),
+                format!("{:p}", v.as_ptr()),
+            ]);
+            last_capacity = v.capacity();
+        }
+    }
+    report.table(&["len", "cap", "buffer"], rows);
+
+    report.gap();
+    report.ok("Capacity doubles: amortized O(1) push");
+    report.ok("Each growth = allocate new buffer, copy, free old one (unless realloc can extend in place)");
+    report.text("Vec::with_capacity(n) avoids every reallocation");
+    report.text("Push by push, with Go's append aliasing: run vec-growth");
+
+    report.step("Go");
+    report.code("s = append(s, x)  // may or may not reallocate - you must reassign");
+    report
+}
+
+// The borrow implication: no growing while a slice is alive
+pub fn slice_borrow_rules() -> DemoReport {
+    let mut report = DemoReport::new("Why You Can't Push While Holding a Slice");
+
+    let mut v = vec![1, 2, 3];
+    let first_two = &v[..2];
+    report.address("first_two", first_two, format!("{:?}", first_two));
+
+    // v.push(4);                  // ❌ Error: cannot borrow `v` as mutable
+    // println!("{:?}", first_two); //    because it is also borrowed as immutable
+    //                                 (tests/compile_fail/push_while_sliced.rs)
+
+    v.push(4);  // ✓ OK: first_two is no longer used
+    report.address("after push", v.as_slice(), format!("{:?}", v));
+
+    report.gap();
+    report.ok("push may reallocate → any slice into the old buffer would dangle");
+    report.ok("The borrow checker rejects it at compile-time");
+
+    report.step("Go (compiles, silently diverges)");
+    report.code("a := make([]int, 3, 3)");
+    report.code("b := a[:2]");
+    report.code("a = append(a, 4)  // cap exceeded → a moves to a NEW array");
+    report.code("b[0] = 99         // writes the OLD array: a[0] is still 0");
+    report.warn("Sometimes shared, sometimes not - depends on capacity at runtime");
+    report
+}
+
+pub const DEMO: Demo = Demo {
+    name: "slices-and-strings",
+    about: "Go slices/strings vs Vec, &[T], String and &str",
+    difficulty: Difficulty::Beginner,
+    tags: &[Tag::Borrowing, Tag::Allocation],
+    examples: &[
+        Example { name: "vec-vs-slice", run: vec_vs_slice },
+        Example { name: "string-vs-str", run: string_vs_str },
+        Example { name: "slicing", run: slicing_shares_memory },
+        Example { name: "growth", run: capacity_growth },
+        Example { name: "borrow-rules", run: slice_borrow_rules },
+    ],
+};
+
+pub fn demonstrate_slices_and_strings() -> Vec<DemoReport> {
+    DEMO.run()
+}
@@ -0,0 +1,362 @@
+// Which pointer do I need? - one decision guide for Box, Rc, Arc, Cell, RefCell, Cow
+// Three questions pick the type:
+//   1. Who owns it?        borrowed-or-owned → Cow, one owner → Box, many → Rc/Arc
+//   2. Shared by threads?  no → Rc + Cell/RefCell, yes → Arc + atomic/Mutex
+//   3. Mutated?            no → the pointer alone, Copy swaps → Cell/atomic,
+//                          in-place edits → RefCell/Mutex
+// Go: every answer is *T. The GC handles ownership, and thread safety
+// is up to you (sync.Mutex, atomic) - the type never says which case you're in.
+
+use std::borrow::Cow;
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use crate::registry::{Demo, Difficulty, Example, Tag};
+use crate::report::DemoReport;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Owners {
+    /// Usually borrowed, sometimes needs its own copy
+    BorrowedOrOwned,
+    /// Exactly one owner at a time
+    One,
+    /// Several owners, none of which outlives the others for sure
+    Many,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Threads {
+    One,
+    Many,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mutation {
+    /// Read-only after construction
+    None,
+    /// Whole Copy values are swapped in and out (counters, flags)
+    Replace,
+    /// Edited in place through a reference (push to a Vec, update a field)
+    InPlace,
+}
+
+/// What the value needs
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Requirements {
+    pub owners: Owners,
+    pub threads: Threads,
+    pub mutation: Mutation,
+}
+
+/// The pointer type the decision tree lands on
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Pointer {
+    Cow,
+    Box,
+    Rc,
+    RcCell,
+    RcRefCell,
+    Arc,
+    ArcAtomic,
+    ArcMutex,
+}
+
+impl Pointer {
+    pub fn name(self) -> &'static str {
+        match self {
+            Pointer::Cow => "Cow<'a, T>",
+            Pointer::Box => "Box<T> (or plain T)",
+            Pointer::Rc => "Rc<T>",
+            Pointer::RcCell => "Rc<Cell<T>>",
+            Pointer::RcRefCell => "Rc<RefCell<T>>",
+            Pointer::Arc => "Arc<T>",
+            Pointer::ArcAtomic => "Arc<AtomicU64>",
+            Pointer::ArcMutex => "Arc<Mutex<T>>",
+        }
+    }
+}
+
+/// The chosen pointer plus the reasoning that led to it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Decision {
+    pub pointer: Pointer,
+    pub reasons: Vec<&'static str>,
+}
+
+/// Walk the decision tree for one set of requirements
+pub fn choose(requirements: Requirements) -> Decision {
+    let Requirements { owners, threads, mutation } = requirements;
+    let mut reasons = Vec::new();
+
+    let pointer = match owners {
+        Owners::BorrowedOrOwned => {
+            reasons.push("mostly borrowed → don't allocate up front");
+            if mutation != Mutation::None {
+                reasons.push("edits clone the data on first write (to_mut)");
+            }
+            if threads == Threads::Many {
+                reasons.push("Cow<T> is Send when T is - no extra wrapper for threads");
+            }
+            Pointer::Cow
+        }
+        Owners::One => {
+            reasons.push("one owner → no counting needed; move it where it's used");
+            if threads == Threads::Many {
+                reasons.push("other threads get it by move (T: Send), not by sharing");
+            }
+            if mutation != Mutation::None {
+                reasons.push("the owner mutates through &mut - no Cell/RefCell needed");
+            }
+            Pointer::Box
+        }
+        Owners::Many => match threads {
+            Threads::One => {
+                reasons.push("many owners, one thread → Rc (non-atomic count)");
+                match mutation {
+                    Mutation::None => Pointer::Rc,
+                    Mutation::Replace => {
+                        reasons.push("shared + Copy swaps → Cell (get/set, no borrow tracking)");
+                        Pointer::RcCell
+                    }
+                    Mutation::InPlace => {
+                        reasons.push("shared + in-place edits → RefCell (borrow checked at runtime)");
+                        Pointer::RcRefCell
+                    }
+                }
+            }
+            Threads::Many => {
+                reasons.push("many owners, many threads → Arc (atomic count)");
+                match mutation {
+                    Mutation::None => Pointer::Arc,
+                    Mutation::Replace => {
+                        reasons.push("cross-thread Copy updates → atomic, no lock");
+                        Pointer::ArcAtomic
+                    }
+                    Mutation::InPlace => {
+                        reasons.push("cross-thread in-place edits → Mutex (or RwLock if read-heavy)");
+                        Pointer::ArcMutex
+                    }
+                }
+            }
+        },
+    };
+
+    Decision { pointer, reasons }
+}
+
+/// Build the chosen pointer and use it the way the requirements say
+pub fn exercise(pointer: Pointer) -> Vec<String> {
+    match pointer {
+        Pointer::Cow => {
+            let mut tags: Cow<'_, [&str]> = Cow::Borrowed(&["rust", "go"]);
+            let before = matches!(tags, Cow::Borrowed(_));
+            tags.to_mut().push("memory");  // First write: clones into a Vec
+            vec![
+                format!("borrowed before edit: {}", before),
+                format!("after to_mut().push: {:?} (owned: {})", tags, matches!(tags, Cow::Owned(_))),
+            ]
+        }
+        Pointer::Box => {
+            let mut config = Box::new(vec![1, 2, 3]);
+            config.push(4);  // Owner mutates directly
+            let moved = thread::spawn(move || config.len()).join().unwrap();  // Moved, not shared
+            vec![format!("owner pushed, then moved into a thread: len = {}", moved)]
+        }
+        Pointer::Rc => {
+            let name = Rc::new(String::from("shared config"));
+            let a = Rc::clone(&name);
+            let b = Rc::clone(&name);
+            vec![format!("{:?} read by 3 owners, strong_count = {}", b, Rc::strong_count(&a))]
+        }
+        Pointer::RcCell => {
+            let hits = Rc::new(Cell::new(0u64));
+            let handlers: Vec<Rc<Cell<u64>>> = (0..3).map(|_| Rc::clone(&hits)).collect();
+            for handler in &handlers {
+                handler.set(handler.get() + 1);  // No borrow, just copy in/out
+            }
+            vec![format!("3 owners each did set(get() + 1): hits = {}", hits.get())]
+        }
+        Pointer::RcRefCell => {
+            let log = Rc::new(RefCell::new(Vec::new()));
+            let writer = Rc::clone(&log);
+            writer.borrow_mut().push("from writer");
+            log.borrow_mut().push("from owner");
+            vec![format!("both owners pushed: {:?}", log.borrow())]
+        }
+        Pointer::Arc => {
+            let table = Arc::new(vec!["a", "b", "c"]);
+            let handles: Vec<_> = (0..3)
+                .map(|_| {
+                    let table = Arc::clone(&table);
+                    thread::spawn(move || table.len())
+                })
+                .collect();
+            let lens: Vec<usize> = handles.into_iter().map(|handle| handle.join().unwrap()).collect();
+            vec![format!("3 threads read the same Vec: {:?}", lens)]
+        }
+        Pointer::ArcAtomic => {
+            let counter = Arc::new(AtomicU64::new(0));
+            let handles: Vec<_> = (0..3)
+                .map(|_| {
+                    let counter = Arc::clone(&counter);
+                    thread::spawn(move || counter.fetch_add(1, Ordering::Relaxed))
+                })
+                .collect();
+            for handle in handles {
+                handle.join().unwrap();
+            }
+            vec![format!("3 threads fetch_add(1): counter = {}", counter.load(Ordering::Relaxed))]
+        }
+        Pointer::ArcMutex => {
+            let queue = Arc::new(Mutex::new(Vec::new()));
+            let handles: Vec<_> = (0..3)
+                .map(|i| {
+                    let queue = Arc::clone(&queue);
+                    thread::spawn(move || queue.lock().unwrap().push(i))
+                })
+                .collect();
+            for handle in handles {
+                handle.join().unwrap();
+            }
+            let mut items = queue.lock().unwrap().clone();
+            items.sort_unstable();
+            vec![format!("3 threads pushed under the lock: {:?}", items)]
+        }
+    }
+}
+
+fn describe(requirements: Requirements) -> String {
+    format!("owners: {:?}, threads: {:?}, mutation: {:?}", requirements.owners, requirements.threads, requirements.mutation)
+}
+
+fn all_requirements() -> Vec<Requirements> {
+    let mut all = Vec::new();
+    for owners in [Owners::BorrowedOrOwned, Owners::One, Owners::Many] {
+        for threads in [Threads::One, Threads::Many] {
+            for mutation in [Mutation::None, Mutation::Replace, Mutation::InPlace] {
+                all.push(Requirements { owners, threads, mutation });
+            }
+        }
+    }
+    all
+}
+
+// The whole tree as a table
+pub fn decision_table() -> DemoReport {
+    let mut report = DemoReport::new("Which Pointer Do I Need? - The Whole Tree");
+
+    let rows = all_requirements()
+        .into_iter()
+        .map(|requirements| {
+            vec![
+                format!("{:?}", requirements.owners),
+                format!("{:?}", requirements.threads),
+                format!("{:?}", requirements.mutation),
+                choose(requirements).pointer.name().to_string(),
+            ]
+        })
+        .collect();
+    report.table(&["Owners", "Threads", "Mutation", "Use"], rows);
+
+    report.gap();
+    report.ok("Start from the simplest row: most values are one owner, no sharing");
+    report.text("Only owners + threads + mutation decide it - the data type doesn't");
+    report
+}
+
+// Walk the tree for a few real situations and run the result
+pub fn decision_walkthrough() -> DemoReport {
+    let mut report = DemoReport::new("Decision Tree - Reasoning + Running Code");
+
+    let cases = [
+        ("Parsed config, passed down once", Owners::One, Threads::Many, Mutation::InPlace),
+        ("Usernames, usually already normalized", Owners::BorrowedOrOwned, Threads::One, Mutation::InPlace),
+        ("Read-only settings shared by UI widgets", Owners::Many, Threads::One, Mutation::None),
+        ("Click counter shared by UI handlers", Owners::Many, Threads::One, Mutation::Replace),
+        ("Event log appended by several widgets", Owners::Many, Threads::One, Mutation::InPlace),
+        ("Lookup table read by worker threads", Owners::Many, Threads::Many, Mutation::None),
+        ("Request counter bumped by workers", Owners::Many, Threads::Many, Mutation::Replace),
+        ("Job queue filled by workers", Owners::Many, Threads::Many, Mutation::InPlace),
+    ];
+
+    for (situation, owners, threads, mutation) in cases {
+        let requirements = Requirements { owners, threads, mutation };
+        let decision = choose(requirements);
+        report.step(format!("{} → {}", situation, decision.pointer.name()));
+        report.text(describe(requirements));
+        for reason in &decision.reasons {
+            report.text(format!("  because {}", reason));
+        }
+        for line in exercise(decision.pointer) {
+            report.ok(line);
+        }
+    }
+    report
+}
+
+// Upgrading too far costs something at every step
+pub fn pointer_costs() -> DemoReport {
+    let mut report = DemoReport::new("What Each Step Up the Tree Costs");
+
+    report.table(
+        &["Type", "Heap alloc", "Per clone", "Per access", "Failure mode"],
+        vec![
+            vec!["T / &T".into(), "no".into(), "-".into(), "none".into(), "compile error".into()],
+            vec!["Cow<T>".into(), "on first write".into(), "-".into(), "match Borrowed/Owned".into(), "compile error".into()],
+            vec!["Box<T>".into(), "yes".into(), "deep copy".into(), "none".into(), "compile error".into()],
+            vec!["Rc<T>".into(), "yes".into(), "count += 1".into(), "none".into(), "leak on cycles".into()],
+            vec!["Cell<T>".into(), "no".into(), "-".into(), "copy in/out".into(), "none".into()],
+            vec!["RefCell<T>".into(), "no".into(), "-".into(), "borrow flag check".into(), "panic".into()],
+            vec!["Arc<T>".into(), "yes".into(), "atomic += 1".into(), "none".into(), "leak on cycles".into()],
+            vec!["Atomic*".into(), "no".into(), "-".into(), "atomic op".into(), "none".into()],
+            vec!["Mutex<T>".into(), "no".into(), "-".into(), "lock/unlock".into(), "deadlock, poison".into()],
+        ],
+    );
+
+    report.gap();
+    report.warn("Arc<Mutex<T>> \"just in case\" pays for atomics and locks you never needed");
+    report.ok("Downgrade when you can: Rc → &T for a call, Mutex → &mut after join");
+    report
+}
+
+pub fn smart_pointers_vs_go() -> DemoReport {
+    let mut report = DemoReport::new("Pointer Choice: Rust vs Go");
+
+    report.step("Go");
+    report.code("type Server struct {");
+    report.code("    cfg   *Config      // shared? owned? read-only? - can't tell");
+    report.code("    hits  *int64       // atomic.AddInt64 by convention");
+    report.code("    mu    sync.Mutex   // guards... whichever fields the comment says");
+    report.code("    queue []Job");
+    report.code("}");
+    report.text("One pointer type; ownership by GC, thread safety by discipline");
+
+    report.step("Rust");
+    report.code("struct Server {");
+    report.code("    cfg: Arc<Config>,           // shared, read-only, cross-thread");
+    report.code("    hits: Arc<AtomicU64>,       // shared counter");
+    report.code("    queue: Arc<Mutex<Vec<Job>>>,// the lock owns what it guards");
+    report.code("}");
+    report.ok("The type IS the answer to the three questions - readers see it, the compiler checks it");
+    report
+}
+
+pub const DEMO: Demo = Demo {
+    name: "smart-pointers",
+    about: "Which smart pointer? Box, Rc, Arc, Cell, RefCell, Cow decision guide",
+    difficulty: Difficulty::Beginner,
+    tags: &[Tag::Ownership, Tag::Allocation],
+    examples: &[
+        Example { name: "table", run: decision_table },
+        Example { name: "walkthrough", run: decision_walkthrough },
+        Example { name: "costs", run: pointer_costs },
+        Example { name: "vs-go", run: smart_pointers_vs_go },
+    ],
+};
+
+pub fn demonstrate_smart_pointers() -> Vec<DemoReport> {
+    DEMO.run()
+}
@@ -0,0 +1,240 @@
+// Stack vs heap - Go's escape analysis cases, one by one in Rust
+// Go: the compiler decides. If a value might outlive its function (returned
+// pointer, closure, interface, global, too big), it silently moves to the
+// heap - `go build -gcflags=-m` prints "moved to heap: x".
+// Rust: the code decides. The same situations are either a compile error,
+// a move (the value is copied out, still no heap), or an explicit Box.
+// Mirrors golang-playground/escape_analysis.go, case by case.
+
+use std::fmt::Display;
+use std::sync::OnceLock;
+
+use crate::registry::{Demo, Difficulty, Example, Tag};
+use crate::report::DemoReport;
+
+// Rough check: within 1 MiB of a local in this frame → stack
+#[inline(never)]
+fn region<T: ?Sized>(value: &T) -> &'static str {
+    let marker = 0u8;
+    let here = &marker as *const u8 as usize;
+    let addr = value as *const T as *const () as usize;
+    if here.abs_diff(addr) < 1 << 20 { "stack" } else { "heap" }
+}
+
+fn locate<T: ?Sized>(report: &mut DemoReport, label: &str, value: &T) {
+    report.address(label, value, region(value));
+}
+
+// Cases 1 and 5: nothing escapes - same answer in both languages
+pub fn no_escape() -> DemoReport {
+    let mut report = DemoReport::new("Nothing Escapes - Stack in Both Languages");
+
+    let x = 42;
+    let ptr = &x;  // Go case 5: pointer used only locally
+    locate(&mut report, "x", &x);
+    locate(&mut report, "*ptr (same place)", ptr);
+    report.text(format!("*ptr = {}", *ptr));
+
+    report.step("Go");
+    report.code("x := 42; ptr := &x; _ = *ptr  // does not escape");
+    report.ok("Go's escape analysis proves it and keeps x on the stack too");
+    report.step("Rust");
+    report.ok("A reference never moves data - it just can't outlive it");
+    report
+}
+
+// Returns a value instead of a pointer: moved into the caller's frame
+#[inline(never)]
+fn make_value() -> [u64; 4] {
+    [42; 4]
+}
+
+// Case 2: return a pointer to a local
+pub fn return_pointer_to_local() -> DemoReport {
+    let mut report = DemoReport::new("Returning a Pointer to a Local");
+
+    report.step("Go (moved to heap: x)");
+    report.code("func escapesViaReturn() *int { x := 42; return &x }");
+    report.warn("Compiles; x is silently heap-allocated and GC-tracked");
+
+    report.step("Rust: &x → compile error");
+    report.code("fn escapes_via_return() -> &'static i32 { let x = 42; &x }  // ❌ E0515");
+    report.text("Checked by tests/compile_fail/return_reference_to_local.rs");
+
+    report.step("Rust: return the value → moved, still on the stack");
+    let value = make_value();
+    locate(&mut report, "value (caller's frame)", &value);
+
+    report.step("Rust: want the heap? say so");
+    let boxed = Box::new(42);
+    locate(&mut report, "*Box::new(42)", boxed.as_ref());
+
+    report.gap();
+    report.ok("The signature says which one you get: -> T or -> Box<T>");
+    report
+}
+
+// Case 3: interface boxing
+pub fn interface_boxing() -> DemoReport {
+    let mut report = DemoReport::new("Interfaces - Boxing Is Opt-In");
+
+    report.step("Go (x escapes to heap)");
+    report.code("var globalInterface interface{}");
+    report.code("globalInterface = x  // non-pointer value stored in an interface → heap copy");
+
+    let x = 42u64;
+    report.step("Rust: generics (impl Display) - no box, static dispatch");
+    fn show(value: &impl Display) -> String {
+        value.to_string()
+    }
+    report.text(format!("show(&x) = {}", show(&x)));
+    locate(&mut report, "x", &x);
+
+    report.step("Rust: &dyn Display - a fat pointer to the SAME stack value");
+    let as_dyn: &dyn Display = &x;
+    locate(&mut report, "&dyn data pointer", as_dyn);
+    report.bytes("size_of::<&dyn Display>() (data + vtable)", size_of::<&dyn Display>());
+
+    report.step("Rust: Box<dyn Display> - heap, because you asked");
+    let boxed: Box<dyn Display> = Box::new(x);
+    locate(&mut report, "Box<dyn Display> data", boxed.as_ref());
+
+    report.gap();
+    report.ok("Dynamic dispatch doesn't imply heap: &dyn Trait points anywhere");
+    report
+}
+
+// Case 4: too large for the stack
+pub fn too_large_for_stack() -> DemoReport {
+    let mut report = DemoReport::new("Too Big for the Stack");
+
+    report.step("Go (make([]int, 1000000) escapes: too large)");
+    report.code("largeArray := make([]int, 1000000)  // heap; goroutine stacks start at ~2KB");
+
+    report.step("Rust");
+    let small = [0u64; 16];
+    let large = vec![0u64; 1_000_000];
+    locate(&mut report, "[u64; 16]", &small);
+    locate(&mut report, "vec![0u64; 1_000_000] buffer", large.as_slice());
+    report.bytes("Vec itself on the stack (ptr, cap, len)", size_of::<Vec<u64>>());
+
+    report.gap();
+    report.ok("Vec/String/Box are always heap - the type tells you");
+    report.warn("[u64; 1_000_000] IS a stack array: 8 MB → overflows the main thread's stack");
+    report.text("Rust never moves an array to the heap for you - pick Vec or Box<[T]>");
+    report
+}
+
+static CONFIG: OnceLock<Vec<String>> = OnceLock::new();
+
+// Case 6: store a pointer in a global
+pub fn store_in_global() -> DemoReport {
+    let mut report = DemoReport::new("Storing a Pointer in a Global");
+
+    report.step("Go (moved to heap: x)");
+    report.code("var globalPtr *int");
+    report.code("func escapesViaGlobal() { x := 42; globalPtr = &x }");
+
+    report.step("Rust: &x into a static → compile error");
+    report.code("static GLOBAL: Mutex<Option<&'static i32>> = ...;");
+    report.code("let x = 42; *GLOBAL.lock().unwrap() = Some(&x);  // ❌ x does not live long enough");
+
+    report.step("Rust: move the value into the static");
+    let config = CONFIG.get_or_init(|| vec!["debug".to_string()]);
+    report.address("CONFIG (static, in the binary)", &CONFIG, "lives for the whole program");
+    locate(&mut report, "its Vec buffer", config.as_slice());
+
+    report.step("Rust: or leak a Box on purpose");
+    let leaked: &'static mut i32 = Box::leak(Box::new(42));
+    locate(&mut report, "Box::leak(Box::new(42))", leaked);
+    report.warn("Box::leak never frees - fine for once-per-process setup only");
+
+    report.gap();
+    report.ok("Globals only hold 'static data - you choose static storage or a leak");
+    report
+}
+
+// Returns a closure by value: x lives INSIDE the closure struct
+fn make_counter() -> impl FnMut() -> i32 {
+    let mut x = 42;
+    move || {
+        x += 1;
+        x
+    }
+}
+
+fn make_boxed_counter() -> Box<dyn FnMut() -> i32> {
+    let mut x = 42;
+    Box::new(move || {
+        x += 1;
+        x
+    })
+}
+
+// Case 7: closure outlives the function
+pub fn closure_capture() -> DemoReport {
+    let mut report = DemoReport::new("Closures That Outlive Their Function");
+
+    report.step("Go (func literal escapes, moved to heap: x)");
+    report.code("func escapesViaClosure() func() int { x := 42; return func() int { return x } }");
+
+    report.step("Rust: capture by reference → compile error");
+    report.code("fn counter() -> impl Fn() -> i32 { let x = 42; || x }  // ❌ E0373: may outlive x");
+    report.text("Checked by tests/compile_fail/closure_outlives_local.rs");
+
+    report.step("Rust: move || - x moves into the closure value");
+    let mut counter = make_counter();
+    report.text(format!("counter() = {}, {}", counter(), counter()));
+    locate(&mut report, "closure (holds x)", &counter);
+    report.bytes("size_of_val(&counter)", size_of_val(&counter));
+
+    report.step("Rust: Box<dyn FnMut> - heap, e.g. to store different closures in one Vec");
+    let mut boxed = make_boxed_counter();
+    report.text(format!("boxed() = {}, {}", boxed(), boxed()));
+    locate(&mut report, "boxed closure", boxed.as_ref());
+
+    report.gap();
+    report.ok("impl Fn is a plain struct of its captures - no allocation");
+    report
+}
+
+pub fn escape_summary() -> DemoReport {
+    let mut report = DemoReport::new("Escape Analysis Cases: Go vs Rust");
+
+    report.table(
+        &["Go case", "Go result", "Rust result"],
+        vec![
+            vec!["local only".into(), "stack".into(), "stack".into()],
+            vec!["return &x".into(), "heap (implicit)".into(), "❌ error → return T (stack) or Box<T>".into()],
+            vec!["x in interface{}".into(), "heap (implicit)".into(), "generic or &dyn (stack), Box<dyn> (heap)".into()],
+            vec!["make([]int, 1e6)".into(), "heap (too large)".into(), "Vec → heap, always".into()],
+            vec!["globalPtr = &x".into(), "heap (implicit)".into(), "❌ error → static value or Box::leak".into()],
+            vec!["return closure".into(), "heap (implicit)".into(), "move || (stack) or Box<dyn Fn> (heap)".into()],
+        ],
+    );
+
+    report.gap();
+    report.text("Go: make escape (golang-playground) prints the compiler's decisions");
+    report.ok("Rust: every heap allocation is in the source - grep for Box/Vec/String/Rc");
+    report
+}
+
+pub const DEMO: Demo = Demo {
+    name: "stack-vs-heap-escape",
+    about: "Go escape analysis cases: stack, move, or explicit Box",
+    difficulty: Difficulty::Intermediate,
+    tags: &[Tag::Allocation],
+    examples: &[
+        Example { name: "no-escape", run: no_escape },
+        Example { name: "return-pointer", run: return_pointer_to_local },
+        Example { name: "interface", run: interface_boxing },
+        Example { name: "too-large", run: too_large_for_stack },
+        Example { name: "global", run: store_in_global },
+        Example { name: "closure", run: closure_capture },
+        Example { name: "summary", run: escape_summary },
+    ],
+};
+
+pub fn demonstrate_stack_vs_heap_escape() -> Vec<DemoReport> {
+    DEMO.run()
+}
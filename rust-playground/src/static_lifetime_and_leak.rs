@@ -0,0 +1,279 @@
+// 'static and leaking on purpose - Box::leak, lazy statics, interning
+// Go:   a package-level var, or anything reachable from one, lives until the
+//       process exits - the GC never frees it, and nobody calls that a leak.
+//       Any pointer may outlive the function that made it (escape analysis
+//       moves it to the heap), so there's no word for "lives forever".
+// Rust: `'static` means "valid until exit": string literals and statics are,
+//       and Box::leak / String::leak turn a heap value into one by promising
+//       never to free it. That's safe, and sometimes the right design (config
+//       read once, an interner with a bounded vocabulary) - a bug when it
+//       happens per request. Demos here declare their leaks, so
+//       `--leak-check` lists them instead of flagging them.
+
+use std::collections::HashSet;
+use std::sync::{LazyLock, Mutex};
+use std::thread;
+use std::time::Instant;
+
+use crate::leak_check::LeakChecker;
+use crate::registry::{Demo, Difficulty, Example, Tag};
+use crate::report::{format_bytes, DemoReport, SideBySideRow};
+
+/// Settings parsed once at startup
+#[derive(Debug)]
+pub struct Config {
+    pub listen: String,
+    pub workers: usize,
+    pub tags: Vec<String>,
+}
+
+impl Config {
+    /// "listen=... workers=N tags=a,b" (missing keys get defaults)
+    pub fn parse(text: &str) -> Config {
+        let mut config = Config { listen: "127.0.0.1:8080".to_string(), workers: 1, tags: Vec::new() };
+        for (key, value) in text.split_whitespace().filter_map(|pair| pair.split_once('=')) {
+            match key {
+                "listen" => config.listen = value.to_string(),
+                "workers" => config.workers = value.parse().unwrap_or(config.workers),
+                "tags" => config.tags = value.split(',').map(String::from).collect(),
+                _ => {}
+            }
+        }
+        config
+    }
+}
+
+const DEFAULT_CONFIG: &str = "listen=0.0.0.0:8080 workers=4 tags=api,edge";
+
+/// Parse once and never free: the result can go anywhere, threads included
+pub fn leak_config(text: &str) -> &'static Config {
+    Box::leak(Box::new(Config::parse(text)))
+}
+
+// Built on first use; a static's value is never dropped
+static DEFAULTS: LazyLock<Config> = LazyLock::new(|| Config::parse(DEFAULT_CONFIG));
+
+static INTERNER: LazyLock<Mutex<HashSet<&'static str>>> = LazyLock::new(|| Mutex::new(HashSet::new()));
+
+/// One shared copy of each distinct string, leaked on first sight
+pub fn intern(text: &str) -> &'static str {
+    let mut interned = INTERNER.lock().unwrap();
+    if let Some(&existing) = interned.get(text) {
+        return existing;
+    }
+    let leaked: &'static str = String::from(text).leak();
+    interned.insert(leaked);
+    leaked
+}
+
+/// How many distinct strings have been interned so far
+pub fn interned_count() -> usize {
+    INTERNER.lock().unwrap().len()
+}
+
+// Run `leak`, declare whatever it left live
+fn leak_declared<R>(report: &mut DemoReport, what: &'static str, leak: impl FnOnce() -> R) -> R {
+    let (result, leaked) = LeakChecker::check(leak, |_| 0);
+    match leaked {
+        Some(0) => report.text(format!("{}: nothing new leaked (already done earlier in this process)", what)),
+        Some(bytes) => report.leak_on_purpose(what, bytes).warn(format!("{}: {} leaked on purpose", what, format_bytes(bytes))),
+        None => report.text(format!("{}: build with --features alloc-stats to measure the leak", what)),
+    };
+    result
+}
+
+// Literals are 'static for free; Box::leak makes heap data 'static
+pub fn box_leak() -> DemoReport {
+    let mut report = DemoReport::new("Box::leak - Heap Data That Lives Forever");
+
+    let literal: &'static str = DEFAULT_CONFIG;
+    let owned = String::from(DEFAULT_CONFIG);
+    report.address("a string literal (read-only data in the binary)", literal, "&'static str - no heap at all");
+    report.address("the same text in a String", owned.as_str(), "heap, freed at the end of this function");
+
+    report.step("Box::leak");
+    report.code("let config: &'static Config = Box::leak(Box::new(Config::parse(text)));");
+    let config = leak_declared(&mut report, "Box::leak(Config)", || leak_config(DEFAULT_CONFIG));
+    report.address("config", config, format!("{:?}", config));
+
+    report.step("Why bother: threads can borrow it");
+    report.code("thread::spawn(move || config.workers)  // no Arc, no clone: &'static is Copy and Send");
+    let seen: Vec<usize> = (0..config.workers)
+        .map(|worker| thread::spawn(move || worker + config.tags.len()))
+        .collect::<Vec<_>>()
+        .into_iter()
+        .map(|handle| handle.join().unwrap())
+        .collect();
+    report.text(format!("{} threads read the same Config through a plain reference: {:?}", seen.len(), seen));
+    report.text("thread::spawn needs F: 'static - owned values or 'static references; a leaked Box is the latter");
+
+    report.step("The family");
+    report.code("Box::leak(b) -> &'static mut T   String::leak(s) -> &'static mut str   Vec::leak(v) -> &'static mut [T]");
+    report.code("Box::into_raw(b) -> *mut T  // also never freed... unless you Box::from_raw it later");
+
+    report.gap();
+    report.ok("Leaking is memory-safe: the value just outlives every reference to it");
+    report.warn("Called once at startup that's a design; called per request it's a leak");
+    report.text("Try: cargo run --features alloc-stats -- --leak-check run static-lifetime-and-leak");
+    report
+}
+
+// lazy_static / LazyLock: a global that's never dropped
+pub fn lazy_config() -> DemoReport {
+    let mut report = DemoReport::new("LazyLock Config - A Leak Nobody Minds");
+
+    report.code("static DEFAULTS: LazyLock<Config> = LazyLock::new(|| Config::parse(DEFAULT_CONFIG));");
+    let defaults = leak_declared(&mut report, "LazyLock<Config> contents", || LazyLock::force(&DEFAULTS));
+    report.address("DEFAULTS (the static itself)", defaults, format!("{:?}", defaults));
+    if let Some(tag) = defaults.tags.first() {
+        report.address("DEFAULTS.tags[0] (its heap buffer)", tag.as_str(), format!("{:?}", tag));
+    }
+    report.text("The Config sits in the static's slot; its Strings and Vec point into the heap");
+
+    report.step("Statics never run Drop");
+    report.text("At exit the process just ends - the OS takes the pages back, no destructor runs");
+    report.text("So a LazyLock's heap data is live forever: exactly what Box::leak does, with a name on it");
+    report.code("lazy_static! { static ref DEFAULTS: Config = ...; }  // the pre-1.80 crate, same thing");
+
+    report.gap();
+    report.ok("Bounded: one Config per process, however often it's read");
+    report.text("This is what the leak checker's \"lazily built statics\" caveat means (see globals for OnceLock)");
+    report
+}
+
+// Interning: leak each distinct string once, share it everywhere
+pub fn interning() -> DemoReport {
+    let mut report = DemoReport::new("String Interning by Leaking");
+
+    let words: Vec<String> = "GET /api GET /health POST /api GET /api PUT /users GET /health GET /api"
+        .split_whitespace()
+        .cycle()
+        .take(1_000)
+        .map(String::from)
+        .collect();
+    report.code("fn intern(s: &str) -> &'static str { ... set.get(s) or String::from(s).leak() ... }");
+    leak_declared(&mut report, "interned strings + the interner's table", || words.iter().for_each(|word| {
+        intern(word);
+    }));
+    let interned: Vec<&'static str> = words.iter().map(|word| intern(word)).collect();  // all hits now: no new leaks
+    report.count("words interned", words.len());
+    report.count("distinct strings in the interner", interned_count());
+
+    let mut distinct: Vec<&'static str> = Vec::new();
+    for &word in &interned {
+        if !distinct.iter().any(|seen| std::ptr::eq(*seen, word)) {
+            distinct.push(word);
+        }
+    }
+    report.table(
+        &["string", "address", "uses"],
+        distinct
+            .iter()
+            .map(|&word| {
+                let uses = interned.iter().filter(|&&other| std::ptr::eq(other, word)).count();
+                vec![word.to_string(), format!("{:#x}", word.as_ptr() as usize), uses.to_string()]
+            })
+            .collect(),
+    );
+    report.ok("Every equal string is the same pointer: comparing two is a pointer compare");
+    report.text(format!(
+        "{} words as owned Strings: {} of heap; interned: {} pointers to {} shared copies",
+        words.len(),
+        format_bytes(words.iter().map(String::capacity).sum()),
+        interned.len(),
+        distinct.len()
+    ));
+
+    report.gap();
+    report.ok("Memory is bounded by the vocabulary (methods, paths, field names), not the traffic");
+    report.warn("Intern user input and the vocabulary is unbounded: that's a leak with extra steps");
+    report
+}
+
+// When leaking is fine, and when it's the bug
+pub fn when_to_leak() -> DemoReport {
+    let mut report = DemoReport::new("When Leaking Is the Right Call");
+
+    report.table(
+        &["leak", "verdict", "why"],
+        vec![
+            vec!["config / CLI args at startup".into(), "fine".into(), "one per process, read everywhere".into()],
+            vec!["interner, bounded vocabulary".into(), "fine".into(), "grows to a ceiling and stops".into()],
+            vec!["big structure at exit".into(), "fine".into(), "skip a teardown the OS does anyway".into()],
+            vec!["Box::leak per request".into(), "bug".into(), "grows with traffic until OOM".into()],
+            vec!["Rc cycle".into(), "bug".into(), "nobody chose it (see leak-check)".into()],
+        ],
+    );
+
+    report.step("Skipping the teardown");
+    let tree: Vec<Vec<String>> = (0..2_000).map(|i| (0..50).map(|j| format!("{}-{}", i, j)).collect()).collect();
+    let strings: usize = tree.iter().map(Vec::len).sum();
+    let start = Instant::now();
+    drop(tree);
+    report.duration(format!("dropping {} Strings one by one", strings), start.elapsed());
+    report.code("std::mem::forget(tree); std::process::exit(0);  // what rustc does with its arenas at the end");
+    report.text("At exit that time buys nothing: the OS unmaps the whole heap in one go");
+
+    report.step("Per request: the bug");
+    let (raw, leaked) = LeakChecker::check(|| (0..100).map(|_| Box::into_raw(Box::new([0u8; 1024]))).collect::<Vec<_>>(), |raw| raw.capacity() * size_of::<*mut [u8; 1024]>());
+    report.code("for request in requests { let scratch: &'static mut [u8; 1024] = Box::leak(Box::new([0; 1024])); ... }");
+    match leaked {
+        Some(bytes) => report.fail(format!("100 requests: {} leaked, and counting", format_bytes(bytes))),
+        None => report.text("100 requests: 100 KiB leaked (build with --features alloc-stats to measure)"),
+    };
+    for ptr in raw {
+        // SAFETY: each pointer came from Box::into_raw above and is freed exactly once
+        drop(unsafe { Box::from_raw(ptr) });
+    }
+    report.text("(given back with Box::from_raw, so this demo stays clean)");
+
+    report.gap();
+    report.ok("Ask: is the total bounded? Once per process, or per vocabulary word - fine");
+    report
+}
+
+pub fn static_vs_go() -> DemoReport {
+    let mut report = DemoReport::new("Living Forever: Go vs Rust");
+
+    report.side_by_side(vec![
+        SideBySideRow::differs("var config = loadConfig()  // package-level", "static CONFIG: LazyLock<Config> = LazyLock::new(load_config);"),
+        SideBySideRow::differs("func get() *Config { return config }", "fn get() -> &'static Config { &CONFIG }"),
+        SideBySideRow::differs("c := &Config{...}; return c  // escapes, GC'd when unreachable", "Box::leak(Box::new(Config { .. }))  // never freed"),
+        SideBySideRow::differs("h := unique.Make(s)  // Go 1.23 interning", "let s: &'static str = intern(s);"),
+    ]);
+
+    report.table(
+        &["", "Go", "Rust"],
+        vec![
+            vec!["\"lives forever\"".into(), "reachable from a global".into(), "'static: literal, static, or leaked".into()],
+            vec!["freed at exit".into(), "no (process ends)".into(), "no (statics never drop)".into()],
+            vec!["pass to a thread".into(), "any pointer".into(), "owned value or &'static".into()],
+            vec!["interned values".into(), "unique.Make: freed when unused".into(), "leaked: kept until exit".into()],
+            vec!["a leak is".into(), "something still reachable".into(), "something never dropped".into()],
+        ],
+    );
+
+    report.gap();
+    report.ok("A Go global and a leaked Rust value cost the same: memory held until exit");
+    report.text("The difference: Rust makes you say it (Box::leak, static) - Go just keeps what's reachable");
+    report.warn("`T: 'static` doesn't mean \"lives forever\": an owned String is 'static too, and is dropped as usual");
+    report
+}
+
+pub const DEMO: Demo = Demo {
+    name: "static-lifetime-and-leak",
+    about: "Box::leak and String::leak for &'static data, LazyLock config, interning by leaking - declared to --leak-check - vs Go's forever-live globals",
+    difficulty: Difficulty::Intermediate,
+    tags: &[Tag::Borrowing, Tag::Allocation],
+    examples: &[
+        Example { name: "box-leak", run: box_leak },
+        Example { name: "lazy-config", run: lazy_config },
+        Example { name: "interning", run: interning },
+        Example { name: "when-to-leak", run: when_to_leak },
+        Example { name: "vs-go", run: static_vs_go },
+    ],
+};
+
+pub fn demonstrate_static_lifetime_and_leak() -> Vec<DemoReport> {
+    DEMO.run()
+}
@@ -0,0 +1,69 @@
+// Teach mode - `--step`: one demo step at a time, Enter for the next
+// A demo runs to the end and records its steps in a DemoReport; the stepper
+// then pages through that finished report piece by piece (see
+// Renderer::pieces), so a live audience reads one idea before the next scrolls
+// in. It paginates output that was already produced - nothing runs between
+// pauses. `--step=preview` prints each step's code first, to talk through
+// before its output appears. Like the quiz, it reads from any BufRead, so a
+// scripted run works the same as a terminal.
+
+use std::io::{self, BufRead, Write};
+
+use crate::render::Renderer;
+use crate::report::DemoReport;
+
+/// What to show while waiting for Enter
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StepMode {
+    /// Just the prompt
+    #[default]
+    Pause,
+    /// The next step's code, then the prompt
+    Preview,
+}
+
+/// Prints reports a step at a time
+pub struct Stepper<R, W> {
+    input: R,
+    output: W,
+    mode: StepMode,
+    // Set by `q` or end of input: print the rest without stopping
+    running: bool,
+}
+
+impl<R: BufRead, W: Write> Stepper<R, W> {
+    pub fn new(input: R, output: W, mode: StepMode) -> Self {
+        Stepper { input, output, mode, running: false }
+    }
+
+    /// One report: the title straight away, then a pause before every step
+    pub fn show(&mut self, renderer: &dyn Renderer, report: &DemoReport) -> io::Result<()> {
+        let mut pieces = renderer.pieces(report).into_iter();
+        if let Some(title) = pieces.next() {
+            write!(self.output, "{}", title.text)?;
+        }
+        for piece in pieces {
+            if !self.running {
+                if self.mode == StepMode::Preview && !piece.preview.is_empty() {
+                    write!(self.output, "\n{}", piece.preview)?;
+                }
+                self.pause()?;
+            }
+            write!(self.output, "{}", piece.text)?;
+        }
+        self.output.flush()
+    }
+
+    fn pause(&mut self) -> io::Result<()> {
+        write!(self.output, "  [Enter: next step, q: run to the end] ")?;
+        self.output.flush()?;
+        let mut line = String::new();
+        if self.input.read_line(&mut line)? == 0 {
+            writeln!(self.output)?;
+            self.running = true;
+        } else if line.trim().eq_ignore_ascii_case("q") {
+            self.running = true;
+        }
+        Ok(())
+    }
+}
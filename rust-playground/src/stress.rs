@@ -0,0 +1,207 @@
+// Stress mode - the Arc/Mutex demos under real load
+// `run stress --threads N --iters M` runs each shared-state pattern with N
+// threads doing M updates each, then checks the final value against N × M.
+// A lock or an atomic read-modify-write never loses an update however hard
+// it's pushed; a load followed by a store does, and the count says how many.
+// Go: the same program with counter++ compiles and loses updates too -
+// `go run -race` is how you'd find it. Rust needs unsafe for a real data
+// race, so the racy version here is two separate (safe) atomic operations.
+
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+use crate::contention_bench::{self, Strategy, timed};
+use crate::registry::{Demo, Difficulty, Example, Tag};
+use crate::report::DemoReport;
+
+pub const DEFAULT_THREADS: usize = 4;
+pub const DEFAULT_ITERS: usize = 100_000;
+
+// Used when the demo runs from the registry (`run stress --threads N --iters M`)
+static THREADS: AtomicUsize = AtomicUsize::new(DEFAULT_THREADS);
+static ITERS: AtomicUsize = AtomicUsize::new(DEFAULT_ITERS);
+
+/// Threads per workload in registry runs
+pub fn set_threads(threads: usize) {
+    THREADS.store(threads.max(1), Ordering::Relaxed);
+}
+
+/// Updates per thread in registry runs
+pub fn set_iters(iters: usize) {
+    ITERS.store(iters.max(1), Ordering::Relaxed);
+}
+
+pub fn threads() -> usize {
+    THREADS.load(Ordering::Relaxed)
+}
+
+pub fn iters() -> usize {
+    ITERS.load(Ordering::Relaxed)
+}
+
+/// One workload's result
+#[derive(Debug, Clone, Copy)]
+pub struct StressRun {
+    pub workload: &'static str,
+    pub expected: u64,
+    pub observed: u64,
+    pub elapsed: Duration,
+}
+
+impl StressRun {
+    pub fn lost(&self) -> u64 {
+        self.expected - self.observed
+    }
+
+    fn row(&self) -> Vec<String> {
+        let per_sec = self.expected as f64 / self.elapsed.as_secs_f64().max(f64::EPSILON);
+        vec![
+            self.workload.to_string(),
+            self.expected.to_string(),
+            self.observed.to_string(),
+            self.lost().to_string(),
+            format!("{:.1?}", self.elapsed),
+            format!("{:.1}M", per_sec / 1e6),
+        ]
+    }
+}
+
+const HEADER: [&str; 6] = ["Workload", "Expected", "Final", "Lost", "Time", "Updates/s"];
+
+fn counter(strategy: Strategy, threads: usize, iters: usize) -> StressRun {
+    let measurement = contention_bench::run(strategy, threads, threads * iters);
+    StressRun {
+        workload: strategy.name(),
+        expected: (threads * iters) as u64,
+        observed: measurement.total,
+        elapsed: measurement.elapsed,
+    }
+}
+
+/// rc-weak's arc-mutex example at scale: every thread pushes `iters` values
+pub fn shared_vec(threads: usize, iters: usize) -> StressRun {
+    let data = Arc::new(Mutex::new(Vec::with_capacity(threads * iters)));
+    let shared = Arc::clone(&data);
+    let elapsed = timed(threads, move || {
+        for i in 0..iters {
+            shared.lock().unwrap().push(i);
+        }
+    });
+    let observed = data.lock().unwrap().len() as u64;
+    StressRun { workload: "Arc<Mutex<Vec>> push", expected: (threads * iters) as u64, observed, elapsed }
+}
+
+/// Read, then write: each step is atomic, the increment isn't
+pub fn load_then_store(threads: usize, iters: usize) -> StressRun {
+    let counter = Arc::new(AtomicU64::new(0));
+    let shared = Arc::clone(&counter);
+    let elapsed = timed(threads, move || {
+        for _ in 0..iters {
+            let seen = shared.load(Ordering::Relaxed);
+            shared.store(seen + 1, Ordering::Relaxed);  // Someone else's update in between is overwritten
+        }
+    });
+    StressRun { workload: "load() then store()", expected: (threads * iters) as u64, observed: counter.load(Ordering::Relaxed), elapsed }
+}
+
+/// The same read-then-write, retried until nobody got in between
+pub fn compare_exchange_loop(threads: usize, iters: usize) -> StressRun {
+    let counter = Arc::new(AtomicU64::new(0));
+    let shared = Arc::clone(&counter);
+    let elapsed = timed(threads, move || {
+        for _ in 0..iters {
+            let mut seen = shared.load(Ordering::Relaxed);
+            while let Err(actual) = shared.compare_exchange_weak(seen, seen + 1, Ordering::Relaxed, Ordering::Relaxed) {
+                seen = actual;
+            }
+        }
+    });
+    StressRun { workload: "compare_exchange loop", expected: (threads * iters) as u64, observed: counter.load(Ordering::Relaxed), elapsed }
+}
+
+fn configuration(report: &mut DemoReport) -> (usize, usize) {
+    let (threads, iters) = (threads(), iters());
+    report.count("threads", threads);
+    report.count("updates per thread", iters);
+    report.text("Change them: cargo run --release -- run stress --threads 16 --iters 1000000");
+    (threads, iters)
+}
+
+// Locks and atomic RMWs: no update is ever lost
+pub fn synchronized_counters() -> DemoReport {
+    let mut report = DemoReport::new("Stress: Shared Counters Under Load");
+
+    let (threads, iters) = configuration(&mut report);
+    let runs = [
+        counter(Strategy::Mutex, threads, iters),
+        counter(Strategy::RwLock, threads, iters),
+        counter(Strategy::Atomic, threads, iters),
+        shared_vec(threads, iters),
+    ];
+    report.table(&HEADER, runs.iter().map(StressRun::row).collect());
+
+    report.gap();
+    if runs.iter().all(|run| run.lost() == 0) {
+        report.ok("Final == threads × iters for every workload: no lost updates");
+    } else {
+        report.fail("Updates went missing");
+    }
+    report.text("The lock (or the single fetch_add) makes read-modify-write one indivisible step");
+    if cfg!(debug_assertions) {
+        report.warn("Debug build - run with --release for representative throughput");
+    }
+
+    report.step("Go");
+    report.code("var mu sync.Mutex; mu.Lock(); counter++; mu.Unlock()  // or atomic.Int64.Add(1)");
+    report.text("Same guarantee, same cost curve - see `run contention-bench` for the scaling table");
+    report
+}
+
+// The race you can write in safe Rust: a non-atomic increment built from atomics
+pub fn lost_updates() -> DemoReport {
+    let mut report = DemoReport::new("Stress: Lost Updates Without a Data Race");
+
+    let (threads, iters) = configuration(&mut report);
+    report.code("let seen = counter.load(Relaxed);");
+    report.code("counter.store(seen + 1, Relaxed);  // ← another thread's store can land in between");
+    let runs = [load_then_store(threads, iters), compare_exchange_loop(threads, iters)];
+    report.table(&HEADER, runs.iter().map(StressRun::row).collect());
+
+    report.gap();
+    match runs[0].lost() {
+        0 if threads == 1 => report.text("One thread can't race with itself - try --threads 4"),
+        0 if thread::available_parallelism().map_or(1, usize::from) == 1 => {
+            report.text("Only one CPU here: threads take turns and rarely get preempted mid-increment")
+        }
+        0 => report.text("No collisions this run - add threads or iters until some show up"),
+        lost => report.warn(format!("{} increments overwritten - safe code, wrong answer", lost)),
+    };
+    if runs[1].lost() == 0 {
+        report.ok("compare_exchange retries when the value changed underneath: nothing lost");
+    }
+    report.text("No UB here - every access is atomic. The bug is the gap between the two of them");
+    report.text("Rust prevents data races, not race conditions: a logic race is still on you");
+
+    report.step("Go");
+    report.code("go func() { for i := 0; i < iters; i++ { counter++ } }()  // compiles, loses updates");
+    report.code("go run -race main.go  // WARNING: DATA RACE ... Write at 0x00c000012345 by goroutine 7");
+    report.text("In Go the plain counter++ version is a real data race - the race detector finds it at runtime");
+    report
+}
+
+pub const DEMO: Demo = Demo {
+    name: "stress",
+    about: "Arc/Mutex/atomic counters under --threads N --iters M: final counts, lost updates and throughput",
+    difficulty: Difficulty::Intermediate,
+    tags: &[Tag::Concurrency, Tag::Benchmark],
+    examples: &[
+        Example { name: "counters", run: synchronized_counters },
+        Example { name: "lost-updates", run: lost_updates },
+    ],
+};
+
+pub fn demonstrate_stress() -> Vec<DemoReport> {
+    DEMO.run()
+}
@@ -0,0 +1,242 @@
+// String concatenation - six ways to build one String, timed and counted
+// Go:   strings are immutable, so `s += part` allocates a new string and copies
+//       everything so far - quadratic in a loop. strings.Builder appends into a
+//       growing []byte (Grow(n) reserves up front), and strings.Join sums the
+//       lengths first and allocates once.
+// Rust: String is Go's Builder already: `s += &part` appends in place and grows
+//       by doubling. The quadratic trap is format!("{}{}", s, part), which
+//       builds a fresh String every time. with_capacity and join know the
+//       final size and allocate once; collect::<String>() has to guess.
+// The results table is repeated in the run summary.
+
+use std::fmt::Write;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use crate::alloc_stats;
+use crate::registry::{Demo, Difficulty, Example, Tag};
+use crate::report::{format_bytes, DemoReport};
+
+/// Parts joined per build
+pub const PARTS: usize = 1_000;
+/// Builds timed per strategy
+const ROUNDS: usize = 50;
+
+/// One way of turning the parts into "item-0,item-1,...,item-999"
+pub struct Strategy {
+    pub name: &'static str,
+    pub go: &'static str,
+    pub build: fn(&[String]) -> String,
+}
+
+pub const STRATEGIES: &[Strategy] = &[
+    Strategy { name: "s = format!(\"{}{}{}\", s, sep, part)", go: "s += sep + part", build: format_loop },
+    Strategy { name: "s += &part (no reserve)", go: "var b strings.Builder", build: push_str_growing },
+    Strategy { name: "String::with_capacity + push_str", go: "b.Grow(n) then b.WriteString", build: push_str_reserved },
+    Strategy { name: "write!(s, \"{}{}\", sep, part) (reserved)", go: "fmt.Fprintf(&b, \"%s%s\", sep, part)", build: write_reserved },
+    Strategy { name: "parts.join(\",\")", go: "strings.Join(parts, \",\")", build: join },
+    Strategy { name: "iter().flat_map(..).collect::<String>()", go: "-", build: collect },
+];
+
+/// The input: PARTS short strings
+pub fn parts() -> Vec<String> {
+    (0..PARTS).map(|i| format!("item-{}", i)).collect()
+}
+
+// The parts plus a comma between each two
+fn total_len(parts: &[String]) -> usize {
+    parts.iter().map(String::len).sum::<usize>() + parts.len().saturating_sub(1)
+}
+
+fn separator(i: usize) -> &'static str {
+    if i == 0 { "" } else { "," }
+}
+
+// A fresh String per step: copies everything built so far, every time
+fn format_loop(parts: &[String]) -> String {
+    let mut s = String::new();
+    for (i, part) in parts.iter().enumerate() {
+        s = format!("{}{}{}", s, separator(i), part);
+    }
+    s
+}
+
+fn push_str_growing(parts: &[String]) -> String {
+    let mut s = String::new();
+    for (i, part) in parts.iter().enumerate() {
+        s += separator(i);
+        s += part;
+    }
+    s
+}
+
+fn push_str_reserved(parts: &[String]) -> String {
+    let mut s = String::with_capacity(total_len(parts));
+    for (i, part) in parts.iter().enumerate() {
+        s.push_str(separator(i));
+        s.push_str(part);
+    }
+    s
+}
+
+fn write_reserved(parts: &[String]) -> String {
+    let mut s = String::with_capacity(total_len(parts));
+    for (i, part) in parts.iter().enumerate() {
+        write!(s, "{}{}", separator(i), part).expect("writing to a String never fails");
+    }
+    s
+}
+
+fn join(parts: &[String]) -> String {
+    parts.join(",")
+}
+
+fn collect(parts: &[String]) -> String {
+    parts.iter().enumerate().flat_map(|(i, part)| [separator(i), part.as_str()]).collect()
+}
+
+/// One strategy's numbers
+pub struct Measured {
+    pub per_build: Duration,
+    /// Per build, None without --features alloc-stats
+    pub allocations: Option<usize>,
+    pub bytes_allocated: Option<usize>,
+}
+
+/// Time `rounds` builds and count one build's allocations
+pub fn measure(strategy: &Strategy, parts: &[String], rounds: usize) -> Measured {
+    let (result, stats) = alloc_stats::measure(|| (strategy.build)(parts));
+    black_box(result);
+    let start = Instant::now();
+    for _ in 0..rounds {
+        black_box((strategy.build)(black_box(parts)));
+    }
+    Measured {
+        per_build: start.elapsed() / rounds as u32,
+        allocations: stats.map(|stats| stats.allocations),
+        bytes_allocated: stats.map(|stats| stats.bytes_allocated),
+    }
+}
+
+// Every strategy on the same input
+pub fn strategies() -> DemoReport {
+    let mut report = DemoReport::new("String Concatenation - Six Strategies");
+
+    let parts = parts();
+    let expected = push_str_reserved(&parts);
+    report.text(format!("{} parts (\"item-0\", \"item-1\", ...) into one {}-byte String, {} rounds each", PARTS, expected.len(), ROUNDS));
+    if cfg!(debug_assertions) {
+        report.warn("Debug build - use --release for real numbers");
+    }
+    let all_agree = STRATEGIES.iter().all(|strategy| (strategy.build)(&parts) == expected);
+
+    let measured: Vec<Measured> = STRATEGIES.iter().map(|strategy| measure(strategy, &parts, ROUNDS)).collect();
+    let fastest = measured.iter().map(|m| m.per_build).min().unwrap_or_default().as_secs_f64().max(f64::EPSILON);
+    let or_na = |value: Option<String>| value.unwrap_or_else(|| "n/a".to_string());
+    report.summary_table(
+        &["strategy", "per build", "vs fastest", "allocs", "bytes allocated"],
+        STRATEGIES
+            .iter()
+            .zip(&measured)
+            .map(|(strategy, m)| {
+                vec![
+                    strategy.name.to_string(),
+                    format!("{:.1?}", m.per_build),
+                    format!("{:.1}x", m.per_build.as_secs_f64() / fastest),
+                    or_na(m.allocations.map(|n| n.to_string())),
+                    or_na(m.bytes_allocated.map(format_bytes)),
+                ]
+            })
+            .collect(),
+    );
+    if !alloc_stats::enabled() {
+        report.text("(allocation columns need --features alloc-stats)");
+    }
+    if all_agree {
+        report.ok("Every strategy built the same String");
+    } else {
+        report.fail("The strategies disagree on the result");
+    }
+
+    report.gap();
+    report.warn("format! in a loop copies the whole string every step: O(n²) bytes, one allocation per part");
+    report.ok("`s += &part` doubles its capacity: ~log2(n) allocations, amortized O(1) per append");
+    report.ok("Reserving and join allocate once - join sums the lengths for you, like strings.Join");
+    report.warn("collect::<String>() can't know the total length (flat_map's size hint is 0): it grows like +=");
+    report.text("write! goes through the fmt machinery: same allocation as push_str, more instructions");
+    report
+}
+
+// How the buffer grows, append by append
+pub fn growth() -> DemoReport {
+    let mut report = DemoReport::new("Where the Allocations Go");
+
+    let parts = parts();
+    let mut s = String::new();
+    let mut rows = Vec::new();
+    let mut last_capacity = s.capacity();
+    for (i, part) in parts.iter().enumerate() {
+        s += separator(i);
+        s += part;
+        if s.capacity() != last_capacity {
+            rows.push(vec![(i + 1).to_string(), s.len().to_string(), s.capacity().to_string()]);
+            last_capacity = s.capacity();
+        }
+    }
+    report.code("let mut s = String::new(); for (i, part) in parts.iter().enumerate() { s += sep(i); s += part; }");
+    let allocations = rows.len();
+    report.table(&["after part", "len", "capacity"], rows);
+    report.count("allocations for 1,000 appends", allocations);
+    report.text("Each one copies the current contents once; the copies add up to less than the final size");
+
+    report.step("format! in a loop");
+    report.code("s = format!(\"{}{}{}\", s, sep(i), part);  // reads s, builds a new String, drops the old one");
+    let copied: usize = (1..=PARTS).map(|n| total_len(&parts[..n])).sum();
+    report.text(format!("bytes written over 1,000 steps: {} for a {} result", format_bytes(copied), format_bytes(s.len())));
+    report.warn("That's Go's `s += part` too: a string is never appended to, only replaced");
+
+    report.gap();
+    report.ok("A Rust String is Go's strings.Builder; `+=` on it is WriteString");
+    report
+}
+
+pub fn concat_vs_go() -> DemoReport {
+    let mut report = DemoReport::new("Concatenation: Go vs Rust");
+
+    report.table(
+        &["Rust", "Go equivalent"],
+        STRATEGIES.iter().map(|strategy| vec![strategy.name.to_string(), strategy.go.to_string()]).collect(),
+    );
+
+    report.step("Go");
+    report.code("s := \"\"; for i, p := range parts { s += sep(i) + p }  // new string each time: O(n²)");
+    report.code("var b strings.Builder; b.Grow(n); for ... { b.WriteString(sep(i)); b.WriteString(p) }");
+    report.code("s := b.String()  // no copy: Builder hands over its []byte");
+    report.text("Builder's String() is free because nothing can write to the bytes afterwards - Builder forbids copying itself");
+
+    report.step("Rust");
+    report.code("let mut s = String::with_capacity(n); for (i, p) in parts.iter().enumerate() { s.push_str(sep(i)); s.push_str(p); }");
+    report.code("let s = parts.join(\",\");  // sums the lengths first, like strings.Join");
+    report.ok("Ownership does what Builder's copy check does: the finished String has one owner, no copy needed");
+    report.warn("`let s = a + &b;` moves `a` - the result reuses a's buffer, and a is gone");
+
+    report.gap();
+    report.text("In a profile the quadratic version shows up as runtime.concatstrings (Go) or alloc::fmt::format (Rust)");
+    report
+}
+
+pub const DEMO: Demo = Demo {
+    name: "string-concat-bench",
+    about: "format!, += , with_capacity + push_str, write!, join and collect::<String>(): time and allocations vs Go's + and strings.Builder",
+    difficulty: Difficulty::Beginner,
+    tags: &[Tag::Allocation, Tag::Benchmark],
+    examples: &[
+        Example { name: "strategies", run: strategies },
+        Example { name: "growth", run: growth },
+        Example { name: "vs-go", run: concat_vs_go },
+    ],
+};
+
+pub fn demonstrate_string_concat_bench() -> Vec<DemoReport> {
+    DEMO.run()
+}
@@ -0,0 +1,324 @@
+// Strings are UTF-8 bytes - in both languages, with different guard rails
+// Go:   a string is an immutable {ptr, len} of bytes, usually UTF-8. s[i] is a
+//       byte, s[i:j] slices bytes anywhere (even mid-character), and
+//       `for _, r := range s` decodes runes. Every += builds a new string.
+// Rust: String/&str are ALWAYS valid UTF-8. There's no s[i] at all, and a
+//       byte range that splits a character panics instead of producing
+//       broken text. chars() and bytes() make you say which one you mean,
+//       and a String grows in place like a Vec<u8>.
+
+use std::collections::{HashMap, HashSet};
+use std::panic;
+use std::rc::Rc;
+
+use crate::alloc_stats;
+use crate::registry::{Demo, Difficulty, Example, Tag};
+use crate::report::DemoReport;
+
+const TEXT: &str = "héllo, 世界 🦀";
+
+// len() counts bytes; slicing is by byte offset and must land on a char boundary
+pub fn byte_indexing() -> DemoReport {
+    let mut report = DemoReport::new("Byte Offsets - Why &s[0..2] Can Panic");
+
+    report.text(format!("s = {:?}", TEXT));
+    report.count("s.len() (bytes)", TEXT.len());
+    report.count("s.chars().count()", TEXT.chars().count());
+
+    report.step("Slicing on a boundary");
+    report.code("&s[0..1]");
+    report.text(format!("→ {:?}", &TEXT[0..1]));
+
+    report.step("Slicing through the middle of 'é' (bytes 1..3)");
+    report.code("&s[0..2]");
+    // Silence the default panic message; the Err is what we report
+    let previous_hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let sliced = panic::catch_unwind(|| TEXT[0..std::hint::black_box(2)].len());
+    panic::set_hook(previous_hook);
+    match sliced {
+        Ok(_) => report.ok("sliced fine"),
+        Err(_) => report.fail("panic: byte index 2 is not a char boundary; it is inside 'é' (bytes 1..3)"),
+    };
+    report.code("s.get(0..2)");
+    report.text(format!("→ {:?} - the non-panicking version", TEXT.get(0..2)));
+    report.text(format!("s.is_char_boundary(2) = {}", TEXT.is_char_boundary(2)));
+
+    report.step("Indexing a single position");
+    report.code("let c = s[1];  // ❌ E0277: the type `str` cannot be indexed by `{integer}`");
+    report.text("Checked by tests/compile_fail/string_index.rs");
+    report.code("s.as_bytes()[1]  // if you really want the byte");
+    report.text(format!("→ 0x{:02x} - the first byte of 'é', not a character", TEXT.as_bytes()[1]));
+
+    report.gap();
+    report.ok("A &str can never hold half a character - the slice panics first");
+    report.warn("Offsets from find() and char_indices() are always safe; arithmetic on them isn't");
+
+    report.step("Go");
+    report.code("s[1]           // 0xc3: a byte, compiles fine");
+    report.code("s[0:2]         // \"h\\xc3\": broken UTF-8, no panic");
+    report.code("utf8.ValidString(s[0:2])  // false - if you remember to check");
+    report
+}
+
+// chars() decodes, bytes() doesn't; char is 4 bytes like Go's rune
+pub fn chars_vs_bytes() -> DemoReport {
+    let mut report = DemoReport::new("chars() vs bytes() vs char_indices()");
+
+    report.code("for (offset, c) in s.char_indices() { ... }");
+    let rows = TEXT
+        .char_indices()
+        .filter(|&(_, c)| c != ' ' && c != ',')
+        .map(|(offset, c)| {
+            let mut buf = [0u8; 4];
+            let encoded = c.encode_utf8(&mut buf);
+            let hex: Vec<String> = encoded.bytes().map(|b| format!("{:02x}", b)).collect();
+            vec![c.to_string(), offset.to_string(), c.len_utf8().to_string(), hex.join(" "), format!("U+{:04X}", c as u32)]
+        })
+        .collect();
+    report.table(&["char", "byte offset", "UTF-8 bytes", "encoding", "code point"], rows);
+
+    report.bytes("size_of::<char>()", size_of::<char>());
+    report.text("A char is a Unicode scalar value, always 4 bytes; in a str it takes 1-4");
+    report.count("s.chars().count() - decodes the whole string, O(n)", TEXT.chars().count());
+    report.text(format!("s.chars().rev() = {:?}", TEXT.chars().rev().collect::<String>()));
+
+    report.gap();
+    report.warn("chars() is code points, not what a user sees: \"e\\u{301}\" is 2 chars that render as é");
+    report.text("Grapheme clusters need a crate (unicode-segmentation) in Rust, and a package in Go");
+
+    report.step("Go");
+    report.code("for i, r := range s { ... }  // i = byte offset, r = rune (int32) - like char_indices()");
+    report.code("len(s)                       // bytes, like s.len()");
+    report.code("utf8.RuneCountInString(s)    // like s.chars().count()");
+    report
+}
+
+// String is a Vec<u8> underneath: push_str grows it in place
+pub fn string_growth() -> DemoReport {
+    let mut report = DemoReport::new("String Growth - push_str vs Go's +=");
+
+    let mut s = String::new();
+    let mut rows = Vec::new();
+    for word in ["go", "pher", "s ", "and ", "crabs ", "share ", "utf-8 ", "bytes"] {
+        let before = s.capacity();
+        s.push_str(word);
+        if s.capacity() != before {
+            rows.push(vec![format!("{:?}", word), s.len().to_string(), s.capacity().to_string()]);
+        }
+    }
+    report.code("let mut s = String::new(); s.push_str(word);  // 8 words");
+    let grew = rows.len();
+    report.table(&["push_str that grew it", "len", "capacity"], rows);
+    report.count("times it grew (each may move the buffer, unless realloc extends it in place)", grew);
+    report.text(format!("final: {:?}", s));
+
+    report.step("Knowing the size");
+    report.code("let mut s = String::with_capacity(64);");
+    let mut sized = String::with_capacity(64);
+    let sized_ptr = sized.as_ptr();
+    sized.push_str(&s);
+    report.ok(format!("Same text, buffer never moved: {}", sized.as_ptr() == sized_ptr));
+
+    report.gap();
+    report.ok("Amortized growth like Vec: appending n bytes costs O(n) in total");
+
+    report.step("Go");
+    report.code("s := \"\"; for _, w := range words { s += w }  // a new string every time: O(n²)");
+    report.code("var b strings.Builder; b.Grow(64); b.WriteString(w); s := b.String()");
+    report.text("Go strings are immutable, so strings.Builder plays the role of String");
+    report
+}
+
+fn allocations_of(f: impl FnOnce()) -> String {
+    match alloc_stats::measure(f).1 {
+        Some(stats) => stats.allocations.to_string(),
+        None => "n/a".to_string(),
+    }
+}
+
+// Heap allocations per common operation, next to the Go idiom
+pub fn allocation_counts() -> DemoReport {
+    let mut report = DemoReport::new("Allocations per String Operation - Rust vs Go");
+
+    let csv = "ada,bo,cy,di";
+    let owned = String::from(csv);
+    let appended = owned.clone();
+    let bytes = csv.as_bytes().to_vec();
+    let rows = vec![
+        vec![
+            "substring".into(),
+            "&s[0..3]".into(),
+            allocations_of(|| {
+                std::hint::black_box(&csv[0..3]);
+            }),
+            "s[0:3]".into(),
+            "0".into(),
+        ],
+        vec![
+            "to owned".into(),
+            "s.to_string()".into(),
+            allocations_of(|| drop(csv.to_string())),
+            "(strings are immutable)".into(),
+            "-".into(),
+        ],
+        vec![
+            "upper-case".into(),
+            "s.to_uppercase()".into(),
+            allocations_of(|| drop(csv.to_uppercase())),
+            "strings.ToUpper(s)".into(),
+            "1".into(),
+        ],
+        vec![
+            "format".into(),
+            "format!(\"{}!\", s)".into(),
+            allocations_of(|| drop(format!("{}!", csv))),
+            "fmt.Sprintf(\"%s!\", s)".into(),
+            "1-2".into(),
+        ],
+        vec![
+            "append".into(),
+            "owned + \"!\"".into(),
+            allocations_of(|| drop(appended + "!")),
+            "s + \"!\"".into(),
+            "1".into(),
+        ],
+        vec![
+            "split, borrowed".into(),
+            "s.split(',').collect::<Vec<&str>>()".into(),
+            allocations_of(|| drop(csv.split(',').collect::<Vec<&str>>())),
+            "strings.Split(s, \",\")".into(),
+            "1".into(),
+        ],
+        vec![
+            "split, owned".into(),
+            "s.split(',').map(String::from).collect()".into(),
+            allocations_of(|| drop(csv.split(',').map(String::from).collect::<Vec<String>>())),
+            "-".into(),
+            "-".into(),
+        ],
+        vec![
+            "split, count only".into(),
+            "s.split(',').count()".into(),
+            allocations_of(|| {
+                std::hint::black_box(csv.split(',').count());
+            }),
+            "strings.Count(s, \",\") + 1".into(),
+            "0".into(),
+        ],
+        vec![
+            "bytes → string".into(),
+            "String::from_utf8(vec)".into(),
+            allocations_of(|| drop(String::from_utf8(bytes))),
+            "string(b)".into(),
+            "1 (copy)".into(),
+        ],
+        vec![
+            "string → bytes".into(),
+            "s.into_bytes()".into(),
+            allocations_of(|| drop(owned.into_bytes())),
+            "[]byte(s)".into(),
+            "1 (copy)".into(),
+        ],
+    ];
+    report.table(&["Operation", "Rust", "Rust allocs", "Go", "Go allocs (-benchmem)"], rows);
+    if !alloc_stats::enabled() {
+        report.text("(Rust allocation counts need --features alloc-stats)");
+    }
+    report.text("owned + \"!\" reallocates only when the String is full - here it was, exactly");
+
+    report.gap();
+    report.ok("String ↔ Vec<u8> conversions move the buffer - Go must copy, since strings are immutable");
+    report.ok("Borrowed splits allocate one Vec of &str; iterating the split allocates nothing");
+    report.text("Go's compiler elides some copies (string(b) as a map key, in comparisons) - not all");
+    report
+}
+
+// Many copies of a few distinct strings: share them instead
+pub fn interning() -> DemoReport {
+    let mut report = DemoReport::new("Interning - One Allocation per Distinct String");
+
+    report.table(
+        &["Type", "size_of", "Owns", "Clone costs"],
+        vec![
+            vec!["String".into(), size_of::<String>().to_string(), "yes, growable".into(), "allocate + copy".into()],
+            vec!["Box<str>".into(), size_of::<Box<str>>().to_string(), "yes, fixed".into(), "allocate + copy".into()],
+            vec!["Rc<str>".into(), size_of::<Rc<str>>().to_string(), "shared".into(), "refcount +1".into()],
+            vec!["&'static str".into(), size_of::<&'static str>().to_string(), "no".into(), "copy 16 bytes".into()],
+            vec!["u32 symbol".into(), size_of::<u32>().to_string(), "the interner does".into(), "copy 4 bytes".into()],
+        ],
+    );
+
+    let levels = ["debug", "info", "warn", "error"];
+    let log: Vec<&str> = (0..1_000).map(|i| levels[i % levels.len()]).collect();
+
+    let (owned, owned_allocs) = alloc_stats::measure(|| log.iter().map(|&level| level.to_string()).collect::<Vec<String>>());
+    let (shared, shared_allocs) = alloc_stats::measure(|| {
+        let mut table: HashMap<&str, Rc<str>> = HashMap::new();
+        log.iter().map(|&level| Rc::clone(table.entry(level).or_insert_with(|| Rc::from(level)))).collect::<Vec<Rc<str>>>()
+    });
+    let allocs = |stats: Option<alloc_stats::AllocStats>| stats.map_or("n/a".to_string(), |stats| stats.allocations.to_string());
+    let distinct_owned: HashSet<*const u8> = owned.iter().map(|s| s.as_ptr()).collect();
+    let distinct_shared: HashSet<*const u8> = shared.iter().map(|s| s.as_ptr()).collect();
+    report.table(
+        &["1,000 log levels as", "Heap allocations", "Distinct buffers"],
+        vec![
+            vec!["Vec<String>".into(), allocs(owned_allocs), distinct_owned.len().to_string()],
+            vec!["Vec<Rc<str>> via an interner".into(), allocs(shared_allocs), distinct_shared.len().to_string()],
+        ],
+    );
+    report.count("Rc::strong_count(&shared[0]) - every \"debug\" entry", Rc::strong_count(&shared[0]));
+    if !alloc_stats::enabled() {
+        report.text("(allocation counts need --features alloc-stats)");
+    }
+
+    report.gap();
+    report.ok("Interned strings compare by pointer, hash once, and cost one allocation each");
+    report.text("Across threads use Arc<str>; for 'static data, just &'static str");
+
+    report.step("Go");
+    report.code("h := unique.Make(level)  // Go 1.23: a canonical handle, compare with ==");
+    report.text("Before 1.23: a map[string]string interner - the GC frees nothing while it's reachable");
+    report
+}
+
+pub fn strings_vs_go() -> DemoReport {
+    let mut report = DemoReport::new("Strings: Go vs Rust");
+
+    report.table(
+        &["", "Go", "Rust"],
+        vec![
+            vec!["encoding".into(), "UTF-8 by convention".into(), "UTF-8, guaranteed".into()],
+            vec!["owned / borrowed".into(), "string (one type)".into(), "String / &str".into()],
+            vec!["mutable".into(), "no - use strings.Builder".into(), "String, in place".into()],
+            vec!["s[i]".into(), "a byte".into(), "doesn't compile".into()],
+            vec!["s[i:j] mid-character".into(), "broken UTF-8".into(), "panic (get() → None)".into()],
+            vec!["characters".into(), "range s → rune".into(), "s.chars() → char".into()],
+            vec!["↔ bytes".into(), "copies".into(), "moves (into_bytes, from_utf8)".into()],
+        ],
+    );
+
+    report.gap();
+    report.ok("Rust turns Go's silent broken-UTF-8 into a compile error or a panic");
+    report.text("Both store bytes, both index by byte offset - the difference is who checks");
+    report
+}
+
+pub const DEMO: Demo = Demo {
+    name: "string-encoding",
+    about: "UTF-8 strings: byte vs char indexing, growth, allocation counts and interning vs Go strings",
+    difficulty: Difficulty::Beginner,
+    tags: &[Tag::Layout, Tag::Allocation],
+    examples: &[
+        Example { name: "bytes", run: byte_indexing },
+        Example { name: "chars", run: chars_vs_bytes },
+        Example { name: "growth", run: string_growth },
+        Example { name: "allocations", run: allocation_counts },
+        Example { name: "interning", run: interning },
+        Example { name: "vs-go", run: strings_vs_go },
+    ],
+};
+
+pub fn demonstrate_string_encoding() -> Vec<DemoReport> {
+    DEMO.run()
+}
@@ -0,0 +1,222 @@
+// Waiting for goroutines - sync.WaitGroup and what Rust uses instead
+// Go:   wg.Add(n); go func() { defer wg.Done(); ... }(); wg.Wait(). Results
+//       go through a channel or a slice the workers write into.
+// Rust: there's no WaitGroup in std because a JoinHandle already is one -
+//       join() waits for one thread AND hands back its return value. For
+//       "everyone reached this point" there's Barrier; for "tell me when
+//       you're done" a channel closes itself when the last Sender drops.
+
+use std::collections::BTreeMap;
+use std::sync::mpsc;
+use std::sync::{Arc, Barrier, Mutex};
+use std::thread;
+use std::time::Duration;
+
+use crate::registry::{Demo, Difficulty, Example, Tag};
+use crate::report::DemoReport;
+
+const WORKERS: usize = 4;
+const JOBS: usize = 20;
+
+// WaitGroup → Vec<JoinHandle>
+pub fn join_handles() -> DemoReport {
+    let mut report = DemoReport::new("WaitGroup → Vec<JoinHandle<T>>");
+
+    report.step("Go");
+    report.code("var wg sync.WaitGroup");
+    report.code("results := make([]int, n)");
+    report.code("for i := 0; i < n; i++ {");
+    report.code("    wg.Add(1)");
+    report.code("    go func(i int) { defer wg.Done(); results[i] = i * i }(i)");
+    report.code("}");
+    report.code("wg.Wait()");
+
+    report.step("Rust");
+    report.code("let handles: Vec<_> = (0..n).map(|i| thread::spawn(move || i * i)).collect();");
+    report.code("let results: Vec<_> = handles.into_iter().map(|h| h.join().unwrap()).collect();");
+    let handles: Vec<_> = (0..WORKERS).map(|i| thread::spawn(move || i * i)).collect();
+    report.count("handles to wait on", handles.len());
+    let results: Vec<usize> = handles.into_iter().map(|handle| handle.join().unwrap()).collect();
+    report.text(format!("results = {:?}", results));
+
+    report.gap();
+    report.ok("No counter to Add/Done: one handle per thread, so the count can't be wrong");
+    report.ok("join() returns the thread's value - no shared results slice, no index bookkeeping");
+    report.text("join() returns Err if the thread panicked; Go's panic would take the process down");
+    report.warn("Dropping a JoinHandle detaches the thread - nothing waits for it");
+    report.text("Borrowing local data? thread::scope joins for you (run scoped-threads)");
+    report
+}
+
+// Barrier: nobody starts phase 2 until everyone finished phase 1
+pub fn barrier() -> DemoReport {
+    let mut report = DemoReport::new("Barrier - Wait Until Everyone Gets Here");
+
+    let barrier = Arc::new(Barrier::new(WORKERS));
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let handles: Vec<_> = (0..WORKERS)
+        .map(|id| {
+            let (barrier, log) = (Arc::clone(&barrier), Arc::clone(&log));
+            thread::spawn(move || {
+                // Stagger phase 1 so the fast workers visibly wait
+                thread::sleep(Duration::from_millis(id as u64 * 5));
+                log.lock().unwrap().push(format!("w{} load", id));
+                let leader = barrier.wait().is_leader();
+                log.lock().unwrap().push(format!("w{} merge", id));
+                leader
+            })
+        })
+        .collect();
+    let leaders = handles.into_iter().map(|handle| handle.join().unwrap()).filter(|&leader| leader).count();
+    let log = Arc::try_unwrap(log).unwrap().into_inner().unwrap();
+
+    report.code("let leader = barrier.wait().is_leader();");
+    report.text(format!("order: {}", log.join(", ")));
+    let last_load = log.iter().rposition(|entry| entry.ends_with("load")).unwrap();
+    let first_merge = log.iter().position(|entry| entry.ends_with("merge")).unwrap();
+    if last_load < first_merge {
+        report.ok("Every load happened before any merge");
+    } else {
+        report.fail("A merge ran before every load finished");
+    }
+    report.count("leaders (exactly one per round)", leaders);
+
+    report.gap();
+    report.text("A Barrier is reusable: call wait() again for the next phase");
+    report.text("is_leader() picks one thread for per-round work, like printing a summary");
+
+    report.step("Go");
+    report.text("No Barrier in sync: a WaitGroup per phase, or a channel every worker waits on");
+    report.code("phase1.Done(); phase1.Wait()  // reusing it: Add must not race a pending Wait");
+    report
+}
+
+// "Tell me when you're done": the channel closes when the last Sender drops
+pub fn channel_completion() -> DemoReport {
+    let mut report = DemoReport::new("Channel Completion - Done When the Senders Are Gone");
+
+    let (done_tx, done_rx) = mpsc::channel();
+    for id in 0..WORKERS {
+        let done_tx = done_tx.clone();
+        thread::spawn(move || {
+            thread::sleep(Duration::from_millis((WORKERS - id) as u64 * 3));
+            done_tx.send(id).unwrap();
+        });
+    }
+    drop(done_tx);  // keep only the workers' clones alive
+    let finished: Vec<usize> = done_rx.iter().collect();
+
+    report.code("drop(done_tx);  // main's copy");
+    report.code("let finished: Vec<_> = done_rx.iter().collect();  // ends when every clone is dropped");
+    report.text(format!("finish order: {:?}", finished));
+    report.count("workers reported", finished.len());
+
+    report.gap();
+    report.ok("rx.iter() ends by itself: each worker's Sender drops when its thread returns");
+    report.warn("Forget drop(done_tx) and the loop waits forever - main still holds a Sender");
+
+    report.step("Go");
+    report.code("go func() { wg.Wait(); close(done) }()  // someone has to close it");
+    report.code("for id := range done { ... }");
+    report.text("Go needs the WaitGroup AND the close; in Rust ownership does the counting");
+    report
+}
+
+// Fan out JOBS jobs to WORKERS workers, wait for all results
+pub fn worker_pool() -> DemoReport {
+    let mut report = DemoReport::new("Worker Pool - Fan Out N Jobs, Wait for All");
+
+    let (job_tx, job_rx) = mpsc::channel::<usize>();
+    let job_rx = Arc::new(Mutex::new(job_rx));
+    let (result_tx, result_rx) = mpsc::channel();
+
+    let workers: Vec<_> = (0..WORKERS)
+        .map(|worker| {
+            let (job_rx, result_tx) = (Arc::clone(&job_rx), result_tx.clone());
+            thread::spawn(move || {
+                loop {
+                    // The guard is a temporary: the lock is released before the job runs
+                    let job = job_rx.lock().unwrap().recv();
+                    let Ok(job) = job else { break };
+                    thread::sleep(Duration::from_micros(200));
+                    result_tx.send((worker, job, job * job)).unwrap();
+                }
+            })
+        })
+        .collect();
+    drop(result_tx);
+
+    for job in 0..JOBS {
+        job_tx.send(job).unwrap();
+    }
+    drop(job_tx);  // closing the queue is how workers learn to stop
+
+    let results: Vec<(usize, usize, usize)> = result_rx.iter().collect();
+    for worker in workers {
+        worker.join().unwrap();
+    }
+
+    let mut per_worker: BTreeMap<usize, usize> = BTreeMap::new();
+    for &(worker, _, _) in &results {
+        *per_worker.entry(worker).or_default() += 1;
+    }
+    let sum: usize = results.iter().map(|&(_, _, square)| square).sum();
+
+    report.count("jobs sent", JOBS);
+    report.count("results received", results.len());
+    report.count("sum of squares", sum);
+    report.table(
+        &["Worker", "Jobs"],
+        per_worker.iter().map(|(worker, jobs)| vec![format!("w{}", worker), jobs.to_string()]).collect(),
+    );
+
+    report.gap();
+    report.ok("Waiting = result_rx running dry, then join() on every worker");
+    report.text("std's Receiver isn't shareable, hence Arc<Mutex<Receiver>>; crossbeam's is");
+
+    report.step("Go");
+    report.code("jobs, results := make(chan int), make(chan int)");
+    report.code("for w := 0; w < 4; w++ { wg.Add(1); go worker(jobs, results, &wg) }");
+    report.code("go func() { wg.Wait(); close(results) }()");
+    report.code("for j := 0; j < 20; j++ { jobs <- j }; close(jobs)");
+    report
+}
+
+pub fn sync_primitives_vs_go() -> DemoReport {
+    let mut report = DemoReport::new("Waiting for Work: Go vs Rust");
+
+    report.table(
+        &["Go", "Rust", "Notes"],
+        vec![
+            vec!["wg.Add / Done / Wait".into(), "Vec<JoinHandle<T>> + join()".into(), "also returns each result".into()],
+            vec!["WaitGroup + borrowed locals".into(), "thread::scope".into(), "joins at the closing brace".into()],
+            vec!["one WaitGroup per phase".into(), "Barrier".into(), "reusable, picks a leader".into()],
+            vec!["wg.Wait(); close(done)".into(), "drop the last Sender".into(), "rx.iter() ends on its own".into()],
+            vec!["errgroup.Group".into(), "join() → Result, or collect Results".into(), "first error wins by choice".into()],
+            vec!["sync.Once".into(), "OnceLock (run globals)".into(), "".into()],
+        ],
+    );
+
+    report.gap();
+    report.ok("Go devs ask for WaitGroup on day one - the answer is: keep the handles");
+    report.warn("wg.Add after wg.Wait started, or a missing Done, is a Go runtime bug; Rust has no counter to get wrong");
+    report
+}
+
+pub const DEMO: Demo = Demo {
+    name: "sync-primitives",
+    about: "WaitGroup in Rust: JoinHandles, Barrier, channel completion and a worker pool",
+    difficulty: Difficulty::Beginner,
+    tags: &[Tag::Concurrency],
+    examples: &[
+        Example { name: "join-handles", run: join_handles },
+        Example { name: "barrier", run: barrier },
+        Example { name: "channel-done", run: channel_completion },
+        Example { name: "worker-pool", run: worker_pool },
+        Example { name: "vs-go", run: sync_primitives_vs_go },
+    ],
+};
+
+pub fn demonstrate_sync_primitives() -> Vec<DemoReport> {
+    DEMO.run()
+}
@@ -0,0 +1,159 @@
+// Telemetry - every demo run as a tracing span (enable with `--features tracing`)
+// The reports are prose for people; a dashboard wants numbers without
+// parsing prose. With the feature each demo (Demo::run) and each example
+// (DemoReport::measured) runs inside a span, and when the span closes it
+// carries what the run cost: duration, allocations, bytes. Every count and
+// measurement an example recorded becomes an event inside its span, and so
+// does every ❌ note. `--trace` installs a subscriber printing them on
+// stderr - JSON lines by default, `--trace=text` for people - so stdout
+// keeps the report. Without the feature the spans are zero-sized no-ops.
+
+#[cfg(feature = "tracing")]
+use std::time::Instant;
+
+#[cfg(feature = "tracing")]
+use tracing::field::Empty;
+#[cfg(feature = "tracing")]
+use tracing_subscriber::fmt::MakeWriter;
+#[cfg(feature = "tracing")]
+use tracing_subscriber::fmt::format::FmtSpan;
+
+use crate::report::DemoReport;
+#[cfg(feature = "tracing")]
+use crate::report::{Entry, NoteKind, Unit};
+
+/// How `--trace` prints spans and events
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TraceFormat {
+    /// One JSON object per line: span closes carry the span's fields
+    Json,
+    /// tracing_subscriber's human-readable lines
+    Text,
+}
+
+/// Print spans and events on stderr from now on (a no-op if already installed)
+#[cfg(feature = "tracing")]
+pub fn init(format: TraceFormat) {
+    use std::io::IsTerminal;
+
+    // Err only when a subscriber is already set (tests, a second init): keep that one
+    let _ = match format {
+        TraceFormat::Json => tracing::subscriber::set_global_default(json_subscriber(std::io::stderr)).map_err(|_| ()),
+        TraceFormat::Text => tracing_subscriber::fmt()
+            .with_writer(std::io::stderr)
+            .with_span_events(FmtSpan::CLOSE)
+            .with_ansi(std::io::stderr().is_terminal())
+            .try_init()
+            .map_err(|_| ()),
+    };
+}
+
+/// The `--trace=json` subscriber: a line per event and per span close, with
+/// the span's recorded fields and its parents
+#[cfg(feature = "tracing")]
+pub fn json_subscriber<W>(writer: W) -> impl tracing::Subscriber + Send + Sync
+where
+    W: for<'writer> MakeWriter<'writer> + Send + Sync + 'static,
+{
+    tracing_subscriber::fmt().with_writer(writer).with_span_events(FmtSpan::CLOSE).json().with_current_span(true).with_span_list(true).finish()
+}
+
+/// Open while a demo runs its examples
+#[must_use = "the span closes when this is dropped"]
+pub struct DemoSpan {
+    #[cfg(feature = "tracing")]
+    span: tracing::span::EnteredSpan,
+    #[cfg(feature = "tracing")]
+    start: Instant,
+}
+
+impl DemoSpan {
+    pub fn enter(demo: &'static str) -> Self {
+        #[cfg(not(feature = "tracing"))]
+        let _ = demo;
+        DemoSpan {
+            #[cfg(feature = "tracing")]
+            span: tracing::info_span!("demo", demo, examples = Empty, duration_ns = Empty, allocations = Empty, bytes_allocated = Empty).entered(),
+            #[cfg(feature = "tracing")]
+            start: Instant::now(),
+        }
+    }
+
+    /// Record the totals over `reports` and close the span
+    pub fn close(self, reports: &[DemoReport]) {
+        #[cfg(feature = "tracing")]
+        {
+            self.span.record("examples", reports.len());
+            self.span.record("duration_ns", self.start.elapsed().as_nanos() as u64);
+            if reports.iter().any(|report| report.alloc.is_some()) {
+                let stats = reports.iter().filter_map(|report| report.alloc);
+                let (allocations, bytes) = stats.fold((0, 0), |(allocations, bytes), stats| (allocations + stats.allocations, bytes + stats.bytes_allocated));
+                self.span.record("allocations", allocations);
+                self.span.record("bytes_allocated", bytes);
+            }
+        }
+        #[cfg(not(feature = "tracing"))]
+        let _ = reports;
+    }
+}
+
+/// Open while one example runs
+#[must_use = "the span closes when this is dropped"]
+pub struct ExampleSpan {
+    #[cfg(feature = "tracing")]
+    span: tracing::span::EnteredSpan,
+}
+
+impl ExampleSpan {
+    pub fn enter() -> Self {
+        ExampleSpan {
+            #[cfg(feature = "tracing")]
+            span: tracing::info_span!(
+                "example",
+                title = Empty,
+                duration_ns = Empty,
+                allocations = Empty,
+                bytes_allocated = Empty,
+                peak_bytes = Empty,
+                leaked_bytes = Empty
+            )
+            .entered(),
+        }
+    }
+
+    /// Record the report's costs, emit its numbers as events and close the span
+    pub fn close(self, report: &DemoReport) {
+        #[cfg(feature = "tracing")]
+        {
+            self.span.record("title", report.title.as_str());
+            if let Some(elapsed) = report.elapsed {
+                self.span.record("duration_ns", elapsed.as_nanos() as u64);
+            }
+            if let Some(stats) = report.alloc {
+                self.span.record("allocations", stats.allocations);
+                self.span.record("bytes_allocated", stats.bytes_allocated);
+                self.span.record("peak_bytes", stats.peak_bytes);
+            }
+            if let Some(leaked) = report.leaked_bytes {
+                self.span.record("leaked_bytes", leaked);
+            }
+            // Inside the span, now that it carries the title
+            for entry in report.entries() {
+                match entry {
+                    Entry::Count { label, value } => tracing::info!(label = label.as_str(), value = *value as u64, "count"),
+                    Entry::Measurement { label, value, unit } => {
+                        let unit = match unit {
+                            Unit::Nanoseconds => "ns",
+                            Unit::Bytes => "bytes",
+                        };
+                        tracing::info!(label = label.as_str(), value, unit, "measurement")
+                    }
+                    Entry::Note(NoteKind::Fail, text) => tracing::error!(note = text.as_str(), "fail"),
+                    _ => {}
+                }
+            }
+        }
+        #[cfg(not(feature = "tracing"))]
+        let _ = report;
+    }
+}
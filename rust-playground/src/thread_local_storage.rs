@@ -0,0 +1,359 @@
+// Thread-local storage - one copy of a variable per thread
+// Go:   no goroutine-local storage, on purpose. Goroutines are cheap, hop
+//       between OS threads and are never named, so per-goroutine state would
+//       be invisible coupling. Request state travels in a context.Context or
+//       a parameter; per-P caches live inside the runtime (sync.Pool).
+// Rust: thread_local! gives each OS thread its own lazily built copy. It's
+//       never shared, so Cell/RefCell are enough - no lock, no atomic - and a
+//       reference to it can't outlive the `with` call that lent it.
+
+use std::cell::{Cell, RefCell};
+use std::hint::black_box;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::registry::{Demo, Difficulty, Example, Tag};
+use crate::report::DemoReport;
+
+const THREADS: usize = 4;
+const INCREMENTS: u64 = if cfg!(debug_assertions) { 200_000 } else { 2_000_000 };
+
+static INITS: AtomicUsize = AtomicUsize::new(0);
+static DESTRUCTORS: AtomicUsize = AtomicUsize::new(0);
+
+// Counts its own construction and destruction
+struct Tracked(Cell<u64>);
+
+impl Tracked {
+    fn new() -> Self {
+        INITS.fetch_add(1, Ordering::Relaxed);
+        Tracked(Cell::new(0))
+    }
+}
+
+impl Drop for Tracked {
+    fn drop(&mut self) {
+        DESTRUCTORS.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+thread_local! {
+    static NEXT_ID: Cell<u64> = const { Cell::new(1) };
+    static LOG: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+    static TRACKED: Tracked = Tracked::new();
+}
+
+/// A per-thread id: no two calls on one thread return the same value
+pub fn next_id() -> u64 {
+    NEXT_ID.with(|next| {
+        let id = next.get();
+        next.set(id + 1);
+        id
+    })
+}
+
+fn log(line: impl Into<String>) {
+    LOG.with_borrow_mut(|log| log.push(line.into()));
+}
+
+// Cell and RefCell contents, lazy init and destructors at thread exit
+pub fn cell_and_refcell() -> DemoReport {
+    let mut report = DemoReport::new("thread_local! with Cell and RefCell");
+
+    report.code("thread_local! {");
+    report.code("    static NEXT_ID: Cell<u64> = const { Cell::new(1) };");
+    report.code("    static LOG: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };");
+    report.code("    static TRACKED: Tracked = Tracked::new();  // not const: built on first use");
+    report.code("}");
+
+    let (inits_before, destructors_before) = (INITS.load(Ordering::Relaxed), DESTRUCTORS.load(Ordering::Relaxed));
+    let rows = thread::scope(|scope| {
+        let handles: Vec<_> = (0..THREADS)
+            .map(|t| {
+                scope.spawn(move || {
+                    let ids: Vec<u64> = (0..=t).map(|_| next_id()).collect();
+                    log(format!("thread {} took ids {:?}", t, ids));
+                    // Only the odd threads ever touch TRACKED
+                    if t % 2 == 1 {
+                        TRACKED.with(|tracked| tracked.0.set(tracked.0.get() + 1));
+                    }
+                    let address = NEXT_ID.with(|next| next as *const Cell<u64> as usize);
+                    (t, ids, LOG.with_borrow(|log| log.len()), address)
+                })
+            })
+            .collect();
+        handles.into_iter().map(|handle| handle.join().unwrap()).collect::<Vec<_>>()
+    });
+    report.table(
+        &["Thread", "next_id() calls", "ids", "LOG lines", "&NEXT_ID"],
+        rows.iter().map(|(t, ids, lines, address)| vec![format!("thread {}", t), ids.len().to_string(), format!("{:?}", ids), lines.to_string(), format!("{:#x}", address)]).collect(),
+    );
+    report.count("Tracked::new() calls (threads that touched TRACKED)", INITS.load(Ordering::Relaxed) - inits_before);
+    report.count("Tracked dropped at thread exit", DESTRUCTORS.load(Ordering::Relaxed) - destructors_before);
+    report.text(format!("main thread: next_id() = {} - its own copy, untouched by the workers", next_id()));
+
+    report.gap();
+    report.ok("Every thread starts at id 1 and has its own LOG: same name, different address");
+    report.ok("Cell for Copy values, RefCell for the rest - a thread-local is never shared, so it needn't be Sync");
+    report.ok("Lazy: the initializer runs on first use per thread; the destructor when that thread exits");
+    report.warn("The main thread's thread-locals may never be dropped - don't rely on them to flush at exit");
+
+    report.step("A reference can't escape `with`");
+    report.code("let log: &RefCell<Vec<String>> = LOG.with(|log| log);  // ❌ lifetime may not live long enough");
+    report.text("Checked by tests/compile_fail/thread_local_ref_escapes.rs");
+    report.ok("The closure gets a borrow that ends with the call - the thread could exit and free it otherwise");
+    report
+}
+
+static TOTAL: AtomicU64 = AtomicU64::new(0);
+static FLUSHES: AtomicUsize = AtomicUsize::new(0);
+
+// A per-thread tally that adds itself to TOTAL when its thread exits
+struct Tally(Cell<u64>);
+
+impl Drop for Tally {
+    fn drop(&mut self) {
+        TOTAL.fetch_add(self.0.get(), Ordering::Relaxed);
+        FLUSHES.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+thread_local! {
+    static TALLY: Tally = const { Tally(Cell::new(0)) };
+}
+
+fn count_event() {
+    TALLY.with(|tally| tally.0.set(tally.0.get() + 1));
+}
+
+// Count locally, aggregate once per thread
+pub fn per_thread_counters() -> DemoReport {
+    let mut report = DemoReport::new("Per-Thread Counters, Aggregated at the End");
+
+    report.code("thread_local! { static TALLY: Tally = const { Tally(Cell::new(0)) }; }");
+    report.code("impl Drop for Tally { fn drop(&mut self) { TOTAL.fetch_add(self.0.get(), Relaxed); } }");
+
+    let (total_before, flushes_before) = (TOTAL.load(Ordering::Relaxed), FLUSHES.load(Ordering::Relaxed));
+    let events_per_thread = [1_000u64, 2_000, 3_000, 4_000];
+    thread::scope(|scope| {
+        let handles: Vec<_> = events_per_thread
+            .map(|events| {
+                scope.spawn(move || {
+                    for _ in 0..events {
+                        count_event();  // A plain add on this thread's Cell
+                    }
+                })
+            })
+            .into_iter()
+            .collect();
+        // join() waits for thread exit, thread-local destructors included
+        for handle in handles {
+            handle.join().unwrap();
+        }
+    });
+    let expected: u64 = events_per_thread.iter().sum();
+    report.count("events counted", (TOTAL.load(Ordering::Relaxed) - total_before) as usize);
+    report.count("expected", expected as usize);
+    report.count("atomic adds to TOTAL (one per thread, at exit)", FLUSHES.load(Ordering::Relaxed) - flushes_before);
+
+    report.gap();
+    report.ok(format!("{} increments, {} shared writes: the hot path never touches shared memory", expected, events_per_thread.len()));
+    report.ok("Threads joined → their Tally destructors have run → TOTAL is complete");
+    report.text("The same shape as per-CPU counters in a kernel or Go's per-P sync.Pool caches");
+
+    report.step("Go");
+    report.code("counts := make([]int64, workers)  // one slot per worker, passed in explicitly");
+    report.code("go func(i int) { for ... { counts[i]++ } }(i)  // false sharing unless padded");
+    report.text("Same idea, but the \"local\" is an index you thread through by hand");
+    report
+}
+
+/// Time `THREADS` threads doing `increments` each through a thread-local Cell
+fn time_thread_local(increments: u64) -> (Duration, u64) {
+    thread_local! {
+        static LOCAL: Cell<u64> = const { Cell::new(0) };
+    }
+    let total = AtomicU64::new(0);
+    let start = Instant::now();
+    thread::scope(|scope| {
+        for _ in 0..THREADS {
+            scope.spawn(|| {
+                for _ in 0..increments {
+                    LOCAL.with(|local| local.set(black_box(local.get()) + 1));
+                }
+                total.fetch_add(LOCAL.with(Cell::take), Ordering::Relaxed);  // Flush once
+            });
+        }
+    });
+    (start.elapsed(), total.into_inner())
+}
+
+fn time_mutex(increments: u64) -> (Duration, u64) {
+    let counter = Arc::new(Mutex::new(0u64));
+    let start = Instant::now();
+    let handles: Vec<_> = (0..THREADS)
+        .map(|_| {
+            let counter = Arc::clone(&counter);
+            thread::spawn(move || {
+                for _ in 0..increments {
+                    *black_box(&counter).lock().unwrap() += 1;
+                }
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+    let elapsed = start.elapsed();
+    let total = *counter.lock().unwrap();
+    (elapsed, total)
+}
+
+fn time_atomic(increments: u64) -> (Duration, u64) {
+    let counter = Arc::new(AtomicU64::new(0));
+    let start = Instant::now();
+    let handles: Vec<_> = (0..THREADS)
+        .map(|_| {
+            let counter = Arc::clone(&counter);
+            thread::spawn(move || {
+                for _ in 0..increments {
+                    black_box(&counter).fetch_add(1, Ordering::Relaxed);
+                }
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+    (start.elapsed(), counter.load(Ordering::Relaxed))
+}
+
+// thread_local vs Arc<Mutex> vs atomic, all threads incrementing at once
+pub fn benchmark() -> DemoReport {
+    let mut report = DemoReport::new("Benchmark - thread_local vs Arc<Mutex> vs Atomic");
+
+    report.count("threads", THREADS);
+    report.count("increments per thread", INCREMENTS as usize);
+    let rows = [
+        ("thread_local Cell + 1 flush", time_thread_local(INCREMENTS)),
+        ("AtomicU64::fetch_add", time_atomic(INCREMENTS)),
+        ("Arc<Mutex<u64>>", time_mutex(INCREMENTS)),
+    ];
+    let fastest = rows.iter().map(|(_, (elapsed, _))| *elapsed).min().unwrap_or_default().max(Duration::from_nanos(1));
+    let operations = (THREADS as u64 * INCREMENTS) as f64;
+    report.table(
+        &["Counter", "total", "ns / increment", "vs fastest"],
+        rows.iter()
+            .map(|(name, (elapsed, total))| {
+                vec![
+                    name.to_string(),
+                    total.to_string(),
+                    format!("{:.2}", elapsed.as_nanos() as f64 / operations),
+                    format!("{:.1}×", elapsed.as_secs_f64() / fastest.as_secs_f64()),
+                ]
+            })
+            .collect(),
+    );
+    if cfg!(debug_assertions) {
+        report.warn("Debug build - run with --release for representative numbers");
+    }
+    let cpus = thread::available_parallelism().map_or(1, |n| n.get());
+    if cpus == 1 {
+        report.warn("1 CPU: the threads take turns, so these are the uncontended atomic and Mutex costs");
+    }
+
+    report.gap();
+    report.ok("thread_local: a plain add per increment - nothing shared until the single flush");
+    report.ok("Atomic: one locked instruction, but every core fights over the same cache line");
+    report.ok("Mutex: lock + unlock per increment, and a sleeping waiter when contended");
+    report.text("All three end with the same total - only the hot path differs");
+    report
+}
+
+thread_local! {
+    static CURRENT_USER: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+// What Go avoids: implicit state that outlives the job it belonged to
+pub fn context_instead() -> DemoReport {
+    let mut report = DemoReport::new("Why Go Passes Context Instead");
+
+    report.step("Thread-local request state on a reused worker");
+    report.code("CURRENT_USER.set(Some(user))  // set by the job that has a user...");
+    report.code("CURRENT_USER.with_borrow(..)   // ...read by the next job, which didn't set it");
+    let (jobs, results) = (mpsc::channel::<Option<&'static str>>(), mpsc::channel::<String>());
+    let (job_sender, job_receiver) = jobs;
+    let (result_sender, result_receiver) = results;
+    let worker = thread::spawn(move || {
+        for user in job_receiver {
+            if let Some(user) = user {
+                CURRENT_USER.set(Some(user.to_string()));
+            }
+            let seen = CURRENT_USER.with_borrow(|current| current.clone());
+            result_sender.send(format!("job for {:?} sees CURRENT_USER = {:?}", user, seen)).unwrap();
+        }
+    });
+    for job in [Some("alice"), None] {
+        job_sender.send(job).unwrap();
+    }
+    drop(job_sender);
+    worker.join().unwrap();
+    for line in result_receiver {
+        report.text(line);
+    }
+    report.fail("The anonymous job ran as alice: one pool thread, two jobs, one leftover thread-local");
+    report.warn("Async is worse: a task can resume on another worker thread and see a different copy");
+
+    report.step("Rust: pass it explicitly");
+    report.code("struct RequestContext<'a> { user: Option<&'a str>, deadline: Instant }");
+    report.code("fn handle(ctx: &RequestContext, body: &[u8]) { ... }  // the signature says what it reads");
+    report.ok("Keep thread_local! for caches and counters that don't care which job is running");
+
+    report.step("Go");
+    report.code("func handle(ctx context.Context, body []byte) {");
+    report.code("    user, _ := ctx.Value(userKey).(string)  // request-scoped, dies with the request");
+    report.code("}");
+    report.text("Goroutines have no ids you can key on, by design - state you need is state you pass");
+    report.text("sync.Pool is the one blessed per-P cache, and it may drop its contents at any GC");
+    report
+}
+
+pub fn tls_vs_go() -> DemoReport {
+    let mut report = DemoReport::new("Per-Thread State: Go vs Rust");
+
+    report.table(
+        &["", "Go", "Rust"],
+        vec![
+            vec!["per-thread variable".into(), "none (no goroutine-local storage)".into(), "thread_local!".into()],
+            vec!["request-scoped values".into(), "context.Context".into(), "a parameter / struct you pass".into()],
+            vec!["reusable scratch buffers".into(), "sync.Pool (per-P, GC may empty it)".into(), "thread_local! RefCell<Vec<u8>>".into()],
+            vec!["per-worker counters".into(), "[]int64 indexed by worker".into(), "thread_local! Cell + flush on exit".into()],
+            vec!["interior mutability needed".into(), "-".into(), "Cell / RefCell (never shared → no Sync)".into()],
+            vec!["cleanup".into(), "GC".into(), "Drop when the thread exits".into()],
+        ],
+    );
+
+    report.gap();
+    report.ok("Both languages agree request state should be explicit; Rust adds a safe tool for the per-thread caches");
+    report
+}
+
+pub const DEMO: Demo = Demo {
+    name: "thread-local-storage",
+    about: "thread_local! with Cell/RefCell, per-thread counters, a benchmark vs Mutex and atomics, and Go's context",
+    difficulty: Difficulty::Intermediate,
+    tags: &[Tag::Concurrency, Tag::Benchmark],
+    examples: &[
+        Example { name: "cell-and-refcell", run: cell_and_refcell },
+        Example { name: "per-thread-counters", run: per_thread_counters },
+        Example { name: "benchmark", run: benchmark },
+        Example { name: "context", run: context_instead },
+        Example { name: "vs-go", run: tls_vs_go },
+    ],
+};
+
+pub fn demonstrate_thread_local_storage() -> Vec<DemoReport> {
+    DEMO.run()
+}
@@ -0,0 +1,177 @@
+// Timing - what each demo cost, summed up at the end of a run
+// Wraps every demo run with a wall clock and, with `--features alloc-stats`,
+// adds up the heap activity its reports recorded. The summary is an ordinary
+// DemoReport (a table sorted most expensive first), so every output format
+// renders it - and the ranking itself is a lesson: the benchmark demos
+// dominate the time, the ones that collect() or clone dominate the bytes.
+// Where the OS reports it (process_stats), RSS before/after and the peak are
+// recorded too: the numbers a Go developer would hold against runtime.MemStats.
+// Benchmark demos can add their result tables (DemoReport::summary_table);
+// those are repeated below the cost table, so one full run ends with every
+// headline number in one place.
+
+use std::cmp::Reverse;
+use std::time::{Duration, Instant};
+
+use crate::alloc_stats::{self, AllocStats};
+use crate::process_stats::{self, MemoryDelta};
+use crate::registry::Demo;
+use crate::report::{DemoReport, Entry, format_bytes};
+
+/// One demo's share of the run
+#[derive(Debug, Clone, Copy)]
+pub struct DemoTiming {
+    pub demo: &'static str,
+    pub elapsed: Duration,
+    /// Summed over the demo's examples (None without alloc-stats)
+    pub alloc: Option<AllocStats>,
+    /// Resident memory around the demo (None where the OS can't tell)
+    pub memory: Option<MemoryDelta>,
+}
+
+/// Result tables a demo asked to have in the summary
+#[derive(Debug, Clone)]
+pub struct DemoResults {
+    pub demo: &'static str,
+    /// Title of the report the tables came from
+    pub title: String,
+    pub tables: Vec<Entry>,
+}
+
+/// Collects a DemoTiming per demo run
+#[derive(Debug, Default)]
+pub struct Timings {
+    runs: Vec<DemoTiming>,
+    results: Vec<DemoResults>,
+}
+
+impl Timings {
+    pub fn new() -> Self {
+        Timings::default()
+    }
+
+    /// Run every example of `demo`, recording how long it took
+    pub fn run(&mut self, demo: &'static Demo) -> Vec<DemoReport> {
+        self.time(demo.name, || demo.run())
+    }
+
+    /// Time any run that produces reports, under `name`
+    pub fn time(&mut self, name: &'static str, run: impl FnOnce() -> Vec<DemoReport>) -> Vec<DemoReport> {
+        let start = Instant::now();
+        let (reports, memory) = process_stats::measure(run);
+        let elapsed = start.elapsed();
+        // Each example restarts the peak for its own number: the demo's is the highest of theirs
+        let memory = memory.map(|mut memory| {
+            memory.after.peak_rss = reports.iter().filter_map(|report| report.memory).map(|example| example.after.peak_rss).fold(memory.after.peak_rss, usize::max);
+            memory
+        });
+        let alloc = reports.iter().filter_map(|report| report.alloc).reduce(|total, stats| AllocStats {
+            allocations: total.allocations + stats.allocations,
+            deallocations: total.deallocations + stats.deallocations,
+            bytes_allocated: total.bytes_allocated + stats.bytes_allocated,
+            peak_bytes: total.peak_bytes.max(stats.peak_bytes),
+        });
+        self.runs.push(DemoTiming { demo: name, elapsed, alloc, memory });
+        for report in reports.iter().filter(|report| !report.summary.is_empty()) {
+            self.results.push(DemoResults { demo: name, title: report.title.clone(), tables: report.summary.clone() });
+        }
+        reports
+    }
+
+    /// In the order they ran
+    pub fn runs(&self) -> &[DemoTiming] {
+        &self.runs
+    }
+
+    /// Summary tables, in the order the demos ran
+    pub fn results(&self) -> &[DemoResults] {
+        &self.results
+    }
+
+    pub fn total(&self) -> Duration {
+        self.runs.iter().map(|run| run.elapsed).sum()
+    }
+
+    /// Slowest first
+    pub fn sorted(&self) -> Vec<DemoTiming> {
+        let mut runs = self.runs.clone();
+        runs.sort_by_key(|run| Reverse(run.elapsed));
+        runs
+    }
+
+    /// The end-of-run table
+    pub fn summary(&self) -> DemoReport {
+        let mut report = DemoReport::new("Run Summary - Cost per Demo");
+
+        let total = self.total().as_secs_f64().max(f64::EPSILON);
+        let with_alloc = alloc_stats::enabled();
+        let with_memory = self.runs.iter().any(|run| run.memory.is_some());
+        let rows = self
+            .sorted()
+            .iter()
+            .map(|run| {
+                let mut row = vec![
+                    run.demo.to_string(),
+                    format!("{:.1?}", run.elapsed),
+                    format!("{:.1}%", run.elapsed.as_secs_f64() / total * 100.0),
+                ];
+                if with_alloc {
+                    let stats = run.alloc.unwrap_or_default();
+                    row.push(stats.allocations.to_string());
+                    row.push(format_bytes(stats.bytes_allocated));
+                    row.push(format_bytes(stats.peak_bytes));
+                }
+                if with_memory {
+                    row.push(run.memory.map_or("-".to_string(), |memory| process_stats::format_change(memory.rss_change())));
+                    row.push(run.memory.map_or("-".to_string(), |memory| format_bytes(memory.after.peak_rss)));
+                }
+                row
+            })
+            .collect();
+        let mut header = vec!["Demo", "Time", "Share"];
+        if with_alloc {
+            header.extend(["Allocs", "Allocated", "Peak"]);
+        }
+        if with_memory {
+            header.extend(["RSS change", "RSS peak"]);
+        }
+        report.table(&header, rows);
+        report.count("demos", self.runs.len());
+        report.duration("total", self.total());
+        report.text(format!("Allocator: {}", alloc_stats::backend()));
+
+        if let Some(heaviest) = self.runs.iter().filter(|run| run.alloc.is_some()).max_by_key(|run| run.alloc.unwrap_or_default().bytes_allocated) {
+            report.text(format!(
+                "Most bytes allocated: {} ({})",
+                heaviest.demo,
+                format_bytes(heaviest.alloc.unwrap_or_default().bytes_allocated)
+            ));
+        }
+        if let Some(largest) = self.runs.iter().filter(|run| run.memory.is_some()).max_by_key(|run| run.memory.map(|memory| memory.rss_change())) {
+            let memory = largest.memory.expect("filtered on memory");
+            report.text(format!("Largest RSS growth: {} ({}, {} → {})", largest.demo, process_stats::format_change(memory.rss_change()), format_bytes(memory.before.rss), format_bytes(memory.after.rss)));
+        }
+        if with_memory {
+            let own_peak = self.runs.iter().filter_map(|run| run.memory).all(|memory| memory.own_peak);
+            report.text(if own_peak {
+                "RSS peak: each demo's own high-water mark, counted from the RSS it started with"
+            } else {
+                "RSS peak: the process's high-water mark so far - this OS can't reset it per demo"
+            });
+            report.text("RSS counts pages, not objects: memory the allocator keeps after free() still shows up (Go: HeapSys vs HeapAlloc)");
+        }
+        if !with_alloc {
+            report.text("(allocation columns need --features alloc-stats)");
+        }
+        if cfg!(debug_assertions) {
+            report.warn("Debug build - the benchmark demos run several times slower than with --release");
+        }
+        for results in &self.results {
+            report.step(format!("{}: {}", results.demo, results.title));
+            for table in &results.tables {
+                report.push(table.clone());
+            }
+        }
+        report
+    }
+}
@@ -0,0 +1,266 @@
+// Traits vs interfaces - Box<dyn Trait>, generics and Go interface values
+// Go:   an interface value is two words (itab, data). Calls go through the
+//       itab; a non-pointer value stored in one is copied to the heap.
+// Rust: you pick per call site. &dyn Trait / Box<dyn Trait> is the same
+//       two-word shape (data, vtable); generics compile one copy of the
+//       function per type and call directly - no pointer, no table.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use crate::registry::{Demo, Difficulty, Example, Tag};
+use crate::report::DemoReport;
+
+const SHAPES: usize = 10_000;
+const ROUNDS: usize = 100;
+
+pub trait Shape {
+    fn area(&self) -> f64;
+    fn name(&self) -> &'static str;
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Circle {
+    pub radius: f64,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Rect {
+    pub width: f64,
+    pub height: f64,
+}
+
+impl Shape for Circle {
+    fn area(&self) -> f64 {
+        std::f64::consts::PI * self.radius * self.radius
+    }
+
+    fn name(&self) -> &'static str {
+        "Circle"
+    }
+}
+
+impl Shape for Rect {
+    fn area(&self) -> f64 {
+        self.width * self.height
+    }
+
+    fn name(&self) -> &'static str {
+        "Rect"
+    }
+}
+
+// The closed-set alternative: no vtable, a match on the tag
+#[derive(Debug, Clone, Copy)]
+pub enum AnyShape {
+    Circle(Circle),
+    Rect(Rect),
+}
+
+impl Shape for AnyShape {
+    fn area(&self) -> f64 {
+        match self {
+            AnyShape::Circle(circle) => circle.area(),
+            AnyShape::Rect(rect) => rect.area(),
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            AnyShape::Circle(circle) => circle.name(),
+            AnyShape::Rect(rect) => rect.name(),
+        }
+    }
+}
+
+// (data pointer, vtable pointer) of a trait object.
+// std::ptr::metadata would name the vtable directly, but it's unstable (ptr_metadata).
+fn split(shape: &dyn Shape) -> (usize, usize) {
+    let data = (shape as *const dyn Shape).cast::<()>() as usize;
+    // SAFETY: transmute checks the sizes at compile time (&dyn is two words), and any
+    // bits are a valid usize. Data first, vtable second is the current fat-pointer
+    // layout, not a guarantee - so the first word is checked against the data pointer.
+    let [first, vtable]: [usize; 2] = unsafe { std::mem::transmute(shape) };
+    assert_eq!(first, data, "fat-pointer layout changed: the data pointer isn't the first word");
+    (data, vtable)
+}
+
+// &dyn Trait is a fat pointer: data + vtable
+pub fn fat_pointers() -> DemoReport {
+    let mut report = DemoReport::new("Trait Objects Are Fat Pointers - data + vtable");
+
+    report.bytes("size_of::<&Circle>()", size_of::<&Circle>());
+    report.bytes("size_of::<&dyn Shape>()", size_of::<&dyn Shape>());
+    report.bytes("size_of::<Box<dyn Shape>>()", size_of::<Box<dyn Shape>>());
+    report.bytes("size_of::<Option<Box<dyn Shape>>>()", size_of::<Option<Box<dyn Shape>>>());
+
+    report.step("Two circles and a rect behind &dyn Shape");
+    let (a, b) = (Circle { radius: 1.0 }, Circle { radius: 2.0 });
+    let rect = Rect { width: 2.0, height: 3.0 };
+    let shapes: [&dyn Shape; 3] = [&a, &b, &rect];
+    let mut rows = vec![];
+    for shape in shapes {
+        let (data, vtable) = split(shape);
+        rows.push(vec![
+            shape.name().to_string(),
+            format!("{:#x}", data),
+            format!("{:#x}", vtable),
+            size_of_val(shape).to_string(),
+        ]);
+    }
+    report.table(&["Value", "data ptr", "vtable ptr", "size_of_val"], rows);
+
+    report.gap();
+    report.ok("Both circles share one vtable - one per (type, trait) pair, in the binary");
+    report.text("The vtable holds drop_in_place, size, align, then area() and name()");
+    report.text("size_of_val(&dyn) reads the size from the vtable");
+    report.ok("The data stays where it was (here: the stack) - &dyn doesn't box");
+
+    report.step("Go");
+    report.code("var s Shape = Circle{1}  // (itab, data): 16 bytes, Circle usually copied to the heap");
+    report.text("itab = interface type + concrete type + method table, cached per pair");
+    report
+}
+
+fn total_area_dyn(shapes: &[Box<dyn Shape>]) -> f64 {
+    shapes.iter().map(|shape| shape.area()).sum()
+}
+
+fn total_area_generic<S: Shape>(shapes: &[S]) -> f64 {
+    shapes.iter().map(|shape| shape.area()).sum()
+}
+
+fn time_rounds(mut f: impl FnMut() -> f64) -> Duration {
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        black_box(f());
+    }
+    start.elapsed()
+}
+
+// Same loop three ways: vtable call, monomorphized call, enum match
+pub fn dispatch_benchmark() -> DemoReport {
+    let mut report = DemoReport::new("Dynamic vs Static Dispatch - Micro-Benchmark");
+
+    let mixed = |i: usize| {
+        if i.is_multiple_of(2) {
+            AnyShape::Circle(Circle { radius: i as f64 })
+        } else {
+            AnyShape::Rect(Rect { width: i as f64, height: 2.0 })
+        }
+    };
+    let boxed: Vec<Box<dyn Shape>> = (0..SHAPES)
+        .map(|i| -> Box<dyn Shape> {
+            match mixed(i) {
+                AnyShape::Circle(circle) => Box::new(circle),
+                AnyShape::Rect(rect) => Box::new(rect),
+            }
+        })
+        .collect();
+    let enums: Vec<AnyShape> = (0..SHAPES).map(mixed).collect();
+    let circles: Vec<Circle> = (0..SHAPES).map(|i| Circle { radius: i as f64 }).collect();
+
+    let calls = SHAPES * ROUNDS;
+    let dyn_time = time_rounds(|| total_area_dyn(black_box(&boxed)));
+    let enum_time = time_rounds(|| total_area_generic(black_box(&enums)));
+    let generic_time = time_rounds(|| total_area_generic(black_box(&circles)));
+    let per_call = |time: Duration| format!("{:.2}", time.as_nanos() as f64 / calls as f64);
+
+    report.count("area() calls per variant", calls);
+    report.table(
+        &["Storage", "Dispatch", "Time", "ns/call", "Heap allocs"],
+        vec![
+            vec!["Vec<Box<dyn Shape>>".into(), "vtable".into(), format!("{:?}", dyn_time), per_call(dyn_time), (SHAPES + 1).to_string()],
+            vec!["Vec<AnyShape>".into(), "enum match".into(), format!("{:?}", enum_time), per_call(enum_time), "1".into()],
+            vec!["Vec<Circle> + generic".into(), "direct, inlined".into(), format!("{:?}", generic_time), per_call(generic_time), "1".into()],
+        ],
+    );
+
+    report.gap();
+    report.text("A vtable call is cheap by itself; what it costs is the inlining and");
+    report.text("vectorizing the compiler can't do through it, plus a pointer chase per Box");
+    report.ok("Generics: each call site knows the type - the loop can be unrolled and vectorized");
+    if cfg!(debug_assertions) {
+        report.warn("Debug build - run with --release to see the real gap");
+    }
+
+    report.step("Go");
+    report.code("for _, s := range shapes { total += s.Area() }  // []Shape: always itab calls");
+    report.text("Go generics use GC-shape stenciling: pointer types share one copy + a dictionary");
+    report
+}
+
+fn describe<S: Shape>(shape: &S) -> String {
+    format!("{} with area {:.1}", shape.name(), shape.area())
+}
+
+fn describe_dyn(shape: &dyn Shape) -> String {
+    format!("{} with area {:.1}", shape.name(), shape.area())
+}
+
+// One generic function → one machine-code copy per type
+pub fn monomorphization() -> DemoReport {
+    let mut report = DemoReport::new("Monomorphization - One Copy per Type");
+
+    let circle = Circle { radius: 1.0 };
+    let rect = Rect { width: 2.0, height: 3.0 };
+    report.text(describe(&circle));
+    report.text(describe(&rect));
+
+    report.step("Function addresses");
+    report.table(
+        &["Function", "Address"],
+        vec![
+            vec!["describe::<Circle>".into(), format!("{:p}", describe::<Circle> as fn(&Circle) -> String)],
+            vec!["describe::<Rect>".into(), format!("{:p}", describe::<Rect> as fn(&Rect) -> String)],
+            vec!["describe_dyn (any Shape)".into(), format!("{:p}", describe_dyn as fn(&dyn Shape) -> String)],
+        ],
+    );
+
+    report.gap();
+    report.ok("describe::<Circle> and describe::<Rect> are separate functions in the binary");
+    report.ok("describe_dyn exists once and works for every Shape via the vtable");
+    report.warn("Generics trade binary size and compile time for speed");
+    report.text("Rule of thumb: generics for hot paths, dyn for plugin-style or heterogeneous lists");
+    report
+}
+
+pub fn traits_vs_interfaces_vs_go() -> DemoReport {
+    let mut report = DemoReport::new("Traits vs Go Interfaces");
+
+    report.table(
+        &["", "Go interface", "Rust trait"],
+        vec![
+            vec!["satisfied by".into(), "matching methods (implicit)".into(), "impl Trait for T (explicit)".into()],
+            vec!["value shape".into(), "(itab, data) 16 bytes".into(), "&dyn / Box<dyn>: (data, vtable) 16 bytes".into()],
+            vec!["value stored".into(), "copied to heap if not a pointer".into(), "&dyn borrows, Box<dyn> boxes - you choose".into()],
+            vec!["static dispatch".into(), "generics (shape stenciling)".into(), "generics (full monomorphization)".into()],
+            vec!["nil".into(), "nil interface ≠ interface holding nil *T".into(), "Option<Box<dyn T>> - no typed nil".into()],
+            vec!["closed set".into(), "type switch".into(), "enum + match (exhaustive)".into()],
+        ],
+    );
+
+    report.step("Go's typed-nil gotcha");
+    report.code("var p *MyErr = nil");
+    report.code("var err error = p");
+    report.code("err != nil  // true! the itab is set, only data is nil");
+    report.ok("Rust: a Box<dyn Error> always points at a value; absence is Option/Result");
+    report
+}
+
+pub const DEMO: Demo = Demo {
+    name: "traits-vs-interfaces",
+    about: "Trait objects, generics and their layout vs Go interface values",
+    difficulty: Difficulty::Intermediate,
+    tags: &[Tag::Layout, Tag::Benchmark],
+    examples: &[
+        Example { name: "fat-pointers", run: fat_pointers },
+        Example { name: "dispatch", run: dispatch_benchmark },
+        Example { name: "monomorphization", run: monomorphization },
+        Example { name: "vs-go", run: traits_vs_interfaces_vs_go },
+    ],
+};
+
+pub fn demonstrate_traits_vs_interfaces() -> Vec<DemoReport> {
+    DEMO.run()
+}
@@ -0,0 +1,155 @@
+// Interactive step-through mode (`cargo run -- tui`, feature `tui`)
+// Live workshops need pacing, not a wall of text: pick a demo, run it,
+// scroll its output, then move on with n/p. Demos still just return
+// DemoReports - this module only decides how they're shown.
+
+use std::io;
+
+use ratatui::DefaultTerminal;
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use ratatui::layout::{Constraint, Layout};
+use ratatui::style::{Modifier, Style, Stylize};
+use ratatui::text::Line;
+use ratatui::widgets::{Block, List, ListItem, ListState, Paragraph};
+use ratatui::Frame;
+
+use crate::report::DemoReport;
+
+const HELP: &str = " ↑/↓ select · Enter run · n/p next/prev demo · j/k PgUp/PgDn scroll · q quit ";
+const PAGE: u16 = 10;
+
+/// One line in the demo menu
+pub struct MenuItem {
+    pub name: String,
+    pub about: String,
+}
+
+struct App<F> {
+    menu: Vec<MenuItem>,
+    selected: ListState,
+    run_demo: F,
+    // Demo whose output is on screen, and that output
+    shown: Option<usize>,
+    output: Vec<String>,
+    scroll: u16,
+}
+
+impl<F: FnMut(&str) -> Vec<DemoReport>> App<F> {
+    fn select(&mut self, index: usize) {
+        self.selected.select(Some(index.min(self.menu.len().saturating_sub(1))));
+    }
+
+    fn selected(&self) -> usize {
+        self.selected.selected().unwrap_or(0)
+    }
+
+    fn run_selected(&mut self) {
+        let index = self.selected();
+        let reports = (self.run_demo)(&self.menu[index].name);
+        let text: String = reports.iter().map(|report| report.to_string()).collect();
+        self.output = text.lines().map(str::to_string).collect();
+        self.shown = Some(index);
+        self.scroll = 0;
+    }
+
+    fn step_demo(&mut self, forward: bool) {
+        let current = self.shown.unwrap_or_else(|| self.selected());
+        let next = if forward {
+            (current + 1).min(self.menu.len() - 1)
+        } else {
+            current.saturating_sub(1)
+        };
+        self.select(next);
+        self.run_selected();
+    }
+
+    fn scroll_by(&mut self, delta: i32) {
+        let max = self.output.len().saturating_sub(1) as i32;
+        self.scroll = (self.scroll as i32 + delta).clamp(0, max) as u16;
+    }
+
+    // Returns false when the user quits
+    fn handle_key(&mut self, code: KeyCode) -> bool {
+        match code {
+            KeyCode::Char('q') | KeyCode::Esc => return false,
+            KeyCode::Up => self.select(self.selected().saturating_sub(1)),
+            KeyCode::Down => self.select(self.selected() + 1),
+            KeyCode::Enter => self.run_selected(),
+            KeyCode::Char('n') | KeyCode::Right => self.step_demo(true),
+            KeyCode::Char('p') | KeyCode::Left => self.step_demo(false),
+            KeyCode::Char('j') => self.scroll_by(1),
+            KeyCode::Char('k') => self.scroll_by(-1),
+            KeyCode::PageDown | KeyCode::Char(' ') => self.scroll_by(PAGE as i32),
+            KeyCode::PageUp => self.scroll_by(-(PAGE as i32)),
+            KeyCode::Home | KeyCode::Char('g') => self.scroll = 0,
+            _ => {}
+        }
+        true
+    }
+
+    fn draw(&mut self, frame: &mut Frame) {
+        let [body, footer] = Layout::vertical([Constraint::Min(1), Constraint::Length(1)]).areas(frame.area());
+        let [menu_area, output_area] =
+            Layout::horizontal([Constraint::Length(28), Constraint::Min(1)]).areas(body);
+
+        let items: Vec<ListItem> = self
+            .menu
+            .iter()
+            .enumerate()
+            .map(|(i, item)| {
+                let marker = if Some(i) == self.shown { "▶ " } else { "  " };
+                ListItem::new(format!("{}{}", marker, item.name))
+            })
+            .collect();
+        let menu = List::new(items)
+            .block(Block::bordered().title(" Demos "))
+            .highlight_style(Style::new().add_modifier(Modifier::REVERSED));
+        frame.render_stateful_widget(menu, menu_area, &mut self.selected);
+
+        let (title, lines) = match self.shown {
+            Some(index) => (
+                format!(" {} ({}/{}) ", self.menu[index].name, self.scroll + 1, self.output.len().max(1)),
+                self.output.iter().map(|line| Line::from(line.as_str())).collect(),
+            ),
+            None => {
+                let about = &self.menu[self.selected()].about;
+                (" Output ".to_string(), vec![Line::from(about.as_str()), Line::from(""), Line::from("Press Enter to run it").italic()])
+            }
+        };
+        let output = Paragraph::new(lines)
+            .block(Block::bordered().title(title))
+            .scroll((self.scroll, 0));
+        frame.render_widget(output, output_area);
+
+        frame.render_widget(Line::from(HELP).dim(), footer);
+    }
+
+    fn run(&mut self, terminal: &mut DefaultTerminal) -> io::Result<()> {
+        loop {
+            terminal.draw(|frame| self.draw(frame))?;
+            if let Event::Key(key) = event::read()?
+                && key.kind == KeyEventKind::Press
+                && !self.handle_key(key.code)
+            {
+                return Ok(());
+            }
+        }
+    }
+}
+
+/// Show `menu` and run demos on demand through `run_demo(name)`
+pub fn run(menu: Vec<MenuItem>, run_demo: impl FnMut(&str) -> Vec<DemoReport>) -> io::Result<()> {
+    if menu.is_empty() {
+        return Ok(());
+    }
+
+    let mut app = App {
+        menu,
+        selected: ListState::default().with_selected(Some(0)),
+        run_demo,
+        shown: None,
+        output: vec![],
+        scroll: 0,
+    };
+    ratatui::run(|terminal| app.run(terminal))
+}
@@ -0,0 +1,471 @@
+// Two owners, one rule - a gallery of borrow-checker errors and their fixes
+// Go:   every program below compiles. The GC keeps whatever a pointer still
+//       reaches alive, so dangling references can't happen - but aliasing
+//       bugs (a pointer into a slice that append moved, removing while
+//       ranging, a counter shared by goroutines) compile too, and show up at
+//       runtime if at all.
+// Rust: a value has one owner, and at any moment either one &mut or any
+//       number of & to it. Each case here breaks that rule in a way real code
+//       does; the broken program lives in tests/compile_fail/gallery/ (checked
+//       by trybuild: `cargo test --test compile_fail`, the .stderr next to it
+//       is the exact diagnostic), and its fixed version in `fixed` below,
+//       which this demo runs.
+
+use crate::registry::{Demo, Difficulty, Example, Tag};
+use crate::report::{DemoReport, SideBySideRow};
+
+/// One broken program from tests/compile_fail/gallery and its fixed twin
+pub struct Case {
+    /// File stem in tests/compile_fail/gallery, and the fn in `fixed`
+    pub name: &'static str,
+    /// The example that shows it
+    pub group: &'static str,
+    /// The error code the compiler reports ("not Send" for the one without)
+    pub error: &'static str,
+    /// The same program in Go
+    pub go: &'static str,
+    /// Go compiles it, but it's a bug there too
+    pub go_bug: bool,
+    /// The program the compiler rejects
+    pub broken: &'static str,
+    /// The compiling version - runs it and says what happened
+    pub fixed: fn() -> String,
+}
+
+impl Case {
+    /// What goes wrong, from the file's header line
+    pub fn scenario(&self) -> &'static str {
+        self.broken.lines().next().and_then(|line| line.split_once(" - ")).map_or("", |(_, scenario)| scenario)
+    }
+
+    /// How `fixed` fixes it, from the file's "// Fix:" line
+    pub fn fix(&self) -> &'static str {
+        self.broken.lines().find_map(|line| line.strip_prefix("// Fix: ")).unwrap_or("")
+    }
+
+    /// The line the compiler rejects: (code, annotation after ❌)
+    pub fn rejected_line(&self) -> Option<(&'static str, &'static str)> {
+        let line = self.broken.lines().find(|line| line.contains("// ❌"))?;
+        let (code, annotation) = line.split_once("// ❌")?;
+        Some((code.trim(), annotation.trim()))
+    }
+}
+
+macro_rules! case {
+    ($name:ident, $group:expr, $error:expr, $go_bug:expr, $go:expr) => {
+        Case {
+            name: stringify!($name),
+            group: $group,
+            error: $error,
+            go: $go,
+            go_bug: $go_bug,
+            broken: include_str!(concat!("../tests/compile_fail/gallery/", stringify!($name), ".rs")),
+            fixed: fixed::$name,
+        }
+    };
+}
+
+pub const CASES: &[Case] = &[
+    case!(two_owners, "ownership", "E0382", false, "both structs hold the same *Config; the GC frees it after the last one"),
+    case!(moved_in_loop, "ownership", "E0382", false, "a slice is passed as a header; every call sees the same array"),
+    case!(used_after_push, "ownership", "E0382", false, "append copies the string header; both share the bytes"),
+    case!(move_out_of_index, "ownership", "E0507", false, "names[0] copies the string header"),
+    case!(move_out_of_borrowed_option, "ownership", "E0507", false, "a *string field is copied; a nil one panics when dereferenced"),
+    case!(returns_ref_to_local, "dangling", "E0515", false, "escape analysis moves `upper` to the heap; returning &upper is fine"),
+    case!(ref_outlives_scope, "dangling", "E0597", false, "the pointer keeps the string alive past its block"),
+    case!(temporary_dropped_while_borrowed, "dangling", "E0716", false, "strings.Split returns substrings the GC keeps alive"),
+    case!(self_referential_struct, "dangling", "E0515", false, "a struct may point into its own field; the GC doesn't mind"),
+    case!(callback_borrows_local, "dangling", "E0597", false, "the closure captures label; escape analysis heap-allocates it"),
+    case!(push_while_borrowed, "aliasing", "E0502", true, "p := &s[0] survives append, but points at the OLD array once it grows"),
+    case!(mutate_while_iterating, "aliasing", "E0502", false, "range reads len once: appended jobs aren't visited"),
+    case!(remove_in_for_loop, "aliasing", "E0502", true, "deleting inside range shifts the slice: the next element is skipped"),
+    case!(get_or_insert_cache, "aliasing", "E0502", false, "map reads return copies; `v, ok := m[k]` then insert"),
+    case!(method_borrows_all_of_self, "aliasing", "E0499", false, "a *Item into the slice stays usable (until an append moves it)"),
+    case!(closure_holds_mut_borrow, "aliasing", "E0502", false, "closures capture by reference; reads and writes interleave"),
+    case!(thread_borrows_local, "threads-async", "E0373", false, "go func() captures lines; it's moved to the heap"),
+    case!(shared_counter_in_scope, "threads-async", "E0499", true, "total++ from 4 goroutines compiles - a data race -race may catch"),
+    case!(async_block_borrows_local, "threads-async", "E0373", false, "a goroutine's closure captures rows; it's moved to the heap"),
+    case!(guard_held_across_await, "threads-async", "not Send", false, "holding a sync.Mutex across a blocking call is allowed (and slow)"),
+];
+
+/// The compiling version of each case, named after its file
+#[allow(clippy::useless_vec)]  // Each fix keeps its broken twin's types
+pub mod fixed {
+    use std::collections::HashMap;
+    use std::future::{self, Future};
+    use std::ops::Range;
+    use std::pin::pin;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::{Arc, Mutex};
+    use std::task::{Context, Poll, Waker};
+    use std::thread;
+
+    // Poll a future to completion on this thread - these never wait on I/O
+    fn block_on<F: Future>(future: F) -> F::Output {
+        let mut future = pin!(future);
+        let mut cx = Context::from_waker(Waker::noop());
+        loop {
+            if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+                return output;
+            }
+        }
+    }
+
+    struct Config {
+        port: u16,
+    }
+
+    struct Server {
+        config: Arc<Config>,
+    }
+
+    struct Worker {
+        config: Arc<Config>,
+    }
+
+    pub fn two_owners() -> String {
+        let config = Arc::new(Config { port: 8080 });
+        let server = Server { config: Arc::clone(&config) };
+        let worker = Worker { config: Arc::clone(&config) };
+        format!("server on {}, worker on {} - {} handles to one Config", server.config.port, worker.config.port, Arc::strong_count(&config))
+    }
+
+    fn send(batch: &[String]) -> usize {
+        batch.len()
+    }
+
+    pub fn moved_in_loop() -> String {
+        let batch = vec!["a".to_string(), "b".to_string()];
+        let sent: usize = (0..3).map(|_| send(&batch)).sum();
+        format!("sent {} items in 3 attempts, and the batch is still here ({} items)", sent, batch.len())
+    }
+
+    pub fn used_after_push() -> String {
+        let mut names = Vec::new();
+        names.push(String::from("gopher"));
+        format!("added {}", names[names.len() - 1])
+    }
+
+    pub fn move_out_of_index() -> String {
+        let names = vec![String::from("ferris"), String::from("gopher")];
+        let first = &names[0];
+        format!("{} of {}", first, names.len())
+    }
+
+    struct Profile {
+        nickname: Option<String>,
+    }
+
+    impl Profile {
+        fn display_name(&self) -> &str {
+            self.nickname.as_deref().unwrap_or("anonymous")
+        }
+    }
+
+    pub fn move_out_of_borrowed_option() -> String {
+        let profiles = [Profile { nickname: Some("ferris".into()) }, Profile { nickname: None }];
+        profiles.iter().map(Profile::display_name).collect::<Vec<_>>().join(", ")
+    }
+
+    fn shout(text: &str) -> String {
+        text.to_uppercase()
+    }
+
+    pub fn returns_ref_to_local() -> String {
+        shout("hello")
+    }
+
+    pub fn ref_outlives_scope() -> String {
+        let line = String::from("GET /index.html");
+        let latest;
+        {
+            latest = &line;
+        }
+        latest.to_string()
+    }
+
+    fn read_config() -> String {
+        String::from("port=8080 host=localhost")
+    }
+
+    pub fn temporary_dropped_while_borrowed() -> String {
+        let config = read_config();
+        let mut settings = config.split(' ');
+        format!("{:?}", settings.next())
+    }
+
+    struct Document {
+        text: String,
+        title: Range<usize>,
+    }
+
+    impl Document {
+        fn open(text: String) -> Document {
+            let title = 0..text.find('\n').unwrap_or(text.len());
+            Document { text, title }
+        }
+
+        fn title(&self) -> &str {
+            &self.text[self.title.clone()]
+        }
+    }
+
+    pub fn self_referential_struct() -> String {
+        let doc = Document::open("Ownership\nbody".into());
+        format!("{} ({} bytes)", doc.title(), doc.text.len())
+    }
+
+    struct Button {
+        on_click: Box<dyn Fn() -> String>,
+    }
+
+    fn make_button() -> Button {
+        let label = String::from("Save");
+        Button { on_click: Box::new(move || format!("clicked {}", label)) }
+    }
+
+    pub fn callback_borrows_local() -> String {
+        (make_button().on_click)()
+    }
+
+    pub fn push_while_borrowed() -> String {
+        let mut scores = vec![10, 20, 30];
+        let best = scores[0];  // A copy, not a borrow
+        scores.push(40);
+        format!("best was {}, now {} scores", best, scores.len())
+    }
+
+    pub fn mutate_while_iterating() -> String {
+        let mut queue = vec![1, 2, 3];
+        let follow_ups: Vec<i32> = queue.iter().filter(|job| *job % 2 == 1).map(|job| job * 10).collect();
+        queue.extend(follow_ups);
+        format!("{:?}", queue)
+    }
+
+    pub fn remove_in_for_loop() -> String {
+        let mut names = vec![String::from("ferris"), String::new(), String::from("gopher")];
+        names.retain(|name| !name.is_empty());
+        format!("{:?}", names)
+    }
+
+    fn load(key: u32) -> String {
+        format!("value {}", key)
+    }
+
+    fn cached(cache: &mut HashMap<u32, String>, key: u32) -> &String {
+        cache.entry(key).or_insert_with(|| load(key))
+    }
+
+    pub fn get_or_insert_cache() -> String {
+        let mut cache = HashMap::new();
+        let first = cached(&mut cache, 7).clone();
+        cached(&mut cache, 7);
+        format!("{} - {} entry after two lookups", first, cache.len())
+    }
+
+    struct Inventory {
+        items: Vec<(String, u32)>,
+        log: Vec<String>,
+    }
+
+    impl Inventory {
+        fn largest(&mut self) -> &mut (String, u32) {
+            self.items.iter_mut().max_by_key(|item| item.1).unwrap()
+        }
+
+        fn note(&mut self, message: &str) {
+            self.log.push(message.to_string());
+        }
+    }
+
+    pub fn method_borrows_all_of_self() -> String {
+        let mut inventory = Inventory { items: vec![("bolts".into(), 40), ("nuts".into(), 90)], log: Vec::new() };
+        let largest = inventory.largest();
+        largest.1 += 10;  // Last use of `largest`: the &mut ends here
+        inventory.note("restocking");
+        format!("{:?}, log: {:?}", inventory.items, inventory.log)
+    }
+
+    pub fn closure_holds_mut_borrow() -> String {
+        let mut clicks = 0;
+        let mut click = || clicks += 1;
+        click();
+        click();
+        format!("clicks: {}", clicks)
+    }
+
+    pub fn thread_borrows_local() -> String {
+        let lines = vec!["a", "b", "c"];
+        let handle = thread::spawn(move || lines.len());
+        format!("the thread counted {} lines", handle.join().unwrap())
+    }
+
+    pub fn shared_counter_in_scope() -> String {
+        let total = AtomicUsize::new(0);
+        thread::scope(|scope| {
+            for _ in 0..4 {
+                scope.spawn(|| total.fetch_add(1, Ordering::Relaxed));
+            }
+        });
+        format!("total = {}", total.into_inner())
+    }
+
+    fn job() -> impl Future<Output = usize> + 'static {
+        let rows = vec![1, 2, 3];
+        async move { rows.len() }
+    }
+
+    pub fn async_block_borrows_local() -> String {
+        format!("the future owned its rows: {}", block_on(job()))
+    }
+
+    fn spawn<F: Future + Send + 'static>(future: F) -> F {
+        future
+    }
+
+    pub fn guard_held_across_await() -> String {
+        let hits = Arc::new(Mutex::new(0));
+        let task = spawn({
+            let hits = Arc::clone(&hits);
+            async move {
+                {
+                    let mut guard = hits.lock().unwrap();
+                    *guard += 1;
+                }  // Guard dropped here, before the await
+                future::ready(()).await;
+            }
+        });
+        block_on(task);
+        format!("hits = {}", hits.lock().unwrap())
+    }
+}
+
+/// The cases an example shows
+pub fn group(name: &str) -> impl Iterator<Item = &'static Case> + '_ {
+    CASES.iter().filter(move |case| case.group == name)
+}
+
+fn show(report: &mut DemoReport, case: &Case) {
+    report.step(format!("{} - {}", case.name, case.scenario()));
+    match case.rejected_line() {
+        Some((code, annotation)) => report.code(format!("{}  // ❌ {}", code, annotation)),
+        None => report.fail(format!("tests/compile_fail/gallery/{}.rs has no ❌ line", case.name)),
+    };
+    report.text(format!("Fix: {}", case.fix()));
+    report.ok(format!("→ {}", (case.fixed)()));
+    report.text(format!("Go: {}", case.go));
+}
+
+fn gallery(title: &str, name: &str, intro: &str, lesson: &str) -> DemoReport {
+    let mut report = DemoReport::new(title);
+
+    report.text(intro);
+    for case in group(name) {
+        show(&mut report, case);
+    }
+
+    report.gap();
+    report.ok(lesson);
+    report
+}
+
+// Every case on one page
+pub fn index() -> DemoReport {
+    let mut report = DemoReport::new("Borrow-Checker Gallery - The Index");
+
+    report.table(
+        &["case", "example", "error", "what goes wrong"],
+        CASES.iter().map(|case| vec![case.name.to_string(), case.group.to_string(), case.error.to_string(), case.scenario().to_string()]).collect(),
+    );
+    report.text("Each broken program: tests/compile_fail/gallery/<case>.rs, its diagnostic in <case>.stderr");
+    report.code("cargo test --test compile_fail  # every case must still fail, with the same error");
+
+    let mut codes: Vec<&str> = CASES.iter().map(|case| case.error).collect();
+    codes.sort();
+    codes.dedup();
+    report.gap();
+    report.ok(format!("{} cases, {} kinds of error - each with a fix that compiles and runs", CASES.len(), codes.len()));
+    report
+}
+
+pub fn ownership() -> DemoReport {
+    gallery(
+        "Moves - A Value Has One Owner",
+        "ownership",
+        "Passing, pushing or unwrapping a non-Copy value moves it; the old name is dead after",
+        "Two places need it? Lend it (&T), clone it, or share it (Rc/Arc) - pick one out loud",
+    )
+}
+
+pub fn dangling() -> DemoReport {
+    gallery(
+        "Dangling - A Borrow Can't Outlive Its Owner",
+        "dangling",
+        "The owner is dropped at the end of its scope; a reference still pointing at it is rejected",
+        "Return or move the owned value instead of a reference to it - Go's escape analysis does this for you, on the heap",
+    )
+}
+
+pub fn aliasing() -> DemoReport {
+    gallery(
+        "Aliasing - One &mut or Many &, Never Both",
+        "aliasing",
+        "A borrow lasts until its last use; mutating the owner before then is rejected",
+        "Finish with the borrow first, copy the value out, or use the API that does both (entry, retain, extend)",
+    )
+}
+
+pub fn threads_async() -> DemoReport {
+    gallery(
+        "Threads and Async - Borrows That Cross a Boundary",
+        "threads-async",
+        "A thread or a future can outlive the function that made it, or run on another thread",
+        "move the data in, share it with Arc/atomics, and drop guards before .await",
+    )
+}
+
+pub fn gallery_vs_go() -> DemoReport {
+    let mut report = DemoReport::new("The Gallery in Go - Compiles, Then What?");
+
+    report.side_by_side(vec![
+        SideBySideRow::differs("return &upper  // escapes to the heap", "&upper  // ❌ E0515: return the String instead"),
+        SideBySideRow::differs("p := &s[0]; s = append(s, 40)  // p sees the old array", "let p = &s[0]; s.push(40);  // ❌ E0502"),
+        SideBySideRow::differs("go func() { total++ }()  // data race", "scope.spawn(|| total += 1)  // ❌ E0499"),
+    ]);
+
+    let bugs: Vec<&Case> = CASES.iter().filter(|case| case.go_bug).collect();
+    report.table(
+        &["in Go", "cases"],
+        vec![
+            vec!["fine - the GC keeps it alive, or a copy is made".into(), (CASES.len() - bugs.len()).to_string()],
+            vec!["compiles, but a bug".into(), bugs.iter().map(|case| case.name).collect::<Vec<_>>().join(", ")],
+        ],
+    );
+    for case in &bugs {
+        report.text(format!("{}: {}", case.name, case.go));
+    }
+
+    report.gap();
+    report.ok("Most of these are safe in Go because the GC owns everything - Rust needs an owner named in the code");
+    report.warn("The rest are aliasing bugs Go compiles: stale pointers, skipped elements, data races");
+    report
+}
+
+pub const DEMO: Demo = Demo {
+    name: "two-owners-compile-fail-gallery",
+    about: "Gallery of 20 borrow-checker errors (moves, dangling refs, iterator invalidation, threads, async) checked by trybuild, each with a fix that runs",
+    difficulty: Difficulty::Intermediate,
+    tags: &[Tag::Ownership, Tag::Borrowing, Tag::Concurrency],
+    examples: &[
+        Example { name: "index", run: index },
+        Example { name: "ownership", run: ownership },
+        Example { name: "dangling", run: dangling },
+        Example { name: "aliasing", run: aliasing },
+        Example { name: "threads-async", run: threads_async },
+        Example { name: "vs-go", run: gallery_vs_go },
+    ],
+};
+
+pub fn demonstrate_two_owners_compile_fail_gallery() -> Vec<DemoReport> {
+    DEMO.run()
+}
@@ -0,0 +1,202 @@
+// Unsafe Rust - where the guarantees end, and how it's fenced in
+// Go:   package unsafe - unsafe.Pointer, uintptr arithmetic. The GC still
+//       keeps objects alive, so a stray pointer rarely dangles, but the
+//       compiler checks nothing.
+// Rust: `unsafe { }` unlocks five extra operations (deref a raw pointer,
+//       call an unsafe fn, access a static mut, impl an unsafe trait, read
+//       a union field). Everything else - borrowck, types - still applies.
+//       The idiom: a small unsafe core behind a safe API that can't misuse it.
+
+use std::slice;
+
+use crate::registry::{Demo, Difficulty, Example, Tag};
+use crate::report::DemoReport;
+
+// Raw pointers: creating one is safe, dereferencing one isn't
+pub fn raw_pointers() -> DemoReport {
+    let mut report = DemoReport::new("Raw Pointers - *const T and *mut T");
+
+    let mut value = 42u32;
+    let read_ptr: *const u32 = &value;
+    let write_ptr: *mut u32 = &raw mut value;
+    report.address("read_ptr (made without unsafe)", &value, "42");
+    report.ok(format!("{:p} == &value: {}", read_ptr, std::ptr::eq(read_ptr, &value)));
+
+    // SAFETY: write_ptr points at `value`, which is alive and not borrowed elsewhere
+    unsafe { *write_ptr += 1 };
+    // SAFETY: same pointee, only read
+    let read = unsafe { *read_ptr };
+    report.count("*read_ptr after *write_ptr += 1", read as usize);
+
+    report.step("Pointer arithmetic");
+    let numbers = [10u64, 20, 30, 40];
+    let first = numbers.as_ptr();
+    // SAFETY: offset 2 is inside the 4-element array
+    let third = unsafe { *first.add(2) };
+    report.count("*numbers.as_ptr().add(2)", third as usize);
+    report.code("unsafe { *first.add(4) }  // one past the end: UB, nobody checks");
+    report.text(format!("null: {:p}, is_null() = {}", std::ptr::null::<u8>(), std::ptr::null::<u8>().is_null()));
+
+    report.gap();
+    report.ok("Raw pointers can be null, dangling, aliased - so reading one needs unsafe");
+    report.text("Nothing tracks them: no lifetime, no borrow, no Drop");
+
+    report.step("Go");
+    report.code("p := unsafe.Pointer(&numbers[0])");
+    report.code("third := *(*uint64)(unsafe.Add(p, 2*8))");
+    report.text("Same power, same lack of checks - but the GC still knows numbers is in use");
+    report
+}
+
+// transmute reinterprets bits: sometimes fine, often UB
+pub fn transmute_hazards() -> DemoReport {
+    let mut report = DemoReport::new("mem::transmute - Reinterpreting Bits");
+
+    report.step("Fine: same size, every bit pattern valid");
+    let float = 1.5f32;
+    // SAFETY: f32 and u32 are both 4 bytes and every u32 is a valid bit pattern
+    #[allow(unnecessary_transmutes)]  // rustc itself suggests to_bits() here
+    let bits: u32 = unsafe { std::mem::transmute(float) };
+    report.text(format!("transmute::<f32, u32>(1.5) = {:#010x}", bits));
+    report.text(format!("1.5f32.to_bits()          = {:#010x}  ← same, no unsafe", float.to_bits()));
+
+    report.step("UB: invalid values (not run)");
+    report.code("let b: bool = unsafe { transmute(2u8) };  // bool must be 0 or 1");
+    report.code("let e: Ordering = unsafe { transmute(7i8) };  // no such variant");
+    report.code("let r: &u8 = unsafe { transmute(0usize) };  // references are never null");
+    report.fail("The compiler assumes these can't happen and optimizes on it");
+
+    report.step("UB: inventing lifetimes (not run)");
+    report.code("fn forever<'a>(s: &'a str) -> &'static str { unsafe { transmute(s) } }");
+    report.fail("Compiles - and hands out a reference that outlives its String");
+
+    report.step("Size mismatches are still caught");
+    report.code("let x: u64 = unsafe { transmute(1u32) };");
+    report.ok("E0512: cannot transmute between types of different sizes");
+
+    report.gap();
+    report.ok("Prefer to_bits/from_bits, from_ne_bytes, as casts, or the bytemuck crate");
+    report
+}
+
+// unsafe unlocks operations; it doesn't switch the checker off
+pub fn what_unsafe_allows() -> DemoReport {
+    let mut report = DemoReport::new("What unsafe Does (and Doesn't) Turn Off");
+
+    report.table(
+        &["With unsafe", "Allowed?"],
+        vec![
+            vec!["dereference a raw pointer".into(), "✓".into()],
+            vec!["call an unsafe fn (incl. FFI)".into(), "✓".into()],
+            vec!["read or write a static mut".into(), "✓".into()],
+            vec!["implement an unsafe trait (Send, Sync)".into(), "✓".into()],
+            vec!["read a union field".into(), "✓".into()],
+            vec!["two &mut to the same value".into(), "❌ borrowck still runs".into()],
+            vec!["use a moved value".into(), "❌ still E0382".into()],
+            vec!["call a method that doesn't exist".into(), "❌ still a type error".into()],
+        ],
+    );
+
+    report.gap();
+    report.code("unsafe { let a = &mut v; let b = &mut v; a.push(1); b.push(2); }  // ❌ E0499");
+    report.ok("unsafe narrows what YOU must prove; the rest is still proven for you");
+    report.text("grep -rn unsafe src/ lists every place that needs a careful review");
+    report
+}
+
+/// Split a slice into two non-overlapping mutable halves
+/// (what std's `split_at_mut` does - the borrow checker can't prove it alone)
+pub fn split_halves<T>(items: &mut [T]) -> (&mut [T], &mut [T]) {
+    let mid = items.len() / 2;
+    let len = items.len();
+    let ptr = items.as_mut_ptr();
+    // SAFETY: [0, mid) and [mid, len) don't overlap and both lie inside `items`,
+    // whose &mut borrow is split between the two results
+    unsafe { (slice::from_raw_parts_mut(ptr, mid), slice::from_raw_parts_mut(ptr.add(mid), len - mid)) }
+}
+
+// A safe API over an unsafe core
+pub fn safe_abstraction() -> DemoReport {
+    let mut report = DemoReport::new("Safe Abstraction Over unsafe - split_at_mut");
+
+    report.step("What the borrow checker rejects");
+    report.code("let left = &mut v[..2];");
+    report.code("let right = &mut v[2..];  // ❌ E0499: v is already borrowed mutably");
+    report.text("It can't see that the two ranges don't overlap");
+
+    report.step("Proven once inside, safe for every caller");
+    let mut scores = vec![1, 2, 3, 4, 5];
+    let (left, right) = split_halves(&mut scores);
+    left[0] *= 10;
+    right[0] *= 100;
+    report.text(format!("left = {:?}, right = {:?}", left, right));
+    report.text(format!("scores = {:?}", scores));
+
+    report.gap();
+    report.ok("The signature ties both halves to the one &mut borrow of the input");
+    report.ok("Callers can't overlap them, keep them past `scores`, or use scores meanwhile");
+    report.text("Vec, String, Rc, Mutex and channels are all built this way in std");
+    report
+}
+
+// Use-after-free: the classic bug safe Rust rules out
+pub fn use_after_free() -> DemoReport {
+    let mut report = DemoReport::new("Use-After-Free - Only With unsafe");
+
+    report.step("Safe Rust");
+    report.code("let r; { let b = Box::new(7); r = &*b; } println!(\"{}\", r);");
+    report.fail("E0597: `*b` does not live long enough");
+
+    report.step("unsafe Rust (examples/use_after_free.rs - not run here)");
+    report.code("let ptr: *const u64 = &*boxed;");
+    report.code("drop(boxed);");
+    report.code("unsafe { *ptr }  // reads freed memory");
+    report.warn("Compiles and usually 'works' - until the allocator reuses that slot");
+    report.text("make use-after-free, then catch it with Miri:");
+    report.code("cargo +nightly miri run --example use_after_free");
+
+    report.step("Go");
+    report.code("p := &x  // x stays alive as long as p does - the GC sees to it");
+    report.text("Go's unsafe can still dangle: keep only a uintptr and the GC may free the object");
+    report
+}
+
+pub fn unsafe_vs_go() -> DemoReport {
+    let mut report = DemoReport::new("unsafe: Go vs Rust");
+
+    report.table(
+        &["", "Go", "Rust"],
+        vec![
+            vec!["raw pointer".into(), "unsafe.Pointer, uintptr".into(), "*const T, *mut T".into()],
+            vec!["reinterpret".into(), "(*T)(unsafe.Pointer(&x))".into(), "transmute, pointer casts".into()],
+            vec!["marked at".into(), "the import".into(), "every block, fn and trait".into()],
+            vec!["dangling".into(), "rare (GC), uintptr can".into(), "possible - your proof".into()],
+            vec!["data race".into(), "possible even without unsafe".into(), "only with unsafe".into()],
+            vec!["checker".into(), "go vet, -race".into(), "Miri, sanitizers".into()],
+        ],
+    );
+
+    report.gap();
+    report.ok("Rust's safety claim: safe code can't cause UB, if the unsafe below it is sound");
+    report.text("Go's claim is weaker but broader: memory-safe except for races and unsafe");
+    report
+}
+
+pub const DEMO: Demo = Demo {
+    name: "unsafe-rust",
+    about: "Unsafe Rust: raw pointers, transmute, safe abstractions vs Go's package unsafe",
+    difficulty: Difficulty::Advanced,
+    tags: &[Tag::Unsafe],
+    examples: &[
+        Example { name: "raw-pointers", run: raw_pointers },
+        Example { name: "transmute", run: transmute_hazards },
+        Example { name: "allows", run: what_unsafe_allows },
+        Example { name: "safe-abstraction", run: safe_abstraction },
+        Example { name: "use-after-free", run: use_after_free },
+        Example { name: "vs-go", run: unsafe_vs_go },
+    ],
+};
+
+pub fn demonstrate_unsafe_rust() -> Vec<DemoReport> {
+    DEMO.run()
+}
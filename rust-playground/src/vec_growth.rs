@@ -0,0 +1,287 @@
+// Vec growth - watching the buffer move
+// Go:   append(s, x) writes in place while len < cap, otherwise allocates a
+//       bigger array, copies, and returns a slice of the NEW array. Every
+//       other slice still points at the old one - sometimes two slices share
+//       an array, sometimes they don't, depending on capacity at runtime.
+// Rust: Vec::push does the same dance (grow ×2, copy, free the old buffer),
+//       but a push needs &mut Vec, so no reference into the old buffer can
+//       be alive when it happens. The aliasing question never comes up.
+
+use std::hint::black_box;
+
+use crate::alloc_stats;
+use crate::registry::{Demo, Difficulty, Example, Tag};
+use crate::report::DemoReport;
+
+const PUSHES: usize = 1_000;
+
+/// One push that changed the capacity
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Growth {
+    pub len: usize,
+    pub old_capacity: usize,
+    pub new_capacity: usize,
+    pub old_ptr: usize,
+    pub new_ptr: usize,
+}
+
+impl Growth {
+    /// The allocator grew the block in place - nothing was copied
+    pub fn in_place(&self) -> bool {
+        self.old_ptr == self.new_ptr
+    }
+}
+
+/// Push `count` values into an empty Vec, recording every capacity change
+pub fn track_growth<T: Clone>(value: T, count: usize) -> Vec<Growth> {
+    let mut v: Vec<T> = Vec::new();
+    let mut growths = Vec::new();
+    for _ in 0..count {
+        let (old_capacity, old_ptr) = (v.capacity(), v.as_ptr() as usize);
+        v.push(value.clone());
+        if v.capacity() != old_capacity {
+            growths.push(Growth { len: v.len(), old_capacity, new_capacity: v.capacity(), old_ptr, new_ptr: v.as_ptr() as usize });
+        }
+    }
+    growths
+}
+
+fn allocations_of(f: impl FnOnce()) -> String {
+    match alloc_stats::measure(f).1 {
+        Some(stats) => stats.allocations.to_string(),
+        None => "n/a".to_string(),
+    }
+}
+
+// Every reallocation of a growing Vec<u64>
+pub fn reallocations() -> DemoReport {
+    let mut report = DemoReport::new("Vec::push - Every Reallocation");
+
+    report.code("let mut v: Vec<u64> = Vec::new();  // cap 0, no allocation yet");
+    report.code("for i in 0..100 { v.push(i); }");
+    let growths = track_growth(0u64, 100);
+    report.table(
+        &["push #", "cap", "buffer", "moved?", "copied"],
+        growths
+            .iter()
+            .map(|growth| {
+                let first = growth.old_capacity == 0;
+                vec![
+                    growth.len.to_string(),
+                    format!("{} → {}", growth.old_capacity, growth.new_capacity),
+                    format!("{:#x}", growth.new_ptr),
+                    if first { "first alloc".into() } else if growth.in_place() { "grown in place".into() } else { "moved".into() },
+                    if first || growth.in_place() { "-".into() } else { format!("{} B", growth.old_capacity * size_of::<u64>()) },
+                ]
+            })
+            .collect(),
+    );
+    report.count("capacity changes for 100 pushes", growths.len());
+
+    report.gap();
+    report.ok("Capacity doubles: a handful of growths, and each element is copied about once on average");
+    report.text("\"grown in place\": realloc found free space after the block - same address, no copy");
+    report.text("Either way the old address can't be trusted after a push that changed the capacity");
+
+    report.step("Go");
+    report.code("s = append(s, i)  // same doubling until 256 elements, then ~1.25× (runtime.growslice)");
+    report
+}
+
+// The first allocation depends on the element size
+pub fn element_size() -> DemoReport {
+    let mut report = DemoReport::new("First Capacity Depends on the Element Size");
+
+    fn first_capacity<T: Clone>(value: T) -> usize {
+        track_growth(value, 1)[0].new_capacity
+    }
+    report.table(
+        &["Element", "size_of", "cap after the first push"],
+        vec![
+            vec!["u8".into(), "1".into(), first_capacity(0u8).to_string()],
+            vec!["u64".into(), "8".into(), first_capacity(0u64).to_string()],
+            vec!["[u8; 2048]".into(), "2048".into(), first_capacity([0u8; 2048]).to_string()],
+            vec!["()".into(), "0".into(), Vec::<()>::new().capacity().to_string()],
+        ],
+    );
+
+    report.gap();
+    report.ok("Small elements start at 8, medium at 4, anything over 1 KiB at 1 - no wasted tiny allocations");
+    report.ok("Zero-sized types never allocate: capacity is usize::MAX from the start");
+
+    report.step("Go");
+    report.code("append([]byte(nil), 1)  // cap 8 - rounded up to the allocator's size class");
+    report.text("Go rounds capacity up to a malloc size class, so cap can jump by odd amounts");
+    report
+}
+
+// Why a reference can't survive a push
+pub fn reference_across_push() -> DemoReport {
+    let mut report = DemoReport::new("Holding a Reference Across push");
+
+    let mut v: Vec<u64> = Vec::with_capacity(2);
+    v.extend([1, 2]);
+    let before = v.as_ptr() as usize;
+    v.push(3);  // Full: this one reallocates
+    let after = v.as_ptr() as usize;
+    report.text(format!("buffer before the third push: {:#x}, after: {:#x}", before, after));
+    if after == before {
+        report.text("Grown in place this time - but push can't promise that, so a reference can't rely on it");
+    } else {
+        report.text("A `&v[0]` taken before the push would point into the freed buffer");
+    }
+
+    report.code("let first = &v[0];");
+    report.code("v.push(4);             // ❌ cannot borrow `v` as mutable because it is also borrowed as immutable");
+    report.code("println!(\"{}\", first);");
+    report.code("for x in &v { v.push(*x); }  // ❌ same error - iterating is borrowing");
+    report.text("Checked by tests/compile_fail/push_while_iterating.rs (and push_while_sliced.rs)");
+
+    report.step("What to write instead");
+    let first = v[0];  // Copy the value out...
+    v.push(4);
+    report.code("let first = v[0];              // copy the value out, not a reference");
+    report.code("let i = 0; v.push(4); v[i]     // an index survives reallocation");
+    report.code("v.extend_from_within(..);      // duplicate the Vec's own elements in one call");
+    v.extend_from_within(..);
+    report.text(format!("first = {}, v = {:?}", first, v));
+
+    report.gap();
+    report.ok("The borrow checker doesn't know WHICH push reallocates, so it rejects all of them");
+    report.ok("Indices and copied values are the escape hatch; neither can dangle");
+
+    report.step("Go");
+    report.code("first := &s[0]; s = append(s, 4); *first = 99  // compiles; may write the old array");
+    report
+}
+
+// Go's append aliasing, and why Rust can't express it
+pub fn append_aliasing() -> DemoReport {
+    let mut report = DemoReport::new("Go's append Aliasing vs Rust's Owned Vec");
+
+    report.step("Go");
+    report.code("base := make([]int, 3, 4)   // len 3, cap 4: one free slot");
+    report.code("a := append(base, 1)        // fits: writes base's array at index 3");
+    report.code("b := append(base, 2)        // fits: writes THE SAME slot");
+    report.code("fmt.Println(a[3], b[3])     // 2 2 - a changed under you");
+    report.code("base = make([]int, 3, 3)    // ...but with cap 3 both appends copy: 1 2");
+    report.warn("Whether a and b share memory depends on the spare capacity, which the code doesn't show");
+
+    report.step("Rust");
+    report.code("let a = { let mut a = base.clone(); a.push(1); a };");
+    report.code("let b = { let mut b = base.clone(); b.push(2); b };");
+    let mut base: Vec<i64> = Vec::with_capacity(4);
+    base.extend([0, 0, 0]);
+    let a = {
+        let mut a = base.clone();
+        a.push(1);
+        a
+    };
+    let b = {
+        let mut b = base.clone();
+        b.push(2);
+        b
+    };
+    report.text(format!("a[3] = {}, b[3] = {}", a[3], b[3]));
+    report.address("base", base.as_slice(), format!("{:?}", base));
+    report.address("a", a.as_slice(), format!("{:?}", a));
+    report.address("b", b.as_slice(), format!("{:?}", b));
+
+    report.gap();
+    report.ok("push needs the Vec itself (&mut), so two \"appends to base\" means two owned copies");
+    report.ok("Three Vecs, three buffers - sharing only happens through explicit & / &mut");
+    report.text("Go's rule of thumb (always s = append(s, ...), never append to a slice you don't own) is what Rust enforces");
+    report
+}
+
+// Planning capacity: with_capacity, reserve, shrink_to_fit
+pub fn reserve_and_shrink() -> DemoReport {
+    let mut report = DemoReport::new("with_capacity, reserve and shrink_to_fit");
+
+    report.table(
+        &[&format!("{} pushes", PUSHES), "Allocations"],
+        vec![
+            vec![
+                "Vec::new()".into(),
+                allocations_of(|| {
+                    let mut v = Vec::new();
+                    for i in 0..PUSHES {
+                        v.push(black_box(i));
+                    }
+                    black_box(v);
+                }),
+            ],
+            vec![
+                "Vec::with_capacity(n)".into(),
+                allocations_of(|| {
+                    let mut v = Vec::with_capacity(PUSHES);
+                    for i in 0..PUSHES {
+                        v.push(black_box(i));
+                    }
+                    black_box(v);
+                }),
+            ],
+            vec!["(0..n).collect()".into(), allocations_of(|| drop(black_box((0..PUSHES).collect::<Vec<usize>>())))],
+        ],
+    );
+    if !alloc_stats::enabled() {
+        report.text("(allocation counts need --features alloc-stats)");
+    }
+
+    let mut v: Vec<usize> = (0..PUSHES).collect();
+    v.truncate(10);
+    report.text(format!("after truncate(10): len {} cap {}", v.len(), v.capacity()));
+    v.shrink_to_fit();
+    report.text(format!("after shrink_to_fit(): len {} cap {}", v.len(), v.capacity()));
+    v.reserve(100);
+    report.text(format!("after reserve(100): len {} cap {} (at least 110, may round up)", v.len(), v.capacity()));
+
+    report.gap();
+    report.ok("Know the size? with_capacity makes it one allocation; collect() sizes itself from the iterator");
+    report.ok("truncate/clear keep the buffer for reuse; shrink_to_fit gives memory back");
+
+    report.step("Go");
+    report.code("s := make([]int, 0, n)   // Vec::with_capacity(n)");
+    report.code("s = slices.Grow(s, 100)  // reserve(100)");
+    report.code("s = slices.Clip(s)       // shrink the cap, not the array - Go can't free part of one");
+    report
+}
+
+pub fn growth_vs_go() -> DemoReport {
+    let mut report = DemoReport::new("Growing Arrays: Go vs Rust");
+
+    report.table(
+        &["", "Go append", "Rust Vec::push"],
+        vec![
+            vec!["growth factor".into(), "2× to 256 elems, then ~1.25×".into(), "2×".into()],
+            vec!["result".into(), "a new slice header - must reassign".into(), "grows the Vec in place".into()],
+            vec!["old references".into(), "keep pointing at the old array".into(), "can't exist (borrow checker)".into()],
+            vec!["two appends to one base".into(), "may share a backing array".into(), "needs two owned Vecs".into()],
+            vec!["old buffer freed".into(), "when the GC finds no slices left".into(), "immediately, inside push".into()],
+            vec!["pre-size".into(), "make([]T, 0, n)".into(), "Vec::with_capacity(n)".into()],
+        ],
+    );
+
+    report.gap();
+    report.ok("Same data structure, same amortized cost - the difference is who may look at the old buffer");
+    report
+}
+
+pub const DEMO: Demo = Demo {
+    name: "vec-growth",
+    about: "Vec reallocation push by push, references across push, and Go's append aliasing",
+    difficulty: Difficulty::Beginner,
+    tags: &[Tag::Allocation, Tag::Borrowing],
+    examples: &[
+        Example { name: "reallocations", run: reallocations },
+        Example { name: "element-size", run: element_size },
+        Example { name: "reference-across-push", run: reference_across_push },
+        Example { name: "append-aliasing", run: append_aliasing },
+        Example { name: "reserve", run: reserve_and_shrink },
+        Example { name: "vs-go", run: growth_vs_go },
+    ],
+};
+
+pub fn demonstrate_vec_growth() -> Vec<DemoReport> {
+    DEMO.run()
+}
@@ -0,0 +1,290 @@
+// Collections in memory - what each container costs per entry
+// Go:   a slice and a map, built in; container/list and container/ring in
+//       the standard library, with every value boxed in an interface. No
+//       deque, no ordered map. A map never gives memory back when you
+//       delete from it.
+// Rust: Vec and VecDeque are one contiguous buffer; HashMap is one table
+//       (SwissTable, like Go 1.24's maps); BTreeMap is a tree of nodes
+//       holding up to 11 entries each; LinkedList allocates every node on
+//       its own. The counting allocator shows the difference: allocations
+//       per insert, bytes per entry, and how far apart iteration has to
+//       reach.
+
+use std::collections::{BTreeMap, HashMap, LinkedList, VecDeque};
+use std::hint::black_box;
+use std::mem::size_of;
+use std::time::{Duration, Instant};
+
+use crate::alloc_stats;
+use crate::registry::{Demo, Difficulty, Example, Tag};
+use crate::report::{DemoReport, SideBySideRow, format_bytes};
+
+/// Entries inserted by the footprint example
+pub const ENTRIES: usize = 10_000;
+/// Entries iterated by the locality benchmark
+pub const ITERATED: usize = 200_000;
+
+/// What building a container cost, and what it kept
+#[derive(Debug, Clone, Copy)]
+pub struct Footprint {
+    pub allocations: usize,
+    pub bytes_allocated: usize,
+    /// Live heap bytes while the container is alive
+    pub retained: usize,
+}
+
+/// Build a container and measure it (None without --features alloc-stats)
+pub fn footprint<C>(build: impl FnOnce() -> C) -> (C, Option<Footprint>) {
+    let before = alloc_stats::live_bytes();
+    let (container, stats) = alloc_stats::measure(build);
+    let retained = alloc_stats::live_bytes().saturating_sub(before);
+    (container, stats.map(|stats| Footprint { allocations: stats.allocations, bytes_allocated: stats.bytes_allocated, retained }))
+}
+
+fn footprint_row<C>(name: &str, layout: &str, build: impl FnOnce() -> C) -> Vec<String> {
+    let (container, usage) = footprint(build);
+    let row = match usage {
+        Some(usage) => vec![
+            name.to_string(),
+            usage.allocations.to_string(),
+            format_bytes(usage.bytes_allocated),
+            format_bytes(usage.retained),
+            format!("{:.1}", usage.retained as f64 / ENTRIES as f64),
+            layout.to_string(),
+        ],
+        None => vec![name.to_string(), "n/a".into(), "n/a".into(), "n/a".into(), "n/a".into(), layout.to_string()],
+    };
+    drop(container);  // Measured while alive, freed only after
+    row
+}
+
+// N inserts into each container: allocations, bytes, bytes per entry
+pub fn inserts() -> DemoReport {
+    let mut report = DemoReport::new("Inserting 10,000 Entries - Allocations and Overhead");
+
+    report.code(format!("for i in 0..{} {{ container.insert(i, i) }}  // (u64, u64): 16 bytes of payload per entry", ENTRIES));
+    if !alloc_stats::enabled() {
+        report.warn("Run with --features alloc-stats for the counts");
+    }
+    let n = ENTRIES as u64;
+    report.summary_table(
+        &["container", "allocations", "allocated", "retained", "bytes/entry", "layout"],
+        vec![
+            footprint_row("Vec<(u64, u64)>", "one buffer, doubles", || (0..n).fold(Vec::new(), |mut vec, i| { vec.push((i, i)); vec })),
+            footprint_row("Vec, with_capacity", "one buffer, sized once", || {
+                let mut vec = Vec::with_capacity(ENTRIES);
+                vec.extend((0..n).map(|i| (i, i)));
+                vec
+            }),
+            footprint_row("VecDeque<(u64, u64)>", "one ring buffer", || (0..n).fold(VecDeque::new(), |mut deque, i| { deque.push_back((i, i)); deque })),
+            footprint_row("HashMap<u64, u64>", "one table + 1 control byte/slot, ≤ 7/8 full", || (0..n).fold(HashMap::new(), |mut map, i| { map.insert(i, i); map })),
+            footprint_row("BTreeMap<u64, u64>", "nodes of up to 11 entries", || (0..n).fold(BTreeMap::new(), |mut map, i| { map.insert(i, i); map })),
+            footprint_row("LinkedList<(u64, u64)>", "one node per entry: +2 pointers", || (0..n).fold(LinkedList::new(), |mut list, i| { list.push_back((i, i)); list })),
+        ],
+    );
+
+    report.gap();
+    report.ok("Vec/VecDeque: ~log2(N) allocations in total - and 1 with the size known up front");
+    report.ok("HashMap rehashes into a bigger table as it grows: few allocations, some empty slots kept");
+    report.text("BTreeMap: in-order inserts leave split nodes half full; collect() from sorted input packs them");
+    report.warn("LinkedList: one allocation per push, 16 bytes of pointers on top of 16 of payload");
+    report
+}
+
+/// The queue the ring example runs: pop one, push one, `rounds` times
+pub fn churn(queue: &mut VecDeque<u64>, rounds: u64) -> u64 {
+    let mut sum = 0;
+    for i in 0..rounds {
+        sum += queue.pop_front().unwrap_or_default();
+        queue.push_back(i);
+    }
+    sum
+}
+
+fn timed<R>(f: impl FnOnce() -> R) -> (R, Duration) {
+    let start = Instant::now();
+    let result = f();
+    (result, start.elapsed())
+}
+
+// VecDeque as a ring: no allocation and no shifting once it's warm
+pub fn ring_buffer() -> DemoReport {
+    let mut report = DemoReport::new("VecDeque - a Ring Buffer, Not a Shifting Vec");
+
+    let mut queue: VecDeque<u64> = (0..8).collect();
+    report.code("let mut queue: VecDeque<u64> = (0..8).collect();  queue.pop_front();  queue.push_back(8);  ..");
+    for i in 8..13 {
+        queue.pop_front();
+        queue.push_back(i);
+    }
+    let (front, back) = queue.as_slices();
+    report.text(format!("After 5 pops and pushes: as_slices() = {:?} + {:?}", front, back));
+    report.text(format!("capacity {} - the contents wrapped around the end of the same buffer", queue.capacity()));
+
+    report.step("A million pops and pushes");
+    let rounds = 1_000_000;
+    let mut queue: VecDeque<u64> = (0..1_000).collect();
+    let ((_, stats), deque_time) = timed(|| alloc_stats::measure(|| black_box(churn(&mut queue, rounds))));
+    let mut shifting: Vec<u64> = (0..1_000).collect();
+    let (_, vec_time) = timed(|| {
+        for i in 0..rounds / 100 {
+            black_box(shifting.remove(0));  // Moves the other 999 down
+            shifting.push(i);
+        }
+    });
+    report.table(
+        &["queue of 1,000", "ops", "time", "ns/op", "allocations"],
+        vec![
+            vec!["VecDeque pop_front + push_back".into(), rounds.to_string(), format!("{:.1?}", deque_time), format!("{:.1}", deque_time.as_nanos() as f64 / rounds as f64), stats.map_or("n/a".into(), |stats| stats.allocations.to_string())],
+            vec!["Vec remove(0) + push".into(), (rounds / 100).to_string(), format!("{:.1?}", vec_time), format!("{:.1}", vec_time.as_nanos() as f64 / (rounds / 100) as f64), "-".into()],
+        ],
+    );
+
+    report.step("Go");
+    report.code("q = q[1:]          // O(1), but the array's front is never reused...");
+    report.code("q = append(q, x)   // ...so append keeps reallocating; popped items stay reachable until then");
+
+    report.gap();
+    report.ok("VecDeque: head and tail indices over one buffer - O(1) at both ends, nothing to free");
+    report.warn("Vec::remove(0) shifts every element: fine for 10, a hot spot for 10,000");
+    report
+}
+
+fn best_of_three(mut run: impl FnMut() -> u64) -> Duration {
+    (0..3)
+        .map(|_| {
+            let start = Instant::now();
+            black_box(run());
+            start.elapsed()
+        })
+        .min()
+        .unwrap_or_default()
+}
+
+// Same sum over each container: how far apart the next entry lives
+pub fn locality() -> DemoReport {
+    let mut report = DemoReport::new("Iteration Locality - Summing 200,000 Values");
+
+    if cfg!(debug_assertions) {
+        report.warn("Debug build - use --release for real numbers");
+    }
+    let n = ITERATED as u64;
+    let vec: Vec<u64> = (0..n).collect();
+    let deque: VecDeque<u64> = (0..n).collect();
+    let map: HashMap<u64, u64> = (0..n).map(|i| (i, i)).collect();
+    let tree: BTreeMap<u64, u64> = (0..n).map(|i| (i, i)).collect();
+    let list: LinkedList<u64> = (0..n).collect();
+    // The same list built between other allocations: its nodes end up scattered
+    let mut scattered = LinkedList::new();
+    let mut clutter = Vec::with_capacity(ITERATED);
+    for i in 0..n {
+        scattered.push_back(i);
+        clutter.push(Box::new([i; 3]));
+    }
+
+    let rows: Vec<(&str, Duration, &str)> = vec![
+        ("Vec<u64>", best_of_three(|| vec.iter().sum()), "8 bytes apart, prefetched"),
+        ("VecDeque<u64>", best_of_three(|| deque.iter().sum()), "one or two contiguous runs"),
+        ("HashMap values()", best_of_three(|| map.values().sum()), "scans control bytes, skips empty slots"),
+        ("BTreeMap values()", best_of_three(|| tree.values().sum()), "11 at a time, then a pointer hop"),
+        ("LinkedList<u64>", best_of_three(|| list.iter().sum()), "a pointer hop per value"),
+        ("LinkedList, scattered", best_of_three(|| scattered.iter().sum()), "a hop to wherever the node landed"),
+    ];
+    let fastest = rows.iter().map(|(_, time, _)| *time).min().unwrap_or_default().as_nanos().max(1) as f64;
+    report.summary_table(
+        &["container", "time", "ns/value", "vs fastest", "memory pattern"],
+        rows.iter()
+            .map(|(name, time, pattern)| {
+                vec![
+                    name.to_string(),
+                    format!("{:.1?}", time),
+                    format!("{:.2}", time.as_nanos() as f64 / ITERATED as f64),
+                    format!("{:.1}×", time.as_nanos() as f64 / fastest),
+                    pattern.to_string(),
+                ]
+            })
+            .collect(),
+    );
+    drop(clutter);
+
+    report.gap();
+    report.ok("Contiguous memory wins iteration: the CPU fetches the next values before you ask");
+    report.text("Each hop in a linked structure is a dependent load - the next address isn't known until this one arrives");
+    report
+}
+
+// HashMap keeps its table after removals - so does a Go map
+pub fn map_shrinking() -> DemoReport {
+    let mut report = DemoReport::new("Maps Don't Shrink by Themselves");
+
+    let baseline = alloc_stats::live_bytes();
+    let mut map: HashMap<u64, [u8; 64]> = HashMap::new();
+    let mut rows = vec![];
+    let mut snapshot = |label: &str, map: &HashMap<u64, [u8; 64]>| {
+        let live = if alloc_stats::enabled() { format_bytes(alloc_stats::live_bytes().saturating_sub(baseline)) } else { "n/a".to_string() };
+        rows.push(vec![label.to_string(), map.len().to_string(), map.capacity().to_string(), live]);
+    };
+    snapshot("empty", &map);
+    map.extend((0..ENTRIES as u64).map(|i| (i, [0; 64])));
+    snapshot("10,000 inserted", &map);
+    map.retain(|key, _| key % 100 == 0);
+    snapshot("99% removed", &map);
+    map.shrink_to_fit();
+    snapshot("shrink_to_fit()", &map);
+    report.table(&["HashMap<u64, [u8; 64]>", "len", "capacity", "heap held"], rows);
+    if !alloc_stats::enabled() {
+        report.text("(heap held needs --features alloc-stats)");
+    }
+
+    report.gap();
+    report.ok("Removing keeps the table for the next inserts - shrink_to_fit() hands it back");
+    report.warn("Go has no shrink: a map that once held a million keys keeps their buckets; the fix is copying into a new map");
+    report
+}
+
+pub fn collections_vs_go() -> DemoReport {
+    let mut report = DemoReport::new("Collections: Go vs Rust");
+
+    report.side_by_side(vec![
+        SideBySideRow::same("s := make([]T, 0, n)", "let v: Vec<T> = Vec::with_capacity(n);"),
+        SideBySideRow::differs("q = q[1:]  // a queue, of sorts", "let q: VecDeque<T> = VecDeque::new();"),
+        SideBySideRow::same("m := make(map[K]V)", "let m: HashMap<K, V> = HashMap::new();"),
+        SideBySideRow::differs("keys := maps.Keys(m); slices.Sort(keys)", "let m: BTreeMap<K, V> = BTreeMap::new();  // always sorted"),
+        SideBySideRow::differs("l := list.New(); l.PushBack(v)  // v boxed in an interface", "let mut l: LinkedList<T> = LinkedList::new();"),
+    ]);
+
+    report.table(
+        &["", "Go", "Rust"],
+        vec![
+            vec!["growable array".into(), "slice (header: 24 B)".into(), format!("Vec ({} B)", size_of::<Vec<u64>>())],
+            vec!["deque".into(), "none (slice tricks, container/ring)".into(), format!("VecDeque ({} B)", size_of::<VecDeque<u64>>())],
+            vec!["hash map".into(), "map: a pointer to a runtime table".into(), format!("HashMap ({} B inline)", size_of::<HashMap<u64, u64>>())],
+            vec!["ordered map".into(), "none in std".into(), format!("BTreeMap ({} B)", size_of::<BTreeMap<u64, u64>>())],
+            vec!["linked list".into(), "container/list: node + boxed value".into(), format!("LinkedList ({} B): node holds T", size_of::<LinkedList<u64>>())],
+            vec!["map shrinks".into(), "never".into(), "shrink_to_fit()".into()],
+        ],
+    );
+
+    report.gap();
+    report.ok("Both languages: reach for the flat one (slice / Vec) first - the numbers above are why");
+    report.text("Rust's generics store T inline in every container; Go's container/* store `any`, one more allocation per value");
+    report
+}
+
+pub const DEMO: Demo = Demo {
+    name: "vecdeque-and-collections-memory",
+    about: "Vec, VecDeque, HashMap, BTreeMap, LinkedList: allocations and bytes per entry, ring buffers, iteration locality, map shrinking vs Go's slice and map",
+    difficulty: Difficulty::Intermediate,
+    tags: &[Tag::Allocation, Tag::Layout, Tag::Benchmark],
+    examples: &[
+        Example { name: "inserts", run: inserts },
+        Example { name: "ring-buffer", run: ring_buffer },
+        Example { name: "locality", run: locality },
+        Example { name: "map-shrinking", run: map_shrinking },
+        Example { name: "vs-go", run: collections_vs_go },
+    ],
+};
+
+pub fn demonstrate_vecdeque_and_collections_memory() -> Vec<DemoReport> {
+    DEMO.run()
+}
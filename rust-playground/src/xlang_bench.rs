@@ -0,0 +1,316 @@
+// Cross-language benchmark - the same workloads, compiled by rustc and by go
+// The demos print Go as pseudocode; this runs real Go. Every workload below
+// has a twin in golang-playground/xlang/main.go with the same operation
+// count, and both programs print one line per workload:
+//
+//   xlang workload=vec-push ops=1000000 ns=4123456 allocs=38 bytes=41678848 gcs=2
+//
+// The xlang_bench binary runs itself (`--emit`) and `go run ./xlang` as child
+// processes, parses those lines and prints one comparison table. Timings come
+// from inside each program, so `go run`'s compile step isn't counted.
+// Allocation counts need the counting allocator: --features alloc-stats.
+
+use std::collections::HashMap;
+use std::fmt::{self, Write};
+use std::hint::black_box;
+use std::path::Path;
+use std::process::Command;
+use std::sync::Mutex;
+use std::thread;
+use std::time::Instant;
+
+use crate::alloc_stats;
+use crate::report::{format_bytes, DemoReport};
+
+/// Threads (Go: goroutines) in the mutex-counter workload
+pub const THREADS: usize = 4;
+
+/// One workload: `run(ops)` performs `ops` operations and returns a checksum
+pub struct Workload {
+    pub name: &'static str,
+    pub about: &'static str,
+    pub ops: usize,
+    pub run: fn(usize) -> u64,
+}
+
+// Keep in sync with `workloads` in golang-playground/xlang/main.go
+pub const WORKLOADS: &[Workload] = &[
+    Workload { name: "box-alloc", about: "one 32-byte heap object per op, all kept alive", ops: 1_000_000, run: box_alloc },
+    Workload { name: "vec-push", about: "push into an empty Vec / append to a nil slice", ops: 1_000_000, run: vec_push },
+    Workload { name: "map-insert", about: "HashMap / map inserts, no size hint", ops: 200_000, run: map_insert },
+    Workload { name: "string-build", about: "\"item-N,\" into one String / strings.Builder", ops: 200_000, run: string_build },
+    Workload { name: "binary-tree", about: "build and count a complete tree, one node per op", ops: (1 << 19) - 1, run: binary_tree },
+    Workload { name: "mutex-counter", about: "4 threads / goroutines sharing one Mutex counter", ops: 1_000_000, run: mutex_counter },
+];
+
+#[allow(dead_code)]  // Only x is read back; the rest makes it the Go struct's 32 bytes
+struct Point {
+    x: i64,
+    y: i64,
+    z: i64,
+    w: i64,
+}
+
+fn box_alloc(ops: usize) -> u64 {
+    let mut points: Vec<Box<Point>> = Vec::with_capacity(ops);
+    for i in 0..ops {
+        points.push(Box::new(Point { x: i as i64, y: 1, z: 2, w: 3 }));
+    }
+    points.iter().map(|point| point.x as u64).sum()
+}
+
+fn vec_push(ops: usize) -> u64 {
+    let mut values: Vec<u64> = Vec::new();
+    for i in 0..ops {
+        values.push(i as u64);
+    }
+    black_box(&values).len() as u64
+}
+
+fn map_insert(ops: usize) -> u64 {
+    let mut map: HashMap<u64, u64> = HashMap::new();
+    for i in 0..ops as u64 {
+        map.insert(i, i * 2);
+    }
+    map.len() as u64
+}
+
+fn string_build(ops: usize) -> u64 {
+    let mut text = String::new();
+    for i in 0..ops {
+        write!(text, "item-{},", i).expect("writing to a String never fails");
+    }
+    text.len() as u64
+}
+
+struct Node {
+    left: Option<Box<Node>>,
+    right: Option<Box<Node>>,
+}
+
+impl Node {
+    fn build(depth: u32) -> Box<Node> {
+        if depth == 0 {
+            return Box::new(Node { left: None, right: None });
+        }
+        Box::new(Node { left: Some(Node::build(depth - 1)), right: Some(Node::build(depth - 1)) })
+    }
+
+    fn count(&self) -> u64 {
+        1 + self.left.as_ref().map_or(0, |left| left.count()) + self.right.as_ref().map_or(0, |right| right.count())
+    }
+}
+
+// A complete tree with `ops` nodes (ops = 2^(depth+1) - 1)
+fn binary_tree(ops: usize) -> u64 {
+    let mut depth = 0;
+    while (1usize << (depth + 2)) - 1 <= ops {
+        depth += 1;
+    }
+    Node::build(depth).count()
+}
+
+fn mutex_counter(ops: usize) -> u64 {
+    let counter = Mutex::new(0u64);
+    thread::scope(|scope| {
+        for _ in 0..THREADS {
+            scope.spawn(|| {
+                for _ in 0..ops / THREADS {
+                    *counter.lock().unwrap() += 1;
+                }
+            });
+        }
+    });
+    counter.into_inner().unwrap()
+}
+
+/// One workload's numbers from one language
+#[derive(Debug, Clone, PartialEq)]
+pub struct Sample {
+    pub workload: String,
+    pub ops: usize,
+    pub nanos: u64,
+    /// None when the program couldn't count (Rust without alloc-stats)
+    pub allocations: Option<u64>,
+    pub bytes: Option<u64>,
+    /// Garbage collections during the run (None for Rust: there are none to count)
+    pub gcs: Option<u64>,
+}
+
+impl Sample {
+    pub fn nanos_per_op(&self) -> f64 {
+        self.nanos as f64 / self.ops.max(1) as f64
+    }
+}
+
+// "-" (or a missing key) means not measured
+fn optional(value: Option<u64>) -> String {
+    value.map_or("-".to_string(), |value| value.to_string())
+}
+
+impl fmt::Display for Sample {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "xlang workload={} ops={} ns={} allocs={} bytes={} gcs={}",
+            self.workload,
+            self.ops,
+            self.nanos,
+            optional(self.allocations),
+            optional(self.bytes),
+            optional(self.gcs)
+        )
+    }
+}
+
+/// Parse one `xlang workload=...` line; anything else (headers, noise) is None
+pub fn parse_line(line: &str) -> Option<Sample> {
+    let fields = line.strip_prefix("xlang ")?;
+    let fields: HashMap<&str, &str> = fields.split_whitespace().filter_map(|field| field.split_once('=')).collect();
+    let number = |key: &str| fields.get(key).and_then(|value| value.parse::<u64>().ok());
+    Some(Sample {
+        workload: fields.get("workload")?.to_string(),
+        ops: number("ops")? as usize,
+        nanos: number("ns")?,
+        allocations: number("allocs"),
+        bytes: number("bytes"),
+        gcs: number("gcs"),
+    })
+}
+
+/// Every sample line in a program's output
+pub fn parse_output(output: &str) -> Vec<Sample> {
+    output.lines().filter_map(parse_line).collect()
+}
+
+/// Run one workload in this process: a warm-up, then the measured run
+pub fn measure(workload: &Workload, ops: usize) -> Sample {
+    black_box((workload.run)(black_box(ops / 10)));
+    let start = Instant::now();
+    let (checksum, stats) = alloc_stats::measure(|| (workload.run)(black_box(ops)));
+    let nanos = start.elapsed().as_nanos() as u64;
+    black_box(checksum);
+    Sample {
+        workload: workload.name.to_string(),
+        ops,
+        nanos,
+        allocations: stats.map(|stats| stats.allocations as u64),
+        bytes: stats.map(|stats| stats.bytes_allocated as u64),
+        gcs: None,
+    }
+}
+
+/// The Rust half's output: a header line, then one sample line per workload
+pub fn emit() -> String {
+    let profile = if cfg!(debug_assertions) { "debug" } else { "release" };
+    let mut output = format!("xlang language=rust profile={} threads={} allocator={}\n", profile, THREADS, alloc_stats::backend());
+    for workload in WORKLOADS {
+        output.push_str(&format!("{}\n", measure(workload, workload.ops)));
+    }
+    output
+}
+
+/// Why a language's column is empty
+#[derive(Debug)]
+pub enum RunError {
+    /// The program couldn't be started (no `go` on PATH, ...)
+    Spawn(String, std::io::Error),
+    /// It ran but exited unsuccessfully
+    Failed(String, String),
+}
+
+impl fmt::Display for RunError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RunError::Spawn(program, error) => write!(f, "couldn't start {}: {}", program, error),
+            RunError::Failed(program, stderr) => write!(f, "{} failed: {}", program, stderr.trim()),
+        }
+    }
+}
+
+impl std::error::Error for RunError {}
+
+fn run_child(mut command: Command, name: &str) -> Result<Vec<Sample>, RunError> {
+    let output = command.output().map_err(|error| RunError::Spawn(name.to_string(), error))?;
+    if !output.status.success() {
+        return Err(RunError::Failed(name.to_string(), String::from_utf8_lossy(&output.stderr).into_owned()));
+    }
+    Ok(parse_output(&String::from_utf8_lossy(&output.stdout)))
+}
+
+/// Run `exe --emit` (the Rust workloads in a fresh process)
+pub fn run_rust(exe: &Path) -> Result<Vec<Sample>, RunError> {
+    let mut command = Command::new(exe);
+    command.arg("--emit");
+    run_child(command, &exe.display().to_string())
+}
+
+/// Run `go run ./xlang` inside `go_dir` (golang-playground)
+pub fn run_go(go_dir: &Path) -> Result<Vec<Sample>, RunError> {
+    let mut command = Command::new("go");
+    command.args(["run", "./xlang"]).current_dir(go_dir);
+    run_child(command, "go run ./xlang")
+}
+
+fn find<'a>(samples: &'a [Sample], workload: &str) -> Option<&'a Sample> {
+    samples.iter().find(|sample| sample.workload == workload)
+}
+
+/// The side-by-side table for whatever each language produced
+pub fn comparison(rust: &[Sample], go: &[Sample]) -> DemoReport {
+    let mut report = DemoReport::new("Rust vs Go - Same Workloads, Real Programs");
+
+    let per_op = |sample: Option<&Sample>| sample.map_or("-".to_string(), |sample| format!("{:.1}", sample.nanos_per_op()));
+    let allocations = |sample: Option<&Sample>| sample.and_then(|sample| sample.allocations).map_or("-".to_string(), |count| count.to_string());
+    let bytes = |sample: Option<&Sample>| sample.and_then(|sample| sample.bytes).map_or("-".to_string(), |bytes| format_bytes(bytes as usize));
+    let rows = WORKLOADS
+        .iter()
+        .map(|workload| {
+            let (rust, go) = (find(rust, workload.name), find(go, workload.name));
+            let ratio = match (rust, go) {
+                (Some(rust), Some(go)) if rust.nanos > 0 => format!("{:.2}×", go.nanos_per_op() / rust.nanos_per_op()),
+                _ => "-".to_string(),
+            };
+            vec![
+                workload.name.to_string(),
+                workload.ops.to_string(),
+                per_op(rust),
+                per_op(go),
+                ratio,
+                allocations(rust),
+                allocations(go),
+                bytes(rust),
+                bytes(go),
+                go.and_then(|go| go.gcs).map_or("-".to_string(), |gcs| gcs.to_string()),
+            ]
+        })
+        .collect();
+    report.table(&["workload", "ops", "Rust ns/op", "Go ns/op", "Go / Rust", "Rust allocs", "Go allocs", "Rust bytes", "Go bytes", "Go GCs"], rows);
+
+    for (language, samples) in [("Rust", rust), ("Go", go)] {
+        for sample in samples {
+            let expected = WORKLOADS.iter().find(|workload| workload.name == sample.workload).map(|workload| workload.ops);
+            if expected.is_some_and(|ops| ops != sample.ops) {
+                report.warn(format!("{} ran {} with {} ops, not {} - the programs are out of sync", language, sample.workload, sample.ops, expected.unwrap_or_default()));
+            }
+        }
+    }
+    report.text(format!("Rust allocator: {} (--features mimalloc / jemalloc to swap it)", alloc_stats::backend()));
+    if rust.iter().all(|sample| sample.allocations.is_none()) {
+        report.text("Rust allocation counts need --features alloc-stats");
+    }
+    if cfg!(debug_assertions) {
+        report.warn("Debug build - run with --release (make xlang-bench) for representative numbers");
+    }
+
+    report.step("Workloads");
+    for workload in WORKLOADS {
+        report.text(format!("{:<14} {}", workload.name, workload.about));
+    }
+
+    report.gap();
+    report.ok("Same algorithm, same sizes: what's left is allocator, GC and code generation");
+    report.text("Go bytes include every append regrowth; Rust counts a realloc as free + alloc, like Go's profiler");
+    report.text("Go GCs: collections that ran inside the timed region - the work Rust does at each drop instead");
+    report
+}
@@ -0,0 +1,74 @@
+// Actors: the handle's round trips, a stopped actor, the state coming back
+// through join() equal to the same adds behind a Mutex.
+
+use std::sync::Mutex;
+use std::thread;
+
+use rust_playground::actor_model::{self, Strategy, Tally};
+
+#[test]
+fn adds_from_many_threads_all_arrive() {
+    let (handle, actor) = actor_model::spawn(Tally::default());
+    let senders: Vec<_> = (0..4)
+        .map(|_| {
+            let handle = handle.clone();
+            std::thread::spawn(move || {
+                for _ in 0..100 {
+                    handle.add("hits", 1);
+                }
+            })
+        })
+        .collect();
+    for sender in senders {
+        sender.join().unwrap();
+    }
+    assert_eq!(handle.get("hits"), Some(400));
+    assert_eq!(handle.get("misses"), Some(0));
+    drop(handle);
+    assert_eq!(actor.join().unwrap().total, 400);
+}
+
+#[test]
+fn a_stopped_actor_answers_nothing() {
+    let (handle, actor) = actor_model::spawn(Tally::default());
+    handle.add("hits", 2);
+    handle.stop();
+    assert_eq!(actor.join().unwrap().total, 2);
+    assert!(!handle.add("hits", 1));
+    assert_eq!(handle.total(), None);
+}
+
+#[test]
+fn every_strategy_counts_exactly() {
+    for strategy in Strategy::ALL {
+        let measurement = actor_model::bench(strategy, 3, 300);
+        assert_eq!(measurement.total, 300, "{}", strategy.name());
+    }
+}
+
+#[test]
+fn the_actor_ends_with_the_mutex_baselines_tally() {
+    let (handle, actor) = actor_model::spawn(Tally::default());
+    let baseline = Mutex::new(Tally::default());
+    thread::scope(|scope| {
+        for (index, key) in ["apples", "pears", "plums"].into_iter().enumerate() {
+            let handle = handle.clone();
+            let baseline = &baseline;
+            scope.spawn(move || {
+                for amount in 0..100 {
+                    handle.add(key, index as u64 + amount);
+                    baseline.lock().unwrap().add(key, index as u64 + amount);
+                }
+            });
+        }
+    });
+    drop(handle);
+    assert_eq!(actor.join().unwrap(), baseline.into_inner().unwrap());
+}
+
+#[test]
+fn owned_state_reports_the_tally_it_got_back() {
+    let report = actor_model::owned_state();
+    assert_eq!(report.count_of("pears (asked with get)"), Some(20));
+    assert_eq!(report.count_of("total"), Some(60));
+}
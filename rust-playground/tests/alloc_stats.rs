@@ -0,0 +1,26 @@
+// The counting allocator's numbers, including a measure nested inside
+// another one (DemoReport::measured around a demo that measures itself).
+// Run with: cargo test --features alloc-stats --test alloc_stats
+#![cfg(feature = "alloc-stats")]
+
+use std::hint::black_box;
+
+use rust_playground::alloc_stats;
+
+const OUTER: usize = 1 << 20;
+const INNER: usize = 1 << 10;
+
+// One test: the allocator's counters are process-wide
+#[test]
+fn nested_measure_keeps_the_outer_peak() {
+    let ((), outer) = alloc_stats::measure(|| {
+        drop(black_box(vec![1u8; OUTER]));  // The outer peak, reached before the inner measure
+        let ((), inner) = alloc_stats::measure(|| drop(black_box(vec![1u8; INNER])));
+        let inner = inner.unwrap();
+        assert_eq!(inner.allocations, 1);
+        assert!(inner.peak_bytes >= INNER && inner.peak_bytes < OUTER, "inner peak {}", inner.peak_bytes);
+    });
+    let outer = outer.unwrap();
+    assert_eq!(outer.allocations, 2);
+    assert!(outer.peak_bytes >= OUTER, "outer peak {} lost to the inner measure", outer.peak_bytes);
+}
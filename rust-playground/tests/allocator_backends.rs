@@ -0,0 +1,48 @@
+// The global allocator this test binary was built with, and the workloads
+// that get timed on it: one row each, on the backend it names.
+
+use rust_playground::alloc_stats;
+use rust_playground::allocator_backends::{self, WORKLOADS};
+
+#[test]
+fn backend_matches_the_features() {
+    let expected = if cfg!(feature = "mimalloc") {
+        "mimalloc"
+    } else if cfg!(feature = "jemalloc") {
+        "jemalloc"
+    } else {
+        "system"
+    };
+    assert_eq!(alloc_stats::backend(), expected);
+}
+
+#[test]
+fn every_workload_allocates() {
+    for workload in WORKLOADS {
+        let run = allocator_backends::measure(workload, 1_000);
+        if let Some(allocations) = run.allocations {
+            assert!(allocations > 0, "{} allocated nothing", workload.name);
+        }
+    }
+}
+
+#[test]
+fn active_names_this_builds_backend() {
+    let report = allocator_backends::active();
+    let expected = format!("allocator: {}", alloc_stats::backend());
+    assert!(report.texts().any(|text| text == expected));
+}
+
+#[test]
+fn workloads_reports_a_row_per_workload() {
+    let report = allocator_backends::workloads();
+    let rows = report.tables().next().unwrap();
+    assert_eq!(rows.iter().map(|row| row[0].as_str()).collect::<Vec<_>>(), WORKLOADS.iter().map(|workload| workload.name).collect::<Vec<_>>());
+    for row in rows {
+        assert_eq!(row[1], alloc_stats::backend());
+        match row[4].parse::<usize>() {
+            Ok(allocations) => assert!(alloc_stats::enabled() && allocations > 0, "{}: {} allocations", row[0], allocations),
+            Err(_) => assert!(!alloc_stats::enabled() && row[4] == "n/a", "{}: {:?}", row[0], row[4]),
+        }
+    }
+}
@@ -0,0 +1,73 @@
+// The browser build's policy and output, checked natively: the wasm target
+// itself can't run `cargo test` here.
+
+use rust_playground::browser::{self, NATIVE_ONLY};
+use rust_playground::registry::Tag;
+use serde_json::Value;
+
+// Only registered with their cargo features
+const FEATURE_GATED: &[&str] = &["async-await", "crossbeam", "ffi-with-go", "parking-lot"];
+
+#[test]
+fn native_only_entries_name_real_demos_and_examples() {
+    for (path, _) in NATIVE_ONLY {
+        let demo = path.split("::").next().unwrap();
+        if FEATURE_GATED.contains(&demo) && rust_playground::find(demo).is_err() {
+            continue;
+        }
+        assert!(rust_playground::find(path).is_ok(), "{} isn't a demo or example", path);
+    }
+}
+
+#[test]
+fn thread_and_benchmark_demos_are_reviewed() {
+    for demo in rust_playground::demos() {
+        if demo.has_tag(Tag::Concurrency) || demo.has_tag(Tag::Benchmark) {
+            let listed = NATIVE_ONLY.iter().any(|(path, _)| path.split("::").next() == Some(demo.name));
+            assert!(listed, "{} spawns threads or reads the clock - add it to browser::NATIVE_ONLY", demo.name);
+        }
+    }
+}
+
+#[test]
+fn blocked_examples_become_a_short_report() {
+    let reports = browser::run("scoped-threads").unwrap();
+    assert_eq!(reports.len(), rust_playground::find("scoped-threads").unwrap().0.examples.len());
+    assert!(reports.iter().all(|report| report.title.ends_with("Native Only")));
+
+    let reports = browser::run("rc-weak").unwrap();
+    assert_eq!(reports.iter().filter(|report| report.title.ends_with("Native Only")).count(), 1);
+}
+
+#[test]
+fn run_json_is_valid_json_with_the_report_structure() {
+    let reports: Value = serde_json::from_str(&browser::run_json("ownership::ownership").unwrap()).unwrap();
+    let report = &reports[0];
+    assert_eq!(report["title"], "1. Ownership - Single Owner");
+    let kinds: Vec<&str> = report["steps"][0]["entries"].as_array().unwrap().iter().map(|entry| entry["kind"].as_str().unwrap()).collect();
+    assert_eq!(kinds, ["address", "ok"]);
+}
+
+#[test]
+fn every_runnable_demo_renders_valid_json() {
+    for demo in rust_playground::demos() {
+        let json = browser::run_json(demo.name).unwrap();
+        assert!(serde_json::from_str::<Value>(&json).is_ok(), "{} rendered invalid JSON", demo.name);
+    }
+}
+
+#[test]
+fn catalogue_marks_what_needs_native() {
+    let catalogue: Value = serde_json::from_str(&browser::catalogue_json()).unwrap();
+    let demos = catalogue.as_array().unwrap();
+    assert_eq!(demos.len(), rust_playground::demos().len());
+    let rc_weak = demos.iter().find(|demo| demo["name"] == "rc-weak").unwrap();
+    let arc_mutex = rc_weak["examples"].as_array().unwrap().iter().find(|example| example["name"] == "arc-mutex").unwrap();
+    assert_eq!(arc_mutex["needs"], "threads");
+    assert_eq!(rc_weak["examples"][0]["needs"], Value::Null);
+}
+
+#[test]
+fn unknown_paths_are_errors() {
+    assert!(browser::run_text("gc").is_err());
+}
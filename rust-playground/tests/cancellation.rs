@@ -0,0 +1,48 @@
+// Cancellation: token trees cancel downwards only, the drop guard cancels on
+// every way out, and a timed-out worker is stopped rather than left running.
+
+use std::thread;
+use std::time::Duration;
+
+use rust_playground::cancellation::{self, CancelToken, Outcome};
+
+#[test]
+fn cancelling_a_token_cancels_its_children_not_its_parent() {
+    let root = CancelToken::new();
+    let child = root.child();
+    let grandchild = child.child();
+    let sibling = root.child();
+    child.cancel();
+    assert!(!root.is_cancelled());
+    assert!(child.is_cancelled());
+    assert!(grandchild.is_cancelled());
+    assert!(!sibling.is_cancelled());
+    root.cancel();
+    assert!(sibling.is_cancelled());
+}
+
+#[test]
+fn the_drop_guard_cancels_when_it_goes_out_of_scope() {
+    let token = CancelToken::new();
+    let worker = {
+        let token = token.clone();
+        thread::spawn(move || cancellation::work_until_cancelled(&token))
+    };
+    {
+        let _cancel = token.clone().drop_guard();
+        assert!(!token.is_cancelled());
+    }
+    assert!(token.is_cancelled());
+    worker.join().unwrap();
+}
+
+#[test]
+fn a_timed_out_worker_is_cancelled() {
+    let (outcome, done) = cancellation::with_timeout(5_000, Duration::from_millis(10));
+    assert_eq!(outcome, Outcome::TimedOut);
+    assert!(done < 5_000, "the worker ran to completion: {}", done);
+
+    let (outcome, done) = cancellation::with_timeout(2, Duration::from_secs(5));
+    assert_eq!(outcome, Outcome::Done(2));
+    assert_eq!(done, 2);
+}
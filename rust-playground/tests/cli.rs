@@ -0,0 +1,40 @@
+// The rust-playground binary itself: flags clap must reject before any demo runs,
+// what a whole run writes around the reports, and how it reads stdin.
+
+use std::process::{Command, Output, Stdio};
+
+use rust_playground::render::CSV_HEADER;
+
+// stdin is empty, so a quiz quits at its first question
+fn playground(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_rust-playground")).args(args).stdin(Stdio::null()).output().expect("the binary was built for this test")
+}
+
+#[test]
+fn zero_goroutines_is_rejected_at_parse_time() {
+    let output = playground(&["run", "goroutines-vs-threads", "--count", "0"]);
+    assert_eq!(output.status.code(), Some(2));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("invalid value '0' for '--count <COUNT>'"), "{}", stderr);
+    assert!(output.stdout.is_empty());
+}
+
+#[test]
+fn a_quiz_run_is_still_one_csv_document() {
+    let output = playground(&["run", "ownership", "--quiz", "--format", "csv"]);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(stdout.lines().next(), Some(CSV_HEADER));
+    assert!(stdout.lines().nth(1).is_some_and(|row| row.starts_with("ownership::")), "{}", stdout);
+    assert!(stdout.contains("--- Quiz: ownership"), "{}", stdout);
+}
+
+#[test]
+fn end_of_input_steps_through_every_remaining_demo() {
+    // Empty stdin ends input at the first pause; later demos and the timing summary run on
+    let output = playground(&["--format", "plain", "--step", "run", "--tag", "unsafe"]);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(stdout.matches("[Enter: next step, q: run to the end]").count(), 1, "{}", stdout);
+    assert!(stdout.contains("=== Run Summary - Cost per Demo ==="));
+}
@@ -0,0 +1,55 @@
+// compare-run without building anything: the stats lines the child prints,
+// keeping the fastest run, and the diff table. (Cargo itself isn't run here.)
+
+use rust_playground::compare_run::{self, ExampleStats, Side};
+use rust_playground::copy_vs_clone;
+use rust_playground::render::{Plain, Renderer};
+
+fn stats(example: &str, nanos: u64, allocations: u64) -> ExampleStats {
+    ExampleStats { example: example.into(), nanos, allocations: Some(allocations), bytes: Some(allocations * 64), peak: Some(64) }
+}
+
+#[test]
+fn stats_lines_round_trip() {
+    let line = "compare example=clone-cost ns=41250 allocs=12 bytes=1536 peak=1024";
+    let parsed = compare_run::parse_line(line).unwrap();
+    assert_eq!((parsed.example.as_str(), parsed.nanos), ("clone-cost", 41_250));
+    assert_eq!((parsed.allocations, parsed.bytes, parsed.peak), (Some(12), Some(1536), Some(1024)));
+    assert_eq!(parsed.to_string(), line);
+
+    let output = "   Compiling rust-playground\ncompare example=derive ns=5 allocs=- bytes=- peak=-\n";
+    let parsed = compare_run::parse_output(output);
+    assert_eq!(parsed.len(), 1);
+    assert_eq!(parsed[0].allocations, None);
+}
+
+#[test]
+fn emit_names_each_measured_report() {
+    let reports = copy_vs_clone::DEMO.run_examples(["derive", "vs-go"]);
+    let parsed = compare_run::parse_output(&compare_run::emit(&["derive", "vs-go"], &reports));
+    let names: Vec<&str> = parsed.iter().map(|stats| stats.example.as_str()).collect();
+    assert_eq!(names, ["derive", "vs-go"]);
+    assert!(parsed.iter().all(|stats| stats.nanos > 0));
+}
+
+#[test]
+fn each_example_keeps_its_fastest_run() {
+    let first = vec![stats("a", 100, 1), stats("b", 50, 1)];
+    let second = vec![stats("a", 80, 1), stats("b", 70, 1)];
+    let best = compare_run::fastest(compare_run::fastest(Vec::new(), first), second);
+    assert_eq!(best.iter().map(|stats| stats.nanos).collect::<Vec<_>>(), [80, 50]);
+}
+
+#[test]
+fn diff_shows_the_change_per_example() {
+    let baseline = Side { features: String::new(), release: true };
+    let candidate = Side { features: "parking_lot".into(), release: true };
+    let base = vec![stats("lock", 1000, 10), stats("old-only", 5, 1)];
+    let cand = vec![stats("lock", 750, 12)];
+    let text = Plain.render(&compare_run::diff("demo", (&baseline, &base), (&candidate, &cand)));
+    assert!(text.contains("default vs parking_lot"), "{}", text);
+    let lock = text.lines().find(|line| line.trim_start().starts_with("lock")).unwrap();
+    assert!(lock.contains("-25.0%") && lock.contains("+20.0%"), "{}", lock);
+    assert!(text.contains("baseline only: old-only"), "{}", text);
+    assert!(!text.contains("Debug build"), "{}", text);
+}
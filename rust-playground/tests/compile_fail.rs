@@ -0,0 +1,12 @@
+// Programs the compiler must reject - the "❌ Error" comments in the demos,
+// checked for real. Each .rs has a .stderr with the expected diagnostic.
+// gallery/ holds the two-owners-compile-fail-gallery demo's cases, each
+// with a compiling fix in src/two_owners_compile_fail_gallery.rs.
+// Regenerate after a toolchain change: TRYBUILD=overwrite cargo test --test compile_fail
+
+#[test]
+fn compile_fail() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/compile_fail/*.rs");
+    t.compile_fail("tests/compile_fail/gallery/*.rs");
+}
@@ -0,0 +1,14 @@
+// actor_model::owned-state - the state moved into the actor's thread can't be touched from outside
+
+use std::collections::HashMap;
+use std::thread;
+
+fn main() {
+    let mut tally: HashMap<&str, u64> = HashMap::new();
+    let actor = thread::spawn(move || {
+        tally.insert("apples", 10);
+        tally
+    });
+    tally.insert("pears", 10);
+    actor.join().unwrap();
+}
@@ -0,0 +1,15 @@
+// ownership::move_example - user1 is unusable once ownership moves to user2
+
+#[derive(Debug)]
+struct User {
+    name: String,
+    age: u32,
+}
+
+fn main() {
+    let user1 = User { name: String::from("Bob"), age: 25 };
+    let user2 = user1;  // Ownership MOVES to user2
+
+    println!("{:?}", user2);
+    println!("{:?}", user1);
+}
@@ -0,0 +1,21 @@
+// builder_and_ownership::reuse_and_conditionals - a &mut chain can't outlive the temporary it started from
+
+struct RequestTemplate {
+    headers: Vec<(String, String)>,
+}
+
+impl RequestTemplate {
+    fn new() -> Self {
+        RequestTemplate { headers: Vec::new() }
+    }
+
+    fn header(&mut self, name: &str, value: &str) -> &mut Self {
+        self.headers.push((name.to_string(), value.to_string()));
+        self
+    }
+}
+
+fn main() {
+    let template = RequestTemplate::new().header("Accept", "json");
+    println!("{}", template.headers.len());
+}
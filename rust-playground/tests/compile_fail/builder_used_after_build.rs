@@ -0,0 +1,22 @@
+// builder_and_ownership::reuse_and_conditionals - build(self) consumes the builder
+
+struct RequestBuilder {
+    url: String,
+}
+
+struct Request {
+    url: String,
+}
+
+impl RequestBuilder {
+    fn build(self) -> Request {
+        Request { url: self.url }
+    }
+}
+
+fn main() {
+    let builder = RequestBuilder { url: String::from("https://api.example.com") };
+    let request = builder.build();
+    let again = builder.build();
+    println!("{} {}", request.url, again.url);
+}
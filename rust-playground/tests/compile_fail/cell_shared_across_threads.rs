@@ -0,0 +1,12 @@
+// interior_mutability_zoo::hierarchy - Cell is !Sync, so no other thread may hold &Cell
+
+use std::cell::Cell;
+use std::thread;
+
+fn main() {
+    let hits = Cell::new(0usize);
+    thread::scope(|s| {
+        s.spawn(|| hits.set(hits.get() + 1));
+    });
+    println!("{}", hits.get());
+}
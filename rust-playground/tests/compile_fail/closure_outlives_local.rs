@@ -0,0 +1,10 @@
+// stack_vs_heap_escape::closure_capture - a returned closure can't borrow a local
+
+fn counter() -> impl Fn() -> i32 {
+    let x = 42;
+    || x
+}
+
+fn main() {
+    println!("{}", counter()());
+}
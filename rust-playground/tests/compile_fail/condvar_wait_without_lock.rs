@@ -0,0 +1,11 @@
+// condvar::condvar_vs_go - Condvar::wait needs the MutexGuard, not the Mutex
+
+use std::sync::{Condvar, Mutex};
+
+fn main() {
+    let ready = Mutex::new(false);
+    let cond = Condvar::new();
+    while !*ready.lock().unwrap() {
+        cond.wait(&ready).unwrap();
+    }
+}
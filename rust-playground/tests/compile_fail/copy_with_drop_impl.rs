@@ -0,0 +1,16 @@
+// drop_glue_and_needs_drop::needs-drop - a Copy type can't have a Drop impl (Copy types never have drop glue)
+
+#[derive(Clone, Copy)]
+struct Handle(u32);
+
+impl Drop for Handle {
+    fn drop(&mut self) {
+        println!("closing {}", self.0);
+    }
+}
+
+fn main() {
+    let handle = Handle(3);
+    let copy = handle;
+    println!("{} {}", handle.0, copy.0);
+}
@@ -0,0 +1,17 @@
+// borrow_checker::no_dangling_references - a reference can't outlive its data
+
+#[derive(Debug)]
+struct Data {
+    value: i32,
+}
+
+fn main() {
+    let reference: &Data;
+
+    {
+        let data = Data { value: 42 };
+        reference = &data;
+    }
+
+    println!("{}", reference.value);
+}
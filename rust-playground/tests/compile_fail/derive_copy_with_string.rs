@@ -0,0 +1,13 @@
+// copy_vs_clone::why_not_copy - a bitwise copy of a String would free its buffer twice
+
+#[derive(Clone, Copy)]
+struct Polyline {
+    name: String,
+    points: Vec<(f64, f64)>,
+}
+
+fn main() {
+    let route = Polyline { name: "route".to_string(), points: vec![] };
+    let copy = route;
+    println!("{} {} {}", route.name, copy.name, copy.points.len());
+}
@@ -0,0 +1,18 @@
+// ownership::mutable_borrowing_example - only ONE &mut at a time
+
+#[derive(Debug)]
+struct User {
+    name: String,
+    age: u32,
+}
+
+fn main() {
+    let mut user = User { name: String::from("Diana"), age: 28 };
+
+    let user_ref = &mut user;
+    let ref2 = &mut user;
+
+    user_ref.age = 29;
+    ref2.age = 30;
+    println!("{:?}", user);
+}
@@ -0,0 +1,16 @@
+// phantomdata_variance::drop_check - a Drop impl that reads a borrow must be dropped before the borrowed value
+
+struct Inspector<'a>(&'a String);
+
+impl Drop for Inspector<'_> {
+    fn drop(&mut self) {
+        println!("inspecting {}", self.0);
+    }
+}
+
+fn main() {
+    let inspector;
+    let name = String::from("gopher");
+    inspector = Inspector(&name);
+    let _ = &inspector;
+}
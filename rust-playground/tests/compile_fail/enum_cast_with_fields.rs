@@ -0,0 +1,14 @@
+// enum_memory::discriminants - `as` only casts fieldless enums; Push(u32) carries data
+
+#[allow(dead_code)]
+#[repr(u8)]
+enum Opcode {
+    Nop = 0,
+    Push(u32) = 1,
+    Halt = 0xFF,
+}
+
+fn main() {
+    let tag = Opcode::Push(42) as u8;
+    println!("{}", tag);
+}
@@ -0,0 +1,8 @@
+// closures_and_moves::fn_traits - a closure that consumes its capture is FnOnce
+
+fn main() {
+    let log = vec![String::from("a"), String::from("b")];
+    let drain = move || drop(log);
+    drain();
+    drain();
+}
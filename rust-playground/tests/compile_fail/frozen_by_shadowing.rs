@@ -0,0 +1,9 @@
+// shadowing_and_rebinding::rebinding - `let names = names;` shadows a mut variable with an immutable one
+
+fn main() {
+    let mut names = vec!["gopher", "ferris"];
+    names.sort();
+    let names = names;
+    names.push("crab");
+    println!("{:?}", names);
+}
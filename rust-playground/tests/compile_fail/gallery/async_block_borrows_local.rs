@@ -0,0 +1,13 @@
+// two_owners_compile_fail_gallery::threads-async - a returned future can't borrow the function's locals
+// Fix: fixed::async_block_borrows_local uses `async move` so the future owns the data
+
+use std::future::Future;
+
+fn job() -> impl Future<Output = usize> + 'static {
+    let rows = vec![1, 2, 3];
+    async { rows.len() }  // ❌ E0373: async block may outlive the current function, but it borrows `rows`
+}
+
+fn main() {
+    let _future = job();
+}
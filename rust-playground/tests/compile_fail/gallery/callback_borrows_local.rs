@@ -0,0 +1,15 @@
+// two_owners_compile_fail_gallery::dangling - a stored callback (Box<dyn Fn()>, 'static) borrows a local
+// Fix: fixed::callback_borrows_local moves the String into the closure
+
+struct Button {
+    on_click: Box<dyn Fn() -> String>,
+}
+
+fn make_button() -> Button {
+    let label = String::from("Save");
+    Button { on_click: Box::new(|| format!("clicked {}", label)) }  // ❌ E0597: `label` does not live long enough - Box<dyn Fn()> means Box<dyn Fn() + 'static>
+}
+
+fn main() {
+    println!("{}", (make_button().on_click)());
+}
@@ -0,0 +1,10 @@
+// two_owners_compile_fail_gallery::aliasing - a closure that mutates a variable borrows it until its last call
+// Fix: fixed::closure_holds_mut_borrow reads the count after the closure's last use
+
+fn main() {
+    let mut clicks = 0;
+    let mut click = || clicks += 1;
+    click();
+    println!("clicks so far: {}", clicks);  // ❌ E0502: cannot borrow `clicks` as immutable because it is also borrowed as mutable
+    click();
+}
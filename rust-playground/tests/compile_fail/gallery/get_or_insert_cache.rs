@@ -0,0 +1,21 @@
+// two_owners_compile_fail_gallery::aliasing - returning a borrow from one branch keeps the map borrowed in the other
+// Fix: fixed::get_or_insert_cache uses the entry API (one lookup, one borrow)
+
+use std::collections::HashMap;
+
+fn load(key: u32) -> String {
+    format!("value {}", key)
+}
+
+fn cached(cache: &mut HashMap<u32, String>, key: u32) -> &String {
+    if let Some(value) = cache.get(&key) {
+        return value;
+    }
+    cache.insert(key, load(key));  // ❌ E0502: cannot borrow `*cache` as mutable because it is also borrowed as immutable
+    &cache[&key]
+}
+
+fn main() {
+    let mut cache = HashMap::new();
+    println!("{}", cached(&mut cache, 7));
+}
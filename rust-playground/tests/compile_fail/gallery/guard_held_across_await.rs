@@ -0,0 +1,18 @@
+// two_owners_compile_fail_gallery::threads-async - a MutexGuard held across .await makes the future !Send
+// Fix: fixed::guard_held_across_await drops the guard before awaiting
+
+use std::future::{self, Future};
+use std::sync::{Arc, Mutex};
+
+fn spawn<F: Future + Send + 'static>(future: F) -> F {
+    future
+}
+
+fn main() {
+    let hits = Arc::new(Mutex::new(0));
+    spawn(async move {
+        let mut guard = hits.lock().unwrap();
+        future::ready(()).await;  // ❌ future cannot be sent between threads safely: MutexGuard is held across this await
+        *guard += 1;
+    });
+}
@@ -0,0 +1,24 @@
+// two_owners_compile_fail_gallery::aliasing - a &mut returned by a method keeps all of `self` borrowed
+// Fix: fixed::method_borrows_all_of_self finishes with the &mut before the next call
+
+struct Inventory {
+    items: Vec<(String, u32)>,
+    log: Vec<String>,
+}
+
+impl Inventory {
+    fn largest(&mut self) -> &mut (String, u32) {
+        self.items.iter_mut().max_by_key(|item| item.1).unwrap()
+    }
+
+    fn note(&mut self, message: &str) {
+        self.log.push(message.to_string());
+    }
+}
+
+fn main() {
+    let mut inventory = Inventory { items: vec![("bolts".into(), 40), ("nuts".into(), 90)], log: Vec::new() };
+    let largest = inventory.largest();
+    inventory.note("restocking");  // ❌ E0499: cannot borrow `inventory` as mutable more than once at a time
+    largest.1 += 10;
+}
@@ -0,0 +1,17 @@
+// two_owners_compile_fail_gallery::ownership - unwrap() on a field behind &self would move it out
+// Fix: fixed::move_out_of_borrowed_option borrows through as_deref() and returns a &str
+
+struct Profile {
+    nickname: Option<String>,
+}
+
+impl Profile {
+    fn display_name(&self) -> String {
+        self.nickname.unwrap()  // ❌ E0507: cannot move out of `self.nickname` which is behind a shared reference
+    }
+}
+
+fn main() {
+    let profile = Profile { nickname: Some("ferris".into()) };
+    println!("{}", profile.display_name());
+}
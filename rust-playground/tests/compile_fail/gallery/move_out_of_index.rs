@@ -0,0 +1,8 @@
+// two_owners_compile_fail_gallery::ownership - indexing can't move an element out of a Vec
+// Fix: fixed::move_out_of_index borrows it (&names[0]), or clones / swap_removes
+
+fn main() {
+    let names = vec![String::from("ferris"), String::from("gopher")];
+    let first = names[0];  // ❌ E0507: cannot move out of index of `Vec<String>`
+    println!("{} of {}", first, names.len());
+}
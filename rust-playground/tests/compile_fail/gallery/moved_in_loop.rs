@@ -0,0 +1,13 @@
+// two_owners_compile_fail_gallery::ownership - a value moved in the first iteration is gone in the second
+// Fix: fixed::moved_in_loop lends it to each call (&[String])
+
+fn send(batch: Vec<String>) -> usize {
+    batch.len()
+}
+
+fn main() {
+    let batch = vec!["a".to_string(), "b".to_string()];
+    for _attempt in 0..3 {
+        send(batch);  // ❌ E0382: value moved here, in previous iteration of loop
+    }
+}
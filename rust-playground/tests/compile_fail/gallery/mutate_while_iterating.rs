@@ -0,0 +1,12 @@
+// two_owners_compile_fail_gallery::aliasing - the loop borrows the Vec; pushing to it is iterator invalidation
+// Fix: fixed::mutate_while_iterating collects the new items, then extends
+
+fn main() {
+    let mut queue = vec![1, 2, 3];
+    for job in &queue {
+        if job % 2 == 1 {
+            queue.push(job * 10);  // ❌ E0502: cannot borrow `queue` as mutable because it is also borrowed as immutable
+        }
+    }
+    println!("{:?}", queue);
+}
@@ -0,0 +1,9 @@
+// two_owners_compile_fail_gallery::aliasing - push may reallocate, so no borrow into the Vec may live across it
+// Fix: fixed::push_while_borrowed copies the value out before pushing
+
+fn main() {
+    let mut scores = vec![10, 20, 30];
+    let best = &scores[0];
+    scores.push(40);  // ❌ E0502: cannot borrow `scores` as mutable because it is also borrowed as immutable
+    println!("best was {}", best);
+}
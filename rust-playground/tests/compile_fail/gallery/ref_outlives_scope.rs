@@ -0,0 +1,11 @@
+// two_owners_compile_fail_gallery::dangling - the reference is used after its target's scope ended
+// Fix: fixed::ref_outlives_scope declares the String in the outer scope
+
+fn main() {
+    let latest;
+    {
+        let line = String::from("GET /index.html");
+        latest = &line;  // ❌ E0597: `line` does not live long enough
+    }
+    println!("{}", latest);
+}
@@ -0,0 +1,12 @@
+// two_owners_compile_fail_gallery::aliasing - removing while iterating would shift the elements under the iterator
+// Fix: fixed::remove_in_for_loop uses retain
+
+fn main() {
+    let mut names = vec![String::from("ferris"), String::new(), String::from("gopher")];
+    for (index, name) in names.iter().enumerate() {
+        if name.is_empty() {
+            names.remove(index);  // ❌ E0502: cannot borrow `names` as mutable because it is also borrowed as immutable
+        }
+    }
+    println!("{:?}", names);
+}
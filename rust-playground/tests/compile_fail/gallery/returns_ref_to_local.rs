@@ -0,0 +1,11 @@
+// two_owners_compile_fail_gallery::dangling - a reference to a local can't leave the function
+// Fix: fixed::returns_ref_to_local returns the String itself (ownership moves out)
+
+fn shout(text: &str) -> &str {
+    let upper = text.to_uppercase();
+    &upper  // ❌ E0515: cannot return reference to local variable `upper`
+}
+
+fn main() {
+    println!("{}", shout("hello"));
+}
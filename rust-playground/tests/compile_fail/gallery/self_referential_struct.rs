@@ -0,0 +1,17 @@
+// two_owners_compile_fail_gallery::dangling - a struct can't hold a borrow of its own field
+// Fix: fixed::self_referential_struct stores a range into the text instead of a &str
+
+struct Document<'a> {
+    text: String,
+    title: &'a str,
+}
+
+fn open(text: String) -> Document<'static> {
+    let title = text.lines().next().unwrap_or("");
+    Document { text, title }  // ❌ E0515: the result points into `text` (and E0505: `text` moves while borrowed)
+}
+
+fn main() {
+    let doc = open("Ownership\nbody".into());
+    println!("{} ({} bytes)", doc.title, doc.text.len());
+}
@@ -0,0 +1,14 @@
+// two_owners_compile_fail_gallery::threads-async - several threads can't each hold &mut to one counter
+// Fix: fixed::shared_counter_in_scope uses an AtomicUsize (shared & with safe mutation)
+
+use std::thread;
+
+fn main() {
+    let mut total = 0;
+    thread::scope(|scope| {
+        for _ in 0..4 {
+            scope.spawn(|| total += 1);  // ❌ E0499: cannot borrow `total` as mutable more than once at a time
+        }
+    });
+    println!("{}", total);
+}
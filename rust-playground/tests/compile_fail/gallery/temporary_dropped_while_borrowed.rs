@@ -0,0 +1,11 @@
+// two_owners_compile_fail_gallery::dangling - an iterator over a temporary String outlives it
+// Fix: fixed::temporary_dropped_while_borrowed binds the String to a variable first
+
+fn read_config() -> String {
+    String::from("port=8080 host=localhost")
+}
+
+fn main() {
+    let mut settings = read_config().split(' ');  // ❌ E0716: temporary value dropped while borrowed
+    println!("{:?}", settings.next());
+}
@@ -0,0 +1,10 @@
+// two_owners_compile_fail_gallery::threads-async - a spawned thread may outlive the function's locals
+// Fix: fixed::thread_borrows_local moves the data in (or uses thread::scope)
+
+use std::thread;
+
+fn main() {
+    let lines = vec!["a", "b", "c"];
+    let handle = thread::spawn(|| lines.len());  // ❌ E0373: closure may outlive the current function, but it borrows `lines`
+    println!("{}", handle.join().unwrap());
+}
@@ -0,0 +1,21 @@
+// two_owners_compile_fail_gallery::ownership - one Config handed to two owners
+// Fix: fixed::two_owners shares it through an Arc (or lend it: &Config)
+
+struct Config {
+    port: u16,
+}
+
+struct Server {
+    config: Config,
+}
+
+struct Worker {
+    config: Config,
+}
+
+fn main() {
+    let config = Config { port: 8080 };
+    let server = Server { config };
+    let worker = Worker { config };  // ❌ E0382: use of moved value: `config` - Server already owns it
+    println!("{} {}", server.config.port, worker.config.port);
+}
@@ -0,0 +1,9 @@
+// two_owners_compile_fail_gallery::ownership - push moves the String into the Vec
+// Fix: fixed::used_after_push reads it back through the Vec (or pushes a clone)
+
+fn main() {
+    let mut names = Vec::new();
+    let name = String::from("gopher");
+    names.push(name);
+    println!("added {}", name);  // ❌ E0382: borrow of moved value: `name`
+}
@@ -0,0 +1,10 @@
+// raii_resources::vs-go - drop(guard) moves the guard: unlocking twice doesn't compile
+
+use std::sync::Mutex;
+
+fn main() {
+    let queue = Mutex::new(vec![1]);
+    let guard = queue.lock().unwrap();
+    drop(guard);
+    drop(guard);
+}
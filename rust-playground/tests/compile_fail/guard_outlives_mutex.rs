@@ -0,0 +1,12 @@
+// raii_resources::guard_lifetime - a MutexGuard borrows its Mutex, so it can't outlive it
+
+use std::sync::Mutex;
+
+fn main() {
+    let mut guard;
+    {
+        let queue = Mutex::new(vec![1]);
+        guard = queue.lock().unwrap();
+    }
+    guard.push(2);
+}
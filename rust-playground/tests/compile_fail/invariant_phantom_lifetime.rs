@@ -0,0 +1,14 @@
+// phantomdata_variance::variance - PhantomData<Cell<T>> makes T invariant: no lifetime shortening
+
+use std::cell::Cell;
+use std::marker::PhantomData;
+
+struct Invariant<T>(PhantomData<Cell<T>>);
+
+fn shorten<'a>(x: Invariant<&'static str>) -> Invariant<&'a str> {
+    x
+}
+
+fn main() {
+    let _ = shorten(Invariant(PhantomData));
+}
@@ -0,0 +1,9 @@
+// shadowing_and_rebinding::let_else - the else branch of let-else has to leave the scope
+
+fn main() {
+    let maybe: Option<u32> = None;
+    let Some(x) = maybe else {
+        println!("none");
+    };
+    println!("{}", x);
+}
@@ -0,0 +1,15 @@
+// lifetimes::explicit_lifetimes - 'a is the SHORTER of the two inputs
+
+fn longest<'a>(x: &'a str, y: &'a str) -> &'a str {
+    if x.len() >= y.len() { x } else { y }
+}
+
+fn main() {
+    let string1 = String::from("ownership");
+    let result;
+    {
+        let string2 = String::from("borrow");
+        result = longest(&string1, &string2);
+    }
+    println!("{}", result);
+}
@@ -0,0 +1,10 @@
+// match_ownership::ergonomics - indexing yields a place inside the Vec, which can't be moved from
+
+fn main() {
+    let names = vec![Some(String::from("ferris")), None];
+    let first = match names[0] {
+        Some(name) => name,
+        None => String::new(),
+    };
+    println!("{} {}", first, names.len());
+}
@@ -0,0 +1,13 @@
+// match_ownership::ergonomics - dereferencing the scrutinee asks to move out of a borrow
+
+fn first_name(borrowed: &Option<String>) -> String {
+    match *borrowed {
+        Some(name) => name,
+        None => String::new(),
+    }
+}
+
+fn main() {
+    let nickname = Some(String::from("ferris"));
+    println!("{}", first_name(&nickname));
+}
@@ -0,0 +1,16 @@
+// match_ownership::moves - a by-value binding moves the payload out of the matched value
+
+#[derive(Debug)]
+enum Message {
+    Text(String),
+    Quit,
+}
+
+fn main() {
+    let message = Message::Text(String::from("hello"));
+    let text = match message {
+        Message::Text(text) => text,
+        Message::Quit => String::new(),
+    };
+    println!("{} {:?}", text, message);
+}
@@ -0,0 +1,18 @@
+// match_ownership::match_vs_go - a match has to cover every variant
+
+enum Message {
+    Text(String),
+    Ping(u32),
+    Quit,
+}
+
+fn handle(message: Message) -> String {
+    match message {
+        Message::Text(body) => body,
+        Message::Ping(n) => n.to_string(),
+    }
+}
+
+fn main() {
+    println!("{}", handle(Message::Quit));
+}
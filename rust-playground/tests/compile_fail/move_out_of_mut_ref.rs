@@ -0,0 +1,17 @@
+// mem_swap_take_replace::naive_move - a field can't be moved out through &mut self
+
+struct Batcher {
+    pending: Vec<String>,
+}
+
+impl Batcher {
+    fn flush(&mut self) -> Vec<String> {
+        self.pending
+    }
+}
+
+fn main() {
+    let mut batcher = Batcher { pending: vec![String::from("GET /")] };
+    let batch = batcher.flush();
+    println!("{} {}", batch.len(), batcher.pending.len());
+}
@@ -0,0 +1,15 @@
+// option_vs_nil::nil_panics - an Option<&User> is not a &User until None is handled
+
+struct User {
+    name: String,
+}
+
+fn find<'a>(users: &'a [User], name: &str) -> Option<&'a User> {
+    users.iter().find(|user| user.name == name)
+}
+
+fn main() {
+    let users = vec![User { name: String::from("alice") }];
+    let user = find(&users, "bob");
+    println!("{}", user.name);
+}
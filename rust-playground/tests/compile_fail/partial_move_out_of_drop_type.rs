@@ -0,0 +1,18 @@
+// partial_moves::drop_types - drop() needs the whole value, so fields can't be moved out
+
+struct LogFile {
+    path: String,
+    lines: Vec<String>,
+}
+
+impl Drop for LogFile {
+    fn drop(&mut self) {
+        println!("flushing {} lines to {}", self.lines.len(), self.path);
+    }
+}
+
+fn main() {
+    let log = LogFile { path: String::from("/var/log/app.log"), lines: vec![String::from("started")] };
+    let lines = log.lines;
+    println!("{}", lines.len());
+}
@@ -0,0 +1,19 @@
+// partial_moves::field_move - a &self method needs every field, including the moved one
+
+struct User {
+    id: u32,
+    name: String,
+}
+
+impl User {
+    fn describe(&self) -> String {
+        format!("#{} {}", self.id, self.name)
+    }
+}
+
+fn main() {
+    let user = User { id: 7, name: String::from("gopher") };
+    let name = user.name;
+    println!("{} {}", name, user.id);
+    println!("{}", user.describe());
+}
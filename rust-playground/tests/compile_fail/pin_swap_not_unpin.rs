@@ -0,0 +1,9 @@
+// pin_self_referential::pin_box_example - a pinned !Unpin value can't be swapped out
+
+use rust_playground::pin_self_referential::PinnedParser;
+
+fn main() {
+    let a = PinnedParser::new(b"GET");
+    let b = PinnedParser::new(b"PUT");
+    std::mem::swap(&mut *a, &mut *b);
+}
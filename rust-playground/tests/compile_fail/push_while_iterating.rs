@@ -0,0 +1,8 @@
+// vec_growth::reference_across_push - the loop's borrow of v outlives the push
+
+fn main() {
+    let mut v = vec![1, 2, 3];
+    for x in &v {
+        v.push(*x);
+    }
+}
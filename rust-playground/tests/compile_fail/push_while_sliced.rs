@@ -0,0 +1,9 @@
+// slices_and_strings::slice_borrow_rules - push may reallocate under a live slice
+
+fn main() {
+    let mut v = vec![1, 2, 3];
+    let first_two = &v[..2];
+
+    v.push(4);
+    println!("{:?}", first_two);
+}
@@ -0,0 +1,10 @@
+// send_sync::rc_is_not_send - Rc's refcount isn't atomic, so it can't cross threads
+
+use std::rc::Rc;
+use std::thread;
+
+fn main() {
+    let data = Rc::new(vec![1, 2, 3]);
+    let handle = thread::spawn(move || println!("{:?}", data));
+    handle.join().unwrap();
+}
@@ -0,0 +1,17 @@
+// builder_and_ownership::partial_moves - moving one field out leaves the whole value unusable
+
+struct Request {
+    url: String,
+    body: Option<String>,
+}
+
+fn send(request: Request) -> usize {
+    request.url.len()
+}
+
+fn main() {
+    let request = Request { url: String::from("https://api.example.com"), body: Some(String::from("hello")) };
+    let body = request.body;
+    println!("{} {:?}", request.url, body);
+    send(request);
+}
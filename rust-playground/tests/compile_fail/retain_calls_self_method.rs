@@ -0,0 +1,21 @@
+// retain_split_borrow::retain - a &self method in retain's closure borrows the Vec being filtered
+
+struct Sensor {
+    readings: Vec<i32>,
+    threshold: i32,
+}
+
+impl Sensor {
+    fn keeps(&self, reading: i32) -> bool {
+        reading >= self.threshold
+    }
+
+    fn filter(&mut self) {
+        self.readings.retain(|&reading| self.keeps(reading));
+    }
+}
+
+fn main() {
+    let mut sensor = Sensor { readings: vec![3, 12], threshold: 10 };
+    sensor.filter();
+}
@@ -0,0 +1,10 @@
+// stack_vs_heap_escape::return_pointer_to_local - Go's `return &x` has no Rust equivalent
+
+fn escapes_via_return() -> &'static i32 {
+    let x = 42;
+    &x
+}
+
+fn main() {
+    println!("{}", escapes_via_return());
+}
@@ -0,0 +1,8 @@
+// shadowing_and_rebinding::borrows - without shadowing `first` with an owned copy, the borrow outlives the push
+
+fn main() {
+    let mut users = vec!["ann".to_string()];
+    let first = &users[0];
+    users.push("bob".into());
+    println!("{}", first);
+}
@@ -0,0 +1,15 @@
+// borrow_checker::borrowing_rules - & and &mut can't overlap
+
+struct Data {
+    value: i32,
+}
+
+fn main() {
+    let mut data = Data { value: 42 };
+
+    let r4 = &data;
+    let r5 = &mut data;
+
+    r5.value = 100;
+    println!("{}", r4.value);
+}
@@ -0,0 +1,9 @@
+// scoped_threads::captured_loop_variable - a spawned thread can't borrow the loop variable
+
+use std::thread;
+
+fn main() {
+    for i in 0..3 {
+        thread::spawn(|| println!("{}", i));
+    }
+}
@@ -0,0 +1,25 @@
+// retain_split_borrow::fields - each accessor borrows all of `player`, so two can't coexist
+
+#[derive(Default)]
+struct Player {
+    position: (i32, i32),
+    inventory: Vec<String>,
+}
+
+impl Player {
+    fn position_mut(&mut self) -> &mut (i32, i32) {
+        &mut self.position
+    }
+
+    fn inventory_mut(&mut self) -> &mut Vec<String> {
+        &mut self.inventory
+    }
+}
+
+fn main() {
+    let mut player = Player::default();
+    let position = player.position_mut();
+    let inventory = player.inventory_mut();
+    position.0 += 1;
+    inventory.push("map".to_string());
+}
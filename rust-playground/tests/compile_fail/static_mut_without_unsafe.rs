@@ -0,0 +1,11 @@
+// globals::mutable_statics - Go's mutable package-level var, ported naively
+
+static mut HITS: usize = 0;
+
+fn hit() {
+    HITS += 1;
+}
+
+fn main() {
+    hit();
+}
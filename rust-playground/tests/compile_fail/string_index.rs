@@ -0,0 +1,7 @@
+// string_encoding::byte_indexing - a str has no integer index
+
+fn main() {
+    let s = String::from("héllo");
+    let c = s[1];
+    println!("{}", c);
+}
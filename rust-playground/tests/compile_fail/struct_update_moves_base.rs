@@ -0,0 +1,13 @@
+// partial_moves::struct_update - ..base moves the non-Copy fields it fills in
+
+struct User {
+    id: u32,
+    name: String,
+}
+
+fn main() {
+    let base = User { id: 7, name: String::from("gopher") };
+    let copy = User { id: 8, ..base };
+    println!("{} {}", copy.id, copy.name);
+    println!("{} {}", base.id, base.name);
+}
@@ -0,0 +1,7 @@
+// mem_swap_take_replace::swap - two &mut into one Vec, even to different elements
+
+fn main() {
+    let mut queue = vec!["a", "b", "c", "d"];
+    std::mem::swap(&mut queue[0], &mut queue[3]);
+    println!("{:?}", queue);
+}
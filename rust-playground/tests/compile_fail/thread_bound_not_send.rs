@@ -0,0 +1,11 @@
+// send_sync::not_send_type - PhantomData<*const ()> opts ThreadBound out of Send
+
+use std::thread;
+
+use rust_playground::send_sync::ThreadBound;
+
+fn main() {
+    let bound = ThreadBound::new(7);
+    let handle = thread::spawn(move || bound.id());
+    handle.join().unwrap();
+}
@@ -0,0 +1,12 @@
+// thread_local_storage::cell_and_refcell - a thread-local can't be borrowed past `with`
+
+use std::cell::RefCell;
+
+thread_local! {
+    static LOG: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+}
+
+fn main() {
+    let log: &RefCell<Vec<String>> = LOG.with(|log| log);
+    log.borrow_mut().push(String::from("too late"));
+}
@@ -0,0 +1,8 @@
+// retain_split_borrow::slices - v[i] borrows all of v, even for two different indices
+
+fn main() {
+    let mut scores = vec![10, 20, 30, 40];
+    let first = &mut scores[0];
+    let last = &mut scores[3];
+    *first += *last;
+}
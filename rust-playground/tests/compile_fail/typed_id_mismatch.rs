@@ -0,0 +1,20 @@
+// phantomdata_variance::typed_ids - an Id<User> is not an Id<Order>, though both are a u64
+
+use std::marker::PhantomData;
+
+struct Id<T> {
+    raw: u64,
+    _entity: PhantomData<fn() -> T>,
+}
+
+struct User;
+struct Order;
+
+fn total_cents(order: Id<Order>) -> u64 {
+    order.raw * 100
+}
+
+fn main() {
+    let alice: Id<User> = Id { raw: 1, _entity: PhantomData };
+    println!("{}", total_cents(alice));
+}
@@ -0,0 +1,12 @@
+// unsafe_rust::what_unsafe_allows - unsafe doesn't switch off the borrow checker
+// (rustc even warns that the unsafe block is unnecessary: nothing in it needs it)
+
+fn main() {
+    let mut v = vec![1];
+    unsafe {
+        let a = &mut v;
+        let b = &mut v;
+        a.push(2);
+        b.push(3);
+    }
+}
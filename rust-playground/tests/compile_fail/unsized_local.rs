@@ -0,0 +1,6 @@
+// dst_and_fat_pointers::unsized_types - a str has no size, so it can't be a local
+
+fn main() {
+    let text: str = *"hello";
+    println!("{}", &text);
+}
@@ -0,0 +1,10 @@
+// phantomdata_variance::typed_ids - a type parameter no field mentions is rejected
+
+struct Id<T> {
+    raw: u64,
+}
+
+fn main() {
+    let id: Id<String> = Id { raw: 1 };
+    println!("{}", id.raw);
+}
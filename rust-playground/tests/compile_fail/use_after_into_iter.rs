@@ -0,0 +1,7 @@
+// iterator_adapters::iter_vs_into_iter - into_iter() consumes the Vec
+
+fn main() {
+    let names = vec![String::from("ana"), String::from("bo")];
+    let owned: Vec<String> = names.into_iter().collect();
+    println!("{:?} {:?}", owned, names);
+}
@@ -0,0 +1,15 @@
+// mpsc_pipeline::ownership_transfer - send() moves the job into the channel
+
+use std::sync::mpsc;
+
+struct Job {
+    payload: Vec<u8>,
+}
+
+fn main() {
+    let (tx, rx) = mpsc::channel();
+    let mut job = Job { payload: vec![1; 16] };
+    tx.send(job).unwrap();
+    job.payload[0] = 9;
+    drop(rx);
+}
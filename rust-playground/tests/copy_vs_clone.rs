@@ -0,0 +1,55 @@
+// Copy vs Clone: a clone owns its own buffers, a move keeps the buffer, and
+// what a clone costs per owner.
+
+use rust_playground::alloc_stats;
+use rust_playground::copy_vs_clone::{self, Point, Polyline};
+use rust_playground::report::NoteKind;
+
+#[test]
+fn a_clone_owns_its_own_buffers() {
+    let original = Polyline { name: "route".to_string(), points: vec![Point { x: 0.0, y: 0.0 }; 3] };
+    let mut copy = original.clone();
+    assert_eq!(copy, original);
+    assert_ne!(copy.points.as_ptr(), original.points.as_ptr());
+    assert_ne!(copy.name.as_ptr(), original.name.as_ptr());
+    copy.points[0].x = 9.0;
+    assert_eq!(original.points[0].x, 0.0);
+}
+
+#[test]
+fn a_move_keeps_the_buffer_and_a_copy_keeps_the_source() {
+    let report = copy_vs_clone::implicit_copies();
+    let texts: Vec<&str> = report.texts().collect();
+    assert!(texts.contains(&"Same buffer as s1 had (yes): only ptr, capacity, len were copied"));
+    assert!(texts.iter().any(|text| text.contains("a = Point { x: 1.0, y: 2.0 }") && text.contains("nudged = Point { x: 2.0, y: 2.0 }")));
+}
+
+#[test]
+fn only_the_clone_row_has_its_own_buffers() {
+    let report = copy_vs_clone::why_not_copy();
+    let Some([original, bitwise, clone]) = report.tables().next() else { panic!("expected three rows") };
+    assert_eq!(original[1..], bitwise[1..]);
+    assert_ne!(original[1], clone[1]);
+    assert_ne!(original[2], clone[2]);
+    assert_eq!(report.notes(NoteKind::Fail).count(), 1);
+}
+
+#[test]
+fn clone_cost_counts_allocations_per_owner() {
+    let report = copy_vs_clone::clone_cost();
+    let rows = report.tables().next().unwrap();
+    let allocations = |what: &str| rows.iter().find(|row| row[0] == what).unwrap()[1].parse::<usize>().unwrap();
+    if alloc_stats::enabled() {
+        // Lower bounds: other tests in this binary allocate at the same time
+        assert!(allocations("String (20 chars)") >= 1);
+        assert!(allocations("Vec<String> × 100") >= 101);
+    } else {
+        assert!(rows.iter().all(|row| row[1] == "n/a"));
+    }
+}
+
+#[test]
+fn the_clone_go_would_alias_is_independent() {
+    let report = copy_vs_clone::copy_vs_go();
+    assert!(report.texts().any(|text| text == "p.points[0].x = 0, q.points[0].x = 9"));
+}
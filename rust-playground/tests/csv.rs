@@ -0,0 +1,47 @@
+// --format csv: a header, one row of costs per measured example, fields
+// quoted only when they have to be.
+
+use std::time::Duration;
+
+use rust_playground::copy_vs_clone;
+use rust_playground::render::{self, Csv, Renderer, CSV_HEADER};
+use rust_playground::report::DemoReport;
+
+fn document(reports: &[DemoReport]) -> String {
+    let mut out = String::new();
+    Csv.begin(&mut out).unwrap();
+    out.push_str(&Csv.render_all(reports));
+    out
+}
+
+#[test]
+fn one_row_per_example_named_demo_and_example() {
+    let reports = copy_vs_clone::DEMO.run_examples(["derive", "vs-go"]);
+    let text = document(&reports);
+    let lines: Vec<&str> = text.lines().collect();
+    assert_eq!(lines[0], CSV_HEADER);
+    assert_eq!(lines.len(), 3, "{}", text);
+    for (line, example) in lines[1..].iter().zip(["derive", "vs-go"]) {
+        let cells: Vec<&str> = line.split(',').collect();
+        assert_eq!(cells.len(), 5, "{}", line);
+        assert_eq!(cells[0], format!("copy-vs-clone::{}", example));
+        assert!(cells[1].parse::<u64>().unwrap() > 0);
+        assert_eq!(cells[2].is_empty(), !cfg!(feature = "alloc-stats"), "{}", line);
+        assert_eq!(cells[4].is_empty(), !rust_playground::process_stats::supported(), "{}", line);
+    }
+}
+
+#[test]
+fn unmeasured_reports_have_no_row() {
+    let mut measured = DemoReport::new("Timed, \"quoted\"");
+    measured.elapsed = Some(Duration::from_nanos(1500));
+    let text = document(&[DemoReport::new("Run Summary"), measured]);
+    assert_eq!(text, format!("{}\n\"Timed, \"\"quoted\"\"\",1500,,,\n", CSV_HEADER));
+}
+
+#[test]
+fn fields_are_quoted_only_when_needed() {
+    assert_eq!(render::csv_field("vec-growth::doubling"), "vec-growth::doubling");
+    assert_eq!(render::csv_field("a,b"), "\"a,b\"");
+    assert_eq!(render::csv_field("line\nbreak"), "\"line\nbreak\"");
+}
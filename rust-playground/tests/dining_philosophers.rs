@@ -0,0 +1,25 @@
+// Dining philosophers: every design feeds everyone, and the deadlock is only
+// staged when asked for.
+
+use rust_playground::dining_philosophers::{self, DESIGNS, PHILOSOPHERS};
+use rust_playground::report::{DemoReport, Entry, NoteKind};
+
+#[test]
+fn every_design_feeds_everyone() {
+    for design in DESIGNS {
+        let dinner = (design.dine)(50);
+        assert_eq!(dinner.meals, PHILOSOPHERS * 50);
+        assert_eq!(dinner.fork_uses, vec![100; PHILOSOPHERS]);
+        assert!(dinner.fair(50), "{}", design.name);
+    }
+}
+
+#[test]
+fn the_watchdog_catches_the_deadlock() {
+    let fails = |report: &DemoReport| report.steps.iter().flat_map(|step| &step.entries).filter(|entry| matches!(entry, Entry::Note(NoteKind::Fail, _))).count();
+    assert_eq!(fails(&dining_philosophers::deadlock()), 0);
+    dining_philosophers::set_deadlock(true);
+    let report = dining_philosophers::deadlock();
+    dining_philosophers::set_deadlock(false);
+    assert_eq!(fails(&report), 1);
+}
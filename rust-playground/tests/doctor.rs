@@ -0,0 +1,35 @@
+// The doctor's feature table has to match Cargo.toml, or it gives wrong advice.
+
+use std::fs;
+use std::path::Path;
+
+use rust_playground::doctor::{self, FEATURES};
+
+// Names declared under [features], `default` aside
+fn manifest_features() -> Vec<String> {
+    let manifest = fs::read_to_string(Path::new(env!("CARGO_MANIFEST_DIR")).join("Cargo.toml")).unwrap();
+    manifest
+        .lines()
+        .skip_while(|line| *line != "[features]")
+        .skip(1)
+        .take_while(|line| !line.starts_with('['))
+        .filter_map(|line| line.split_once(" = ").map(|(name, _)| name.trim().to_string()))
+        .filter(|name| name != "default")
+        .collect()
+}
+
+#[test]
+fn every_cargo_feature_is_checked() {
+    let mut declared = manifest_features();
+    let mut checked: Vec<String> = FEATURES.iter().map(|feature| feature.name.to_string()).collect();
+    declared.sort();
+    checked.sort();
+    assert_eq!(checked, declared);
+}
+
+#[test]
+fn the_allocator_hook_is_detected() {
+    assert_eq!(doctor::allocator_hook_works().is_some(), cfg!(feature = "alloc-stats"));
+    let enabled = FEATURES.iter().find(|feature| feature.name == "alloc-stats").unwrap().enabled;
+    assert_eq!(enabled, cfg!(feature = "alloc-stats"));
+}
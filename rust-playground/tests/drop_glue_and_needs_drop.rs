@@ -0,0 +1,74 @@
+// Drop glue: what needs_drop answers, the glue running per element, and
+// clear_in_place skipping the loop for plain data - and the same answers in
+// the demo's own table and counts.
+
+use rust_playground::drop_glue_and_needs_drop::{self, Counted, Pair, Point};
+use rust_playground::report::Entry;
+
+fn needs_drop(name: &str) -> bool {
+    drop_glue_and_needs_drop::glue_table().into_iter().find(|row| row.name == name).unwrap().needs_drop
+}
+
+#[test]
+fn glue_follows_the_fields() {
+    assert!(!needs_drop("u8"));
+    assert!(!needs_drop("Point"));
+    assert!(!needs_drop("ManuallyDrop<String>"));
+    assert!(needs_drop("String"));
+    assert!(needs_drop("Pair"));
+    assert!(needs_drop("(u32, String)"));
+}
+
+#[test]
+fn clear_in_place_runs_glue_only_when_needed() {
+    let mut points = vec![Point { x: 0.0, y: 0.0 }; 10];
+    assert_eq!(drop_glue_and_needs_drop::clear_in_place(&mut points), 0);
+    assert!(points.is_empty());
+
+    let before = drop_glue_and_needs_drop::drops();
+    let mut pairs: Vec<Pair> = (0..10).map(|id| Pair { left: Counted, right: Counted, id }).collect();
+    assert_eq!(drop_glue_and_needs_drop::clear_in_place(&mut pairs), 10);
+    assert_eq!(drop_glue_and_needs_drop::drops() - before, 20);
+    assert!(pairs.is_empty());
+}
+
+#[test]
+fn clearing_strings_frees_each_buffer() {
+    let run = drop_glue_and_needs_drop::time_clear("strings", || vec!["abc".to_string(); 100]);
+    assert!(run.needs_drop);
+    if let Some(frees) = run.frees {
+        assert_eq!(frees, 100);
+    }
+    let run = drop_glue_and_needs_drop::time_clear("bytes", || vec![0u8; 100]);
+    assert!(!run.needs_drop);
+    assert_eq!(run.frees.unwrap_or(0), 0);
+}
+
+#[test]
+fn the_report_answers_needs_drop_per_type() {
+    let report = drop_glue_and_needs_drop::needs_drop();
+    let rows = report.tables().next().unwrap();
+    let answer = |name: &str| rows.iter().find(|row| row[0] == name).unwrap_or_else(|| panic!("no row {}", name))[1].as_str();
+    for (name, expected) in [("u8", "false"), ("[u64; 1024]", "false"), ("Point", "false"), ("&String", "false"), ("ManuallyDrop<String>", "false"), ("String", "true"), ("Option<Box<u8>>", "true"), ("Counted", "true"), ("Pair", "true")] {
+        assert_eq!(answer(name), expected, "{}", name);
+    }
+}
+
+#[test]
+fn generated_glue_drops_each_field_once() {
+    let report = drop_glue_and_needs_drop::generated_glue();
+    let counts: Vec<(&str, usize)> = report.entries().filter_map(|entry| match entry {
+        Entry::Count { label, value } => Some((label.as_str(), *value)),
+        _ => None,
+    }).collect();
+    assert_eq!(
+        counts,
+        [
+            ("Counted drops when a Pair went out of scope", 2),
+            ("drops at the end of the block (right only)", 1),
+            ("drops after drop(kept)", 2),
+            ("elements clear_in_place ran glue for", 1_000),
+            ("Counted drops", 2_000),
+        ]
+    );
+}
@@ -0,0 +1,40 @@
+// Enum memory: both state machines agree, and the niches the demo prints are
+// the ones this compiler uses.
+
+use std::mem::size_of;
+
+use rust_playground::enum_memory::{self, Conn, Event, Opcode};
+
+#[test]
+fn both_state_machines_flush_the_same_bytes() {
+    let events = enum_memory::events(10_000);
+    assert_eq!(enum_memory::run_enum(&events), enum_memory::run_dyn(&events));
+    assert!(enum_memory::run_enum(&events) > 0);
+}
+
+#[test]
+fn flushing_delivers_what_was_buffered() {
+    let mut flushed = 0;
+    let events = [Event::Open, Event::Data(3), Event::Data(4), Event::Flush, Event::Close];
+    let state = events.iter().fold(Conn::Idle, |state, &event| state.on(event, &mut flushed));
+    assert!(matches!(state, Conn::Closed));
+    assert_eq!(flushed, 7);
+    assert_eq!(enum_memory::run_dyn(&events), 7);
+}
+
+#[test]
+fn repr_u8_tags_are_the_declared_values() {
+    assert_eq!(Opcode::Nop.tag(), 0);
+    assert_eq!(Opcode::Push(42).tag(), 1);
+    assert_eq!(Opcode::Jump { offset: -3 }.tag(), 7);
+    assert_eq!(Opcode::Halt.tag(), 0xFF);
+}
+
+#[test]
+fn niches_make_option_free() {
+    assert_eq!(size_of::<Option<bool>>(), 1);
+    assert_eq!(size_of::<Option<char>>(), 4);
+    assert_eq!(size_of::<Option<&u64>>(), size_of::<&u64>());
+    assert_eq!(size_of::<Option<Vec<u8>>>(), size_of::<Vec<u8>>());
+    assert_eq!(size_of::<Conn>(), 8);
+}
@@ -0,0 +1,56 @@
+// Every exercise must start broken and have a solution that passes - checked
+// with the same rustc-driven `check` that `exercise next` uses.
+
+use std::fs;
+
+use rust_playground::exercises::{self, EXERCISES, NOT_DONE_MARKER, Outcome, Progress};
+use rust_playground::registry::DemoRegistry;
+
+#[test]
+fn exercises_start_broken() {
+    for exercise in EXERCISES {
+        let source = fs::read_to_string(exercise.path()).unwrap();
+        assert!(source.contains(NOT_DONE_MARKER), "{} has no marker", exercise.name);
+        let outcome = exercises::check(&exercise.path()).unwrap();
+        assert!(matches!(outcome, Outcome::CompileError(_)), "{} compiles as shipped: {:?}", exercise.name, outcome);
+    }
+}
+
+#[test]
+fn solutions_pass() {
+    for exercise in EXERCISES {
+        assert_eq!(exercises::check(&exercise.solution()).unwrap(), Outcome::Done, "{}", exercise.name);
+    }
+}
+
+#[test]
+fn marker_keeps_a_fixed_exercise_open() {
+    let exercise = &EXERCISES[0];
+    let path = std::env::temp_dir().join(format!("rust-playground-marker-{}.rs", std::process::id()));
+    let solution = fs::read_to_string(exercise.solution()).unwrap();
+    fs::write(&path, format!("{}\n{}", NOT_DONE_MARKER, solution)).unwrap();
+    let outcome = exercises::check(&path);
+    fs::remove_file(&path).unwrap();
+    assert_eq!(outcome.unwrap(), Outcome::MarkerLeft);
+}
+
+#[test]
+fn progress_survives_a_reload() {
+    let path = std::env::temp_dir().join(format!("rust-playground-progress-{}", std::process::id()));
+    let mut progress = Progress::load_from(path.clone()).unwrap();
+    assert_eq!(progress.current().unwrap().name, EXERCISES[0].name);
+    progress.complete(&EXERCISES[0]).unwrap();
+
+    let reloaded = Progress::load_from(path.clone()).unwrap();
+    assert_eq!(reloaded.finished(), 1);
+    assert_eq!(reloaded.current().unwrap().name, EXERCISES[1].name);
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn related_demos_exist() {
+    let registry = DemoRegistry::builtin();
+    for exercise in EXERCISES {
+        assert!(registry.get(exercise.demo).is_some(), "{}: no demo `{}`", exercise.name, exercise.demo);
+    }
+}
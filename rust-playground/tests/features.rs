@@ -0,0 +1,46 @@
+// The feature matrix: one capability per Cargo feature, a self-test exactly
+// when the feature is compiled in, and every self-test passing in this build.
+// Run with --all-features too: that's when the self-tests really run.
+
+use rust_playground::doctor::FEATURES;
+use rust_playground::features::{self, CAPABILITIES};
+use rust_playground::registry::DemoRegistry;
+use rust_playground::report::NoteKind;
+
+#[test]
+fn every_feature_has_a_capability() {
+    let mut features: Vec<&str> = FEATURES.iter().map(|feature| feature.name).collect();
+    let mut capabilities: Vec<&str> = CAPABILITIES.iter().map(|capability| capability.feature).collect();
+    features.sort();
+    capabilities.sort();
+    assert_eq!(capabilities, features);
+}
+
+#[test]
+fn self_tests_run_exactly_for_compiled_in_features() {
+    for feature in FEATURES {
+        let result = (features::capability(feature.name).unwrap().self_test)();
+        assert_eq!(result.is_some(), feature.enabled, "{}", feature.name);
+        if let Some(Err(problem)) = result {
+            panic!("{}: {}", feature.name, problem);
+        }
+    }
+}
+
+#[test]
+fn feature_demos_are_registered_only_with_the_feature() {
+    let registry = DemoRegistry::builtin();
+    for feature in FEATURES {
+        for demo in features::capability(feature.name).unwrap().demos {
+            assert_eq!(registry.get(demo).is_some(), feature.enabled, "{} ({})", demo, feature.name);
+        }
+    }
+}
+
+#[test]
+fn the_matrix_reports_no_failures() {
+    let report = features::matrix();
+    assert_eq!(report.notes(NoteKind::Fail).count(), 0);
+    let all_on = FEATURES.iter().all(|feature| feature.enabled);
+    assert_eq!(report.steps.iter().any(|step| step.heading.as_deref() == Some("How to enable the rest")), !all_on);
+}
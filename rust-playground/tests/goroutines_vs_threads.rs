@@ -0,0 +1,25 @@
+// Spawn costs: every requested thread or task is started and counted.
+
+use rust_playground::goroutines_vs_threads::{measure_async_tasks, measure_threads, thread_spawn_example};
+use rust_playground::report::NoteKind;
+
+#[test]
+fn every_thread_is_spawned_and_released() {
+    let cost = measure_threads(16);
+    assert_eq!(cost.count, 16);
+    assert!(cost.spawn_error.is_none(), "{:?}", cost.spawn_error);
+}
+
+#[test]
+fn every_task_is_parked_once() {
+    let cost = measure_async_tasks(16);
+    assert_eq!(cost.count, 16);
+    assert!(cost.reserved_per_unit > 0);
+}
+
+#[test]
+fn the_report_counts_the_spawned_threads() {
+    let report = thread_spawn_example(8);
+    assert_eq!(report.count_of("Spawned"), Some(8));
+    assert!(!report.notes(NoteKind::Warn).any(|note| note.starts_with("Spawning stopped")));
+}
@@ -0,0 +1,32 @@
+// Ownership graphs: counts come from the live Rc values, edges render as DOT.
+
+use rust_playground::graph::{self, EdgeKind};
+
+#[test]
+fn tree_has_strong_children_and_weak_parents() {
+    let tree = graph::tree();
+    let strong = tree.edges().iter().filter(|edge| edge.kind == EdgeKind::Strong).count();
+    let weak = tree.edges().iter().filter(|edge| edge.kind == EdgeKind::Weak).count();
+    assert_eq!((strong, weak), (3, 2));  // branch variable + two children; two parent pointers
+
+    let branch = tree.nodes().iter().find(|node| node.label == "TreeNode 5").expect("the branch");
+    assert_eq!(branch.counts, Some((1, 2)));
+}
+
+#[test]
+fn cycle_counts_each_node_twice() {
+    let cycle = graph::cycle();
+    let counts: Vec<_> = cycle.nodes().iter().filter_map(|node| node.counts).collect();
+    assert_eq!(counts, [(2, 0), (2, 0)]);
+    assert!(cycle.to_dot().contains("strong 1 and 1"));
+}
+
+#[test]
+fn every_graph_renders_as_a_digraph() {
+    for named in graph::GRAPHS {
+        let dot = graph::build(named.name).expect("listed").to_dot();
+        assert!(dot.starts_with("digraph ownership {"), "{}", named.name);
+        assert!(dot.trim_end().ends_with('}'), "{}", named.name);
+    }
+    assert!(graph::build("no-such-graph").is_none());
+}
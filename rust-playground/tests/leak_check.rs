@@ -0,0 +1,54 @@
+// "Freed at the end of scope", checked by the counting allocator:
+// every demo below must leave nothing live except what it leaks on purpose
+// (whole demos about a leak, or leaks a demo declares with leak_on_purpose).
+// Run with: cargo test --features alloc-stats --test leak_check
+#![cfg(feature = "alloc-stats")]
+
+use rust_playground::leak_check::{self, LeakPolicy};
+use rust_playground::report::{DemoReport, Entry, NoteKind};
+use rust_playground::{
+    borrow_checker, cow, defer_vs_drop, drop_order, error_handling, layout, lifetimes,
+    ownership, rc_weak, scoped_threads, slices_and_strings, smart_pointers, static_lifetime_and_leak,
+    traits_vs_interfaces,
+};
+
+// Demos whose whole point is a leak
+const LEAKS_ON_PURPOSE: [&str; 1] = ["ManuallyDrop - Opting Out of Drop"];
+
+// One test: the allocator's counters are process-wide
+#[test]
+fn demos_free_what_they_allocate() {
+    leak_check::enable(LeakPolicy::Warn);
+
+    let modules: [fn() -> Vec<DemoReport>; 15] = [
+        ownership::demonstrate_ownership,
+        borrow_checker::demonstrate_borrow_checker,
+        drop_order::demonstrate_drop_order,
+        rc_weak::demonstrate_rc,
+        lifetimes::demonstrate_lifetimes,
+        slices_and_strings::demonstrate_slices_and_strings,
+        layout::demonstrate_layout,
+        cow::demonstrate_cow,
+        error_handling::demonstrate_error_handling,
+        defer_vs_drop::demonstrate_defer_vs_drop,
+        scoped_threads::demonstrate_scoped_threads,
+        smart_pointers::demonstrate_smart_pointers,
+        traits_vs_interfaces::demonstrate_traits_vs_interfaces,
+        leak_check::demonstrate_leak_check,
+        static_lifetime_and_leak::demonstrate_static_lifetime_and_leak,
+    ];
+    for report in modules.into_iter().flat_map(|demonstrate| demonstrate()) {
+        let on_purpose: usize = report.leaked_on_purpose.iter().map(|(_, bytes)| bytes).sum();
+        let leaked = report.leaked_bytes.expect("leak check is enabled").saturating_sub(on_purpose);
+        if LEAKS_ON_PURPOSE.contains(&report.title.as_str()) {
+            assert!(leaked > 0, "{}: expected the checker to catch a leak", report.title);
+        } else {
+            assert_eq!(leaked, 0, "{}: memory still live after the demo", report.title);
+        }
+    }
+
+    // A declared leak is listed in the check, not flagged
+    let report = DemoReport::measured(static_lifetime_and_leak::box_leak);
+    assert!(report.leaked_on_purpose.iter().all(|&(_, bytes)| bytes > 0));
+    assert!(!report.entries().any(|entry| matches!(entry, Entry::Note(NoteKind::Warn, text) if text.contains("still live"))));
+}
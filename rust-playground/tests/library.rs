@@ -0,0 +1,29 @@
+// The library facade: discovery and running by name, no CLI involved.
+
+use rust_playground::UnknownDemo;
+
+#[test]
+fn demos_lists_the_registry() {
+    let names: Vec<_> = rust_playground::demos().iter().map(|demo| demo.name).collect();
+    assert!(names.contains(&"rc-weak"));
+    assert!(names.contains(&"ownership"));
+}
+
+#[test]
+fn run_accepts_demo_and_example_paths() {
+    let demo = rust_playground::find("rc-weak").unwrap().0;
+    assert_eq!(rust_playground::run("rc-weak").unwrap().len(), demo.examples.len());
+
+    let reports = rust_playground::run("rc_weak::arc_mutex").unwrap();
+    assert_eq!(reports.len(), 1);
+    assert_eq!(reports[0].title, "Arc<Mutex<T>> - Thread-Safe Rc<RefCell<T>>");
+}
+
+#[test]
+fn unknown_names_are_errors() {
+    assert_eq!(rust_playground::run("gc").unwrap_err(), UnknownDemo::Demo("gc".into()));
+    assert_eq!(
+        rust_playground::run("rc-weak::finalizer").unwrap_err(),
+        UnknownDemo::Example { demo: "rc-weak", example: "finalizer".into() }
+    );
+}
@@ -0,0 +1,61 @@
+// The address-space picture: reading /proc/self/maps lines, which region an
+// address falls in, and the diagram's order and offsets.
+
+use rust_playground::memviz::{self, AddressSpace, Mapping, Region};
+use rust_playground::report::{DemoReport, Entry};
+
+const MAPS: &str = "\
+5637b1e87000-5637b1e89000 r--p 00000000 fe:00 317563                     /usr/bin/rust-playground
+5637ec6f1000-5637ec712000 rw-p 00000000 00:00 0                          [heap]
+7f5afdaf2000-7f5afdb17000 rw-p 00000000 00:00 0
+7f5afdd09000-7f5afdd0b000 r-xp 00000000 00:00 0                          [vdso]
+7ffd042eb000-7ffd0430c000 rw-p 00000000 00:00 0                          [stack]
+";
+
+#[test]
+fn maps_lines_become_regions() {
+    let mappings = memviz::parse_maps(MAPS);
+    let regions: Vec<Region> = mappings.iter().map(|mapping| mapping.region).collect();
+    assert_eq!(regions, [Region::Static, Region::Heap, Region::Mapped, Region::Unknown, Region::Stack]);
+    assert_eq!(mappings[1], Mapping { start: 0x5637ec6f1000, end: 0x5637ec712000, region: Region::Heap });
+}
+
+#[test]
+fn an_anonymous_mapping_holding_our_stack_is_the_stack() {
+    let space = AddressSpace { mappings: memviz::parse_maps(MAPS), stack_hint: 0x7f5afdb00000 };
+    assert_eq!(space.region(0x7f5afdb10000), Region::Stack);
+    assert_eq!(space.region(0x5637ec700000), Region::Heap);
+    assert_eq!(space.region(0x1000), Region::Unknown);
+
+    let no_maps = AddressSpace { mappings: Vec::new(), stack_hint: 0x7ffd04300000 };
+    assert_eq!(no_maps.region(0x7ffd04300040), Region::Stack);
+    assert_eq!(no_maps.region(0x5637ec700000), Region::Unknown);
+}
+
+#[test]
+fn diagram_goes_from_high_to_low_addresses() {
+    let space = AddressSpace { mappings: memviz::parse_maps(MAPS), stack_hint: 0x7ffd04300000 };
+    let mut report = DemoReport::new("addresses");
+    let (local, buffer, literal) = (0x7ffd04300010usize, 0x5637ec700020usize, 0x5637b1e88000usize);
+    for (label, addr) in [("buffer", buffer), ("local", local), ("literal", literal), ("buffer + 8", buffer + 8)] {
+        report.steps[0].entries.push(Entry::Address { label: label.into(), addr, value: String::new() });
+    }
+    let lines = memviz::diagram(&report, &space);
+    let order: Vec<&str> = lines.iter().filter(|line| line.starts_with("+-- ") && !line.contains("apart")).map(|line| line.split_whitespace().nth(1).unwrap()).collect();
+    assert_eq!(order, ["stack", "heap", "static"], "{:#?}", lines);
+    assert!(lines.iter().any(|line| line.contains("+8") && line.ends_with("buffer + 8")), "{:#?}", lines);
+    assert!(lines.iter().any(|line| line.contains("TiB apart")), "{:#?}", lines);
+    assert!(memviz::diagram(&DemoReport::new("none"), &space).is_empty());
+}
+
+#[test]
+fn a_local_and_a_box_land_in_different_regions() {
+    let local = 7u64;
+    let boxed = Box::new(7u64);
+    let space = AddressSpace::current();
+    if space.mappings.is_empty() {
+        return;  // No /proc/self/maps here
+    }
+    assert_eq!(space.region(&local as *const u64 as usize), Region::Stack);
+    assert_ne!(space.region(&*boxed as *const u64 as usize), Region::Stack);
+}
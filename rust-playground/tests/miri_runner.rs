@@ -0,0 +1,54 @@
+// The Miri pack, checked: every examples/miri/*.rs names the diagnostic it
+// must produce on its `// Expected:` line, and this runs each one under
+// `cargo +nightly miri run` and looks for it on stderr.
+//
+// Ignored by default - it needs a nightly toolchain with Miri:
+//   rustup +nightly component add miri && cargo +nightly miri setup
+//   cargo test --test miri_runner -- --ignored
+
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+// (example name, file)
+fn pack() -> Vec<(String, String)> {
+    let mut examples: Vec<(String, String)> = fs::read_dir(Path::new(env!("CARGO_MANIFEST_DIR")).join("examples/miri"))
+        .expect("examples/miri exists")
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|extension| extension == "rs"))
+        .map(|path| (format!("miri_{}", path.file_stem().unwrap().to_string_lossy()), fs::read_to_string(&path).unwrap()))
+        .collect();
+    examples.sort();
+    examples
+}
+
+fn expected(source: &str) -> Option<&str> {
+    source.lines().find_map(|line| line.strip_prefix("// Expected: ")).map(str::trim)
+}
+
+#[test]
+fn every_example_documents_its_diagnostic() {
+    let manifest = fs::read_to_string(Path::new(env!("CARGO_MANIFEST_DIR")).join("Cargo.toml")).unwrap();
+    for (name, source) in pack() {
+        assert!(expected(&source).is_some(), "{} has no `// Expected:` line", name);
+        assert!(source.contains(&format!("--example {}", name)), "{}'s Run: line names another example", name);
+        assert!(manifest.contains(&format!("name = \"{}\"", name)), "{} isn't an [[example]] in Cargo.toml", name);
+    }
+}
+
+#[test]
+#[ignore = "needs nightly with Miri: rustup +nightly component add miri"]
+fn miri_reports_the_documented_undefined_behavior() {
+    for (name, source) in pack() {
+        let expected = expected(&source).unwrap();
+        let output = Command::new("cargo")
+            .args(["+nightly", "miri", "run", "-q", "--example", &name])
+            .current_dir(env!("CARGO_MANIFEST_DIR"))
+            .output()
+            .expect("cargo is on PATH");
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert!(!output.status.success(), "{} ran clean under Miri", name);
+        assert!(stderr.contains("Undefined Behavior"), "{}: Miri failed for another reason:\n{}", name, stderr);
+        assert!(stderr.contains(expected), "{}: expected {:?} in:\n{}", name, expected, stderr);
+    }
+}
@@ -0,0 +1,109 @@
+// The demos' claims as properties: proptest generates the clone/drop
+// sequences and thread counts, and shrinks any failure to the smallest one.
+
+use std::cell::Cell;
+use std::rc::{Rc, Weak};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use proptest::prelude::*;
+use rust_playground::stress;
+
+/// One step on a pool of Rc handles: clone handle i, or drop it
+#[derive(Debug, Clone)]
+enum Op {
+    Clone(usize),
+    Drop(usize),
+}
+
+fn ops() -> impl Strategy<Value = Vec<Op>> {
+    prop::collection::vec(prop_oneof![any::<usize>().prop_map(Op::Clone), any::<usize>().prop_map(Op::Drop)], 0..64)
+}
+
+// Counts its own drops, so "freed exactly once" is checkable
+struct Tracked<'a>(&'a Cell<usize>);
+
+impl Drop for Tracked<'_> {
+    fn drop(&mut self) {
+        self.0.set(self.0.get() + 1);
+    }
+}
+
+proptest! {
+    // rc_weak::rc_example - strong_count is 1 + clones - drops
+    #[test]
+    fn strong_count_is_clones_minus_drops(ops in ops()) {
+        let drops = Cell::new(0);
+        let mut handles = vec![Rc::new(Tracked(&drops))];
+        let (mut clones, mut dropped) = (0usize, 0usize);
+        for op in ops {
+            match op {
+                Op::Clone(i) if !handles.is_empty() => {
+                    let handle = Rc::clone(&handles[i % handles.len()]);
+                    handles.push(handle);
+                    clones += 1;
+                }
+                Op::Drop(i) if handles.len() > 1 => {
+                    let index = i % handles.len();
+                    drop(handles.swap_remove(index));
+                    dropped += 1;
+                }
+                _ => {}
+            }
+            prop_assert_eq!(Rc::strong_count(&handles[0]), 1 + clones - dropped);
+            prop_assert_eq!(drops.get(), 0);
+        }
+        drop(handles);
+        prop_assert_eq!(drops.get(), 1);
+    }
+
+    // rc_weak::weak_example - Weak never keeps the value alive
+    #[test]
+    fn weak_upgrade_fails_once_every_strong_is_gone(strong in 1usize..16, weak in 1usize..16) {
+        let first = Rc::new(String::from("config"));
+        let strongs: Vec<Rc<String>> = (1..strong).map(|_| Rc::clone(&first)).collect();
+        let weaks: Vec<Weak<String>> = (0..weak).map(|_| Rc::downgrade(&first)).collect();
+        prop_assert_eq!(Rc::strong_count(&first), strong);
+        prop_assert_eq!(Rc::weak_count(&first), weak);
+        prop_assert!(weaks.iter().all(|weak| weak.upgrade().is_some()));
+
+        drop(strongs);
+        prop_assert!(weaks.iter().all(|weak| weak.upgrade().is_some()));
+        drop(first);
+        prop_assert!(weaks.iter().all(|weak| weak.upgrade().is_none()));
+        prop_assert!(weaks.iter().all(|weak| weak.strong_count() == 0));
+    }
+}
+
+proptest! {
+    // Threads are slow to spawn; fewer cases still cover the range
+    #![proptest_config(ProptestConfig::with_cases(32))]
+
+    // rc_weak::arc_mutex_example - no increment is lost
+    #[test]
+    fn arc_mutex_counter_sees_every_increment(threads in 1usize..8, iterations in 0usize..500) {
+        let counter = Arc::new(Mutex::new(0usize));
+        let handles: Vec<_> = (0..threads)
+            .map(|_| {
+                let counter = Arc::clone(&counter);
+                thread::spawn(move || {
+                    for _ in 0..iterations {
+                        *counter.lock().unwrap() += 1;
+                    }
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+        prop_assert_eq!(*counter.lock().unwrap(), threads * iterations);
+        prop_assert_eq!(Arc::strong_count(&counter), 1);
+    }
+
+    // stress - synchronized workloads lose nothing, whatever the shape
+    #[test]
+    fn synchronized_stress_workloads_lose_nothing(threads in 1usize..8, iterations in 0usize..500) {
+        prop_assert_eq!(stress::shared_vec(threads, iterations).lost(), 0);
+        prop_assert_eq!(stress::compare_exchange_loop(threads, iterations).lost(), 0);
+    }
+}
@@ -0,0 +1,98 @@
+// --quiz: data/quiz.txt parses and names real demos, and a scripted player's
+// answers are checked, re-asked, skipped and scored.
+
+use std::io::Cursor;
+
+use rust_playground::quiz::{self, Answer, Quiz};
+use rust_playground::registry::DemoRegistry;
+
+const RETRY: &str = "(answer with a letter or number)";
+
+// The letter of a question's right answer, and one that's wrong
+fn right(question: &quiz::Question) -> char {
+    (b'a' + question.answer as u8) as char
+}
+
+fn wrong(question: &quiz::Question) -> char {
+    let index = (question.answer + 1) % question.choices.len();
+    (b'a' + index as u8) as char
+}
+
+fn play(input: &str, demo: &str) -> ((usize, usize), String) {
+    let mut output = Vec::new();
+    let mut quiz = Quiz::new(Cursor::new(input), &mut output);
+    quiz.ask_demo(demo).unwrap();
+    quiz.summary().unwrap();
+    let score = quiz.score();
+    drop(quiz);
+    (score, String::from_utf8(output).unwrap())
+}
+
+#[test]
+fn a_question_needs_exactly_one_right_answer() {
+    let none = quiz::parse("== ownership\n? Moved?\n- yes\n- no\n").unwrap_err();
+    assert_eq!(none, "\"Moved?\" needs exactly one `+` answer, found 0");
+    let two = quiz::parse("== ownership\n? Moved?\n+ yes\n+ no\n? Next\n+ ok\n").unwrap_err();
+    assert_eq!(two, "\"Moved?\" needs exactly one `+` answer, found 2");
+
+    let parsed = quiz::parse("== ownership\n? Moved?\n- yes\n+ no\n> s1 moved\n").unwrap();
+    assert_eq!((parsed[0].demo.as_str(), parsed[0].answer, parsed[0].explanation.as_str()), ("ownership", 1, "s1 moved"));
+}
+
+#[test]
+fn every_quiz_demo_is_a_registered_demo() {
+    let registry = DemoRegistry::builtin();
+    for question in quiz::questions() {
+        assert!(registry.get(&question.demo).is_some(), "data/quiz.txt: no demo {:?}", question.demo);
+    }
+}
+
+#[test]
+fn out_of_range_and_non_numeric_answers_are_asked_again() {
+    let question = &quiz::questions()[0];
+    let beyond = question.choices.len() + 1;
+    let input = format!("z\n{}\n0\nfoo\n{}\n", beyond, right(question));
+    let mut output = Vec::new();
+    let answer = Quiz::new(Cursor::new(input), &mut output).ask(question).unwrap();
+    assert_eq!(answer, Answer::Correct);
+    assert_eq!(String::from_utf8(output).unwrap().matches(RETRY).count(), 4);
+}
+
+#[test]
+fn a_number_picks_the_same_choice_as_its_letter() {
+    let question = &quiz::questions()[0];
+    let mut output = Vec::new();
+    let answer = Quiz::new(Cursor::new(format!("{}\n", question.answer + 1)), &mut output).ask(question).unwrap();
+    assert_eq!(answer, Answer::Correct);
+}
+
+#[test]
+fn skip_counts_as_missed_and_quit_asks_nothing_more() {
+    let ((correct, asked), output) = play("s\nq\n", "ownership");
+    assert_eq!((correct, asked), (0, 1));
+    assert!(output.contains("=== Quiz Score: 0/1 (0%) ==="), "{}", output);
+    assert!(output.contains("Worth another look:"));
+
+    let mut output = Vec::new();
+    let mut quiz = Quiz::new(Cursor::new("q\n"), &mut output);
+    quiz.ask_demo("ownership").unwrap();
+    quiz.ask_demo("rc-weak").unwrap();
+    assert_eq!(quiz.score(), (0, 0));
+    quiz.summary().unwrap();
+    drop(quiz);
+    let output = String::from_utf8(output).unwrap();
+    assert!(!output.contains("--- Quiz: rc-weak") && !output.contains("Quiz Score"), "{}", output);
+}
+
+#[test]
+fn the_summary_scores_and_lists_the_misses() {
+    let questions: Vec<_> = quiz::questions_for("ownership").collect();
+    let (first, second) = (questions[0], questions[1]);
+    let ((correct, asked), output) = play(&format!("{}\n{}\n", right(first), wrong(second)), "ownership");
+    assert_eq!((correct, asked), (1, 2));
+    assert!(output.contains("✓ Correct."));
+    assert!(output.contains("=== Quiz Score: 1/2 (50%) ==="), "{}", output);
+    let misses = &output[output.find("Worth another look:").unwrap()..];
+    assert!(misses.contains(&format!("[ownership] {}", second.prompt)) && !misses.contains(&first.prompt));
+    assert!(misses.contains(&format!("→ {}", second.choices[second.answer])));
+}
@@ -0,0 +1,67 @@
+// RAII resources: dropping the owner gives the resource back - the file,
+// the connection, the lock - and forgetting it doesn't.
+
+use rust_playground::raii_resources::{self, Connection, Server};
+use rust_playground::report::NoteKind;
+
+#[test]
+fn dropping_a_connection_hangs_up() {
+    let server = Server::new();
+    let first = Connection::connect(&server);
+    let mut second = Connection::connect(&server);
+    assert_eq!(second.request("ping"), 4);
+    assert_eq!(server.open_connections(), 2);
+    drop(first);
+    drop(second);
+    assert_eq!((server.open_connections(), server.accepted()), (0, 2));
+}
+
+#[test]
+fn a_forgotten_connection_stays_open() {
+    let server = Server::new();
+    std::mem::forget(Connection::connect(&server));
+    assert_eq!(server.open_connections(), 1);
+}
+
+#[test]
+fn the_file_closes_when_its_scope_ends() {
+    let report = raii_resources::files();
+    let Some(rows) = report.tables().next() else {
+        return;  // No /proc/self/fd on this OS
+    };
+    let descriptors: Vec<usize> = rows.iter().map(|row| row[1].parse().unwrap()).collect();
+    assert!(descriptors[1] > descriptors[0], "the file wasn't open while alive: {:?}", descriptors);
+    assert!(descriptors[2] < descriptors[1], "the file stayed open after its scope: {:?}", descriptors);
+    assert!(report.notes(NoteKind::Fail).next().is_none());
+}
+
+#[test]
+fn each_connection_hangs_up_at_its_owners_end() {
+    let report = raii_resources::connections();
+    assert_eq!(report.count_of("connections accepted"), Some(100));
+    assert_eq!(report.count_of("most open at once"), Some(1));
+    assert_eq!(report.count_of("open after the loop"), Some(0));
+    assert_eq!(report.count_of("open while pooled"), Some(1));
+    assert_eq!(report.count_of("open after dropping the pool"), Some(0));
+}
+
+#[test]
+fn the_lock_is_held_exactly_while_a_guard_lives() {
+    let report = raii_resources::lock_guards();
+    let texts: Vec<&str> = report.texts().collect();
+    for expected in [
+        "guard sees [1, 2, 3]; try_lock() from here: WouldBlock - still held",
+        "after the block: try_lock() acquired",
+        "len = 3; held afterwards: false",
+        "held while `guard` is alive: true",
+        "held after drop(guard): false",
+    ] {
+        assert!(texts.contains(&expected), "missing {:?}", expected);
+    }
+}
+
+#[test]
+fn the_forgotten_connection_is_reported_open() {
+    let report = raii_resources::forgotten();
+    assert_eq!(report.count_of("connections the server still has open"), Some(1));
+}
@@ -0,0 +1,54 @@
+// RcTracker: counts at labeled points, without keeping the value alive.
+
+use std::rc::Rc;
+
+use rust_playground::rc_tracker::{RcTracker, Snapshot};
+use rust_playground::report::DemoReport;
+
+#[test]
+fn snapshots_record_strong_and_weak_counts() {
+    let data = Rc::new(String::from("gopher"));
+    let mut tracker = RcTracker::new(&data);
+    tracker.snapshot("created");
+    let clone = Rc::clone(&data);
+    let weak = Rc::downgrade(&data);
+    let counts = tracker.snapshot("clone + weak");
+    assert_eq!(counts, Snapshot { label: "clone + weak".into(), strong: 2, weak: 1 });
+    drop((data, clone));
+    assert!(tracker.snapshot("dropped").freed(), "the tracker's Weak doesn't keep the value alive");
+    assert!(weak.upgrade().is_none());
+
+    let counts: Vec<_> = tracker.snapshots().iter().map(|s| (s.strong, s.weak)).collect();
+    assert_eq!(counts, vec![(1, 0), (2, 1), (0, 0)]);
+    assert_eq!(tracker.peak_strong(), 2);
+}
+
+#[test]
+fn sparkline_scales_to_the_peak() {
+    let data = Rc::new(0);
+    let mut tracker = RcTracker::new(&data);
+    let mut clones = Vec::new();
+    for _ in 0..4 {
+        tracker.snapshot("");
+        clones.push(Rc::clone(&data));
+    }
+    drop((data, clones));
+    tracker.snapshot("freed");
+    assert_eq!(tracker.sparkline(), "▂▄▆█ ");
+    assert!(tracker.to_string().ends_with("▂▄▆█ "));
+}
+
+#[test]
+fn add_to_puts_a_table_in_the_report() {
+    let data = Rc::new(1);
+    let mut tracker = RcTracker::new(&data);
+    tracker.snapshot("created");
+    drop(data);
+    tracker.snapshot("dropped");
+
+    let mut report = DemoReport::new("Tracked");
+    tracker.add_to(&mut report);
+    let text = report.to_string();
+    assert!(text.contains("(freed)"));
+    assert!(text.contains("strong over time: █   (peak 1)"));
+}
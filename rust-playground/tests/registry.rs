@@ -0,0 +1,47 @@
+// The registry is what the CLI is built from: names become subcommands and
+// quiz keys, example names become `-e` values, tags become `--tag` filters.
+
+use std::collections::HashSet;
+
+use rust_playground::quiz;
+use rust_playground::registry::{DemoRegistry, Tag};
+
+#[test]
+fn every_demo_is_runnable_from_the_cli() {
+    let registry = DemoRegistry::builtin();
+    for demo in registry.demos() {
+        assert!(!demo.examples.is_empty(), "{} has no examples", demo.name);
+        assert!(!demo.tags.is_empty(), "{} has no tags", demo.name);
+        let names: HashSet<_> = demo.examples.iter().map(|example| example.name).collect();
+        assert_eq!(names.len(), demo.examples.len(), "{} repeats an example name", demo.name);
+    }
+}
+
+#[test]
+fn quiz_questions_belong_to_registered_demos() {
+    let registry = DemoRegistry::builtin();
+    for question in quiz::questions() {
+        assert!(registry.get(&question.demo).is_some(), "data/quiz.txt: no demo `{}`", question.demo);
+    }
+}
+
+#[test]
+fn tags_filter_the_menu() {
+    let registry = DemoRegistry::builtin();
+    assert_eq!(registry.tagged(&[]).count(), registry.demos().len());
+
+    let concurrency: Vec<_> = registry.tagged(&[Tag::Concurrency]).map(|demo| demo.name).collect();
+    assert!(concurrency.contains(&"channels"));
+    assert!(!concurrency.contains(&"ownership"));
+
+    let either = registry.tagged(&[Tag::Unsafe, Tag::Layout]).count();
+    assert!(either > registry.tagged(&[Tag::Unsafe]).count());
+}
+
+#[test]
+#[should_panic(expected = "registered twice")]
+fn names_are_unique() {
+    let mut registry = DemoRegistry::new();
+    registry.register(&rust_playground::ownership::DEMO);
+    registry.register(&rust_playground::ownership::DEMO);
+}
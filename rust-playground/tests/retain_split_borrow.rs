@@ -0,0 +1,66 @@
+// Split borrows: the helpers that hand out disjoint &mut, and the values each
+// example ends up with.
+
+use rust_playground::report::DemoReport;
+use rust_playground::retain_split_borrow::{self, Player, Sensor};
+
+#[test]
+fn parts_mut_hands_out_both_fields() {
+    let mut player = Player::default();
+    let (position, inventory) = player.parts_mut();
+    position.0 = 5;
+    inventory.push("map".into());
+    assert_eq!((player.position, player.inventory.len()), ((5, 0), 1));
+}
+
+#[test]
+fn filter_counts_what_retain_dropped() {
+    let mut sensor = Sensor { readings: vec![3, 12, 7, 15], threshold: 10, dropped: 0 };
+    sensor.filter();
+    assert_eq!((sensor.readings.as_slice(), sensor.dropped), ([12, 15].as_slice(), 2));
+}
+
+#[test]
+fn cascade_pours_everything_into_the_last_bucket() {
+    let mut buckets = vec![vec!["a".to_string()], vec![], vec!["b".to_string()]];
+    retain_split_borrow::cascade(&mut buckets);
+    assert_eq!(buckets, [vec![], vec![], vec!["b".to_string(), "a".to_string()]]);
+}
+
+fn assert_reports(report: &DemoReport, expected: &[&str]) {
+    let texts: Vec<&str> = report.texts().collect();
+    for line in expected {
+        assert!(texts.contains(line), "{}: missing {:?}", report.title, line);
+    }
+}
+
+#[test]
+fn both_field_borrows_land() {
+    assert_reports(&retain_split_borrow::fields(), &[r#"player = Player { name: "ferris", position: (3, -1), inventory: ["map", "torch"] }"#]);
+}
+
+#[test]
+fn split_apis_swap_the_right_elements() {
+    assert_reports(
+        &retain_split_borrow::slices(),
+        &[
+            "scores = [40, 20, 30, 10]",
+            "scores = [100, 20, 30, 10]",
+            "scores = [100, 10, 30, 20]",
+            "→ Err(OverlappingIndices): overlapping indices are checked at run time instead",
+            r#"buckets = [[], [], [], ["d", "b", "c", "a"]]"#,
+        ],
+    );
+}
+
+#[test]
+fn iter_mut_hands_out_every_element_once() {
+    let report = retain_split_borrow::iter_mut();
+    assert_reports(&report, &["prices = [90, 225, 359, 67]", "prices = [90, 226, 361, 70]", "pixels = [2, 128, 253, 3, 128, 252, 0, 128, 255, 1, 128, 254]"]);
+    assert_eq!(report.count_of("&mut i32 alive at the same time"), Some(4));
+}
+
+#[test]
+fn retain_keeps_and_counts() {
+    assert_reports(&retain_split_borrow::retain(), &["readings = [12, 15, 21], dropped = 3", "take-the-Vec fix: readings = [20]"]);
+}
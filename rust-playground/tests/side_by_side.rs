@@ -0,0 +1,51 @@
+// Go │ Rust columns: every renderer keeps the pairing and marks the rows that differ.
+
+use rust_playground::render::{Colored, Html, Json, Markdown, Plain, Renderer};
+use rust_playground::report::{DemoReport, SideBySideRow};
+
+fn sample() -> DemoReport {
+    let mut report = DemoReport::new("Sample");
+    report.side_by_side(vec![
+        SideBySideRow::same("user := &User{}", "let user = User {};"),
+        SideBySideRow::differs("ptr := user", "let owner = user;  // moves"),
+        SideBySideRow::differs("use(user)", ""),
+    ]);
+    report
+}
+
+#[test]
+fn plain_lines_up_the_rust_column() {
+    let text = Plain.render(&sample());
+    let lines: Vec<&str> = text.lines().filter(|line| line.contains('│') || line.contains('≠')).collect();
+    assert_eq!(lines[0], "  Go              │ Rust");
+    assert_eq!(lines[1], "  user := &User{} │ let user = User {};");
+    assert_eq!(lines[2], "  ptr := user     ≠ let owner = user;  // moves");
+    assert_eq!(lines[3], "  use(user)       ≠", "an empty side leaves no trailing spaces");
+}
+
+#[test]
+fn colored_highlights_only_differing_rows() {
+    let text = Colored.render(&sample());
+    let row = |code: &str| text.lines().find(|line| line.contains(code)).unwrap().to_string();
+    assert!(!row("user := &User{}").contains("\x1b[1;35m"));
+    assert!(row("ptr := user").contains("\x1b[1;35mlet owner = user;  // moves\x1b[0m"));
+}
+
+#[test]
+fn markdown_html_and_json_keep_the_pairs() {
+    let markdown = Markdown.render(&sample());
+    assert!(markdown.contains("| Go | | Rust |"));
+    assert!(markdown.contains("| `ptr := user` | ≠ | `let owner = user;  // moves` |"));
+    assert!(markdown.contains("| `use(user)` | ≠ |  |"));
+
+    let html = Html.render(&sample());
+    assert!(html.contains("<tr class=\"differs\"><td>ptr := user</td><td>let owner = user;  // moves</td></tr>"));
+    assert!(html.contains("<tr><td>user := &amp;User{}</td>"));
+
+    let json: serde_json::Value = serde_json::from_str(&Json.render(&sample())).unwrap();
+    let entry = &json["steps"][0]["entries"][0];
+    assert_eq!(entry["kind"], "side_by_side");
+    assert_eq!(entry["rows"][1]["rust"], "let owner = user;  // moves");
+    assert_eq!(entry["rows"][0]["differs"], false);
+    assert_eq!(entry["rows"][2]["differs"], true);
+}
@@ -0,0 +1,74 @@
+// Snapshot tests: the text each single-threaded demo prints, kept in
+// tests/snapshots/*.snap, so a refactor can't quietly change what a class
+// reads. Addresses differ every run and are replaced with 0x[addr].
+//
+// Changed a demo on purpose? Regenerate, then read the diff before committing:
+//   INSTA_UPDATE=always cargo test --test snapshots
+//
+// Left out: demos whose output depends on timing or thread scheduling
+// (benchmarks, contention, pipelines). With alloc-stats the reports carry
+// allocation counts, so the suite only runs without it.
+#![cfg(not(feature = "alloc-stats"))]
+
+use rust_playground::render::{Markdown, Plain, Renderer};
+
+const DETERMINISTIC: &[&str] = &[
+    "ownership",
+    "borrow-checker",
+    "comparison",
+    "lifetimes",
+    "drop-order",
+    "slices-and-strings",
+    "layout",
+    "cow",
+    "error-handling",
+    "pin-self-referential",
+    "smart-pointers",
+    "stack-vs-heap-escape",
+    "leak-check",
+    "unsafe-rust",
+    "closures-and-moves",
+    "string-encoding",
+    "workload",
+];
+
+// 0x followed by 4+ hex digits → 0x[addr]
+fn normalize(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(at) = rest.find("0x") {
+        out.push_str(&rest[..at]);
+        let digits = rest[at + 2..].bytes().take_while(u8::is_ascii_hexdigit).count();
+        if digits >= 4 {
+            out.push_str("0x[addr]");
+        } else {
+            out.push_str(&rest[at..at + 2 + digits]);
+        }
+        rest = &rest[at + 2 + digits..];
+    }
+    out.push_str(rest);
+    out
+}
+
+fn capture(renderer: &dyn Renderer, demo: &str) -> String {
+    let reports = rust_playground::run(demo).expect("registered demo");
+    normalize(&renderer.render_all(&reports))
+}
+
+#[test]
+fn demo_output_matches_snapshots() {
+    for demo in DETERMINISTIC {
+        insta::assert_snapshot!(*demo, capture(&Plain, demo));
+    }
+}
+
+#[test]
+fn markdown_output_matches_snapshot() {
+    insta::assert_snapshot!("ownership.md", capture(&Markdown, "ownership"));
+}
+
+#[test]
+fn normalize_hides_addresses_only() {
+    assert_eq!(normalize("v: 0x7ffd5e8a1c40 -> [1, 2]"), "v: 0x[addr] -> [1, 2]");
+    assert_eq!(normalize("flags 0x1f"), "flags 0x1f");
+}
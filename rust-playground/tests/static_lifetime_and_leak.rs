@@ -0,0 +1,17 @@
+// Interning hands out one &'static str per distinct string.
+
+use rust_playground::static_lifetime_and_leak::{self, Config};
+
+#[test]
+fn equal_strings_intern_to_one_pointer() {
+    let a = static_lifetime_and_leak::intern("GET");
+    let b = static_lifetime_and_leak::intern(&String::from("GET"));
+    assert!(std::ptr::eq(a, b));
+    assert!(!std::ptr::eq(a, static_lifetime_and_leak::intern("PUT")));
+}
+
+#[test]
+fn leaked_config_is_parsed() {
+    let config: &'static Config = static_lifetime_and_leak::leak_config("workers=3 tags=a,b");
+    assert_eq!((config.workers, config.listen.as_str(), config.tags.len()), (3, "127.0.0.1:8080", 2));
+}
@@ -0,0 +1,48 @@
+// --step: the pieces add up to the normal output, and the stepper stops
+// before each step until told to run on.
+
+use rust_playground::render::{Plain, Renderer};
+use rust_playground::report::DemoReport;
+use rust_playground::stepper::{StepMode, Stepper};
+
+fn sample() -> DemoReport {
+    let mut report = DemoReport::new("Sample");
+    report.text("intro");
+    report.step("Second");
+    report.code("let x = 1;");
+    report.step("Third");
+    report.code("let y = x;");
+    report
+}
+
+const PROMPT: &str = "[Enter: next step, q: run to the end]";
+
+#[test]
+fn pieces_add_up_to_the_report() {
+    let report = sample();
+    let pieces = Plain.pieces(&report);
+    assert_eq!(pieces.len(), 4);  // title + three steps
+    assert_eq!(pieces.iter().map(|piece| piece.text.as_str()).collect::<String>(), Plain.render(&report));
+    assert!(pieces[2].preview.contains("next: Second") && pieces[2].preview.contains("let x = 1;"));
+}
+
+#[test]
+fn pauses_before_every_step() {
+    let mut output = Vec::new();
+    Stepper::new("\n\n\n".as_bytes(), &mut output, StepMode::Pause).show(&Plain, &sample()).unwrap();
+    let output = String::from_utf8(output).unwrap();
+    assert_eq!(output.matches(PROMPT).count(), 3);
+    assert!(!output.contains("next:"));
+    assert!(output.find(PROMPT).unwrap() < output.find("intro").unwrap());
+}
+
+#[test]
+fn q_runs_to_the_end() {
+    let mut output = Vec::new();
+    let mut stepper = Stepper::new("q\n".as_bytes(), &mut output, StepMode::Preview);
+    stepper.show(&Plain, &sample()).unwrap();
+    stepper.show(&Plain, &sample()).unwrap();
+    let output = String::from_utf8(output).unwrap();
+    assert_eq!(output.matches(PROMPT).count(), 1);
+    assert_eq!(output.matches("let y = x;").count(), 2);
+}
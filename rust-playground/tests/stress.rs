@@ -0,0 +1,17 @@
+// Stress workloads: whatever the thread count, synchronized updates all land.
+
+use rust_playground::stress;
+
+#[test]
+fn synchronized_workloads_lose_nothing() {
+    for threads in [1, 4] {
+        assert_eq!(stress::shared_vec(threads, 1_000).lost(), 0);
+        assert_eq!(stress::compare_exchange_loop(threads, 1_000).lost(), 0);
+    }
+}
+
+#[test]
+fn one_thread_cannot_lose_updates() {
+    let run = stress::load_then_store(1, 10_000);
+    assert_eq!(run.observed, 10_000);
+}
@@ -0,0 +1,19 @@
+// Every concatenation strategy has to build the same String to be comparable.
+
+use rust_playground::string_concat_bench::{self, PARTS, STRATEGIES};
+
+#[test]
+fn strategies_agree_on_the_result() {
+    let parts = string_concat_bench::parts();
+    let expected = parts.join(",");
+    for strategy in STRATEGIES {
+        assert_eq!((strategy.build)(&parts), expected, "{}", strategy.name);
+    }
+    assert_eq!(expected.matches(',').count(), PARTS - 1);
+}
+
+#[test]
+fn the_results_go_into_the_run_summary() {
+    let report = string_concat_bench::strategies();
+    assert_eq!(report.summary.len(), 1);
+}
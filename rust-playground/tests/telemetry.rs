@@ -0,0 +1,57 @@
+// Telemetry: a demo run under the JSON subscriber gives one line per span
+// close, the demo's wrapping its examples'.
+// Run with: cargo test --features tracing --test telemetry
+#![cfg(feature = "tracing")]
+
+use std::io::{self, Write};
+use std::sync::{Arc, Mutex};
+
+use rust_playground::{copy_vs_clone, telemetry};
+
+#[derive(Clone, Default)]
+struct Captured(Arc<Mutex<Vec<u8>>>);
+
+impl Write for Captured {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+fn run_traced(run: impl FnOnce()) -> Vec<String> {
+    let captured = Captured::default();
+    let writer = captured.clone();
+    tracing::subscriber::with_default(telemetry::json_subscriber(move || writer.clone()), run);
+    let text = String::from_utf8(captured.0.lock().unwrap().clone()).unwrap();
+    text.lines().map(str::to_string).collect()
+}
+
+#[test]
+fn a_demo_span_closes_after_its_example_spans() {
+    let lines = run_traced(|| {
+        copy_vs_clone::DEMO.run();
+    });
+    let closes: Vec<&String> = lines.iter().filter(|line| line.contains(r#""message":"close""#)).collect();
+    assert_eq!(closes.len(), copy_vs_clone::DEMO.examples.len() + 1);
+    let demo = closes.last().unwrap();
+    assert!(demo.contains(r#""demo":"copy-vs-clone""#), "{}", demo);
+    assert!(demo.contains(&format!(r#""examples":{}"#, copy_vs_clone::DEMO.examples.len())), "{}", demo);
+    for example in &closes[..closes.len() - 1] {
+        assert!(example.contains(r#""name":"example""#) && example.contains(r#""duration_ns":"#), "{}", example);
+        assert!(example.contains(r#""title":"#), "{}", example);
+    }
+}
+
+#[test]
+fn only_the_named_examples_run() {
+    let lines = run_traced(|| {
+        copy_vs_clone::DEMO.run_examples(["derive", "no-such-example"]);
+    });
+    let closes = lines.iter().filter(|line| line.contains(r#""message":"close""#)).count();
+    assert_eq!(closes, 2);
+    assert!(lines.iter().any(|line| line.contains(r#""title":"Deriving Copy and Clone""#)));
+}
@@ -0,0 +1,74 @@
+// The end-of-run summary: one row per demo, most expensive first.
+
+use std::thread;
+use std::time::Duration;
+
+use rust_playground::report::{DemoReport, Entry};
+use rust_playground::timing::Timings;
+
+#[test]
+fn summary_lists_the_slowest_demo_first() {
+    let mut timings = Timings::new();
+    timings.time("fast", Vec::new);
+    timings.time("slow", || {
+        thread::sleep(Duration::from_millis(5));
+        Vec::new()
+    });
+
+    let order: Vec<&str> = timings.sorted().iter().map(|run| run.demo).collect();
+    assert_eq!(order, ["slow", "fast"]);
+    assert!(timings.total() >= Duration::from_millis(5));
+
+    let summary = timings.summary();
+    let rows = summary
+        .entries()
+        .find_map(|entry| match entry {
+            Entry::Table { rows, .. } => Some(rows.clone()),
+            _ => None,
+        })
+        .expect("a summary table");
+    assert_eq!(rows[0][0], "slow");
+    assert_eq!(summary.count_of("demos"), Some(2));
+}
+
+#[test]
+fn reports_pass_through_untouched() {
+    let mut timings = Timings::new();
+    let reports = timings.run(&rust_playground::layout::DEMO);
+    assert_eq!(reports.len(), rust_playground::layout::DEMO.examples.len());
+    assert_eq!(timings.runs()[0].demo, "layout");
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn resident_memory_is_recorded_around_each_demo() {
+    let mut timings = Timings::new();
+    timings.time("touches 8 MiB", || {
+        let pages = vec![1u8; 8 << 20];
+        std::hint::black_box(&pages);
+        Vec::new()
+    });
+
+    let memory = timings.runs()[0].memory.expect("/proc/self/status is readable");
+    assert!(memory.after.peak_rss >= 8 << 20);
+    assert!(memory.after.peak_rss >= memory.after.rss);
+    assert_eq!(rust_playground::process_stats::format_change(-2048), "-2.0 KiB");
+}
+
+#[test]
+fn summary_tables_are_repeated_at_the_end() {
+    let mut timings = Timings::new();
+    timings.time("bench", || {
+        let mut report = DemoReport::new("Results");
+        report.text("prose stays in the demo");
+        report.summary_table(&["strategy", "ns"], vec![vec!["join".into(), "42".into()]]);
+        vec![report]
+    });
+    timings.time("plain", || vec![DemoReport::new("No results")]);
+
+    assert_eq!(timings.results().len(), 1);
+    let summary = timings.summary();
+    let step = summary.steps.last().unwrap();
+    assert_eq!(step.heading.as_deref(), Some("bench: Results"));
+    assert!(matches!(&step.entries[..], [Entry::Table { rows, .. }] if rows[0][0] == "join"));
+}
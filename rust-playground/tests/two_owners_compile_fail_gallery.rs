@@ -0,0 +1,96 @@
+// The gallery and tests/compile_fail/gallery stay in step: every file has a
+// case with a fix, every case's file is annotated and fails with its error,
+// and every fix runs and is reported under its case.
+
+use std::collections::BTreeSet;
+use std::fs;
+
+use rust_playground::report::{Entry, NoteKind};
+use rust_playground::two_owners_compile_fail_gallery::{self, CASES};
+
+const GALLERY: &str = "tests/compile_fail/gallery";
+
+#[test]
+fn every_file_has_a_case_and_every_case_a_file() {
+    let files: BTreeSet<String> = fs::read_dir(GALLERY)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|extension| extension == "rs"))
+        .map(|path| path.file_stem().unwrap().to_string_lossy().into_owned())
+        .collect();
+    let cases: BTreeSet<String> = CASES.iter().map(|case| case.name.to_string()).collect();
+    assert_eq!(files, cases);
+}
+
+#[test]
+fn every_case_is_annotated() {
+    let examples: Vec<&str> = two_owners_compile_fail_gallery::DEMO.examples.iter().map(|example| example.name).collect();
+    for case in CASES {
+        assert!(case.broken.starts_with(&format!("// two_owners_compile_fail_gallery::{} - ", case.group)), "{}: header names another example", case.name);
+        assert!(examples.contains(&case.group), "{}: no example {}", case.name, case.group);
+        assert!(!case.scenario().is_empty(), "{}: no scenario", case.name);
+        assert!(case.fix().starts_with(&format!("fixed::{} ", case.name)), "{}: the Fix line names another fn", case.name);
+        let (_, annotation) = case.rejected_line().unwrap_or_else(|| panic!("{}: no ❌ line", case.name));
+        assert!(annotation.starts_with(case.error) || case.error == "not Send", "{}: ❌ line doesn't say {}", case.name, case.error);
+    }
+}
+
+#[test]
+fn every_case_fails_with_its_error() {
+    for case in CASES {
+        let stderr = fs::read_to_string(format!("{}/{}.stderr", GALLERY, case.name)).unwrap();
+        let expected = match case.error {
+            "not Send" => "error: future cannot be sent between threads safely".to_string(),
+            code => format!("error[{}]", code),
+        };
+        assert!(stderr.starts_with(&expected), "{}: first error isn't {}", case.name, expected);
+    }
+}
+
+// What each fixed version prints when it runs
+const RESULTS: [(&str, &str); 20] = [
+    ("two_owners", "server on 8080, worker on 8080 - 3 handles to one Config"),
+    ("moved_in_loop", "sent 6 items in 3 attempts, and the batch is still here (2 items)"),
+    ("used_after_push", "added gopher"),
+    ("move_out_of_index", "ferris of 2"),
+    ("move_out_of_borrowed_option", "ferris, anonymous"),
+    ("returns_ref_to_local", "HELLO"),
+    ("ref_outlives_scope", "GET /index.html"),
+    ("temporary_dropped_while_borrowed", "Some(\"port=8080\")"),
+    ("self_referential_struct", "Ownership (14 bytes)"),
+    ("callback_borrows_local", "clicked Save"),
+    ("push_while_borrowed", "best was 10, now 4 scores"),
+    ("mutate_while_iterating", "[1, 2, 3, 10, 30]"),
+    ("remove_in_for_loop", "[\"ferris\", \"gopher\"]"),
+    ("get_or_insert_cache", "value 7 - 1 entry after two lookups"),
+    ("method_borrows_all_of_self", "[(\"bolts\", 40), (\"nuts\", 100)], log: [\"restocking\"]"),
+    ("closure_holds_mut_borrow", "clicks: 2"),
+    ("thread_borrows_local", "the thread counted 3 lines"),
+    ("shared_counter_in_scope", "total = 4"),
+    ("async_block_borrows_local", "the future owned its rows: 3"),
+    ("guard_held_across_await", "hits = 1"),
+];
+
+#[test]
+fn every_fix_computes_its_result() {
+    assert_eq!(CASES.len(), RESULTS.len());
+    for (case, (name, expected)) in CASES.iter().zip(RESULTS) {
+        assert_eq!(case.name, name);
+        assert_eq!((case.fixed)(), expected, "{}", name);
+    }
+}
+
+#[test]
+fn every_fix_is_reported_under_its_case() {
+    let reports = two_owners_compile_fail_gallery::demonstrate_two_owners_compile_fail_gallery();
+    assert!(reports.iter().all(|report| report.notes(NoteKind::Fail).next().is_none()));
+    for (name, expected) in RESULTS {
+        let step = reports
+            .iter()
+            .flat_map(|report| &report.steps)
+            .find(|step| step.heading.as_deref().is_some_and(|heading| heading.starts_with(&format!("{} - ", name))))
+            .unwrap_or_else(|| panic!("no step for {}", name));
+        let result = format!("→ {}", expected);
+        assert!(step.entries.iter().any(|entry| matches!(entry, Entry::Note(NoteKind::Ok, text) if *text == result)), "{}: result not reported", name);
+    }
+}
@@ -0,0 +1,29 @@
+// Collections memory: a warm ring buffer never grows, and the footprint of a
+// presized Vec is exactly its payload.
+
+use std::collections::VecDeque;
+
+use rust_playground::vecdeque_and_collections_memory::{self, ENTRIES};
+
+#[test]
+fn a_warm_ring_buffer_keeps_its_buffer() {
+    let mut queue: VecDeque<u64> = (0..100).collect();
+    let capacity = queue.capacity();
+    let popped = vecdeque_and_collections_memory::churn(&mut queue, 10_000);
+    assert_eq!(popped, (0..100).sum::<u64>() + (0..9_900).sum::<u64>());
+    assert_eq!(queue.len(), 100);
+    assert_eq!(queue.capacity(), capacity);
+}
+
+#[test]
+fn a_presized_vec_holds_exactly_its_payload() {
+    let (vec, usage) = vecdeque_and_collections_memory::footprint(|| Vec::<(u64, u64)>::with_capacity(ENTRIES));
+    assert_eq!(vec.capacity(), ENTRIES);
+    match usage {
+        Some(usage) => {
+            assert_eq!(usage.allocations, 1);
+            assert_eq!(usage.retained, ENTRIES * 16);
+        }
+        None => assert!(!rust_playground::alloc_stats::enabled()),
+    }
+}
@@ -0,0 +1,82 @@
+// -q / -v / -vv: what each level keeps and adds, on a hand-built report.
+
+use rust_playground::render::{Json, Plain, Renderer, Verbose, Verbosity};
+use rust_playground::report::{DemoReport, Entry};
+use std::time::Duration;
+
+fn sample() -> DemoReport {
+    let value = 42u64;
+    let mut report = DemoReport::new("Sample");
+    report.text("Some tutorial prose");
+    report.address("value", &value, "42");
+    report.ok("A takeaway");
+    report.step("Go");
+    report.code("x := 42");
+    report.step("Numbers");
+    report.count("items", 3);
+    report.duration("elapsed", Duration::from_micros(1500));
+    report.table(&["Kind", "Bytes"], vec![vec!["u64".into(), "8".into()]]);
+    report.table(&["", "Go", "Rust"], vec![vec!["nil".into(), "yes".into(), "no".into()]]);
+    report
+}
+
+#[test]
+fn flags_pick_a_level() {
+    assert_eq!(Verbosity::from_flags(false, 0), Verbosity::Normal);
+    assert_eq!(Verbosity::from_flags(false, 1), Verbosity::Verbose);
+    assert_eq!(Verbosity::from_flags(false, 3), Verbosity::VeryVerbose);
+    assert_eq!(Verbosity::from_flags(true, 2), Verbosity::Quiet);
+}
+
+#[test]
+fn normal_is_the_report_unchanged() {
+    let report = sample();
+    assert_eq!(Verbosity::Normal.apply(&report), Some(report.clone()));
+    let verbose = Verbose { renderer: &Plain, verbosity: Verbosity::Normal };
+    assert_eq!(verbose.render(&report), Plain.render(&report));
+}
+
+#[test]
+fn quiet_keeps_only_measured_numbers() {
+    let quiet = Verbosity::Quiet.apply(&sample()).unwrap();
+    let kinds: Vec<&str> = quiet
+        .entries()
+        .map(|entry| match entry {
+            Entry::Count { .. } => "count",
+            Entry::Measurement { .. } => "measurement",
+            Entry::Table { .. } => "table",
+            _ => "prose",
+        })
+        .collect();
+    assert_eq!(kinds, ["count", "measurement", "table"]);
+    assert_eq!(quiet.steps.len(), 1, "steps left empty are dropped");
+
+    let mut prose_only = DemoReport::new("Prose");
+    prose_only.text("nothing measured here");
+    assert_eq!(Verbosity::Quiet.apply(&prose_only), None);
+    let quiet = Verbose { renderer: &Plain, verbosity: Verbosity::Quiet };
+    assert_eq!(quiet.render(&prose_only), "");
+}
+
+#[test]
+fn verbose_appends_raw_details() {
+    let report = sample();
+    let verbose = Verbosity::Verbose.apply(&report).unwrap();
+    assert_eq!(&verbose.steps[..report.steps.len()], &report.steps[..]);
+    let details = verbose.steps.last().unwrap();
+    assert_eq!(details.heading.as_deref(), Some("Details"));
+    let text = Plain.render(&verbose);
+    assert!(text.contains("1500000 ns"), "raw nanoseconds:\n{}", text);
+    assert!(text.contains("aligned to"), "address details:\n{}", text);
+
+    let very = Plain.render(&Verbosity::VeryVerbose.apply(&report).unwrap());
+    assert!(very.contains("from previous") && very.contains("the report itself"), "{}", very);
+}
+
+#[test]
+fn json_stays_one_array() {
+    let reports = [sample(), DemoReport::new("Empty")];
+    let quiet = Verbose { renderer: &Json, verbosity: Verbosity::Quiet };
+    let value: serde_json::Value = serde_json::from_str(&quiet.render_all(&reports)).unwrap();
+    assert_eq!(value.as_array().unwrap().len(), 1);
+}
@@ -0,0 +1,45 @@
+// The cross-language runner: the line format both programs print, and the
+// table built from whatever each language produced. (Go itself isn't run here.)
+
+use rust_playground::render::{Plain, Renderer};
+use rust_playground::xlang_bench::{self, Sample, WORKLOADS};
+
+#[test]
+fn sample_lines_round_trip() {
+    let line = "xlang workload=vec-push ops=1000000 ns=4123456 allocs=38 bytes=41678848 gcs=2";
+    let sample = xlang_bench::parse_line(line).unwrap();
+    assert_eq!(sample.workload, "vec-push");
+    assert_eq!((sample.ops, sample.nanos), (1_000_000, 4_123_456));
+    assert_eq!((sample.allocations, sample.bytes, sample.gcs), (Some(38), Some(41_678_848), Some(2)));
+    assert_eq!(sample.to_string(), line);
+}
+
+#[test]
+fn headers_noise_and_unmeasured_fields() {
+    let output = "xlang language=go version=go1.25.3 threads=4\ngo: downloading nothing\nxlang workload=box-alloc ops=10 ns=50 allocs=- bytes=- gcs=-\n";
+    let samples = xlang_bench::parse_output(output);
+    assert_eq!(samples.len(), 1);
+    assert_eq!((samples[0].allocations, samples[0].gcs), (None, None));
+}
+
+#[test]
+fn rust_workloads_agree_on_small_sizes() {
+    for workload in WORKLOADS {
+        let sample = xlang_bench::measure(workload, 1_023);
+        assert_eq!(sample.workload, workload.name);
+        assert_eq!(xlang_bench::parse_line(&sample.to_string()), Some(sample));
+    }
+}
+
+#[test]
+fn comparison_lines_up_both_languages() {
+    let rust: Vec<Sample> = WORKLOADS.iter().map(|workload| Sample { workload: workload.name.into(), ops: workload.ops, nanos: workload.ops as u64 * 10, allocations: Some(1), bytes: Some(64), gcs: None }).collect();
+    let go = vec![Sample { workload: "vec-push".into(), ops: 1_000_000, nanos: 30_000_000, allocations: Some(40), bytes: Some(4096), gcs: Some(3) }];
+    let text = Plain.render(&xlang_bench::comparison(&rust, &go));
+    let vec_push = text.lines().find(|line| line.trim_start().starts_with("vec-push")).unwrap();
+    assert!(vec_push.contains("3.00×"), "{}", vec_push);
+    assert!(!text.contains("out of sync"), "{}", text);
+
+    let stale = vec![Sample { ops: 5, ..go[0].clone() }];
+    assert!(Plain.render(&xlang_bench::comparison(&rust, &stale)).contains("out of sync"));
+}